@@ -0,0 +1,477 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Post-trade allocation of parent account fills across sub-accounts.
+//!
+//! Each parent account may be configured with an [`AllocationRule`]. Fills on the parent account
+//! are then split across its sub-accounts, generating an [`AllocationRecord`] per sub-account
+//! and updating the net position held by each sub-account. Allocated quantities are rounded to
+//! the fill's size precision, with any rounding remainder assigned by largest remainder so the
+//! allocations always sum to the fill quantity.
+
+use ahash::AHashMap;
+use nautilus_model::{
+    enums::OrderSide,
+    events::OrderFilled,
+    identifiers::{AccountId, ClientOrderId, InstrumentId, TradeId},
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+/// The rule by which a parent account's fills are allocated across sub-accounts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AllocationRule {
+    /// Allocate proportionally to the weights (which need not sum to one).
+    Ratio(Vec<(AccountId, f64)>),
+    /// Allocate to each sub-account in order until its net position in the instrument reaches
+    /// its target quantity, with any excess allocated to the last sub-account. Fills reducing
+    /// existing sub-account positions are first allocated against those positions (lowest
+    /// priority first).
+    Priority(Vec<(AccountId, Quantity)>),
+}
+
+impl AllocationRule {
+    fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Ratio(weights) => {
+                if weights.is_empty() {
+                    anyhow::bail!("Ratio allocation requires at least one sub-account");
+                }
+                if weights.iter().any(|(_, w)| !w.is_finite() || *w < 0.0) {
+                    anyhow::bail!("Ratio allocation weights must be finite and non-negative");
+                }
+                if weights.iter().map(|(_, w)| w).sum::<f64>() <= 0.0 {
+                    anyhow::bail!("Ratio allocation weights must sum to a positive value");
+                }
+            }
+            Self::Priority(targets) => {
+                if targets.is_empty() {
+                    anyhow::bail!("Priority allocation requires at least one sub-account");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A record of part of a parent account fill allocated to a sub-account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationRecord {
+    pub trade_id: TradeId,
+    pub client_order_id: ClientOrderId,
+    pub parent_account_id: AccountId,
+    pub sub_account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub price: Price,
+}
+
+/// The net position of a sub-account in an instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubAccountPosition {
+    /// The signed quantity (positive for long).
+    pub signed_qty: f64,
+    /// The average price of the open quantity.
+    pub avg_px: f64,
+    /// The realized PnL in price points multiplied by quantity.
+    pub realized_pnl: f64,
+}
+
+impl SubAccountPosition {
+    fn apply(&mut self, side: OrderSide, quantity: f64, price: f64) {
+        let signed = match side {
+            OrderSide::Buy => quantity,
+            OrderSide::Sell => -quantity,
+            OrderSide::NoOrderSide => return,
+        };
+
+        if self.signed_qty == 0.0 || self.signed_qty.signum() == signed.signum() {
+            let open = self.signed_qty.abs();
+            self.avg_px = (open * self.avg_px + quantity * price) / (open + quantity);
+            self.signed_qty += signed;
+            return;
+        }
+
+        let closed = quantity.min(self.signed_qty.abs());
+        self.realized_pnl += closed * (price - self.avg_px) * self.signed_qty.signum();
+        self.signed_qty += signed;
+        if self.signed_qty == 0.0 {
+            self.avg_px = 0.0;
+        } else if self.signed_qty.signum() == signed.signum() {
+            self.avg_px = price;
+        }
+    }
+}
+
+/// Allocates parent account fills across sub-accounts.
+#[derive(Clone, Debug, Default)]
+pub struct FillAllocator {
+    rules: AHashMap<AccountId, AllocationRule>,
+    allocated: AHashMap<(AccountId, AccountId, InstrumentId), f64>,
+    records: Vec<AllocationRecord>,
+    positions: AHashMap<(AccountId, InstrumentId), SubAccountPosition>,
+}
+
+impl FillAllocator {
+    /// Creates a new [`FillAllocator`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the allocation rule for `parent_account_id`, replacing any existing rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rule` has no sub-accounts, or invalid ratio weights.
+    pub fn set_rule(
+        &mut self,
+        parent_account_id: AccountId,
+        rule: AllocationRule,
+    ) -> anyhow::Result<()> {
+        rule.validate()?;
+        self.rules.insert(parent_account_id, rule);
+        Ok(())
+    }
+
+    /// Returns the allocation rule for `parent_account_id` (if configured).
+    #[must_use]
+    pub fn rule(&self, parent_account_id: &AccountId) -> Option<&AllocationRule> {
+        self.rules.get(parent_account_id)
+    }
+
+    /// Allocates `fill` across the sub-accounts of its account, returning the records created.
+    ///
+    /// Fills on accounts without an allocation rule are not allocated.
+    pub fn allocate(&mut self, fill: &OrderFilled) -> Vec<AllocationRecord> {
+        let Some(rule) = self.rules.get(&fill.account_id) else {
+            return Vec::new();
+        };
+
+        let precision = fill.last_qty.precision;
+        let scale = 10_f64.powi(i32::from(precision));
+        let total_units = (fill.last_qty.as_f64() * scale).round() as u64;
+
+        let units = match rule {
+            AllocationRule::Ratio(weights) => {
+                let sum: f64 = weights.iter().map(|(_, w)| w).sum();
+                let shares: Vec<f64> = weights.iter().map(|(_, w)| w / sum).collect();
+                let ids = weights.iter().map(|(id, _)| *id);
+                ids.zip(split_units(total_units, &shares))
+                    .collect::<Vec<_>>()
+            }
+            AllocationRule::Priority(targets) => {
+                let sign = order_side_sign(fill.order_side);
+                let held: Vec<f64> = targets
+                    .iter()
+                    .map(|(sub_account_id, _)| {
+                        self.allocated
+                            .get(&(fill.account_id, *sub_account_id, fill.instrument_id))
+                            .copied()
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                let mut units = vec![0_u64; targets.len()];
+                let mut remaining = total_units;
+
+                // Reduce opposing sub-account positions first, lowest priority first
+                for i in (0..targets.len()).rev() {
+                    if held[i] * sign < 0.0 {
+                        let open = (held[i].abs() * scale).round() as u64;
+                        let take = remaining.min(open);
+                        units[i] += take;
+                        remaining -= take;
+                    }
+                }
+
+                // Then open towards each target in priority order
+                let last = targets.len() - 1;
+                for (i, (_, target)) in targets.iter().enumerate() {
+                    let open = (held[i] * sign).max(0.0);
+                    let capacity = ((target.as_f64() - open).max(0.0) * scale).round() as u64;
+                    let take = if i == last {
+                        remaining
+                    } else {
+                        remaining.min(capacity)
+                    };
+                    units[i] += take;
+                    remaining -= take;
+                }
+
+                targets
+                    .iter()
+                    .map(|(sub_account_id, _)| *sub_account_id)
+                    .zip(units)
+                    .collect()
+            }
+        };
+
+        let mut records = Vec::new();
+        for (sub_account_id, units) in units {
+            if units == 0 {
+                continue;
+            }
+            let quantity = Quantity::new(units as f64 / scale, precision);
+            let record = AllocationRecord {
+                trade_id: fill.trade_id,
+                client_order_id: fill.client_order_id,
+                parent_account_id: fill.account_id,
+                sub_account_id,
+                instrument_id: fill.instrument_id,
+                order_side: fill.order_side,
+                quantity,
+                price: fill.last_px,
+            };
+
+            *self
+                .allocated
+                .entry((fill.account_id, sub_account_id, fill.instrument_id))
+                .or_default() += order_side_sign(fill.order_side) * quantity.as_f64();
+            self.positions
+                .entry((sub_account_id, fill.instrument_id))
+                .or_default()
+                .apply(fill.order_side, quantity.as_f64(), fill.last_px.as_f64());
+            records.push(record);
+        }
+
+        self.records.extend_from_slice(&records);
+        records
+    }
+
+    /// Returns all allocation records created.
+    #[must_use]
+    pub fn records(&self) -> &[AllocationRecord] {
+        &self.records
+    }
+
+    /// Returns the allocation records for `sub_account_id`.
+    #[must_use]
+    pub fn records_for(&self, sub_account_id: &AccountId) -> Vec<&AllocationRecord> {
+        self.records
+            .iter()
+            .filter(|record| &record.sub_account_id == sub_account_id)
+            .collect()
+    }
+
+    /// Returns the position of `sub_account_id` in `instrument_id` (if any allocations).
+    #[must_use]
+    pub fn position(
+        &self,
+        sub_account_id: &AccountId,
+        instrument_id: &InstrumentId,
+    ) -> Option<&SubAccountPosition> {
+        self.positions.get(&(*sub_account_id, *instrument_id))
+    }
+
+    /// Clears all records, positions and allocated totals, retaining the rules.
+    pub fn reset(&mut self) {
+        self.allocated.clear();
+        self.records.clear();
+        self.positions.clear();
+    }
+}
+
+fn order_side_sign(side: OrderSide) -> f64 {
+    match side {
+        OrderSide::Buy => 1.0,
+        OrderSide::Sell => -1.0,
+        OrderSide::NoOrderSide => 0.0,
+    }
+}
+
+fn split_units(total: u64, shares: &[f64]) -> Vec<u64> {
+    let exact: Vec<f64> = shares.iter().map(|share| share * total as f64).collect();
+    let mut units: Vec<u64> = exact.iter().map(|value| value.floor() as u64).collect();
+    let mut remainder = total - units.iter().sum::<u64>();
+
+    let mut order: Vec<usize> = (0..shares.len()).collect();
+    order.sort_by(|a, b| {
+        let frac_a = exact[*a] - exact[*a].floor();
+        let frac_b = exact[*b] - exact[*b].floor();
+        frac_b.total_cmp(&frac_a)
+    });
+    for i in order {
+        if remainder == 0 {
+            break;
+        }
+        units[i] += 1;
+        remainder -= 1;
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn fill(side: OrderSide, qty: &str, px: &str) -> OrderFilled {
+        OrderFilled {
+            account_id: AccountId::from("PARENT-001"),
+            order_side: side,
+            last_qty: Quantity::from(qty),
+            last_px: Price::from(px),
+            ..Default::default()
+        }
+    }
+
+    fn ratio_allocator() -> FillAllocator {
+        let mut allocator = FillAllocator::new();
+        allocator
+            .set_rule(
+                AccountId::from("PARENT-001"),
+                AllocationRule::Ratio(vec![
+                    (AccountId::from("SUB-001"), 1.0),
+                    (AccountId::from("SUB-002"), 1.0),
+                    (AccountId::from("SUB-003"), 1.0),
+                ]),
+            )
+            .unwrap();
+        allocator
+    }
+
+    #[rstest]
+    fn test_invalid_rules_rejected() {
+        let mut allocator = FillAllocator::new();
+
+        assert!(
+            allocator
+                .set_rule(AccountId::from("PARENT-001"), AllocationRule::Ratio(vec![]))
+                .is_err()
+        );
+        assert!(
+            allocator
+                .set_rule(
+                    AccountId::from("PARENT-001"),
+                    AllocationRule::Ratio(vec![(AccountId::from("SUB-001"), -1.0)])
+                )
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_ratio_allocation_sums_to_fill() {
+        let mut allocator = ratio_allocator();
+
+        let records = allocator.allocate(&fill(OrderSide::Buy, "100", "10.00"));
+
+        let quantities: Vec<_> = records.iter().map(|r| r.quantity).collect();
+        assert_eq!(
+            quantities,
+            vec![Quantity::from(34), Quantity::from(33), Quantity::from(33)]
+        );
+    }
+
+    #[rstest]
+    fn test_unconfigured_account_not_allocated() {
+        let mut allocator = ratio_allocator();
+        let mut fill = fill(OrderSide::Buy, "100", "10.00");
+        fill.account_id = AccountId::from("OTHER-001");
+
+        assert!(allocator.allocate(&fill).is_empty());
+        assert!(allocator.records().is_empty());
+    }
+
+    #[rstest]
+    fn test_priority_allocation_respects_targets_across_fills() {
+        let mut allocator = FillAllocator::new();
+        allocator
+            .set_rule(
+                AccountId::from("PARENT-001"),
+                AllocationRule::Priority(vec![
+                    (AccountId::from("SUB-001"), Quantity::from(60)),
+                    (AccountId::from("SUB-002"), Quantity::from(40)),
+                ]),
+            )
+            .unwrap();
+
+        let first = allocator.allocate(&fill(OrderSide::Buy, "50", "10.00"));
+        let second = allocator.allocate(&fill(OrderSide::Buy, "50", "10.00"));
+
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].quantity, Quantity::from(50));
+        assert_eq!(second[0].sub_account_id, AccountId::from("SUB-001"));
+        assert_eq!(second[0].quantity, Quantity::from(10));
+        assert_eq!(second[1].quantity, Quantity::from(40));
+    }
+
+    #[rstest]
+    fn test_priority_allocation_round_trip_nets_by_side_and_instrument() {
+        let mut allocator = FillAllocator::new();
+        allocator
+            .set_rule(
+                AccountId::from("PARENT-001"),
+                AllocationRule::Priority(vec![
+                    (AccountId::from("SUB-001"), Quantity::from(60)),
+                    (AccountId::from("SUB-002"), Quantity::from(40)),
+                ]),
+            )
+            .unwrap();
+        let sub_001 = AccountId::from("SUB-001");
+        let sub_002 = AccountId::from("SUB-002");
+        let instrument_id = OrderFilled::default().instrument_id;
+
+        // Open 100 then close 70: the lowest priority sub-account is reduced first
+        allocator.allocate(&fill(OrderSide::Buy, "100", "10.00"));
+        let closing = allocator.allocate(&fill(OrderSide::Sell, "70", "11.00"));
+        assert_eq!(closing.len(), 2);
+        assert_eq!(closing[0].sub_account_id, sub_001);
+        assert_eq!(closing[0].quantity, Quantity::from(30));
+        assert_eq!(closing[1].sub_account_id, sub_002);
+        assert_eq!(closing[1].quantity, Quantity::from(40));
+
+        // Close the remainder, flattening every sub-account
+        allocator.allocate(&fill(OrderSide::Sell, "30", "11.00"));
+        for sub_account_id in [sub_001, sub_002] {
+            let position = allocator.position(&sub_account_id, &instrument_id).unwrap();
+            assert_eq!(position.signed_qty, 0.0);
+        }
+
+        // Targets apply afresh to a new position, including on the sell side
+        let reopen = allocator.allocate(&fill(OrderSide::Sell, "100", "11.00"));
+        assert_eq!(reopen[0].quantity, Quantity::from(60));
+        assert_eq!(reopen[1].quantity, Quantity::from(40));
+
+        // Allocations in another instrument are tracked independently
+        let mut other = fill(OrderSide::Buy, "50", "5.00");
+        other.instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let records = allocator.allocate(&other);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].sub_account_id, sub_001);
+        assert_eq!(records[0].quantity, Quantity::from(50));
+    }
+
+    #[rstest]
+    fn test_sub_account_positions() {
+        let mut allocator = ratio_allocator();
+        allocator.allocate(&fill(OrderSide::Buy, "30", "10.00"));
+        allocator.allocate(&fill(OrderSide::Sell, "15", "12.00"));
+
+        let position = allocator
+            .position(
+                &AccountId::from("SUB-001"),
+                &OrderFilled::default().instrument_id,
+            )
+            .unwrap();
+
+        assert_eq!(position.signed_qty, 5.0);
+        assert_eq!(position.avg_px, 10.0);
+        assert_eq!(position.realized_pnl, 10.0);
+        assert_eq!(allocator.records_for(&AccountId::from("SUB-001")).len(), 2);
+    }
+}
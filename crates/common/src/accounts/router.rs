@@ -0,0 +1,371 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Routing of trading commands across multiple accounts held by one trader.
+//!
+//! Accounts are registered per venue, with the first registered account becoming the venue
+//! default. Strategies may be assigned to a specific account at a venue, in which case their
+//! commands are routed to that account's execution client. Balance checks and reporting are
+//! available per account, along with balances consolidated across all accounts.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashMap;
+use nautilus_model::{
+    accounts::Account,
+    identifiers::{AccountId, ClientId, InstrumentId, StrategyId, Venue},
+    instruments::Instrument,
+    types::{Currency, Money, Price, Quantity},
+};
+
+use crate::{cache::Cache, messages::execution::TradingCommand};
+
+#[derive(Clone, Copy, Debug)]
+struct AccountRoute {
+    venue: Venue,
+    client_id: Option<ClientId>,
+}
+
+/// A point-in-time summary of a single account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountSummary {
+    pub account_id: AccountId,
+    pub venue: Venue,
+    pub balances_total: AHashMap<Currency, Money>,
+    pub balances_free: AHashMap<Currency, Money>,
+    pub balances_locked: AHashMap<Currency, Money>,
+    pub open_orders: usize,
+    pub open_positions: usize,
+}
+
+/// Routes commands to, and reports on, multiple accounts held by a single trader.
+pub struct AccountRouter {
+    cache: Rc<RefCell<Cache>>,
+    routes: AHashMap<AccountId, AccountRoute>,
+    venue_defaults: AHashMap<Venue, AccountId>,
+    strategy_accounts: AHashMap<(StrategyId, Venue), AccountId>,
+}
+
+impl Debug for AccountRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(AccountRouter))
+            .field("routes", &self.routes)
+            .field("venue_defaults", &self.venue_defaults)
+            .field("strategy_accounts", &self.strategy_accounts)
+            .finish()
+    }
+}
+
+impl AccountRouter {
+    /// Creates a new [`AccountRouter`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            cache,
+            routes: AHashMap::new(),
+            venue_defaults: AHashMap::new(),
+            strategy_accounts: AHashMap::new(),
+        }
+    }
+
+    /// Registers `account_id` at `venue`, with commands routed to `client_id` (if specified).
+    ///
+    /// The first account registered for a venue becomes its default.
+    pub fn register(&mut self, account_id: AccountId, venue: Venue, client_id: Option<ClientId>) {
+        self.routes
+            .insert(account_id, AccountRoute { venue, client_id });
+        self.venue_defaults.entry(venue).or_insert(account_id);
+    }
+
+    /// Sets the default account for the account's venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `account_id` is not registered.
+    pub fn set_default(&mut self, account_id: AccountId) -> anyhow::Result<()> {
+        let route = self.route_for(&account_id)?;
+        self.venue_defaults.insert(route.venue, account_id);
+        Ok(())
+    }
+
+    /// Assigns `strategy_id` to trade through `account_id` at the account's venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `account_id` is not registered.
+    pub fn assign(&mut self, strategy_id: StrategyId, account_id: AccountId) -> anyhow::Result<()> {
+        let route = self.route_for(&account_id)?;
+        self.strategy_accounts
+            .insert((strategy_id, route.venue), account_id);
+        Ok(())
+    }
+
+    /// Returns all registered account IDs, sorted.
+    #[must_use]
+    pub fn account_ids(&self) -> Vec<AccountId> {
+        let mut account_ids: Vec<AccountId> = self.routes.keys().copied().collect();
+        account_ids.sort();
+        account_ids
+    }
+
+    /// Returns the account for `strategy_id` (if specified) at `venue`, falling back to the venue
+    /// default.
+    #[must_use]
+    pub fn resolve(&self, strategy_id: Option<StrategyId>, venue: Venue) -> Option<AccountId> {
+        strategy_id
+            .and_then(|strategy_id| self.strategy_accounts.get(&(strategy_id, venue)))
+            .or_else(|| self.venue_defaults.get(&venue))
+            .copied()
+    }
+
+    /// Routes `command` to the execution client of its resolved account, returning the account.
+    ///
+    /// The command's client ID is set from the account route when one is configured.
+    pub fn route(&self, command: &mut TradingCommand) -> Option<AccountId> {
+        let account_id = match command {
+            TradingCommand::QueryAccount(command) => command.account_id,
+            _ => self.resolve(command.strategy_id(), command.instrument_id().venue)?,
+        };
+
+        if let Some(client_id) = self.routes.get(&account_id).and_then(|r| r.client_id) {
+            match command {
+                TradingCommand::SubmitOrder(command) => command.client_id = Some(client_id),
+                TradingCommand::SubmitOrderList(command) => command.client_id = Some(client_id),
+                TradingCommand::SubmitSpreadOrder(command) => command.client_id = Some(client_id),
+                TradingCommand::ModifyOrder(command) => command.client_id = Some(client_id),
+                TradingCommand::CancelOrder(command) => command.client_id = Some(client_id),
+                TradingCommand::CancelAllOrders(command) => command.client_id = Some(client_id),
+                TradingCommand::BatchCancelOrders(command) => command.client_id = Some(client_id),
+                TradingCommand::QueryOrder(command) => command.client_id = Some(client_id),
+                TradingCommand::QueryAccount(command) => command.client_id = Some(client_id),
+            }
+        }
+
+        Some(account_id)
+    }
+
+    /// Checks the account has sufficient free balance for an order's notional value.
+    ///
+    /// Margin accounts require the initial margin of the notional, cash accounts the full
+    /// notional.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account or instrument is not found, or the free balance is
+    /// insufficient.
+    pub fn check_balance(
+        &self,
+        account_id: &AccountId,
+        instrument_id: &InstrumentId,
+        quantity: Quantity,
+        price: Price,
+    ) -> anyhow::Result<()> {
+        let cache = self.cache.borrow();
+        let Some(account) = cache.account(account_id) else {
+            anyhow::bail!("Account {account_id} not found");
+        };
+        let Some(instrument) = cache.instrument(instrument_id) else {
+            anyhow::bail!("Instrument {instrument_id} not found");
+        };
+
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let required = if account.is_margin_account() {
+            let margin_init: f64 = instrument.margin_init().try_into().unwrap_or(1.0);
+            notional.as_f64() * margin_init
+        } else {
+            notional.as_f64()
+        };
+        let free = account
+            .balance_free(Some(notional.currency))
+            .map_or(0.0, |money| money.as_f64());
+
+        if required > free {
+            anyhow::bail!(
+                "Insufficient free balance on {account_id}: required {required} {}, free {free}",
+                notional.currency
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns a summary of `account_id`, if registered and present in the cache.
+    #[must_use]
+    pub fn summary(&self, account_id: &AccountId) -> Option<AccountSummary> {
+        let route = self.routes.get(account_id)?;
+        let cache = self.cache.borrow();
+        let account = cache.account(account_id)?;
+
+        Some(AccountSummary {
+            account_id: *account_id,
+            venue: route.venue,
+            balances_total: account.balances_total(),
+            balances_free: account.balances_free(),
+            balances_locked: account.balances_locked(),
+            open_orders: cache.orders_open_count(None, None, None, Some(account_id), None),
+            open_positions: cache.positions_open_count(None, None, None, Some(account_id), None),
+        })
+    }
+
+    /// Returns summaries of all registered accounts present in the cache, sorted by account ID.
+    #[must_use]
+    pub fn summaries(&self) -> Vec<AccountSummary> {
+        self.account_ids()
+            .iter()
+            .filter_map(|account_id| self.summary(account_id))
+            .collect()
+    }
+
+    /// Returns the total balances per currency consolidated across all registered accounts.
+    #[must_use]
+    pub fn consolidated_balances(&self) -> AHashMap<Currency, Money> {
+        let mut balances: AHashMap<Currency, Money> = AHashMap::new();
+        for summary in self.summaries() {
+            for (currency, money) in summary.balances_total {
+                balances
+                    .entry(currency)
+                    .and_modify(|total| *total = *total + money)
+                    .or_insert(money);
+            }
+        }
+        balances
+    }
+
+    fn route_for(&self, account_id: &AccountId) -> anyhow::Result<AccountRoute> {
+        match self.routes.get(account_id) {
+            Some(route) => Ok(*route),
+            None => anyhow::bail!("Account {account_id} not registered"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        accounts::{AccountAny, CashAccount},
+        enums::AccountType,
+        events::AccountState,
+        instruments::{CurrencyPair, InstrumentAny, stubs::audusd_sim},
+        types::AccountBalance,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cash_account(account_id: &str, free: &str) -> AccountAny {
+        let state = AccountState::new(
+            AccountId::from(account_id),
+            AccountType::Cash,
+            vec![AccountBalance::new(
+                Money::from(free),
+                Money::from("0 USD"),
+                Money::from(free),
+            )],
+            vec![],
+            true,
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            Some(Currency::USD()),
+        );
+        AccountAny::Cash(CashAccount::new(state, true, false))
+    }
+
+    fn router(audusd_sim: CurrencyPair) -> AccountRouter {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(cash_account("SIM-001", "1000 USD"))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(cash_account("SIM-002", "500000 USD"))
+            .unwrap();
+
+        let mut router = AccountRouter::new(cache);
+        router.register(AccountId::from("SIM-001"), Venue::from("SIM"), None);
+        router.register(
+            AccountId::from("SIM-002"),
+            Venue::from("SIM"),
+            Some(ClientId::from("SIM-PRIME")),
+        );
+        router
+    }
+
+    #[rstest]
+    fn test_resolve_uses_strategy_assignment_then_default(audusd_sim: CurrencyPair) {
+        let mut router = router(audusd_sim);
+        router
+            .assign(StrategyId::from("S-002"), AccountId::from("SIM-002"))
+            .unwrap();
+
+        assert_eq!(
+            router.resolve(Some(StrategyId::from("S-001")), Venue::from("SIM")),
+            Some(AccountId::from("SIM-001"))
+        );
+        assert_eq!(
+            router.resolve(Some(StrategyId::from("S-002")), Venue::from("SIM")),
+            Some(AccountId::from("SIM-002"))
+        );
+        assert_eq!(router.resolve(None, Venue::from("OTHER")), None);
+    }
+
+    #[rstest]
+    fn test_assign_unregistered_account_errors(audusd_sim: CurrencyPair) {
+        let mut router = router(audusd_sim);
+
+        assert!(
+            router
+                .assign(StrategyId::from("S-001"), AccountId::from("SIM-999"))
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_check_balance_per_account(audusd_sim: CurrencyPair) {
+        let router = router(audusd_sim);
+        let instrument_id = audusd_sim.id;
+
+        let small = router.check_balance(
+            &AccountId::from("SIM-001"),
+            &instrument_id,
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+        );
+        let large = router.check_balance(
+            &AccountId::from("SIM-002"),
+            &instrument_id,
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+        );
+
+        assert!(small.is_err());
+        assert!(large.is_ok());
+    }
+
+    #[rstest]
+    fn test_consolidated_balances(audusd_sim: CurrencyPair) {
+        let router = router(audusd_sim);
+
+        let balances = router.consolidated_balances();
+
+        assert_eq!(router.summaries().len(), 2);
+        assert_eq!(balances[&Currency::USD()], Money::from("501000 USD"));
+    }
+}
@@ -0,0 +1,337 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock-driven accrual scheduling for funding and borrow costs.
+//!
+//! The [`AccrualScheduler`] sets a clock alert for the next accrual time of each instrument
+//! schedule, re-arming it after every fire. At each accrual it calls the handlers registered for
+//! the schedule kind (the funding accrual and borrow cost engines) and publishes an
+//! [`AccrualEvent`] on the accrual topic.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    identifiers::InstrumentId,
+    instruments::schedule::{AccrualKind, AccrualSchedule, InstrumentDefinition},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    clock::Clock,
+    msgbus::{self, MStr, Topic},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Returns the accrual topic for the given `kind`.
+#[must_use]
+pub fn get_accrual_topic(kind: AccrualKind) -> MStr<Topic> {
+    match kind {
+        AccrualKind::Funding => "events.accrual.funding".into(),
+        AccrualKind::Borrow => "events.accrual.borrow".into(),
+    }
+}
+
+/// A scheduled accrual for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccrualEvent {
+    pub instrument_id: InstrumentId,
+    pub kind: AccrualKind,
+    /// UNIX timestamp (nanoseconds) of the scheduled accrual.
+    pub ts_accrual: UnixNanos,
+}
+
+/// A handler called at each accrual of its kind.
+pub type AccrualHandler = Rc<dyn Fn(&AccrualEvent)>;
+
+#[derive(Default)]
+struct SchedulerState {
+    schedules: IndexMap<(InstrumentId, AccrualKind), AccrualSchedule>,
+    handlers: IndexMap<AccrualKind, Vec<AccrualHandler>>,
+    running: bool,
+}
+
+/// Drives funding and borrow accruals from instrument schedules through clock alerts.
+#[derive(Clone)]
+pub struct AccrualScheduler {
+    clock: Rc<RefCell<dyn Clock>>,
+    state: Rc<RefCell<SchedulerState>>,
+}
+
+impl Debug for AccrualScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct(stringify!(AccrualScheduler))
+            .field("schedules", &state.schedules.keys().collect::<Vec<_>>())
+            .field("running", &state.running)
+            .finish()
+    }
+}
+
+impl AccrualScheduler {
+    /// Creates a new [`AccrualScheduler`] instance.
+    #[must_use]
+    pub fn new(clock: Rc<RefCell<dyn Clock>>) -> Self {
+        Self {
+            clock,
+            state: Rc::new(RefCell::new(SchedulerState::default())),
+        }
+    }
+
+    /// Registers a `handler` called at each accrual of `kind`.
+    pub fn register_handler(&self, kind: AccrualKind, handler: AccrualHandler) {
+        self.state
+            .borrow_mut()
+            .handlers
+            .entry(kind)
+            .or_default()
+            .push(handler);
+    }
+
+    /// Adds the schedules attached to an instrument definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheduler is running and an alert cannot be set.
+    pub fn add_definition(&self, definition: &InstrumentDefinition) -> anyhow::Result<()> {
+        for schedule in &definition.schedules {
+            self.add_schedule(definition.id(), schedule.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Adds (or replaces) the `schedule` for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheduler is running and the alert cannot be set.
+    pub fn add_schedule(
+        &self,
+        instrument_id: InstrumentId,
+        schedule: AccrualSchedule,
+    ) -> anyhow::Result<()> {
+        let key = (instrument_id, schedule.kind);
+        let running = {
+            let mut state = self.state.borrow_mut();
+            state.schedules.insert(key, schedule);
+            state.running
+        };
+        if running {
+            self.arm(key)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the schedule of `kind` for `instrument_id`, cancelling its alert.
+    pub fn remove_schedule(&self, instrument_id: InstrumentId, kind: AccrualKind) {
+        if self
+            .state
+            .borrow_mut()
+            .schedules
+            .shift_remove(&(instrument_id, kind))
+            .is_some()
+        {
+            self.clock
+                .borrow_mut()
+                .cancel_timer(&Self::alert_name(instrument_id, kind));
+        }
+    }
+
+    /// Returns the next accrual time for each schedule.
+    #[must_use]
+    pub fn next_accruals(&self) -> Vec<AccrualEvent> {
+        let now = self.clock.borrow().timestamp_ns();
+        self.state
+            .borrow()
+            .schedules
+            .iter()
+            .map(|((instrument_id, kind), schedule)| AccrualEvent {
+                instrument_id: *instrument_id,
+                kind: *kind,
+                ts_accrual: schedule.next_after(now),
+            })
+            .collect()
+    }
+
+    /// Starts setting alerts for all schedules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an alert cannot be set.
+    pub fn start(&self) -> anyhow::Result<()> {
+        let keys: Vec<_> = {
+            let mut state = self.state.borrow_mut();
+            state.running = true;
+            state.schedules.keys().copied().collect()
+        };
+        for key in keys {
+            self.arm(key)?;
+        }
+        Ok(())
+    }
+
+    /// Stops the scheduler, cancelling all alerts.
+    pub fn stop(&self) {
+        let keys: Vec<_> = {
+            let mut state = self.state.borrow_mut();
+            state.running = false;
+            state.schedules.keys().copied().collect()
+        };
+        let mut clock = self.clock.borrow_mut();
+        for (instrument_id, kind) in keys {
+            clock.cancel_timer(&Self::alert_name(instrument_id, kind));
+        }
+    }
+
+    fn alert_name(instrument_id: InstrumentId, kind: AccrualKind) -> String {
+        format!("Accrual-{kind:?}-{instrument_id}")
+    }
+
+    fn arm(&self, key: (InstrumentId, AccrualKind)) -> anyhow::Result<()> {
+        let Some(schedule) = self.state.borrow().schedules.get(&key).cloned() else {
+            return Ok(());
+        };
+        let (instrument_id, kind) = key;
+
+        let scheduler = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            scheduler.on_alert(instrument_id, kind, event.ts_event);
+        });
+
+        let mut clock = self.clock.borrow_mut();
+        let next = schedule.next_after(clock.timestamp_ns());
+        clock.set_time_alert_ns(
+            &Self::alert_name(instrument_id, kind),
+            next,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+        )
+    }
+
+    fn on_alert(&self, instrument_id: InstrumentId, kind: AccrualKind, ts: UnixNanos) {
+        let handlers = {
+            let state = self.state.borrow();
+            if !state.running || !state.schedules.contains_key(&(instrument_id, kind)) {
+                return;
+            }
+            state.handlers.get(&kind).cloned().unwrap_or_default()
+        };
+
+        let event = AccrualEvent {
+            instrument_id,
+            kind,
+            ts_accrual: ts,
+        };
+        for handler in handlers {
+            handler(&event);
+        }
+        msgbus::publish_any(get_accrual_topic(kind), &event);
+
+        if let Err(e) = self.arm((instrument_id, kind)) {
+            log::error!("Failed to re-arm {kind:?} accrual for {instrument_id}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
+    use nautilus_model::instruments::{Instrument, InstrumentAny, stubs::audusd_sim};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+
+    fn advance(clock: &Rc<RefCell<TestClock>>, to: u64) {
+        let events = clock.borrow_mut().advance_time(to.into(), true);
+        let handlers = clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+    }
+
+    #[rstest]
+    fn test_accruals_fire_on_schedule_and_rearm() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let scheduler = AccrualScheduler::new(clock.clone());
+        let definition = InstrumentDefinition::new(
+            InstrumentAny::CurrencyPair(audusd_sim()),
+            vec![
+                AccrualSchedule::funding_8h(),
+                AccrualSchedule::borrow_daily(),
+            ],
+        );
+        scheduler.add_definition(&definition).unwrap();
+
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        for kind in [AccrualKind::Funding, AccrualKind::Borrow] {
+            let fired = fired.clone();
+            scheduler.register_handler(
+                kind,
+                Rc::new(move |event: &AccrualEvent| fired.borrow_mut().push(*event)),
+            );
+        }
+        scheduler.start().unwrap();
+
+        // Advance one step at a time as each alert re-arms the next
+        for hour in [8, 16, 24] {
+            advance(&clock, hour * HOUR);
+        }
+
+        let fired = fired.borrow();
+        let funding: Vec<_> = fired
+            .iter()
+            .filter(|e| e.kind == AccrualKind::Funding)
+            .map(|e| e.ts_accrual)
+            .collect();
+        assert_eq!(
+            funding,
+            vec![
+                UnixNanos::from(8 * HOUR),
+                UnixNanos::from(16 * HOUR),
+                UnixNanos::from(24 * HOUR)
+            ]
+        );
+        let borrow: Vec<_> = fired
+            .iter()
+            .filter(|e| e.kind == AccrualKind::Borrow)
+            .collect();
+        assert_eq!(borrow.len(), 1);
+        assert_eq!(borrow[0].instrument_id, definition.instrument.id());
+    }
+
+    #[rstest]
+    fn test_stop_cancels_alerts() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let scheduler = AccrualScheduler::new(clock.clone());
+        let instrument_id = audusd_sim().id;
+        scheduler
+            .add_schedule(instrument_id, AccrualSchedule::borrow_daily())
+            .unwrap();
+        scheduler.start().unwrap();
+        assert_eq!(clock.borrow().timer_count(), 1);
+
+        scheduler.stop();
+        assert_eq!(clock.borrow().timer_count(), 0);
+        assert_eq!(
+            scheduler.next_accruals()[0].ts_accrual,
+            UnixNanos::from(24 * HOUR)
+        );
+    }
+}
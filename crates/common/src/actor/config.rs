@@ -0,0 +1,604 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Schema validated actor configuration.
+//!
+//! [`ImportableActorConfig`] carries raw JSON maps. A [`ConfigSchema`] describes the expected
+//! fields so configs are checked before instantiation, with every problem reported at once, and
+//! can be exported as JSON Schema for UI-driven configuration. Config types opt in through
+//! [`TypedActorConfig`].
+
+use std::{collections::HashMap, fmt::Display};
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value, json};
+
+use super::data_actor::ImportableActorConfig;
+
+/// The JSON Schema dialect of exported schemas.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The expected type of a config field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldKind {
+    /// A string.
+    String,
+    /// A whole number within optional bounds.
+    Integer { min: Option<i64>, max: Option<i64> },
+    /// A number within optional bounds.
+    Number { min: Option<f64>, max: Option<f64> },
+    /// A boolean.
+    Boolean,
+    /// One of a fixed set of strings.
+    Enum(Vec<String>),
+    /// An array whose items are all of the given kind.
+    Array(Box<FieldKind>),
+    /// An arbitrary JSON object.
+    Object,
+}
+
+impl FieldKind {
+    fn to_json_schema(&self) -> Map<String, Value> {
+        let mut schema = Map::new();
+        match self {
+            Self::String => {
+                schema.insert("type".into(), json!("string"));
+            }
+            Self::Integer { min, max } => {
+                schema.insert("type".into(), json!("integer"));
+                if let Some(min) = min {
+                    schema.insert("minimum".into(), json!(min));
+                }
+                if let Some(max) = max {
+                    schema.insert("maximum".into(), json!(max));
+                }
+            }
+            Self::Number { min, max } => {
+                schema.insert("type".into(), json!("number"));
+                if let Some(min) = min {
+                    schema.insert("minimum".into(), json!(min));
+                }
+                if let Some(max) = max {
+                    schema.insert("maximum".into(), json!(max));
+                }
+            }
+            Self::Boolean => {
+                schema.insert("type".into(), json!("boolean"));
+            }
+            Self::Enum(values) => {
+                schema.insert("type".into(), json!("string"));
+                schema.insert("enum".into(), json!(values));
+            }
+            Self::Array(items) => {
+                schema.insert("type".into(), json!("array"));
+                schema.insert("items".into(), Value::Object(items.to_json_schema()));
+            }
+            Self::Object => {
+                schema.insert("type".into(), json!("object"));
+            }
+        }
+        schema
+    }
+
+    fn check(&self, path: &str, value: &Value, issues: &mut Vec<ConfigIssue>) {
+        if let (Self::Array(items), Some(array)) = (self, value.as_array()) {
+            for (i, item) in array.iter().enumerate() {
+                items.check(&format!("{path}[{i}]"), item, issues);
+            }
+            return;
+        }
+
+        let problem = match self {
+            Self::String => (!value.is_string()).then(|| expected("a string", value)),
+            Self::Integer { min, max } => match value.as_i64() {
+                Some(n) => out_of_range(n, *min, *max),
+                None => Some(expected("an integer", value)),
+            },
+            Self::Number { min, max } => match value.as_f64() {
+                Some(n) => out_of_range(n, *min, *max),
+                None => Some(expected("a number", value)),
+            },
+            Self::Boolean => (!value.is_boolean()).then(|| expected("a boolean", value)),
+            Self::Enum(values) => match value.as_str() {
+                Some(s) if values.iter().any(|v| v == s) => None,
+                Some(s) => Some(format!("'{s}' is not one of {}", values.join(", "))),
+                None => Some(expected("a string", value)),
+            },
+            Self::Array(_) => Some(expected("an array", value)),
+            Self::Object => (!value.is_object()).then(|| expected("an object", value)),
+        };
+
+        if let Some(message) = problem {
+            issues.push(ConfigIssue::new(path, message));
+        }
+    }
+}
+
+/// Describes a single config field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigField {
+    /// The field name.
+    pub name: String,
+    /// The expected type.
+    pub kind: FieldKind,
+    /// Whether the field must be present (fields with a default never need to be).
+    pub required: bool,
+    /// The value used when the field is absent.
+    pub default: Option<Value>,
+    /// The human readable description.
+    pub description: Option<String>,
+}
+
+impl ConfigField {
+    /// Creates a new optional [`ConfigField`] instance.
+    #[must_use]
+    pub fn new(name: &str, kind: FieldKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            required: false,
+            default: None,
+            description: None,
+        }
+    }
+
+    /// Marks the field as required.
+    #[must_use]
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Sets the value used when the field is absent.
+    #[must_use]
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the field description.
+    #[must_use]
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+}
+
+/// A single problem found while validating a config.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// The path of the offending field (e.g. `instrument_ids[1]`).
+    pub path: String,
+    /// What is wrong with the field.
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(path: &str, message: String) -> Self {
+        Self {
+            path: path.to_string(),
+            message,
+        }
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The error returned when a config fails schema validation, listing every issue found.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid config for {title} ({} issue(s)): {}", .issues.len(), format_issues(.issues))]
+pub struct ConfigValidationError {
+    /// The schema title.
+    pub title: String,
+    /// The issues found.
+    pub issues: Vec<ConfigIssue>,
+}
+
+fn format_issues(issues: &[ConfigIssue]) -> String {
+    issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Describes the fields of an actor config.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigSchema {
+    /// The schema title (usually the config type name).
+    pub title: String,
+    /// The human readable description.
+    pub description: Option<String>,
+    /// The known fields, in display order.
+    pub fields: Vec<ConfigField>,
+    /// Whether fields not described by the schema are accepted.
+    pub allow_unknown: bool,
+}
+
+impl ConfigSchema {
+    /// Creates a new empty [`ConfigSchema`] instance rejecting unknown fields.
+    #[must_use]
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            description: None,
+            fields: Vec::new(),
+            allow_unknown: false,
+        }
+    }
+
+    /// Adds a field.
+    #[must_use]
+    pub fn field(mut self, field: ConfigField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Sets the schema description.
+    #[must_use]
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Accepts fields not described by the schema.
+    #[must_use]
+    pub const fn allowing_unknown(mut self) -> Self {
+        self.allow_unknown = true;
+        self
+    }
+
+    /// Validates `config`, collecting every issue rather than stopping at the first.
+    ///
+    /// A `null` value is treated as absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all missing, mistyped, out of range and unknown fields.
+    pub fn validate(&self, config: &HashMap<String, Value>) -> Result<(), ConfigValidationError> {
+        let mut issues = Vec::new();
+
+        for field in &self.fields {
+            match config.get(&field.name).filter(|value| !value.is_null()) {
+                Some(value) => field.kind.check(&field.name, value, &mut issues),
+                None if field.required && field.default.is_none() => {
+                    issues.push(ConfigIssue::new(&field.name, "is required".to_string()));
+                }
+                None => {}
+            }
+        }
+
+        if !self.allow_unknown {
+            let mut unknown: Vec<&String> = config
+                .keys()
+                .filter(|key| !self.fields.iter().any(|f| &f.name == *key))
+                .collect();
+            unknown.sort();
+            for key in unknown {
+                let message = match self.closest_field(key) {
+                    Some(name) => format!("unknown field, did you mean '{name}'?"),
+                    None => "unknown field".to_string(),
+                };
+                issues.push(ConfigIssue::new(key, message));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigValidationError {
+                title: self.title.clone(),
+                issues,
+            })
+        }
+    }
+
+    /// Returns `config` with defaults filled in for absent fields.
+    #[must_use]
+    pub fn apply_defaults(&self, config: &HashMap<String, Value>) -> Map<String, Value> {
+        let mut resolved: Map<String, Value> = config
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        for field in &self.fields {
+            if let Some(default) = &field.default
+                && resolved.get(&field.name).is_none_or(Value::is_null)
+            {
+                resolved.insert(field.name.clone(), default.clone());
+            }
+        }
+        resolved
+    }
+
+    /// Exports the schema as a JSON Schema document.
+    #[must_use]
+    pub fn to_json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        for field in &self.fields {
+            let mut property = field.kind.to_json_schema();
+            if let Some(description) = &field.description {
+                property.insert("description".into(), json!(description));
+            }
+            if let Some(default) = &field.default {
+                property.insert("default".into(), default.clone());
+            }
+            properties.insert(field.name.clone(), Value::Object(property));
+        }
+
+        let required: Vec<&str> = self
+            .fields
+            .iter()
+            .filter(|f| f.required && f.default.is_none())
+            .map(|f| f.name.as_str())
+            .collect();
+
+        let mut schema = Map::new();
+        schema.insert("$schema".into(), json!(JSON_SCHEMA_DIALECT));
+        schema.insert("title".into(), json!(self.title));
+        if let Some(description) = &self.description {
+            schema.insert("description".into(), json!(description));
+        }
+        schema.insert("type".into(), json!("object"));
+        schema.insert("properties".into(), Value::Object(properties));
+        schema.insert("required".into(), json!(required));
+        schema.insert("additionalProperties".into(), json!(self.allow_unknown));
+        Value::Object(schema)
+    }
+
+    fn closest_field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .map(|f| (edit_distance(key, &f.name), f.name.as_str()))
+            .filter(|(distance, name)| *distance <= name.len().max(key.len()) / 3 + 1)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, name)| name)
+    }
+}
+
+/// A config type with a schema, validated before deserialization.
+pub trait TypedActorConfig: DeserializeOwned {
+    /// Returns the schema describing the config fields.
+    fn schema() -> ConfigSchema;
+
+    /// Validates `config` against [`Self::schema`] then deserializes it with defaults applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation or deserialization fails.
+    fn from_config_map(config: &HashMap<String, Value>) -> anyhow::Result<Self> {
+        let schema = Self::schema();
+        schema.validate(config)?;
+        let resolved = schema.apply_defaults(config);
+        serde_json::from_value(Value::Object(resolved))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize {} config: {e}", schema.title))
+    }
+}
+
+impl ImportableActorConfig {
+    /// Validates the raw config map against `schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing every issue found.
+    pub fn validate(&self, schema: &ConfigSchema) -> Result<(), ConfigValidationError> {
+        schema.validate(&self.config)
+    }
+
+    /// Validates and deserializes the raw config map as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation or deserialization fails.
+    pub fn parse<T: TypedActorConfig>(&self) -> anyhow::Result<T> {
+        T::from_config_map(&self.config)
+    }
+}
+
+fn expected(kind: &str, value: &Value) -> String {
+    format!("expected {kind}, was {}", describe(value))
+}
+
+fn out_of_range<T: PartialOrd + Display>(n: T, min: Option<T>, max: Option<T>) -> Option<String> {
+    match (min, max) {
+        (Some(min), _) if n < min => Some(format!("must be >= {min}, was {n}")),
+        (_, Some(max)) if n > max => Some(format!("must be <= {max}, was {n}")),
+        _ => None,
+    }
+}
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct MomentumConfig {
+        instrument_id: String,
+        lookback: u32,
+        threshold: f64,
+        side: String,
+        venues: Vec<String>,
+    }
+
+    impl TypedActorConfig for MomentumConfig {
+        fn schema() -> ConfigSchema {
+            ConfigSchema::new("MomentumConfig")
+                .with_description("Momentum strategy settings")
+                .field(
+                    ConfigField::new("instrument_id", FieldKind::String)
+                        .required()
+                        .with_description("The instrument to trade"),
+                )
+                .field(
+                    ConfigField::new(
+                        "lookback",
+                        FieldKind::Integer {
+                            min: Some(1),
+                            max: Some(1_000),
+                        },
+                    )
+                    .with_default(json!(20)),
+                )
+                .field(
+                    ConfigField::new(
+                        "threshold",
+                        FieldKind::Number {
+                            min: Some(0.0),
+                            max: None,
+                        },
+                    )
+                    .required(),
+                )
+                .field(
+                    ConfigField::new("side", FieldKind::Enum(vec!["BUY".into(), "SELL".into()]))
+                        .with_default(json!("BUY")),
+                )
+                .field(
+                    ConfigField::new("venues", FieldKind::Array(Box::new(FieldKind::String)))
+                        .with_default(json!([])),
+                )
+        }
+    }
+
+    fn config(value: Value) -> HashMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_applies_defaults() {
+        let parsed = MomentumConfig::from_config_map(&config(json!({
+            "instrument_id": "ETHUSDT.BINANCE",
+            "threshold": 0.5,
+        })))
+        .unwrap();
+
+        assert_eq!(
+            parsed,
+            MomentumConfig {
+                instrument_id: "ETHUSDT.BINANCE".to_string(),
+                lookback: 20,
+                threshold: 0.5,
+                side: "BUY".to_string(),
+                venues: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_validate_collects_all_issues() {
+        let err = MomentumConfig::schema()
+            .validate(&config(json!({
+                "lookback": 0,
+                "threshold": "high",
+                "side": "HOLD",
+                "venues": ["BINANCE", 5],
+                "treshold": 1.0,
+            })))
+            .unwrap_err();
+
+        let issues: Vec<String> = err.issues.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            issues,
+            vec![
+                "instrument_id: is required",
+                "lookback: must be >= 1, was 0",
+                "threshold: expected a number, was a string",
+                "side: 'HOLD' is not one of BUY, SELL",
+                "venues[1]: expected a string, was a number",
+                "treshold: unknown field, did you mean 'threshold'?",
+            ]
+        );
+        assert!(
+            err.to_string()
+                .starts_with("Invalid config for MomentumConfig (6 issue(s))")
+        );
+    }
+
+    #[rstest]
+    fn test_allowing_unknown_accepts_extra_fields() {
+        let schema = ConfigSchema::new("Loose")
+            .field(ConfigField::new("a", FieldKind::Boolean))
+            .allowing_unknown();
+
+        assert!(schema.validate(&config(json!({"a": true, "b": 1}))).is_ok());
+    }
+
+    #[rstest]
+    fn test_to_json_schema() {
+        let schema = MomentumConfig::schema().to_json_schema();
+
+        assert_eq!(schema["$schema"], json!(JSON_SCHEMA_DIALECT));
+        assert_eq!(schema["title"], json!("MomentumConfig"));
+        assert_eq!(schema["required"], json!(["instrument_id", "threshold"]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(
+            schema["properties"]["lookback"],
+            json!({"type": "integer", "minimum": 1, "maximum": 1000, "default": 20})
+        );
+        assert_eq!(
+            schema["properties"]["venues"],
+            json!({"type": "array", "items": {"type": "string"}, "default": []})
+        );
+        assert_eq!(
+            schema["properties"]["instrument_id"]["description"],
+            json!("The instrument to trade")
+        );
+    }
+
+    #[rstest]
+    fn test_importable_config_parse() {
+        let importable = ImportableActorConfig {
+            actor_path: "strategies:Momentum".to_string(),
+            config_path: "strategies:MomentumConfig".to_string(),
+            config: config(json!({"instrument_id": "ETHUSDT.BINANCE", "threshold": 1.5})),
+        };
+
+        assert!(importable.validate(&MomentumConfig::schema()).is_ok());
+        let parsed: MomentumConfig = importable.parse().unwrap();
+        assert_eq!(parsed.threshold, 1.5);
+    }
+}
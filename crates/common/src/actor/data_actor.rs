@@ -25,6 +25,7 @@ use std::{
 };
 
 use ahash::{AHashMap, AHashSet};
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use nautilus_core::{UUID4, UnixNanos, correctness::check_predicate_true};
@@ -34,13 +35,13 @@ use nautilus_model::defi::{
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
-        MarkPriceUpdate, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
-        close::InstrumentClose,
+        Bar, BarType, DataType, EconomicEvent, EconomicEventFilter, FundingRateUpdate,
+        IndexPriceUpdate, InstrumentStatus, MarkPriceUpdate, OrderBookDeltas, OrderBookDepth10,
+        QuoteTick, TradeTick, YieldCurveData, close::InstrumentClose,
     },
     enums::BookType,
     events::order::{any::OrderEventAny, canceled::OrderCanceled, filled::OrderFilled},
-    identifiers::{ActorId, ClientId, ComponentId, InstrumentId, TraderId, Venue},
+    identifiers::{ActorId, ClientId, ComponentId, InstrumentId, StrategyId, TraderId, Venue},
     instruments::InstrumentAny,
     orderbook::OrderBook,
 };
@@ -51,6 +52,7 @@ use super::indicators::Indicators;
 use super::{
     Actor,
     registry::{get_actor_unchecked, try_get_actor_unchecked},
+    subscriptions::DeclaredSubscription,
 };
 #[cfg(feature = "defi")]
 use crate::defi;
@@ -61,6 +63,7 @@ use crate::{
     cache::Cache,
     clock::Clock,
     component::Component,
+    crash::catch_panic,
     enums::{ComponentState, ComponentTrigger},
     logging::{CMD, RECV, REQ, SEND},
     messages::{
@@ -81,19 +84,24 @@ use crate::{
         system::ShutdownSystem,
     },
     msgbus::{
-        self, MStr, ShareableMessageHandler, Topic, TypedHandler, get_message_bus,
+        self, MStr, Pattern, ShareableMessageHandler, Topic, TypedHandler, get_message_bus,
         switchboard::{
             MessagingSwitchboard, get_bars_topic, get_book_deltas_topic, get_book_snapshots_topic,
-            get_custom_topic, get_funding_rate_topic, get_index_price_topic,
-            get_instrument_close_topic, get_instrument_status_topic, get_instrument_topic,
-            get_instruments_topic, get_mark_price_topic, get_order_cancels_topic,
-            get_order_fills_topic, get_quotes_topic, get_trades_topic,
+            get_custom_topic, get_economic_events_topic, get_funding_rate_topic,
+            get_index_price_topic, get_instrument_close_topic, get_instrument_status_topic,
+            get_instrument_topic, get_instruments_topic, get_mark_price_topic,
+            get_order_cancels_topic, get_order_fills_topic, get_quotes_topic, get_trades_topic,
+            get_yield_curve_topic,
         },
     },
+    reconnect::{ReconnectEvent, ReconnectStage},
     signal::Signal,
     timer::{TimeEvent, TimeEventCallback},
 };
 
+/// The pattern matching the reconnect topics of all data clients.
+const RECONNECT_PATTERN: &str = "events.reconnect.*";
+
 /// Common configuration for [`DataActor`] based components.
 #[derive(Debug, Clone)]
 #[cfg_attr(
@@ -107,6 +115,10 @@ pub struct DataActorConfig {
     pub log_events: bool,
     /// If commands should be logged.
     pub log_commands: bool,
+    /// If the actor state should be saved to the cache on stop and loaded from it on start.
+    pub manage_state: bool,
+    /// The data subscriptions made on start, restored after reconnects, and removed on stop.
+    pub subscriptions: Vec<DeclaredSubscription>,
 }
 
 impl Default for DataActorConfig {
@@ -115,6 +127,8 @@ impl Default for DataActorConfig {
             actor_id: None,
             log_events: true,
             log_commands: true,
+            manage_state: true,
+            subscriptions: Vec::new(),
         }
     }
 }
@@ -381,6 +395,26 @@ pub trait DataActor:
         Ok(())
     }
 
+    /// Actions to be performed when receiving yield curve data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the yield curve fails.
+    #[allow(unused_variables)]
+    fn on_yield_curve(&mut self, yield_curve: &YieldCurveData) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when receiving an economic calendar event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the economic event fails.
+    #[allow(unused_variables)]
+    fn on_economic_event(&mut self, event: &EconomicEvent) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Actions to be performed when receiving an order filled event.
     ///
     /// # Errors
@@ -391,6 +425,51 @@ pub trait DataActor:
         Ok(())
     }
 
+    /// Actions to be performed when replaying an order event
+    /// (see [`DataActor::replay_order_events`]).
+    ///
+    /// The default routes fills and cancels to [`DataActor::on_order_filled`] and
+    /// [`DataActor::on_order_canceled`], ignoring other event types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the order event fails.
+    fn on_order_event(&mut self, event: &OrderEventAny) -> anyhow::Result<()> {
+        match event {
+            OrderEventAny::Filled(filled) => self.on_order_filled(filled),
+            OrderEventAny::Canceled(canceled) => self.on_order_canceled(canceled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Replays the order events of `strategy_id` with `ts_event` at or after `from_ts` through
+    /// [`DataActor::on_order_event`], in event order.
+    ///
+    /// Events are read from the orders held in the cache, which are loaded from the cache
+    /// database on startup, so a restarted strategy can rebuild derived state that was not
+    /// snapshotted. Returns the number of events replayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling any event fails, stopping the replay at that event.
+    fn replay_order_events(
+        &mut self,
+        strategy_id: &StrategyId,
+        from_ts: UnixNanos,
+    ) -> anyhow::Result<usize> {
+        // Collect first so handlers are free to borrow the cache
+        let events = self.cache().order_events(strategy_id, from_ts);
+        log::info!(
+            "Replaying {} order event(s) for {strategy_id} from {from_ts}",
+            events.len()
+        );
+
+        for event in &events {
+            self.on_order_event(event)?;
+        }
+        Ok(events.len())
+    }
+
     /// Actions to be performed when receiving an order canceled event.
     ///
     /// # Errors
@@ -734,6 +813,40 @@ pub trait DataActor:
         }
     }
 
+    /// Handles received yield curve data, storing it in the cache as the latest curve.
+    fn handle_yield_curve(&mut self, yield_curve: &YieldCurveData) {
+        log_received(&yield_curve);
+
+        if let Some(cache) = self.cache.clone()
+            && let Err(e) = cache.borrow_mut().add_yield_curve(yield_curve.clone())
+        {
+            log_error(&e);
+        }
+
+        if self.not_running() {
+            log_not_running(&yield_curve);
+            return;
+        }
+
+        if let Err(e) = self.on_yield_curve(yield_curve) {
+            log_error(&e);
+        }
+    }
+
+    /// Handles a received economic calendar event.
+    fn handle_economic_event(&mut self, event: &EconomicEvent) {
+        log_received(&event);
+
+        if self.not_running() {
+            log_not_running(&event);
+            return;
+        }
+
+        if let Err(e) = self.on_economic_event(event) {
+            log_error(&e);
+        }
+    }
+
     /// Handles a received order filled event.
     fn handle_order_filled(&mut self, event: &OrderFilled) {
         log_received(&event);
@@ -960,7 +1073,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_any(move |data: &dyn Any| {
-            get_actor_unchecked::<Self>(&actor_id).handle_data(data);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_data(data)
+            });
         });
 
         DataActorCore::subscribe_data(self, handler, data_type, client_id, params);
@@ -979,11 +1094,13 @@ pub trait DataActor:
         let topic = get_quotes_topic(instrument_id);
 
         let handler = TypedHandler::from(move |quote: &QuoteTick| {
-            if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
-                actor.handle_quote(quote);
-            } else {
-                log::error!("Actor {actor_id} not found for quote handling");
-            }
+            catch_actor_panic(actor_id, || {
+                if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    actor.handle_quote(quote);
+                } else {
+                    log::error!("Actor {actor_id} not found for quote handling");
+                }
+            });
         });
 
         DataActorCore::subscribe_quotes(self, topic, handler, instrument_id, client_id, params);
@@ -1002,11 +1119,13 @@ pub trait DataActor:
         let topic = get_instruments_topic(venue);
 
         let handler = ShareableMessageHandler::from_typed(move |instrument: &InstrumentAny| {
-            if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
-                actor.handle_instrument(instrument);
-            } else {
-                log::error!("Actor {actor_id} not found for instruments handling");
-            }
+            catch_actor_panic(actor_id, || {
+                if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    actor.handle_instrument(instrument);
+                } else {
+                    log::error!("Actor {actor_id} not found for instruments handling");
+                }
+            });
         });
 
         DataActorCore::subscribe_instruments(self, topic, handler, venue, client_id, params);
@@ -1025,11 +1144,13 @@ pub trait DataActor:
         let topic = get_instrument_topic(instrument_id);
 
         let handler = ShareableMessageHandler::from_typed(move |instrument: &InstrumentAny| {
-            if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
-                actor.handle_instrument(instrument);
-            } else {
-                log::error!("Actor {actor_id} not found for instrument handling");
-            }
+            catch_actor_panic(actor_id, || {
+                if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    actor.handle_instrument(instrument);
+                } else {
+                    log::error!("Actor {actor_id} not found for instrument handling");
+                }
+            });
         });
 
         DataActorCore::subscribe_instrument(self, topic, handler, instrument_id, client_id, params);
@@ -1051,7 +1172,9 @@ pub trait DataActor:
         let topic = get_book_deltas_topic(instrument_id);
 
         let handler = TypedHandler::from(move |deltas: &OrderBookDeltas| {
-            get_actor_unchecked::<Self>(&actor_id).handle_book_deltas(deltas);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_book_deltas(deltas)
+            });
         });
 
         DataActorCore::subscribe_book_deltas(
@@ -1083,7 +1206,9 @@ pub trait DataActor:
         let topic = get_book_snapshots_topic(instrument_id, interval_ms);
 
         let handler = TypedHandler::from(move |book: &OrderBook| {
-            get_actor_unchecked::<Self>(&actor_id).handle_book(book);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_book(book)
+            });
         });
 
         DataActorCore::subscribe_book_at_interval(
@@ -1112,7 +1237,9 @@ pub trait DataActor:
         let topic = get_trades_topic(instrument_id);
 
         let handler = TypedHandler::from(move |trade: &TradeTick| {
-            get_actor_unchecked::<Self>(&actor_id).handle_trade(trade);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_trade(trade)
+            });
         });
 
         DataActorCore::subscribe_trades(self, topic, handler, instrument_id, client_id, params);
@@ -1131,7 +1258,9 @@ pub trait DataActor:
         let topic = get_bars_topic(bar_type);
 
         let handler = TypedHandler::from(move |bar: &Bar| {
-            get_actor_unchecked::<Self>(&actor_id).handle_bar(bar);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_bar(bar)
+            });
         });
 
         DataActorCore::subscribe_bars(self, topic, handler, bar_type, client_id, params);
@@ -1150,7 +1279,9 @@ pub trait DataActor:
         let topic = get_mark_price_topic(instrument_id);
 
         let handler = TypedHandler::from(move |mark_price: &MarkPriceUpdate| {
-            get_actor_unchecked::<Self>(&actor_id).handle_mark_price(mark_price);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_mark_price(mark_price)
+            });
         });
 
         DataActorCore::subscribe_mark_prices(
@@ -1176,7 +1307,9 @@ pub trait DataActor:
         let topic = get_index_price_topic(instrument_id);
 
         let handler = TypedHandler::from(move |index_price: &IndexPriceUpdate| {
-            get_actor_unchecked::<Self>(&actor_id).handle_index_price(index_price);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_index_price(index_price)
+            });
         });
 
         DataActorCore::subscribe_index_prices(
@@ -1202,7 +1335,9 @@ pub trait DataActor:
         let topic = get_funding_rate_topic(instrument_id);
 
         let handler = TypedHandler::from(move |funding_rate: &FundingRateUpdate| {
-            get_actor_unchecked::<Self>(&actor_id).handle_funding_rate(funding_rate);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_funding_rate(funding_rate)
+            });
         });
 
         DataActorCore::subscribe_funding_rates(
@@ -1228,7 +1363,9 @@ pub trait DataActor:
         let topic = get_instrument_status_topic(instrument_id);
 
         let handler = ShareableMessageHandler::from_typed(move |status: &InstrumentStatus| {
-            get_actor_unchecked::<Self>(&actor_id).handle_instrument_status(status);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_instrument_status(status)
+            });
         });
 
         DataActorCore::subscribe_instrument_status(
@@ -1254,7 +1391,9 @@ pub trait DataActor:
         let topic = get_instrument_close_topic(instrument_id);
 
         let handler = ShareableMessageHandler::from_typed(move |close: &InstrumentClose| {
-            get_actor_unchecked::<Self>(&actor_id).handle_instrument_close(close);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_instrument_close(close)
+            });
         });
 
         DataActorCore::subscribe_instrument_close(
@@ -1277,7 +1416,9 @@ pub trait DataActor:
 
         let handler = TypedHandler::from(move |event: &OrderEventAny| {
             if let OrderEventAny::Filled(filled) = event {
-                get_actor_unchecked::<Self>(&actor_id).handle_order_filled(filled);
+                catch_actor_panic(actor_id, || {
+                    get_actor_unchecked::<Self>(&actor_id).handle_order_filled(filled)
+                });
             }
         });
 
@@ -1294,13 +1435,56 @@ pub trait DataActor:
 
         let handler = TypedHandler::from(move |event: &OrderEventAny| {
             if let OrderEventAny::Canceled(canceled) = event {
-                get_actor_unchecked::<Self>(&actor_id).handle_order_canceled(canceled);
+                catch_actor_panic(actor_id, || {
+                    get_actor_unchecked::<Self>(&actor_id).handle_order_canceled(canceled)
+                });
             }
         });
 
         DataActorCore::subscribe_order_cancels(self, topic, handler);
     }
 
+    /// Subscribe to [`YieldCurveData`] for the `curve_name` (e.g. a currency code).
+    ///
+    /// Received curves are stored in the cache as the latest curve for the name.
+    fn subscribe_yield_curve(&mut self, curve_name: &str)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        let actor_id = self.actor_id().inner();
+        let topic = get_yield_curve_topic(Ustr::from(curve_name));
+
+        let handler = TypedHandler::from(move |yield_curve: &YieldCurveData| {
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_yield_curve(yield_curve)
+            });
+        });
+
+        DataActorCore::subscribe_yield_curve(self, topic, handler);
+    }
+
+    /// Subscribe to [`EconomicEvent`]s passing the `filter`.
+    ///
+    /// Events are routed per country, so only the filtered countries (or all countries when the
+    /// filter has none) are received, and events below the minimum impact are dropped.
+    fn subscribe_economic_events(&mut self, filter: EconomicEventFilter)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        let actor_id = self.actor_id().inner();
+        let min_impact = filter.min_impact;
+
+        let handler = TypedHandler::from(move |event: &EconomicEvent| {
+            if event.impact >= min_impact {
+                catch_actor_panic(actor_id, || {
+                    get_actor_unchecked::<Self>(&actor_id).handle_economic_event(event)
+                });
+            }
+        });
+
+        DataActorCore::subscribe_economic_events(self, &filter, handler);
+    }
+
     #[cfg(feature = "defi")]
     /// Subscribe to streaming [`Block`] data for the `chain`.
     fn subscribe_blocks(
@@ -1315,7 +1499,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_blocks_topic(chain);
 
         let handler = TypedHandler::from(move |block: &Block| {
-            get_actor_unchecked::<Self>(&actor_id).handle_block(block);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_block(block)
+            });
         });
 
         DataActorCore::subscribe_blocks(self, topic, handler, chain, client_id, params);
@@ -1335,7 +1521,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_pool_topic(instrument_id);
 
         let handler = TypedHandler::from(move |pool: &Pool| {
-            get_actor_unchecked::<Self>(&actor_id).handle_pool(pool);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_pool(pool)
+            });
         });
 
         DataActorCore::subscribe_pool(self, topic, handler, instrument_id, client_id, params);
@@ -1355,7 +1543,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_pool_swaps_topic(instrument_id);
 
         let handler = TypedHandler::from(move |swap: &PoolSwap| {
-            get_actor_unchecked::<Self>(&actor_id).handle_pool_swap(swap);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_pool_swap(swap)
+            });
         });
 
         DataActorCore::subscribe_pool_swaps(self, topic, handler, instrument_id, client_id, params);
@@ -1375,7 +1565,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_liquidity_topic(instrument_id);
 
         let handler = TypedHandler::from(move |update: &PoolLiquidityUpdate| {
-            get_actor_unchecked::<Self>(&actor_id).handle_pool_liquidity_update(update);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_pool_liquidity_update(update)
+            });
         });
 
         DataActorCore::subscribe_pool_liquidity_updates(
@@ -1402,7 +1594,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_collect_topic(instrument_id);
 
         let handler = TypedHandler::from(move |collect: &PoolFeeCollect| {
-            get_actor_unchecked::<Self>(&actor_id).handle_pool_fee_collect(collect);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_pool_fee_collect(collect)
+            });
         });
 
         DataActorCore::subscribe_pool_fee_collects(
@@ -1429,7 +1623,9 @@ pub trait DataActor:
         let topic = defi::switchboard::get_defi_flash_topic(instrument_id);
 
         let handler = TypedHandler::from(move |flash: &PoolFlash| {
-            get_actor_unchecked::<Self>(&actor_id).handle_pool_flash(flash);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_pool_flash(flash)
+            });
         });
 
         DataActorCore::subscribe_pool_flash_events(
@@ -1621,6 +1817,115 @@ pub trait DataActor:
         DataActorCore::unsubscribe_order_cancels(self, instrument_id);
     }
 
+    /// Unsubscribe from [`YieldCurveData`] for the `curve_name`.
+    fn unsubscribe_yield_curve(&mut self, curve_name: &str)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        DataActorCore::unsubscribe_yield_curve(self, curve_name);
+    }
+
+    /// Unsubscribe from [`EconomicEvent`]s for the countries of the `filter`.
+    fn unsubscribe_economic_events(&mut self, filter: EconomicEventFilter)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        DataActorCore::unsubscribe_economic_events(self, &filter);
+    }
+
+    /// Makes the subscriptions declared in the actor's config, and subscribes to reconnect
+    /// events to restore them.
+    fn subscribe_declared(&mut self)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        let subscriptions = self.config.subscriptions.clone();
+        if subscriptions.is_empty() {
+            return;
+        }
+
+        for subscription in subscriptions {
+            log::debug!("Subscribing to declared {subscription}");
+            match subscription {
+                DeclaredSubscription::Instrument(instrument_id) => {
+                    self.subscribe_instrument(instrument_id, None, None);
+                }
+                DeclaredSubscription::Quotes(instrument_id) => {
+                    self.subscribe_quotes(instrument_id, None, None);
+                }
+                DeclaredSubscription::Trades(instrument_id) => {
+                    self.subscribe_trades(instrument_id, None, None);
+                }
+                DeclaredSubscription::Bars(bar_type) => self.subscribe_bars(bar_type, None, None),
+                DeclaredSubscription::BookDeltas {
+                    instrument_id,
+                    book_type,
+                    depth,
+                } => self.subscribe_book_deltas(instrument_id, book_type, depth, None, false, None),
+                DeclaredSubscription::BookAtInterval {
+                    instrument_id,
+                    book_type,
+                    depth,
+                    interval_ms,
+                } => self.subscribe_book_at_interval(
+                    instrument_id,
+                    book_type,
+                    depth,
+                    interval_ms,
+                    None,
+                    None,
+                ),
+            }
+        }
+
+        let actor_id = self.actor_id().inner();
+        let handler = ShareableMessageHandler::from_typed(move |event: &ReconnectEvent| {
+            if event.stage != ReconnectStage::Resubscribing {
+                return;
+            }
+            catch_actor_panic(actor_id, || {
+                if let Some(actor) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    actor.resend_declared_subscriptions(event);
+                } else {
+                    log::error!("Actor {actor_id} not found for reconnect event handling");
+                }
+            });
+        });
+        DataActorCore::add_reconnect_subscription(self, handler);
+    }
+
+    /// Removes the subscriptions declared in the actor's config.
+    fn unsubscribe_declared(&mut self)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        DataActorCore::remove_reconnect_subscription(self);
+
+        for subscription in self.config.subscriptions.clone() {
+            log::debug!("Unsubscribing from declared {subscription}");
+            match subscription {
+                DeclaredSubscription::Instrument(instrument_id) => {
+                    self.unsubscribe_instrument(instrument_id, None, None);
+                }
+                DeclaredSubscription::Quotes(instrument_id) => {
+                    self.unsubscribe_quotes(instrument_id, None, None);
+                }
+                DeclaredSubscription::Trades(instrument_id) => {
+                    self.unsubscribe_trades(instrument_id, None, None);
+                }
+                DeclaredSubscription::Bars(bar_type) => self.unsubscribe_bars(bar_type, None, None),
+                DeclaredSubscription::BookDeltas { instrument_id, .. } => {
+                    self.unsubscribe_book_deltas(instrument_id, None, None);
+                }
+                DeclaredSubscription::BookAtInterval {
+                    instrument_id,
+                    interval_ms,
+                    ..
+                } => self.unsubscribe_book_at_interval(instrument_id, interval_ms, None, None),
+            }
+        }
+    }
+
     #[cfg(feature = "defi")]
     /// Unsubscribe from streaming [`Block`] data for the `chain`.
     fn unsubscribe_blocks(
@@ -1718,7 +2023,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &CustomDataResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_data_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_data_response(resp)
+            });
         });
 
         DataActorCore::request_data(
@@ -1744,7 +2051,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &InstrumentResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_instrument_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_instrument_response(resp)
+            });
         });
 
         DataActorCore::request_instrument(
@@ -1776,7 +2085,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &InstrumentsResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_instruments_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_instruments_response(resp)
+            });
         });
 
         DataActorCore::request_instruments(self, venue, start, end, client_id, params, handler)
@@ -1799,7 +2110,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &BookResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_book_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_book_response(resp)
+            });
         });
 
         DataActorCore::request_book_snapshot(self, instrument_id, depth, client_id, params, handler)
@@ -1824,7 +2137,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &QuotesResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_quotes_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_quotes_response(resp)
+            });
         });
 
         DataActorCore::request_quotes(
@@ -1858,7 +2173,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &TradesResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_trades_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_trades_response(resp)
+            });
         });
 
         DataActorCore::request_trades(
@@ -1892,7 +2209,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &FundingRatesResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_funding_rates_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_funding_rates_response(resp)
+            });
         });
 
         DataActorCore::request_funding_rates(
@@ -1926,7 +2245,9 @@ pub trait DataActor:
     {
         let actor_id = self.actor_id().inner();
         let handler = ShareableMessageHandler::from_typed(move |resp: &BarsResponse| {
-            get_actor_unchecked::<Self>(&actor_id).handle_bars_response(resp);
+            catch_actor_panic(actor_id, || {
+                get_actor_unchecked::<Self>(&actor_id).handle_bars_response(resp)
+            });
         });
 
         DataActorCore::request_bars(
@@ -1984,11 +2305,13 @@ where
         // Register default time event handler for this actor
         let actor_id = self.actor_id().inner();
         let callback = TimeEventCallback::from(move |event: TimeEvent| {
-            if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
-                actor.handle_time_event(&event);
-            } else {
-                log::error!("Actor {actor_id} not found for time event handling");
-            }
+            catch_actor_panic(actor_id, || {
+                if let Some(mut actor) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    actor.handle_time_event(&event);
+                } else {
+                    log::error!("Actor {actor_id} not found for time event handling");
+                }
+            });
         });
 
         clock.borrow_mut().register_default_handler(callback);
@@ -1997,11 +2320,20 @@ where
     }
 
     fn on_start(&mut self) -> anyhow::Result<()> {
+        if self.config.manage_state {
+            load_actor_state(self)?;
+        }
+        self.subscribe_declared();
         DataActor::on_start(self)
     }
 
     fn on_stop(&mut self) -> anyhow::Result<()> {
-        DataActor::on_stop(self)
+        DataActor::on_stop(self)?;
+        self.unsubscribe_declared();
+        if self.config.manage_state {
+            save_actor_state(self)?;
+        }
+        Ok(())
     }
 
     fn on_resume(&mut self) -> anyhow::Result<()> {
@@ -2025,6 +2357,50 @@ where
     }
 }
 
+/// Saves the state returned by [`DataActor::on_save`] to the cache.
+///
+/// Does nothing if the actor is not registered or returns no state.
+fn save_actor_state<T: DataActor>(actor: &T) -> anyhow::Result<()> {
+    let Some(cache) = actor.cache.clone() else {
+        return Ok(());
+    };
+
+    let state = actor.on_save()?;
+    if state.is_empty() {
+        return Ok(());
+    }
+
+    let component_id = ComponentId::new(actor.actor_id.inner().as_str());
+    let state = state
+        .into_iter()
+        .map(|(key, value)| (key, Bytes::from(value)))
+        .collect();
+    cache.borrow_mut().update_actor_state(&component_id, state)
+}
+
+/// Passes any state saved in the cache to [`DataActor::on_load`].
+///
+/// Does nothing if the actor is not registered or no state was saved.
+fn load_actor_state<T: DataActor>(actor: &mut T) -> anyhow::Result<()> {
+    let Some(cache) = actor.cache.clone() else {
+        return Ok(());
+    };
+
+    let component_id = ComponentId::new(actor.actor_id.inner().as_str());
+    let state = cache.borrow().actor_state(&component_id)?;
+    if state.is_empty() {
+        return Ok(());
+    }
+
+    log::info!("Loading saved state for {component_id}");
+    actor.on_load(
+        state
+            .into_iter()
+            .map(|(key, value)| (key, value.to_vec()))
+            .collect(),
+    )
+}
+
 /// Core functionality for all actors.
 #[derive(Clone)]
 #[allow(
@@ -2051,6 +2427,9 @@ pub struct DataActorCore {
     index_price_handlers: AHashMap<MStr<Topic>, TypedHandler<IndexPriceUpdate>>,
     funding_rate_handlers: AHashMap<MStr<Topic>, TypedHandler<FundingRateUpdate>>,
     order_event_handlers: AHashMap<MStr<Topic>, TypedHandler<OrderEventAny>>,
+    yield_curve_handlers: AHashMap<MStr<Topic>, TypedHandler<YieldCurveData>>,
+    economic_event_handlers: AHashMap<MStr<Pattern>, TypedHandler<EconomicEvent>>,
+    reconnect_handler: Option<ShareableMessageHandler>,
     #[cfg(feature = "defi")]
     block_handlers: AHashMap<MStr<Topic>, TypedHandler<Block>>,
     #[cfg(feature = "defi")]
@@ -2099,7 +2478,7 @@ impl DataActorCore {
         }
 
         self.topic_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_any(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_any(topic.into(), handler, None));
     }
 
     /// Removes a subscription handler for the `topic` if present.
@@ -2129,7 +2508,7 @@ impl DataActorCore {
             return;
         }
         self.quote_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_quotes(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_quotes(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2152,7 +2531,7 @@ impl DataActorCore {
             return;
         }
         self.trade_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_trades(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_trades(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2171,7 +2550,7 @@ impl DataActorCore {
             return;
         }
         self.bar_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_bars(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_bars(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2194,7 +2573,7 @@ impl DataActorCore {
             return;
         }
         self.order_event_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_order_events(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_order_events(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2204,6 +2583,163 @@ impl DataActorCore {
         }
     }
 
+    pub(crate) fn add_yield_curve_subscription(
+        &mut self,
+        topic: MStr<Topic>,
+        handler: TypedHandler<YieldCurveData>,
+    ) {
+        if self.yield_curve_handlers.contains_key(&topic) {
+            log::warn!(
+                "Actor {} attempted duplicate yield curve subscription to '{topic}'",
+                self.actor_id
+            );
+            return;
+        }
+        self.yield_curve_handlers.insert(topic, handler.clone());
+        self.as_principal(|| msgbus::subscribe_yield_curves(topic.into(), handler, None));
+    }
+
+    pub(crate) fn remove_yield_curve_subscription(&mut self, topic: MStr<Topic>) {
+        if let Some(handler) = self.yield_curve_handlers.remove(&topic) {
+            msgbus::unsubscribe_yield_curves(topic.into(), &handler);
+        }
+    }
+
+    pub(crate) fn add_economic_event_subscription(
+        &mut self,
+        pattern: MStr<Pattern>,
+        handler: TypedHandler<EconomicEvent>,
+    ) {
+        if self.economic_event_handlers.contains_key(&pattern) {
+            log::warn!(
+                "Actor {} attempted duplicate economic event subscription to '{pattern}'",
+                self.actor_id
+            );
+            return;
+        }
+        self.economic_event_handlers
+            .insert(pattern, handler.clone());
+        self.as_principal(|| msgbus::subscribe_economic_events(pattern, handler, None));
+    }
+
+    pub(crate) fn remove_economic_event_subscription(&mut self, pattern: MStr<Pattern>) {
+        if let Some(handler) = self.economic_event_handlers.remove(&pattern) {
+            msgbus::unsubscribe_economic_events(pattern, &handler);
+        }
+    }
+
+    pub(crate) fn add_reconnect_subscription(&mut self, handler: ShareableMessageHandler) {
+        if self.reconnect_handler.is_some() {
+            log::warn!(
+                "Actor {} attempted duplicate reconnect event subscription",
+                self.actor_id
+            );
+            return;
+        }
+        self.reconnect_handler = Some(handler.clone());
+        self.as_principal(|| msgbus::subscribe_any(RECONNECT_PATTERN.into(), handler, None));
+    }
+
+    pub(crate) fn remove_reconnect_subscription(&mut self) {
+        if let Some(handler) = self.reconnect_handler.take() {
+            msgbus::unsubscribe_any(RECONNECT_PATTERN.into(), handler);
+        }
+    }
+
+    /// Re-sends the subscribe commands for the declared subscriptions affected by the reconnect
+    /// `event`, leaving the registered handlers in place.
+    pub fn resend_declared_subscriptions(&self, event: &ReconnectEvent) {
+        for subscription in &self.config.subscriptions {
+            if !subscription.is_affected_by(event) {
+                continue;
+            }
+            log::info!(
+                "Resubscribing to declared {subscription} after {} reconnected",
+                event.client_id
+            );
+            let command = self.declared_subscribe_command(subscription);
+            self.send_data_cmd(DataCommand::Subscribe(command));
+        }
+    }
+
+    fn declared_subscribe_command(&self, subscription: &DeclaredSubscription) -> SubscribeCommand {
+        let command_id = UUID4::new();
+        let ts_init = self.timestamp_ns();
+        let instrument_id = subscription.instrument_id();
+        let venue = Some(instrument_id.venue);
+        match subscription {
+            DeclaredSubscription::Instrument(_) => {
+                SubscribeCommand::Instrument(SubscribeInstrument {
+                    instrument_id,
+                    client_id: None,
+                    venue,
+                    command_id,
+                    ts_init,
+                    correlation_id: None,
+                    params: None,
+                })
+            }
+            DeclaredSubscription::Quotes(_) => SubscribeCommand::Quotes(SubscribeQuotes {
+                instrument_id,
+                client_id: None,
+                venue,
+                command_id,
+                ts_init,
+                correlation_id: None,
+                params: None,
+            }),
+            DeclaredSubscription::Trades(_) => SubscribeCommand::Trades(SubscribeTrades {
+                instrument_id,
+                client_id: None,
+                venue,
+                command_id,
+                ts_init,
+                correlation_id: None,
+                params: None,
+            }),
+            DeclaredSubscription::Bars(bar_type) => SubscribeCommand::Bars(SubscribeBars {
+                bar_type: *bar_type,
+                client_id: None,
+                venue,
+                command_id,
+                ts_init,
+                correlation_id: None,
+                params: None,
+            }),
+            DeclaredSubscription::BookDeltas {
+                book_type, depth, ..
+            } => SubscribeCommand::BookDeltas(SubscribeBookDeltas {
+                instrument_id,
+                book_type: *book_type,
+                client_id: None,
+                venue,
+                command_id,
+                ts_init,
+                depth: *depth,
+                managed: false,
+                correlation_id: None,
+                params: None,
+            }),
+            DeclaredSubscription::BookAtInterval {
+                book_type,
+                depth,
+                interval_ms,
+                ..
+            } => SubscribeCommand::BookSnapshots(SubscribeBookSnapshots {
+                instrument_id,
+                book_type: *book_type,
+                client_id: None,
+                venue,
+                command_id,
+                ts_init,
+                depth: *depth,
+                interval_ms: *interval_ms,
+                correlation_id: None,
+                params: None,
+            }),
+        }
+    }
+
     pub(crate) fn add_deltas_subscription(
         &mut self,
         topic: MStr<Topic>,
@@ -2217,7 +2753,7 @@ impl DataActorCore {
             return;
         }
         self.deltas_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_book_deltas(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_book_deltas(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2241,7 +2777,7 @@ impl DataActorCore {
             return;
         }
         self.depth10_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_book_depth10(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_book_depth10(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2264,7 +2800,7 @@ impl DataActorCore {
             return;
         }
         self.topic_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_any(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_any(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2287,7 +2823,7 @@ impl DataActorCore {
             return;
         }
         self.topic_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_any(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_any(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2310,7 +2846,7 @@ impl DataActorCore {
             return;
         }
         self.book_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_book_snapshots(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_book_snapshots(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2333,7 +2869,7 @@ impl DataActorCore {
             return;
         }
         self.mark_price_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_mark_prices(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_mark_prices(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2356,7 +2892,7 @@ impl DataActorCore {
             return;
         }
         self.index_price_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_index_prices(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_index_prices(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2379,7 +2915,7 @@ impl DataActorCore {
             return;
         }
         self.funding_rate_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_funding_rates(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_funding_rates(topic.into(), handler, None));
     }
 
     #[allow(dead_code)]
@@ -2403,7 +2939,7 @@ impl DataActorCore {
             return;
         }
         self.block_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_blocks(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_blocks(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2428,7 +2964,7 @@ impl DataActorCore {
             return;
         }
         self.pool_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_pools(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_pools(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2453,7 +2989,7 @@ impl DataActorCore {
             return;
         }
         self.pool_swap_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_swaps(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_swaps(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2478,7 +3014,7 @@ impl DataActorCore {
             return;
         }
         self.pool_liquidity_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_liquidity(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_liquidity(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2503,7 +3039,7 @@ impl DataActorCore {
             return;
         }
         self.pool_collect_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_collects(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_collects(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2528,7 +3064,7 @@ impl DataActorCore {
             return;
         }
         self.pool_flash_handlers.insert(topic, handler.clone());
-        msgbus::subscribe_defi_flash(topic.into(), handler, None);
+        self.as_principal(|| msgbus::subscribe_defi_flash(topic.into(), handler, None));
     }
 
     #[cfg(feature = "defi")]
@@ -2563,6 +3099,9 @@ impl DataActorCore {
             index_price_handlers: AHashMap::new(),
             funding_rate_handlers: AHashMap::new(),
             order_event_handlers: AHashMap::new(),
+            yield_curve_handlers: AHashMap::new(),
+            economic_event_handlers: AHashMap::new(),
+            reconnect_handler: None,
             #[cfg(feature = "defi")]
             block_handlers: AHashMap::new(),
             #[cfg(feature = "defi")]
@@ -2604,6 +3143,11 @@ impl DataActorCore {
         self.actor_id
     }
 
+    /// Runs `f` with this actor as the message bus principal, so the topic ACL is enforced.
+    fn as_principal<R>(&self, f: impl FnOnce() -> R) -> R {
+        msgbus::with_principal(&self.actor_id.inner(), f)
+    }
+
     fn default_actor_id(config: &DataActorConfig) -> ActorId {
         let memory_address = std::ptr::from_ref(config) as usize;
         ActorId::from(format!("{}-{memory_address}", stringify!(DataActor)))
@@ -3185,6 +3729,29 @@ impl DataActorCore {
         self.add_order_event_subscription(topic, handler);
     }
 
+    /// Helper method for registering yield curve subscriptions from the trait.
+    pub fn subscribe_yield_curve(
+        &mut self,
+        topic: MStr<Topic>,
+        handler: TypedHandler<YieldCurveData>,
+    ) {
+        self.check_registered();
+        self.add_yield_curve_subscription(topic, handler);
+    }
+
+    /// Helper method for registering economic event subscriptions from the trait.
+    pub fn subscribe_economic_events(
+        &mut self,
+        filter: &EconomicEventFilter,
+        handler: TypedHandler<EconomicEvent>,
+    ) {
+        self.check_registered();
+
+        for pattern in economic_event_patterns(filter) {
+            self.add_economic_event_subscription(pattern, handler.clone());
+        }
+    }
+
     /// Helper method for unsubscribing from data.
     pub fn unsubscribe_data(
         &mut self,
@@ -3530,6 +4097,23 @@ impl DataActorCore {
         self.remove_order_event_subscription(topic);
     }
 
+    /// Helper method for unsubscribing from yield curves.
+    pub fn unsubscribe_yield_curve(&mut self, curve_name: &str) {
+        self.check_registered();
+
+        let topic = get_yield_curve_topic(Ustr::from(curve_name));
+        self.remove_yield_curve_subscription(topic);
+    }
+
+    /// Helper method for unsubscribing from economic events.
+    pub fn unsubscribe_economic_events(&mut self, filter: &EconomicEventFilter) {
+        self.check_registered();
+
+        for pattern in economic_event_patterns(filter) {
+            self.remove_economic_event_subscription(pattern);
+        }
+    }
+
     /// Helper method for requesting data.
     ///
     /// # Errors
@@ -3915,6 +4499,26 @@ fn check_timestamps(
     Ok(())
 }
 
+/// Returns the patterns economic events passing the `filter` are routed on.
+fn economic_event_patterns(filter: &EconomicEventFilter) -> Vec<MStr<Pattern>> {
+    if filter.countries.is_empty() {
+        return vec!["data.EconomicEvent.country=*".into()];
+    }
+    filter
+        .countries
+        .iter()
+        .map(|country| get_economic_events_topic(*country).into())
+        .collect()
+}
+
+/// Dispatches an actor callback, converting a panic into a crash report which faults the actor.
+fn catch_actor_panic(actor_id: Ustr, f: impl FnOnce()) {
+    // Message bus calls made by the actor's handlers are checked against its topic grants
+    msgbus::with_principal(&actor_id, || {
+        let _ = catch_panic(Some(actor_id), f);
+    });
+}
+
 fn log_error(e: &anyhow::Error) {
     log::error!("{e}");
 }
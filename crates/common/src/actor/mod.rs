@@ -25,16 +25,20 @@ use std::{any::Any, fmt::Debug};
 
 use ustr::Ustr;
 
+pub mod config;
 pub mod data_actor;
 #[cfg(feature = "indicators")]
 pub(crate) mod indicators;
 pub mod registry;
+pub mod subscriptions;
 
 #[cfg(test)]
 mod tests;
 
 // Re-exports
+pub use config::{ConfigField, ConfigSchema, ConfigValidationError, FieldKind, TypedActorConfig};
 pub use data_actor::{DataActor, DataActorConfig, DataActorCore};
+pub use subscriptions::DeclaredSubscription;
 
 pub use crate::component::Component;
 
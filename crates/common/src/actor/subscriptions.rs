@@ -0,0 +1,129 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Declarative data subscriptions for [`DataActor`](super::DataActor) based components.
+//!
+//! Subscriptions listed in [`DataActorConfig::subscriptions`](super::DataActorConfig) are made
+//! when the actor starts (before its `on_start` handler runs), removed when it stops (after its
+//! `on_stop` handler runs), and re-sent to the data engine when a data client reports it is
+//! resubscribing after a reconnect, so actors need no subscription boilerplate of their own.
+
+use std::{fmt::Display, num::NonZeroUsize};
+
+use nautilus_model::{
+    data::BarType,
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::reconnect::ReconnectEvent;
+
+/// A data subscription declared in an actor's configuration.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeclaredSubscription {
+    Instrument(InstrumentId),
+    Quotes(InstrumentId),
+    Trades(InstrumentId),
+    Bars(BarType),
+    BookDeltas {
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+    },
+    BookAtInterval {
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+        interval_ms: NonZeroUsize,
+    },
+}
+
+impl DeclaredSubscription {
+    /// Returns the instrument the subscription is for.
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::Instrument(instrument_id)
+            | Self::Quotes(instrument_id)
+            | Self::Trades(instrument_id)
+            | Self::BookDeltas { instrument_id, .. }
+            | Self::BookAtInterval { instrument_id, .. } => *instrument_id,
+            Self::Bars(bar_type) => bar_type.instrument_id(),
+        }
+    }
+
+    /// Returns whether a reconnect `event` affects the subscription.
+    ///
+    /// The subscription is affected when the event lists its instrument, or the reconnecting
+    /// client is named after the instrument's venue.
+    #[must_use]
+    pub fn is_affected_by(&self, event: &ReconnectEvent) -> bool {
+        let instrument_id = self.instrument_id();
+        event.instruments.contains(&instrument_id)
+            || event.client_id == ClientId::from(instrument_id.venue.as_str())
+    }
+}
+
+impl Display for DeclaredSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Instrument(instrument_id) => write!(f, "Instrument({instrument_id})"),
+            Self::Quotes(instrument_id) => write!(f, "Quotes({instrument_id})"),
+            Self::Trades(instrument_id) => write!(f, "Trades({instrument_id})"),
+            Self::Bars(bar_type) => write!(f, "Bars({bar_type})"),
+            Self::BookDeltas { instrument_id, .. } => write!(f, "BookDeltas({instrument_id})"),
+            Self::BookAtInterval {
+                instrument_id,
+                interval_ms,
+                ..
+            } => write!(f, "BookAtInterval({instrument_id}, {interval_ms}ms)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::reconnect::ReconnectStage;
+
+    fn event(client_id: &str, instruments: &[&str]) -> ReconnectEvent {
+        ReconnectEvent {
+            client_id: ClientId::from(client_id),
+            stage: ReconnectStage::Resubscribing,
+            instruments: instruments
+                .iter()
+                .map(|id| InstrumentId::from(*id))
+                .collect(),
+            ts_event: UnixNanos::default(),
+        }
+    }
+
+    #[rstest]
+    #[case(event("OTHER", &["AUDUSD.SIM"]), true)]
+    #[case(event("SIM", &[]), true)]
+    #[case(event("OTHER", &["GBPUSD.SIM"]), false)]
+    fn test_is_affected_by(#[case] event: ReconnectEvent, #[case] expected: bool) {
+        let bar_type = BarType::from("AUDUSD.SIM-1-MINUTE-LAST-EXTERNAL");
+        assert_eq!(bar_type.instrument_id(), InstrumentId::from("AUDUSD.SIM"));
+        assert_eq!(
+            DeclaredSubscription::Bars(bar_type).is_affected_by(&event),
+            expected
+        );
+    }
+}
@@ -26,17 +26,22 @@ use std::{
 use bytes::Bytes;
 use indexmap::IndexMap;
 use log::LevelFilter;
-use nautilus_core::UnixNanos;
+use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
     data::{
-        Bar, BarType, BookOrder, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
-        MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
-        close::InstrumentClose, stubs::*,
+        Bar, BarType, BookOrder, DataType, EconomicEvent, EconomicEventFilter, EventImpact,
+        FundingRateUpdate, IndexPriceUpdate, InstrumentStatus, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDeltas, QuoteTick, TradeTick, YieldCurveData, close::InstrumentClose, stubs::*,
+    },
+    enums::{BookAction, BookType, OrderSide, OrderType},
+    events::{OrderCanceled, OrderFilled},
+    identifiers::{
+        AccountId, ActorId, ClientId, ClientOrderId, ComponentId, StrategyId, TraderId, Venue,
+        VenueOrderId,
     },
-    enums::{BookAction, BookType, OrderSide},
-    identifiers::{ClientId, TraderId, Venue},
     instruments::{CurrencyPair, InstrumentAny, stubs::*},
     orderbook::OrderBook,
+    orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
     stubs::TestDefault,
     types::{Price, Quantity},
 };
@@ -52,30 +57,37 @@ use {
     },
 };
 
-use super::{Actor, DataActor, DataActorCore, data_actor::DataActorConfig};
+use super::{
+    Actor, DataActor, DataActorCore, data_actor::DataActorConfig,
+    subscriptions::DeclaredSubscription,
+};
 #[cfg(feature = "defi")]
 use crate::defi::switchboard::{
     get_defi_blocks_topic, get_defi_pool_swaps_topic, get_defi_pool_topic,
 };
 use crate::{
-    actor::registry::{get_actor, get_actor_unchecked, register_actor},
+    actor::registry::{clear_actor_registry, get_actor, get_actor_unchecked, register_actor},
     cache::Cache,
     clock::TestClock,
     component::Component,
+    crash::{CrashConfig, CrashReport, set_crash_config},
     logging::{logger::LogGuard, logging_is_initialized},
     messages::data::{
-        BarsResponse, BookResponse, CustomDataResponse, DataResponse, FundingRatesResponse,
-        InstrumentResponse, InstrumentsResponse, QuotesResponse, TradesResponse,
+        BarsResponse, BookResponse, CustomDataResponse, DataCommand, DataResponse,
+        FundingRatesResponse, InstrumentResponse, InstrumentsResponse, QuotesResponse,
+        SubscribeCommand, TradesResponse, UnsubscribeCommand,
     },
     msgbus::{
-        self, MessageBus, get_message_bus,
+        self, AclPermission, MessageBus, TopicAcl, TypedIntoHandler, get_message_bus,
         switchboard::{
             MessagingSwitchboard, get_bars_topic, get_book_deltas_topic, get_book_snapshots_topic,
-            get_custom_topic, get_funding_rate_topic, get_index_price_topic,
-            get_instrument_close_topic, get_instrument_status_topic, get_instrument_topic,
-            get_instruments_topic, get_mark_price_topic, get_quotes_topic, get_trades_topic,
+            get_custom_topic, get_economic_events_topic, get_funding_rate_topic,
+            get_index_price_topic, get_instrument_close_topic, get_instrument_status_topic,
+            get_instrument_topic, get_instruments_topic, get_mark_price_topic, get_quotes_topic,
+            get_trades_topic, get_yield_curve_topic,
         },
     },
+    reconnect::{ReconnectEvent, ReconnectStage, get_reconnect_topic},
     runner::{SyncDataCommandSender, set_data_cmd_sender},
     testing::init_logger_for_testing,
     timer::TimeEvent,
@@ -97,6 +109,8 @@ struct TestDataActor {
     pub received_funding_rates: Vec<FundingRateUpdate>,
     pub received_status: Vec<InstrumentStatus>,
     pub received_closes: Vec<InstrumentClose>,
+    pub received_yield_curves: Vec<YieldCurveData>,
+    pub received_economic_events: Vec<EconomicEvent>,
     #[cfg(feature = "defi")]
     pub received_blocks: Vec<Block>,
     #[cfg(feature = "defi")]
@@ -223,6 +237,16 @@ impl DataActor for TestDataActor {
         Ok(())
     }
 
+    fn on_yield_curve(&mut self, yield_curve: &YieldCurveData) -> anyhow::Result<()> {
+        self.received_yield_curves.push(yield_curve.clone());
+        Ok(())
+    }
+
+    fn on_economic_event(&mut self, event: &EconomicEvent) -> anyhow::Result<()> {
+        self.received_economic_events.push(event.clone());
+        Ok(())
+    }
+
     #[cfg(feature = "defi")]
     fn on_block(&mut self, block: &Block) -> anyhow::Result<()> {
         self.received_blocks.push(block.clone());
@@ -266,6 +290,8 @@ impl TestDataActor {
             received_funding_rates: Vec::new(),
             received_status: Vec::new(),
             received_closes: Vec::new(),
+            received_yield_curves: Vec::new(),
+            received_economic_events: Vec::new(),
             #[cfg(feature = "defi")]
             received_blocks: Vec::new(),
             #[cfg(feature = "defi")]
@@ -428,6 +454,42 @@ fn test_subscribe_and_receive_custom_data(
     assert_eq!(actor.received_data.len(), 2);
 }
 
+#[rstest]
+fn test_actor_callback_panic_is_caught(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let actor_id = register_data_actor(clock, cache, trader_id);
+    let data_type = DataType::new(stringify!(String), None);
+    {
+        let mut actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+        actor.start().unwrap();
+        actor.subscribe_data(data_type.clone(), None, None);
+    }
+
+    let dump_dir = std::env::temp_dir().join(format!("crash-{}", UUID4::new()));
+    set_crash_config(CrashConfig {
+        dump_dir: Some(dump_dir.clone()),
+        ..Default::default()
+    });
+
+    // The handler panics as the actor is no longer registered, which is captured rather than
+    // unwinding into the publisher
+    clear_actor_registry();
+    msgbus::publish_any(get_custom_topic(&data_type), &String::from("CustomData-01"));
+    set_crash_config(CrashConfig::default());
+
+    let dumps: Vec<_> = std::fs::read_dir(&dump_dir).unwrap().collect();
+    assert_eq!(dumps.len(), 1);
+    let path = dumps[0].as_ref().unwrap().path();
+    let report: CrashReport =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(report.component_id, Some(actor_id));
+    assert!(report.message.contains("not found"));
+    std::fs::remove_dir_all(dump_dir).unwrap();
+}
+
 #[rstest]
 fn test_unsubscribe_custom_data(
     clock: Rc<RefCell<TestClock>>,
@@ -629,6 +691,31 @@ fn test_subscribe_and_receive_quotes(
     assert_eq!(actor.received_quotes.len(), 2);
 }
 
+#[rstest]
+fn test_subscriptions_checked_against_actor_acl(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+    audusd_sim: CurrencyPair,
+) {
+    let actor_id = register_data_actor(clock, cache, trader_id);
+    let mut actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    actor.start().unwrap();
+    let mut acl = TopicAcl::default();
+    acl.deny(&actor_id, AclPermission::Subscribe, "data.quotes.*");
+    msgbus::set_acl(acl);
+
+    actor.subscribe_quotes(audusd_sim.id, None, None);
+    actor.subscribe_trades(audusd_sim.id, None, None);
+    msgbus::publish_quote(get_quotes_topic(audusd_sim.id), &QuoteTick::default());
+    msgbus::publish_trade(get_trades_topic(audusd_sim.id), &TradeTick::default());
+
+    assert!(actor.received_quotes.is_empty());
+    assert_eq!(actor.received_trades.len(), 1);
+    assert_eq!(msgbus::acl_violations().len(), 1);
+    assert!(msgbus::clear_acl().is_some());
+}
+
 #[rstest]
 fn test_unsubscribe_quotes(
     clock: Rc<RefCell<TestClock>>,
@@ -1137,6 +1224,93 @@ fn test_subscribe_and_receive_funding_rates(
     assert_eq!(actor.received_funding_rates[1], fr2);
 }
 
+#[rstest]
+fn test_subscribe_and_receive_yield_curves(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let actor_id = register_data_actor(clock, cache.clone(), trader_id);
+    let mut actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    actor.start().unwrap();
+
+    actor.subscribe_yield_curve("USD");
+
+    let curve = |curve_name: &str, ts: u64| {
+        YieldCurveData::new(
+            ts.into(),
+            ts.into(),
+            curve_name.to_string(),
+            vec![1.0],
+            vec![0.05],
+        )
+    };
+    msgbus::publish_yield_curve(get_yield_curve_topic(Ustr::from("USD")), &curve("USD", 1));
+    msgbus::publish_yield_curve(get_yield_curve_topic(Ustr::from("EUR")), &curve("EUR", 2));
+
+    assert_eq!(actor.received_yield_curves.len(), 1);
+    assert_eq!(actor.received_yield_curves[0].curve_name, "USD");
+    assert_eq!(
+        cache.borrow().yield_curve_data("USD").unwrap().ts_event,
+        UnixNanos::from(1)
+    );
+    assert!(cache.borrow().yield_curve_data("EUR").is_none());
+
+    actor.unsubscribe_yield_curve("USD");
+    msgbus::publish_yield_curve(get_yield_curve_topic(Ustr::from("USD")), &curve("USD", 3));
+    assert_eq!(actor.received_yield_curves.len(), 1);
+}
+
+#[rstest]
+fn test_subscribe_and_receive_economic_events(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let actor_id = register_data_actor(clock, cache, trader_id);
+    let mut actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    actor.start().unwrap();
+
+    let filter = EconomicEventFilter::new(vec![Ustr::from("US")], EventImpact::High);
+    actor.subscribe_economic_events(filter.clone());
+
+    let event = |country: &str, impact: EventImpact| {
+        EconomicEvent::new(
+            Ustr::from("NFP"),
+            Ustr::from("Non-Farm Payrolls"),
+            Ustr::from(country),
+            impact,
+            None,
+            Some(200.0),
+            Some(36.0),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    };
+    let publish = |country: &str, impact: EventImpact| {
+        msgbus::publish_economic_event(
+            get_economic_events_topic(Ustr::from(country)),
+            &event(country, impact),
+        );
+    };
+    publish("US", EventImpact::High);
+    publish("US", EventImpact::Medium);
+    publish("EU", EventImpact::High);
+
+    assert_eq!(actor.received_economic_events.len(), 1);
+    assert_eq!(actor.received_economic_events[0].country, Ustr::from("US"));
+
+    actor.unsubscribe_economic_events(filter);
+    publish("US", EventImpact::High);
+    assert_eq!(actor.received_economic_events.len(), 1);
+
+    // An empty country list receives every country
+    actor.subscribe_economic_events(EconomicEventFilter::default());
+    publish("EU", EventImpact::Low);
+    publish("JP", EventImpact::High);
+    assert_eq!(actor.received_economic_events.len(), 3);
+}
+
 #[rstest]
 fn test_subscribe_and_receive_instrument_status(
     clock: Rc<RefCell<TestClock>>,
@@ -1923,6 +2097,239 @@ fn test_on_save_and_on_load(
     assert_eq!(actor_ref.loaded_state.as_ref(), Some(&snapshot));
 }
 
+#[rstest]
+fn test_state_saved_on_stop_and_loaded_on_start(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let config = DataActorConfig {
+        actor_id: Some(ActorId::from("SAVE-LOAD-001")),
+        ..Default::default()
+    };
+    let component_id = ComponentId::from("SAVE-LOAD-001");
+
+    let mut actor = SaveLoadActor::new(config.clone());
+    actor
+        .register(trader_id, clock.clone(), cache.clone())
+        .unwrap();
+    actor.start().unwrap();
+    assert!(actor.loaded_state.is_none());
+    actor.stop().unwrap();
+
+    let saved = cache.borrow().actor_state(&component_id).unwrap();
+    assert_eq!(saved.get("answer"), Some(&Bytes::from(vec![4, 2])));
+
+    // A fresh instance with the same ID resumes with the saved state
+    let mut resumed = SaveLoadActor::new(config);
+    resumed.register(trader_id, clock, cache).unwrap();
+    resumed.start().unwrap();
+
+    let loaded = resumed.loaded_state.as_ref().unwrap();
+    assert_eq!(loaded.get("answer"), Some(&vec![4, 2]));
+}
+
+#[rstest]
+fn test_state_not_managed_when_disabled(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let config = DataActorConfig {
+        actor_id: Some(ActorId::from("SAVE-LOAD-002")),
+        manage_state: false,
+        ..Default::default()
+    };
+
+    let mut actor = SaveLoadActor::new(config);
+    actor.register(trader_id, clock, cache.clone()).unwrap();
+    actor.start().unwrap();
+    actor.stop().unwrap();
+
+    let saved = cache
+        .borrow()
+        .actor_state(&ComponentId::from("SAVE-LOAD-002"))
+        .unwrap();
+    assert!(saved.is_empty());
+}
+
+#[rstest]
+fn test_declared_subscriptions_managed_by_lifecycle(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+    audusd_sim: CurrencyPair,
+) {
+    set_data_cmd_sender(Arc::new(SyncDataCommandSender));
+    *get_message_bus().borrow_mut() = MessageBus::default();
+
+    let commands = Rc::new(RefCell::new(Vec::new()));
+    let sink = commands.clone();
+    msgbus::register_data_command_endpoint(
+        MessagingSwitchboard::data_engine_queue_execute(),
+        TypedIntoHandler::from(move |command: DataCommand| sink.borrow_mut().push(command)),
+    );
+
+    let config = DataActorConfig {
+        actor_id: Some(ActorId::from("DECLARED-001")),
+        subscriptions: vec![DeclaredSubscription::Quotes(audusd_sim.id)],
+        ..Default::default()
+    };
+    let mut actor = TestDataActor::new(config);
+    actor.register(trader_id, clock, cache).unwrap();
+    let actor_id = actor.actor_id().inner();
+    register_actor(actor);
+    let mut actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+
+    actor.start().unwrap();
+    let topic = get_quotes_topic(audusd_sim.id);
+    msgbus::publish_quote(topic, &QuoteTick::default());
+    assert_eq!(actor.received_quotes.len(), 1);
+    assert_eq!(commands.borrow().len(), 1);
+
+    // The declared subscription is re-sent when the venue's client resubscribes
+    let event = ReconnectEvent {
+        client_id: ClientId::from("SIM"),
+        stage: ReconnectStage::Resubscribing,
+        instruments: vec![],
+        ts_event: UnixNanos::default(),
+    };
+    msgbus::publish_any(get_reconnect_topic(event.client_id), &event);
+    assert_eq!(commands.borrow().len(), 2);
+    assert!(matches!(
+        commands.borrow().last(),
+        Some(DataCommand::Subscribe(SubscribeCommand::Quotes(cmd))) if cmd.instrument_id == audusd_sim.id
+    ));
+
+    actor.stop().unwrap();
+    msgbus::publish_quote(topic, &QuoteTick::default());
+    assert_eq!(actor.received_quotes.len(), 1);
+    assert!(matches!(
+        commands.borrow().last(),
+        Some(DataCommand::Unsubscribe(UnsubscribeCommand::Quotes(_)))
+    ));
+
+    // Reconnects after stop no longer resubscribe
+    msgbus::publish_any(get_reconnect_topic(event.client_id), &event);
+    assert_eq!(commands.borrow().len(), 3);
+}
+
+// ---------------------------------------------------------------------------------------------
+// order event replay
+// ---------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct OrderReplayActor {
+    core: DataActorCore,
+    replayed: Vec<String>,
+}
+
+impl Deref for OrderReplayActor {
+    type Target = DataActorCore;
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for OrderReplayActor {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl DataActor for OrderReplayActor {
+    fn on_order_filled(&mut self, event: &OrderFilled) -> anyhow::Result<()> {
+        self.replayed
+            .push(format!("filled {}", event.client_order_id));
+        Ok(())
+    }
+
+    fn on_order_canceled(&mut self, event: &OrderCanceled) -> anyhow::Result<()> {
+        self.replayed
+            .push(format!("canceled {}", event.client_order_id));
+        Ok(())
+    }
+}
+
+fn accepted_order(instrument: &CurrencyPair, strategy_id: &str, client_order_id: &str) -> OrderAny {
+    let mut order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id)
+        .strategy_id(StrategyId::from(strategy_id))
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .quantity(Quantity::from(100_000))
+        .build();
+    let account_id = AccountId::from("SIM-001");
+    order
+        .apply(TestOrderEventStubs::submitted(&order, account_id))
+        .unwrap();
+    order
+        .apply(TestOrderEventStubs::accepted(
+            &order,
+            account_id,
+            VenueOrderId::from("V-1"),
+        ))
+        .unwrap();
+    order
+}
+
+#[rstest]
+fn test_replay_order_events(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+    audusd_sim: CurrencyPair,
+) {
+    let mut filled = accepted_order(&audusd_sim, "S-001", "O-1");
+    let fill = TestOrderEventStubs::filled(
+        &filled,
+        &InstrumentAny::CurrencyPair(audusd_sim),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(UnixNanos::from(5)),
+        None,
+    );
+    filled.apply(fill).unwrap();
+
+    let mut canceled = accepted_order(&audusd_sim, "S-001", "O-2");
+    let cancel = TestOrderEventStubs::canceled(&canceled, AccountId::from("SIM-001"), None);
+    canceled.apply(cancel).unwrap();
+
+    let other = accepted_order(&audusd_sim, "S-002", "O-3");
+    for order in [filled, canceled, other] {
+        cache
+            .borrow_mut()
+            .add_order(order, None, None, false)
+            .unwrap();
+    }
+
+    let mut actor = OrderReplayActor {
+        core: DataActorCore::new(DataActorConfig::default()),
+        replayed: Vec::new(),
+    };
+    actor.register(trader_id, clock, cache).unwrap();
+
+    let strategy_id = StrategyId::from("S-001");
+    let count = actor
+        .replay_order_events(&strategy_id, UnixNanos::default())
+        .unwrap();
+
+    // Initialized, submitted and accepted for both orders plus the cancel (ts 0), then the fill
+    assert_eq!(count, 8);
+    assert_eq!(actor.replayed, vec!["canceled O-2", "filled O-1"]);
+
+    actor.replayed.clear();
+    let count = actor
+        .replay_order_events(&strategy_id, UnixNanos::from(1))
+        .unwrap();
+
+    assert_eq!(count, 1);
+    assert_eq!(actor.replayed, vec!["filled O-1"]);
+}
+
 #[rstest]
 fn test_data_actor_core_tracks_quote_handlers(
     clock: Rc<RefCell<TestClock>>,
@@ -0,0 +1,720 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Alert notifications for operational events.
+//!
+//! The [`AlertNotifier`] subscribes to order rejections, drawdown breaches and cache integrity
+//! mismatches on the message bus (faults are passed in from crash reports with
+//! [`AlertNotifier::notify`]), and routes each [`Alert`] to the configured channels. Each
+//! [`AlertRoute`] selects alerts by kind and minimum severity, renders them with an optional
+//! template, and is rate limited so a burst of events does not flood the channel; suppressed
+//! alerts are counted and reported with the next delivered alert.
+//!
+//! Delivery is performed by an [`AlertTransport`] supplied by the caller, which receives the
+//! rendered webhook JSON, Slack message payload or email message.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::events::OrderEventAny;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::{
+    cache::integrity::{IntegrityReport, get_integrity_topic},
+    crash::CrashReport,
+    msgbus::{self, ShareableMessageHandler, TypedHandler},
+    risk::DrawdownBreached,
+};
+
+/// The severity of an alert.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display, Serialize, Deserialize,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// The kind of event an alert was raised for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Display, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlertKind {
+    /// An order was denied, rejected, or a modify or cancel was rejected.
+    OrderRejected,
+    /// A component or task panicked.
+    Fault,
+    /// A drawdown limit was breached.
+    DrawdownBreach,
+    /// Related cache collections were found inconsistent.
+    IntegrityMismatch,
+}
+
+/// An alert raised for an operational event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub severity: AlertSeverity,
+    pub title: String,
+    /// The event details, available to templates as `{name}` placeholders.
+    pub fields: IndexMap<String, String>,
+    pub ts_event: UnixNanos,
+}
+
+impl Alert {
+    /// Creates a new [`Alert`] instance.
+    #[must_use]
+    pub fn new(
+        kind: AlertKind,
+        severity: AlertSeverity,
+        title: impl Into<String>,
+        ts_event: UnixNanos,
+    ) -> Self {
+        Self {
+            kind,
+            severity,
+            title: title.into(),
+            fields: IndexMap::new(),
+            ts_event,
+        }
+    }
+
+    /// Adds a detail field to the alert.
+    #[must_use]
+    pub fn with_field(mut self, name: &str, value: impl ToString) -> Self {
+        self.fields.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns an alert for a denied or rejected order event (`None` for other events).
+    #[must_use]
+    pub fn from_order_event(event: &OrderEventAny) -> Option<Self> {
+        let (label, reason) = match event {
+            OrderEventAny::Denied(e) => ("Order denied", e.reason),
+            OrderEventAny::Rejected(e) => ("Order rejected", e.reason),
+            OrderEventAny::ModifyRejected(e) => ("Order modify rejected", e.reason),
+            OrderEventAny::CancelRejected(e) => ("Order cancel rejected", e.reason),
+            _ => return None,
+        };
+        Some(
+            Self::new(
+                AlertKind::OrderRejected,
+                AlertSeverity::Warning,
+                format!("{label}: {}", event.client_order_id()),
+                event.ts_event(),
+            )
+            .with_field("strategy_id", event.strategy_id())
+            .with_field("instrument_id", event.instrument_id())
+            .with_field("client_order_id", event.client_order_id())
+            .with_field("reason", reason),
+        )
+    }
+
+    /// Returns an alert for a drawdown breach.
+    #[must_use]
+    pub fn from_drawdown(event: &DrawdownBreached) -> Self {
+        Self::new(
+            AlertKind::DrawdownBreach,
+            AlertSeverity::Critical,
+            format!("Drawdown limit breached for {}", event.strategy_id),
+            event.ts_event,
+        )
+        .with_field("strategy_id", event.strategy_id)
+        .with_field("limit_kind", format!("{:?}", event.kind))
+        .with_field("drawdown", event.drawdown)
+        .with_field("limit", event.limit)
+        .with_field("pnl", event.pnl)
+    }
+
+    /// Returns an alert for an integrity report with issues (`None` when consistent).
+    #[must_use]
+    pub fn from_integrity(report: &IntegrityReport) -> Option<Self> {
+        if report.issues.is_empty() {
+            return None;
+        }
+        let unrepaired = report.issues.len().saturating_sub(report.repaired);
+        let severity = if unrepaired > 0 {
+            AlertSeverity::Critical
+        } else {
+            AlertSeverity::Info
+        };
+        let mut alert = Self::new(
+            AlertKind::IntegrityMismatch,
+            severity,
+            format!("{} cache integrity issue(s)", report.issues.len()),
+            report.ts_event,
+        )
+        .with_field("issues", report.issues.len())
+        .with_field("repaired", report.repaired);
+        if let Some(first) = report.issues.first() {
+            alert = alert.with_field("first_issue", format!("{first:?}"));
+        }
+        Some(alert)
+    }
+
+    /// Returns an alert for a crash report.
+    #[must_use]
+    pub fn from_crash(report: &CrashReport) -> Self {
+        let source = report.component_id.map_or("task", |id| id.as_str());
+        let mut alert = Self::new(
+            AlertKind::Fault,
+            AlertSeverity::Critical,
+            format!("Panic in {source}"),
+            report.ts,
+        )
+        .with_field("source", source)
+        .with_field("message", &report.message)
+        .with_field("faulted", report.faulted);
+        if let Some(location) = &report.location {
+            alert = alert.with_field("location", location);
+        }
+        alert
+    }
+}
+
+/// A template rendering an alert, with `{kind}`, `{severity}`, `{title}`, `{ts_event}` and
+/// `{<field>}` placeholders (unknown placeholders are left as is).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertTemplate {
+    pub subject: String,
+    pub body: String,
+}
+
+impl Default for AlertTemplate {
+    fn default() -> Self {
+        Self {
+            subject: "[{severity}] {title}".to_string(),
+            body: String::new(),
+        }
+    }
+}
+
+impl AlertTemplate {
+    /// Renders the subject and body for the `alert`.
+    ///
+    /// An empty body template renders the alert fields as `name: value` lines.
+    #[must_use]
+    pub fn render(&self, alert: &Alert) -> (String, String) {
+        let subject = render_template(&self.subject, alert);
+        let body = if self.body.is_empty() {
+            alert
+                .fields
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            render_template(&self.body, alert)
+        };
+        (subject, body)
+    }
+}
+
+/// Substitutes the alert placeholders in `template`.
+#[must_use]
+pub fn render_template(template: &str, alert: &Alert) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = &after[..end];
+        let value = match name {
+            "kind" => Some(alert.kind.to_string()),
+            "severity" => Some(alert.severity.to_string()),
+            "title" => Some(alert.title.clone()),
+            "ts_event" => Some(alert.ts_event.to_string()),
+            _ => alert.fields.get(name).cloned(),
+        };
+        match value {
+            Some(value) => rendered.push_str(&value),
+            None => rendered.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// A channel alerts are delivered through.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertChannel {
+    /// A generic webhook receiving the alert as JSON.
+    Webhook { url: String },
+    /// A Slack incoming webhook.
+    Slack {
+        webhook_url: String,
+        channel: Option<String>,
+    },
+    /// An email to one or more recipients.
+    Email { from: String, to: Vec<String> },
+}
+
+/// A rendered alert ready for delivery by an [`AlertTransport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlertDelivery {
+    /// The name of the route the alert was delivered for.
+    pub route: String,
+    pub channel: AlertChannel,
+    pub content_type: &'static str,
+    pub payload: String,
+}
+
+impl AlertChannel {
+    /// Renders the payload for the `alert` with the `template`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized.
+    pub fn render(
+        &self,
+        alert: &Alert,
+        template: &AlertTemplate,
+    ) -> anyhow::Result<(&'static str, String)> {
+        let (subject, body) = template.render(alert);
+        match self {
+            Self::Webhook { .. } => {
+                let payload = serde_json::json!({
+                    "kind": alert.kind.to_string(),
+                    "severity": alert.severity.to_string(),
+                    "title": alert.title,
+                    "subject": subject,
+                    "body": body,
+                    "fields": alert.fields,
+                    "ts_event": alert.ts_event.as_u64(),
+                });
+                Ok(("application/json", serde_json::to_string(&payload)?))
+            }
+            Self::Slack { channel, .. } => {
+                let mut payload = serde_json::json!({
+                    "text": if body.is_empty() {
+                        format!("*{subject}*")
+                    } else {
+                        format!("*{subject}*\n```{body}```")
+                    },
+                });
+                if let Some(channel) = channel {
+                    payload["channel"] = serde_json::Value::String(channel.clone());
+                }
+                Ok(("application/json", serde_json::to_string(&payload)?))
+            }
+            Self::Email { from, to } => {
+                let subject = subject.replace(['\r', '\n'], " ");
+                let message = format!(
+                    "From: {from}\r\nTo: {}\r\nSubject: {subject}\r\n\
+                     Content-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+                    to.join(", "),
+                    body.replace('\n', "\r\n"),
+                );
+                Ok(("message/rfc822", message))
+            }
+        }
+    }
+}
+
+/// Delivers rendered alerts.
+pub trait AlertTransport {
+    /// Delivers the `delivery` to its channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery fails.
+    fn deliver(&self, delivery: &AlertDelivery) -> anyhow::Result<()>;
+}
+
+/// Routes matching alerts to a channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertRoute {
+    /// The unique name of the route.
+    pub name: String,
+    /// The alert kinds to deliver (all kinds when empty).
+    pub kinds: Vec<AlertKind>,
+    pub min_severity: AlertSeverity,
+    pub channel: AlertChannel,
+    pub template: AlertTemplate,
+    /// The maximum number of alerts delivered per rate limit window.
+    pub max_alerts: usize,
+    /// The rate limit window in nanoseconds.
+    pub window_ns: u64,
+}
+
+impl AlertRoute {
+    /// Returns whether the route delivers the `alert`.
+    #[must_use]
+    pub fn matches(&self, alert: &Alert) -> bool {
+        alert.severity >= self.min_severity
+            && (self.kinds.is_empty() || self.kinds.contains(&alert.kind))
+    }
+}
+
+/// Delivery statistics for a route.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AlertRouteStats {
+    pub delivered: u64,
+    /// The number of alerts dropped by the rate limit.
+    pub suppressed: u64,
+    pub failed: u64,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct RouteState {
+    sent: VecDeque<UnixNanos>,
+    /// Alerts suppressed since the last delivery.
+    pending_suppressed: u64,
+    stats: AlertRouteStats,
+}
+
+/// Subscribes to operational events and delivers alerts through the configured routes.
+#[derive(Clone)]
+pub struct AlertNotifier {
+    routes: Rc<Vec<AlertRoute>>,
+    transport: Rc<dyn AlertTransport>,
+    state: Rc<RefCell<IndexMap<String, RouteState>>>,
+    handlers: Rc<RefCell<Option<AlertHandlers>>>,
+}
+
+struct AlertHandlers {
+    order_events: TypedHandler<OrderEventAny>,
+    drawdown: ShareableMessageHandler,
+    integrity: ShareableMessageHandler,
+}
+
+impl Debug for AlertNotifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(AlertNotifier))
+            .field("routes", &self.routes)
+            .field("subscribed", &self.handlers.borrow().is_some())
+            .finish()
+    }
+}
+
+impl AlertNotifier {
+    const ORDER_EVENTS_PATTERN: &str = "events.order.*";
+    const DRAWDOWN_PATTERN: &str = "events.risk.drawdown.*";
+
+    /// Creates a new [`AlertNotifier`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a route name is duplicated, or a route has a zero rate limit.
+    pub fn new(routes: Vec<AlertRoute>, transport: Rc<dyn AlertTransport>) -> anyhow::Result<Self> {
+        let mut state = IndexMap::with_capacity(routes.len());
+        for route in &routes {
+            anyhow::ensure!(
+                route.max_alerts > 0 && route.window_ns > 0,
+                "Route '{}' must have a positive rate limit",
+                route.name
+            );
+            anyhow::ensure!(
+                state
+                    .insert(route.name.clone(), RouteState::default())
+                    .is_none(),
+                "Duplicate route name '{}'",
+                route.name
+            );
+        }
+
+        Ok(Self {
+            routes: Rc::new(routes),
+            transport,
+            state: Rc::new(RefCell::new(state)),
+            handlers: Rc::new(RefCell::new(None)),
+        })
+    }
+
+    /// Returns the delivery statistics for the route `name`.
+    #[must_use]
+    pub fn stats(&self, name: &str) -> Option<AlertRouteStats> {
+        self.state.borrow().get(name).map(|s| s.stats.clone())
+    }
+
+    /// Subscribes to order events, drawdown breaches and cache integrity reports.
+    pub fn subscribe(&self) {
+        if self.handlers.borrow().is_some() {
+            log::warn!("Alert notifier already subscribed");
+            return;
+        }
+
+        let notifier = self.clone();
+        let order_events = TypedHandler::from(move |event: &OrderEventAny| {
+            if let Some(alert) = Alert::from_order_event(event) {
+                notifier.notify(&alert);
+            }
+        });
+        let notifier = self.clone();
+        let drawdown = ShareableMessageHandler::from_typed(move |event: &DrawdownBreached| {
+            notifier.notify(&Alert::from_drawdown(event));
+        });
+        let notifier = self.clone();
+        let integrity = ShareableMessageHandler::from_typed(move |report: &IntegrityReport| {
+            if let Some(alert) = Alert::from_integrity(report) {
+                notifier.notify(&alert);
+            }
+        });
+
+        msgbus::subscribe_order_events(
+            Self::ORDER_EVENTS_PATTERN.into(),
+            order_events.clone(),
+            None,
+        );
+        msgbus::subscribe_any(Self::DRAWDOWN_PATTERN.into(), drawdown.clone(), None);
+        msgbus::subscribe_any(get_integrity_topic().into(), integrity.clone(), None);
+
+        *self.handlers.borrow_mut() = Some(AlertHandlers {
+            order_events,
+            drawdown,
+            integrity,
+        });
+    }
+
+    /// Unsubscribes from all alert sources.
+    pub fn unsubscribe(&self) {
+        let Some(handlers) = self.handlers.borrow_mut().take() else {
+            return;
+        };
+        msgbus::unsubscribe_order_events(Self::ORDER_EVENTS_PATTERN.into(), &handlers.order_events);
+        msgbus::unsubscribe_any(Self::DRAWDOWN_PATTERN.into(), handlers.drawdown);
+        msgbus::unsubscribe_any(get_integrity_topic().into(), handlers.integrity);
+    }
+
+    /// Delivers the `alert` through every matching route within its rate limit, returning the
+    /// number of routes it was delivered to.
+    pub fn notify(&self, alert: &Alert) -> usize {
+        let mut delivered = 0;
+        for route in self.routes.iter().filter(|route| route.matches(alert)) {
+            let mut state = self.state.borrow_mut();
+            let Some(state) = state.get_mut(&route.name) else {
+                continue;
+            };
+
+            let window_start = alert.ts_event.as_u64().saturating_sub(route.window_ns);
+            while state
+                .sent
+                .front()
+                .is_some_and(|ts| ts.as_u64() <= window_start)
+            {
+                state.sent.pop_front();
+            }
+            if state.sent.len() >= route.max_alerts {
+                state.pending_suppressed += 1;
+                state.stats.suppressed += 1;
+                continue;
+            }
+
+            let mut alert = alert.clone();
+            if state.pending_suppressed > 0 {
+                alert = alert.with_field("suppressed", state.pending_suppressed);
+            }
+            let result = route.channel.render(&alert, &route.template).and_then(
+                |(content_type, payload)| {
+                    self.transport.deliver(&AlertDelivery {
+                        route: route.name.clone(),
+                        channel: route.channel.clone(),
+                        content_type,
+                        payload,
+                    })
+                },
+            );
+
+            state.sent.push_back(alert.ts_event);
+            match result {
+                Ok(()) => {
+                    state.pending_suppressed = 0;
+                    state.stats.delivered += 1;
+                    delivered += 1;
+                }
+                Err(e) => {
+                    log::error!("Failed to deliver alert via route '{}': {e}", route.name);
+                    state.stats.failed += 1;
+                    state.stats.last_error = Some(e.to_string());
+                }
+            }
+        }
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        events::OrderRejected,
+        identifiers::{StrategyId, TraderId},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{msgbus::switchboard::get_event_orders_topic, risk::DrawdownLimitKind};
+
+    #[derive(Default)]
+    struct RecordingTransport(RefCell<Vec<AlertDelivery>>);
+
+    impl AlertTransport for RecordingTransport {
+        fn deliver(&self, delivery: &AlertDelivery) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(delivery.clone());
+            Ok(())
+        }
+    }
+
+    fn route(name: &str, channel: AlertChannel, max_alerts: usize) -> AlertRoute {
+        AlertRoute {
+            name: name.to_string(),
+            kinds: Vec::new(),
+            min_severity: AlertSeverity::Warning,
+            channel,
+            template: AlertTemplate::default(),
+            max_alerts,
+            window_ns: 1_000,
+        }
+    }
+
+    fn alert(ts: u64) -> Alert {
+        Alert::new(
+            AlertKind::Fault,
+            AlertSeverity::Critical,
+            "Panic in ExecClient",
+            UnixNanos::from(ts),
+        )
+        .with_field("message", "boom")
+    }
+
+    #[rstest]
+    fn test_render_template() {
+        let rendered = render_template("{severity}: {title} ({message}) {unknown} {", &alert(1));
+        assert_eq!(rendered, "CRITICAL: Panic in ExecClient (boom) {unknown} {");
+    }
+
+    #[rstest]
+    fn test_render_channels() {
+        let template = AlertTemplate::default();
+        let slack = AlertChannel::Slack {
+            webhook_url: "https://hooks.slack.com/x".to_string(),
+            channel: Some("#alerts".to_string()),
+        };
+        let (content_type, payload) = slack.render(&alert(1), &template).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(content_type, "application/json");
+        assert_eq!(value["channel"], "#alerts");
+        assert_eq!(
+            value["text"],
+            "*[CRITICAL] Panic in ExecClient*\n```message: boom```"
+        );
+
+        let email = AlertChannel::Email {
+            from: "bot@example.com".to_string(),
+            to: vec!["ops@example.com".to_string()],
+        };
+        let (_, message) = email.render(&alert(1), &template).unwrap();
+        assert!(message.starts_with("From: bot@example.com\r\nTo: ops@example.com\r\n"));
+        assert!(message.contains("Subject: [CRITICAL] Panic in ExecClient\r\n"));
+        assert!(message.ends_with("\r\n\r\nmessage: boom\r\n"));
+    }
+
+    #[rstest]
+    fn test_notify_rate_limits_and_reports_suppressed() {
+        let transport = Rc::new(RecordingTransport::default());
+        let webhook = AlertChannel::Webhook {
+            url: "https://example.com/hook".to_string(),
+        };
+        let notifier =
+            AlertNotifier::new(vec![route("ops", webhook, 2)], transport.clone()).unwrap();
+
+        assert_eq!(notifier.notify(&alert(100)), 1);
+        assert_eq!(notifier.notify(&alert(200)), 1);
+        assert_eq!(notifier.notify(&alert(300)), 0);
+        assert_eq!(notifier.notify(&alert(1_150)), 1);
+
+        let stats = notifier.stats("ops").unwrap();
+        assert_eq!(stats.delivered, 3);
+        assert_eq!(stats.suppressed, 1);
+        let deliveries = transport.0.borrow();
+        let last: serde_json::Value = serde_json::from_str(&deliveries[2].payload).unwrap();
+        assert_eq!(last["fields"]["suppressed"], "1");
+    }
+
+    #[rstest]
+    fn test_route_filters_by_kind_and_severity() {
+        let transport = Rc::new(RecordingTransport::default());
+        let mut drawdown_only = route(
+            "risk",
+            AlertChannel::Webhook {
+                url: "https://example.com/risk".to_string(),
+            },
+            10,
+        );
+        drawdown_only.kinds = vec![AlertKind::DrawdownBreach];
+        let notifier = AlertNotifier::new(vec![drawdown_only], transport).unwrap();
+
+        assert_eq!(notifier.notify(&alert(1)), 0);
+
+        let info = Alert::new(
+            AlertKind::DrawdownBreach,
+            AlertSeverity::Info,
+            "test",
+            UnixNanos::from(1),
+        );
+        assert_eq!(notifier.notify(&info), 0);
+    }
+
+    #[rstest]
+    fn test_subscribed_sources_raise_alerts() {
+        let transport = Rc::new(RecordingTransport::default());
+        let webhook = AlertChannel::Webhook {
+            url: "https://example.com/hook".to_string(),
+        };
+        let notifier =
+            AlertNotifier::new(vec![route("ops", webhook, 10)], transport.clone()).unwrap();
+        notifier.subscribe();
+
+        let strategy_id = StrategyId::from("S-001");
+        let rejected = OrderEventAny::Rejected(OrderRejected {
+            strategy_id,
+            reason: "INSUFFICIENT_MARGIN".into(),
+            ..Default::default()
+        });
+        msgbus::publish_order_event(get_event_orders_topic(strategy_id), &rejected);
+
+        let breach = DrawdownBreached {
+            trader_id: TraderId::from("TRADER-001"),
+            strategy_id,
+            kind: DrawdownLimitKind::Daily,
+            drawdown: 0.06,
+            limit: 0.05,
+            pnl: -600.0,
+            event_id: UUID4::new(),
+            ts_event: UnixNanos::from(2),
+            ts_init: UnixNanos::from(2),
+        };
+        msgbus::publish_any("events.risk.drawdown.S-001".into(), &breach);
+
+        let deliveries = transport.0.borrow();
+        assert_eq!(deliveries.len(), 2);
+        assert!(deliveries[0].payload.contains("INSUFFICIENT_MARGIN"));
+        assert!(deliveries[1].payload.contains("DRAWDOWN_BREACH"));
+        drop(deliveries);
+
+        notifier.unsubscribe();
+        msgbus::publish_order_event(get_event_orders_topic(strategy_id), &rejected);
+        assert_eq!(transport.0.borrow().len(), 2);
+    }
+}
@@ -0,0 +1,27 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming analytics for quantitative strategies.
+
+pub mod pairs;
+pub mod realized_vol;
+
+// Re-exports
+pub use crate::analytics::pairs::{
+    HedgeRatioMethod, PairSpread, PairSpreadState, PairSpreadValue, get_pair_spread_topic,
+};
+pub use crate::analytics::realized_vol::{
+    BarVolatility, RealizedVolEstimator, RealizedVolatility, TickVolatility, get_realized_vol_topic,
+};
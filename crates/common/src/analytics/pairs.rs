@@ -0,0 +1,618 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pairs and cointegration analytics.
+//!
+//! [`PairSpread`] estimates the hedge ratio between two instruments, regressing the price of
+//! instrument A on the price of instrument B either by rolling OLS or by a Kalman filter, and
+//! computes the z-score of the resulting spread over a rolling window. It is updated like an
+//! indicator from aligned bars, quotes or raw prices, and its state can be snapshotted with
+//! [`PairSpread::save_state`] and restored with [`PairSpread::load_state`] (for example from an
+//! actor's `on_save` / `on_load`) so live estimates survive restarts.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_core::{UnixNanos, correctness::FAILED};
+use nautilus_model::{
+    data::{Bar, QuoteTick},
+    enums::PriceType,
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::msgbus::{self, MStr, Topic};
+
+/// Returns the topic pair spread values for instruments `a` and `b` are published on.
+#[must_use]
+pub fn get_pair_spread_topic(a: InstrumentId, b: InstrumentId) -> MStr<Topic> {
+    format!("data.PairSpread.{a}.{b}").into()
+}
+
+/// The method used to estimate the hedge ratio of a pair.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum HedgeRatioMethod {
+    /// Ordinary least squares over the rolling window.
+    RollingOls,
+    /// A Kalman filter over the (hedge ratio, intercept) state.
+    Kalman {
+        /// The state transition covariance scale, in (0, 1).
+        delta: f64,
+        /// The observation noise variance.
+        observation_var: f64,
+    },
+}
+
+impl HedgeRatioMethod {
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Self::Kalman {
+            delta,
+            observation_var,
+        } = *self
+        {
+            anyhow::ensure!(
+                delta > 0.0 && delta < 1.0,
+                "Kalman `delta` must be in (0, 1), was {delta}"
+            );
+            anyhow::ensure!(
+                observation_var > 0.0 && observation_var.is_finite(),
+                "Kalman `observation_var` must be positive, was {observation_var}"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Represents the hedge ratio and spread z-score of an instrument pair.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PairSpreadValue {
+    pub instrument_a: InstrumentId,
+    pub instrument_b: InstrumentId,
+    /// The units of B to hedge one unit of A.
+    pub hedge_ratio: f64,
+    pub intercept: f64,
+    /// The spread `a - hedge_ratio * b - intercept`.
+    pub spread: f64,
+    /// The z-score of the spread over the window (when the window is full and not flat).
+    pub zscore: Option<f64>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PairSpreadValue {
+    /// Publishes the value on its pair spread topic.
+    pub fn publish(&self) {
+        msgbus::publish_any(
+            get_pair_spread_topic(self.instrument_a, self.instrument_b),
+            self,
+        );
+    }
+}
+
+impl Display for PairSpreadValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_a={}, instrument_b={}, hedge_ratio={:.6}, spread={:.6}, zscore={})",
+            stringify!(PairSpreadValue),
+            self.instrument_a,
+            self.instrument_b,
+            self.hedge_ratio,
+            self.spread,
+            self.zscore
+                .map_or_else(|| "None".to_string(), |z| format!("{z:.4}")),
+        )
+    }
+}
+
+/// The serializable state of a [`PairSpread`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PairSpreadState {
+    pub instrument_a: InstrumentId,
+    pub instrument_b: InstrumentId,
+    pub method: HedgeRatioMethod,
+    pub period: usize,
+    /// The (a, b) price observations in the OLS window.
+    pub observations: Vec<(f64, f64)>,
+    pub spreads: Vec<f64>,
+    pub hedge_ratio: Option<f64>,
+    pub intercept: f64,
+    /// The Kalman state covariance (row major).
+    pub covariance: [f64; 4],
+    pub count: usize,
+}
+
+/// Streaming hedge ratio and spread z-score for an instrument pair.
+#[derive(Clone, Debug)]
+pub struct PairSpread {
+    instrument_a: InstrumentId,
+    instrument_b: InstrumentId,
+    method: HedgeRatioMethod,
+    period: usize,
+    observations: VecDeque<(f64, f64)>,
+    spreads: VecDeque<f64>,
+    hedge_ratio: Option<f64>,
+    intercept: f64,
+    covariance: [f64; 4],
+    count: usize,
+    pending_a: Option<(UnixNanos, f64)>,
+    pending_b: Option<(UnixNanos, f64)>,
+    mid_a: Option<f64>,
+    mid_b: Option<f64>,
+    last: Option<PairSpreadValue>,
+}
+
+impl PairSpread {
+    /// Creates a new [`PairSpread`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instruments are the same, `period` is less than 2, or the
+    /// Kalman parameters are invalid.
+    pub fn new_checked(
+        instrument_a: InstrumentId,
+        instrument_b: InstrumentId,
+        method: HedgeRatioMethod,
+        period: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            instrument_a != instrument_b,
+            "Pair instruments must differ, both were {instrument_a}"
+        );
+        anyhow::ensure!(period >= 2, "`period` must be at least 2, was {period}");
+        method.validate()?;
+
+        Ok(Self {
+            instrument_a,
+            instrument_b,
+            method,
+            period,
+            observations: VecDeque::with_capacity(period + 1),
+            spreads: VecDeque::with_capacity(period + 1),
+            hedge_ratio: None,
+            intercept: 0.0,
+            covariance: [1.0, 0.0, 0.0, 1.0],
+            count: 0,
+            pending_a: None,
+            pending_b: None,
+            mid_a: None,
+            mid_b: None,
+            last: None,
+        })
+    }
+
+    /// Creates a new [`PairSpread`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arguments are invalid (see [`Self::new_checked`]).
+    #[must_use]
+    pub fn new(
+        instrument_a: InstrumentId,
+        instrument_b: InstrumentId,
+        method: HedgeRatioMethod,
+        period: usize,
+    ) -> Self {
+        Self::new_checked(instrument_a, instrument_b, method, period).expect(FAILED)
+    }
+
+    /// Returns the current hedge ratio (units of B per unit of A).
+    #[must_use]
+    pub const fn hedge_ratio(&self) -> Option<f64> {
+        self.hedge_ratio
+    }
+
+    /// Returns the latest value.
+    #[must_use]
+    pub const fn value(&self) -> Option<&PairSpreadValue> {
+        self.last.as_ref()
+    }
+
+    /// Returns whether the spread window is full.
+    #[must_use]
+    pub fn initialized(&self) -> bool {
+        self.spreads.len() >= self.period
+    }
+
+    /// Updates the pair with the `bar`, once bars for both legs with the same `ts_event`
+    /// have been received.
+    pub fn handle_bar(&mut self, bar: &Bar) -> Option<PairSpreadValue> {
+        let instrument_id = bar.bar_type.instrument_id();
+        let close = (bar.ts_event, bar.close.as_f64());
+        if instrument_id == self.instrument_a {
+            self.pending_a = Some(close);
+        } else if instrument_id == self.instrument_b {
+            self.pending_b = Some(close);
+        } else {
+            return None;
+        }
+
+        match (self.pending_a, self.pending_b) {
+            (Some((ts_a, a)), Some((ts_b, b))) if ts_a == ts_b => {
+                self.pending_a = None;
+                self.pending_b = None;
+                self.update_raw(a, b, bar.ts_event, bar.ts_init)
+            }
+            _ => None,
+        }
+    }
+
+    /// Updates the pair with the mid price of the `quote`, once quotes for both legs have
+    /// been received.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> Option<PairSpreadValue> {
+        let mid = quote.extract_price(PriceType::Mid).as_f64();
+        if quote.instrument_id == self.instrument_a {
+            self.mid_a = Some(mid);
+        } else if quote.instrument_id == self.instrument_b {
+            self.mid_b = Some(mid);
+        } else {
+            return None;
+        }
+        let (a, b) = (self.mid_a?, self.mid_b?);
+        self.update_raw(a, b, quote.ts_event, quote.ts_init)
+    }
+
+    /// Updates the pair with an aligned observation of both prices.
+    pub fn update_raw(
+        &mut self,
+        a: f64,
+        b: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Option<PairSpreadValue> {
+        if !a.is_finite() || !b.is_finite() {
+            return None;
+        }
+        self.count += 1;
+
+        match self.method {
+            HedgeRatioMethod::RollingOls => {
+                self.observations.push_back((a, b));
+                if self.observations.len() > self.period {
+                    self.observations.pop_front();
+                }
+                self.estimate_ols();
+            }
+            HedgeRatioMethod::Kalman {
+                delta,
+                observation_var,
+            } => self.estimate_kalman(a, b, delta, observation_var),
+        }
+
+        let hedge_ratio = self.hedge_ratio?;
+        let spread = a - hedge_ratio * b - self.intercept;
+        self.spreads.push_back(spread);
+        if self.spreads.len() > self.period {
+            self.spreads.pop_front();
+        }
+
+        let value = PairSpreadValue {
+            instrument_a: self.instrument_a,
+            instrument_b: self.instrument_b,
+            hedge_ratio,
+            intercept: self.intercept,
+            spread,
+            zscore: self.zscore(spread),
+            ts_event,
+            ts_init,
+        };
+        self.last = Some(value);
+        Some(value)
+    }
+
+    fn estimate_ols(&mut self) {
+        if self.observations.len() < 2 {
+            return;
+        }
+        let n = self.observations.len() as f64;
+        let mean_a = self.observations.iter().map(|(a, _)| a).sum::<f64>() / n;
+        let mean_b = self.observations.iter().map(|(_, b)| b).sum::<f64>() / n;
+        let (cov, var) = self
+            .observations
+            .iter()
+            .fold((0.0, 0.0), |(cov, var), (a, b)| {
+                (
+                    cov + (a - mean_a) * (b - mean_b),
+                    var + (b - mean_b).powi(2),
+                )
+            });
+        // A flat B leg leaves the previous estimate in place
+        if var > f64::EPSILON {
+            let beta = cov / var;
+            self.hedge_ratio = Some(beta);
+            self.intercept = mean_a - beta * mean_b;
+        }
+    }
+
+    fn estimate_kalman(&mut self, a: f64, b: f64, delta: f64, observation_var: f64) {
+        let beta = self.hedge_ratio.unwrap_or(0.0);
+        let alpha = self.intercept;
+
+        // Predict: R = P + Vw, with Vw = delta / (1 - delta) * I
+        let w = delta / (1.0 - delta);
+        let [p00, p01, p10, p11] = self.covariance;
+        let (r00, r01, r10, r11) = (p00 + w, p01, p10, p11 + w);
+
+        // Observation a = beta * b + alpha, so H = [b, 1]
+        let error = a - (beta * b + alpha);
+        let rh0 = r00 * b + r01;
+        let rh1 = r10 * b + r11;
+        let q = b * rh0 + rh1 + observation_var;
+        let (k0, k1) = (rh0 / q, rh1 / q);
+
+        // Update: theta += K * e, P = R - K * H * R
+        let hr0 = b * r00 + r10;
+        let hr1 = b * r01 + r11;
+        self.covariance = [
+            r00 - k0 * hr0,
+            r01 - k0 * hr1,
+            r10 - k1 * hr0,
+            r11 - k1 * hr1,
+        ];
+        self.hedge_ratio = Some(beta + k0 * error);
+        self.intercept = alpha + k1 * error;
+    }
+
+    fn zscore(&self, spread: f64) -> Option<f64> {
+        if !self.initialized() {
+            return None;
+        }
+        let n = self.spreads.len() as f64;
+        let mean = self.spreads.iter().sum::<f64>() / n;
+        let var = self.spreads.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let std = var.sqrt();
+        (std > f64::EPSILON).then(|| (spread - mean) / std)
+    }
+
+    /// Returns a snapshot of the estimator state.
+    #[must_use]
+    pub fn state(&self) -> PairSpreadState {
+        PairSpreadState {
+            instrument_a: self.instrument_a,
+            instrument_b: self.instrument_b,
+            method: self.method,
+            period: self.period,
+            observations: self.observations.iter().copied().collect(),
+            spreads: self.spreads.iter().copied().collect(),
+            hedge_ratio: self.hedge_ratio,
+            intercept: self.intercept,
+            covariance: self.covariance,
+            count: self.count,
+        }
+    }
+
+    /// Restores the estimator from the `state` snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot was taken from a differently configured estimator.
+    pub fn restore(&mut self, state: PairSpreadState) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            state.instrument_a == self.instrument_a && state.instrument_b == self.instrument_b,
+            "State is for pair {}/{}, expected {}/{}",
+            state.instrument_a,
+            state.instrument_b,
+            self.instrument_a,
+            self.instrument_b,
+        );
+        anyhow::ensure!(
+            state.method == self.method && state.period == self.period,
+            "State configuration {:?} (period {}) does not match {:?} (period {})",
+            state.method,
+            state.period,
+            self.method,
+            self.period,
+        );
+
+        self.reset();
+        self.observations = state.observations.into_iter().collect();
+        self.spreads = state.spreads.into_iter().collect();
+        self.hedge_ratio = state.hedge_ratio;
+        self.intercept = state.intercept;
+        self.covariance = state.covariance;
+        self.count = state.count;
+        Ok(())
+    }
+
+    /// Serializes the estimator state to MessagePack bytes, which hold floats exactly so the
+    /// restored estimates match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn save_state(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(rmp_serde::to_vec_named(&self.state())?)
+    }
+
+    /// Restores the estimator from MessagePack bytes produced by [`Self::save_state`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes cannot be deserialized or the state does not match.
+    pub fn load_state(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        let state: PairSpreadState = rmp_serde::from_slice(bytes)?;
+        self.restore(state)
+    }
+
+    /// Resets the estimator to its initial state.
+    pub fn reset(&mut self) {
+        self.observations.clear();
+        self.spreads.clear();
+        self.hedge_ratio = None;
+        self.intercept = 0.0;
+        self.covariance = [1.0, 0.0, 0.0, 1.0];
+        self.count = 0;
+        self.pending_a = None;
+        self.pending_b = None;
+        self.mid_a = None;
+        self.mid_b = None;
+        self.last = None;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BarType,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ids() -> (InstrumentId, InstrumentId) {
+        (
+            InstrumentId::from("ESZ4.XCME"),
+            InstrumentId::from("NQZ4.XCME"),
+        )
+    }
+
+    fn bar(bar_type: &str, close: &str, ts: u64) -> Bar {
+        let price = Price::from(close);
+        Bar::new(
+            BarType::from(bar_type),
+            price,
+            price,
+            price,
+            price,
+            Quantity::from(1),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    #[rstest]
+    fn test_invalid_config() {
+        let (a, b) = ids();
+        assert!(PairSpread::new_checked(a, a, HedgeRatioMethod::RollingOls, 10).is_err());
+        assert!(PairSpread::new_checked(a, b, HedgeRatioMethod::RollingOls, 1).is_err());
+        let kalman = HedgeRatioMethod::Kalman {
+            delta: 1.5,
+            observation_var: 1.0,
+        };
+        assert!(PairSpread::new_checked(a, b, kalman, 10).is_err());
+    }
+
+    #[rstest]
+    fn test_rolling_ols_exact_fit() {
+        let (a, b) = ids();
+        let mut pair = PairSpread::new(a, b, HedgeRatioMethod::RollingOls, 5);
+        for i in 0..5_u32 {
+            let x = 100.0 + f64::from(i);
+            pair.update_raw(
+                2.0 * x + 1.0,
+                x,
+                UnixNanos::from(u64::from(i)),
+                UnixNanos::default(),
+            );
+        }
+
+        let value = pair.value().unwrap();
+        assert!((value.hedge_ratio - 2.0).abs() < 1e-9);
+        assert!((value.intercept - 1.0).abs() < 1e-6);
+        assert!(value.spread.abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_zscore_on_spread_deviation() {
+        let (a, b) = ids();
+        let mut pair = PairSpread::new(a, b, HedgeRatioMethod::RollingOls, 4);
+        for i in 0..4_u32 {
+            let x = 100.0 + f64::from(i);
+            pair.update_raw(2.0 * x, x, UnixNanos::default(), UnixNanos::default());
+        }
+        let value = pair
+            .update_raw(
+                2.0 * 104.0 + 3.0,
+                104.0,
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert!(pair.initialized());
+        assert!(value.spread > 0.0);
+        assert!(value.zscore.unwrap() > 0.0);
+    }
+
+    #[rstest]
+    fn test_kalman_converges() {
+        let (a, b) = ids();
+        let method = HedgeRatioMethod::Kalman {
+            delta: 1e-4,
+            observation_var: 1e-3,
+        };
+        let mut pair = PairSpread::new(a, b, method, 20);
+        for i in 0..500_u32 {
+            let x = 10.0 + (f64::from(i) * 0.3).sin();
+            pair.update_raw(1.5 * x, x, UnixNanos::default(), UnixNanos::default());
+        }
+
+        assert!((pair.hedge_ratio().unwrap() - 1.5).abs() < 1e-2);
+    }
+
+    #[rstest]
+    fn test_handle_bar_aligns_legs() {
+        let (a, b) = ids();
+        let mut pair = PairSpread::new(a, b, HedgeRatioMethod::RollingOls, 2);
+        assert!(
+            pair.handle_bar(&bar("ESZ4.XCME-1-MINUTE-LAST-EXTERNAL", "200.00", 1))
+                .is_none()
+        );
+        // A misaligned bar for the other leg does not update
+        assert!(
+            pair.handle_bar(&bar("NQZ4.XCME-1-MINUTE-LAST-EXTERNAL", "100.00", 2))
+                .is_none()
+        );
+        assert!(
+            pair.handle_bar(&bar("ESZ4.XCME-1-MINUTE-LAST-EXTERNAL", "200.00", 2))
+                .is_none()
+        );
+        pair.handle_bar(&bar("ESZ4.XCME-1-MINUTE-LAST-EXTERNAL", "202.00", 3));
+        let value = pair
+            .handle_bar(&bar("NQZ4.XCME-1-MINUTE-LAST-EXTERNAL", "101.00", 3))
+            .unwrap();
+
+        assert!((value.hedge_ratio - 2.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_state_round_trip() {
+        let (a, b) = ids();
+        let method = HedgeRatioMethod::Kalman {
+            delta: 1e-4,
+            observation_var: 1e-3,
+        };
+        let mut pair = PairSpread::new(a, b, method, 5);
+        for i in 0..20_u32 {
+            let x = 50.0 + f64::from(i % 7);
+            pair.update_raw(0.8 * x + 2.0, x, UnixNanos::default(), UnixNanos::default());
+        }
+        let bytes = pair.save_state().unwrap();
+
+        let mut restored = PairSpread::new(a, b, method, 5);
+        restored.load_state(&bytes).unwrap();
+        assert_eq!(restored.state(), pair.state());
+
+        let expected = pair.update_raw(42.0, 51.0, UnixNanos::default(), UnixNanos::default());
+        let actual = restored.update_raw(42.0, 51.0, UnixNanos::default(), UnixNanos::default());
+        assert_eq!(actual, expected);
+
+        let mut other = PairSpread::new(a, b, HedgeRatioMethod::RollingOls, 5);
+        assert!(other.load_state(&bytes).is_err());
+    }
+}
@@ -0,0 +1,509 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Realized volatility estimators.
+//!
+//! [`BarVolatility`] estimates volatility over a rolling window of bars with the close-to-close,
+//! Parkinson (high-low) or Garman-Klass (OHLC) estimators, annualized by the number of bars per
+//! year. [`TickVolatility`] estimates it from tick returns as realized variance or bipower
+//! variation (robust to jumps), annualized by the time spanned by the window.
+//!
+//! Both are updated like indicators and produce [`RealizedVolatility`] values, which can be
+//! published on the message bus as derived data (see [`get_realized_vol_topic`]).
+
+use std::{collections::VecDeque, f64::consts::LN_2, fmt::Display};
+
+use nautilus_core::{UnixNanos, correctness::FAILED, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display as StrumDisplay;
+
+use crate::msgbus::{self, MStr, Topic};
+
+const NANOSECONDS_IN_YEAR: f64 = 365.25 * 86_400.0 * NANOSECONDS_IN_SECOND as f64;
+
+/// Returns the topic realized volatility from the `estimator` for the `instrument_id` is
+/// published on.
+#[must_use]
+pub fn get_realized_vol_topic(
+    instrument_id: InstrumentId,
+    estimator: RealizedVolEstimator,
+) -> MStr<Topic> {
+    format!("data.RealizedVolatility.{instrument_id}.{estimator}").into()
+}
+
+/// A realized volatility estimator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RealizedVolEstimator {
+    /// The standard deviation of close-to-close log returns (bars).
+    CloseToClose,
+    /// The high-low range estimator (bars).
+    Parkinson,
+    /// The open-high-low-close estimator (bars).
+    GarmanKlass,
+    /// The sum of squared log returns (ticks).
+    RealizedVariance,
+    /// The scaled sum of products of adjacent absolute log returns (ticks).
+    BipowerVariation,
+}
+
+impl RealizedVolEstimator {
+    /// Returns whether the estimator is computed from bars (otherwise from ticks).
+    #[must_use]
+    pub const fn is_bar_based(self) -> bool {
+        matches!(
+            self,
+            Self::CloseToClose | Self::Parkinson | Self::GarmanKlass
+        )
+    }
+}
+
+/// Represents an annualized realized volatility estimate for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealizedVolatility {
+    pub instrument_id: InstrumentId,
+    pub estimator: RealizedVolEstimator,
+    /// The annualized volatility.
+    pub value: f64,
+    /// The number of observations in the window.
+    pub window: usize,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl RealizedVolatility {
+    /// Publishes the estimate on its realized volatility topic.
+    pub fn publish(&self) {
+        msgbus::publish_any(
+            get_realized_vol_topic(self.instrument_id, self.estimator),
+            self,
+        );
+    }
+}
+
+impl Display for RealizedVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, estimator={}, value={:.6})",
+            stringify!(RealizedVolatility),
+            self.instrument_id,
+            self.estimator,
+            self.value,
+        )
+    }
+}
+
+/// Streaming realized volatility over a rolling window of bars.
+#[derive(Clone, Debug)]
+pub struct BarVolatility {
+    estimator: RealizedVolEstimator,
+    period: usize,
+    periods_per_year: f64,
+    terms: VecDeque<f64>,
+    prev_close: Option<f64>,
+    last_ts: Option<(UnixNanos, UnixNanos)>,
+    instrument_id: Option<InstrumentId>,
+}
+
+impl BarVolatility {
+    /// Creates a new [`BarVolatility`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the estimator is not bar based, `period` is less than 2, or
+    /// `periods_per_year` is not positive.
+    pub fn new_checked(
+        estimator: RealizedVolEstimator,
+        period: usize,
+        periods_per_year: f64,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            estimator.is_bar_based(),
+            "Estimator {estimator} is not bar based"
+        );
+        anyhow::ensure!(period >= 2, "`period` must be at least 2, was {period}");
+        anyhow::ensure!(
+            periods_per_year > 0.0 && periods_per_year.is_finite(),
+            "`periods_per_year` must be positive, was {periods_per_year}"
+        );
+
+        Ok(Self {
+            estimator,
+            period,
+            periods_per_year,
+            terms: VecDeque::with_capacity(period + 1),
+            prev_close: None,
+            last_ts: None,
+            instrument_id: None,
+        })
+    }
+
+    /// Creates a new [`BarVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arguments are invalid (see [`Self::new_checked`]).
+    #[must_use]
+    pub fn new(estimator: RealizedVolEstimator, period: usize, periods_per_year: f64) -> Self {
+        Self::new_checked(estimator, period, periods_per_year).expect(FAILED)
+    }
+
+    /// Returns the estimator.
+    #[must_use]
+    pub const fn estimator(&self) -> RealizedVolEstimator {
+        self.estimator
+    }
+
+    /// Returns whether the window is full.
+    #[must_use]
+    pub fn initialized(&self) -> bool {
+        self.terms.len() >= self.period
+    }
+
+    /// Updates the estimator with the `bar`.
+    pub fn handle_bar(&mut self, bar: &Bar) {
+        let open = bar.open.as_f64();
+        let high = bar.high.as_f64();
+        let low = bar.low.as_f64();
+        let close = bar.close.as_f64();
+        if open <= 0.0 || low <= 0.0 || close <= 0.0 {
+            return;
+        }
+
+        let term = match self.estimator {
+            RealizedVolEstimator::CloseToClose => self.prev_close.map(|prev| (close / prev).ln()),
+            RealizedVolEstimator::Parkinson => Some((high / low).ln().powi(2) / (4.0 * LN_2)),
+            RealizedVolEstimator::GarmanKlass => Some(
+                0.5 * (high / low).ln().powi(2) - (2.0 * LN_2 - 1.0) * (close / open).ln().powi(2),
+            ),
+            _ => unreachable!("checked on construction"),
+        };
+        self.prev_close = Some(close);
+        self.instrument_id = Some(bar.bar_type.instrument_id());
+        self.last_ts = Some((bar.ts_event, bar.ts_init));
+
+        if let Some(term) = term {
+            self.terms.push_back(term);
+            if self.terms.len() > self.period {
+                self.terms.pop_front();
+            }
+        }
+    }
+
+    /// Returns the annualized volatility once the window is full.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        if !self.initialized() {
+            return None;
+        }
+        let n = self.terms.len() as f64;
+        let variance = match self.estimator {
+            RealizedVolEstimator::CloseToClose => {
+                let mean = self.terms.iter().sum::<f64>() / n;
+                self.terms.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)
+            }
+            _ => self.terms.iter().sum::<f64>() / n,
+        };
+        Some((variance.max(0.0) * self.periods_per_year).sqrt())
+    }
+
+    /// Returns the current estimate as derived data.
+    #[must_use]
+    pub fn to_data(&self) -> Option<RealizedVolatility> {
+        let (ts_event, ts_init) = self.last_ts?;
+        Some(RealizedVolatility {
+            instrument_id: self.instrument_id?,
+            estimator: self.estimator,
+            value: self.value()?,
+            window: self.terms.len(),
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Resets the estimator to its initial state.
+    pub fn reset(&mut self) {
+        self.terms.clear();
+        self.prev_close = None;
+        self.last_ts = None;
+        self.instrument_id = None;
+    }
+}
+
+/// Streaming realized volatility over a rolling window of tick returns.
+#[derive(Clone, Debug)]
+pub struct TickVolatility {
+    estimator: RealizedVolEstimator,
+    period: usize,
+    /// The log returns in the window with their timestamps.
+    returns: VecDeque<(f64, UnixNanos)>,
+    last_price: Option<(f64, UnixNanos)>,
+    /// The timestamp of the price the oldest return in the window is measured from.
+    window_start: Option<UnixNanos>,
+    last_ts_init: UnixNanos,
+    instrument_id: Option<InstrumentId>,
+}
+
+impl TickVolatility {
+    /// Creates a new [`TickVolatility`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the estimator is bar based, or `period` is less than 2.
+    pub fn new_checked(estimator: RealizedVolEstimator, period: usize) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !estimator.is_bar_based(),
+            "Estimator {estimator} is not tick based"
+        );
+        anyhow::ensure!(period >= 2, "`period` must be at least 2, was {period}");
+
+        Ok(Self {
+            estimator,
+            period,
+            returns: VecDeque::with_capacity(period + 1),
+            last_price: None,
+            window_start: None,
+            last_ts_init: UnixNanos::default(),
+            instrument_id: None,
+        })
+    }
+
+    /// Creates a new [`TickVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arguments are invalid (see [`Self::new_checked`]).
+    #[must_use]
+    pub fn new(estimator: RealizedVolEstimator, period: usize) -> Self {
+        Self::new_checked(estimator, period).expect(FAILED)
+    }
+
+    /// Returns the estimator.
+    #[must_use]
+    pub const fn estimator(&self) -> RealizedVolEstimator {
+        self.estimator
+    }
+
+    /// Returns whether the window is full.
+    #[must_use]
+    pub fn initialized(&self) -> bool {
+        self.returns.len() >= self.period
+    }
+
+    /// Updates the estimator with the `trade` price.
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        self.instrument_id = Some(trade.instrument_id);
+        self.last_ts_init = trade.ts_init;
+        self.update_raw(trade.price.as_f64(), trade.ts_event);
+    }
+
+    /// Updates the estimator with the `quote` mid price.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.instrument_id = Some(quote.instrument_id);
+        self.last_ts_init = quote.ts_init;
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        self.update_raw(mid, quote.ts_event);
+    }
+
+    /// Updates the estimator with a `price` observed at `ts`.
+    pub fn update_raw(&mut self, price: f64, ts: UnixNanos) {
+        if price <= 0.0 || !price.is_finite() {
+            return;
+        }
+        if let Some((last, last_ts)) = self.last_price {
+            if self.returns.is_empty() {
+                self.window_start = Some(last_ts);
+            }
+            self.returns.push_back(((price / last).ln(), ts));
+            if self.returns.len() > self.period
+                && let Some((_, dropped_ts)) = self.returns.pop_front()
+            {
+                self.window_start = Some(dropped_ts);
+            }
+        }
+        self.last_price = Some((price, ts));
+    }
+
+    /// Returns the variance of the window (not annualized).
+    #[must_use]
+    pub fn window_variance(&self) -> Option<f64> {
+        if !self.initialized() {
+            return None;
+        }
+        let variance = match self.estimator {
+            RealizedVolEstimator::RealizedVariance => self.returns.iter().map(|(r, _)| r * r).sum(),
+            _ => {
+                let products: f64 = self
+                    .returns
+                    .iter()
+                    .zip(self.returns.iter().skip(1))
+                    .map(|((a, _), (b, _))| a.abs() * b.abs())
+                    .sum();
+                let n = self.returns.len() as f64;
+                // Scaled to the full window from its n - 1 adjacent pairs
+                std::f64::consts::FRAC_PI_2 * products * n / (n - 1.0)
+            }
+        };
+        Some(variance)
+    }
+
+    /// Returns the volatility annualized by the time spanned by the window.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        let variance = self.window_variance()?;
+        let start = self.window_start?;
+        let (_, end) = *self.returns.back()?;
+        let span = end.as_u64().saturating_sub(start.as_u64());
+        if span == 0 {
+            return None;
+        }
+        Some((variance * NANOSECONDS_IN_YEAR / span as f64).sqrt())
+    }
+
+    /// Returns the current estimate as derived data.
+    #[must_use]
+    pub fn to_data(&self) -> Option<RealizedVolatility> {
+        let (_, ts_event) = self.last_price?;
+        Some(RealizedVolatility {
+            instrument_id: self.instrument_id?,
+            estimator: self.estimator,
+            value: self.value()?,
+            window: self.returns.len(),
+            ts_event,
+            ts_init: self.last_ts_init,
+        })
+    }
+
+    /// Resets the estimator to its initial state.
+    pub fn reset(&mut self) {
+        self.returns.clear();
+        self.last_price = None;
+        self.window_start = None;
+        self.last_ts_init = UnixNanos::default();
+        self.instrument_id = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BarType,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn bar(open: &str, high: &str, low: &str, close: &str, ts: u64) -> Bar {
+        Bar::new(
+            BarType::from("AUDUSD.SIM-1-DAY-LAST-EXTERNAL"),
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from(1),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    #[rstest]
+    fn test_close_to_close() {
+        let mut vol = BarVolatility::new(RealizedVolEstimator::CloseToClose, 2, 252.0);
+        vol.handle_bar(&bar("1.00", "1.00", "1.00", "1.00", 1));
+        vol.handle_bar(&bar("1.00", "1.10", "1.00", "1.10", 2));
+        assert!(!vol.initialized());
+        vol.handle_bar(&bar("1.10", "1.10", "1.00", "1.00", 3));
+
+        let r = 1.1_f64.ln();
+        let expected = (2.0 * r * r * 252.0).sqrt();
+        assert!((vol.value().unwrap() - expected).abs() < 1e-12);
+        let data = vol.to_data().unwrap();
+        assert_eq!(data.instrument_id, InstrumentId::from("AUDUSD.SIM"));
+        assert_eq!(data.window, 2);
+    }
+
+    #[rstest]
+    fn test_parkinson_and_garman_klass() {
+        let mut parkinson = BarVolatility::new(RealizedVolEstimator::Parkinson, 2, 1.0);
+        let mut garman_klass = BarVolatility::new(RealizedVolEstimator::GarmanKlass, 2, 1.0);
+        for b in [
+            bar("1.00", "1.20", "1.00", "1.10", 1),
+            bar("1.10", "1.20", "1.00", "1.10", 2),
+        ] {
+            parkinson.handle_bar(&b);
+            garman_klass.handle_bar(&b);
+        }
+
+        let hl = 1.2_f64.ln().powi(2);
+        let co_first = 1.1_f64.ln().powi(2);
+        assert!((parkinson.value().unwrap() - (hl / (4.0 * LN_2)).sqrt()).abs() < 1e-12);
+        let gk = 0.5 * hl - (2.0 * LN_2 - 1.0) * co_first / 2.0;
+        assert!((garman_klass.value().unwrap() - gk.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_tick_realized_and_bipower_variance() {
+        let mut rv = TickVolatility::new(RealizedVolEstimator::RealizedVariance, 3);
+        let mut bv = TickVolatility::new(RealizedVolEstimator::BipowerVariation, 3);
+        let prices = [100.0, 101.0, 100.0, 101.0, 102.0];
+        for (i, price) in prices.iter().enumerate() {
+            let ts = UnixNanos::from(i as u64 * NANOSECONDS_IN_SECOND);
+            rv.update_raw(*price, ts);
+            bv.update_raw(*price, ts);
+        }
+
+        let returns: Vec<f64> = prices.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+        let window = &returns[1..];
+        let expected_rv: f64 = window.iter().map(|r| r * r).sum();
+        let expected_bv = std::f64::consts::FRAC_PI_2
+            * (window[0].abs() * window[1].abs() + window[1].abs() * window[2].abs())
+            * 3.0
+            / 2.0;
+        assert!((rv.window_variance().unwrap() - expected_rv).abs() < 1e-12);
+        assert!((bv.window_variance().unwrap() - expected_bv).abs() < 1e-12);
+
+        // The window spans the three seconds from the second to the last price
+        let expected = (expected_rv * NANOSECONDS_IN_YEAR / (3.0 * 1e9)).sqrt();
+        assert!((rv.value().unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_invalid_estimator_for_input() {
+        assert!(
+            BarVolatility::new_checked(RealizedVolEstimator::BipowerVariation, 10, 252.0).is_err()
+        );
+        assert!(TickVolatility::new_checked(RealizedVolEstimator::Parkinson, 10).is_err());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut vol = TickVolatility::new(RealizedVolEstimator::RealizedVariance, 2);
+        for i in 0..4_u64 {
+            vol.update_raw(100.0 + i as f64, UnixNanos::from(i));
+        }
+        assert!(vol.initialized());
+
+        vol.reset();
+
+        assert!(!vol.initialized());
+        assert!(vol.value().is_none());
+    }
+}
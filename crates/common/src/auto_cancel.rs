@@ -0,0 +1,618 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Automatic cancellation of working orders by strategy policy.
+//!
+//! An [`AutoCancelManager`] cancels the working orders of strategies at the session close, at the
+//! last close before a weekend or holiday, or once an order has been working for a maximum
+//! duration, per each strategy's [`AutoCancelPolicy`]. Session times come from a
+//! [`SessionCalendar`]. Ahead of each cancellation an [`AutoCancelNotice`] is published on the
+//! strategy's auto-cancel topic, so the strategy can keep specific orders with
+//! [`AutoCancelManager::keep_order`].
+//!
+//! The manager is driven by calling [`AutoCancelManager::check`], typically from a timer firing
+//! more often than the shortest notice period.
+
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use ahash::AHashMap;
+use chrono::{Datelike, NaiveDate, Weekday};
+use nautilus_core::{
+    UUID4, UnixNanos,
+    correctness::{FAILED, check_predicate_true},
+    datetime::NANOSECONDS_IN_SECOND,
+};
+use nautilus_model::{
+    identifiers::{ClientOrderId, StrategyId, TraderId},
+    matching::tif::TradingSession,
+    orders::Order,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    messages::execution::{CancelOrder, TradingCommand},
+    msgbus::{self, MStr, MessagingSwitchboard, Topic},
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+/// The number of days searched for the next session close.
+const MAX_DAYS_TO_CLOSE: usize = 366;
+
+/// Returns the auto-cancel notice topic for the `strategy_id`.
+#[must_use]
+pub fn get_auto_cancel_topic(strategy_id: StrategyId) -> MStr<Topic> {
+    format!("events.auto_cancel.{strategy_id}").into()
+}
+
+/// The trading days of a venue, with a daily session.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionCalendar {
+    pub session: TradingSession,
+    /// The weekdays without a session.
+    pub closed_weekdays: Vec<Weekday>,
+    /// The dates without a session (in UTC).
+    pub holidays: BTreeSet<NaiveDate>,
+}
+
+impl SessionCalendar {
+    /// Creates a new [`SessionCalendar`] instance, closed on weekends.
+    #[must_use]
+    pub fn new(session: TradingSession) -> Self {
+        Self {
+            session,
+            closed_weekdays: vec![Weekday::Sat, Weekday::Sun],
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Adds the `holidays` to the calendar.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Checks the calendar has a session on at least one weekday.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every weekday is closed.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        check_predicate_true(
+            weekdays
+                .iter()
+                .any(|weekday| !self.closed_weekdays.contains(weekday)),
+            "`closed_weekdays` must leave at least one weekday open",
+        )
+    }
+
+    /// Returns whether there is a session on `date`.
+    #[must_use]
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !self.closed_weekdays.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Returns the first session close at or after `ts`, or `None` if the calendar has no
+    /// trading day within a year of `ts`.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let mut close = self.session.close_for(ts);
+        for _ in 0..=MAX_DAYS_TO_CLOSE {
+            if close >= ts && self.is_trading_day(date_of(close)) {
+                return Some(close);
+            }
+            close += NANOSECONDS_IN_DAY;
+        }
+        None
+    }
+
+    /// Returns whether the session closing at `close` is followed by a day without a session.
+    #[must_use]
+    pub fn is_break_after(&self, close: UnixNanos) -> bool {
+        !self.is_trading_day(date_of(close + NANOSECONDS_IN_DAY))
+    }
+}
+
+fn date_of(ts: UnixNanos) -> NaiveDate {
+    ts.to_datetime_utc().date_naive()
+}
+
+/// A strategy's policy for automatically canceling its working orders.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoCancelPolicy {
+    /// Whether working orders are canceled at every session close.
+    pub cancel_at_session_end: bool,
+    /// Whether working orders are canceled at the last session close before a weekend or holiday.
+    pub cancel_before_break: bool,
+    /// The maximum duration an order may be working (since acceptance) before it is canceled.
+    pub max_working_ns: Option<u64>,
+    /// How long before a cancellation the notice is published.
+    pub notice_ns: u64,
+}
+
+impl AutoCancelPolicy {
+    /// Returns whether the policy cancels orders at a session close.
+    #[must_use]
+    pub const fn needs_session_close(&self) -> bool {
+        self.cancel_at_session_end || self.cancel_before_break
+    }
+}
+
+/// The reason for an automatic cancellation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AutoCancelReason {
+    SessionEnd,
+    MarketBreak,
+    MaxWorkingDuration,
+}
+
+/// Represents a notice that working orders of a strategy are about to be canceled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoCancelNotice {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub reason: AutoCancelReason,
+    /// When the orders will be canceled.
+    pub cancel_at: UnixNanos,
+    pub client_order_ids: Vec<ClientOrderId>,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for AutoCancelNotice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, reason={:?}, cancel_at={}, orders={})",
+            stringify!(AutoCancelNotice),
+            self.strategy_id,
+            self.reason,
+            self.cancel_at,
+            self.client_order_ids.len(),
+        )
+    }
+}
+
+/// The notices published and cancels sent by one [`AutoCancelManager::check`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AutoCancelActions {
+    pub notices: Vec<AutoCancelNotice>,
+    pub cancels: Vec<CancelOrder>,
+}
+
+/// Cancels the working orders of strategies per their [`AutoCancelPolicy`].
+pub struct AutoCancelManager {
+    trader_id: TraderId,
+    cache: Rc<RefCell<Cache>>,
+    calendar: SessionCalendar,
+    policies: AHashMap<StrategyId, AutoCancelPolicy>,
+    /// The pending cancellation each order has been notified of.
+    notified: AHashMap<ClientOrderId, UnixNanos>,
+    /// The cancellation each order has been kept through.
+    kept: AHashMap<ClientOrderId, UnixNanos>,
+    /// The cancellation sent for each order.
+    canceled: AHashMap<ClientOrderId, UnixNanos>,
+}
+
+impl Debug for AutoCancelManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(AutoCancelManager))
+            .field("trader_id", &self.trader_id)
+            .field("calendar", &self.calendar)
+            .field("policies", &self.policies)
+            .finish()
+    }
+}
+
+impl AutoCancelManager {
+    /// Creates a new [`AutoCancelManager`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `calendar` is invalid (see [`SessionCalendar::validate`]).
+    pub fn new_checked(
+        trader_id: TraderId,
+        cache: Rc<RefCell<Cache>>,
+        calendar: SessionCalendar,
+    ) -> anyhow::Result<Self> {
+        calendar.validate()?;
+
+        Ok(Self {
+            trader_id,
+            cache,
+            calendar,
+            policies: AHashMap::new(),
+            notified: AHashMap::new(),
+            kept: AHashMap::new(),
+            canceled: AHashMap::new(),
+        })
+    }
+
+    /// Creates a new [`AutoCancelManager`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `calendar` is invalid (see [`SessionCalendar::validate`]).
+    #[must_use]
+    pub fn new(trader_id: TraderId, cache: Rc<RefCell<Cache>>, calendar: SessionCalendar) -> Self {
+        Self::new_checked(trader_id, cache, calendar).expect(FAILED)
+    }
+
+    /// Sets the auto-cancel `policy` for the `strategy_id`.
+    pub fn set_policy(&mut self, strategy_id: StrategyId, policy: AutoCancelPolicy) {
+        self.policies.insert(strategy_id, policy);
+    }
+
+    /// Removes the auto-cancel policy for the `strategy_id`.
+    pub fn remove_policy(&mut self, strategy_id: &StrategyId) -> Option<AutoCancelPolicy> {
+        self.policies.remove(strategy_id)
+    }
+
+    /// Keeps the order through the cancellation it has been notified of, returning whether a
+    /// notified cancellation was pending.
+    pub fn keep_order(&mut self, client_order_id: ClientOrderId) -> bool {
+        match self.notified.get(&client_order_id) {
+            Some(cancel_at) => {
+                self.kept.insert(client_order_id, *cancel_at);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Publishes notices and sends cancels for working orders due at `ts_now`.
+    ///
+    /// Session close policies are skipped while the calendar has no session close within a year
+    /// of `ts_now`.
+    pub fn check(&mut self, ts_now: UnixNanos) -> AutoCancelActions {
+        let session_close = if self
+            .policies
+            .values()
+            .any(AutoCancelPolicy::needs_session_close)
+        {
+            let next_close = self.calendar.next_close(ts_now);
+            if next_close.is_none() {
+                log::warn!("No session close within a year of {ts_now}, skipping session cancels");
+            }
+            next_close.map(|close| (close, self.calendar.is_break_after(close)))
+        } else {
+            None
+        };
+        let mut notices: AHashMap<(StrategyId, AutoCancelReason, UnixNanos), Vec<ClientOrderId>> =
+            AHashMap::new();
+        let mut cancels = Vec::new();
+
+        let cache = self.cache.borrow();
+        let open_orders = cache.orders_open(None, None, None, None, None);
+        self.notified
+            .retain(|id, _| open_orders.iter().any(|o| o.client_order_id() == *id));
+        self.kept
+            .retain(|id, _| open_orders.iter().any(|o| o.client_order_id() == *id));
+        self.canceled
+            .retain(|id, _| open_orders.iter().any(|o| o.client_order_id() == *id));
+
+        for order in open_orders {
+            let Some(policy) = self.policies.get(&order.strategy_id()) else {
+                continue;
+            };
+            let client_order_id = order.client_order_id();
+
+            let session_cancel = match session_close {
+                Some((close, _)) if policy.cancel_at_session_end => {
+                    Some((close, AutoCancelReason::SessionEnd))
+                }
+                Some((close, true)) if policy.cancel_before_break => {
+                    Some((close, AutoCancelReason::MarketBreak))
+                }
+                _ => None,
+            };
+            let duration_cancel = policy.max_working_ns.map(|max_working_ns| {
+                let working_since = order.ts_accepted().unwrap_or_else(|| order.ts_init());
+                (
+                    working_since + max_working_ns,
+                    AutoCancelReason::MaxWorkingDuration,
+                )
+            });
+            let due = [session_cancel, duration_cancel]
+                .into_iter()
+                .flatten()
+                .filter(|(cancel_at, _)| self.kept.get(&client_order_id) != Some(cancel_at))
+                .min_by_key(|(cancel_at, _)| *cancel_at);
+            let Some((cancel_at, reason)) = due else {
+                continue;
+            };
+
+            if ts_now >= cancel_at {
+                if self.canceled.get(&client_order_id) == Some(&cancel_at) {
+                    continue;
+                }
+                self.canceled.insert(client_order_id, cancel_at);
+                log::info!("Auto-canceling order {client_order_id}: {reason:?}");
+                cancels.push(CancelOrder::new(
+                    self.trader_id,
+                    None,
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    order.venue_order_id(),
+                    UUID4::new(),
+                    ts_now,
+                    None,
+                ));
+            } else if ts_now + policy.notice_ns >= cancel_at
+                && self.notified.get(&client_order_id) != Some(&cancel_at)
+            {
+                self.notified.insert(client_order_id, cancel_at);
+                notices
+                    .entry((order.strategy_id(), reason, cancel_at))
+                    .or_default()
+                    .push(client_order_id);
+            }
+        }
+        drop(cache);
+
+        let mut notices: Vec<AutoCancelNotice> = notices
+            .into_iter()
+            .map(
+                |((strategy_id, reason, cancel_at), client_order_ids)| AutoCancelNotice {
+                    trader_id: self.trader_id,
+                    strategy_id,
+                    reason,
+                    cancel_at,
+                    client_order_ids,
+                    event_id: UUID4::new(),
+                    ts_event: ts_now,
+                    ts_init: ts_now,
+                },
+            )
+            .collect();
+        notices.sort_by_key(|notice| (notice.cancel_at, notice.strategy_id));
+
+        for notice in &notices {
+            msgbus::publish_any(get_auto_cancel_topic(notice.strategy_id), notice);
+        }
+        for cancel in &cancels {
+            msgbus::send_trading_command(
+                MessagingSwitchboard::exec_engine_execute(),
+                TradingCommand::CancelOrder(cancel.clone()),
+            );
+        }
+
+        AutoCancelActions { notices, cancels }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        events::OrderEventAny,
+        identifiers::{AccountId, InstrumentId, VenueOrderId},
+        orders::{OrderAny, OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+    // Friday 2024-01-05 00:00:00 UTC
+    const FRIDAY: u64 = 1_704_412_800 * NANOSECONDS_IN_SECOND;
+
+    fn calendar() -> SessionCalendar {
+        SessionCalendar::new(TradingSession::new(14 * HOUR, 21 * HOUR))
+    }
+
+    fn working_order(cache: &Rc<RefCell<Cache>>, client_order_id: &str, ts_accepted: u64) {
+        let mut order: OrderAny = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("ESH4.XCME"))
+            .strategy_id(StrategyId::from("S-001"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .price(Price::from("4500.00"))
+            .quantity(Quantity::from(1))
+            .build();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        let OrderEventAny::Accepted(mut accepted) =
+            TestOrderEventStubs::accepted(&order, account_id, VenueOrderId::from("V-1"))
+        else {
+            unreachable!()
+        };
+        accepted.ts_event = UnixNanos::from(ts_accepted);
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        cache.borrow_mut().update_order(&order).unwrap();
+    }
+
+    fn manager(policy: AutoCancelPolicy) -> (AutoCancelManager, Rc<RefCell<Cache>>) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut manager =
+            AutoCancelManager::new(TraderId::from("TRADER-001"), cache.clone(), calendar());
+        manager.set_policy(StrategyId::from("S-001"), policy);
+        (manager, cache)
+    }
+
+    #[rstest]
+    fn test_calendar_skips_weekends_and_holidays() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        let calendar = calendar().with_holidays([monday]);
+        let friday_close = UnixNanos::from(FRIDAY + 21 * HOUR);
+
+        assert_eq!(
+            calendar.next_close(UnixNanos::from(FRIDAY)),
+            Some(friday_close)
+        );
+        assert!(calendar.is_break_after(friday_close));
+        assert_eq!(
+            calendar.next_close(friday_close + 1),
+            Some(UnixNanos::from(FRIDAY + 4 * 24 * HOUR + 21 * HOUR))
+        );
+    }
+
+    #[rstest]
+    fn test_calendar_with_all_weekdays_closed() {
+        let mut calendar = calendar();
+        calendar.closed_weekdays = vec![
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        assert!(calendar.validate().is_err());
+        assert_eq!(calendar.next_close(UnixNanos::from(FRIDAY)), None);
+        assert!(
+            AutoCancelManager::new_checked(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(Cache::default())),
+                calendar,
+            )
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_check_without_session_close_within_a_year() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let holidays = start.iter_days().take(MAX_DAYS_TO_CLOSE + 1);
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut manager = AutoCancelManager::new(
+            TraderId::from("TRADER-001"),
+            cache.clone(),
+            calendar().with_holidays(holidays),
+        );
+        manager.set_policy(
+            StrategyId::from("S-001"),
+            AutoCancelPolicy {
+                cancel_at_session_end: true,
+                max_working_ns: Some(2 * HOUR),
+                ..Default::default()
+            },
+        );
+        working_order(&cache, "O-1", FRIDAY + 15 * HOUR);
+
+        // Session cancels are skipped, duration cancels still apply
+        assert_eq!(
+            manager.check(UnixNanos::from(FRIDAY + 16 * HOUR)),
+            AutoCancelActions::default()
+        );
+        let actions = manager.check(UnixNanos::from(FRIDAY + 17 * HOUR));
+        assert_eq!(actions.cancels.len(), 1);
+        assert_eq!(
+            actions.cancels[0].client_order_id,
+            ClientOrderId::from("O-1")
+        );
+    }
+
+    #[rstest]
+    fn test_notice_then_cancel_before_weekend() {
+        let (mut manager, cache) = manager(AutoCancelPolicy {
+            cancel_before_break: true,
+            notice_ns: HOUR,
+            ..Default::default()
+        });
+        working_order(&cache, "O-1", FRIDAY + 15 * HOUR);
+
+        assert_eq!(
+            manager.check(UnixNanos::from(FRIDAY + 19 * HOUR)),
+            AutoCancelActions::default()
+        );
+        let actions = manager.check(UnixNanos::from(FRIDAY + 20 * HOUR));
+        assert_eq!(actions.notices.len(), 1);
+        assert_eq!(actions.notices[0].reason, AutoCancelReason::MarketBreak);
+        assert!(actions.cancels.is_empty());
+        assert!(
+            manager
+                .check(UnixNanos::from(FRIDAY + 20 * HOUR + 1))
+                .notices
+                .is_empty()
+        );
+
+        let actions = manager.check(UnixNanos::from(FRIDAY + 21 * HOUR));
+        assert_eq!(actions.cancels.len(), 1);
+        assert_eq!(
+            actions.cancels[0].client_order_id,
+            ClientOrderId::from("O-1")
+        );
+        assert!(
+            manager
+                .check(UnixNanos::from(FRIDAY + 21 * HOUR))
+                .cancels
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_kept_order_survives_notified_cancel() {
+        let (mut manager, cache) = manager(AutoCancelPolicy {
+            max_working_ns: Some(2 * HOUR),
+            notice_ns: HOUR,
+            ..Default::default()
+        });
+        working_order(&cache, "O-1", FRIDAY + 15 * HOUR);
+        working_order(&cache, "O-2", FRIDAY + 15 * HOUR);
+
+        let actions = manager.check(UnixNanos::from(FRIDAY + 16 * HOUR));
+        assert_eq!(actions.notices[0].client_order_ids.len(), 2);
+        assert!(manager.keep_order(ClientOrderId::from("O-2")));
+
+        let actions = manager.check(UnixNanos::from(FRIDAY + 17 * HOUR));
+        assert_eq!(actions.cancels.len(), 1);
+        assert_eq!(
+            actions.cancels[0].client_order_id,
+            ClientOrderId::from("O-1")
+        );
+    }
+
+    #[rstest]
+    fn test_orders_without_policy_are_ignored() {
+        let (mut manager, cache) = manager(AutoCancelPolicy {
+            cancel_at_session_end: true,
+            ..Default::default()
+        });
+        manager.remove_policy(&StrategyId::from("S-001"));
+        working_order(&cache, "O-1", FRIDAY + 15 * HOUR);
+
+        assert_eq!(
+            manager.check(UnixNanos::from(FRIDAY + 22 * HOUR)),
+            AutoCancelActions::default()
+        );
+    }
+}
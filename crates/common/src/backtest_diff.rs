@@ -0,0 +1,585 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Comparison of backtest result artifacts for regression detection.
+//!
+//! A [`BacktestArtifact`] captures the observable results of a run: its fills, the sequence of
+//! order events, the final account balances and the summary statistics. Artifacts are built from
+//! the cache at the end of a run and persisted as JSON, so a CI job can compare the artifact of
+//! the current code against a blessed baseline with [`diff_artifacts`]. Numeric fields are
+//! compared within the [`DiffTolerance`], and any remaining differences are reported as a
+//! structured [`BacktestDiff`].
+
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::OrderSide,
+    events::OrderEventAny,
+    identifiers::{AccountId, ClientOrderId, InstrumentId, TradeId},
+    orders::Order,
+    types::{Money, Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::Cache;
+
+/// A fill captured in a [`BacktestArtifact`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FillRecord {
+    pub trade_id: TradeId,
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Option<Money>,
+    pub ts_event: UnixNanos,
+}
+
+/// An order event captured in a [`BacktestArtifact`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub client_order_id: ClientOrderId,
+    /// The event type name, e.g. `Accepted`.
+    pub kind: String,
+    pub ts_event: UnixNanos,
+}
+
+/// A final account balance captured in a [`BacktestArtifact`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BalanceRecord {
+    pub account_id: AccountId,
+    pub total: Money,
+    pub locked: Money,
+    pub free: Money,
+}
+
+/// The observable results of a backtest run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestArtifact {
+    pub fills: Vec<FillRecord>,
+    pub events: Vec<EventRecord>,
+    pub balances: Vec<BalanceRecord>,
+    pub stats: BTreeMap<String, f64>,
+}
+
+impl BacktestArtifact {
+    /// Captures the fills, order events and account balances in the `cache`, with the run's
+    /// summary `stats`.
+    ///
+    /// Records are ordered deterministically (by event time, then identifiers) so artifacts of
+    /// identical runs compare equal.
+    #[must_use]
+    pub fn from_cache(cache: &Cache, stats: BTreeMap<String, f64>) -> Self {
+        let orders = cache.orders(None, None, None, None, None);
+
+        let mut fills: Vec<FillRecord> = orders
+            .iter()
+            .flat_map(|order| order.events())
+            .filter_map(|event| match event {
+                OrderEventAny::Filled(fill) => Some(FillRecord {
+                    trade_id: fill.trade_id,
+                    client_order_id: fill.client_order_id,
+                    instrument_id: fill.instrument_id,
+                    side: fill.order_side,
+                    last_qty: fill.last_qty,
+                    last_px: fill.last_px,
+                    commission: fill.commission,
+                    ts_event: fill.ts_event,
+                }),
+                _ => None,
+            })
+            .collect();
+        fills.sort_by(|a, b| {
+            (a.ts_event, a.trade_id.as_str()).cmp(&(b.ts_event, b.trade_id.as_str()))
+        });
+
+        let mut events: Vec<EventRecord> = orders
+            .iter()
+            .flat_map(|order| order.events())
+            .map(|event| EventRecord {
+                client_order_id: event.client_order_id(),
+                kind: format!("{:?}", event.event_type()),
+                ts_event: event.ts_event(),
+            })
+            .collect();
+        // Stable, so each order's events keep their sequence
+        events.sort_by(|a, b| {
+            (a.ts_event, a.client_order_id.as_str()).cmp(&(b.ts_event, b.client_order_id.as_str()))
+        });
+
+        let mut balances: Vec<BalanceRecord> = cache
+            .account_ids()
+            .into_iter()
+            .filter_map(|account_id| cache.account(account_id))
+            .flat_map(|account| {
+                let account_id = account.id();
+                account
+                    .balances()
+                    .into_values()
+                    .map(move |balance| BalanceRecord {
+                        account_id,
+                        total: balance.total,
+                        locked: balance.locked,
+                        free: balance.free,
+                    })
+            })
+            .collect();
+        balances.sort_by(|a, b| {
+            (a.account_id.as_str(), a.total.currency.code.as_str())
+                .cmp(&(b.account_id.as_str(), b.total.currency.code.as_str()))
+        });
+
+        Self {
+            fills,
+            events,
+            balances,
+            stats,
+        }
+    }
+
+    /// Loads an artifact from the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or decoded.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Saves the artifact as JSON to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifact cannot be encoded or the file cannot be written.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// The tolerances within which artifact values are considered equal.
+///
+/// The default tolerance requires exact equality.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiffTolerance {
+    /// The absolute tolerance for prices.
+    pub price: f64,
+    /// The absolute tolerance for quantities.
+    pub quantity: f64,
+    /// The absolute tolerance for commissions and balances.
+    pub money: f64,
+    /// The relative tolerance for statistics.
+    pub stats_relative: f64,
+    /// The tolerance for event timestamps (nanoseconds).
+    pub ts_ns: u64,
+}
+
+/// The section of an artifact a difference was found in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DiffSection {
+    Fills,
+    Events,
+    Balances,
+    Stats,
+}
+
+/// The kind of a difference between two artifacts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DiffKind {
+    /// The record is only in the baseline.
+    Missing,
+    /// The record is only in the candidate.
+    Unexpected,
+    /// A field differs beyond its tolerance.
+    Changed {
+        field: String,
+        baseline: String,
+        candidate: String,
+    },
+}
+
+/// A difference between two artifacts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiffEntry {
+    pub section: DiffSection,
+    /// The key identifying the record within its section.
+    pub key: String,
+    pub kind: DiffKind,
+}
+
+/// The differences between a baseline and a candidate artifact.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestDiff {
+    pub entries: Vec<DiffEntry>,
+}
+
+impl BacktestDiff {
+    /// Returns whether the artifacts matched within tolerance.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the differences found in `section`.
+    pub fn section(&self, section: DiffSection) -> impl Iterator<Item = &DiffEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.section == section)
+    }
+
+    /// Returns an error describing the differences, if any, for use as a regression check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifacts differ.
+    pub fn ensure_empty(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(self.is_empty(), "Backtest results drifted:\n{self}");
+        Ok(())
+    }
+
+    fn push(&mut self, section: DiffSection, key: &str, kind: DiffKind) {
+        self.entries.push(DiffEntry {
+            section,
+            key: key.to_string(),
+            kind,
+        });
+    }
+
+    fn compare(
+        &mut self,
+        section: DiffSection,
+        key: &str,
+        field: &str,
+        baseline: impl Display,
+        candidate: impl Display,
+        within: bool,
+    ) {
+        if !within {
+            self.push(
+                section,
+                key,
+                DiffKind::Changed {
+                    field: field.to_string(),
+                    baseline: baseline.to_string(),
+                    candidate: candidate.to_string(),
+                },
+            );
+        }
+    }
+}
+
+impl Display for BacktestDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.entries {
+            match &entry.kind {
+                DiffKind::Missing => writeln!(f, "{:?} {}: missing", entry.section, entry.key)?,
+                DiffKind::Unexpected => {
+                    writeln!(f, "{:?} {}: unexpected", entry.section, entry.key)?;
+                }
+                DiffKind::Changed {
+                    field,
+                    baseline,
+                    candidate,
+                } => writeln!(
+                    f,
+                    "{:?} {}: {field} {baseline} -> {candidate}",
+                    entry.section, entry.key
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn within(baseline: f64, candidate: f64, tolerance: f64) -> bool {
+    (baseline - candidate).abs() <= tolerance
+}
+
+fn within_ts(baseline: UnixNanos, candidate: UnixNanos, tolerance: u64) -> bool {
+    baseline.as_u64().abs_diff(candidate.as_u64()) <= tolerance
+}
+
+fn diff_keyed<T>(
+    diff: &mut BacktestDiff,
+    section: DiffSection,
+    baseline: IndexMap<String, &T>,
+    mut candidate: IndexMap<String, &T>,
+    mut compare: impl FnMut(&mut BacktestDiff, &str, &T, &T),
+) {
+    for (key, baseline) in baseline {
+        match candidate.shift_remove(&key) {
+            Some(candidate) => compare(diff, &key, baseline, candidate),
+            None => diff.push(section, &key, DiffKind::Missing),
+        }
+    }
+    for key in candidate.keys() {
+        diff.push(section, key, DiffKind::Unexpected);
+    }
+}
+
+/// Compares the `candidate` artifact against the `baseline` within the `tolerance`.
+///
+/// Fills are matched by client order ID and trade ID, balances by account and currency, and
+/// statistics by name. Order events are compared as a sequence per order.
+#[must_use]
+pub fn diff_artifacts(
+    baseline: &BacktestArtifact,
+    candidate: &BacktestArtifact,
+    tolerance: &DiffTolerance,
+) -> BacktestDiff {
+    let mut diff = BacktestDiff::default();
+
+    let fill_key = |fill: &FillRecord| format!("{}/{}", fill.client_order_id, fill.trade_id);
+    diff_keyed(
+        &mut diff,
+        DiffSection::Fills,
+        baseline.fills.iter().map(|f| (fill_key(f), f)).collect(),
+        candidate.fills.iter().map(|f| (fill_key(f), f)).collect(),
+        |diff, key, a, b| {
+            let section = DiffSection::Fills;
+            diff.compare(section, key, "side", a.side, b.side, a.side == b.side);
+            diff.compare(
+                section,
+                key,
+                "last_qty",
+                a.last_qty,
+                b.last_qty,
+                within(a.last_qty.as_f64(), b.last_qty.as_f64(), tolerance.quantity),
+            );
+            diff.compare(
+                section,
+                key,
+                "last_px",
+                a.last_px,
+                b.last_px,
+                within(a.last_px.as_f64(), b.last_px.as_f64(), tolerance.price),
+            );
+            let commission = |c: Option<Money>| c.map_or(0.0, |c| c.as_f64());
+            diff.compare(
+                section,
+                key,
+                "commission",
+                commission(a.commission),
+                commission(b.commission),
+                within(
+                    commission(a.commission),
+                    commission(b.commission),
+                    tolerance.money,
+                ),
+            );
+            diff.compare(
+                section,
+                key,
+                "ts_event",
+                a.ts_event,
+                b.ts_event,
+                within_ts(a.ts_event, b.ts_event, tolerance.ts_ns),
+            );
+        },
+    );
+
+    fn group_events(artifact: &BacktestArtifact) -> IndexMap<String, Vec<&EventRecord>> {
+        let mut grouped: IndexMap<String, Vec<&EventRecord>> = IndexMap::new();
+        for event in &artifact.events {
+            grouped
+                .entry(event.client_order_id.to_string())
+                .or_default()
+                .push(event);
+        }
+        grouped
+    }
+
+    let baseline_events = group_events(baseline);
+    let candidate_events = group_events(candidate);
+    diff_keyed(
+        &mut diff,
+        DiffSection::Events,
+        baseline_events
+            .iter()
+            .map(|(k, v)| (k.clone(), v))
+            .collect(),
+        candidate_events
+            .iter()
+            .map(|(k, v)| (k.clone(), v))
+            .collect(),
+        |diff, key, a, b| {
+            let kinds = |events: &[&EventRecord]| {
+                events
+                    .iter()
+                    .map(|e| e.kind.as_str())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            };
+            let (a_kinds, b_kinds) = (kinds(a), kinds(b));
+            if a_kinds != b_kinds {
+                diff.compare(
+                    DiffSection::Events,
+                    key,
+                    "sequence",
+                    a_kinds,
+                    b_kinds,
+                    false,
+                );
+                return;
+            }
+            for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                diff.compare(
+                    DiffSection::Events,
+                    key,
+                    &format!("{}[{i}].ts_event", a.kind),
+                    a.ts_event,
+                    b.ts_event,
+                    within_ts(a.ts_event, b.ts_event, tolerance.ts_ns),
+                );
+            }
+        },
+    );
+
+    let balance_key =
+        |balance: &BalanceRecord| format!("{}/{}", balance.account_id, balance.total.currency);
+    diff_keyed(
+        &mut diff,
+        DiffSection::Balances,
+        baseline
+            .balances
+            .iter()
+            .map(|b| (balance_key(b), b))
+            .collect(),
+        candidate
+            .balances
+            .iter()
+            .map(|b| (balance_key(b), b))
+            .collect(),
+        |diff, key, a, b| {
+            for (field, a, b) in [
+                ("total", a.total, b.total),
+                ("locked", a.locked, b.locked),
+                ("free", a.free, b.free),
+            ] {
+                let is_within = within(a.as_f64(), b.as_f64(), tolerance.money);
+                diff.compare(DiffSection::Balances, key, field, a, b, is_within);
+            }
+        },
+    );
+
+    diff_keyed(
+        &mut diff,
+        DiffSection::Stats,
+        baseline.stats.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        candidate
+            .stats
+            .iter()
+            .map(|(k, v)| (k.clone(), v))
+            .collect(),
+        |diff, key, a, b| {
+            let scale = a.abs().max(b.abs());
+            let is_within =
+                (a.is_nan() && b.is_nan()) || within(*a, *b, tolerance.stats_relative * scale);
+            diff.compare(DiffSection::Stats, key, "value", a, b, is_within);
+        },
+    );
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::Currency;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn artifact() -> BacktestArtifact {
+        let client_order_id = ClientOrderId::from("O-1");
+        BacktestArtifact {
+            fills: vec![FillRecord {
+                trade_id: TradeId::from("T-1"),
+                client_order_id,
+                instrument_id: InstrumentId::from("AUD/USD.SIM"),
+                side: OrderSide::Buy,
+                last_qty: Quantity::from(100_000),
+                last_px: Price::from("1.00010"),
+                commission: Some(Money::new(2.0, Currency::USD())),
+                ts_event: UnixNanos::from(10),
+            }],
+            events: ["Initialized", "Submitted", "Accepted", "Filled"]
+                .into_iter()
+                .zip(0..)
+                .map(|(kind, ts)| EventRecord {
+                    client_order_id,
+                    kind: kind.to_string(),
+                    ts_event: UnixNanos::from(ts),
+                })
+                .collect(),
+            balances: vec![BalanceRecord {
+                account_id: AccountId::from("SIM-001"),
+                total: Money::new(1_000_000.0, Currency::USD()),
+                locked: Money::new(0.0, Currency::USD()),
+                free: Money::new(1_000_000.0, Currency::USD()),
+            }],
+            stats: BTreeMap::from([("sharpe_ratio".to_string(), 1.5)]),
+        }
+    }
+
+    #[rstest]
+    fn test_identical_artifacts_have_no_diff() {
+        let diff = diff_artifacts(&artifact(), &artifact(), &DiffTolerance::default());
+
+        assert!(diff.is_empty());
+        assert!(diff.ensure_empty().is_ok());
+    }
+
+    #[rstest]
+    fn test_differences_within_tolerance_are_ignored() {
+        let mut candidate = artifact();
+        candidate.fills[0].last_px = Price::from("1.00011");
+        candidate.stats.insert("sharpe_ratio".to_string(), 1.5001);
+        let tolerance = DiffTolerance {
+            price: 0.000_02,
+            stats_relative: 0.001,
+            ..Default::default()
+        };
+
+        assert!(diff_artifacts(&artifact(), &candidate, &tolerance).is_empty());
+        assert!(!diff_artifacts(&artifact(), &candidate, &DiffTolerance::default()).is_empty());
+    }
+
+    #[rstest]
+    fn test_structured_diff_reports_each_section() {
+        let mut candidate = artifact();
+        candidate.fills[0].trade_id = TradeId::from("T-2");
+        candidate.events.swap(1, 2);
+        candidate.balances[0].free = Money::new(999_000.0, Currency::USD());
+        candidate.stats.insert("win_rate".to_string(), 0.5);
+
+        let diff = diff_artifacts(&artifact(), &candidate, &DiffTolerance::default());
+
+        let fills: Vec<_> = diff.section(DiffSection::Fills).map(|e| &e.kind).collect();
+        assert_eq!(fills, vec![&DiffKind::Missing, &DiffKind::Unexpected]);
+        assert!(matches!(
+            &diff.section(DiffSection::Events).next().unwrap().kind,
+            DiffKind::Changed { field, .. } if field == "sequence"
+        ));
+        assert_eq!(diff.section(DiffSection::Balances).count(), 1);
+        assert_eq!(
+            diff.section(DiffSection::Stats).next().unwrap().kind,
+            DiffKind::Unexpected
+        );
+        assert!(diff.ensure_empty().is_err());
+    }
+}
@@ -0,0 +1,390 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bandwidth and message-rate accounting per data client and subscription.
+//!
+//! The [`BandwidthAccountant`] counts the messages and bytes received from each data client,
+//! broken down by subscription (typically the topic the data is published on), and derives
+//! rolling messages/sec and bytes/sec rates over a configurable window. Operators can query the
+//! heaviest subscriptions, publish a [`BandwidthSnapshot`] on the telemetry bus, and set a
+//! [`BandwidthBudget`] per client which either warns on or drops traffic beyond its limits.
+
+use std::collections::VecDeque;
+
+use ahash::AHashMap;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::identifiers::ClientId;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use ustr::Ustr;
+
+use crate::msgbus::{self, switchboard::MessagingSwitchboard};
+
+const NANOS_PER_SEC: f64 = 1_000_000_000.0;
+const BUCKETS_PER_WINDOW: u64 = 10;
+
+/// The action taken when a data client exceeds its bandwidth budget.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BudgetAction {
+    /// Log a warning and continue to accept messages.
+    #[default]
+    Warn,
+    /// Drop messages until the rate falls back within budget.
+    Drop,
+}
+
+/// The rate limits for a data client.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BandwidthBudget {
+    pub max_messages_per_sec: Option<f64>,
+    pub max_bytes_per_sec: Option<f64>,
+    pub action: BudgetAction,
+}
+
+/// The message and byte counts and rolling rates for a client or subscription.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RateStats {
+    /// The total messages accepted.
+    pub messages: u64,
+    /// The total bytes accepted.
+    pub bytes: u64,
+    /// The total messages dropped for exceeding the budget.
+    pub dropped: u64,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec: f64,
+}
+
+/// The rate statistics for a single subscription of a data client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionBandwidth {
+    pub client_id: ClientId,
+    pub subscription: Ustr,
+    pub stats: RateStats,
+}
+
+/// A snapshot of all bandwidth statistics for publishing on the telemetry bus.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BandwidthSnapshot {
+    pub clients: Vec<(ClientId, RateStats)>,
+    /// The subscriptions ordered by bytes/sec, highest first.
+    pub subscriptions: Vec<SubscriptionBandwidth>,
+    pub ts_init: UnixNanos,
+}
+
+#[derive(Clone, Debug)]
+struct RateCounter {
+    bucket_ns: u64,
+    /// Per-bucket `(bucket index, messages, bytes)`, oldest first.
+    buckets: VecDeque<(u64, u64, u64)>,
+    messages: u64,
+    bytes: u64,
+    dropped: u64,
+}
+
+impl RateCounter {
+    fn new(bucket_ns: u64) -> Self {
+        Self {
+            bucket_ns,
+            buckets: VecDeque::with_capacity(BUCKETS_PER_WINDOW as usize + 1),
+            messages: 0,
+            bytes: 0,
+            dropped: 0,
+        }
+    }
+
+    fn record(&mut self, bytes: u64, ts: UnixNanos) {
+        let index = ts.as_u64() / self.bucket_ns;
+        match self.buckets.back_mut() {
+            Some(bucket) if bucket.0 >= index => {
+                bucket.1 += 1;
+                bucket.2 += bytes;
+            }
+            _ => self.buckets.push_back((index, 1, bytes)),
+        }
+        self.messages += 1;
+        self.bytes += bytes;
+        self.prune(index);
+    }
+
+    fn prune(&mut self, index: u64) {
+        while self
+            .buckets
+            .front()
+            .is_some_and(|bucket| bucket.0 + BUCKETS_PER_WINDOW <= index)
+        {
+            self.buckets.pop_front();
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn rates(&self, ts: UnixNanos) -> (f64, f64) {
+        let index = ts.as_u64() / self.bucket_ns;
+        let (messages, bytes) = self
+            .buckets
+            .iter()
+            .filter(|bucket| bucket.0 + BUCKETS_PER_WINDOW > index)
+            .fold((0, 0), |acc, bucket| (acc.0 + bucket.1, acc.1 + bucket.2));
+        let window_secs = (self.bucket_ns * BUCKETS_PER_WINDOW) as f64 / NANOS_PER_SEC;
+        (messages as f64 / window_secs, bytes as f64 / window_secs)
+    }
+
+    fn stats(&self, ts: UnixNanos) -> RateStats {
+        let (messages_per_sec, bytes_per_sec) = self.rates(ts);
+        RateStats {
+            messages: self.messages,
+            bytes: self.bytes,
+            dropped: self.dropped,
+            messages_per_sec,
+            bytes_per_sec,
+        }
+    }
+}
+
+/// Accounts for message and byte rates per data client and per subscription.
+#[derive(Clone, Debug)]
+pub struct BandwidthAccountant {
+    bucket_ns: u64,
+    clients: IndexMap<ClientId, RateCounter>,
+    subscriptions: IndexMap<(ClientId, Ustr), RateCounter>,
+    budgets: AHashMap<ClientId, BandwidthBudget>,
+    breached: AHashMap<ClientId, bool>,
+}
+
+impl BandwidthAccountant {
+    /// Creates a new [`BandwidthAccountant`] instance with rates over a rolling `window_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_ns` is less than 10 nanoseconds.
+    #[must_use]
+    pub fn new(window_ns: u64) -> Self {
+        assert!(
+            window_ns >= BUCKETS_PER_WINDOW,
+            "`window_ns` must be at least {BUCKETS_PER_WINDOW}, was {window_ns}"
+        );
+        Self {
+            bucket_ns: window_ns / BUCKETS_PER_WINDOW,
+            clients: IndexMap::new(),
+            subscriptions: IndexMap::new(),
+            budgets: AHashMap::new(),
+            breached: AHashMap::new(),
+        }
+    }
+
+    /// Sets the bandwidth budget for `client_id`.
+    pub fn set_budget(&mut self, client_id: ClientId, budget: BandwidthBudget) {
+        self.budgets.insert(client_id, budget);
+    }
+
+    /// Removes the bandwidth budget for `client_id`.
+    pub fn remove_budget(&mut self, client_id: &ClientId) {
+        self.budgets.remove(client_id);
+        self.breached.remove(client_id);
+    }
+
+    /// Records a message of `bytes` received from `client_id` for `subscription`.
+    ///
+    /// Returns `false` if the message should be dropped because the client has exceeded a
+    /// budget with [`BudgetAction::Drop`].
+    pub fn on_message(
+        &mut self,
+        client_id: ClientId,
+        subscription: Ustr,
+        bytes: usize,
+        ts: UnixNanos,
+    ) -> bool {
+        let bucket_ns = self.bucket_ns;
+        let client = self
+            .clients
+            .entry(client_id)
+            .or_insert_with(|| RateCounter::new(bucket_ns));
+
+        if let Some(budget) = self.budgets.get(&client_id) {
+            let (messages_per_sec, bytes_per_sec) = client.rates(ts);
+            let exceeded = budget
+                .max_messages_per_sec
+                .is_some_and(|max| messages_per_sec >= max)
+                || budget
+                    .max_bytes_per_sec
+                    .is_some_and(|max| bytes_per_sec >= max);
+            let was_breached = self.breached.insert(client_id, exceeded).unwrap_or(false);
+
+            if exceeded && !was_breached {
+                log::warn!(
+                    "Data client {client_id} exceeded bandwidth budget: \
+                     {messages_per_sec:.1} msgs/s, {bytes_per_sec:.1} bytes/s"
+                );
+            } else if !exceeded && was_breached {
+                log::info!("Data client {client_id} back within bandwidth budget");
+            }
+
+            if exceeded && budget.action == BudgetAction::Drop {
+                client.dropped += 1;
+                self.subscriptions
+                    .entry((client_id, subscription))
+                    .or_insert_with(|| RateCounter::new(bucket_ns))
+                    .dropped += 1;
+                return false;
+            }
+        }
+
+        let bytes = bytes as u64;
+        client.record(bytes, ts);
+        self.subscriptions
+            .entry((client_id, subscription))
+            .or_insert_with(|| RateCounter::new(bucket_ns))
+            .record(bytes, ts);
+        true
+    }
+
+    /// Returns whether `client_id` is currently over its budget.
+    #[must_use]
+    pub fn is_over_budget(&self, client_id: &ClientId) -> bool {
+        self.breached.get(client_id).copied().unwrap_or(false)
+    }
+
+    /// Returns the statistics for `client_id` as of `ts`.
+    #[must_use]
+    pub fn client_stats(&self, client_id: &ClientId, ts: UnixNanos) -> Option<RateStats> {
+        self.clients.get(client_id).map(|counter| counter.stats(ts))
+    }
+
+    /// Returns the statistics for `subscription` on `client_id` as of `ts`.
+    #[must_use]
+    pub fn subscription_stats(
+        &self,
+        client_id: ClientId,
+        subscription: Ustr,
+        ts: UnixNanos,
+    ) -> Option<RateStats> {
+        self.subscriptions
+            .get(&(client_id, subscription))
+            .map(|counter| counter.stats(ts))
+    }
+
+    /// Returns up to `n` subscriptions with the highest bytes/sec as of `ts`.
+    #[must_use]
+    pub fn top_subscriptions(&self, n: usize, ts: UnixNanos) -> Vec<SubscriptionBandwidth> {
+        let mut subscriptions: Vec<SubscriptionBandwidth> = self
+            .subscriptions
+            .iter()
+            .map(
+                |((client_id, subscription), counter)| SubscriptionBandwidth {
+                    client_id: *client_id,
+                    subscription: *subscription,
+                    stats: counter.stats(ts),
+                },
+            )
+            .collect();
+        subscriptions.sort_by(|a, b| b.stats.bytes_per_sec.total_cmp(&a.stats.bytes_per_sec));
+        subscriptions.truncate(n);
+        subscriptions
+    }
+
+    /// Returns a snapshot of all statistics as of `ts_init`.
+    #[must_use]
+    pub fn snapshot(&self, ts_init: UnixNanos) -> BandwidthSnapshot {
+        BandwidthSnapshot {
+            clients: self
+                .clients
+                .iter()
+                .map(|(client_id, counter)| (*client_id, counter.stats(ts_init)))
+                .collect(),
+            subscriptions: self.top_subscriptions(self.subscriptions.len(), ts_init),
+            ts_init,
+        }
+    }
+
+    /// Publishes a snapshot on the bandwidth telemetry topic.
+    pub fn publish(&self, ts_init: UnixNanos) {
+        let snapshot = self.snapshot(ts_init);
+        msgbus::publish_any(MessagingSwitchboard::telemetry_bandwidth_topic(), &snapshot);
+    }
+
+    /// Clears all counters, retaining budgets.
+    pub fn reset(&mut self) {
+        self.clients.clear();
+        self.subscriptions.clear();
+        self.breached.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SEC: u64 = 1_000_000_000;
+
+    #[rstest]
+    fn test_rates_per_client_and_subscription() {
+        let mut accountant = BandwidthAccountant::new(SEC);
+        let client = ClientId::from("BINANCE");
+        let quotes = Ustr::from("data.quotes.BINANCE.BTCUSDT");
+        let trades = Ustr::from("data.trades.BINANCE.BTCUSDT");
+
+        for i in 0..10 {
+            assert!(accountant.on_message(client, quotes, 100, (i * SEC / 10).into()));
+        }
+        assert!(accountant.on_message(client, trades, 50, (SEC / 2).into()));
+
+        let ts = UnixNanos::from(SEC - 1);
+        let stats = accountant.client_stats(&client, ts).unwrap();
+        assert_eq!(stats.messages, 11);
+        assert_eq!(stats.bytes, 1_050);
+        assert!((stats.messages_per_sec - 11.0).abs() < 1e-9);
+        assert!((stats.bytes_per_sec - 1_050.0).abs() < 1e-9);
+
+        let top = accountant.top_subscriptions(1, ts);
+        assert_eq!(top[0].subscription, quotes);
+
+        // Rates decay once the window has passed
+        let later = accountant.client_stats(&client, (3 * SEC).into()).unwrap();
+        assert!(later.messages_per_sec.abs() < 1e-9);
+        assert_eq!(later.messages, 11);
+    }
+
+    #[rstest]
+    fn test_budget_drop_enforced_until_rate_recovers() {
+        let mut accountant = BandwidthAccountant::new(SEC);
+        let client = ClientId::from("BINANCE");
+        let topic = Ustr::from("data.quotes.BINANCE.BTCUSDT");
+        accountant.set_budget(
+            client,
+            BandwidthBudget {
+                max_messages_per_sec: Some(3.0),
+                max_bytes_per_sec: None,
+                action: BudgetAction::Drop,
+            },
+        );
+
+        let accepted: Vec<bool> = (0..5)
+            .map(|_| accountant.on_message(client, topic, 10, 0.into()))
+            .collect();
+        assert_eq!(accepted, vec![true, true, true, false, false]);
+        assert!(accountant.is_over_budget(&client));
+
+        let stats = accountant
+            .subscription_stats(client, topic, 0.into())
+            .unwrap();
+        assert_eq!(stats.messages, 3);
+        assert_eq!(stats.dropped, 2);
+
+        assert!(accountant.on_message(client, topic, 10, (2 * SEC).into()));
+        assert!(!accountant.is_over_budget(&client));
+    }
+}
@@ -0,0 +1,741 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A lightweight HTTP endpoint serving order book snapshots for debugging dashboards.
+//!
+//! `GET /book/{instrument_id}?depth=N` returns the cached book for the instrument as JSON: the
+//! top `N` levels per side, the last update sequence and time, and the result of the book
+//! integrity check. Each instrument may be served at most once per configured interval, with
+//! throttled requests answered `429 Too Many Requests`.
+//!
+//! The cache is single-threaded, so the listener and its connections are non-blocking and are
+//! served on the owning thread from a clock timer (or by calling [`BookVizEndpoint::poll`]).
+//! Each poll accepts a bounded number of new connections, only reads what has already arrived,
+//! and only writes what the socket accepts, queueing the rest of a response for later polls, so
+//! slow or idle clients cannot stall the owning thread.
+
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    io::{ErrorKind, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::BookType,
+    identifiers::InstrumentId,
+    orderbook::{BookLevel, OrderBook, analysis::book_check_integrity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Configuration for the [`BookVizEndpoint`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookVizConfig {
+    /// The number of levels per side returned when the request has no `depth`.
+    pub default_depth: usize,
+    /// The maximum number of levels per side a request may ask for.
+    pub max_depth: usize,
+    /// The minimum interval between snapshots served for the same instrument (nanoseconds).
+    pub min_interval_ns: u64,
+    /// The time an accepted connection has to send its request head before being dropped.
+    pub read_timeout: Duration,
+    /// The time a connection has to receive its response before being dropped.
+    pub write_timeout: Duration,
+    /// The maximum number of new connections accepted per poll.
+    pub max_accepts_per_poll: usize,
+    /// The maximum number of connections held open awaiting their request head or writing their
+    /// response.
+    pub max_pending: usize,
+}
+
+impl Default for BookVizConfig {
+    /// Creates a new default [`BookVizConfig`] instance.
+    fn default() -> Self {
+        Self {
+            default_depth: 10,
+            max_depth: 100,
+            min_interval_ns: 1_000_000_000,
+            read_timeout: Duration::from_millis(100),
+            write_timeout: Duration::from_secs(5),
+            max_accepts_per_poll: 16,
+            max_pending: 64,
+        }
+    }
+}
+
+/// A price level in a [`BookSnapshotView`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookLevelView {
+    pub price: String,
+    pub size: String,
+    pub orders: usize,
+}
+
+impl From<&BookLevel> for BookLevelView {
+    fn from(level: &BookLevel) -> Self {
+        Self {
+            price: level.price.value.to_string(),
+            size: level.size_decimal().to_string(),
+            orders: level.len(),
+        }
+    }
+}
+
+/// The integrity status of a [`BookSnapshotView`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookIntegrityStatus {
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+/// The JSON body served for an order book.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSnapshotView {
+    pub instrument_id: InstrumentId,
+    pub book_type: BookType,
+    pub bids: Vec<BookLevelView>,
+    pub asks: Vec<BookLevelView>,
+    pub sequence: u64,
+    pub update_count: u64,
+    pub ts_last: UnixNanos,
+    pub integrity: BookIntegrityStatus,
+}
+
+impl BookSnapshotView {
+    /// Creates a view of the top `depth` levels per side of `book`.
+    #[must_use]
+    pub fn from_book(book: &OrderBook, depth: usize) -> Self {
+        let integrity = match book_check_integrity(book) {
+            Ok(()) => BookIntegrityStatus {
+                ok: true,
+                error: None,
+            },
+            Err(e) => BookIntegrityStatus {
+                ok: false,
+                error: Some(e.to_string()),
+            },
+        };
+        Self {
+            instrument_id: book.instrument_id,
+            book_type: book.book_type,
+            bids: book.bids(Some(depth)).map(BookLevelView::from).collect(),
+            asks: book.asks(Some(depth)).map(BookLevelView::from).collect(),
+            sequence: book.sequence,
+            update_count: book.update_count,
+            ts_last: book.ts_last,
+            integrity,
+        }
+    }
+}
+
+/// An HTTP response produced by the [`BookVizEndpoint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookVizResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl BookVizResponse {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+
+    const fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            429 => "Too Many Requests",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn to_http(&self) -> String {
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body,
+        )
+    }
+}
+
+/// An accepted connection whose request head has not fully arrived.
+#[derive(Debug)]
+struct PendingConnection {
+    stream: TcpStream,
+    request: Vec<u8>,
+    accepted_at: Instant,
+}
+
+/// A connection whose response has not been fully written.
+#[derive(Debug)]
+struct WritingConnection {
+    stream: TcpStream,
+    response: Vec<u8>,
+    written: usize,
+    started_at: Instant,
+}
+
+/// The progress of reading a pending connection's request head.
+enum ReadProgress {
+    Complete,
+    Pending,
+    Closed,
+}
+
+/// The progress of writing a connection's response.
+enum WriteProgress {
+    Complete,
+    Pending,
+}
+
+const MAX_REQUEST_HEAD_LEN: usize = 8192;
+
+/// Serves order book snapshots from the cache over HTTP.
+#[derive(Clone)]
+pub struct BookVizEndpoint {
+    config: BookVizConfig,
+    cache: Rc<RefCell<Cache>>,
+    listener: Rc<RefCell<Option<TcpListener>>>,
+    pending: Rc<RefCell<Vec<PendingConnection>>>,
+    writing: Rc<RefCell<Vec<WritingConnection>>>,
+    last_served: Rc<RefCell<AHashMap<InstrumentId, UnixNanos>>>,
+}
+
+impl Debug for BookVizEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(BookVizEndpoint))
+            .field("config", &self.config)
+            .field("listener", &self.listener.borrow())
+            .field("pending", &self.pending.borrow().len())
+            .field("writing", &self.writing.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl BookVizEndpoint {
+    pub const TIMER_NAME: &'static str = "BOOK_VIZ_POLL";
+
+    /// Creates a new [`BookVizEndpoint`] instance.
+    #[must_use]
+    pub fn new(config: BookVizConfig, cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            config,
+            cache,
+            listener: Rc::new(RefCell::new(None)),
+            pending: Rc::new(RefCell::new(Vec::new())),
+            writing: Rc::new(RefCell::new(Vec::new())),
+            last_served: Rc::new(RefCell::new(AHashMap::new())),
+        }
+    }
+
+    /// Binds the endpoint's listener to `addr`, returning the bound local address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if binding the listener fails.
+    pub fn bind(&self, addr: &str) -> anyhow::Result<SocketAddr> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+        log::info!("Order book snapshot endpoint listening on http://{local_addr}");
+        *self.listener.borrow_mut() = Some(listener);
+        Ok(local_addr)
+    }
+
+    /// Handles a request for `method` and `target` (path and query) at `ts_now`.
+    #[must_use]
+    pub fn handle_request(&self, method: &str, target: &str, ts_now: UnixNanos) -> BookVizResponse {
+        if method != "GET" {
+            return BookVizResponse::error(405, "Only GET is supported");
+        }
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let Some(raw_id) = path.strip_prefix("/book/").filter(|id| !id.is_empty()) else {
+            return BookVizResponse::error(404, "Expected /book/{instrument_id}");
+        };
+        let raw_id = raw_id.replace("%2F", "/").replace("%2f", "/");
+        let Ok(instrument_id) = InstrumentId::from_as_ref(&raw_id) else {
+            return BookVizResponse::error(400, &format!("Invalid instrument ID {raw_id}"));
+        };
+
+        let mut depth = self.config.default_depth;
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            if key == "depth" {
+                match value.parse::<usize>() {
+                    Ok(value) if value > 0 => depth = value.min(self.config.max_depth),
+                    _ => return BookVizResponse::error(400, &format!("Invalid depth {value}")),
+                }
+            }
+        }
+
+        if let Some(last) = self.last_served.borrow().get(&instrument_id)
+            && ts_now.as_u64().saturating_sub(last.as_u64()) < self.config.min_interval_ns
+        {
+            return BookVizResponse::error(429, &format!("Throttled {instrument_id}"));
+        }
+
+        let cache = self.cache.borrow();
+        let Some(book) = cache.order_book(&instrument_id) else {
+            return BookVizResponse::error(404, &format!("No order book for {instrument_id}"));
+        };
+        let view = BookSnapshotView::from_book(book, depth);
+        match serde_json::to_string(&view) {
+            Ok(body) => {
+                self.last_served.borrow_mut().insert(instrument_id, ts_now);
+                BookVizResponse { status: 200, body }
+            }
+            Err(e) => BookVizResponse::error(500, &e.to_string()),
+        }
+    }
+
+    /// Accepts new connections and answers those whose request has arrived at `ts_now`,
+    /// returning the number of requests answered.
+    ///
+    /// At most [`BookVizConfig::max_accepts_per_poll`] connections are accepted per call, and
+    /// connections are only read and written without blocking: a connection whose request head
+    /// is still incomplete is kept for later polls until [`BookVizConfig::read_timeout`] elapses,
+    /// and the unsent part of a response is written on later polls until
+    /// [`BookVizConfig::write_timeout`] elapses.
+    ///
+    /// Returns zero when the endpoint has not been bound.
+    pub fn poll(&self, ts_now: UnixNanos) -> usize {
+        if self.listener.borrow().is_none() {
+            return 0;
+        }
+        self.flush_writing();
+        self.accept_pending();
+
+        let mut served = 0;
+        let mut pending = self.pending.borrow_mut();
+        let mut i = 0;
+        while i < pending.len() {
+            let connection = &mut pending[i];
+            let progress = match read_available(connection) {
+                Ok(progress) => progress,
+                Err(e) => {
+                    log::warn!("Failed to read order book snapshot request: {e}");
+                    ReadProgress::Closed
+                }
+            };
+
+            let response = match progress {
+                ReadProgress::Complete => Some(self.respond(&connection.request, ts_now)),
+                ReadProgress::Pending
+                    if connection.accepted_at.elapsed() >= self.config.read_timeout =>
+                {
+                    Some(BookVizResponse::error(
+                        408,
+                        "Request head not received in time",
+                    ))
+                }
+                ReadProgress::Pending => {
+                    i += 1;
+                    continue;
+                }
+                ReadProgress::Closed => None,
+            };
+
+            let connection = pending.swap_remove(i);
+            if let Some(response) = response {
+                self.writing.borrow_mut().push(WritingConnection {
+                    stream: connection.stream,
+                    response: response.to_http().into_bytes(),
+                    written: 0,
+                    started_at: Instant::now(),
+                });
+                served += 1;
+            }
+        }
+        drop(pending);
+
+        // Most responses fit the socket send buffer and complete within the same poll
+        if served > 0 {
+            self.flush_writing();
+        }
+        served
+    }
+
+    /// Writes what the sockets accept of the queued responses, dropping connections once their
+    /// response is complete, they fail, or their write timeout elapses.
+    fn flush_writing(&self) {
+        self.writing
+            .borrow_mut()
+            .retain_mut(|connection| match write_available(connection) {
+                Ok(WriteProgress::Complete) => false,
+                Ok(WriteProgress::Pending)
+                    if connection.started_at.elapsed() >= self.config.write_timeout =>
+                {
+                    log::warn!(
+                        "Dropping order book snapshot connection after writing {} of {} bytes",
+                        connection.written,
+                        connection.response.len()
+                    );
+                    false
+                }
+                Ok(WriteProgress::Pending) => true,
+                Err(e) => {
+                    log::warn!("Failed to serve order book snapshot request: {e}");
+                    false
+                }
+            });
+    }
+
+    fn accept_pending(&self) {
+        let listener = self.listener.borrow();
+        let Some(listener) = listener.as_ref() else {
+            return;
+        };
+        let mut pending = self.pending.borrow_mut();
+        let writing = self.writing.borrow().len();
+
+        for _ in 0..self.config.max_accepts_per_poll {
+            if pending.len() + writing >= self.config.max_pending {
+                break;
+            }
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if let Err(e) = stream.set_nonblocking(true) {
+                        log::warn!("Failed to configure order book snapshot connection: {e}");
+                        continue;
+                    }
+                    pending.push(PendingConnection {
+                        stream,
+                        request: Vec::new(),
+                        accepted_at: Instant::now(),
+                    });
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    log::error!("Failed to accept order book snapshot connection: {e}");
+                    break;
+                }
+            }
+        }
+    }
+
+    fn respond(&self, request: &[u8], ts_now: UnixNanos) -> BookVizResponse {
+        let head = String::from_utf8_lossy(request);
+        let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => self.handle_request(method, target, ts_now),
+            _ => BookVizResponse::error(400, "Malformed request line"),
+        }
+    }
+
+    /// Starts serving pending connections every `interval_ns` on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint has not been bound or the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock, interval_ns: u64) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.listener.borrow().is_some(),
+            "Order book snapshot endpoint was not bound"
+        );
+        let endpoint = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            endpoint.poll(event.ts_event);
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            interval_ns,
+            None,
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Stops serving on `clock`, closing the listener and any pending connections.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+        self.listener.borrow_mut().take();
+        self.pending.borrow_mut().clear();
+        self.writing.borrow_mut().clear();
+    }
+}
+
+/// Reads whatever has arrived on `connection` without blocking.
+fn read_available(connection: &mut PendingConnection) -> anyhow::Result<ReadProgress> {
+    let mut buf = [0_u8; 1024];
+    loop {
+        if connection.request.windows(4).any(|w| w == b"\r\n\r\n") {
+            return Ok(ReadProgress::Complete);
+        }
+        match connection.stream.read(&mut buf) {
+            Ok(0) => return Ok(ReadProgress::Closed),
+            Ok(n) => {
+                connection.request.extend_from_slice(&buf[..n]);
+                anyhow::ensure!(
+                    connection.request.len() <= MAX_REQUEST_HEAD_LEN,
+                    "Request head too large"
+                );
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(ReadProgress::Pending),
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Writes what the socket accepts of the remaining response on `connection` without blocking.
+fn write_available(connection: &mut WritingConnection) -> anyhow::Result<WriteProgress> {
+    while connection.written < connection.response.len() {
+        match connection
+            .stream
+            .write(&connection.response[connection.written..])
+        {
+            Ok(0) => anyhow::bail!("Connection closed before the response was written"),
+            Ok(n) => connection.written += n,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(WriteProgress::Pending),
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(WriteProgress::Complete)
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BookOrder,
+        enums::OrderSide,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn endpoint(config: BookVizConfig) -> BookVizEndpoint {
+        let instrument_id = InstrumentId::from("AUDUSD.SIM");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        for ((side, price), i) in [
+            (OrderSide::Buy, "0.70000"),
+            (OrderSide::Buy, "0.69990"),
+            (OrderSide::Sell, "0.70010"),
+            (OrderSide::Sell, "0.70020"),
+        ]
+        .into_iter()
+        .zip(1_u64..)
+        {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(100_000), i);
+            book.add(order, 0, i, UnixNanos::from(10));
+        }
+
+        let mut cache = Cache::default();
+        cache.add_order_book(book).unwrap();
+        BookVizEndpoint::new(config, Rc::new(RefCell::new(cache)))
+    }
+
+    #[rstest]
+    fn test_handle_request_returns_top_levels() {
+        let endpoint = endpoint(BookVizConfig::default());
+
+        let response =
+            endpoint.handle_request("GET", "/book/AUDUSD.SIM?depth=1", UnixNanos::from(0));
+
+        assert_eq!(response.status, 200);
+        let view: BookSnapshotView = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(view.bids.len(), 1);
+        assert_eq!(view.asks.len(), 1);
+        assert_eq!(view.bids[0].price, "0.70000");
+        assert_eq!(view.asks[0].price, "0.70010");
+        assert_eq!(view.sequence, 4);
+        assert_eq!(view.ts_last, UnixNanos::from(10));
+        assert!(view.integrity.ok);
+    }
+
+    #[rstest]
+    #[case("POST", "/book/AUDUSD.SIM", 405)]
+    #[case("GET", "/orders", 404)]
+    #[case("GET", "/book/ETHUSDT.BINANCE", 404)]
+    #[case("GET", "/book/INVALID", 400)]
+    #[case("GET", "/book/AUDUSD.SIM?depth=0", 400)]
+    fn test_handle_request_errors(
+        #[case] method: &str,
+        #[case] target: &str,
+        #[case] expected: u16,
+    ) {
+        let endpoint = endpoint(BookVizConfig::default());
+        assert_eq!(
+            endpoint
+                .handle_request(method, target, UnixNanos::from(0))
+                .status,
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_handle_request_throttles_per_instrument() {
+        let endpoint = endpoint(BookVizConfig {
+            min_interval_ns: 100,
+            ..Default::default()
+        });
+        let target = "/book/AUDUSD.SIM";
+
+        assert_eq!(
+            endpoint
+                .handle_request("GET", target, UnixNanos::from(0))
+                .status,
+            200
+        );
+        assert_eq!(
+            endpoint
+                .handle_request("GET", target, UnixNanos::from(99))
+                .status,
+            429
+        );
+        assert_eq!(
+            endpoint
+                .handle_request("GET", target, UnixNanos::from(100))
+                .status,
+            200
+        );
+    }
+
+    #[rstest]
+    fn test_poll_serves_http_request() {
+        let endpoint = endpoint(BookVizConfig::default());
+        let addr = endpoint.bind("127.0.0.1:0").unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream
+                .write_all(b"GET /book/AUDUSD.SIM HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        while endpoint.poll(UnixNanos::from(0)) == 0 {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\"instrument_id\":\"AUDUSD.SIM\""));
+    }
+
+    #[rstest]
+    fn test_poll_does_not_block_on_idle_connections() {
+        let endpoint = endpoint(BookVizConfig {
+            read_timeout: Duration::from_millis(200),
+            max_accepts_per_poll: 1,
+            ..Default::default()
+        });
+        let addr = endpoint.bind("127.0.0.1:0").unwrap();
+
+        // Connects without sending a request
+        let mut idle = TcpStream::connect(addr).unwrap();
+        let mut active = TcpStream::connect(addr).unwrap();
+        active
+            .write_all(b"GET /book/AUDUSD.SIM HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+
+        // Each poll accepts a single connection, and the idle one never holds it up
+        while endpoint.poll(UnixNanos::from(0)) == 0 {
+            assert!(endpoint.pending.borrow().len() <= 2);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(endpoint.pending.borrow().len(), 1);
+
+        let mut response = String::new();
+        active.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        // The idle connection is answered once its read timeout elapses
+        std::thread::sleep(Duration::from_millis(250));
+        assert_eq!(endpoint.poll(UnixNanos::from(0)), 1);
+        assert!(endpoint.pending.borrow().is_empty());
+
+        let mut response = String::new();
+        idle.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+    }
+
+    #[rstest]
+    fn test_poll_queues_responses_for_stalled_clients() {
+        let endpoint = endpoint(BookVizConfig {
+            write_timeout: Duration::from_millis(200),
+            ..Default::default()
+        });
+        let addr = endpoint.bind("127.0.0.1:0").unwrap();
+
+        // Queues a response far larger than the socket buffers for clients which do not read
+        let mut clients = Vec::new();
+        for _ in 0..2 {
+            let client = TcpStream::connect(addr).unwrap();
+            while endpoint.pending.borrow().len() + endpoint.writing.borrow().len()
+                < clients.len() + 1
+            {
+                endpoint.poll(UnixNanos::from(0));
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            clients.push(client);
+        }
+        let body = vec![b'x'; 32 * 1024 * 1024];
+        for connection in endpoint.pending.borrow_mut().drain(..) {
+            endpoint.writing.borrow_mut().push(WritingConnection {
+                stream: connection.stream,
+                response: body.clone(),
+                written: 0,
+                started_at: Instant::now(),
+            });
+        }
+
+        let start = Instant::now();
+        endpoint.poll(UnixNanos::from(0));
+        assert!(start.elapsed() < Duration::from_millis(100));
+        assert_eq!(endpoint.writing.borrow().len(), 2);
+
+        // A reading client receives its whole response over later polls
+        let mut reader = clients.remove(0);
+        let reader = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            reader.read_to_end(&mut received).unwrap();
+            received.len()
+        });
+        while endpoint.writing.borrow().len() == 2 {
+            endpoint.poll(UnixNanos::from(0));
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(reader.join().unwrap(), body.len());
+
+        // The stalled client is dropped once its write timeout elapses
+        std::thread::sleep(Duration::from_millis(250));
+        endpoint.poll(UnixNanos::from(0));
+        assert!(endpoint.writing.borrow().is_empty());
+    }
+}
@@ -0,0 +1,561 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Management of bracket orders and their attached stop-loss and take-profit children.
+//!
+//! Brackets are created with [`OrderFactory::bracket`](crate::factories::OrderFactory::bracket)
+//! and registered with a [`BracketManager`], which builds the submit command and then tracks the
+//! entry fill. Once the entry has filled, trailing and breakeven rules move the stop-loss child
+//! as prices update, emitting [`BracketAdjustment`]s to be sent as modify commands.
+//!
+//! Venues without native bracket support are handled by emulating the children locally, see
+//! [`BracketSupport::emulation_trigger`].
+
+use ahash::AHashMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    enums::{ContingencyType, OrderSide, TriggerType},
+    events::OrderFilled,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+    orders::{Order, OrderList},
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::execution::{ModifyOrder, SubmitOrderList};
+
+/// Whether a venue supports bracket orders natively.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BracketSupport {
+    /// The venue manages the contingent children.
+    #[default]
+    Native,
+    /// The children are held and triggered locally by the order emulator.
+    Emulated,
+}
+
+impl BracketSupport {
+    /// Returns the emulation trigger to create the bracket with.
+    #[must_use]
+    pub const fn emulation_trigger(self) -> Option<TriggerType> {
+        match self {
+            Self::Native => None,
+            Self::Emulated => Some(TriggerType::Default),
+        }
+    }
+}
+
+/// The reason a stop-loss child was moved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AdjustmentReason {
+    Trailing,
+    Breakeven,
+}
+
+/// A stop-loss child trigger price change produced by a [`BracketManager`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BracketAdjustment {
+    pub entry_id: ClientOrderId,
+    pub stop_loss_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub trigger_price: Price,
+    pub reason: AdjustmentReason,
+}
+
+/// The tracked state of a single bracket.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BracketState {
+    pub entry_id: ClientOrderId,
+    pub stop_loss_id: ClientOrderId,
+    pub take_profit_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub entry_side: OrderSide,
+    pub stop_loss_price: Price,
+    pub take_profit_price: Price,
+    /// The average entry fill price, once the entry has (partially) filled.
+    pub entry_avg_px: Option<f64>,
+    pub entry_filled_qty: f64,
+    /// The trailing distance of the stop-loss from the market price.
+    pub trailing_offset: Option<f64>,
+    /// The favorable move from entry which triggers the breakeven adjustment.
+    pub breakeven_trigger: Option<f64>,
+    /// The offset from entry the stop-loss is moved to on breakeven.
+    pub breakeven_offset: f64,
+    pub breakeven_applied: bool,
+}
+
+impl BracketState {
+    fn direction(&self) -> f64 {
+        match self.entry_side {
+            OrderSide::Sell => -1.0,
+            _ => 1.0,
+        }
+    }
+
+    fn improves(&self, candidate: f64) -> bool {
+        (candidate - self.stop_loss_price.as_f64()) * self.direction() > 0.0
+    }
+}
+
+/// Tracks bracket orders and adjusts their stop-loss children after the entry fills.
+#[derive(Debug)]
+pub struct BracketManager {
+    trader_id: TraderId,
+    brackets: AHashMap<ClientOrderId, BracketState>,
+    children: AHashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl BracketManager {
+    /// Creates a new [`BracketManager`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            brackets: AHashMap::new(),
+            children: AHashMap::new(),
+        }
+    }
+
+    /// Registers a bracket order list, returning the entry client order ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list is not an entry with stop-loss and take-profit children, or the
+    /// entry is already registered.
+    pub fn register(&mut self, order_list: &OrderList) -> anyhow::Result<ClientOrderId> {
+        let Some(entry) = order_list
+            .orders
+            .iter()
+            .find(|order| order.contingency_type() == Some(ContingencyType::Oto))
+        else {
+            anyhow::bail!("Order list {} has no OTO entry order", order_list.id);
+        };
+        let entry_id = entry.client_order_id();
+        if self.brackets.contains_key(&entry_id) {
+            anyhow::bail!("Bracket {entry_id} already registered");
+        }
+
+        let mut stop_loss = None;
+        let mut take_profit = None;
+        for order in order_list
+            .orders
+            .iter()
+            .filter(|order| order.parent_order_id() == Some(entry_id))
+        {
+            match (order.trigger_price(), order.price()) {
+                (Some(trigger_price), _) => {
+                    stop_loss = Some((order.client_order_id(), trigger_price))
+                }
+                (None, Some(price)) => take_profit = Some((order.client_order_id(), price)),
+                (None, None) => {}
+            }
+        }
+        let (Some((stop_loss_id, stop_loss_price)), Some((take_profit_id, take_profit_price))) =
+            (stop_loss, take_profit)
+        else {
+            anyhow::bail!(
+                "Order list {} requires stop-loss and take-profit children of {entry_id}",
+                order_list.id
+            );
+        };
+
+        self.children.insert(stop_loss_id, entry_id);
+        self.children.insert(take_profit_id, entry_id);
+        self.brackets.insert(
+            entry_id,
+            BracketState {
+                entry_id,
+                stop_loss_id,
+                take_profit_id,
+                instrument_id: entry.instrument_id(),
+                strategy_id: entry.strategy_id(),
+                entry_side: entry.order_side(),
+                stop_loss_price,
+                take_profit_price,
+                entry_avg_px: None,
+                entry_filled_qty: 0.0,
+                trailing_offset: None,
+                breakeven_trigger: None,
+                breakeven_offset: 0.0,
+                breakeven_applied: false,
+            },
+        );
+
+        Ok(entry_id)
+    }
+
+    /// Registers the bracket and returns the command to submit it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bracket cannot be registered (see [`Self::register`]).
+    pub fn submit(
+        &mut self,
+        order_list: OrderList,
+        client_id: Option<ClientId>,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<SubmitOrderList> {
+        self.register(&order_list)?;
+        Ok(SubmitOrderList::new(
+            self.trader_id,
+            client_id,
+            order_list.strategy_id,
+            order_list.instrument_id,
+            order_list,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            ts_init,
+        ))
+    }
+
+    /// Returns the state of the bracket with the given entry ID.
+    #[must_use]
+    pub fn bracket(&self, entry_id: &ClientOrderId) -> Option<&BracketState> {
+        self.brackets.get(entry_id)
+    }
+
+    /// Returns the number of tracked brackets.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.brackets.len()
+    }
+
+    /// Trails the stop-loss `offset` behind the best market price after entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bracket is not registered, or `offset` is not positive.
+    pub fn set_trailing_stop(
+        &mut self,
+        entry_id: &ClientOrderId,
+        offset: f64,
+    ) -> anyhow::Result<()> {
+        if offset <= 0.0 || !offset.is_finite() {
+            anyhow::bail!("Invalid trailing offset {offset} for bracket {entry_id}");
+        }
+        self.bracket_mut(entry_id)?.trailing_offset = Some(offset);
+        Ok(())
+    }
+
+    /// Moves the stop-loss to the entry price plus `offset` once the market has moved `trigger`
+    /// in favor of the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bracket is not registered, or `trigger` is not positive.
+    pub fn set_breakeven(
+        &mut self,
+        entry_id: &ClientOrderId,
+        trigger: f64,
+        offset: f64,
+    ) -> anyhow::Result<()> {
+        if trigger <= 0.0 || !trigger.is_finite() || !offset.is_finite() {
+            anyhow::bail!("Invalid breakeven trigger {trigger} for bracket {entry_id}");
+        }
+        let bracket = self.bracket_mut(entry_id)?;
+        bracket.breakeven_trigger = Some(trigger);
+        bracket.breakeven_offset = offset;
+        Ok(())
+    }
+
+    /// Handles a fill for any order of a tracked bracket.
+    ///
+    /// Entry fills update the average entry price, while a fill of either child completes the
+    /// bracket, which is then no longer tracked.
+    pub fn on_fill(&mut self, fill: &OrderFilled) {
+        if let Some(bracket) = self.brackets.get_mut(&fill.client_order_id) {
+            let qty = fill.last_qty.as_f64();
+            let notional = bracket.entry_avg_px.unwrap_or(0.0) * bracket.entry_filled_qty
+                + fill.last_px.as_f64() * qty;
+            bracket.entry_filled_qty += qty;
+            bracket.entry_avg_px = Some(notional / bracket.entry_filled_qty);
+        } else if let Some(entry_id) = self.children.get(&fill.client_order_id).copied() {
+            self.remove(&entry_id);
+        }
+    }
+
+    /// Updates tracked brackets for `instrument_id` with a market `price`, returning the resulting
+    /// stop-loss adjustments.
+    ///
+    /// Stops are only ever moved in favor of the entry, and only after the entry has filled.
+    pub fn on_price(
+        &mut self,
+        instrument_id: &InstrumentId,
+        price: Price,
+    ) -> Vec<BracketAdjustment> {
+        let px = price.as_f64();
+        let mut adjustments = Vec::new();
+
+        for bracket in self
+            .brackets
+            .values_mut()
+            .filter(|bracket| bracket.instrument_id == *instrument_id)
+        {
+            let Some(entry_px) = bracket.entry_avg_px else {
+                continue;
+            };
+            let direction = bracket.direction();
+            let mut candidate: Option<(f64, AdjustmentReason)> = None;
+
+            if let Some(trigger) = bracket.breakeven_trigger
+                && !bracket.breakeven_applied
+                && (px - entry_px) * direction >= trigger
+            {
+                bracket.breakeven_applied = true;
+                let stop = entry_px + bracket.breakeven_offset * direction;
+                if bracket.improves(stop) {
+                    candidate = Some((stop, AdjustmentReason::Breakeven));
+                }
+            }
+
+            if let Some(offset) = bracket.trailing_offset {
+                let stop = px - offset * direction;
+                let better =
+                    candidate.is_none_or(|(current, _)| (stop - current) * direction > 0.0);
+                if better && bracket.improves(stop) {
+                    candidate = Some((stop, AdjustmentReason::Trailing));
+                }
+            }
+
+            if let Some((stop, reason)) = candidate {
+                let trigger_price = Price::new(stop, bracket.stop_loss_price.precision);
+                if trigger_price == bracket.stop_loss_price {
+                    continue;
+                }
+                bracket.stop_loss_price = trigger_price;
+                adjustments.push(BracketAdjustment {
+                    entry_id: bracket.entry_id,
+                    stop_loss_id: bracket.stop_loss_id,
+                    instrument_id: bracket.instrument_id,
+                    strategy_id: bracket.strategy_id,
+                    trigger_price,
+                    reason,
+                });
+            }
+        }
+
+        adjustments
+    }
+
+    /// Returns the command modifying the stop-loss child for `adjustment`.
+    #[must_use]
+    pub fn modify_command(
+        &self,
+        adjustment: &BracketAdjustment,
+        client_id: Option<ClientId>,
+        venue_order_id: Option<VenueOrderId>,
+        ts_init: UnixNanos,
+    ) -> ModifyOrder {
+        ModifyOrder::new(
+            self.trader_id,
+            client_id,
+            adjustment.strategy_id,
+            adjustment.instrument_id,
+            adjustment.stop_loss_id,
+            venue_order_id,
+            None,
+            None,
+            Some(adjustment.trigger_price),
+            UUID4::new(),
+            ts_init,
+            None,
+        )
+    }
+
+    /// Stops tracking the bracket with the given entry ID, returning its final state.
+    pub fn remove(&mut self, entry_id: &ClientOrderId) -> Option<BracketState> {
+        let bracket = self.brackets.remove(entry_id)?;
+        self.children.remove(&bracket.stop_loss_id);
+        self.children.remove(&bracket.take_profit_id);
+        Some(bracket)
+    }
+
+    fn bracket_mut(&mut self, entry_id: &ClientOrderId) -> anyhow::Result<&mut BracketState> {
+        match self.brackets.get_mut(entry_id) {
+            Some(bracket) => Ok(bracket),
+            None => anyhow::bail!("Bracket {entry_id} not registered"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::{
+        identifiers::stubs::{strategy_id_ema_cross, trader_id},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, factories::OrderFactory};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT.BINANCE")
+    }
+
+    fn bracket(side: OrderSide, sl: &str, tp: &str, support: BracketSupport) -> OrderList {
+        let mut factory = OrderFactory::new(
+            trader_id(),
+            strategy_id_ema_cross(),
+            None,
+            None,
+            Rc::new(RefCell::new(TestClock::new())),
+            false,
+            true,
+        );
+        factory.bracket(
+            instrument_id(),
+            side,
+            Quantity::from("10"),
+            Some(Price::from("100.00")),
+            Price::from(sl),
+            None,
+            Price::from(tp),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            support.emulation_trigger(),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn fill(client_order_id: ClientOrderId, px: &str, qty: &str) -> OrderFilled {
+        OrderFilled {
+            client_order_id,
+            instrument_id: instrument_id(),
+            last_px: Price::from(px),
+            last_qty: Quantity::from(qty),
+            ..Default::default()
+        }
+    }
+
+    fn manager_with_filled_entry(
+        side: OrderSide,
+        sl: &str,
+        tp: &str,
+    ) -> (BracketManager, ClientOrderId) {
+        let mut manager = BracketManager::new(trader_id());
+        let entry_id = manager
+            .register(&bracket(side, sl, tp, BracketSupport::Native))
+            .unwrap();
+        manager.on_fill(&fill(entry_id, "100.00", "10"));
+        (manager, entry_id)
+    }
+
+    #[rstest]
+    fn test_register_extracts_children() {
+        let order_list = bracket(OrderSide::Buy, "95.00", "110.00", BracketSupport::Emulated);
+        let mut manager = BracketManager::new(trader_id());
+
+        let command = manager
+            .submit(order_list, None, UnixNanos::default())
+            .unwrap();
+        let entry_id = command.order_list.orders[0].client_order_id();
+        let state = manager.bracket(&entry_id).unwrap();
+
+        assert_eq!(
+            state.stop_loss_id,
+            command.order_list.orders[1].client_order_id()
+        );
+        assert_eq!(
+            state.take_profit_id,
+            command.order_list.orders[2].client_order_id()
+        );
+        assert_eq!(state.stop_loss_price, Price::from("95.00"));
+        assert_eq!(state.take_profit_price, Price::from("110.00"));
+        assert_eq!(
+            command.order_list.orders[1].emulation_trigger(),
+            Some(TriggerType::Default)
+        );
+        assert!(manager.register(&command.order_list).is_err());
+    }
+
+    #[rstest]
+    fn test_no_adjustment_before_entry_fill() {
+        let mut manager = BracketManager::new(trader_id());
+        let entry_id = manager
+            .register(&bracket(
+                OrderSide::Buy,
+                "95.00",
+                "110.00",
+                BracketSupport::Native,
+            ))
+            .unwrap();
+        manager.set_trailing_stop(&entry_id, 2.0).unwrap();
+
+        let adjustments = manager.on_price(&instrument_id(), Price::from("105.00"));
+
+        assert!(adjustments.is_empty());
+    }
+
+    #[rstest]
+    fn test_trailing_stop_only_ratchets_in_favor() {
+        let (mut manager, entry_id) = manager_with_filled_entry(OrderSide::Buy, "95.00", "110.00");
+        manager.set_trailing_stop(&entry_id, 2.0).unwrap();
+
+        let up = manager.on_price(&instrument_id(), Price::from("104.00"));
+        let down = manager.on_price(&instrument_id(), Price::from("103.00"));
+
+        assert_eq!(up.len(), 1);
+        assert_eq!(up[0].trigger_price, Price::from("102.00"));
+        assert_eq!(up[0].reason, AdjustmentReason::Trailing);
+        assert!(down.is_empty());
+        assert_eq!(
+            manager.bracket(&entry_id).unwrap().stop_loss_price,
+            Price::from("102.00")
+        );
+    }
+
+    #[rstest]
+    fn test_breakeven_for_short_entry() {
+        let (mut manager, entry_id) = manager_with_filled_entry(OrderSide::Sell, "105.00", "90.00");
+        manager.set_breakeven(&entry_id, 3.0, 0.5).unwrap();
+
+        let early = manager.on_price(&instrument_id(), Price::from("98.00"));
+        let triggered = manager.on_price(&instrument_id(), Price::from("97.00"));
+
+        assert!(early.is_empty());
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].trigger_price, Price::from("99.50"));
+        assert_eq!(triggered[0].reason, AdjustmentReason::Breakeven);
+
+        let command = manager.modify_command(&triggered[0], None, None, UnixNanos::default());
+        assert_eq!(command.client_order_id, triggered[0].stop_loss_id);
+        assert_eq!(command.trigger_price, Some(Price::from("99.50")));
+    }
+
+    #[rstest]
+    fn test_child_fill_completes_bracket() {
+        let (mut manager, entry_id) = manager_with_filled_entry(OrderSide::Buy, "95.00", "110.00");
+        let take_profit_id = manager.bracket(&entry_id).unwrap().take_profit_id;
+
+        manager.on_fill(&fill(take_profit_id, "110.00", "10"));
+
+        assert_eq!(manager.count(), 0);
+        assert!(manager.set_trailing_stop(&entry_id, 1.0).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pluggable compression of payloads persisted to the cache database.
+//!
+//! Every payload written through [`compress`] is wrapped in a versioned envelope, including
+//! payloads stored with [`CompressionCodec::None`]:
+//!
+//! | Bytes  | Field                                      |
+//! |--------|--------------------------------------------|
+//! | 0..4   | Magic `0xC1 'N' 'X' 'Z'`                   |
+//! | 4      | Envelope version (currently `1`)           |
+//! | 5      | Codec tag                                  |
+//! | 6..14  | Uncompressed length (little-endian `u64`)  |
+//! | 14..   | Body                                       |
+//!
+//! Since every payload is enveloped the stored bytes are never interpreted by inspecting user
+//! data, and the recorded length is validated against the decoded body. Payloads written before
+//! the envelope was introduced carry no magic and are passed through unchanged on decompression
+//! until they are migrated with [`migrate_payload`].
+//!
+//! Order and position snapshots are encoded with [`encode_snapshot`] so database adapters apply
+//! the same codec and envelope to their snapshot path as to general entries.
+//!
+//! The `zstd` and `lz4` codecs require the corresponding crate features.
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use strum::Display;
+
+const ENVELOPE_MAGIC: [u8; 4] = [0xC1, b'N', b'X', b'Z'];
+const ENVELOPE_VERSION: u8 = 1;
+const HEADER_LEN: usize = 14;
+
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// The compression codec for payloads persisted to the cache database.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionCodec {
+    /// Payloads are stored uncompressed.
+    #[default]
+    None,
+    /// Zstandard compression (requires the `zstd` feature).
+    Zstd,
+    /// LZ4 block compression (requires the `lz4` feature).
+    Lz4,
+}
+
+impl CompressionCodec {
+    const fn tag(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Zstd => 1,
+            Self::Lz4 => 2,
+        }
+    }
+
+    const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::None),
+            1 => Some(Self::Zstd),
+            2 => Some(Self::Lz4),
+            _ => None,
+        }
+    }
+}
+
+struct Envelope<'a> {
+    codec: CompressionCodec,
+    len: usize,
+    body: &'a [u8],
+}
+
+/// Parses the envelope of a stored `payload`, returning `None` for legacy payloads without
+/// the envelope magic.
+fn parse_envelope(payload: &[u8]) -> anyhow::Result<Option<Envelope<'_>>> {
+    if !payload.starts_with(&ENVELOPE_MAGIC) {
+        return Ok(None);
+    }
+    anyhow::ensure!(
+        payload.len() >= HEADER_LEN,
+        "Truncated cache payload envelope ({} bytes)",
+        payload.len()
+    );
+
+    let version = payload[4];
+    anyhow::ensure!(
+        version == ENVELOPE_VERSION,
+        "Unsupported cache payload envelope version {version}"
+    );
+
+    let Some(codec) = CompressionCodec::from_tag(payload[5]) else {
+        anyhow::bail!("Unknown cache payload codec tag {}", payload[5]);
+    };
+
+    let mut len_bytes = [0u8; 8];
+    len_bytes.copy_from_slice(&payload[6..HEADER_LEN]);
+    let len = usize::try_from(u64::from_le_bytes(len_bytes))?;
+
+    Ok(Some(Envelope {
+        codec,
+        len,
+        body: &payload[HEADER_LEN..],
+    }))
+}
+
+/// Returns the codec a stored `payload` was encoded with, or `None` for legacy payloads
+/// stored without an envelope (or with a malformed one).
+#[must_use]
+pub fn payload_codec(payload: &[u8]) -> Option<CompressionCodec> {
+    parse_envelope(payload).ok().flatten().map(|e| e.codec)
+}
+
+/// Compresses `payload` with `codec` and wraps it in the versioned envelope for storage.
+///
+/// # Errors
+///
+/// Returns an error if the codec's feature is not enabled or compression fails.
+pub fn compress(codec: CompressionCodec, payload: &[u8]) -> anyhow::Result<Bytes> {
+    let compressed = match codec {
+        CompressionCodec::None => payload.to_vec(),
+        CompressionCodec::Zstd => zstd_compress(payload)?,
+        CompressionCodec::Lz4 => lz4_compress(payload)?,
+    };
+
+    let mut framed = Vec::with_capacity(HEADER_LEN + compressed.len());
+    framed.extend_from_slice(&ENVELOPE_MAGIC);
+    framed.push(ENVELOPE_VERSION);
+    framed.push(codec.tag());
+    framed.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    framed.extend_from_slice(&compressed);
+    Ok(Bytes::from(framed))
+}
+
+/// Decompresses a stored `payload`, passing through legacy payloads without an envelope.
+///
+/// # Errors
+///
+/// Returns an error if the envelope is malformed, the payload's codec feature is not enabled,
+/// decompression fails, or the decoded length does not match the envelope.
+pub fn decompress(payload: &[u8]) -> anyhow::Result<Bytes> {
+    let Some(envelope) = parse_envelope(payload)? else {
+        return Ok(Bytes::copy_from_slice(payload));
+    };
+
+    let decoded = match envelope.codec {
+        CompressionCodec::None => envelope.body.to_vec(),
+        CompressionCodec::Zstd => zstd_decompress(envelope.body)?,
+        CompressionCodec::Lz4 => lz4_decompress(envelope.body)?,
+    };
+    anyhow::ensure!(
+        decoded.len() == envelope.len,
+        "Cache payload length mismatch: envelope records {} bytes, decoded {}",
+        envelope.len,
+        decoded.len()
+    );
+    Ok(Bytes::from(decoded))
+}
+
+/// Re-encodes a stored `payload` with `codec`, returning `None` if it is already enveloped
+/// with `codec`.
+///
+/// Legacy payloads without an envelope are always re-encoded.
+///
+/// # Errors
+///
+/// Returns an error if decompressing or compressing the payload fails.
+pub fn migrate_payload(payload: &[u8], codec: CompressionCodec) -> anyhow::Result<Option<Bytes>> {
+    if parse_envelope(payload)?.is_some_and(|e| e.codec == codec) {
+        return Ok(None);
+    }
+    compress(codec, &decompress(payload)?).map(Some)
+}
+
+/// Serializes an order or position `snapshot` to JSON and encodes it with `codec`.
+///
+/// # Errors
+///
+/// Returns an error if serialization or compression fails.
+pub fn encode_snapshot<T: Serialize>(
+    codec: CompressionCodec,
+    snapshot: &T,
+) -> anyhow::Result<Bytes> {
+    compress(codec, &serde_json::to_vec(snapshot)?)
+}
+
+/// Decodes a snapshot stored with [`encode_snapshot`], whatever codec it was encoded with.
+///
+/// # Errors
+///
+/// Returns an error if decompression or deserialization fails.
+pub fn decode_snapshot<T: DeserializeOwned>(payload: &[u8]) -> anyhow::Result<T> {
+    Ok(serde_json::from_slice(&decompress(payload)?)?)
+}
+
+#[cfg(feature = "zstd")]
+fn zstd_compress(payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    Ok(zstd::encode_all(payload, ZSTD_LEVEL)?)
+}
+
+#[cfg(feature = "zstd")]
+fn zstd_decompress(payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    Ok(zstd::decode_all(payload)?)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_compress(_payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("Zstd compression requires the `zstd` feature")
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_decompress(_payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("Zstd decompression requires the `zstd` feature")
+}
+
+#[cfg(feature = "lz4")]
+fn lz4_compress(payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    Ok(lz4_flex::compress_prepend_size(payload))
+}
+
+#[cfg(feature = "lz4")]
+fn lz4_decompress(payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    Ok(lz4_flex::decompress_size_prepended(payload)?)
+}
+
+#[cfg(not(feature = "lz4"))]
+fn lz4_compress(_payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("LZ4 compression requires the `lz4` feature")
+}
+
+#[cfg(not(feature = "lz4"))]
+fn lz4_decompress(_payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("LZ4 decompression requires the `lz4` feature")
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        events::OrderSnapshot,
+        identifiers::InstrumentId,
+        orders::builder::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const PAYLOAD: &[u8] = br#"{"client_order_id":"O-1","quantity":"100","status":"FILLED"}"#;
+
+    #[rstest]
+    fn test_none_codec_is_enveloped() {
+        let stored = compress(CompressionCodec::None, PAYLOAD).unwrap();
+        assert_eq!(&stored[HEADER_LEN..], PAYLOAD);
+        assert_eq!(payload_codec(&stored), Some(CompressionCodec::None));
+        assert_eq!(decompress(&stored).unwrap().as_ref(), PAYLOAD);
+    }
+
+    #[rstest]
+    fn test_user_bytes_resembling_a_header_round_trip() {
+        // The previous two byte marker followed by a valid codec tag
+        let user = [0xC1, b'N', 0x01, 0xFF, 0x00];
+        let stored = compress(CompressionCodec::None, &user).unwrap();
+        assert_eq!(decompress(&stored).unwrap().as_ref(), user);
+
+        // User bytes which begin with the envelope magic itself
+        let mut user = ENVELOPE_MAGIC.to_vec();
+        user.extend_from_slice(&[ENVELOPE_VERSION, 0, 0xFF]);
+        let stored = compress(CompressionCodec::None, &user).unwrap();
+        assert_eq!(decompress(&stored).unwrap().as_ref(), user.as_slice());
+    }
+
+    #[rstest]
+    fn test_corrupt_envelope_is_rejected() {
+        let mut stored = compress(CompressionCodec::None, PAYLOAD).unwrap().to_vec();
+        stored.pop();
+        assert!(decompress(&stored).is_err());
+
+        let mut stored = compress(CompressionCodec::None, PAYLOAD).unwrap().to_vec();
+        stored[4] = ENVELOPE_VERSION + 1;
+        assert!(decompress(&stored).is_err());
+
+        assert!(decompress(&ENVELOPE_MAGIC).is_err());
+    }
+
+    #[rstest]
+    fn test_legacy_msgpack_payload_passes_through() {
+        let legacy = rmp_serde::to_vec(&("O-1", 100)).unwrap();
+        assert_eq!(payload_codec(&legacy), None);
+        assert_eq!(decompress(&legacy).unwrap().as_ref(), legacy.as_slice());
+
+        let migrated = migrate_payload(&legacy, CompressionCodec::None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(payload_codec(&migrated), Some(CompressionCodec::None));
+        assert!(
+            migrate_payload(&migrated, CompressionCodec::None)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[rstest]
+    fn test_order_snapshot_round_trip() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let snapshot = OrderSnapshot::from(order);
+
+        let codec = if cfg!(feature = "zstd") {
+            CompressionCodec::Zstd
+        } else {
+            CompressionCodec::None
+        };
+        let stored = encode_snapshot(codec, &snapshot).unwrap();
+        assert_eq!(payload_codec(&stored), Some(codec));
+
+        let decoded: OrderSnapshot = decode_snapshot(&stored).unwrap();
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[rstest]
+    #[case(CompressionCodec::Zstd)]
+    #[case(CompressionCodec::Lz4)]
+    fn test_codec_round_trip_and_migration(#[case] codec: CompressionCodec) {
+        let result = compress(codec, PAYLOAD);
+        let enabled = match codec {
+            CompressionCodec::Zstd => cfg!(feature = "zstd"),
+            CompressionCodec::Lz4 => cfg!(feature = "lz4"),
+            CompressionCodec::None => true,
+        };
+        if !enabled {
+            assert!(result.is_err());
+            return;
+        }
+
+        let stored = result.unwrap();
+        assert_eq!(payload_codec(&stored), Some(codec));
+        assert_eq!(decompress(&stored).unwrap().as_ref(), PAYLOAD);
+
+        let migrated = migrate_payload(PAYLOAD, codec).unwrap().unwrap();
+        assert_eq!(payload_codec(&migrated), Some(codec));
+        assert!(migrate_payload(&migrated, codec).unwrap().is_none());
+
+        let reverted = migrate_payload(&migrated, CompressionCodec::None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(payload_codec(&reverted), Some(CompressionCodec::None));
+        assert_eq!(decompress(&reverted).unwrap().as_ref(), PAYLOAD);
+    }
+}
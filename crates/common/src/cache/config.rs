@@ -15,6 +15,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::compression::CompressionCodec;
 use crate::{enums::SerializationEncoding, msgbus::database::DatabaseConfig};
 
 /// Configuration for `Cache` instances.
@@ -29,6 +30,8 @@ pub struct CacheConfig {
     pub database: Option<DatabaseConfig>,
     /// The encoding for database operations, controls the type of serializer used.
     pub encoding: SerializationEncoding,
+    /// The compression codec for payloads persisted to the database.
+    pub compression: CompressionCodec,
     /// If timestamps should be persisted as ISO 8601 strings.
     pub timestamps_as_iso8601: bool,
     /// The buffer interval (milliseconds) between pipelined/batched transactions.
@@ -47,6 +50,9 @@ pub struct CacheConfig {
     pub bar_capacity: usize,
     /// If market data should be persisted to disk.
     pub save_market_data: bool,
+    /// If orders, positions and accounts loaded from the database should be rebuilt from their
+    /// event log (event sourcing mode), rather than taken from the stored snapshot.
+    pub event_sourcing: bool,
 }
 
 impl Default for CacheConfig {
@@ -55,6 +61,7 @@ impl Default for CacheConfig {
         Self {
             database: None,
             encoding: SerializationEncoding::MsgPack,
+            compression: CompressionCodec::None,
             timestamps_as_iso8601: false,
             buffer_interval_ms: None,
             use_trader_prefix: true,
@@ -64,6 +71,7 @@ impl Default for CacheConfig {
             tick_capacity: 10_000,
             bar_capacity: 10_000,
             save_market_data: false,
+            event_sourcing: false,
         }
     }
 }
@@ -75,6 +83,7 @@ impl CacheConfig {
     pub const fn new(
         database: Option<DatabaseConfig>,
         encoding: SerializationEncoding,
+        compression: CompressionCodec,
         timestamps_as_iso8601: bool,
         buffer_interval_ms: Option<usize>,
         use_trader_prefix: bool,
@@ -84,10 +93,12 @@ impl CacheConfig {
         tick_capacity: usize,
         bar_capacity: usize,
         save_market_data: bool,
+        event_sourcing: bool,
     ) -> Self {
         Self {
             database,
             encoding,
+            compression,
             timestamps_as_iso8601,
             buffer_interval_ms,
             use_trader_prefix,
@@ -97,6 +108,7 @@ impl CacheConfig {
             tick_capacity,
             bar_capacity,
             save_market_data,
+            event_sourcing,
         }
     }
 }
@@ -38,6 +38,7 @@ use nautilus_model::{
 };
 use ustr::Ustr;
 
+use super::compression::CompressionCodec;
 use crate::{custom::CustomData, signal::Signal};
 
 #[derive(Debug, Default)]
@@ -471,12 +472,16 @@ pub trait CacheDatabaseAdapter {
         position_id: PositionId,
     ) -> anyhow::Result<()>;
 
-    /// Updates actor state in the cache.
+    /// Updates the saved state of the actor with the given `component_id`.
     ///
     /// # Errors
     ///
     /// Returns an error if updating actor state fails.
-    fn update_actor(&self) -> anyhow::Result<()>;
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()>;
 
     /// Updates strategy state in the cache.
     ///
@@ -508,17 +513,28 @@ pub trait CacheDatabaseAdapter {
 
     /// Creates a snapshot of order state.
     ///
+    /// Implementations should persist the snapshot as encoded by
+    /// [`compression::encode_snapshot`](super::compression::encode_snapshot) with `codec`, and
+    /// read it back with [`compression::decode_snapshot`](super::compression::decode_snapshot).
+    ///
     /// # Errors
     ///
     /// Returns an error if snapshotting order state fails.
-    fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()>;
+    fn snapshot_order_state(&self, order: &OrderAny, codec: CompressionCodec)
+    -> anyhow::Result<()>;
 
     /// Creates a snapshot of position state.
     ///
+    /// Encoded the same way as [`Self::snapshot_order_state`].
+    ///
     /// # Errors
     ///
     /// Returns an error if snapshotting position state fails.
-    fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()>;
+    fn snapshot_position_state(
+        &self,
+        position: &Position,
+        codec: CompressionCodec,
+    ) -> anyhow::Result<()>;
 
     /// Records a heartbeat timestamp.
     ///
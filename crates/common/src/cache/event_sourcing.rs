@@ -0,0 +1,524 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Event sourcing: rebuilding the cache's execution state from its event log.
+//!
+//! In event sourcing mode the orders, positions and accounts held by the [`Cache`] are treated
+//! as a projection of the persisted event log rather than as the source of truth. The
+//! [`EventLog`] collects the order events and account states of the cached objects in event time
+//! order; [`project`] replays it deterministically into a [`CacheProjection`]:
+//!
+//! - Orders are rebuilt from their events, starting from `OrderInitialized`.
+//! - Positions are rebuilt by applying each fill carrying a position ID, in event order.
+//! - Accounts are rebuilt from their account states.
+//!
+//! [`Cache::rebuild_from_event_log`] replaces the cached state with the projection, and both it
+//! and [`Cache::verify_event_log`] compare the projection against the stored snapshot, reporting
+//! each difference as a [`ProjectionMismatch`].
+
+use std::fmt::Display;
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    accounts::AccountAny,
+    events::{AccountState, OrderEventAny},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId},
+    instruments::InstrumentAny,
+    orders::{Order, OrderAny},
+    position::Position,
+};
+use serde::Serialize;
+
+use super::Cache;
+
+/// An entry in the [`EventLog`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoggedEvent {
+    Order(OrderEventAny),
+    Account(AccountState),
+}
+
+impl LoggedEvent {
+    /// Returns the UNIX timestamp (nanoseconds) when the event occurred.
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Order(event) => event.ts_event(),
+            Self::Account(state) => state.ts_event,
+        }
+    }
+}
+
+/// The order and account events from which execution state is projected, in event time order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventLog {
+    events: Vec<LoggedEvent>,
+}
+
+impl EventLog {
+    /// Creates a new [`EventLog`] instance, ordering `events` by `ts_event`.
+    ///
+    /// The sort is stable, so events with equal timestamps keep their given order.
+    #[must_use]
+    pub fn new(mut events: Vec<LoggedEvent>) -> Self {
+        events.sort_by_key(LoggedEvent::ts_event);
+        Self { events }
+    }
+
+    /// Creates an event log from the events of the orders and accounts held in `cache`.
+    #[must_use]
+    pub fn from_cache(cache: &Cache) -> Self {
+        let mut order_ids: Vec<&ClientOrderId> = cache.orders.keys().collect();
+        order_ids.sort();
+        let mut account_ids: Vec<&AccountId> = cache.accounts.keys().collect();
+        account_ids.sort();
+
+        let orders = order_ids
+            .into_iter()
+            .flat_map(|id| cache.orders[id].events())
+            .cloned()
+            .map(LoggedEvent::Order);
+        let accounts = account_ids
+            .into_iter()
+            .flat_map(|id| cache.accounts[id].events())
+            .map(LoggedEvent::Account);
+        Self::new(orders.chain(accounts).collect())
+    }
+
+    /// Returns the logged events in event time order.
+    #[must_use]
+    pub fn events(&self) -> &[LoggedEvent] {
+        &self.events
+    }
+
+    /// Returns the number of logged events.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns whether the log is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// The execution state projected from an [`EventLog`].
+#[derive(Clone, Debug, Default)]
+pub struct CacheProjection {
+    pub orders: AHashMap<ClientOrderId, OrderAny>,
+    pub positions: AHashMap<PositionId, Position>,
+    pub accounts: AHashMap<AccountId, AccountAny>,
+}
+
+/// Replays `log` into a [`CacheProjection`], resolving fill instruments with `instrument`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - An order's first event is not `OrderInitialized`, or an event is an invalid transition.
+/// - A fill opens a position for an instrument which `instrument` cannot resolve.
+/// - An account state cannot be applied.
+pub fn project<'a>(
+    log: &EventLog,
+    instrument: impl Fn(&InstrumentId) -> Option<&'a InstrumentAny>,
+) -> anyhow::Result<CacheProjection> {
+    let mut order_events: AHashMap<ClientOrderId, Vec<OrderEventAny>> = AHashMap::new();
+    let mut account_states: AHashMap<AccountId, Vec<AccountState>> = AHashMap::new();
+    let mut positions: AHashMap<PositionId, Position> = AHashMap::new();
+
+    for event in log.events() {
+        match event {
+            LoggedEvent::Order(event) => {
+                if let OrderEventAny::Filled(fill) = event
+                    && let Some(position_id) = fill.position_id
+                {
+                    if let Some(position) = positions.get_mut(&position_id) {
+                        position.apply(fill);
+                    } else {
+                        let Some(instrument) = instrument(&fill.instrument_id) else {
+                            anyhow::bail!(
+                                "Cannot open position {position_id}: instrument {} not found",
+                                fill.instrument_id
+                            );
+                        };
+                        positions.insert(position_id, Position::new(instrument, *fill));
+                    }
+                }
+                order_events
+                    .entry(event.client_order_id())
+                    .or_default()
+                    .push(event.clone());
+            }
+            LoggedEvent::Account(state) => {
+                account_states
+                    .entry(state.account_id)
+                    .or_default()
+                    .push(state.clone());
+            }
+        }
+    }
+
+    let orders = order_events
+        .into_iter()
+        .map(|(id, events)| {
+            OrderAny::from_events(events)
+                .map(|order| (id, order))
+                .map_err(|e| anyhow::anyhow!("Failed to rebuild order {id}: {e}"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let accounts = account_states
+        .into_iter()
+        .map(|(id, states)| {
+            AccountAny::from_events(states)
+                .map(|account| (id, account))
+                .map_err(|e| anyhow::anyhow!("Failed to rebuild account {id}: {e}"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(CacheProjection {
+        orders,
+        positions,
+        accounts,
+    })
+}
+
+/// A difference between the stored cache state and its projection from the event log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ProjectionMismatch {
+    /// The object is stored but not produced by the event log.
+    NotInLog { kind: &'static str, id: String },
+    /// The object is produced by the event log but not stored.
+    NotStored { kind: &'static str, id: String },
+    /// A field of the stored object differs from its projection.
+    FieldDiffers {
+        kind: &'static str,
+        id: String,
+        field: &'static str,
+        stored: String,
+        rebuilt: String,
+    },
+}
+
+impl Display for ProjectionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotInLog { kind, id } => write!(f, "{kind} {id} stored but not in event log"),
+            Self::NotStored { kind, id } => write!(f, "{kind} {id} in event log but not stored"),
+            Self::FieldDiffers {
+                kind,
+                id,
+                field,
+                stored,
+                rebuilt,
+            } => write!(
+                f,
+                "{kind} {id} `{field}` stored as {stored} but rebuilt as {rebuilt}"
+            ),
+        }
+    }
+}
+
+/// The outcome of [`Cache::rebuild_from_event_log`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RebuildReport {
+    pub events: usize,
+    pub orders: usize,
+    pub positions: usize,
+    pub accounts: usize,
+    /// The differences between the replaced stored state and the projection.
+    pub mismatches: Vec<ProjectionMismatch>,
+}
+
+/// Compares the fields of `stored` and `rebuilt` objects keyed by ID.
+fn compare<K, V>(
+    kind: &'static str,
+    stored: &AHashMap<K, V>,
+    rebuilt: &AHashMap<K, V>,
+    fields: impl Fn(&V) -> Vec<(&'static str, String)>,
+    mismatches: &mut Vec<ProjectionMismatch>,
+) where
+    K: Ord + Display + std::hash::Hash,
+{
+    let mut ids: Vec<&K> = stored.keys().chain(rebuilt.keys()).collect();
+    ids.sort();
+    ids.dedup();
+
+    for id in ids {
+        match (stored.get(id), rebuilt.get(id)) {
+            (Some(_), None) => mismatches.push(ProjectionMismatch::NotInLog {
+                kind,
+                id: id.to_string(),
+            }),
+            (None, Some(_)) => mismatches.push(ProjectionMismatch::NotStored {
+                kind,
+                id: id.to_string(),
+            }),
+            (Some(stored), Some(rebuilt)) => {
+                for ((field, stored), (_, rebuilt)) in
+                    fields(stored).into_iter().zip(fields(rebuilt))
+                {
+                    if stored != rebuilt {
+                        mismatches.push(ProjectionMismatch::FieldDiffers {
+                            kind,
+                            id: id.to_string(),
+                            field,
+                            stored,
+                            rebuilt,
+                        });
+                    }
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn order_fields(order: &OrderAny) -> Vec<(&'static str, String)> {
+    vec![
+        ("status", order.status().to_string()),
+        ("filled_qty", order.filled_qty().to_string()),
+        ("avg_px", format!("{:?}", order.avg_px())),
+        ("event_count", order.event_count().to_string()),
+    ]
+}
+
+fn position_fields(position: &Position) -> Vec<(&'static str, String)> {
+    vec![
+        ("side", position.side.to_string()),
+        ("quantity", position.quantity.to_string()),
+        ("realized_pnl", format!("{:?}", position.realized_pnl)),
+        ("event_count", position.event_count().to_string()),
+    ]
+}
+
+fn account_fields(account: &AccountAny) -> Vec<(&'static str, String)> {
+    let mut balances: Vec<String> = account
+        .balances()
+        .values()
+        .map(|b| format!("{}/{}/{}", b.total, b.locked, b.free))
+        .collect();
+    balances.sort();
+    vec![
+        ("balances", balances.join(",")),
+        ("event_count", account.events().len().to_string()),
+    ]
+}
+
+impl Cache {
+    /// Returns the event log of the orders and accounts held in the cache.
+    #[must_use]
+    pub fn event_log(&self) -> EventLog {
+        EventLog::from_cache(self)
+    }
+
+    /// Projects `log` and compares the projection against the stored orders, positions and
+    /// accounts without modifying the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be projected (see [`project`]).
+    pub fn verify_event_log(&self, log: &EventLog) -> anyhow::Result<Vec<ProjectionMismatch>> {
+        let projection = project(log, |id| self.instruments.get(id))?;
+        Ok(self.projection_mismatches(&projection))
+    }
+
+    /// Rebuilds the cached orders, positions and accounts purely from `log`, replacing the stored
+    /// state and rebuilding the index.
+    ///
+    /// Differences between the replaced state and the projection are returned in the report and
+    /// logged. The cache is left unchanged when the log cannot be projected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log cannot be projected (see [`project`]).
+    pub fn rebuild_from_event_log(&mut self, log: &EventLog) -> anyhow::Result<RebuildReport> {
+        let projection = project(log, |id| self.instruments.get(id))?;
+        let mismatches = self.projection_mismatches(&projection);
+        for mismatch in &mismatches {
+            log::warn!("Event log projection mismatch: {mismatch}");
+        }
+
+        let report = RebuildReport {
+            events: log.len(),
+            orders: projection.orders.len(),
+            positions: projection.positions.len(),
+            accounts: projection.accounts.len(),
+            mismatches,
+        };
+        self.orders = projection.orders;
+        self.positions = projection.positions;
+        self.accounts = projection.accounts;
+        self.build_index();
+
+        log::info!(
+            "Rebuilt {} orders, {} positions and {} accounts from {} events",
+            report.orders,
+            report.positions,
+            report.accounts,
+            report.events,
+        );
+        Ok(report)
+    }
+
+    fn projection_mismatches(&self, projection: &CacheProjection) -> Vec<ProjectionMismatch> {
+        let mut mismatches = Vec::new();
+        compare(
+            "order",
+            &self.orders,
+            &projection.orders,
+            order_fields,
+            &mut mismatches,
+        );
+        compare(
+            "position",
+            &self.positions,
+            &projection.positions,
+            position_fields,
+            &mut mismatches,
+        );
+        compare(
+            "account",
+            &self.accounts,
+            &projection.accounts,
+            account_fields,
+            &mut mismatches,
+        );
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OmsType, OrderSide, OrderType},
+        events::account::stubs::cash_account_state,
+        identifiers::{AccountId, VenueOrderId},
+        instruments::{CurrencyPair, Instrument, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cache_with_fill(audusd_sim: CurrencyPair) -> (Cache, PositionId) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let account_id = AccountId::from("SIM-001");
+        let position_id = PositionId::from("P-1");
+
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            Some(position_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(account_id),
+        );
+        order.apply(filled.clone()).unwrap();
+        let OrderEventAny::Filled(fill) = filled else {
+            unreachable!()
+        };
+
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache
+            .add_account(AccountAny::from_events(vec![cash_account_state()]).unwrap())
+            .unwrap();
+        cache.add_order(order, None, None, false).unwrap();
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+        (cache, position_id)
+    }
+
+    #[rstest]
+    fn test_rebuild_matches_stored_state(audusd_sim: CurrencyPair) {
+        let (mut cache, position_id) = cache_with_fill(audusd_sim);
+        let log = cache.event_log();
+
+        // Four order events and the account state
+        assert_eq!(log.len(), 5);
+
+        let report = cache.rebuild_from_event_log(&log).unwrap();
+
+        assert_eq!(report.orders, 1);
+        assert_eq!(report.positions, 1);
+        assert_eq!(report.accounts, 1);
+        assert!(report.mismatches.is_empty());
+        assert!(cache.position(&position_id).is_some());
+        assert_eq!(cache.positions_open_count(None, None, None, None, None), 1);
+    }
+
+    #[rstest]
+    fn test_verify_reports_drifted_snapshot(audusd_sim: CurrencyPair) {
+        let (mut cache, position_id) = cache_with_fill(audusd_sim);
+        let log = cache.event_log();
+        cache.positions.get_mut(&position_id).unwrap().quantity = Quantity::from(1);
+        cache.positions.insert(
+            PositionId::from("P-ORPHAN"),
+            cache.positions[&position_id].clone(),
+        );
+
+        let mismatches = cache.verify_event_log(&log).unwrap();
+
+        assert_eq!(
+            mismatches,
+            vec![
+                ProjectionMismatch::FieldDiffers {
+                    kind: "position",
+                    id: "P-1".to_string(),
+                    field: "quantity",
+                    stored: "1".to_string(),
+                    rebuilt: "100000".to_string(),
+                },
+                ProjectionMismatch::NotInLog {
+                    kind: "position",
+                    id: "P-ORPHAN".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_project_requires_fill_instrument(audusd_sim: CurrencyPair) {
+        let (cache, _) = cache_with_fill(audusd_sim);
+        let log = cache.event_log();
+        assert!(project(&log, |_| None).is_err());
+        assert!(project(&EventLog::default(), |_| None).is_ok());
+    }
+}
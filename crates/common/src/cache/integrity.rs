@@ -0,0 +1,557 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Periodic validation of invariants between related cache collections.
+//!
+//! [`Cache::integrity_issues`] cross-checks orders, positions and accounts against each other
+//! and against the cache index, returning each discrepancy as an [`IntegrityIssue`]. Index
+//! membership discrepancies are recoverable and can be repaired with
+//! [`Cache::repair_integrity`]; broken references between orders, positions and accounts are
+//! only reported.
+//!
+//! The [`IntegrityChecker`] runs the check on a clock timer, optionally repairs recoverable
+//! issues, and publishes an [`IntegrityReport`] on the integrity topic.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashSet;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    identifiers::{AccountId, ClientOrderId, PositionId},
+    orders::Order,
+};
+use serde::Serialize;
+
+use super::{Cache, index::CacheIndex};
+use crate::{
+    clock::Clock,
+    msgbus::{self, MStr, Topic},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// A discrepancy between related cache collections.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum IntegrityIssue {
+    /// An object is missing from an index it should be a member of.
+    MissingIndexEntry { index: &'static str, id: String },
+    /// An index references an object which is not cached or no longer qualifies for it.
+    DanglingIndexEntry { index: &'static str, id: String },
+    /// An order references a position which is not cached.
+    OrderPositionMissing {
+        client_order_id: ClientOrderId,
+        position_id: PositionId,
+    },
+    /// A position references an order which is not cached.
+    PositionOrderMissing {
+        position_id: PositionId,
+        client_order_id: ClientOrderId,
+    },
+    /// An order or position references an account which is not cached.
+    AccountMissing { id: String, account_id: AccountId },
+}
+
+impl IntegrityIssue {
+    /// Returns whether the issue can be repaired by [`Cache::repair_integrity`].
+    #[must_use]
+    pub const fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingIndexEntry { .. } | Self::DanglingIndexEntry { .. }
+        )
+    }
+}
+
+/// The result of an integrity check run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssue>,
+    /// The number of issues repaired.
+    pub repaired: usize,
+    pub ts_event: UnixNanos,
+}
+
+/// Returns the cache integrity topic.
+#[must_use]
+pub fn get_integrity_topic() -> MStr<Topic> {
+    "events.cache.integrity".into()
+}
+
+const ORDER_SETS: [&str; 6] = [
+    "orders",
+    "orders_open",
+    "orders_closed",
+    "orders_inflight",
+    "orders_emulated",
+    "orders_pending_cancel",
+];
+const POSITION_SETS: [&str; 3] = ["positions", "positions_open", "positions_closed"];
+
+fn order_set<'a>(index: &'a mut CacheIndex, name: &str) -> Option<&'a mut AHashSet<ClientOrderId>> {
+    match name {
+        "orders" => Some(&mut index.orders),
+        "orders_open" => Some(&mut index.orders_open),
+        "orders_closed" => Some(&mut index.orders_closed),
+        "orders_inflight" => Some(&mut index.orders_inflight),
+        "orders_emulated" => Some(&mut index.orders_emulated),
+        "orders_pending_cancel" => Some(&mut index.orders_pending_cancel),
+        _ => None,
+    }
+}
+
+fn position_set<'a>(index: &'a mut CacheIndex, name: &str) -> Option<&'a mut AHashSet<PositionId>> {
+    match name {
+        "positions" => Some(&mut index.positions),
+        "positions_open" => Some(&mut index.positions_open),
+        "positions_closed" => Some(&mut index.positions_closed),
+        _ => None,
+    }
+}
+
+impl Cache {
+    /// Returns all discrepancies between orders, positions, accounts and the cache index.
+    #[must_use]
+    pub fn integrity_issues(&self) -> Vec<IntegrityIssue> {
+        let mut issues = Vec::new();
+        let index = &self.index;
+        let missing = |index: &'static str, id: &dyn ToString| IntegrityIssue::MissingIndexEntry {
+            index,
+            id: id.to_string(),
+        };
+        let dangling =
+            |index: &'static str, id: &dyn ToString| IntegrityIssue::DanglingIndexEntry {
+                index,
+                id: id.to_string(),
+            };
+
+        for (client_order_id, order) in &self.orders {
+            let memberships = [
+                ("orders", true, &index.orders),
+                ("orders_open", order.is_open(), &index.orders_open),
+                ("orders_closed", order.is_closed(), &index.orders_closed),
+                (
+                    "orders_inflight",
+                    order.is_inflight(),
+                    &index.orders_inflight,
+                ),
+            ];
+            for (name, expected, set) in memberships {
+                match (expected, set.contains(client_order_id)) {
+                    (true, false) => issues.push(missing(name, client_order_id)),
+                    (false, true) => issues.push(dangling(name, client_order_id)),
+                    _ => {}
+                }
+            }
+            if !index.order_strategy.contains_key(client_order_id) {
+                issues.push(missing("order_strategy", client_order_id));
+            }
+            if let Some(position_id) = order.position_id()
+                && !self.positions.contains_key(&position_id)
+            {
+                issues.push(IntegrityIssue::OrderPositionMissing {
+                    client_order_id: *client_order_id,
+                    position_id,
+                });
+            }
+            if let Some(account_id) = order.account_id()
+                && !self.accounts.contains_key(&account_id)
+            {
+                issues.push(IntegrityIssue::AccountMissing {
+                    id: client_order_id.to_string(),
+                    account_id,
+                });
+            }
+        }
+
+        for (position_id, position) in &self.positions {
+            let memberships = [
+                ("positions", true, &index.positions),
+                ("positions_open", position.is_open(), &index.positions_open),
+                (
+                    "positions_closed",
+                    position.is_closed(),
+                    &index.positions_closed,
+                ),
+            ];
+            for (name, expected, set) in memberships {
+                match (expected, set.contains(position_id)) {
+                    (true, false) => issues.push(missing(name, position_id)),
+                    (false, true) => issues.push(dangling(name, position_id)),
+                    _ => {}
+                }
+            }
+            if !index.position_strategy.contains_key(position_id) {
+                issues.push(missing("position_strategy", position_id));
+            }
+            for client_order_id in position.client_order_ids() {
+                if !self.orders.contains_key(&client_order_id) {
+                    issues.push(IntegrityIssue::PositionOrderMissing {
+                        position_id: *position_id,
+                        client_order_id,
+                    });
+                }
+            }
+            if !self.accounts.contains_key(&position.account_id) {
+                issues.push(IntegrityIssue::AccountMissing {
+                    id: position_id.to_string(),
+                    account_id: position.account_id,
+                });
+            }
+        }
+
+        let order_sets = [
+            ("orders", &index.orders),
+            ("orders_open", &index.orders_open),
+            ("orders_closed", &index.orders_closed),
+            ("orders_inflight", &index.orders_inflight),
+            ("orders_emulated", &index.orders_emulated),
+            ("orders_pending_cancel", &index.orders_pending_cancel),
+        ];
+        for (name, set) in order_sets {
+            for client_order_id in set {
+                if !self.orders.contains_key(client_order_id) {
+                    issues.push(dangling(name, client_order_id));
+                }
+            }
+        }
+        let order_keyed = [
+            (
+                "order_strategy",
+                index.order_strategy.keys().collect::<Vec<_>>(),
+            ),
+            ("order_position", index.order_position.keys().collect()),
+            ("client_order_ids", index.client_order_ids.keys().collect()),
+            ("venue_order_ids", index.venue_order_ids.values().collect()),
+        ];
+        for (name, ids) in order_keyed {
+            for client_order_id in ids {
+                if !self.orders.contains_key(client_order_id) {
+                    issues.push(dangling(name, client_order_id));
+                }
+            }
+        }
+
+        let position_sets = [
+            ("positions", &index.positions),
+            ("positions_open", &index.positions_open),
+            ("positions_closed", &index.positions_closed),
+        ];
+        for (name, set) in position_sets {
+            for position_id in set {
+                if !self.positions.contains_key(position_id) {
+                    issues.push(dangling(name, position_id));
+                }
+            }
+        }
+        let position_keyed = [
+            (
+                "position_strategy",
+                index.position_strategy.keys().collect::<Vec<_>>(),
+            ),
+            ("position_orders", index.position_orders.keys().collect()),
+        ];
+        for (name, ids) in position_keyed {
+            for position_id in ids {
+                if !self.positions.contains_key(position_id) {
+                    issues.push(dangling(name, position_id));
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Repairs the recoverable `issues` by correcting the cache index, returning the number of
+    /// issues repaired.
+    pub fn repair_integrity(&mut self, issues: &[IntegrityIssue]) -> usize {
+        let mut repaired = 0;
+        for issue in issues {
+            let fixed = match issue {
+                IntegrityIssue::MissingIndexEntry { index, id } => {
+                    self.insert_index_entry(index, id)
+                }
+                IntegrityIssue::DanglingIndexEntry { index, id } => {
+                    self.remove_index_entry(index, id)
+                }
+                _ => false,
+            };
+            if fixed {
+                log::warn!("Repaired cache integrity issue: {issue:?}");
+                repaired += 1;
+            }
+        }
+        repaired
+    }
+
+    fn insert_index_entry(&mut self, name: &str, id: &str) -> bool {
+        let client_order_id = ClientOrderId::from(id);
+        if let Some(order) = self.orders.get(&client_order_id) {
+            if name == "order_strategy" {
+                self.index
+                    .order_strategy
+                    .insert(client_order_id, order.strategy_id());
+                return true;
+            }
+            if let Some(set) = order_set(&mut self.index, name) {
+                return set.insert(client_order_id);
+            }
+        }
+
+        let position_id = PositionId::from(id);
+        if let Some(position) = self.positions.get(&position_id) {
+            if name == "position_strategy" {
+                self.index
+                    .position_strategy
+                    .insert(position_id, position.strategy_id);
+                return true;
+            }
+            if let Some(set) = position_set(&mut self.index, name) {
+                return set.insert(position_id);
+            }
+        }
+        false
+    }
+
+    fn remove_index_entry(&mut self, name: &str, id: &str) -> bool {
+        let index = &mut self.index;
+        if ORDER_SETS.contains(&name) {
+            let client_order_id = ClientOrderId::from(id);
+            return order_set(index, name).is_some_and(|set| set.remove(&client_order_id));
+        }
+        if POSITION_SETS.contains(&name) {
+            let position_id = PositionId::from(id);
+            return position_set(index, name).is_some_and(|set| set.remove(&position_id));
+        }
+        match name {
+            "order_strategy" => index
+                .order_strategy
+                .remove(&ClientOrderId::from(id))
+                .is_some(),
+            "order_position" => index
+                .order_position
+                .remove(&ClientOrderId::from(id))
+                .is_some(),
+            "client_order_ids" => index
+                .client_order_ids
+                .remove(&ClientOrderId::from(id))
+                .is_some(),
+            "venue_order_ids" => {
+                let client_order_id = ClientOrderId::from(id);
+                let len = index.venue_order_ids.len();
+                index
+                    .venue_order_ids
+                    .retain(|_, value| *value != client_order_id);
+                index.venue_order_ids.len() < len
+            }
+            "position_strategy" => index
+                .position_strategy
+                .remove(&PositionId::from(id))
+                .is_some(),
+            "position_orders" => index
+                .position_orders
+                .remove(&PositionId::from(id))
+                .is_some(),
+            _ => false,
+        }
+    }
+}
+
+/// Configuration for the periodic [`IntegrityChecker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntegrityCheckerConfig {
+    /// The interval between checks (nanoseconds).
+    pub interval_ns: u64,
+    /// Whether to repair recoverable issues when found.
+    pub repair: bool,
+}
+
+impl Default for IntegrityCheckerConfig {
+    /// Creates a new default [`IntegrityCheckerConfig`] instance.
+    fn default() -> Self {
+        Self {
+            interval_ns: 60_000_000_000,
+            repair: false,
+        }
+    }
+}
+
+/// Runs cache integrity checks on a clock timer.
+#[derive(Clone)]
+pub struct IntegrityChecker {
+    cache: Rc<RefCell<Cache>>,
+    config: IntegrityCheckerConfig,
+}
+
+impl Debug for IntegrityChecker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(IntegrityChecker))
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl IntegrityChecker {
+    /// The name of the timer used for periodic checks.
+    pub const TIMER_NAME: &str = "CacheIntegrityCheck";
+
+    /// Creates a new [`IntegrityChecker`] instance.
+    #[must_use]
+    pub const fn new(cache: Rc<RefCell<Cache>>, config: IntegrityCheckerConfig) -> Self {
+        Self { cache, config }
+    }
+
+    /// Runs a check, repairing recoverable issues if configured, and publishes the report.
+    pub fn run(&self, ts_event: UnixNanos) -> IntegrityReport {
+        let issues = self.cache.borrow().integrity_issues();
+        let repaired = if self.config.repair && !issues.is_empty() {
+            self.cache.borrow_mut().repair_integrity(&issues)
+        } else {
+            0
+        };
+
+        if issues.is_empty() {
+            log::debug!("Cache integrity check passed");
+        } else {
+            log::error!(
+                "Cache integrity check found {} issue(s), repaired {repaired}",
+                issues.len()
+            );
+        }
+
+        let report = IntegrityReport {
+            issues,
+            repaired,
+            ts_event,
+        };
+        msgbus::publish_any(get_integrity_topic(), &report);
+        report
+    }
+
+    /// Starts periodic checks on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock) -> anyhow::Result<()> {
+        let checker = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            checker.run(event.ts_event);
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            self.config.interval_ns,
+            None,
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Stops periodic checks on `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::stubs::audusd_sim,
+        orders::builder::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, msgbus::ShareableMessageHandler};
+
+    fn cache_with_order() -> (Cache, ClientOrderId) {
+        let mut cache = Cache::default();
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let client_order_id = order.client_order_id();
+        cache.add_order(order, None, None, false).unwrap();
+        (cache, client_order_id)
+    }
+
+    #[rstest]
+    fn test_integrity_issues_detected_and_repaired() {
+        let (mut cache, client_order_id) = cache_with_order();
+        assert!(cache.integrity_issues().is_empty());
+
+        cache.index.orders.remove(&client_order_id);
+        cache
+            .index
+            .orders_open
+            .insert(ClientOrderId::from("O-MISSING"));
+        cache
+            .index
+            .positions_closed
+            .insert(PositionId::from("P-MISSING"));
+
+        let issues = cache.integrity_issues();
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(IntegrityIssue::is_repairable));
+        assert!(issues.contains(&IntegrityIssue::MissingIndexEntry {
+            index: "orders",
+            id: client_order_id.to_string(),
+        }));
+
+        assert_eq!(cache.repair_integrity(&issues), 3);
+        assert!(cache.integrity_issues().is_empty());
+        assert!(cache.check_integrity());
+    }
+
+    #[rstest]
+    fn test_checker_runs_on_timer_and_publishes_report() {
+        let (mut cache, client_order_id) = cache_with_order();
+        cache.index.order_strategy.remove(&client_order_id);
+        let cache = Rc::new(RefCell::new(cache));
+
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        let captured = reports.clone();
+        let handler = ShareableMessageHandler::from_typed(move |r: &IntegrityReport| {
+            captured.borrow_mut().push(r.clone());
+        });
+        msgbus::subscribe_any(get_integrity_topic().into(), handler, None);
+
+        let checker = IntegrityChecker::new(
+            cache.clone(),
+            IntegrityCheckerConfig {
+                interval_ns: 1_000,
+                repair: true,
+            },
+        );
+        let mut clock = TestClock::new();
+        checker.start(&mut clock).unwrap();
+
+        let events = clock.advance_time(2_000.into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let reports = reports.borrow();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].repaired, 1);
+        assert!(reports[1].issues.is_empty());
+        assert!(cache.borrow().integrity_issues().is_empty());
+    }
+}
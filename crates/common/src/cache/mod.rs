@@ -17,9 +17,12 @@
 //!
 //! Provides methods to load, query, and update cached data such as instruments, orders, and prices.
 
+pub mod compression;
 pub mod config;
 pub mod database;
+pub mod event_sourcing;
 pub mod fifo;
+pub mod integrity;
 pub mod quote;
 
 mod index;
@@ -38,6 +41,7 @@ use bytes::Bytes;
 pub use config::CacheConfig; // Re-export
 use database::{CacheDatabaseAdapter, CacheMap};
 use index::CacheIndex;
+use indexmap::IndexMap;
 use nautilus_core::{
     UUID4, UnixNanos,
     correctness::{
@@ -49,10 +53,11 @@ use nautilus_core::{
 use nautilus_model::{
     accounts::{Account, AccountAny},
     data::{
-        Bar, BarType, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate, QuoteTick,
-        TradeTick, YieldCurveData,
+        Bar, BarType, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
+        PriceBandKind, PriceBandUpdate, QuoteTick, TradeTick, YieldCurveData,
     },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
+    events::{OrderEventAny, OrderFilled},
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Venue, VenueOrderId,
@@ -62,13 +67,13 @@ use nautilus_model::{
         OrderBook,
         own::{OwnOrderBook, should_handle_own_book_order},
     },
-    orders::{Order, OrderAny, OrderList},
+    orders::{Order, OrderAny, OrderList, OrderTag},
     position::Position,
     types::{Currency, Money, Price, Quantity},
 };
 use ustr::Ustr;
 
-use crate::xrate::get_exchange_rate;
+use crate::{reference::InstrumentReference, xrate::get_exchange_rate};
 
 /// A common in-memory `Cache` for market and execution related data.
 #[cfg_attr(
@@ -80,8 +85,10 @@ pub struct Cache {
     index: CacheIndex,
     database: Option<Box<dyn CacheDatabaseAdapter>>,
     general: AHashMap<String, Bytes>,
+    actor_states: AHashMap<ComponentId, IndexMap<String, Bytes>>,
     currencies: AHashMap<Ustr, Currency>,
     instruments: AHashMap<InstrumentId, InstrumentAny>,
+    instrument_references: AHashMap<InstrumentId, InstrumentReference>,
     synthetics: AHashMap<InstrumentId, SyntheticInstrument>,
     books: AHashMap<InstrumentId, OrderBook>,
     own_books: AHashMap<InstrumentId, OwnOrderBook>,
@@ -91,6 +98,7 @@ pub struct Cache {
     mark_prices: AHashMap<InstrumentId, VecDeque<MarkPriceUpdate>>,
     index_prices: AHashMap<InstrumentId, VecDeque<IndexPriceUpdate>>,
     funding_rates: AHashMap<InstrumentId, VecDeque<FundingRateUpdate>>,
+    price_bands: AHashMap<InstrumentId, IndexMap<PriceBandKind, PriceBandUpdate>>,
     bars: AHashMap<BarType, VecDeque<Bar>>,
     greeks: AHashMap<InstrumentId, GreeksData>,
     yield_curves: AHashMap<String, YieldCurveData>,
@@ -109,8 +117,10 @@ impl Debug for Cache {
             .field("config", &self.config)
             .field("index", &self.index)
             .field("general", &self.general)
+            .field("actor_states", &self.actor_states)
             .field("currencies", &self.currencies)
             .field("instruments", &self.instruments)
+            .field("instrument_references", &self.instrument_references)
             .field("synthetics", &self.synthetics)
             .field("books", &self.books)
             .field("own_books", &self.own_books)
@@ -120,6 +130,7 @@ impl Debug for Cache {
             .field("mark_prices", &self.mark_prices)
             .field("index_prices", &self.index_prices)
             .field("funding_rates", &self.funding_rates)
+            .field("price_bands", &self.price_bands)
             .field("bars", &self.bars)
             .field("greeks", &self.greeks)
             .field("yield_curves", &self.yield_curves)
@@ -154,8 +165,10 @@ impl Cache {
             index: CacheIndex::default(),
             database,
             general: AHashMap::new(),
+            actor_states: AHashMap::new(),
             currencies: AHashMap::new(),
             instruments: AHashMap::new(),
+            instrument_references: AHashMap::new(),
             synthetics: AHashMap::new(),
             books: AHashMap::new(),
             own_books: AHashMap::new(),
@@ -165,6 +178,7 @@ impl Cache {
             mark_prices: AHashMap::new(),
             index_prices: AHashMap::new(),
             funding_rates: AHashMap::new(),
+            price_bands: AHashMap::new(),
             bars: AHashMap::new(),
             greeks: AHashMap::new(),
             yield_curves: AHashMap::new(),
@@ -202,7 +216,11 @@ impl Cache {
     /// Returns an error if loading general cache data fails.
     pub fn cache_general(&mut self) -> anyhow::Result<()> {
         self.general = match &mut self.database {
-            Some(db) => db.load()?,
+            Some(db) => db
+                .load()?
+                .into_iter()
+                .map(|(key, value)| Ok((key, compression::decompress(&value)?)))
+                .collect::<anyhow::Result<_>>()?,
             None => AHashMap::new(),
         };
 
@@ -215,6 +233,9 @@ impl Cache {
 
     /// Loads all core caches (currencies, instruments, accounts, orders, positions) from the database.
     ///
+    /// In event sourcing mode the orders, positions and accounts are then rebuilt from their event
+    /// log, with any differences from the stored snapshot logged.
+    ///
     /// # Errors
     ///
     /// Returns an error if loading all cache data fails.
@@ -230,6 +251,11 @@ impl Cache {
         self.accounts = cache_map.accounts;
         self.orders = cache_map.orders;
         self.positions = cache_map.positions;
+
+        if self.config.event_sourcing {
+            let log = self.event_log();
+            self.rebuild_from_event_log(&log)?;
+        }
         Ok(())
     }
 
@@ -1214,8 +1240,10 @@ impl Cache {
         log::debug!("Resetting cache");
 
         self.general.clear();
+        self.actor_states.clear();
         self.currencies.clear();
         self.instruments.clear();
+        self.instrument_references.clear();
         self.synthetics.clear();
         self.books.clear();
         self.own_books.clear();
@@ -1225,6 +1253,7 @@ impl Cache {
         self.mark_prices.clear();
         self.index_prices.clear();
         self.funding_rates.clear();
+        self.price_bands.clear();
         self.bars.clear();
         self.accounts.clear();
         self.orders.clear();
@@ -1269,7 +1298,8 @@ impl Cache {
 
     /// Adds a raw bytes `value` to the cache under the `key`.
     ///
-    /// The cache stores only raw bytes; interpretation is the caller's responsibility.
+    /// The cache stores only raw bytes; interpretation is the caller's responsibility. The
+    /// value is persisted within the compression envelope of the configured codec.
     ///
     /// # Errors
     ///
@@ -1282,11 +1312,40 @@ impl Cache {
         self.general.insert(key.to_string(), value.clone());
 
         if let Some(database) = &mut self.database {
-            database.add(key.to_string(), value)?;
+            let payload = compression::compress(self.config.compression, &value)?;
+            database.add(key.to_string(), payload)?;
         }
         Ok(())
     }
 
+    /// Re-encodes general entries stored in the database with the configured compression codec.
+    ///
+    /// Entries already encoded with the configured codec are left untouched. Returns the number
+    /// of entries migrated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading, re-encoding or persisting an entry fails.
+    pub fn migrate_general_compression(&mut self) -> anyhow::Result<usize> {
+        let Some(database) = &mut self.database else {
+            return Ok(0);
+        };
+
+        let mut migrated = 0;
+        for (key, value) in database.load()? {
+            if let Some(payload) = compression::migrate_payload(&value, self.config.compression)? {
+                database.add(key, payload)?;
+                migrated += 1;
+            }
+        }
+
+        log::info!(
+            "Migrated {migrated} general object(s) to {} compression",
+            self.config.compression
+        );
+        Ok(migrated)
+    }
+
     /// Adds an `OrderBook` to the cache.
     ///
     /// # Errors
@@ -1416,6 +1475,16 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the `price_band` update to the cache, replacing the current band of its kind.
+    pub fn add_price_band(&mut self, price_band: PriceBandUpdate) {
+        log::debug!("Adding `PriceBandUpdate` for {}", price_band.instrument_id);
+
+        self.price_bands
+            .entry(price_band.instrument_id)
+            .or_default()
+            .insert(price_band.kind, price_band);
+    }
+
     /// Adds the `quote` tick to the cache.
     ///
     /// # Errors
@@ -1597,10 +1666,19 @@ impl Cache {
 
     /// Adds the `yield_curve` data to the cache.
     ///
+    /// Only the latest curve is kept for each curve name, so a curve older than the cached one
+    /// (by `ts_event`) is ignored.
+    ///
     /// # Errors
     ///
     /// Returns an error if persisting the yield curve data to the backing database fails.
     pub fn add_yield_curve(&mut self, yield_curve: YieldCurveData) -> anyhow::Result<()> {
+        if let Some(cached) = self.yield_curves.get(&yield_curve.curve_name)
+            && cached.ts_event > yield_curve.ts_event
+        {
+            log::debug!("Ignoring stale `YieldCurveData` {}", yield_curve.curve_name);
+            return Ok(());
+        }
         log::debug!("Adding `YieldCurveData` {}", yield_curve.curve_name);
 
         if self.config.save_market_data
@@ -1614,6 +1692,12 @@ impl Cache {
         Ok(())
     }
 
+    /// Gets the latest yield curve data for the `curve_name`.
+    #[must_use]
+    pub fn yield_curve_data(&self, curve_name: &str) -> Option<&YieldCurveData> {
+        self.yield_curves.get(curve_name)
+    }
+
     /// Gets the yield curve for the `key`.
     pub fn yield_curve(&self, key: &str) -> Option<Box<dyn Fn(f64) -> f64>> {
         self.yield_curves.get(key).map(|curve| {
@@ -1665,6 +1749,22 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the static `reference` data for an instrument to the cache.
+    ///
+    /// Reference data for an instrument already in the cache is merged, with the fields set in
+    /// `reference` taking precedence.
+    pub fn add_instrument_reference(&mut self, reference: InstrumentReference) {
+        log::debug!("Adding `InstrumentReference` {}", reference.instrument_id);
+
+        match self.instrument_references.get_mut(&reference.instrument_id) {
+            Some(existing) => existing.merge(reference),
+            None => {
+                self.instrument_references
+                    .insert(reference.instrument_id, reference);
+            }
+        }
+    }
+
     /// Adds the `synthetic` instrument to the cache.
     ///
     /// # Errors
@@ -1997,6 +2097,7 @@ impl Cache {
         if let Some(database) = &mut self.database {
             database.update_account(&account)?;
         }
+        self.accounts.insert(account.id(), account);
         Ok(())
     }
 
@@ -2161,13 +2262,15 @@ impl Cache {
         }
 
         if let Some(database) = &mut self.database {
-            database.snapshot_position_state(position).map_err(|e| {
-                log::error!(
-                    "Failed to snapshot position state for {}: {e:?}",
-                    position.id
-                );
-                e
-            })?;
+            database
+                .snapshot_position_state(position, self.config.compression)
+                .map_err(|e| {
+                    log::error!(
+                        "Failed to snapshot position state for {}: {e:?}",
+                        position.id
+                    );
+                    e
+                })?;
         } else {
             log::warn!(
                 "Cannot snapshot position state for {} (no database configured)",
@@ -2230,7 +2333,7 @@ impl Cache {
             return Ok(());
         };
 
-        database.snapshot_order_state(order)
+        database.snapshot_order_state(order, self.config.compression)
     }
 
     // -- IDENTIFIER QUERIES ----------------------------------------------------------------------
@@ -2675,6 +2778,25 @@ impl Cache {
         self.get_orders_for_ids(&client_order_ids, side)
     }
 
+    /// Returns the events of all orders for `strategy_id` with `ts_event` at or after `from_ts`.
+    ///
+    /// Events are ordered by `ts_event`; events sharing a timestamp keep their order within each
+    /// order, and orders are taken in `ts_init` then client order ID order.
+    #[must_use]
+    pub fn order_events(&self, strategy_id: &StrategyId, from_ts: UnixNanos) -> Vec<OrderEventAny> {
+        let mut orders = self.orders(None, None, Some(strategy_id), None, None);
+        orders.sort_by_key(|order| (order.ts_init(), order.client_order_id()));
+
+        let mut events: Vec<OrderEventAny> = orders
+            .iter()
+            .flat_map(|order| order.events())
+            .filter(|event| event.ts_event() >= from_ts)
+            .cloned()
+            .collect();
+        events.sort_by_key(OrderEventAny::ts_event);
+        events
+    }
+
     /// Returns references to all open orders matching the optional filter parameters.
     #[must_use]
     pub fn orders_open(
@@ -2747,6 +2869,56 @@ impl Cache {
         }
     }
 
+    /// Returns references to all orders with the structured tag `key=value`.
+    #[must_use]
+    pub fn orders_with_tag(&self, key: &str, value: &str) -> Vec<&OrderAny> {
+        self.orders
+            .values()
+            .filter(|order| order.has_tag(key, value))
+            .collect()
+    }
+
+    /// Returns all fills of orders with the structured tag `key=value`.
+    #[must_use]
+    pub fn fills_with_tag(&self, key: &str, value: &str) -> Vec<OrderFilled> {
+        self.orders_with_tag(key, value)
+            .into_iter()
+            .flat_map(|order| order.events())
+            .filter_map(|event| match event {
+                OrderEventAny::Filled(fill) => Some(*fill),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the structured tags propagated to the `position_id` from its orders.
+    #[must_use]
+    pub fn position_tags(&self, position_id: &PositionId) -> Vec<OrderTag> {
+        let mut tags: Vec<OrderTag> = self
+            .orders_for_position(position_id)
+            .into_iter()
+            .flat_map(|order| order.order_tags())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Returns references to all positions opened or modified by orders with the structured tag
+    /// `key=value`.
+    #[must_use]
+    pub fn positions_with_tag(&self, key: &str, value: &str) -> Vec<&Position> {
+        self.positions
+            .iter()
+            .filter(|(position_id, _)| {
+                self.position_tags(position_id)
+                    .iter()
+                    .any(|tag| tag.matches(key, value))
+            })
+            .map(|(_, position)| position)
+            .collect()
+    }
+
     /// Returns whether an order with the `client_order_id` exists.
     #[must_use]
     pub fn order_exists(&self, client_order_id: &ClientOrderId) -> bool {
@@ -3168,6 +3340,74 @@ impl Cache {
         Ok(self.general.get(key))
     }
 
+    /// Returns the general entries whose keys start with `prefix`, ordered by key.
+    #[must_use]
+    pub fn general_with_prefix(&self, prefix: &str) -> Vec<(&str, &Bytes)> {
+        let mut entries: Vec<(&str, &Bytes)> = self
+            .general
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.as_str(), value))
+            .collect();
+        entries.sort_by_key(|(key, _)| *key);
+        entries
+    }
+
+    // -- ACTOR STATE -----------------------------------------------------------------------------
+
+    /// Saves the `state` of the actor with the given `component_id`, replacing any previous state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the state to the backing database fails.
+    pub fn update_actor_state(
+        &mut self,
+        component_id: &ComponentId,
+        state: IndexMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        log::debug!("Saving state for {component_id}");
+
+        if let Some(database) = &mut self.database {
+            let persisted: AHashMap<String, Bytes> = state
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            database.update_actor(component_id, &persisted)?;
+        }
+
+        self.actor_states.insert(*component_id, state);
+        Ok(())
+    }
+
+    /// Returns the saved state of the actor with the given `component_id`.
+    ///
+    /// State persisted in the backing database takes precedence so actors resume across
+    /// restarts; entries loaded from the database are ordered by key. Returns an empty map if
+    /// no state was saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading the state from the backing database fails.
+    pub fn actor_state(
+        &self,
+        component_id: &ComponentId,
+    ) -> anyhow::Result<IndexMap<String, Bytes>> {
+        if let Some(database) = &self.database {
+            let persisted = database.load_actor(component_id)?;
+            if !persisted.is_empty() {
+                let mut state: IndexMap<String, Bytes> = persisted.into_iter().collect();
+                state.sort_unstable_keys();
+                return Ok(state);
+            }
+        }
+
+        Ok(self
+            .actor_states
+            .get(component_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
     // -- DATA QUERIES ----------------------------------------------------------------------------
 
     /// Returns the price for the `instrument_id` and `price_type` (if found).
@@ -3308,6 +3548,15 @@ impl Cache {
             .and_then(|index_prices| index_prices.front())
     }
 
+    /// Returns the current price bands (one per kind) for the `instrument_id`.
+    #[must_use]
+    pub fn price_bands(&self, instrument_id: &InstrumentId) -> Vec<PriceBandUpdate> {
+        self.price_bands
+            .get(instrument_id)
+            .map(|bands| bands.values().copied().collect())
+            .unwrap_or_default()
+    }
+
     /// Gets a reference to the latest funding rate update for the `instrument_id`.
     #[must_use]
     pub fn funding_rate(&self, instrument_id: &InstrumentId) -> Option<&FundingRateUpdate> {
@@ -3544,6 +3793,35 @@ impl Cache {
         bar_types
     }
 
+    /// Returns a reference to the static reference data for the `instrument_id` (if found).
+    #[must_use]
+    pub fn instrument_reference(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&InstrumentReference> {
+        self.instrument_references.get(instrument_id)
+    }
+
+    /// Returns the IDs of all instruments with reference data for the `sector`.
+    #[must_use]
+    pub fn instrument_ids_by_sector(&self, sector: &str) -> Vec<&InstrumentId> {
+        self.instrument_references
+            .values()
+            .filter(|r| r.sector.is_some_and(|s| s.as_str() == sector))
+            .map(|r| &r.instrument_id)
+            .collect()
+    }
+
+    /// Returns the IDs of all instruments with reference data mapping to the `underlying`.
+    #[must_use]
+    pub fn instrument_ids_by_underlying(&self, underlying: &Ustr) -> Vec<&InstrumentId> {
+        self.instrument_references
+            .values()
+            .filter(|r| r.underlying.as_ref() == Some(underlying))
+            .map(|r| &r.instrument_id)
+            .collect()
+    }
+
     // -- SYNTHETIC QUERIES -----------------------------------------------------------------------
 
     /// Returns a reference to the synthetic instrument for the `instrument_id` (if found).
@@ -3587,6 +3865,12 @@ impl Cache {
         self.index.venue_account.get(venue)
     }
 
+    /// Returns references to the account IDs of all accounts contained in the cache.
+    #[must_use]
+    pub fn account_ids(&self) -> Vec<&AccountId> {
+        self.accounts.keys().collect()
+    }
+
     /// Returns references to all accounts for the `account_id`.
     #[must_use]
     pub fn accounts(&self, account_id: &AccountId) -> Vec<&AccountAny> {
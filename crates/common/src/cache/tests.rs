@@ -26,7 +26,10 @@ use nautilus_model::defi::{
 };
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, BarType, FundingRateUpdate, MarkPriceUpdate, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, FundingRateUpdate, MarkPriceUpdate, PriceBandKind, PriceBandUpdate,
+        QuoteTick, TradeTick, YieldCurveData,
+    },
     enums::{
         AggressorSide, BookType, OmsType, OrderSide, OrderStatus, OrderType, PositionSide,
         PriceType, TriggerType,
@@ -42,7 +45,7 @@ use nautilus_model::{
     instruments::{CurrencyPair, Instrument, InstrumentAny, SyntheticInstrument, stubs::*},
     orderbook::OrderBook,
     orders::{
-        Order, OrderList,
+        Order, OrderList, OrderTag,
         builder::OrderTestBuilder,
         stubs::{TestOrderEventStubs, TestOrdersGenerator},
     },
@@ -51,8 +54,9 @@ use nautilus_model::{
     types::{Currency, Price, Quantity},
 };
 use rstest::{fixture, rstest};
+use ustr::Ustr;
 
-use crate::cache::Cache;
+use crate::{cache::Cache, reference::InstrumentReference};
 
 #[fixture]
 fn cache() -> Cache {
@@ -610,6 +614,21 @@ fn test_add_general_when_value(mut cache: Cache) {
     assert_eq!(result, Some(&value));
 }
 
+#[rstest]
+fn test_general_with_prefix(mut cache: Cache) {
+    let value = Bytes::from_static(&[0_u8]);
+    cache.add("journal.b", value.clone()).unwrap();
+    cache.add("journal.a", value.clone()).unwrap();
+    cache.add("other", value).unwrap();
+
+    let keys: Vec<&str> = cache
+        .general_with_prefix("journal.")
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec!["journal.a", "journal.b"]);
+}
+
 #[rstest]
 fn test_orders_for_position(mut cache: Cache, audusd_sim: CurrencyPair) {
     let order = OrderTestBuilder::new(OrderType::Limit)
@@ -628,6 +647,38 @@ fn test_orders_for_position(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(cache.orders_for_position(&position_id), vec![&order]);
 }
 
+#[rstest]
+fn test_orders_with_tag_and_position_tags(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let tagged = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .tags(vec![Ustr::from("ENTRY"), Ustr::from("algo=twap")])
+        .build();
+    let untagged = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Sell)
+        .price(Price::from("1.10000"))
+        .quantity(Quantity::from(100_000))
+        .client_order_id(ClientOrderId::from("O-2"))
+        .build();
+
+    let position_id = PositionId::test_default();
+    cache
+        .add_order(tagged.clone(), Some(position_id), None, false)
+        .unwrap();
+    cache.add_order(untagged, None, None, false).unwrap();
+
+    assert_eq!(cache.orders_with_tag("algo", "twap"), vec![&tagged]);
+    assert!(cache.orders_with_tag("algo", "vwap").is_empty());
+    assert!(cache.fills_with_tag("algo", "twap").is_empty());
+    assert_eq!(
+        cache.position_tags(&position_id),
+        vec![OrderTag::new("algo", "twap")]
+    );
+}
+
 #[rstest]
 fn test_correct_order_indexing(mut cache: Cache) {
     let binance = Venue::from("BINANCE");
@@ -1322,6 +1373,33 @@ fn test_add_funding_rate_updates_existing(mut cache: Cache, audusd_sim: Currency
     assert_eq!(result, Some(&funding_rate2));
 }
 
+#[rstest]
+fn test_add_price_band_replaces_band_of_same_kind(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let band = |kind, lower, upper, ts: u64| {
+        PriceBandUpdate::new(
+            audusd_sim.id,
+            kind,
+            Price::from(lower),
+            Price::from(upper),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    };
+    let dynamic = band(PriceBandKind::Dynamic, "0.99000", "1.01000", 2);
+    let daily = band(PriceBandKind::DailyLimit, "0.95000", "1.05000", 3);
+
+    cache.add_price_band(band(PriceBandKind::Dynamic, "0.98000", "1.02000", 1));
+    cache.add_price_band(dynamic);
+    cache.add_price_band(daily);
+
+    assert_eq!(cache.price_bands(&audusd_sim.id), vec![dynamic, daily]);
+    assert!(
+        cache
+            .price_bands(&InstrumentId::from("EUR/USD.SIM"))
+            .is_empty()
+    );
+}
+
 #[rstest]
 fn test_bar_when_empty(cache: Cache) {
     let bar = Bar::default();
@@ -3180,3 +3258,62 @@ fn test_update_closed_emulated_order_removes_from_orders_emulated(
     );
     assert_eq!(cache.orders_emulated_count(None, None, None, None, None), 0);
 }
+
+#[rstest]
+fn test_add_yield_curve_keeps_latest_curve(mut cache: Cache) {
+    let curve = |ts: u64, rate: f64| {
+        YieldCurveData::new(
+            ts.into(),
+            ts.into(),
+            "USD".to_string(),
+            vec![1.0],
+            vec![rate],
+        )
+    };
+    cache.add_yield_curve(curve(2, 0.05)).unwrap();
+    cache.add_yield_curve(curve(1, 0.04)).unwrap();
+
+    let latest = cache.yield_curve_data("USD").unwrap();
+    assert_eq!(latest.ts_event, UnixNanos::from(2));
+    assert_eq!(cache.yield_curve("USD").unwrap()(1.0), 0.05);
+
+    cache.add_yield_curve(curve(3, 0.06)).unwrap();
+    assert_eq!(
+        cache.yield_curve_data("USD").unwrap().interest_rates,
+        vec![0.06]
+    );
+    assert!(cache.yield_curve_data("EUR").is_none());
+}
+
+#[rstest]
+fn test_add_instrument_reference_merges_and_queries(mut cache: Cache) {
+    let call_id = InstrumentId::from("AAPL240621C00200000.XCBO");
+    let put_id = InstrumentId::from("AAPL240621P00200000.XCBO");
+    let mut call = InstrumentReference::new(call_id);
+    call.underlying = Some(Ustr::from("AAPL"));
+    call.specs
+        .insert("exercise".to_string(), "american".to_string());
+    let mut put = InstrumentReference::new(put_id);
+    put.underlying = Some(Ustr::from("AAPL"));
+    let mut call_update = InstrumentReference::new(call_id);
+    call_update.sector = Some(Ustr::from("4520"));
+
+    cache.add_instrument_reference(call);
+    cache.add_instrument_reference(put);
+    cache.add_instrument_reference(call_update);
+
+    let reference = cache.instrument_reference(&call_id).unwrap();
+    assert_eq!(reference.underlying, Some(Ustr::from("AAPL")));
+    assert_eq!(reference.sector, Some(Ustr::from("4520")));
+    assert_eq!(reference.spec("exercise"), Some("american"));
+    assert_eq!(
+        cache
+            .instrument_ids_by_underlying(&Ustr::from("AAPL"))
+            .len(),
+        2
+    );
+    assert_eq!(cache.instrument_ids_by_sector("4520"), vec![&call_id]);
+
+    cache.reset();
+    assert!(cache.instrument_reference(&call_id).is_none());
+}
@@ -0,0 +1,518 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Incremental compaction and deduplication of catalog segments.
+//!
+//! Appending live recordings produces many small, often overlapping segments. Compaction
+//! merges every segment that is small or overlaps another, removes identical records, sorts by
+//! `ts_init` and rewrites the result as fewer segments with uniform row groups. Large segments
+//! which overlap nothing are left untouched, so repeated compaction only rewrites new data.
+//!
+//! The rewritten segment directory is assembled in a staging directory and swapped in with
+//! renames; [`FileCatalog::recover`] completes or rolls back a swap interrupted by a crash.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ahash::AHashSet;
+use nautilus_core::UnixNanos;
+use nautilus_model::{data::HasTsInit, identifiers::InstrumentId};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use super::{FileCatalog, SEGMENT_EXTENSION, SegmentInfo, write_segment_in};
+
+/// Configuration for [`FileCatalog::compact`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactionConfig {
+    /// Segments with fewer records are merged even if they overlap no other segment.
+    pub small_segment_records: usize,
+    /// The maximum number of records written to one compacted segment.
+    pub max_segment_records: usize,
+    /// The number of records per row group in compacted segments.
+    pub row_group_size: usize,
+}
+
+impl Default for CompactionConfig {
+    /// Creates a new default [`CompactionConfig`] instance.
+    fn default() -> Self {
+        Self {
+            small_segment_records: 100_000,
+            max_segment_records: 5_000_000,
+            row_group_size: 100_000,
+        }
+    }
+}
+
+/// The outcome of a compaction run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// The names of the segments merged and removed.
+    pub segments_removed: Vec<String>,
+    /// The compacted segments written.
+    pub segments_written: Vec<SegmentInfo>,
+    /// The number of records read from the merged segments.
+    pub records_read: usize,
+    /// The number of records written to the compacted segments.
+    pub records_written: usize,
+    /// The number of identical records dropped.
+    pub duplicates_removed: usize,
+}
+
+impl CompactionReport {
+    /// Returns whether compaction left the catalog unchanged.
+    #[must_use]
+    pub fn is_noop(&self) -> bool {
+        self.segments_removed.is_empty()
+    }
+}
+
+/// Sorts `records` by `ts_init` (stable) and drops records identical to an earlier record
+/// with the same `ts_init`.
+///
+/// Returns the deduplicated records and the number removed.
+#[must_use]
+pub fn sort_and_dedup<T: HasTsInit + PartialEq>(mut records: Vec<T>) -> (Vec<T>, usize) {
+    records.sort_by_key(HasTsInit::ts_init);
+
+    let mut deduped: Vec<T> = Vec::with_capacity(records.len());
+    let mut group_start = 0;
+    let mut removed = 0;
+
+    for record in records {
+        if deduped
+            .last()
+            .is_none_or(|last| last.ts_init() != record.ts_init())
+        {
+            group_start = deduped.len();
+        }
+
+        if deduped[group_start..].contains(&record) {
+            removed += 1;
+        } else {
+            deduped.push(record);
+        }
+    }
+
+    (deduped, removed)
+}
+
+impl FileCatalog {
+    /// Compacts the segments stored for `data_type` and `instrument_id`.
+    ///
+    /// Segments that overlap another segment or hold fewer than
+    /// [`CompactionConfig::small_segment_records`] records are merged, deduplicated (see
+    /// [`sort_and_dedup`]) and rewritten. Output segments never span an untouched segment, so
+    /// the compacted catalog has no overlapping segments. Nothing is rewritten unless at least
+    /// two segments qualify.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `max_segment_records` or `row_group_size` is zero.
+    /// - Any segment cannot be read or written, or the directory swap fails.
+    pub fn compact<T>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        config: &CompactionConfig,
+    ) -> anyhow::Result<CompactionReport>
+    where
+        T: Serialize + DeserializeOwned + HasTsInit + PartialEq,
+    {
+        anyhow::ensure!(
+            config.max_segment_records > 0,
+            "`max_segment_records` must be positive"
+        );
+        anyhow::ensure!(
+            config.row_group_size > 0,
+            "`row_group_size` must be positive"
+        );
+
+        self.recover(data_type, instrument_id)?;
+        let segments = self.load_segments::<T>(data_type, instrument_id)?;
+
+        let ranges: Vec<Option<(UnixNanos, UnixNanos)>> = segments
+            .iter()
+            .map(|s| {
+                let first = s.records.iter().map(HasTsInit::ts_init).min()?;
+                let last = s.records.iter().map(HasTsInit::ts_init).max()?;
+                Some((first, last))
+            })
+            .collect();
+
+        let merge: Vec<bool> = segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                segment.records.len() < config.small_segment_records || overlaps_any(i, &ranges)
+            })
+            .collect();
+
+        if merge.iter().filter(|m| **m).count() < 2 {
+            return Ok(CompactionReport::default());
+        }
+
+        let mut segments_removed = Vec::new();
+        let mut keep_firsts = Vec::new();
+        let mut merged_records = Vec::new();
+        for ((segment, should_merge), range) in segments.into_iter().zip(merge).zip(&ranges) {
+            if should_merge {
+                segments_removed.push(segment.info.name);
+                merged_records.extend(segment.records);
+            } else if let Some((first, _)) = range {
+                keep_firsts.push(*first);
+            }
+        }
+        keep_firsts.sort_unstable();
+
+        let records_read = merged_records.len();
+        let (records, duplicates_removed) = sort_and_dedup(merged_records);
+        let records_written = records.len();
+
+        // Split at untouched segments and at the size limit, never within one timestamp
+        let mut batches: Vec<Vec<T>> = Vec::new();
+        let mut current_bucket = None;
+        for record in records {
+            let bucket = keep_firsts.partition_point(|first| *first <= record.ts_init());
+            match batches.last_mut() {
+                Some(batch)
+                    if current_bucket == Some(bucket)
+                        && (batch.len() < config.max_segment_records
+                            || batch.last().map(HasTsInit::ts_init) == Some(record.ts_init())) =>
+                {
+                    batch.push(record);
+                }
+                _ => {
+                    batches.push(vec![record]);
+                    current_bucket = Some(bucket);
+                }
+            }
+        }
+
+        let segments_written = self.swap_segments(
+            data_type,
+            instrument_id,
+            &segments_removed,
+            &batches,
+            config.row_group_size,
+        )?;
+
+        Ok(CompactionReport {
+            segments_removed,
+            segments_written,
+            records_read,
+            records_written,
+            duplicates_removed,
+        })
+    }
+
+    /// Completes or rolls back a segment swap interrupted part way.
+    ///
+    /// Returns `true` if an interrupted swap was found and resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a filesystem operation fails.
+    pub fn recover(&self, data_type: &str, instrument_id: &InstrumentId) -> anyhow::Result<bool> {
+        let live = self.segment_dir(data_type, instrument_id);
+        let staging = swap_sibling(&live, "compacting");
+        let replaced = swap_sibling(&live, "replaced");
+
+        if !live.exists() && replaced.exists() {
+            // Interrupted between the two renames: the staging directory is complete
+            if staging.exists() {
+                fs::rename(&staging, &live)?;
+                fs::remove_dir_all(&replaced)?;
+            } else {
+                fs::rename(&replaced, &live)?;
+            }
+            return Ok(true);
+        }
+
+        let mut recovered = false;
+        if staging.exists() {
+            // Interrupted before the swap: discard the partial staging directory
+            fs::remove_dir_all(&staging)?;
+            recovered = true;
+        }
+        if replaced.exists() {
+            fs::remove_dir_all(&replaced)?;
+            recovered = true;
+        }
+        Ok(recovered)
+    }
+
+    fn swap_segments<T: Serialize + HasTsInit>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        removed: &[String],
+        batches: &[Vec<T>],
+        row_group_size: usize,
+    ) -> anyhow::Result<Vec<SegmentInfo>> {
+        let live = self.segment_dir(data_type, instrument_id);
+        let staging = swap_sibling(&live, "compacting");
+        let replaced = swap_sibling(&live, "replaced");
+
+        fs::create_dir_all(&staging)?;
+
+        let removed: AHashSet<String> = removed
+            .iter()
+            .map(|name| format!("{name}.{SEGMENT_EXTENSION}"))
+            .collect();
+        for entry in fs::read_dir(&live)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            if removed.contains(file_name.to_string_lossy().as_ref()) {
+                continue;
+            }
+            let target = staging.join(&file_name);
+            if fs::hard_link(entry.path(), &target).is_err() {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+
+        let written = batches
+            .iter()
+            .map(|batch| write_segment_in(&staging, batch, row_group_size))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        fs::rename(&live, &replaced)?;
+        fs::rename(&staging, &live)?;
+        fs::remove_dir_all(&replaced)?;
+
+        Ok(written)
+    }
+}
+
+fn overlaps_any(index: usize, ranges: &[Option<(UnixNanos, UnixNanos)>]) -> bool {
+    let Some((first, last)) = ranges[index] else {
+        return false;
+    };
+    ranges.iter().enumerate().any(|(other, range)| {
+        other != index
+            && range
+                .is_some_and(|(other_first, other_last)| other_first <= last && first <= other_last)
+    })
+}
+
+fn swap_sibling(live: &Path, tag: &str) -> PathBuf {
+    let name = live
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    live.with_file_name(format!(".{name}.{tag}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::catalog::IntegrityConfig;
+
+    fn quote_at(ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    fn quotes(ts: impl IntoIterator<Item = u64>) -> Vec<QuoteTick> {
+        ts.into_iter().map(quote_at).collect()
+    }
+
+    fn config(small: usize, max: usize, row_group: usize) -> CompactionConfig {
+        CompactionConfig {
+            small_segment_records: small,
+            max_segment_records: max,
+            row_group_size: row_group,
+        }
+    }
+
+    #[rstest]
+    fn test_sort_and_dedup() {
+        let mut distinct = quote_at(2);
+        distinct.ts_event = UnixNanos::from(1);
+        let records = vec![quote_at(3), quote_at(2), distinct, quote_at(2), quote_at(1)];
+
+        let (deduped, removed) = sort_and_dedup(records);
+
+        assert_eq!(removed, 1);
+        let ts: Vec<u64> = deduped.iter().map(|q| q.ts_init.as_u64()).collect();
+        assert_eq!(ts, vec![1, 2, 2, 3]);
+    }
+
+    #[rstest]
+    fn test_compact_merges_overlapping_segments() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([1, 2, 3]), 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([3, 4, 5]), 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([5, 6]), 10)
+            .unwrap();
+
+        let report = catalog
+            .compact::<QuoteTick>("quotes", &instrument_id, &config(0, 100, 2))
+            .unwrap();
+
+        assert_eq!(report.segments_removed.len(), 3);
+        assert_eq!(report.records_read, 8);
+        assert_eq!(report.duplicates_removed, 2);
+        assert_eq!(report.records_written, 6);
+
+        let segments = catalog.list_segments("quotes", &instrument_id).unwrap();
+        assert_eq!(segments, report.segments_written);
+        let row_groups: Vec<Vec<QuoteTick>> = catalog
+            .read_row_groups("quotes", &instrument_id, &segments[0])
+            .unwrap();
+        assert_eq!(
+            row_groups.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 2, 2]
+        );
+
+        let integrity = catalog
+            .integrity_report::<QuoteTick>("quotes", &instrument_id, &IntegrityConfig::default())
+            .unwrap();
+        assert!(integrity.is_clean());
+    }
+
+    #[rstest]
+    fn test_compact_leaves_large_segments_and_does_not_span_them() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([1]), 10)
+            .unwrap();
+        let large = catalog
+            .write_segment("quotes", &instrument_id, &quotes(10..20), 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([30]), 10)
+            .unwrap();
+
+        let report = catalog
+            .compact::<QuoteTick>("quotes", &instrument_id, &config(5, 100, 10))
+            .unwrap();
+
+        assert_eq!(report.segments_removed.len(), 2);
+        assert_eq!(report.segments_written.len(), 2);
+
+        let segments = catalog.list_segments("quotes", &instrument_id).unwrap();
+        assert_eq!(segments.len(), 3);
+        assert!(segments.contains(&large));
+
+        let integrity = catalog
+            .integrity_report::<QuoteTick>("quotes", &instrument_id, &IntegrityConfig::default())
+            .unwrap();
+        assert!(integrity.overlaps.is_empty());
+    }
+
+    #[rstest]
+    fn test_compact_splits_at_max_segment_records() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([1, 2, 3]), 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([4, 5]), 10)
+            .unwrap();
+
+        let report = catalog
+            .compact::<QuoteTick>("quotes", &instrument_id, &config(10, 2, 10))
+            .unwrap();
+
+        assert_eq!(report.segments_written.len(), 3);
+        assert_eq!(report.records_written, 5);
+    }
+
+    #[rstest]
+    fn test_compact_single_candidate_is_noop() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+
+        let info = catalog
+            .write_segment("quotes", &instrument_id, &quotes([1, 2]), 10)
+            .unwrap();
+
+        let report = catalog
+            .compact::<QuoteTick>("quotes", &instrument_id, &CompactionConfig::default())
+            .unwrap();
+
+        assert!(report.is_noop());
+        assert_eq!(
+            catalog.list_segments("quotes", &instrument_id).unwrap(),
+            vec![info]
+        );
+    }
+
+    #[rstest]
+    fn test_recover_completes_interrupted_swap() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        let live = catalog.segment_dir("quotes", &instrument_id);
+
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes([1]), 10)
+            .unwrap();
+        let staging = swap_sibling(&live, "compacting");
+        let new_info = write_segment_in(&staging, &quotes([1, 2]), 10).unwrap();
+        fs::rename(&live, swap_sibling(&live, "replaced")).unwrap();
+
+        assert!(catalog.recover("quotes", &instrument_id).unwrap());
+        assert_eq!(
+            catalog.list_segments("quotes", &instrument_id).unwrap(),
+            vec![new_info]
+        );
+        assert!(!swap_sibling(&live, "replaced").exists());
+        assert!(!catalog.recover("quotes", &instrument_id).unwrap());
+    }
+
+    #[rstest]
+    fn test_recover_discards_partial_staging() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        let live = catalog.segment_dir("quotes", &instrument_id);
+
+        let info = catalog
+            .write_segment("quotes", &instrument_id, &quotes([1]), 10)
+            .unwrap();
+        write_segment_in(&swap_sibling(&live, "compacting"), &quotes([5]), 10).unwrap();
+
+        assert!(catalog.recover("quotes", &instrument_id).unwrap());
+        assert_eq!(
+            catalog.list_segments("quotes", &instrument_id).unwrap(),
+            vec![info]
+        );
+    }
+}
@@ -0,0 +1,1042 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow Flight remote access to a [`FileCatalog`].
+//!
+//! [`CatalogFlightService`] implements the catalog side of the Flight `ListFlights`,
+//! `GetFlightInfo` and `DoGet` calls: queries (instrument, data type, time range) are carried in
+//! opaque [`CatalogTicket`] bytes, and results are streamed as Arrow [`RecordBatch`]es decoded
+//! one segment at a time, so workers can consume historical data from a central store without
+//! copying segment files.
+//!
+//! [`CatalogFlightServer`] serves these calls as the `arrow.flight.protocol.FlightService` gRPC
+//! service over HTTP/2 (with [`tonic`]), so standard Flight clients can query the catalog
+//! unchanged. Responses are `FlightInfo` or [`FlightData`] protobufs, with record batches framed
+//! as Arrow IPC messages. `DoGet` segments are read and encoded on a blocking thread as the
+//! client consumes the stream, so a response is never buffered in full.
+//!
+//! Only the read-side `ListFlights`, `GetFlightInfo` and `DoGet` methods are served;
+//! `Handshake`, `DoPut`, `DoExchange`, `DoAction` and `ListActions` return `UNIMPLEMENTED`.
+
+use std::{convert::Infallible, iter, str::FromStr};
+
+use arrow::{
+    ipc::{root_as_message, writer::StreamWriter},
+    record_batch::RecordBatch,
+};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{
+        Bar, HasTsInit, QuoteTick, TradeTick,
+        arrow::{bars_to_record_batch, quotes_to_record_batch, trades_to_record_batch},
+    },
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tokio::net::TcpListener;
+use tonic::{
+    Status,
+    codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    codegen::{
+        Body as HttpBody, BoxFuture, Bytes, Context, Poll, Service, StdError, http,
+        tokio_stream::wrappers::ReceiverStream,
+    },
+    server::{Grpc, NamedService, ServerStreamingService},
+    transport::{Server, server::TcpIncoming},
+};
+
+use super::{FileCatalog, SegmentInfo};
+use crate::msgbus::serializer::wire::{Reader, WIRE_LEN};
+
+/// The data type name for quote ticks.
+pub const DATA_TYPE_QUOTES: &str = "quotes";
+/// The data type name for trade ticks.
+pub const DATA_TYPE_TRADES: &str = "trades";
+/// The data type name for bars.
+pub const DATA_TYPE_BARS: &str = "bars";
+
+/// The gRPC service name of the Flight protocol.
+pub const FLIGHT_SERVICE_NAME: &str = "arrow.flight.protocol.FlightService";
+/// The gRPC path of the Flight `ListFlights` method.
+pub const LIST_FLIGHTS_PATH: &str = "/arrow.flight.protocol.FlightService/ListFlights";
+/// The gRPC path of the Flight `GetFlightInfo` method.
+pub const GET_FLIGHT_INFO_PATH: &str = "/arrow.flight.protocol.FlightService/GetFlightInfo";
+/// The gRPC path of the Flight `DoGet` method.
+pub const DO_GET_PATH: &str = "/arrow.flight.protocol.FlightService/DoGet";
+
+const SUPPORTED_DATA_TYPES: [&str; 3] = [DATA_TYPE_QUOTES, DATA_TYPE_TRADES, DATA_TYPE_BARS];
+
+/// The number of response messages buffered ahead of a slow Flight client.
+const RESPONSE_BUFFER: usize = 16;
+
+/// A catalog query, encoded as the opaque ticket of a Flight `DoGet` call.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogTicket {
+    /// The data type to stream (see [`DATA_TYPE_QUOTES`] etc.).
+    pub data_type: String,
+    /// The instrument to stream.
+    pub instrument_id: InstrumentId,
+    /// The inclusive lower `ts_init` bound (unbounded if `None`).
+    pub start: Option<UnixNanos>,
+    /// The inclusive upper `ts_init` bound (unbounded if `None`).
+    pub end: Option<UnixNanos>,
+}
+
+impl CatalogTicket {
+    /// Creates a new [`CatalogTicket`] instance.
+    #[must_use]
+    pub fn new(
+        data_type: &str,
+        instrument_id: InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Self {
+        Self {
+            data_type: data_type.to_string(),
+            instrument_id,
+            start,
+            end,
+        }
+    }
+
+    /// Encodes the ticket as Flight ticket bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Decodes a ticket from Flight ticket bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid encoded ticket.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    fn contains(&self, ts: UnixNanos) -> bool {
+        self.start.is_none_or(|start| ts >= start) && self.end.is_none_or(|end| ts <= end)
+    }
+
+    fn overlaps(&self, info: &SegmentInfo) -> bool {
+        self.start.is_none_or(|start| info.ts_last >= start)
+            && self.end.is_none_or(|end| info.ts_first <= end)
+    }
+}
+
+/// Describes the data a ticket will stream, as returned by `GetFlightInfo`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogFlightInfo {
+    /// The ticket to pass to `DoGet`.
+    pub ticket: CatalogTicket,
+    /// The segments the ticket will read.
+    pub segments: Vec<SegmentInfo>,
+    /// The earliest declared `ts_init` across the segments.
+    pub ts_first: Option<UnixNanos>,
+    /// The latest declared `ts_init` across the segments.
+    pub ts_last: Option<UnixNanos>,
+}
+
+/// A record batch stream returned by [`CatalogFlightService::do_get`].
+pub type RecordBatchStream = Box<dyn Iterator<Item = anyhow::Result<RecordBatch>> + Send>;
+
+/// A Flight data stream returned by [`CatalogFlightService::do_get_flight_data`].
+pub type FlightDataStream = Box<dyn Iterator<Item = anyhow::Result<FlightData>> + Send>;
+
+/// The protobuf response messages of a Flight call, returned by
+/// [`CatalogFlightService::handle_message`].
+pub type FlightMessageStream = Box<dyn Iterator<Item = anyhow::Result<Vec<u8>>> + Send>;
+
+/// A Flight `FlightData` message carrying one Arrow IPC message.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlightData {
+    /// The Arrow IPC message flatbuffer (schema or record batch header).
+    pub data_header: Vec<u8>,
+    /// Application defined metadata.
+    pub app_metadata: Vec<u8>,
+    /// The Arrow IPC message body.
+    pub data_body: Vec<u8>,
+}
+
+impl FlightData {
+    /// Encodes the message as `arrow.flight.protocol.FlightData` protobuf bytes.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.data_header.len() + self.data_body.len() + 16);
+        proto::put_bytes(proto::FLIGHT_DATA_HEADER, &self.data_header, &mut buf);
+        proto::put_bytes(
+            proto::FLIGHT_DATA_APP_METADATA,
+            &self.app_metadata,
+            &mut buf,
+        );
+        proto::put_bytes(proto::FLIGHT_DATA_BODY, &self.data_body, &mut buf);
+        buf
+    }
+
+    /// Decodes a message from `arrow.flight.protocol.FlightData` protobuf bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid `FlightData` message.
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut data = Self::default();
+        let mut reader = Reader::new(bytes);
+        while !reader.is_empty() {
+            match reader.tag()? {
+                (proto::FLIGHT_DATA_HEADER, WIRE_LEN) => {
+                    data.data_header = reader.len_delimited()?.to_vec();
+                }
+                (proto::FLIGHT_DATA_APP_METADATA, WIRE_LEN) => {
+                    data.app_metadata = reader.len_delimited()?.to_vec();
+                }
+                (proto::FLIGHT_DATA_BODY, WIRE_LEN) => {
+                    data.data_body = reader.len_delimited()?.to_vec();
+                }
+                (_, wire_type) => reader.skip(wire_type)?,
+            }
+        }
+        Ok(data)
+    }
+}
+
+/// Serves catalog queries as streams of Arrow record batches.
+#[derive(Clone, Debug)]
+pub struct CatalogFlightService {
+    catalog: FileCatalog,
+    batch_size: usize,
+}
+
+impl CatalogFlightService {
+    /// Creates a new [`CatalogFlightService`] streaming at most `batch_size` rows per batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    #[must_use]
+    pub fn new(catalog: FileCatalog, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "`batch_size` must be positive");
+        Self {
+            catalog,
+            batch_size,
+        }
+    }
+
+    /// Returns the underlying catalog.
+    #[must_use]
+    pub const fn catalog(&self) -> &FileCatalog {
+        &self.catalog
+    }
+
+    /// Lists an unbounded ticket for every instrument stored under `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type directory cannot be read.
+    pub fn list_flights(&self, data_type: &str) -> anyhow::Result<Vec<CatalogTicket>> {
+        let dir = self.catalog.root().join(data_type);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut tickets = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // Skip compaction staging directories and anything not an instrument ID
+            if name.starts_with('.') || !entry.file_type()?.is_dir() {
+                continue;
+            }
+            if let Ok(instrument_id) = InstrumentId::from_str(&name) {
+                tickets.push(CatalogTicket::new(data_type, instrument_id, None, None));
+            }
+        }
+
+        tickets.sort_by(|a, b| {
+            a.instrument_id
+                .to_string()
+                .cmp(&b.instrument_id.to_string())
+        });
+        Ok(tickets)
+    }
+
+    /// Resolves the segments a ticket will read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segments cannot be listed.
+    pub fn get_flight_info(&self, ticket: &CatalogTicket) -> anyhow::Result<CatalogFlightInfo> {
+        let segments: Vec<SegmentInfo> = self
+            .catalog
+            .list_segments(&ticket.data_type, &ticket.instrument_id)?
+            .into_iter()
+            .filter(|info| ticket.overlaps(info))
+            .collect();
+
+        Ok(CatalogFlightInfo {
+            ticket: ticket.clone(),
+            ts_first: segments.iter().map(|s| s.ts_first).min(),
+            ts_last: segments.iter().map(|s| s.ts_last).max(),
+            segments,
+        })
+    }
+
+    /// Streams the records selected by `ticket` as record batches.
+    ///
+    /// Segments are read lazily as the stream is consumed. Records outside the ticket range are
+    /// dropped, and each batch holds at most `batch_size` rows from a single segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type is not supported or the segments cannot be listed;
+    /// read and encode failures are yielded by the stream.
+    pub fn do_get(&self, ticket: &CatalogTicket) -> anyhow::Result<RecordBatchStream> {
+        match ticket.data_type.as_str() {
+            DATA_TYPE_QUOTES => self.stream::<QuoteTick>(ticket, quotes_to_record_batch),
+            DATA_TYPE_TRADES => self.stream::<TradeTick>(ticket, trades_to_record_batch),
+            DATA_TYPE_BARS => self.stream::<Bar>(ticket, bars_to_record_batch),
+            other => anyhow::bail!("Unsupported data type for Flight streaming: {other}"),
+        }
+    }
+
+    /// Decodes `ticket_bytes` and streams the selected records as one Arrow IPC stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ticket is invalid, no records are selected, or any segment
+    /// cannot be read or encoded.
+    pub fn do_get_ipc(&self, ticket_bytes: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let ticket = CatalogTicket::from_bytes(ticket_bytes)?;
+        let mut batches = self.do_get(&ticket)?;
+
+        let Some(first) = batches.next().transpose()? else {
+            anyhow::bail!("No data for {} {}", ticket.data_type, ticket.instrument_id);
+        };
+
+        let mut buffer = Vec::new();
+        let mut writer = StreamWriter::try_new(&mut buffer, first.schema_ref())?;
+        writer.write(&first)?;
+        for batch in batches {
+            writer.write(&batch?)?;
+        }
+        writer.finish()?;
+        drop(writer);
+        Ok(buffer)
+    }
+
+    /// Decodes `ticket_bytes` and streams the selected records as Flight `DoGet` messages.
+    ///
+    /// The first message carries the Arrow schema, followed by one message per record batch.
+    /// Batches are encoded lazily as the stream is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ticket is invalid, the data type is not supported or the
+    /// segments cannot be listed; read and encode failures are yielded by the stream.
+    pub fn do_get_flight_data(&self, ticket_bytes: &[u8]) -> anyhow::Result<FlightDataStream> {
+        let ticket = CatalogTicket::from_bytes(ticket_bytes)?;
+        let mut batches = self.do_get(&ticket)?.peekable();
+
+        let schema = match batches.peek() {
+            Some(Ok(first)) => first.schema(),
+            _ => empty_batch(&ticket.data_type)?.schema(),
+        };
+        let mut writer = StreamWriter::try_new(Vec::new(), &schema)?;
+        let header = split_ipc_messages(&std::mem::take(writer.get_mut()))?;
+
+        let data = batches.flat_map(move |batch| {
+            let messages = batch.and_then(|batch| {
+                writer.write(&batch)?;
+                split_ipc_messages(&std::mem::take(writer.get_mut()))
+            });
+            match messages {
+                Ok(messages) => messages.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(e) => vec![Err(e)],
+            }
+        });
+
+        Ok(Box::new(header.into_iter().map(Ok).chain(data)))
+    }
+
+    /// Serves one Flight call.
+    ///
+    /// `path` is the gRPC method path and `message` the protobuf request message. Returns the
+    /// protobuf response messages: `FlightInfo` messages for [`LIST_FLIGHTS_PATH`] (whose
+    /// `Criteria` expression selects a data type, or all supported types when empty) and
+    /// [`GET_FLIGHT_INFO_PATH`] (whose `FlightDescriptor` command is the ticket bytes), and
+    /// [`FlightData`] messages for [`DO_GET_PATH`]. `DoGet` segments are read and encoded lazily.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the method is not supported, the request is malformed, or the query
+    /// fails; failures while streaming are yielded by the stream.
+    pub fn handle_message(
+        &self,
+        path: &str,
+        message: &[u8],
+    ) -> anyhow::Result<FlightMessageStream> {
+        match path {
+            LIST_FLIGHTS_PATH => {
+                let expression = proto::decode_bytes_field(message, proto::CRITERIA_EXPRESSION)?;
+                let data_types = if expression.is_empty() {
+                    SUPPORTED_DATA_TYPES.to_vec()
+                } else {
+                    vec![std::str::from_utf8(expression)?]
+                };
+                let mut infos = Vec::new();
+                for data_type in data_types {
+                    for ticket in self.list_flights(data_type)? {
+                        infos.push(Ok(self.encode_flight_info(&ticket)?));
+                    }
+                }
+                Ok(Box::new(infos.into_iter()))
+            }
+            GET_FLIGHT_INFO_PATH => {
+                let cmd = proto::decode_bytes_field(message, proto::DESCRIPTOR_CMD)?;
+                let ticket = CatalogTicket::from_bytes(cmd)?;
+                let info = self.encode_flight_info(&ticket)?;
+                Ok(Box::new(iter::once(Ok(info))))
+            }
+            DO_GET_PATH => {
+                let ticket_bytes = proto::decode_bytes_field(message, proto::TICKET_TICKET)?;
+                let data = self.do_get_flight_data(ticket_bytes)?;
+                Ok(Box::new(data.map(|data| data.map(|data| data.encode()))))
+            }
+            other => anyhow::bail!("Unsupported Flight method {other}"),
+        }
+    }
+
+    /// Encodes the `arrow.flight.protocol.FlightInfo` for `ticket`.
+    fn encode_flight_info(&self, ticket: &CatalogTicket) -> anyhow::Result<Vec<u8>> {
+        let info = self.get_flight_info(ticket)?;
+        let ticket_bytes = ticket.to_bytes()?;
+
+        let schema = empty_batch(&ticket.data_type)?.schema();
+        let mut writer = StreamWriter::try_new(Vec::new(), &schema)?;
+        let schema_bytes = std::mem::take(writer.get_mut());
+
+        let mut descriptor = Vec::new();
+        proto::put_varint_field(
+            proto::DESCRIPTOR_TYPE,
+            proto::DESCRIPTOR_TYPE_CMD,
+            &mut descriptor,
+        );
+        proto::put_bytes(proto::DESCRIPTOR_CMD, &ticket_bytes, &mut descriptor);
+
+        let mut flight_ticket = Vec::new();
+        proto::put_bytes(proto::TICKET_TICKET, &ticket_bytes, &mut flight_ticket);
+        let mut endpoint = Vec::new();
+        proto::put_bytes(proto::ENDPOINT_TICKET, &flight_ticket, &mut endpoint);
+
+        let mut buf = Vec::new();
+        proto::put_bytes(proto::INFO_SCHEMA, &schema_bytes, &mut buf);
+        proto::put_bytes(proto::INFO_DESCRIPTOR, &descriptor, &mut buf);
+        // Segments are only known to overlap the range, so an endpoint is only emitted when
+        // there is something to read
+        if !info.segments.is_empty() {
+            proto::put_bytes(proto::INFO_ENDPOINT, &endpoint, &mut buf);
+        }
+        // Record and byte counts are unknown without reading the segments
+        proto::put_varint_field(proto::INFO_TOTAL_RECORDS, -1_i64 as u64, &mut buf);
+        proto::put_varint_field(proto::INFO_TOTAL_BYTES, -1_i64 as u64, &mut buf);
+        proto::put_varint_field(proto::INFO_ORDERED, 1, &mut buf);
+        Ok(buf)
+    }
+
+    fn stream<T>(
+        &self,
+        ticket: &CatalogTicket,
+        encode: fn(&[T]) -> anyhow::Result<RecordBatch>,
+    ) -> anyhow::Result<RecordBatchStream>
+    where
+        T: DeserializeOwned + HasTsInit + 'static,
+    {
+        let info = self.get_flight_info(ticket)?;
+        let catalog = self.catalog.clone();
+        let batch_size = self.batch_size;
+        let ticket = ticket.clone();
+
+        let batches = info.segments.into_iter().flat_map(move |segment| {
+            let records: Vec<T> =
+                match catalog.read_segment(&ticket.data_type, &ticket.instrument_id, &segment) {
+                    Ok(records) => records
+                        .into_iter()
+                        .filter(|r: &T| ticket.contains(r.ts_init()))
+                        .collect(),
+                    Err(e) => return Box::new(iter::once(Err(e))) as RecordBatchStream,
+                };
+
+            let chunks: Vec<anyhow::Result<RecordBatch>> =
+                records.chunks(batch_size).map(encode).collect();
+            Box::new(chunks.into_iter())
+        });
+
+        Ok(Box::new(batches))
+    }
+}
+
+/// Serves a [`CatalogFlightService`] as the Arrow Flight gRPC service.
+#[derive(Clone, Debug)]
+pub struct CatalogFlightServer {
+    service: CatalogFlightService,
+}
+
+impl CatalogFlightServer {
+    /// Creates a new [`CatalogFlightServer`] instance.
+    #[must_use]
+    pub const fn new(service: CatalogFlightService) -> Self {
+        Self { service }
+    }
+
+    /// Serves Flight calls on `listener` until `shutdown` completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transport fails.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()>,
+    ) -> anyhow::Result<()> {
+        Server::builder()
+            .serve_with_incoming_shutdown(self, TcpIncoming::from(listener), shutdown)
+            .await?;
+        Ok(())
+    }
+}
+
+impl NamedService for CatalogFlightServer {
+    const NAME: &'static str = FLIGHT_SERVICE_NAME;
+}
+
+impl<B> Service<http::Request<B>> for CatalogFlightServer
+where
+    B: HttpBody + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let path = match request.uri().path() {
+            LIST_FLIGHTS_PATH => LIST_FLIGHTS_PATH,
+            GET_FLIGHT_INFO_PATH => GET_FLIGHT_INFO_PATH,
+            DO_GET_PATH => DO_GET_PATH,
+            other => {
+                let status = Status::unimplemented(format!("Unsupported Flight method {other}"));
+                return Box::pin(async move { Ok(status.into_http()) });
+            }
+        };
+
+        let method = FlightMethod {
+            service: self.service.clone(),
+            path,
+        };
+        Box::pin(async move { Ok(Grpc::new(RawCodec).server_streaming(method, request).await) })
+    }
+}
+
+/// A server streaming Flight method, answered by [`CatalogFlightService::handle_message`].
+struct FlightMethod {
+    service: CatalogFlightService,
+    path: &'static str,
+}
+
+impl ServerStreamingService<Bytes> for FlightMethod {
+    type Response = Bytes;
+    type ResponseStream = ReceiverStream<Result<Bytes, Status>>;
+    type Future = BoxFuture<tonic::Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: tonic::Request<Bytes>) -> Self::Future {
+        let service = self.service.clone();
+        let path = self.path;
+
+        Box::pin(async move {
+            let message = request.into_inner();
+            let messages =
+                tokio::task::spawn_blocking(move || service.handle_message(path, &message))
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?
+                    .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+            // Catalog reads block, so responses are produced on a blocking thread and handed
+            // over as the client consumes them
+            let (tx, rx) = tokio::sync::mpsc::channel(RESPONSE_BUFFER);
+            tokio::task::spawn_blocking(move || {
+                for message in messages {
+                    let item = message
+                        .map(Bytes::from)
+                        .map_err(|e| Status::internal(e.to_string()));
+                    let failed = item.is_err();
+                    if tx.blocking_send(item).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+
+            Ok(tonic::Response::new(ReceiverStream::new(rx)))
+        })
+    }
+}
+
+/// Passes protobuf messages through as raw bytes, as they are encoded by the service.
+#[derive(Clone, Copy, Debug, Default)]
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Bytes;
+    type Decode = Bytes;
+    type Encoder = Self;
+    type Decoder = Self;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        *self
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        *self
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn encode(&mut self, item: Bytes, dst: &mut EncodeBuf<'_>) -> Result<(), Status> {
+        bytes::BufMut::put(dst, item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Bytes>, Status> {
+        let len = bytes::Buf::remaining(src);
+        Ok(Some(bytes::Buf::copy_to_bytes(src, len)))
+    }
+}
+
+/// Returns an empty record batch for `data_type`, from which its schema is taken.
+fn empty_batch(data_type: &str) -> anyhow::Result<RecordBatch> {
+    match data_type {
+        DATA_TYPE_QUOTES => quotes_to_record_batch(&[]),
+        DATA_TYPE_TRADES => trades_to_record_batch(&[]),
+        DATA_TYPE_BARS => bars_to_record_batch(&[]),
+        other => anyhow::bail!("Unsupported data type for Flight streaming: {other}"),
+    }
+}
+
+/// Splits Arrow IPC stream bytes into one [`FlightData`] per IPC message, stopping at the
+/// end-of-stream marker.
+fn split_ipc_messages(mut bytes: &[u8]) -> anyhow::Result<Vec<FlightData>> {
+    const CONTINUATION: [u8; 4] = [0xFF; 4];
+
+    let mut messages = Vec::new();
+    while !bytes.is_empty() {
+        if bytes.starts_with(&CONTINUATION) {
+            bytes = &bytes[4..];
+        }
+        anyhow::ensure!(bytes.len() >= 4, "Truncated IPC message length");
+        let meta_len =
+            usize::try_from(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))?;
+        bytes = &bytes[4..];
+        if meta_len == 0 {
+            break;
+        }
+
+        anyhow::ensure!(bytes.len() >= meta_len, "Truncated IPC message metadata");
+        let (header, rest) = bytes.split_at(meta_len);
+        let message =
+            root_as_message(header).map_err(|e| anyhow::anyhow!("Invalid IPC message: {e}"))?;
+        let body_len = usize::try_from(message.bodyLength())?;
+        anyhow::ensure!(rest.len() >= body_len, "Truncated IPC message body");
+        let (body, rest) = rest.split_at(body_len);
+
+        messages.push(FlightData {
+            data_header: header.to_vec(),
+            app_metadata: Vec::new(),
+            data_body: body.to_vec(),
+        });
+        bytes = rest;
+    }
+    Ok(messages)
+}
+
+/// Flight protocol message fields, encoded with the shared Protobuf wire primitives.
+mod proto {
+    use crate::msgbus::serializer::wire::{
+        Reader, WIRE_LEN, WIRE_VARINT, put_len_delimited, put_tag, put_varint,
+    };
+
+    // arrow.flight.protocol field numbers
+    pub const CRITERIA_EXPRESSION: u32 = 1;
+    pub const TICKET_TICKET: u32 = 1;
+    pub const DESCRIPTOR_TYPE: u32 = 1;
+    pub const DESCRIPTOR_CMD: u32 = 2;
+    pub const DESCRIPTOR_TYPE_CMD: u64 = 2;
+    pub const ENDPOINT_TICKET: u32 = 1;
+    pub const INFO_SCHEMA: u32 = 1;
+    pub const INFO_DESCRIPTOR: u32 = 2;
+    pub const INFO_ENDPOINT: u32 = 3;
+    pub const INFO_TOTAL_RECORDS: u32 = 4;
+    pub const INFO_TOTAL_BYTES: u32 = 5;
+    pub const INFO_ORDERED: u32 = 6;
+    pub const FLIGHT_DATA_HEADER: u32 = 2;
+    pub const FLIGHT_DATA_APP_METADATA: u32 = 3;
+    pub const FLIGHT_DATA_BODY: u32 = 1000;
+
+    pub fn put_varint_field(field: u32, value: u64, buf: &mut Vec<u8>) {
+        put_tag(field, WIRE_VARINT, buf);
+        put_varint(value, buf);
+    }
+
+    /// Writes a length-delimited field, omitting it when empty as proto3 does.
+    pub fn put_bytes(field: u32, bytes: &[u8], buf: &mut Vec<u8>) {
+        if !bytes.is_empty() {
+            put_len_delimited(field, bytes, buf);
+        }
+    }
+
+    /// Returns the last occurrence of the length-delimited `field` in `message`, or an empty
+    /// slice if absent.
+    pub fn decode_bytes_field(message: &[u8], field: u32) -> anyhow::Result<&[u8]> {
+        let mut reader = Reader::new(message);
+        let mut value: &[u8] = &[];
+        while !reader.is_empty() {
+            match reader.tag()? {
+                (tag_field, WIRE_LEN) if tag_field == field => value = reader.len_delimited()?,
+                (_, wire_type) => reader.skip(wire_type)?,
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::ipc::reader::StreamReader;
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    fn service_with_quotes() -> (tempfile::TempDir, CatalogFlightService, InstrumentId) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        let first: Vec<QuoteTick> = (1..=5).map(quote_at).collect();
+        let second: Vec<QuoteTick> = (10..=12).map(quote_at).collect();
+        catalog
+            .write_segment(DATA_TYPE_QUOTES, &instrument_id, &first, 10)
+            .unwrap();
+        catalog
+            .write_segment(DATA_TYPE_QUOTES, &instrument_id, &second, 10)
+            .unwrap();
+        (
+            temp_dir,
+            CatalogFlightService::new(catalog, 2),
+            instrument_id,
+        )
+    }
+
+    #[rstest]
+    fn test_ticket_bytes_round_trip() {
+        let ticket = CatalogTicket::new(
+            DATA_TYPE_TRADES,
+            stub_trade_ethusdt_buyer().instrument_id,
+            Some(UnixNanos::from(1)),
+            None,
+        );
+        let bytes = ticket.to_bytes().unwrap();
+        assert_eq!(CatalogTicket::from_bytes(&bytes).unwrap(), ticket);
+        assert!(CatalogTicket::from_bytes(b"not a ticket").is_err());
+    }
+
+    #[rstest]
+    fn test_list_flights() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+
+        let tickets = service.list_flights(DATA_TYPE_QUOTES).unwrap();
+
+        assert_eq!(
+            tickets,
+            vec![CatalogTicket::new(
+                DATA_TYPE_QUOTES,
+                instrument_id,
+                None,
+                None
+            )]
+        );
+        assert!(service.list_flights(DATA_TYPE_BARS).unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_get_flight_info_selects_overlapping_segments() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let ticket = CatalogTicket::new(
+            DATA_TYPE_QUOTES,
+            instrument_id,
+            Some(UnixNanos::from(8)),
+            None,
+        );
+
+        let info = service.get_flight_info(&ticket).unwrap();
+
+        assert_eq!(info.segments.len(), 1);
+        assert_eq!(info.ts_first, Some(UnixNanos::from(10)));
+        assert_eq!(info.ts_last, Some(UnixNanos::from(12)));
+    }
+
+    #[rstest]
+    fn test_do_get_streams_range_in_batches() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let ticket = CatalogTicket::new(
+            DATA_TYPE_QUOTES,
+            instrument_id,
+            Some(UnixNanos::from(3)),
+            Some(UnixNanos::from(11)),
+        );
+
+        let batches: Vec<RecordBatch> = service
+            .do_get(&ticket)
+            .unwrap()
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+
+        // Segment one yields ts 3..=5 (2 + 1 rows), segment two ts 10..=11 (2 rows)
+        let rows: Vec<usize> = batches.iter().map(RecordBatch::num_rows).collect();
+        assert_eq!(rows, vec![2, 1, 2]);
+    }
+
+    #[rstest]
+    fn test_do_get_ipc_decodes_as_single_stream() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let ticket = CatalogTicket::new(DATA_TYPE_QUOTES, instrument_id, None, None);
+
+        let bytes = service.do_get_ipc(&ticket.to_bytes().unwrap()).unwrap();
+        let reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+        let total: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+
+        assert_eq!(total, 8);
+    }
+
+    #[rstest]
+    fn test_do_get_unsupported_data_type_errors() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let ticket = CatalogTicket::new("greeks", instrument_id, None, None);
+
+        assert!(service.do_get(&ticket).is_err());
+    }
+
+    fn flight_messages(response: FlightMessageStream) -> Vec<Vec<u8>> {
+        response.collect::<anyhow::Result<Vec<_>>>().unwrap()
+    }
+
+    fn do_get_request(instrument_id: InstrumentId) -> Vec<u8> {
+        let ticket = CatalogTicket::new(DATA_TYPE_QUOTES, instrument_id, None, None);
+        let mut request = Vec::new();
+        proto::put_bytes(
+            proto::TICKET_TICKET,
+            &ticket.to_bytes().unwrap(),
+            &mut request,
+        );
+        request
+    }
+
+    /// Re-frames `FlightData` messages as an IPC stream, as a Flight client does, returning the
+    /// row count of each batch.
+    fn ipc_stream_rows<M: AsRef<[u8]>>(messages: &[M]) -> Vec<usize> {
+        let mut stream = Vec::new();
+        for message in messages {
+            let data = FlightData::decode(message.as_ref()).unwrap();
+            stream.extend_from_slice(&[0xFF; 4]);
+            stream.extend_from_slice(&(data.data_header.len() as i32).to_le_bytes());
+            stream.extend_from_slice(&data.data_header);
+            stream.extend_from_slice(&data.data_body);
+        }
+        stream.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+
+        let reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        reader.map(|batch| batch.unwrap().num_rows()).collect()
+    }
+
+    #[rstest]
+    fn test_flight_data_round_trip() {
+        let data = FlightData {
+            data_header: vec![1, 2, 3],
+            app_metadata: Vec::new(),
+            data_body: vec![4; 300],
+        };
+        assert_eq!(FlightData::decode(&data.encode()).unwrap(), data);
+    }
+
+    #[rstest]
+    fn test_do_get_message_decodes_as_ipc_stream() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+
+        let messages = flight_messages(
+            service
+                .handle_message(DO_GET_PATH, &do_get_request(instrument_id))
+                .unwrap(),
+        );
+
+        let rows = ipc_stream_rows(&messages);
+        assert_eq!(messages.len(), 1 + rows.len());
+        assert_eq!(rows.iter().sum::<usize>(), 8);
+    }
+
+    #[rstest]
+    fn test_flight_info_message_carries_ticket() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let ticket = CatalogTicket::new(DATA_TYPE_QUOTES, instrument_id, None, None);
+        let mut descriptor = Vec::new();
+        proto::put_bytes(
+            proto::DESCRIPTOR_CMD,
+            &ticket.to_bytes().unwrap(),
+            &mut descriptor,
+        );
+
+        let messages = flight_messages(
+            service
+                .handle_message(GET_FLIGHT_INFO_PATH, &descriptor)
+                .unwrap(),
+        );
+        assert_eq!(messages.len(), 1);
+
+        let endpoint = proto::decode_bytes_field(&messages[0], proto::INFO_ENDPOINT).unwrap();
+        let flight_ticket = proto::decode_bytes_field(endpoint, proto::ENDPOINT_TICKET).unwrap();
+        let ticket_bytes = proto::decode_bytes_field(flight_ticket, proto::TICKET_TICKET).unwrap();
+        assert_eq!(CatalogTicket::from_bytes(ticket_bytes).unwrap(), ticket);
+
+        let schema = proto::decode_bytes_field(&messages[0], proto::INFO_SCHEMA).unwrap();
+        let reader = StreamReader::try_new(schema, None).unwrap();
+        assert_eq!(reader.schema().fields().len(), quote_batch_columns());
+    }
+
+    #[rstest]
+    fn test_do_get_message_reads_segments_lazily() {
+        let (dir, service, instrument_id) = service_with_quotes();
+
+        let mut response = service
+            .handle_message(DO_GET_PATH, &do_get_request(instrument_id))
+            .unwrap();
+
+        // Schema plus the three batches of the first segment
+        for _ in 0..4 {
+            response.next().unwrap().unwrap();
+        }
+
+        // The second segment is only read once the stream reaches it
+        std::fs::remove_dir_all(dir.path().join(DATA_TYPE_QUOTES)).unwrap();
+        assert!(response.next().unwrap().is_err());
+    }
+
+    fn quote_batch_columns() -> usize {
+        quotes_to_record_batch(&[quote_at(1)])
+            .unwrap()
+            .num_columns()
+    }
+
+    #[rstest]
+    fn test_list_flights_message_and_unknown_method() {
+        let (_dir, service, _instrument_id) = service_with_quotes();
+
+        let messages = flight_messages(service.handle_message(LIST_FLIGHTS_PATH, &[]).unwrap());
+        assert_eq!(messages.len(), 1);
+
+        let mut criteria = Vec::new();
+        proto::put_bytes(
+            proto::CRITERIA_EXPRESSION,
+            DATA_TYPE_BARS.as_bytes(),
+            &mut criteria,
+        );
+        let messages = flight_messages(
+            service
+                .handle_message(LIST_FLIGHTS_PATH, &criteria)
+                .unwrap(),
+        );
+        assert!(messages.is_empty());
+
+        assert!(
+            service
+                .handle_message("/arrow.flight.protocol.FlightService/DoPut", &[])
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_serves_do_get_over_grpc() {
+        let (_dir, service, instrument_id) = service_with_quotes();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(CatalogFlightServer::new(service).serve(listener, async {
+            shutdown_rx.await.ok();
+        }));
+
+        let channel = tonic::transport::Endpoint::from_shared(format!("http://{addr}"))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        let mut client = tonic::client::Grpc::new(channel);
+
+        client.ready().await.unwrap();
+        let mut response = client
+            .server_streaming(
+                tonic::Request::new(Bytes::from(do_get_request(instrument_id))),
+                http::uri::PathAndQuery::from_static(DO_GET_PATH),
+                RawCodec,
+            )
+            .await
+            .unwrap()
+            .into_inner();
+        let mut messages = Vec::new();
+        while let Some(message) = response.message().await.unwrap() {
+            messages.push(message);
+        }
+        assert_eq!(ipc_stream_rows(&messages).iter().sum::<usize>(), 8);
+
+        client.ready().await.unwrap();
+        let status = client
+            .server_streaming(
+                tonic::Request::new(Bytes::new()),
+                http::uri::PathAndQuery::from_static("/arrow.flight.protocol.FlightService/DoPut"),
+                RawCodec,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+        shutdown_tx.send(()).unwrap();
+        server.await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,391 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integrity and gap reporting for catalog segments.
+//!
+//! [`scan_segments`] inspects the segments stored for one instrument and data type and returns
+//! an [`IntegrityReport`] describing time coverage, gaps above a threshold, duplicate
+//! timestamps, records out of `ts_init` order and overlapping or mislabelled segments. The
+//! report is serializable so it can be persisted or passed between pipeline stages.
+
+use ahash::AHashMap;
+use nautilus_core::{UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{data::HasTsInit, identifiers::InstrumentId};
+use serde::{Deserialize, Serialize};
+
+use super::CatalogSegment;
+
+/// Configuration for [`scan_segments`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityConfig {
+    /// The largest interval between consecutive records not reported as a gap.
+    pub max_gap_ns: u64,
+}
+
+impl Default for IntegrityConfig {
+    /// Creates a new default [`IntegrityConfig`] instance (gaps above one minute).
+    fn default() -> Self {
+        Self {
+            max_gap_ns: 60 * NANOSECONDS_IN_SECOND,
+        }
+    }
+}
+
+/// Per-segment coverage details.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentCoverage {
+    /// The segment name.
+    pub name: String,
+    /// The number of records in the segment.
+    pub record_count: usize,
+    /// The minimum `ts_init` found in the segment.
+    pub ts_first: Option<UnixNanos>,
+    /// The maximum `ts_init` found in the segment.
+    pub ts_last: Option<UnixNanos>,
+    /// Whether the records fall outside, or do not span, the range declared by the name.
+    pub range_mismatch: bool,
+}
+
+/// An interval between consecutive records exceeding the configured threshold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageGap {
+    /// The `ts_init` of the last record before the gap.
+    pub start: UnixNanos,
+    /// The `ts_init` of the first record after the gap.
+    pub end: UnixNanos,
+}
+
+impl CoverageGap {
+    /// Returns the gap duration in nanoseconds.
+    #[must_use]
+    pub fn duration_ns(&self) -> u64 {
+        self.end.as_u64() - self.start.as_u64()
+    }
+}
+
+/// A `ts_init` shared by more than one record across the scanned segments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateTimestamp {
+    /// The duplicated timestamp.
+    pub ts_init: UnixNanos,
+    /// The number of records carrying the timestamp.
+    pub count: usize,
+}
+
+/// A record whose `ts_init` is earlier than the preceding record in the same segment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonMonotonicRecord {
+    /// The segment name.
+    pub segment: String,
+    /// The record index within the segment.
+    pub index: usize,
+    /// The record `ts_init`.
+    pub ts_init: UnixNanos,
+    /// The `ts_init` of the preceding record.
+    pub previous_ts_init: UnixNanos,
+}
+
+/// Two segments whose record ranges intersect.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentOverlap {
+    /// The earlier-starting segment.
+    pub first: String,
+    /// The later-starting segment.
+    pub second: String,
+}
+
+/// A structured integrity report for one instrument and data type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// The instrument scanned.
+    pub instrument_id: InstrumentId,
+    /// The data type scanned.
+    pub data_type: String,
+    /// Coverage of each segment, in scan order.
+    pub segments: Vec<SegmentCoverage>,
+    /// The total number of records.
+    pub record_count: usize,
+    /// The earliest `ts_init` across all segments.
+    pub ts_first: Option<UnixNanos>,
+    /// The latest `ts_init` across all segments.
+    pub ts_last: Option<UnixNanos>,
+    /// Gaps above the configured threshold, in time order.
+    pub gaps: Vec<CoverageGap>,
+    /// Duplicated timestamps, in time order.
+    pub duplicates: Vec<DuplicateTimestamp>,
+    /// Records out of `ts_init` order within their segment.
+    pub non_monotonic: Vec<NonMonotonicRecord>,
+    /// Segments whose record ranges intersect.
+    pub overlaps: Vec<SegmentOverlap>,
+}
+
+impl IntegrityReport {
+    /// Returns whether no issues were found.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.gaps.is_empty()
+            && self.duplicates.is_empty()
+            && self.non_monotonic.is_empty()
+            && self.overlaps.is_empty()
+            && !self.segments.iter().any(|s| s.range_mismatch)
+    }
+
+    /// Returns the nanoseconds between the first and last record.
+    #[must_use]
+    pub fn span_ns(&self) -> u64 {
+        match (self.ts_first, self.ts_last) {
+            (Some(first), Some(last)) => last.as_u64() - first.as_u64(),
+            _ => 0,
+        }
+    }
+
+    /// Returns the total nanoseconds spent in reported gaps.
+    #[must_use]
+    pub fn gap_ns(&self) -> u64 {
+        self.gaps.iter().map(CoverageGap::duration_ns).sum()
+    }
+
+    /// Returns the fraction of the span not covered by gaps, or `None` for an empty span.
+    #[must_use]
+    pub fn coverage_ratio(&self) -> Option<f64> {
+        let span = self.span_ns();
+        (span > 0).then(|| 1.0 - self.gap_ns() as f64 / span as f64)
+    }
+}
+
+/// Scans `segments` for `instrument_id` and `data_type`, returning an integrity report.
+#[must_use]
+pub fn scan_segments<T: HasTsInit>(
+    instrument_id: InstrumentId,
+    data_type: &str,
+    segments: &[CatalogSegment<T>],
+    config: &IntegrityConfig,
+) -> IntegrityReport {
+    let mut coverage = Vec::with_capacity(segments.len());
+    let mut non_monotonic = Vec::new();
+    let mut all_ts = Vec::new();
+
+    for segment in segments {
+        let mut previous: Option<UnixNanos> = None;
+        for (index, record) in segment.records.iter().enumerate() {
+            let ts_init = record.ts_init();
+            if let Some(previous_ts_init) = previous
+                && ts_init < previous_ts_init
+            {
+                non_monotonic.push(NonMonotonicRecord {
+                    segment: segment.info.name.clone(),
+                    index,
+                    ts_init,
+                    previous_ts_init,
+                });
+            }
+            previous = Some(ts_init);
+            all_ts.push(ts_init);
+        }
+
+        let ts_first = segment.records.iter().map(HasTsInit::ts_init).min();
+        let ts_last = segment.records.iter().map(HasTsInit::ts_init).max();
+        let range_mismatch =
+            ts_first != Some(segment.info.ts_first) || ts_last != Some(segment.info.ts_last);
+
+        coverage.push(SegmentCoverage {
+            name: segment.info.name.clone(),
+            record_count: segment.records.len(),
+            ts_first,
+            ts_last,
+            range_mismatch,
+        });
+    }
+
+    all_ts.sort_unstable();
+
+    let gaps = all_ts
+        .windows(2)
+        .filter(|w| w[1].as_u64() - w[0].as_u64() > config.max_gap_ns)
+        .map(|w| CoverageGap {
+            start: w[0],
+            end: w[1],
+        })
+        .collect();
+
+    let mut counts: AHashMap<UnixNanos, usize> = AHashMap::new();
+    for ts in &all_ts {
+        *counts.entry(*ts).or_default() += 1;
+    }
+    let mut duplicates: Vec<DuplicateTimestamp> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(ts_init, count)| DuplicateTimestamp { ts_init, count })
+        .collect();
+    duplicates.sort_by_key(|d| d.ts_init);
+
+    IntegrityReport {
+        instrument_id,
+        data_type: data_type.to_string(),
+        record_count: all_ts.len(),
+        ts_first: all_ts.first().copied(),
+        ts_last: all_ts.last().copied(),
+        overlaps: find_overlaps(&coverage),
+        segments: coverage,
+        gaps,
+        duplicates,
+        non_monotonic,
+    }
+}
+
+fn find_overlaps(coverage: &[SegmentCoverage]) -> Vec<SegmentOverlap> {
+    let mut ranges: Vec<(&str, UnixNanos, UnixNanos)> = coverage
+        .iter()
+        .filter_map(|c| Some((c.name.as_str(), c.ts_first?, c.ts_last?)))
+        .collect();
+    ranges.sort_by_key(|(_, first, last)| (*first, *last));
+
+    let mut overlaps = Vec::new();
+    for (i, (name, _, last)) in ranges.iter().enumerate() {
+        for (other, other_first, _) in &ranges[i + 1..] {
+            if other_first > last {
+                break;
+            }
+            overlaps.push(SegmentOverlap {
+                first: (*name).to_string(),
+                second: (*other).to_string(),
+            });
+        }
+    }
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::catalog::{FileCatalog, SegmentInfo};
+
+    fn quote_at(ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    fn segment(ts: &[u64]) -> CatalogSegment<QuoteTick> {
+        CatalogSegment::from_records(ts.iter().map(|t| quote_at(*t)).collect()).unwrap()
+    }
+
+    fn config(max_gap_ns: u64) -> IntegrityConfig {
+        IntegrityConfig { max_gap_ns }
+    }
+
+    #[rstest]
+    fn test_clean_segments() {
+        let segments = vec![segment(&[1, 2, 3]), segment(&[4, 5])];
+        let report = scan_segments(quote_at(0).instrument_id, "quotes", &segments, &config(5));
+
+        assert!(report.is_clean());
+        assert_eq!(report.record_count, 5);
+        assert_eq!(report.ts_first, Some(UnixNanos::from(1)));
+        assert_eq!(report.ts_last, Some(UnixNanos::from(5)));
+        assert_eq!(report.coverage_ratio(), Some(1.0));
+    }
+
+    #[rstest]
+    fn test_reports_gaps_above_threshold() {
+        let segments = vec![segment(&[0, 10, 20]), segment(&[100, 105])];
+        let report = scan_segments(quote_at(0).instrument_id, "quotes", &segments, &config(10));
+
+        assert_eq!(
+            report.gaps,
+            vec![CoverageGap {
+                start: UnixNanos::from(20),
+                end: UnixNanos::from(100),
+            }]
+        );
+        assert_eq!(report.gap_ns(), 80);
+        assert_eq!(report.span_ns(), 105);
+        assert!(!report.is_clean());
+    }
+
+    #[rstest]
+    fn test_reports_duplicates_and_overlaps() {
+        let segments = vec![segment(&[1, 2, 3]), segment(&[3, 4]), segment(&[10])];
+        let report = scan_segments(quote_at(0).instrument_id, "quotes", &segments, &config(100));
+
+        assert_eq!(
+            report.duplicates,
+            vec![DuplicateTimestamp {
+                ts_init: UnixNanos::from(3),
+                count: 2,
+            }]
+        );
+        assert_eq!(report.overlaps.len(), 1);
+        assert_eq!(report.overlaps[0].first, segments[0].info.name);
+        assert_eq!(report.overlaps[0].second, segments[1].info.name);
+    }
+
+    #[rstest]
+    fn test_reports_non_monotonic_and_range_mismatch() {
+        let mut unordered = segment(&[1, 5, 3]);
+        unordered.info = SegmentInfo::new(UnixNanos::from(1), UnixNanos::from(4));
+        let report = scan_segments(
+            quote_at(0).instrument_id,
+            "quotes",
+            &[unordered],
+            &config(100),
+        );
+
+        assert_eq!(report.non_monotonic.len(), 1);
+        assert_eq!(report.non_monotonic[0].index, 2);
+        assert_eq!(report.non_monotonic[0].previous_ts_init, UnixNanos::from(5));
+        assert!(report.segments[0].range_mismatch);
+    }
+
+    #[rstest]
+    fn test_empty_scan() {
+        let segments: Vec<CatalogSegment<QuoteTick>> = Vec::new();
+        let report = scan_segments(quote_at(0).instrument_id, "quotes", &segments, &config(1));
+
+        assert!(report.is_clean());
+        assert_eq!(report.record_count, 0);
+        assert_eq!(report.coverage_ratio(), None);
+    }
+
+    #[rstest]
+    fn test_file_catalog_integrity_report_serializes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(1), quote_at(2)], 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(2), quote_at(500)], 10)
+            .unwrap();
+
+        let report = catalog
+            .integrity_report::<QuoteTick>("quotes", &instrument_id, &config(100))
+            .unwrap();
+
+        assert_eq!(report.record_count, 4);
+        assert_eq!(report.gaps.len(), 1);
+        assert_eq!(report.duplicates.len(), 1);
+        assert_eq!(report.overlaps.len(), 1);
+
+        let json = serde_json::to_string(&report).unwrap();
+        let decoded: IntegrityReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, report);
+    }
+}
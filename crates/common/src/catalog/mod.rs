@@ -0,0 +1,550 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A lightweight file-based data catalog.
+//!
+//! Records are stored per data type and instrument as immutable segments:
+//!
+//! ```text
+//! {root}/{data_type}/{instrument_id}/{ts_first}-{ts_last}.msgpack
+//! ```
+//!
+//! where `ts_first`/`ts_last` are the zero-padded minimum and maximum `ts_init` of the records
+//! in the segment. Each segment holds its records as MsgPack encoded row groups.
+
+pub mod compaction;
+#[cfg(feature = "flight")]
+pub mod flight;
+pub mod integrity;
+pub mod prefetch;
+pub mod replay;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{EconomicEvent, HasTsInit},
+    identifiers::InstrumentId,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use ustr::Ustr;
+
+// Re-exports
+pub use crate::catalog::compaction::{CompactionConfig, CompactionReport, sort_and_dedup};
+pub use crate::catalog::integrity::{
+    CoverageGap, DuplicateTimestamp, IntegrityConfig, IntegrityReport, NonMonotonicRecord,
+    SegmentCoverage, SegmentOverlap, scan_segments,
+};
+pub use crate::catalog::prefetch::{PrefetchChunk, PrefetchConfig, PrefetchStats, Prefetcher};
+pub use crate::catalog::replay::{ReplayBuffer, ReplayBuildReport, ReplayHeader, ReplayRecord};
+
+/// The file extension for catalog segments.
+pub const SEGMENT_EXTENSION: &str = "msgpack";
+
+/// The data type economic events are stored under, partitioned by country rather than
+/// instrument.
+pub const ECONOMIC_EVENTS_DATA_TYPE: &str = "economic_events";
+
+/// Identifies a stored segment and its declared `ts_init` range.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SegmentInfo {
+    /// The segment file stem.
+    pub name: String,
+    /// The minimum `ts_init` declared by the segment name.
+    pub ts_first: UnixNanos,
+    /// The maximum `ts_init` declared by the segment name.
+    pub ts_last: UnixNanos,
+}
+
+impl SegmentInfo {
+    /// Creates a new [`SegmentInfo`] with the canonical name for the given range.
+    #[must_use]
+    pub fn new(ts_first: UnixNanos, ts_last: UnixNanos) -> Self {
+        Self {
+            name: format!("{:020}-{:020}", ts_first.as_u64(), ts_last.as_u64()),
+            ts_first,
+            ts_last,
+        }
+    }
+
+    /// Parses segment info from a file stem of the form `{ts_first}-{ts_last}[-{suffix}]`.
+    ///
+    /// Returns `None` if the stem does not follow the segment naming scheme.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut parts = name.splitn(3, '-');
+        let ts_first = parts.next()?.parse::<u64>().ok()?;
+        let ts_last = parts.next()?.parse::<u64>().ok()?;
+        Some(Self {
+            name: name.to_string(),
+            ts_first: UnixNanos::from(ts_first),
+            ts_last: UnixNanos::from(ts_last),
+        })
+    }
+
+    /// Returns the segment file name.
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        format!("{}.{SEGMENT_EXTENSION}", self.name)
+    }
+}
+
+/// A catalog segment together with its decoded records.
+#[derive(Clone, Debug)]
+pub struct CatalogSegment<T> {
+    /// The segment identity and declared range.
+    pub info: SegmentInfo,
+    /// The records in stored order.
+    pub records: Vec<T>,
+}
+
+impl<T: HasTsInit> CatalogSegment<T> {
+    /// Creates a new [`CatalogSegment`] named after the `ts_init` range of `records`.
+    ///
+    /// Returns `None` if `records` is empty.
+    #[must_use]
+    pub fn from_records(records: Vec<T>) -> Option<Self> {
+        let ts_first = records.iter().map(HasTsInit::ts_init).min()?;
+        let ts_last = records.iter().map(HasTsInit::ts_init).max()?;
+        Some(Self {
+            info: SegmentInfo::new(ts_first, ts_last),
+            records,
+        })
+    }
+}
+
+/// A file-based catalog of segmented data.
+#[derive(Clone, Debug)]
+pub struct FileCatalog {
+    root: PathBuf,
+}
+
+impl FileCatalog {
+    /// Creates a new [`FileCatalog`] rooted at `root`.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the catalog root directory.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the directory holding segments for `data_type` and `instrument_id`.
+    #[must_use]
+    pub fn segment_dir(&self, data_type: &str, instrument_id: &InstrumentId) -> PathBuf {
+        self.root
+            .join(data_type)
+            .join(instrument_id.to_string().replace('/', ""))
+    }
+
+    /// Returns the file path of the given segment.
+    #[must_use]
+    pub fn segment_path(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        info: &SegmentInfo,
+    ) -> PathBuf {
+        self.segment_dir(data_type, instrument_id)
+            .join(info.file_name())
+    }
+
+    /// Writes `records` as a new segment, split into row groups of `row_group_size` records.
+    ///
+    /// The segment is written to a temporary file and renamed into place, so readers never
+    /// observe a partially written segment. If a segment with the same range already exists a
+    /// numeric suffix is appended to the name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `records` is empty or `row_group_size` is zero.
+    /// - Serialization or any filesystem operation fails.
+    pub fn write_segment<T: Serialize + HasTsInit>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        records: &[T],
+        row_group_size: usize,
+    ) -> anyhow::Result<SegmentInfo> {
+        write_segment_in(
+            &self.segment_dir(data_type, instrument_id),
+            records,
+            row_group_size,
+        )
+    }
+
+    /// Lists the segments stored for `data_type` and `instrument_id`, ordered by declared range.
+    ///
+    /// Files not following the segment naming scheme are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segment directory cannot be read.
+    pub fn list_segments(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Vec<SegmentInfo>> {
+        list_segments_in(&self.segment_dir(data_type, instrument_id))
+    }
+
+    /// Returns the directory holding economic event segments for the `country`.
+    #[must_use]
+    pub fn economic_events_dir(&self, country: &Ustr) -> PathBuf {
+        self.root
+            .join(ECONOMIC_EVENTS_DATA_TYPE)
+            .join(country.as_str())
+    }
+
+    /// Writes `events` as new segments, one per country.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `row_group_size` is zero, or serialization or any filesystem
+    /// operation fails.
+    pub fn write_economic_events(
+        &self,
+        events: &[EconomicEvent],
+        row_group_size: usize,
+    ) -> anyhow::Result<Vec<SegmentInfo>> {
+        let mut by_country: IndexMap<Ustr, Vec<EconomicEvent>> = IndexMap::new();
+        for event in events {
+            by_country
+                .entry(event.country)
+                .or_default()
+                .push(event.clone());
+        }
+
+        by_country
+            .into_iter()
+            .map(|(country, mut events)| {
+                events.sort_by_key(|event| event.ts_init);
+                write_segment_in(&self.economic_events_dir(&country), &events, row_group_size)
+            })
+            .collect()
+    }
+
+    /// Loads the stored economic events for the `country` scheduled within the optional
+    /// `start` and `end` bounds (inclusive), ordered by scheduled time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any segment cannot be listed, read or decoded.
+    pub fn load_economic_events(
+        &self,
+        country: &Ustr,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<EconomicEvent>> {
+        let dir = self.economic_events_dir(country);
+        let mut events = Vec::new();
+        for info in list_segments_in(&dir)? {
+            let bytes = fs::read(dir.join(info.file_name()))?;
+            let row_groups: Vec<Vec<EconomicEvent>> = rmp_serde::from_slice(&bytes)?;
+            events.extend(row_groups.into_iter().flatten().filter(|event| {
+                start.is_none_or(|start| event.ts_event >= start)
+                    && end.is_none_or(|end| event.ts_event <= end)
+            }));
+        }
+        events.sort_by_key(|event| (event.ts_event, event.ts_init));
+        Ok(events)
+    }
+
+    /// Reads the row groups of a stored segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segment cannot be read or decoded.
+    pub fn read_row_groups<T: DeserializeOwned>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        info: &SegmentInfo,
+    ) -> anyhow::Result<Vec<Vec<T>>> {
+        let bytes = fs::read(self.segment_path(data_type, instrument_id, info))?;
+        Ok(rmp_serde::from_slice(&bytes)?)
+    }
+
+    /// Reads all records of a stored segment in stored order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segment cannot be read or decoded.
+    pub fn read_segment<T: DeserializeOwned>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        info: &SegmentInfo,
+    ) -> anyhow::Result<Vec<T>> {
+        Ok(self
+            .read_row_groups(data_type, instrument_id, info)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    /// Loads every segment stored for `data_type` and `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any segment cannot be listed, read or decoded.
+    pub fn load_segments<T: DeserializeOwned>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Vec<CatalogSegment<T>>> {
+        self.list_segments(data_type, instrument_id)?
+            .into_iter()
+            .map(|info| {
+                let records = self.read_segment(data_type, instrument_id, &info)?;
+                Ok(CatalogSegment { info, records })
+            })
+            .collect()
+    }
+
+    /// Scans the stored segments for `data_type` and `instrument_id` and returns an integrity
+    /// report (see [`scan_segments`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any segment cannot be loaded.
+    pub fn integrity_report<T: DeserializeOwned + HasTsInit>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+        config: &IntegrityConfig,
+    ) -> anyhow::Result<IntegrityReport> {
+        let segments = self.load_segments::<T>(data_type, instrument_id)?;
+        Ok(scan_segments(*instrument_id, data_type, &segments, config))
+    }
+}
+
+/// Lists the segments in `dir` (see [`FileCatalog::list_segments`]).
+fn list_segments_in(dir: &Path) -> anyhow::Result<Vec<SegmentInfo>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut segments = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(SEGMENT_EXTENSION) {
+            continue;
+        }
+        if let Some(info) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(SegmentInfo::from_name)
+        {
+            segments.push(info);
+        }
+    }
+
+    segments
+        .sort_by(|a, b| (a.ts_first, a.ts_last, &a.name).cmp(&(b.ts_first, b.ts_last, &b.name)));
+    Ok(segments)
+}
+
+/// Writes `records` as a new segment file in `dir` (see [`FileCatalog::write_segment`]).
+pub(crate) fn write_segment_in<T: Serialize + HasTsInit>(
+    dir: &Path,
+    records: &[T],
+    row_group_size: usize,
+) -> anyhow::Result<SegmentInfo> {
+    anyhow::ensure!(!records.is_empty(), "Cannot write an empty segment");
+    anyhow::ensure!(row_group_size > 0, "`row_group_size` must be positive");
+
+    let ts_first = records.iter().map(HasTsInit::ts_init).min().unwrap();
+    let ts_last = records.iter().map(HasTsInit::ts_init).max().unwrap();
+    fs::create_dir_all(dir)?;
+
+    let mut info = SegmentInfo::new(ts_first, ts_last);
+    let base_name = info.name.clone();
+    let mut suffix = 1;
+    while dir.join(info.file_name()).exists() {
+        info.name = format!("{base_name}-{suffix}");
+        suffix += 1;
+    }
+
+    let row_groups: Vec<&[T]> = records.chunks(row_group_size).collect();
+    let bytes = rmp_serde::to_vec_named(&row_groups)?;
+    write_atomic(&dir.join(info.file_name()), &bytes)?;
+
+    Ok(info)
+}
+
+/// Writes `bytes` to a temporary sibling of `path` then renames it into place.
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, bytes)?;
+    fs::File::open(&tmp_path)?.sync_all()?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    #[rstest]
+    fn test_segment_info_name_round_trip() {
+        let info = SegmentInfo::new(UnixNanos::from(5), UnixNanos::from(10));
+        assert_eq!(info.name, "00000000000000000005-00000000000000000010");
+        assert_eq!(SegmentInfo::from_name(&info.name), Some(info.clone()));
+
+        let suffixed = SegmentInfo::from_name(&format!("{}-2", info.name)).unwrap();
+        assert_eq!(suffixed.ts_first, info.ts_first);
+        assert_eq!(suffixed.ts_last, info.ts_last);
+        assert!(SegmentInfo::from_name("not-a-segment").is_none());
+    }
+
+    #[rstest]
+    fn test_write_list_and_read_segments() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+
+        let records: Vec<QuoteTick> = (1..=5).map(|i| quote_at(i * 10)).collect();
+        let info = catalog
+            .write_segment("quotes", &instrument_id, &records, 2)
+            .unwrap();
+        let later = catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(100)], 2)
+            .unwrap();
+
+        assert_eq!(info.ts_first, UnixNanos::from(10));
+        assert_eq!(info.ts_last, UnixNanos::from(50));
+        assert_eq!(
+            catalog.list_segments("quotes", &instrument_id).unwrap(),
+            vec![info.clone(), later]
+        );
+
+        let row_groups: Vec<Vec<QuoteTick>> = catalog
+            .read_row_groups("quotes", &instrument_id, &info)
+            .unwrap();
+        assert_eq!(
+            row_groups.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert_eq!(
+            catalog
+                .read_segment::<QuoteTick>("quotes", &instrument_id, &info)
+                .unwrap(),
+            records
+        );
+    }
+
+    #[rstest]
+    fn test_write_segment_with_same_range_gets_suffix() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+
+        let first = catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(1)], 10)
+            .unwrap();
+        let second = catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(1)], 10)
+            .unwrap();
+
+        assert_ne!(first.name, second.name);
+        assert_eq!(
+            catalog
+                .list_segments("quotes", &instrument_id)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_write_empty_segment_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_ethusdt_binance().instrument_id;
+        let records: Vec<QuoteTick> = Vec::new();
+
+        assert!(
+            catalog
+                .write_segment("quotes", &instrument_id, &records, 10)
+                .is_err()
+        );
+        assert!(
+            catalog
+                .list_segments("quotes", &instrument_id)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_write_and_load_economic_events() {
+        use nautilus_model::data::EventImpact;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let event = |country: &str, ts: u64| {
+            EconomicEvent::new(
+                Ustr::from(format!("{country}-{ts}").as_str()),
+                Ustr::from("CPI"),
+                Ustr::from(country),
+                EventImpact::High,
+                None,
+                Some(0.3),
+                Some(0.2),
+                UnixNanos::from(ts),
+                UnixNanos::from(ts),
+            )
+        };
+        let events = vec![event("US", 30), event("EU", 20), event("US", 10)];
+
+        let segments = catalog.write_economic_events(&events, 10).unwrap();
+        assert_eq!(segments.len(), 2);
+
+        let us = catalog
+            .load_economic_events(&Ustr::from("US"), None, None)
+            .unwrap();
+        assert_eq!(us, vec![event("US", 10), event("US", 30)]);
+
+        let bounded = catalog
+            .load_economic_events(&Ustr::from("US"), Some(UnixNanos::from(20)), None)
+            .unwrap();
+        assert_eq!(bounded, vec![event("US", 30)]);
+        assert!(
+            catalog
+                .load_economic_events(&Ustr::from("JP"), None, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,405 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pipelined, multi-threaded loading of catalog data for backtests.
+//!
+//! The segments of all requested sources are grouped into chunks of time-overlapping segments.
+//! Chunks never overlap each other, so emitting each chunk sorted by `ts_init` yields one globally
+//! ordered stream while every segment is read exactly once. Worker threads decode and sort chunks
+//! ahead of the consumer, each into its own bounded channel, and the consumer reads the channels
+//! round-robin so chunks arrive in order with at most `capacity` chunks buffered per worker.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{Receiver, SyncSender, sync_channel},
+    },
+    thread::JoinHandle,
+    time::Instant,
+};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{data::HasTsInit, identifiers::InstrumentId};
+use serde::de::DeserializeOwned;
+
+use super::{FileCatalog, SegmentInfo};
+
+/// Configuration for a [`Prefetcher`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrefetchConfig {
+    /// The number of background decode threads.
+    pub workers: usize,
+    /// The maximum number of decoded chunks buffered per worker.
+    pub capacity: usize,
+}
+
+impl Default for PrefetchConfig {
+    /// Creates a new default [`PrefetchConfig`] instance.
+    fn default() -> Self {
+        Self {
+            workers: 2,
+            capacity: 2,
+        }
+    }
+}
+
+/// A decoded, `ts_init` ordered chunk of catalog records.
+#[derive(Clone, Debug)]
+pub struct PrefetchChunk<T> {
+    /// The position of the chunk in the stream.
+    pub index: usize,
+    /// The earliest declared `ts_init` of the chunk segments.
+    pub ts_first: UnixNanos,
+    /// The latest declared `ts_init` of the chunk segments.
+    pub ts_last: UnixNanos,
+    /// The chunk records in `ts_init` order.
+    pub records: Vec<T>,
+}
+
+/// A point-in-time snapshot of [`Prefetcher`] metrics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrefetchStats {
+    /// The total number of chunks in the stream.
+    pub chunks_total: u64,
+    /// The number of chunks decoded by the workers.
+    pub chunks_loaded: u64,
+    /// The number of records decoded by the workers.
+    pub records_loaded: u64,
+    /// The total time workers spent reading, decoding and sorting.
+    pub load_ns: u64,
+    /// The total time workers spent blocked on a full channel (consumer bound).
+    pub producer_blocked_ns: u64,
+    /// The total time the consumer spent waiting for the next chunk (I/O bound).
+    pub consumer_wait_ns: u64,
+}
+
+#[derive(Debug, Default)]
+struct PrefetchMetrics {
+    chunks_loaded: AtomicU64,
+    records_loaded: AtomicU64,
+    load_ns: AtomicU64,
+    producer_blocked_ns: AtomicU64,
+    consumer_wait_ns: AtomicU64,
+}
+
+#[derive(Clone, Debug)]
+struct ChunkPlan {
+    ts_first: UnixNanos,
+    ts_last: UnixNanos,
+    segments: Vec<(String, InstrumentId, SegmentInfo)>,
+}
+
+/// Streams catalog data in `ts_init` order, decoding ahead on background threads.
+///
+/// Dropping the prefetcher stops and joins the workers.
+#[derive(Debug)]
+pub struct Prefetcher<T> {
+    receivers: Vec<Receiver<anyhow::Result<PrefetchChunk<T>>>>,
+    handles: Vec<JoinHandle<()>>,
+    metrics: Arc<PrefetchMetrics>,
+    chunks_total: usize,
+    next: usize,
+}
+
+impl<T> Prefetcher<T>
+where
+    T: DeserializeOwned + HasTsInit + Send + 'static,
+{
+    /// Starts prefetching all segments of `sources` (data type and instrument pairs).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segments of any source cannot be listed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `config.workers` or `config.capacity` is zero.
+    pub fn start(
+        catalog: &FileCatalog,
+        sources: &[(&str, InstrumentId)],
+        config: PrefetchConfig,
+    ) -> anyhow::Result<Self> {
+        assert!(config.workers > 0, "`workers` must be positive");
+        assert!(config.capacity > 0, "`capacity` must be positive");
+
+        let plans = Arc::new(plan_chunks(catalog, sources)?);
+        let metrics = Arc::new(PrefetchMetrics::default());
+        let chunks_total = plans.len();
+        let workers = config.workers.min(chunks_total).max(1);
+
+        let mut receivers = Vec::with_capacity(workers);
+        let mut handles = Vec::with_capacity(workers);
+        for worker in 0..workers {
+            let (tx, rx) = sync_channel(config.capacity);
+            let catalog = catalog.clone();
+            let plans = plans.clone();
+            let metrics = metrics.clone();
+            handles.push(std::thread::spawn(move || {
+                run_worker(&catalog, &plans, worker, workers, &tx, &metrics);
+            }));
+            receivers.push(rx);
+        }
+
+        Ok(Self {
+            receivers,
+            handles,
+            metrics,
+            chunks_total,
+            next: 0,
+        })
+    }
+
+    /// Returns a snapshot of the prefetch metrics.
+    #[must_use]
+    pub fn stats(&self) -> PrefetchStats {
+        PrefetchStats {
+            chunks_total: self.chunks_total as u64,
+            chunks_loaded: self.metrics.chunks_loaded.load(Ordering::Relaxed),
+            records_loaded: self.metrics.records_loaded.load(Ordering::Relaxed),
+            load_ns: self.metrics.load_ns.load(Ordering::Relaxed),
+            producer_blocked_ns: self.metrics.producer_blocked_ns.load(Ordering::Relaxed),
+            consumer_wait_ns: self.metrics.consumer_wait_ns.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl<T> Iterator for Prefetcher<T> {
+    type Item = anyhow::Result<PrefetchChunk<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.chunks_total {
+            return None;
+        }
+
+        let start = Instant::now();
+        let received = self.receivers[self.next % self.receivers.len()].recv();
+        add_elapsed(&self.metrics.consumer_wait_ns, start);
+
+        let item = received
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("Prefetch worker terminated unexpectedly")));
+        // Stop the stream after the first failure since later chunks would leave a gap
+        self.next = if item.is_ok() {
+            self.next + 1
+        } else {
+            self.chunks_total
+        };
+        Some(item)
+    }
+}
+
+impl<T> Drop for Prefetcher<T> {
+    fn drop(&mut self) {
+        // Disconnect the channels first so blocked workers exit
+        self.receivers.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn add_elapsed(counter: &AtomicU64, start: Instant) {
+    let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+    counter.fetch_add(elapsed, Ordering::Relaxed);
+}
+
+fn plan_chunks(
+    catalog: &FileCatalog,
+    sources: &[(&str, InstrumentId)],
+) -> anyhow::Result<Vec<ChunkPlan>> {
+    let mut segments = Vec::new();
+    for (data_type, instrument_id) in sources {
+        for info in catalog.list_segments(data_type, instrument_id)? {
+            segments.push(((*data_type).to_string(), *instrument_id, info));
+        }
+    }
+    segments.sort_by_key(|(_, _, info)| (info.ts_first, info.ts_last));
+
+    let mut plans: Vec<ChunkPlan> = Vec::new();
+    for segment in segments {
+        let (ts_first, ts_last) = (segment.2.ts_first, segment.2.ts_last);
+        match plans.last_mut() {
+            Some(plan) if ts_first <= plan.ts_last => {
+                plan.ts_last = plan.ts_last.max(ts_last);
+                plan.segments.push(segment);
+            }
+            _ => plans.push(ChunkPlan {
+                ts_first,
+                ts_last,
+                segments: vec![segment],
+            }),
+        }
+    }
+
+    Ok(plans)
+}
+
+fn load_chunk<T: DeserializeOwned + HasTsInit>(
+    catalog: &FileCatalog,
+    index: usize,
+    plan: &ChunkPlan,
+) -> anyhow::Result<PrefetchChunk<T>> {
+    let mut records = Vec::new();
+    for (data_type, instrument_id, info) in &plan.segments {
+        records.extend(catalog.read_segment::<T>(data_type, instrument_id, info)?);
+    }
+    // Stable sort keeps stored order for records sharing a timestamp
+    records.sort_by_key(HasTsInit::ts_init);
+
+    Ok(PrefetchChunk {
+        index,
+        ts_first: plan.ts_first,
+        ts_last: plan.ts_last,
+        records,
+    })
+}
+
+fn run_worker<T: DeserializeOwned + HasTsInit>(
+    catalog: &FileCatalog,
+    plans: &[ChunkPlan],
+    worker: usize,
+    workers: usize,
+    tx: &SyncSender<anyhow::Result<PrefetchChunk<T>>>,
+    metrics: &PrefetchMetrics,
+) {
+    for (index, plan) in plans.iter().enumerate().skip(worker).step_by(workers) {
+        let start = Instant::now();
+        let result = load_chunk(catalog, index, plan);
+        add_elapsed(&metrics.load_ns, start);
+
+        let failed = result.is_err();
+        if let Ok(chunk) = &result {
+            metrics.chunks_loaded.fetch_add(1, Ordering::Relaxed);
+            metrics
+                .records_loaded
+                .fetch_add(chunk.records.len() as u64, Ordering::Relaxed);
+        }
+
+        let start = Instant::now();
+        if tx.send(result).is_err() || failed {
+            return; // Consumer dropped or chunk failed
+        }
+        add_elapsed(&metrics.producer_blocked_ns, start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{QuoteTick, stubs::quote_ethusdt_binance};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(instrument_id: InstrumentId, ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.instrument_id = instrument_id;
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    fn write(catalog: &FileCatalog, instrument_id: InstrumentId, ts: &[u64]) {
+        let quotes: Vec<QuoteTick> = ts.iter().map(|t| quote_at(instrument_id, *t)).collect();
+        catalog
+            .write_segment("quotes", &instrument_id, &quotes, 2)
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(2, 1)]
+    #[case(3, 2)]
+    fn test_prefetch_streams_merged_in_order(#[case] workers: usize, #[case] capacity: usize) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let a = InstrumentId::from("ETHUSDT.BINANCE");
+        let b = InstrumentId::from("BTCUSDT.BINANCE");
+        write(&catalog, a, &[1, 4, 6]);
+        write(&catalog, b, &[2, 5]);
+        write(&catalog, a, &[10, 12]);
+        write(&catalog, b, &[20, 21]);
+
+        let prefetcher = Prefetcher::<QuoteTick>::start(
+            &catalog,
+            &[("quotes", a), ("quotes", b)],
+            PrefetchConfig { workers, capacity },
+        )
+        .unwrap();
+        let chunks: Vec<PrefetchChunk<QuoteTick>> =
+            prefetcher.collect::<anyhow::Result<_>>().unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().enumerate().all(|(i, c)| c.index == i));
+        let ts: Vec<u64> = chunks
+            .iter()
+            .flat_map(|c| c.records.iter().map(|q| q.ts_init.as_u64()))
+            .collect();
+        assert_eq!(ts, vec![1, 2, 4, 5, 6, 10, 12, 20, 21]);
+    }
+
+    #[rstest]
+    fn test_prefetch_stats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let a = InstrumentId::from("ETHUSDT.BINANCE");
+        write(&catalog, a, &[1, 2]);
+        write(&catalog, a, &[5]);
+
+        let mut prefetcher =
+            Prefetcher::<QuoteTick>::start(&catalog, &[("quotes", a)], PrefetchConfig::default())
+                .unwrap();
+        while prefetcher.next().is_some() {}
+        let stats = prefetcher.stats();
+
+        assert_eq!(stats.chunks_total, 2);
+        assert_eq!(stats.chunks_loaded, 2);
+        assert_eq!(stats.records_loaded, 3);
+    }
+
+    #[rstest]
+    fn test_prefetch_empty_sources() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let a = InstrumentId::from("ETHUSDT.BINANCE");
+
+        let mut prefetcher =
+            Prefetcher::<QuoteTick>::start(&catalog, &[("quotes", a)], PrefetchConfig::default())
+                .unwrap();
+
+        assert!(prefetcher.next().is_none());
+    }
+
+    #[rstest]
+    fn test_prefetch_drop_before_consuming_joins_workers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let a = InstrumentId::from("ETHUSDT.BINANCE");
+        for ts in [1, 10, 20, 30, 40] {
+            write(&catalog, a, &[ts]);
+        }
+
+        let prefetcher = Prefetcher::<QuoteTick>::start(
+            &catalog,
+            &[("quotes", a)],
+            PrefetchConfig {
+                workers: 2,
+                capacity: 1,
+            },
+        )
+        .unwrap();
+
+        drop(prefetcher);
+    }
+}
@@ -0,0 +1,765 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pre-compiled replay buffers for catalog data.
+//!
+//! A replay buffer holds every record of one data type and instrument as fixed-stride,
+//! little-endian native fields, merged in `ts_init` order. Record `i` lives at a known offset,
+//! so the backtest loop reads data with no deserialization beyond copying fixed-width fields.
+//!
+//! Each buffer header records a hash of the source segment files. [`FileCatalog::build_replay`]
+//! skips the rebuild while the hash matches, and [`FileCatalog::open_replay`] refuses stale
+//! buffers, so edits or compaction of the underlying catalog are always picked up.
+//!
+//! [`ReplayBuffer::open`] memory maps the buffer file read-only, so records are paged in on access
+//! rather than copied up front. Where the file cannot be mapped (or mapping is not supported by
+//! the platform) it is read into memory instead. Buffers are always replaced by atomic rename, so an open mapping keeps reading the
+//! file it was opened on. Truncating a buffer file in place while it is mapped is not supported.
+
+use std::{
+    fs,
+    io::Read,
+    marker::PhantomData,
+    mem::size_of,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::{STACKSTR_CAPACITY, UnixNanos};
+use nautilus_model::{
+    data::{HasTsInit, QuoteTick, TradeTick},
+    enums::{AggressorSide, FromU8},
+    identifiers::{InstrumentId, TradeId},
+    types::{Price, Quantity, fixed::FIXED_PRECISION, price::PriceRaw, quantity::QuantityRaw},
+};
+use serde::de::DeserializeOwned;
+
+use super::{FileCatalog, write_atomic};
+
+/// The magic bytes opening every replay buffer file.
+pub const REPLAY_MAGIC: [u8; 8] = *b"NXREPLAY";
+
+/// The current replay buffer format version.
+pub const REPLAY_VERSION: u16 = 1;
+
+/// The length of the replay buffer header in bytes.
+pub const REPLAY_HEADER_LEN: usize = 32;
+
+/// The directory (under the catalog root) holding replay buffers.
+pub const REPLAY_DIR: &str = ".replay";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+const PRICE_RAW_LEN: usize = size_of::<PriceRaw>();
+const QUANTITY_RAW_LEN: usize = size_of::<QuantityRaw>();
+const NANOS_LEN: usize = size_of::<u64>();
+
+/// A record type with a fixed-stride binary layout for replay buffers.
+///
+/// The instrument ID is not stored per record since a buffer holds a single instrument.
+pub trait ReplayRecord: Sized {
+    /// The tag identifying the record type in the buffer header.
+    const KIND: u8;
+    /// The encoded length of one record in bytes.
+    const STRIDE: usize;
+
+    /// Encodes the record into `buf`, which is exactly [`Self::STRIDE`] bytes long.
+    fn encode(&self, buf: &mut [u8]);
+
+    /// Decodes a record from `buf`, which is exactly [`Self::STRIDE`] bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is not [`Self::STRIDE`] bytes long or does not hold a valid
+    /// record written by [`Self::encode`].
+    fn decode(buf: &[u8], instrument_id: InstrumentId) -> anyhow::Result<Self>;
+}
+
+struct FieldWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> FieldWriter<'a> {
+    const fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn put(&mut self, bytes: &[u8]) {
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+    }
+}
+
+struct FieldReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> FieldReader<'a> {
+    const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Creates a reader over a single record, checking it is exactly `stride` bytes long.
+    fn record(buf: &'a [u8], stride: usize) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            buf.len() == stride,
+            "Replay record length {} does not match stride {stride}",
+            buf.len()
+        );
+        Ok(Self::new(buf))
+    }
+
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        let bytes = self.buf[self.pos..self.pos + N].try_into().unwrap();
+        self.pos += N;
+        bytes
+    }
+
+    fn price(&mut self, precision: u8) -> anyhow::Result<Price> {
+        Price::from_raw_checked(PriceRaw::from_le_bytes(self.take()), precision)
+    }
+
+    fn quantity(&mut self, precision: u8) -> anyhow::Result<Quantity> {
+        Quantity::from_raw_checked(QuantityRaw::from_le_bytes(self.take()), precision)
+    }
+
+    fn nanos(&mut self) -> UnixNanos {
+        UnixNanos::from(u64::from_le_bytes(self.take()))
+    }
+}
+
+impl ReplayRecord for QuoteTick {
+    const KIND: u8 = 1;
+    const STRIDE: usize = 2 * PRICE_RAW_LEN + 2 * QUANTITY_RAW_LEN + 4 + 2 * NANOS_LEN;
+
+    fn encode(&self, buf: &mut [u8]) {
+        let mut writer = FieldWriter::new(buf);
+        writer.put(&[
+            self.bid_price.precision,
+            self.ask_price.precision,
+            self.bid_size.precision,
+            self.ask_size.precision,
+        ]);
+        writer.put(&self.bid_price.raw.to_le_bytes());
+        writer.put(&self.ask_price.raw.to_le_bytes());
+        writer.put(&self.bid_size.raw.to_le_bytes());
+        writer.put(&self.ask_size.raw.to_le_bytes());
+        writer.put(&self.ts_event.as_u64().to_le_bytes());
+        writer.put(&self.ts_init.as_u64().to_le_bytes());
+    }
+
+    fn decode(buf: &[u8], instrument_id: InstrumentId) -> anyhow::Result<Self> {
+        let mut reader = FieldReader::record(buf, Self::STRIDE)?;
+        let [bid_prec, ask_prec, bid_size_prec, ask_size_prec] = reader.take();
+        Ok(Self {
+            instrument_id,
+            bid_price: reader.price(bid_prec)?,
+            ask_price: reader.price(ask_prec)?,
+            bid_size: reader.quantity(bid_size_prec)?,
+            ask_size: reader.quantity(ask_size_prec)?,
+            ts_event: reader.nanos(),
+            ts_init: reader.nanos(),
+        })
+    }
+}
+
+impl ReplayRecord for TradeTick {
+    const KIND: u8 = 2;
+    const STRIDE: usize = PRICE_RAW_LEN + QUANTITY_RAW_LEN + 4 + STACKSTR_CAPACITY + 2 * NANOS_LEN;
+
+    fn encode(&self, buf: &mut [u8]) {
+        let trade_id = self.trade_id.as_str().as_bytes();
+        let mut padded = [0u8; STACKSTR_CAPACITY];
+        padded[..trade_id.len()].copy_from_slice(trade_id);
+
+        let mut writer = FieldWriter::new(buf);
+        writer.put(&[
+            self.price.precision,
+            self.size.precision,
+            self.aggressor_side as u8,
+            trade_id.len() as u8,
+        ]);
+        writer.put(&self.price.raw.to_le_bytes());
+        writer.put(&self.size.raw.to_le_bytes());
+        writer.put(&padded);
+        writer.put(&self.ts_event.as_u64().to_le_bytes());
+        writer.put(&self.ts_init.as_u64().to_le_bytes());
+    }
+
+    fn decode(buf: &[u8], instrument_id: InstrumentId) -> anyhow::Result<Self> {
+        let mut reader = FieldReader::record(buf, Self::STRIDE)?;
+        let [price_prec, size_prec, aggressor, trade_id_len] = reader.take();
+        let price = reader.price(price_prec)?;
+        let size = reader.quantity(size_prec)?;
+        let padded: [u8; STACKSTR_CAPACITY] = reader.take();
+
+        let Some(aggressor_side) = AggressorSide::from_u8(aggressor) else {
+            anyhow::bail!("Invalid aggressor side {aggressor} in replay record");
+        };
+        let Some(trade_id) = padded.get(..usize::from(trade_id_len)) else {
+            anyhow::bail!(
+                "Invalid trade ID length {trade_id_len} in replay record, max {STACKSTR_CAPACITY}"
+            );
+        };
+
+        Ok(Self {
+            instrument_id,
+            price,
+            size,
+            aggressor_side,
+            trade_id: TradeId::from_bytes(trade_id)?,
+            ts_event: reader.nanos(),
+            ts_init: reader.nanos(),
+        })
+    }
+}
+
+/// The decoded header of a replay buffer file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayHeader {
+    /// The record type tag (see [`ReplayRecord::KIND`]).
+    pub kind: u8,
+    /// The fixed precision mode the buffer was built with.
+    pub fixed_precision: u8,
+    /// The encoded length of one record in bytes.
+    pub stride: usize,
+    /// The number of records in the buffer.
+    pub record_count: usize,
+    /// The hash of the source segment files the buffer was built from.
+    pub source_hash: u64,
+}
+
+impl ReplayHeader {
+    fn to_bytes(self) -> [u8; REPLAY_HEADER_LEN] {
+        let mut buf = [0u8; REPLAY_HEADER_LEN];
+        let mut writer = FieldWriter::new(&mut buf);
+        writer.put(&REPLAY_MAGIC);
+        writer.put(&REPLAY_VERSION.to_le_bytes());
+        writer.put(&[self.fixed_precision, self.kind]);
+        writer.put(&(self.stride as u32).to_le_bytes());
+        writer.put(&(self.record_count as u64).to_le_bytes());
+        writer.put(&self.source_hash.to_le_bytes());
+        buf
+    }
+
+    /// Decodes a header from the start of `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is too short, or the magic or version do not match.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bytes.len() >= REPLAY_HEADER_LEN,
+            "Replay buffer too short for header: {} bytes",
+            bytes.len()
+        );
+
+        let mut reader = FieldReader::new(bytes);
+        let magic: [u8; 8] = reader.take();
+        anyhow::ensure!(magic == REPLAY_MAGIC, "Not a replay buffer");
+        let version = u16::from_le_bytes(reader.take());
+        anyhow::ensure!(
+            version == REPLAY_VERSION,
+            "Unsupported replay buffer version {version}, expected {REPLAY_VERSION}"
+        );
+        let [fixed_precision, kind] = reader.take();
+
+        Ok(Self {
+            kind,
+            fixed_precision,
+            stride: u32::from_le_bytes(reader.take()) as usize,
+            record_count: u64::from_le_bytes(reader.take()) as usize,
+            source_hash: u64::from_le_bytes(reader.take()),
+        })
+    }
+
+    /// Reads the header of the replay buffer file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or holds an invalid header.
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let mut buf = [0u8; REPLAY_HEADER_LEN];
+        fs::File::open(path)?.read_exact(&mut buf)?;
+        Self::from_bytes(&buf)
+    }
+}
+
+/// The backing storage of a [`ReplayBuffer`].
+#[derive(Debug)]
+enum ReplayBytes {
+    Owned(Vec<u8>),
+    Mapped(memmap2::Mmap),
+}
+
+impl Deref for ReplayBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Mapped(map) => map,
+        }
+    }
+}
+
+#[allow(unsafe_code)]
+fn load_bytes(file: &fs::File, len: usize) -> anyhow::Result<ReplayBytes> {
+    // A zero-length mapping is invalid, and anything shorter than a header is rejected anyway
+    if len < REPLAY_HEADER_LEN {
+        return read_bytes(file, len);
+    }
+
+    // SAFETY: The mapping is read-only, and buffer files are only ever replaced by atomic
+    // rename, so the mapped file is not modified while the mapping is alive
+    match unsafe { memmap2::MmapOptions::new().len(len).map(file) } {
+        Ok(map) => Ok(ReplayBytes::Mapped(map)),
+        Err(e) => {
+            log::debug!("Reading replay buffer into memory, mapping failed: {e}");
+            read_bytes(file, len)
+        }
+    }
+}
+
+fn read_bytes(mut file: &fs::File, len: usize) -> anyhow::Result<ReplayBytes> {
+    let mut bytes = Vec::with_capacity(len);
+    file.read_to_end(&mut bytes)?;
+    Ok(ReplayBytes::Owned(bytes))
+}
+
+/// A loaded replay buffer of fixed-stride records for a single instrument.
+#[derive(Debug)]
+pub struct ReplayBuffer<T> {
+    instrument_id: InstrumentId,
+    header: ReplayHeader,
+    bytes: ReplayBytes,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ReplayRecord> ReplayBuffer<T> {
+    /// Encodes `records` into an in-memory replay buffer.
+    #[must_use]
+    pub fn from_records(instrument_id: InstrumentId, records: &[T], source_hash: u64) -> Self {
+        let header = ReplayHeader {
+            kind: T::KIND,
+            fixed_precision: FIXED_PRECISION,
+            stride: T::STRIDE,
+            record_count: records.len(),
+            source_hash,
+        };
+
+        let mut bytes = vec![0u8; REPLAY_HEADER_LEN + records.len() * T::STRIDE];
+        bytes[..REPLAY_HEADER_LEN].copy_from_slice(&header.to_bytes());
+        for (record, slot) in records
+            .iter()
+            .zip(bytes[REPLAY_HEADER_LEN..].chunks_exact_mut(T::STRIDE))
+        {
+            record.encode(slot);
+        }
+
+        Self {
+            instrument_id,
+            header,
+            bytes: ReplayBytes::Owned(bytes),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wraps the raw bytes of a replay buffer file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The header is invalid or was written for another record type.
+    /// - The buffer was built in a different fixed precision mode.
+    /// - The length does not match the declared record count and stride.
+    pub fn from_bytes(instrument_id: InstrumentId, bytes: Vec<u8>) -> anyhow::Result<Self> {
+        Self::from_storage(instrument_id, ReplayBytes::Owned(bytes))
+    }
+
+    fn from_storage(instrument_id: InstrumentId, bytes: ReplayBytes) -> anyhow::Result<Self> {
+        let header = ReplayHeader::from_bytes(&bytes)?;
+        anyhow::ensure!(
+            header.kind == T::KIND && header.stride == T::STRIDE,
+            "Replay buffer record kind {} (stride {}) does not match expected kind {} (stride {})",
+            header.kind,
+            header.stride,
+            T::KIND,
+            T::STRIDE
+        );
+        anyhow::ensure!(
+            header.fixed_precision == FIXED_PRECISION,
+            "Replay buffer built with fixed precision {}, expected {FIXED_PRECISION}",
+            header.fixed_precision
+        );
+        let expected_len = REPLAY_HEADER_LEN + header.record_count * header.stride;
+        anyhow::ensure!(
+            bytes.len() == expected_len,
+            "Replay buffer length {} does not match expected {expected_len}",
+            bytes.len()
+        );
+
+        Ok(Self {
+            instrument_id,
+            header,
+            bytes,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Opens the replay buffer file at `path`, memory mapping it where supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be mapped or read, or is not a valid buffer for `T`.
+    pub fn open(path: &Path, instrument_id: InstrumentId) -> anyhow::Result<Self> {
+        let file = fs::File::open(path)?;
+        let len = usize::try_from(file.metadata()?.len())?;
+        Self::from_storage(instrument_id, load_bytes(&file, len)?)
+    }
+
+    /// Returns whether the buffer is backed by a memory mapping of its file.
+    #[must_use]
+    pub const fn is_mapped(&self) -> bool {
+        match self.bytes {
+            ReplayBytes::Owned(_) => false,
+            ReplayBytes::Mapped(_) => true,
+        }
+    }
+
+    /// Returns the instrument ID of the buffer records.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the buffer header.
+    #[must_use]
+    pub const fn header(&self) -> &ReplayHeader {
+        &self.header
+    }
+
+    /// Returns the number of records in the buffer.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.header.record_count
+    }
+
+    /// Returns whether the buffer holds no records.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.header.record_count == 0
+    }
+
+    /// Returns the raw bytes of the buffer, including the header.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the record at `index`, or `None` if out of range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored record is invalid.
+    pub fn get(&self, index: usize) -> anyhow::Result<Option<T>> {
+        if index >= self.len() {
+            return Ok(None);
+        }
+        let offset = REPLAY_HEADER_LEN + index * T::STRIDE;
+        T::decode(&self.bytes[offset..offset + T::STRIDE], self.instrument_id).map(Some)
+    }
+
+    /// Returns an iterator over the records in stored order, yielding an error for any invalid
+    /// stored record.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = anyhow::Result<T>> + '_ {
+        self.bytes[REPLAY_HEADER_LEN..]
+            .chunks_exact(T::STRIDE)
+            .map(|slot| T::decode(slot, self.instrument_id))
+    }
+}
+
+/// The outcome of a [`FileCatalog::build_replay`] call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayBuildReport {
+    /// The replay buffer file path.
+    pub path: PathBuf,
+    /// The number of records in the buffer.
+    pub record_count: usize,
+    /// The hash of the source segment files.
+    pub source_hash: u64,
+    /// Whether the buffer was (re)built, `false` if the existing buffer was up to date.
+    pub rebuilt: bool,
+}
+
+impl FileCatalog {
+    /// Returns the replay buffer file path for `data_type` and `instrument_id`.
+    #[must_use]
+    pub fn replay_path(&self, data_type: &str, instrument_id: &InstrumentId) -> PathBuf {
+        self.root().join(REPLAY_DIR).join(data_type).join(format!(
+            "{}.bin",
+            instrument_id.to_string().replace('/', "")
+        ))
+    }
+
+    /// Computes the hash of the source segment files for `data_type` and `instrument_id`.
+    ///
+    /// The hash covers the name and full contents of every segment, so any write, removal or
+    /// compaction of the segments changes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segments cannot be listed or read.
+    pub fn source_hash(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<u64> {
+        let mut hash = FNV_OFFSET_BASIS;
+        for info in self.list_segments(data_type, instrument_id)? {
+            let bytes = fs::read(self.segment_path(data_type, instrument_id, &info))?;
+            for chunk in [
+                info.name.as_bytes(),
+                &(bytes.len() as u64).to_le_bytes(),
+                &bytes,
+            ] {
+                for byte in chunk {
+                    hash ^= u64::from(*byte);
+                    hash = hash.wrapping_mul(FNV_PRIME);
+                }
+            }
+        }
+        Ok(hash)
+    }
+
+    /// Builds the replay buffer for `data_type` and `instrument_id` unless it is up to date.
+    ///
+    /// Records from all segments are merged in `ts_init` order (stable, so records sharing a
+    /// timestamp keep their stored order) and written atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the segments cannot be read or the buffer cannot be written.
+    pub fn build_replay<T>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<ReplayBuildReport>
+    where
+        T: ReplayRecord + DeserializeOwned + HasTsInit,
+    {
+        let path = self.replay_path(data_type, instrument_id);
+        let source_hash = self.source_hash(data_type, instrument_id)?;
+
+        if let Ok(header) = ReplayHeader::read(&path)
+            && header.source_hash == source_hash
+            && header.kind == T::KIND
+            && header.fixed_precision == FIXED_PRECISION
+        {
+            return Ok(ReplayBuildReport {
+                path,
+                record_count: header.record_count,
+                source_hash,
+                rebuilt: false,
+            });
+        }
+
+        let mut records: Vec<T> = self
+            .load_segments::<T>(data_type, instrument_id)?
+            .into_iter()
+            .flat_map(|segment| segment.records)
+            .collect();
+        records.sort_by_key(HasTsInit::ts_init);
+
+        let buffer = ReplayBuffer::from_records(*instrument_id, &records, source_hash);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic(&path, buffer.as_bytes())?;
+
+        Ok(ReplayBuildReport {
+            path,
+            record_count: records.len(),
+            source_hash,
+            rebuilt: true,
+        })
+    }
+
+    /// Opens the replay buffer for `data_type` and `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is missing, invalid, or stale relative to the current
+    /// source segments (call [`FileCatalog::build_replay`] first).
+    pub fn open_replay<T: ReplayRecord>(
+        &self,
+        data_type: &str,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<ReplayBuffer<T>> {
+        let path = self.replay_path(data_type, instrument_id);
+        let buffer = ReplayBuffer::<T>::open(&path, *instrument_id)?;
+        let source_hash = self.source_hash(data_type, instrument_id)?;
+        anyhow::ensure!(
+            buffer.header().source_hash == source_hash,
+            "Replay buffer {} is stale, rebuild required",
+            path.display()
+        );
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_trade_ethusdt_buyer};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(ts: u64) -> QuoteTick {
+        let mut quote = quote_ethusdt_binance();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        quote
+    }
+
+    #[rstest]
+    fn test_quote_encode_decode_round_trip() {
+        let quote = quote_at(42);
+        let mut buf = vec![0u8; QuoteTick::STRIDE];
+
+        quote.encode(&mut buf);
+
+        assert_eq!(QuoteTick::decode(&buf, quote.instrument_id).unwrap(), quote);
+    }
+
+    #[rstest]
+    fn test_trade_encode_decode_round_trip() {
+        let trade = stub_trade_ethusdt_buyer();
+        let mut buf = vec![0u8; TradeTick::STRIDE];
+
+        trade.encode(&mut buf);
+
+        assert_eq!(TradeTick::decode(&buf, trade.instrument_id).unwrap(), trade);
+    }
+
+    #[rstest]
+    fn test_trade_decode_rejects_corrupt_records() {
+        let trade = stub_trade_ethusdt_buyer();
+        let mut buf = vec![0u8; TradeTick::STRIDE];
+        trade.encode(&mut buf);
+
+        let mut bad_len = buf.clone();
+        bad_len[3] = u8::MAX;
+        assert!(TradeTick::decode(&bad_len, trade.instrument_id).is_err());
+
+        let mut bad_aggressor = buf.clone();
+        bad_aggressor[2] = u8::MAX;
+        assert!(TradeTick::decode(&bad_aggressor, trade.instrument_id).is_err());
+
+        let mut bad_precision = buf.clone();
+        bad_precision[0] = u8::MAX;
+        assert!(TradeTick::decode(&bad_precision, trade.instrument_id).is_err());
+
+        assert!(TradeTick::decode(&buf[1..], trade.instrument_id).is_err());
+    }
+
+    #[rstest]
+    fn test_buffer_from_bytes_validates_layout() {
+        let quote = quote_at(1);
+        let buffer = ReplayBuffer::from_records(quote.instrument_id, &[quote], 7);
+        let bytes = buffer.as_bytes().to_vec();
+
+        assert!(ReplayBuffer::<TradeTick>::from_bytes(quote.instrument_id, bytes.clone()).is_err());
+        assert!(
+            ReplayBuffer::<QuoteTick>::from_bytes(
+                quote.instrument_id,
+                bytes[..bytes.len() - 1].to_vec()
+            )
+            .is_err()
+        );
+        let reloaded = ReplayBuffer::<QuoteTick>::from_bytes(quote.instrument_id, bytes).unwrap();
+        assert_eq!(reloaded.header().source_hash, 7);
+        assert_eq!(reloaded.get(0).unwrap(), Some(quote));
+        assert_eq!(reloaded.get(1).unwrap(), None);
+    }
+
+    #[rstest]
+    fn test_build_replay_merges_segments_in_order() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(5), quote_at(6)], 10)
+            .unwrap();
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(1), quote_at(3)], 10)
+            .unwrap();
+
+        let report = catalog
+            .build_replay::<QuoteTick>("quotes", &instrument_id)
+            .unwrap();
+        let buffer = catalog
+            .open_replay::<QuoteTick>("quotes", &instrument_id)
+            .unwrap();
+
+        assert!(report.rebuilt);
+        assert_eq!(report.record_count, 4);
+        assert_eq!(buffer.is_mapped(), cfg!(any(unix, windows)));
+        let ts: Vec<u64> = buffer.iter().map(|q| q.unwrap().ts_init.as_u64()).collect();
+        assert_eq!(ts, vec![1, 3, 5, 6]);
+    }
+
+    #[rstest]
+    fn test_build_replay_invalidated_by_source_change() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = FileCatalog::new(temp_dir.path());
+        let instrument_id = quote_at(0).instrument_id;
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(1)], 10)
+            .unwrap();
+        catalog
+            .build_replay::<QuoteTick>("quotes", &instrument_id)
+            .unwrap();
+
+        let unchanged = catalog
+            .build_replay::<QuoteTick>("quotes", &instrument_id)
+            .unwrap();
+        assert!(!unchanged.rebuilt);
+
+        catalog
+            .write_segment("quotes", &instrument_id, &[quote_at(2)], 10)
+            .unwrap();
+        assert!(
+            catalog
+                .open_replay::<QuoteTick>("quotes", &instrument_id)
+                .is_err()
+        );
+
+        let rebuilt = catalog
+            .build_replay::<QuoteTick>("quotes", &instrument_id)
+            .unwrap();
+        assert!(rebuilt.rebuilt);
+        assert_eq!(rebuilt.record_count, 2);
+        assert_ne!(rebuilt.source_hash, unchanged.source_hash);
+        assert!(
+            catalog
+                .open_replay::<QuoteTick>("quotes", &instrument_id)
+                .is_ok()
+        );
+    }
+}
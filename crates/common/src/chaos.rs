@@ -0,0 +1,435 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fault injection for testing operational handling in sandbox environments.
+//!
+//! The [`ChaosInjector`] sits between strategies and execution clients (and between data clients
+//! and the data engine), simulating venue rejects, delayed acknowledgements, disconnects and
+//! corrupted market data, either on demand or according to scheduled [`ChaosRule`]s. Injection
+//! is refused in the live environment.
+//!
+//! Probabilistic rules draw from a seeded generator so runs are reproducible.
+
+use std::fmt::Display;
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    identifiers::{ClientId, InstrumentId},
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{enums::Environment, messages::execution::TradingCommand};
+
+/// A fault to inject.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChaosFault {
+    /// Reject the command as the venue would.
+    Reject { reason: Ustr },
+    /// Delay the acknowledgement of the command.
+    DelayAck { delay_ns: u64 },
+    /// Disconnect the client, dropping its commands for the duration.
+    Disconnect { duration_ns: u64 },
+    /// Corrupt market data updates.
+    CorruptData,
+}
+
+impl Display for ChaosFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reject { reason } => write!(f, "Reject({reason})"),
+            Self::DelayAck { delay_ns } => write!(f, "DelayAck({delay_ns}ns)"),
+            Self::Disconnect { duration_ns } => write!(f, "Disconnect({duration_ns}ns)"),
+            Self::CorruptData => write!(f, "CorruptData"),
+        }
+    }
+}
+
+/// A rule describing when a fault is injected.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChaosRule {
+    pub fault: ChaosFault,
+    /// Restricts the rule to commands for this client (`None` for all).
+    pub client_id: Option<ClientId>,
+    /// Restricts the rule to this instrument (`None` for all).
+    pub instrument_id: Option<InstrumentId>,
+    /// The start of the active window.
+    pub start_ns: UnixNanos,
+    /// The end of the active window (`None` for open-ended).
+    pub end_ns: Option<UnixNanos>,
+    /// The probability the fault is injected for each matching message.
+    pub probability: f64,
+    /// The number of remaining injections (`None` for unlimited).
+    pub remaining: Option<usize>,
+}
+
+impl ChaosRule {
+    /// Creates a new [`ChaosRule`] injecting `fault` into every matching message.
+    #[must_use]
+    pub const fn new(fault: ChaosFault) -> Self {
+        Self {
+            fault,
+            client_id: None,
+            instrument_id: None,
+            start_ns: UnixNanos::new(0),
+            end_ns: None,
+            probability: 1.0,
+            remaining: None,
+        }
+    }
+
+    fn is_active(&self, ts: UnixNanos) -> bool {
+        ts >= self.start_ns
+            && self.end_ns.is_none_or(|end| ts < end)
+            && self.remaining.is_none_or(|remaining| remaining > 0)
+    }
+
+    fn matches(&self, client_id: Option<ClientId>, instrument_id: InstrumentId) -> bool {
+        self.client_id.is_none_or(|id| Some(id) == client_id)
+            && self.instrument_id.is_none_or(|id| id == instrument_id)
+    }
+}
+
+/// What should happen to a command after fault injection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChaosOutcome {
+    /// Forward the command unchanged.
+    Pass,
+    /// Do not forward the command, reject it with the reason.
+    Reject(Ustr),
+    /// Forward the command, delaying its acknowledgement.
+    Delay(u64),
+    /// Do not forward the command, the client is disconnected.
+    Drop,
+}
+
+/// A record of an injected fault.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InjectedFault {
+    pub fault: ChaosFault,
+    pub client_id: Option<ClientId>,
+    pub instrument_id: InstrumentId,
+    pub ts: UnixNanos,
+}
+
+/// Injects faults into commands and market data.
+#[derive(Clone, Debug)]
+pub struct ChaosInjector {
+    rules: Vec<ChaosRule>,
+    disconnected_until: AHashMap<ClientId, UnixNanos>,
+    history: Vec<InjectedFault>,
+    rng_state: u64,
+}
+
+impl ChaosInjector {
+    /// Creates a new [`ChaosInjector`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `environment` is live.
+    pub fn new(environment: Environment, seed: u64) -> anyhow::Result<Self> {
+        if environment == Environment::Live {
+            anyhow::bail!("Chaos injection is not permitted in the live environment");
+        }
+        Ok(Self {
+            rules: Vec::new(),
+            disconnected_until: AHashMap::new(),
+            history: Vec::new(),
+            // Scramble the seed, as xorshift state must be non-zero and small seeds start poorly
+            rng_state: (seed ^ 0x9E37_79B9_7F4A_7C15).max(1),
+        })
+    }
+
+    /// Adds a scheduled rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule's probability is not within [0, 1].
+    pub fn schedule(&mut self, rule: ChaosRule) -> anyhow::Result<()> {
+        if !(0.0..=1.0).contains(&rule.probability) {
+            anyhow::bail!("Invalid chaos rule probability {}", rule.probability);
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Injects `fault` once, into the next matching command (or data update for
+    /// [`ChaosFault::CorruptData`]).
+    pub fn inject(&mut self, fault: ChaosFault, client_id: Option<ClientId>) {
+        self.rules.push(ChaosRule {
+            client_id,
+            remaining: Some(1),
+            ..ChaosRule::new(fault)
+        });
+    }
+
+    /// Disconnects `client_id` immediately for `duration_ns`.
+    pub fn disconnect(&mut self, client_id: ClientId, ts: UnixNanos, duration_ns: u64) {
+        self.disconnected_until
+            .insert(client_id, UnixNanos::from(ts.as_u64() + duration_ns));
+    }
+
+    /// Returns whether `client_id` is disconnected at `ts`.
+    #[must_use]
+    pub fn is_disconnected(&self, client_id: &ClientId, ts: UnixNanos) -> bool {
+        self.disconnected_until
+            .get(client_id)
+            .is_some_and(|until| ts < *until)
+    }
+
+    /// Removes all rules and reconnects all clients.
+    pub fn clear(&mut self) {
+        self.rules.clear();
+        self.disconnected_until.clear();
+    }
+
+    /// Applies command faults to `command`, returning the outcome.
+    pub fn on_command(&mut self, command: &TradingCommand, ts: UnixNanos) -> ChaosOutcome {
+        if matches!(command, TradingCommand::QueryAccount(_)) {
+            return ChaosOutcome::Pass;
+        }
+        let client_id = command.client_id();
+        if client_id.is_some_and(|id| self.is_disconnected(&id, ts)) {
+            return ChaosOutcome::Drop;
+        }
+
+        let instrument_id = command.instrument_id();
+        let Some(fault) = self.fire(client_id, instrument_id, ts, |fault| {
+            !matches!(fault, ChaosFault::CorruptData)
+        }) else {
+            return ChaosOutcome::Pass;
+        };
+
+        match fault {
+            ChaosFault::Reject { reason } => ChaosOutcome::Reject(reason),
+            ChaosFault::DelayAck { delay_ns } => ChaosOutcome::Delay(delay_ns),
+            ChaosFault::Disconnect { duration_ns } => {
+                if let Some(client_id) = client_id {
+                    self.disconnect(client_id, ts, duration_ns);
+                }
+                ChaosOutcome::Drop
+            }
+            ChaosFault::CorruptData => unreachable!("data faults are not applied to commands"),
+        }
+    }
+
+    /// Returns a corrupted (crossed) copy of `quote` if a data fault fires.
+    pub fn on_quote(&mut self, quote: &QuoteTick, ts: UnixNanos) -> Option<QuoteTick> {
+        self.fire_data(quote.instrument_id, ts)?;
+        let mut corrupted = *quote;
+        let spread = (quote.ask_price.as_f64() - quote.bid_price.as_f64()).abs();
+        corrupted.bid_price = Price::new(
+            quote.ask_price.as_f64() + spread.max(quote.ask_price.as_f64() * 0.01),
+            quote.bid_price.precision,
+        );
+        Some(corrupted)
+    }
+
+    /// Returns a corrupted (zero priced) copy of `trade` if a data fault fires.
+    pub fn on_trade(&mut self, trade: &TradeTick, ts: UnixNanos) -> Option<TradeTick> {
+        self.fire_data(trade.instrument_id, ts)?;
+        let mut corrupted = *trade;
+        corrupted.price = Price::new(0.0, trade.price.precision);
+        Some(corrupted)
+    }
+
+    /// Returns the injected faults in the order they occurred.
+    #[must_use]
+    pub fn history(&self) -> &[InjectedFault] {
+        &self.history
+    }
+
+    fn fire_data(&mut self, instrument_id: InstrumentId, ts: UnixNanos) -> Option<ChaosFault> {
+        self.fire(None, instrument_id, ts, |fault| {
+            matches!(fault, ChaosFault::CorruptData)
+        })
+    }
+
+    fn fire(
+        &mut self,
+        client_id: Option<ClientId>,
+        instrument_id: InstrumentId,
+        ts: UnixNanos,
+        applies: impl Fn(&ChaosFault) -> bool,
+    ) -> Option<ChaosFault> {
+        for index in 0..self.rules.len() {
+            let rule = &self.rules[index];
+            if !applies(&rule.fault)
+                || !rule.is_active(ts)
+                || !rule.matches(client_id, instrument_id)
+            {
+                continue;
+            }
+            let probability = rule.probability;
+            if probability < 1.0 && self.next_f64() >= probability {
+                continue;
+            }
+
+            let rule = &mut self.rules[index];
+            if let Some(remaining) = rule.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            let fault = rule.fault;
+            log::warn!("Injecting {fault} for {instrument_id} ({client_id:?}) at {ts}");
+            self.history.push(InjectedFault {
+                fault,
+                client_id,
+                instrument_id,
+                ts,
+            });
+            self.rules.retain(|rule| rule.remaining != Some(0));
+            return Some(fault);
+        }
+        None
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        // Xorshift64
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::CancelOrder;
+
+    fn cancel(client_id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            Some(ClientId::from(client_id)),
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::from("O-1"),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        ))
+    }
+
+    fn injector() -> ChaosInjector {
+        ChaosInjector::new(Environment::Sandbox, 42).unwrap()
+    }
+
+    #[rstest]
+    fn test_refused_in_live_environment() {
+        assert!(ChaosInjector::new(Environment::Live, 42).is_err());
+    }
+
+    #[rstest]
+    fn test_on_demand_reject_fires_once_for_matching_client() {
+        let mut injector = injector();
+        injector.inject(
+            ChaosFault::Reject {
+                reason: Ustr::from("INSUFFICIENT_MARGIN"),
+            },
+            Some(ClientId::from("BINANCE")),
+        );
+
+        assert_eq!(
+            injector.on_command(&cancel("BYBIT"), 1.into()),
+            ChaosOutcome::Pass
+        );
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 2.into()),
+            ChaosOutcome::Reject(Ustr::from("INSUFFICIENT_MARGIN"))
+        );
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 3.into()),
+            ChaosOutcome::Pass
+        );
+        assert_eq!(injector.history().len(), 1);
+    }
+
+    #[rstest]
+    fn test_scheduled_disconnect_drops_commands_for_duration() {
+        let mut injector = injector();
+        injector
+            .schedule(ChaosRule {
+                start_ns: 10.into(),
+                remaining: Some(1),
+                ..ChaosRule::new(ChaosFault::Disconnect { duration_ns: 100 })
+            })
+            .unwrap();
+
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 5.into()),
+            ChaosOutcome::Pass
+        );
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 10.into()),
+            ChaosOutcome::Drop
+        );
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 50.into()),
+            ChaosOutcome::Drop
+        );
+        assert!(injector.is_disconnected(&ClientId::from("BINANCE"), 109.into()));
+        assert_eq!(
+            injector.on_command(&cancel("BINANCE"), 110.into()),
+            ChaosOutcome::Pass
+        );
+    }
+
+    #[rstest]
+    fn test_probabilistic_delay_is_reproducible() {
+        let run = || {
+            let mut injector = injector();
+            injector
+                .schedule(ChaosRule {
+                    probability: 0.5,
+                    ..ChaosRule::new(ChaosFault::DelayAck { delay_ns: 1_000 })
+                })
+                .unwrap();
+            (0..100)
+                .map(|ts| injector.on_command(&cancel("BINANCE"), ts.into()))
+                .collect::<Vec<_>>()
+        };
+
+        let outcomes = run();
+        let delayed = outcomes
+            .iter()
+            .filter(|outcome| **outcome == ChaosOutcome::Delay(1_000))
+            .count();
+
+        assert_eq!(outcomes, run());
+        assert!(delayed > 25 && delayed < 75);
+    }
+
+    #[rstest]
+    fn test_corrupt_quote_is_crossed() {
+        let mut injector = injector();
+        injector.inject(ChaosFault::CorruptData, None);
+        let quote = QuoteTick::default();
+
+        let corrupted = injector.on_quote(&quote, 1.into()).unwrap();
+
+        assert!(corrupted.bid_price > corrupted.ask_price);
+        assert!(injector.on_quote(&quote, 2.into()).is_none());
+    }
+}
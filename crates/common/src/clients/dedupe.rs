@@ -0,0 +1,387 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Command deduplication for idempotent execution command handling.
+//!
+//! Retried submissions (e.g. after a request timeout or a reconnect) can reach an execution
+//! client more than once. The [`CommandDeduplicator`] remembers recently seen command IDs, and
+//! the client order IDs of submitted orders, for a configurable time window so that a retried
+//! command is recognized and dropped before it results in a duplicate venue order.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ahash::AHashMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{identifiers::ClientOrderId, orders::Order};
+
+use crate::messages::execution::TradingCommand;
+
+/// The default deduplication window (60 seconds).
+pub const DEFAULT_DEDUPE_WINDOW_NS: u64 = 60_000_000_000;
+
+/// The key a duplicate command was detected on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DedupeKey {
+    /// The command ID was already seen within the window.
+    CommandId(UUID4),
+    /// An order with the client order ID was already submitted within the window.
+    ClientOrderId(ClientOrderId),
+}
+
+impl Display for DedupeKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CommandId(command_id) => write!(f, "command_id={command_id}"),
+            Self::ClientOrderId(client_order_id) => {
+                write!(f, "client_order_id={client_order_id}")
+            }
+        }
+    }
+}
+
+/// The outcome of checking a command against the deduplication window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DedupeDecision {
+    /// The command has not been seen within the window and should be processed.
+    Accept,
+    /// The command is a duplicate and should be dropped.
+    Duplicate(DedupeKey),
+}
+
+impl DedupeDecision {
+    /// Returns whether the command is a duplicate.
+    #[must_use]
+    pub const fn is_duplicate(&self) -> bool {
+        matches!(self, Self::Duplicate(_))
+    }
+}
+
+/// Configuration for a [`CommandDeduplicator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommandDedupeConfig {
+    /// The time window (nanoseconds) within which repeated keys are treated as duplicates.
+    pub window_ns: u64,
+    /// If submitted orders should also be deduplicated on their client order ID.
+    pub dedupe_client_order_ids: bool,
+}
+
+impl Default for CommandDedupeConfig {
+    fn default() -> Self {
+        Self {
+            window_ns: DEFAULT_DEDUPE_WINDOW_NS,
+            dedupe_client_order_ids: true,
+        }
+    }
+}
+
+/// Counters describing deduplication activity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DedupeMetrics {
+    /// The number of commands checked.
+    pub checked: u64,
+    /// The number of commands accepted.
+    pub accepted: u64,
+    /// The number of duplicates detected on command ID.
+    pub command_id_hits: u64,
+    /// The number of duplicates detected on client order ID.
+    pub client_order_id_hits: u64,
+    /// The number of keys evicted after their window elapsed.
+    pub evicted: u64,
+}
+
+impl DedupeMetrics {
+    /// Returns the total number of duplicates detected.
+    #[must_use]
+    pub const fn hits(&self) -> u64 {
+        self.command_id_hits + self.client_order_id_hits
+    }
+}
+
+/// Drops trading commands which repeat a recently seen command or client order ID.
+#[derive(Debug)]
+pub struct CommandDeduplicator {
+    config: CommandDedupeConfig,
+    seen: AHashMap<DedupeKey, UnixNanos>,
+    expiries: VecDeque<(UnixNanos, DedupeKey)>,
+    metrics: DedupeMetrics,
+}
+
+impl Default for CommandDeduplicator {
+    fn default() -> Self {
+        Self::new(CommandDedupeConfig::default())
+    }
+}
+
+impl CommandDeduplicator {
+    /// Creates a new [`CommandDeduplicator`] instance.
+    #[must_use]
+    pub fn new(config: CommandDedupeConfig) -> Self {
+        Self {
+            config,
+            seen: AHashMap::new(),
+            expiries: VecDeque::new(),
+            metrics: DedupeMetrics::default(),
+        }
+    }
+
+    /// Returns the deduplication configuration.
+    #[must_use]
+    pub const fn config(&self) -> &CommandDedupeConfig {
+        &self.config
+    }
+
+    /// Returns the current deduplication metrics.
+    #[must_use]
+    pub const fn metrics(&self) -> DedupeMetrics {
+        self.metrics
+    }
+
+    /// Returns the number of keys currently tracked within the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns whether no keys are currently tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Checks the `command` against the window at `ts_now`, recording its keys when accepted.
+    ///
+    /// A duplicate command does not refresh the window of the original keys.
+    pub fn check(&mut self, command: &TradingCommand, ts_now: UnixNanos) -> DedupeDecision {
+        self.evict_expired(ts_now);
+        self.metrics.checked += 1;
+
+        let keys = self.keys_for(command);
+
+        if let Some(key) = keys.iter().find(|key| self.seen.contains_key(key)) {
+            match key {
+                DedupeKey::CommandId(_) => self.metrics.command_id_hits += 1,
+                DedupeKey::ClientOrderId(_) => self.metrics.client_order_id_hits += 1,
+            }
+            log::warn!("Dropping duplicate command {command} ({key})");
+            return DedupeDecision::Duplicate(*key);
+        }
+
+        let expire_at = ts_now + self.config.window_ns;
+        for key in keys {
+            self.seen.insert(key, expire_at);
+            self.expiries.push_back((expire_at, key));
+        }
+
+        self.metrics.accepted += 1;
+        DedupeDecision::Accept
+    }
+
+    /// Removes all tracked keys and resets the metrics.
+    pub fn reset(&mut self) {
+        self.seen.clear();
+        self.expiries.clear();
+        self.metrics = DedupeMetrics::default();
+    }
+
+    fn keys_for(&self, command: &TradingCommand) -> Vec<DedupeKey> {
+        let mut keys = vec![DedupeKey::CommandId(command.command_id())];
+
+        if self.config.dedupe_client_order_ids {
+            match command {
+                TradingCommand::SubmitOrder(cmd) => {
+                    keys.push(DedupeKey::ClientOrderId(cmd.client_order_id));
+                }
+                TradingCommand::SubmitSpreadOrder(cmd) => {
+                    keys.push(DedupeKey::ClientOrderId(cmd.client_order_id));
+                }
+                TradingCommand::SubmitOrderList(cmd) => keys.extend(
+                    cmd.order_list
+                        .orders
+                        .iter()
+                        .map(|order| DedupeKey::ClientOrderId(order.client_order_id())),
+                ),
+                _ => {}
+            }
+        }
+
+        keys
+    }
+
+    fn evict_expired(&mut self, ts_now: UnixNanos) {
+        while let Some((expire_at, key)) = self.expiries.front().copied() {
+            if expire_at > ts_now {
+                break;
+            }
+            self.expiries.pop_front();
+
+            // Only evict when the stored expiry matches (the key may have been re-inserted)
+            if self.seen.get(&key) == Some(&expire_at) {
+                self.seen.remove(&key);
+                self.metrics.evicted += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::{InstrumentId, StrategyId, TraderId},
+        orders::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::{CancelOrder, SubmitOrder};
+
+    fn submit(client_order_id: &str, command_id: UUID4) -> TradingCommand {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("BTCUSDT.BINANCE"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(1))
+            .build();
+
+        TradingCommand::SubmitOrder(SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            command_id,
+            UnixNanos::default(),
+        ))
+    }
+
+    fn cancel(client_order_id: &str, command_id: UUID4) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            ClientOrderId::from(client_order_id),
+            None,
+            command_id,
+            UnixNanos::default(),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_accepts_first_command() {
+        let mut dedupe = CommandDeduplicator::default();
+        let decision = dedupe.check(&submit("O-1", UUID4::new()), UnixNanos::from(1));
+
+        assert_eq!(decision, DedupeDecision::Accept);
+        assert_eq!(dedupe.len(), 2);
+        assert_eq!(dedupe.metrics().accepted, 1);
+    }
+
+    #[rstest]
+    fn test_duplicate_command_id() {
+        let mut dedupe = CommandDeduplicator::default();
+        let command_id = UUID4::new();
+        let command = cancel("O-1", command_id);
+
+        dedupe.check(&command, UnixNanos::from(1));
+        let decision = dedupe.check(&command, UnixNanos::from(2));
+
+        assert_eq!(
+            decision,
+            DedupeDecision::Duplicate(DedupeKey::CommandId(command_id))
+        );
+        assert_eq!(dedupe.metrics().command_id_hits, 1);
+        assert_eq!(dedupe.metrics().hits(), 1);
+    }
+
+    #[rstest]
+    fn test_resubmitted_order_with_new_command_id() {
+        let mut dedupe = CommandDeduplicator::default();
+
+        dedupe.check(&submit("O-1", UUID4::new()), UnixNanos::from(1));
+        let decision = dedupe.check(&submit("O-1", UUID4::new()), UnixNanos::from(2));
+
+        assert_eq!(
+            decision,
+            DedupeDecision::Duplicate(DedupeKey::ClientOrderId(ClientOrderId::from("O-1")))
+        );
+        assert_eq!(dedupe.metrics().client_order_id_hits, 1);
+    }
+
+    #[rstest]
+    fn test_cancels_for_same_order_are_not_duplicates() {
+        let mut dedupe = CommandDeduplicator::default();
+
+        dedupe.check(&cancel("O-1", UUID4::new()), UnixNanos::from(1));
+        let decision = dedupe.check(&cancel("O-1", UUID4::new()), UnixNanos::from(2));
+
+        assert_eq!(decision, DedupeDecision::Accept);
+    }
+
+    #[rstest]
+    fn test_keys_expire_after_window() {
+        let config = CommandDedupeConfig {
+            window_ns: 100,
+            ..Default::default()
+        };
+        let mut dedupe = CommandDeduplicator::new(config);
+        let command = cancel("O-1", UUID4::new());
+
+        dedupe.check(&command, UnixNanos::from(1));
+        assert!(dedupe.check(&command, UnixNanos::from(100)).is_duplicate());
+
+        let decision = dedupe.check(&command, UnixNanos::from(101));
+
+        assert_eq!(decision, DedupeDecision::Accept);
+        assert_eq!(dedupe.metrics().evicted, 1);
+    }
+
+    #[rstest]
+    fn test_client_order_id_dedupe_disabled() {
+        let config = CommandDedupeConfig {
+            dedupe_client_order_ids: false,
+            ..Default::default()
+        };
+        let mut dedupe = CommandDeduplicator::new(config);
+
+        dedupe.check(&submit("O-1", UUID4::new()), UnixNanos::from(1));
+        let decision = dedupe.check(&submit("O-1", UUID4::new()), UnixNanos::from(2));
+
+        assert_eq!(decision, DedupeDecision::Accept);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut dedupe = CommandDeduplicator::default();
+        let command = cancel("O-1", UUID4::new());
+
+        dedupe.check(&command, UnixNanos::from(1));
+        dedupe.check(&command, UnixNanos::from(2));
+        dedupe.reset();
+
+        assert!(dedupe.is_empty());
+        assert_eq!(dedupe.metrics(), DedupeMetrics::default());
+        assert_eq!(
+            dedupe.check(&command, UnixNanos::from(3)),
+            DedupeDecision::Accept
+        );
+    }
+}
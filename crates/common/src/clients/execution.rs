@@ -31,7 +31,7 @@ use super::log_not_implemented;
 use crate::messages::execution::{
     BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
     GenerateOrderStatusReport, GenerateOrderStatusReports, GeneratePositionStatusReports,
-    ModifyOrder, QueryAccount, QueryOrder, SubmitOrder, SubmitOrderList,
+    ModifyOrder, QueryAccount, QueryOrder, SubmitOrder, SubmitOrderList, SubmitSpreadOrder,
 };
 
 /// Defines the interface for an execution client managing order operations.
@@ -114,6 +114,19 @@ pub trait ExecutionClient {
         Ok(())
     }
 
+    /// Submits a spread order as a venue-native combo order.
+    ///
+    /// Only called for venues which support the spread natively, otherwise the legs are submitted
+    /// as synchronized single orders (see [`SpreadOrderManager`](crate::spread::SpreadOrderManager)).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if submission fails.
+    fn submit_spread_order(&self, cmd: &SubmitSpreadOrder) -> anyhow::Result<()> {
+        log_not_implemented(cmd);
+        Ok(())
+    }
+
     /// Modifies an existing order.
     ///
     /// # Errors
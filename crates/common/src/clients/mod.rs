@@ -18,6 +18,9 @@
 //! Provides the core trait interfaces that define how clients interact with
 //! data providers and execution venues.
 
+pub mod dedupe;
+pub mod polling;
+
 mod data;
 mod execution;
 
@@ -0,0 +1,687 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A generic polling data client for REST endpoints.
+//!
+//! The [`RestPollingDataClient`] fetches user-defined endpoints on a schedule, maps each JSON
+//! response to [`CustomData`] or standard market data types according to a [`ResponseMapping`],
+//! and publishes the results on the message bus. It is intended for long-tail data sources which
+//! do not warrant a full adapter.
+//!
+//! The HTTP transport is provided by the caller through the [`RestFetcher`] trait, so the client
+//! carries no HTTP dependency of its own. Field locations in the response are given as JSON
+//! pointers (RFC 6901), e.g. `/data/0/bid`.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc, str::FromStr};
+
+use ahash::AHashMap;
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::DateTime;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{DataType, MarkPriceUpdate, QuoteTick, TradeTick},
+    enums::AggressorSide,
+    identifiers::{ClientId, InstrumentId, TradeId, Venue},
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::DataClient;
+use crate::{
+    clock::Clock,
+    custom::CustomData,
+    msgbus::{
+        self,
+        switchboard::{get_custom_topic, get_mark_price_topic, get_quotes_topic, get_trades_topic},
+    },
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Fetches the raw response body of a polled endpoint.
+pub trait RestFetcher {
+    /// Performs the HTTP request for the `endpoint` and returns the response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or returns an unsuccessful status.
+    fn fetch(&self, endpoint: &PollEndpoint) -> anyhow::Result<Bytes>;
+}
+
+/// The unit of timestamp fields in polled responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimestampUnit {
+    Seconds,
+    #[default]
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    /// An RFC 3339 formatted string.
+    Rfc3339,
+}
+
+impl TimestampUnit {
+    /// Parses a timestamp `value` in this unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a valid timestamp in this unit.
+    pub fn parse(self, value: &Value) -> anyhow::Result<UnixNanos> {
+        if self == Self::Rfc3339 {
+            let Some(text) = value.as_str() else {
+                anyhow::bail!("Expected an RFC 3339 string timestamp, was {value}");
+            };
+            let datetime = DateTime::parse_from_rfc3339(text)?;
+            let nanos = datetime
+                .timestamp_nanos_opt()
+                .ok_or_else(|| anyhow::anyhow!("Timestamp out of range: {text}"))?;
+            return Ok(UnixNanos::from(u64::try_from(nanos)?));
+        }
+
+        let number = f64::from_str(&value_to_string(value)?)?;
+        anyhow::ensure!(
+            number.is_finite() && number >= 0.0,
+            "Invalid timestamp {number}"
+        );
+        let scale = match self {
+            Self::Seconds => 1e9,
+            Self::Milliseconds => 1e6,
+            Self::Microseconds => 1e3,
+            Self::Nanoseconds | Self::Rfc3339 => 1.0,
+        };
+        Ok(UnixNanos::from((number * scale).round() as u64))
+    }
+}
+
+/// The data a polled record is mapped to, with the JSON pointers of its fields (relative to
+/// the record).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MappingTarget {
+    /// The whole record as JSON encoded [`CustomData`] of the `data_type`.
+    Custom { data_type: DataType },
+    /// A [`QuoteTick`].
+    Quote {
+        instrument_id: InstrumentId,
+        bid_price: String,
+        ask_price: String,
+        bid_size: String,
+        ask_size: String,
+    },
+    /// A [`TradeTick`], with optional `BUY`/`SELL` aggressor side and trade ID fields.
+    Trade {
+        instrument_id: InstrumentId,
+        price: String,
+        size: String,
+        aggressor_side: Option<String>,
+        trade_id: Option<String>,
+    },
+    /// A [`MarkPriceUpdate`].
+    MarkPrice {
+        instrument_id: InstrumentId,
+        value: String,
+    },
+}
+
+/// Specifies how a polled JSON response is mapped to data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseMapping {
+    /// The JSON pointer of the record (or array of records) in the response (the root when
+    /// `None`).
+    pub records: Option<String>,
+    /// The target data for each record.
+    pub target: MappingTarget,
+    /// The JSON pointer of the event timestamp in each record (the poll time when `None`).
+    pub ts_event: Option<String>,
+    /// The unit of the event timestamp.
+    pub ts_unit: TimestampUnit,
+}
+
+/// A REST endpoint polled on a fixed interval.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PollEndpoint {
+    /// The unique name of the endpoint (also used as its timer name).
+    pub name: String,
+    pub url: String,
+    pub headers: IndexMap<String, String>,
+    /// The polling interval in nanoseconds.
+    pub interval_ns: u64,
+    pub mapping: ResponseMapping,
+}
+
+/// Data mapped from a polled response.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PolledData {
+    Custom(CustomData),
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    MarkPrice(MarkPriceUpdate),
+}
+
+impl PolledData {
+    /// Returns the event timestamp of the data.
+    #[must_use]
+    pub const fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Custom(data) => data.ts_event,
+            Self::Quote(quote) => quote.ts_event,
+            Self::Trade(trade) => trade.ts_event,
+            Self::MarkPrice(mark) => mark.ts_event,
+        }
+    }
+
+    /// Publishes the data on its standard topic.
+    pub fn publish(&self) {
+        match self {
+            Self::Custom(data) => msgbus::publish_any(get_custom_topic(&data.data_type), data),
+            Self::Quote(quote) => {
+                msgbus::publish_quote(get_quotes_topic(quote.instrument_id), quote)
+            }
+            Self::Trade(trade) => {
+                msgbus::publish_trade(get_trades_topic(trade.instrument_id), trade)
+            }
+            Self::MarkPrice(mark) => {
+                msgbus::publish_mark_price(get_mark_price_topic(mark.instrument_id), mark);
+            }
+        }
+    }
+}
+
+/// Maps a JSON response `body` to data according to the `mapping`.
+///
+/// # Errors
+///
+/// Returns an error if the body is not valid JSON, or a mapped field is missing or invalid.
+pub fn map_response(
+    mapping: &ResponseMapping,
+    body: &[u8],
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<PolledData>> {
+    let root: Value = serde_json::from_slice(body)?;
+    let records = match &mapping.records {
+        Some(pointer) => field(&root, pointer)?,
+        None => &root,
+    };
+    let records: Vec<&Value> = match records {
+        Value::Array(items) => items.iter().collect(),
+        record => vec![record],
+    };
+
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| map_record(mapping, record, index, ts_init))
+        .collect()
+}
+
+fn map_record(
+    mapping: &ResponseMapping,
+    record: &Value,
+    index: usize,
+    ts_init: UnixNanos,
+) -> anyhow::Result<PolledData> {
+    let ts_event = match &mapping.ts_event {
+        Some(pointer) => mapping.ts_unit.parse(field(record, pointer)?)?,
+        None => ts_init,
+    };
+
+    let data = match &mapping.target {
+        MappingTarget::Custom { data_type } => PolledData::Custom(CustomData::new(
+            data_type.clone(),
+            Bytes::from(serde_json::to_vec(record)?),
+            ts_event,
+            ts_init,
+        )),
+        MappingTarget::Quote {
+            instrument_id,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+        } => PolledData::Quote(QuoteTick::new_checked(
+            *instrument_id,
+            price_field(record, bid_price)?,
+            price_field(record, ask_price)?,
+            quantity_field(record, bid_size)?,
+            quantity_field(record, ask_size)?,
+            ts_event,
+            ts_init,
+        )?),
+        MappingTarget::Trade {
+            instrument_id,
+            price,
+            size,
+            aggressor_side,
+            trade_id,
+        } => {
+            let aggressor_side = match aggressor_side {
+                Some(pointer) => match value_to_string(field(record, pointer)?)?
+                    .to_ascii_uppercase()
+                    .as_str()
+                {
+                    "BUY" | "BUYER" => AggressorSide::Buyer,
+                    "SELL" | "SELLER" => AggressorSide::Seller,
+                    _ => AggressorSide::NoAggressor,
+                },
+                None => AggressorSide::NoAggressor,
+            };
+            let trade_id = match trade_id {
+                Some(pointer) => TradeId::new_checked(value_to_string(field(record, pointer)?)?)?,
+                None => TradeId::new(format!("{}-{index}", ts_event.as_u64())),
+            };
+            PolledData::Trade(TradeTick::new_checked(
+                *instrument_id,
+                price_field(record, price)?,
+                quantity_field(record, size)?,
+                aggressor_side,
+                trade_id,
+                ts_event,
+                ts_init,
+            )?)
+        }
+        MappingTarget::MarkPrice {
+            instrument_id,
+            value,
+        } => PolledData::MarkPrice(MarkPriceUpdate::new(
+            *instrument_id,
+            price_field(record, value)?,
+            ts_event,
+            ts_init,
+        )),
+    };
+    Ok(data)
+}
+
+fn field<'a>(value: &'a Value, pointer: &str) -> anyhow::Result<&'a Value> {
+    value
+        .pointer(pointer)
+        .ok_or_else(|| anyhow::anyhow!("Field '{pointer}' not found in response"))
+}
+
+fn value_to_string(value: &Value) -> anyhow::Result<String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(number) => Ok(number.to_string()),
+        other => anyhow::bail!("Expected a string or number, was {other}"),
+    }
+}
+
+fn price_field(record: &Value, pointer: &str) -> anyhow::Result<Price> {
+    Price::from_str(&value_to_string(field(record, pointer)?)?).map_err(anyhow::Error::msg)
+}
+
+fn quantity_field(record: &Value, pointer: &str) -> anyhow::Result<Quantity> {
+    Quantity::from_str(&value_to_string(field(record, pointer)?)?).map_err(anyhow::Error::msg)
+}
+
+/// Polling statistics for an endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EndpointStats {
+    pub polls: u64,
+    pub records_published: u64,
+    /// The number of records dropped as not newer than the last published record.
+    pub records_stale: u64,
+    pub errors: u64,
+    pub last_error: Option<String>,
+    pub last_ts_event: Option<UnixNanos>,
+}
+
+/// A data client which polls REST endpoints and publishes the mapped data.
+///
+/// When an endpoint maps an event timestamp, records not newer than the last published record
+/// for the endpoint are dropped, so overlapping responses are not republished.
+#[derive(Clone)]
+pub struct RestPollingDataClient {
+    client_id: ClientId,
+    venue: Option<Venue>,
+    clock: Rc<RefCell<dyn Clock>>,
+    fetcher: Rc<dyn RestFetcher>,
+    endpoints: Rc<IndexMap<String, PollEndpoint>>,
+    stats: Rc<RefCell<AHashMap<String, EndpointStats>>>,
+    is_running: bool,
+}
+
+impl Debug for RestPollingDataClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(RestPollingDataClient))
+            .field("client_id", &self.client_id)
+            .field("venue", &self.venue)
+            .field("endpoints", &self.endpoints.keys().collect::<Vec<_>>())
+            .field("is_running", &self.is_running)
+            .finish()
+    }
+}
+
+impl RestPollingDataClient {
+    /// Creates a new [`RestPollingDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an endpoint name is duplicated or an interval is zero.
+    pub fn new(
+        client_id: ClientId,
+        venue: Option<Venue>,
+        clock: Rc<RefCell<dyn Clock>>,
+        fetcher: Rc<dyn RestFetcher>,
+        endpoints: Vec<PollEndpoint>,
+    ) -> anyhow::Result<Self> {
+        let mut by_name = IndexMap::with_capacity(endpoints.len());
+        for endpoint in endpoints {
+            anyhow::ensure!(
+                endpoint.interval_ns > 0,
+                "Endpoint '{}' must have a positive interval",
+                endpoint.name
+            );
+            let name = endpoint.name.clone();
+            anyhow::ensure!(
+                by_name.insert(name.clone(), endpoint).is_none(),
+                "Duplicate endpoint name '{name}'"
+            );
+        }
+
+        Ok(Self {
+            client_id,
+            venue,
+            clock,
+            fetcher,
+            endpoints: Rc::new(by_name),
+            stats: Rc::new(RefCell::new(AHashMap::new())),
+            is_running: false,
+        })
+    }
+
+    /// Returns the polling statistics for the endpoint `name`.
+    #[must_use]
+    pub fn stats(&self, name: &str) -> Option<EndpointStats> {
+        self.stats.borrow().get(name).cloned()
+    }
+
+    /// Polls the endpoint `name` once and publishes the mapped data, returning the number of
+    /// records published.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint is unknown, or the fetch or mapping fails.
+    pub fn poll(&self, name: &str, ts_init: UnixNanos) -> anyhow::Result<usize> {
+        let endpoint = self
+            .endpoints
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown endpoint '{name}'"))?;
+
+        let result = self
+            .fetcher
+            .fetch(endpoint)
+            .and_then(|body| map_response(&endpoint.mapping, &body, ts_init));
+
+        // Publish outside the stats borrow, as handlers may query the client
+        let fresh = {
+            let mut stats = self.stats.borrow_mut();
+            let stats = stats.entry(name.to_string()).or_default();
+            stats.polls += 1;
+            let records = match result {
+                Ok(records) => records,
+                Err(e) => {
+                    stats.errors += 1;
+                    stats.last_error = Some(e.to_string());
+                    return Err(e);
+                }
+            };
+
+            let dedupe = endpoint.mapping.ts_event.is_some();
+            let mut fresh = Vec::with_capacity(records.len());
+            for data in records {
+                let ts_event = data.ts_event();
+                if dedupe && stats.last_ts_event.is_some_and(|last| ts_event <= last) {
+                    stats.records_stale += 1;
+                    continue;
+                }
+                stats.last_ts_event =
+                    Some(stats.last_ts_event.map_or(ts_event, |l| l.max(ts_event)));
+                fresh.push(data);
+            }
+            stats.records_published += fresh.len() as u64;
+            fresh
+        };
+
+        let published = fresh.len();
+        for data in &fresh {
+            data.publish();
+        }
+
+        Ok(published)
+    }
+
+    fn start_timers(&self) -> anyhow::Result<()> {
+        let mut clock = self.clock.borrow_mut();
+        for endpoint in self.endpoints.values() {
+            let client = self.clone();
+            let name = endpoint.name.clone();
+            let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+                if let Err(e) = client.poll(&name, event.ts_event) {
+                    log::error!("Polling endpoint '{name}' failed: {e}");
+                }
+            });
+            clock.set_timer_ns(
+                &endpoint.name,
+                endpoint.interval_ns,
+                None,
+                None,
+                Some(TimeEventCallback::from(callback)),
+                None,
+                Some(true),
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn cancel_timers(&self) {
+        let mut clock = self.clock.borrow_mut();
+        for name in self.endpoints.keys() {
+            clock.cancel_timer(name);
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl DataClient for RestPollingDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        self.venue
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        if self.is_running {
+            return Ok(());
+        }
+        self.start_timers()?;
+        self.is_running = true;
+        log::info!(
+            "Polling {} endpoint(s) for {}",
+            self.endpoints.len(),
+            self.client_id
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        self.cancel_timers();
+        self.is_running = false;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        self.stop()?;
+        self.stats.borrow_mut().clear();
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        self.stop()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_running
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    struct StaticFetcher(RefCell<Vec<&'static str>>);
+
+    impl RestFetcher for StaticFetcher {
+        fn fetch(&self, endpoint: &PollEndpoint) -> anyhow::Result<Bytes> {
+            let mut bodies = self.0.borrow_mut();
+            anyhow::ensure!(!bodies.is_empty(), "No response for {}", endpoint.url);
+            Ok(Bytes::from_static(bodies.remove(0).as_bytes()))
+        }
+    }
+
+    fn quote_mapping() -> ResponseMapping {
+        ResponseMapping {
+            records: Some("/data".to_string()),
+            target: MappingTarget::Quote {
+                instrument_id: InstrumentId::from("BTCUSD.EXAMPLE"),
+                bid_price: "/bid".to_string(),
+                ask_price: "/ask".to_string(),
+                bid_size: "/bid_qty".to_string(),
+                ask_size: "/ask_qty".to_string(),
+            },
+            ts_event: Some("/time".to_string()),
+            ts_unit: TimestampUnit::Milliseconds,
+        }
+    }
+
+    #[rstest]
+    fn test_map_quotes() {
+        let body = br#"{"data": [
+            {"bid": "100.5", "ask": "101.0", "bid_qty": 2, "ask_qty": "3", "time": 1000},
+            {"bid": 100.6, "ask": 101.1, "bid_qty": 1, "ask_qty": 1, "time": 2000}
+        ]}"#;
+
+        let data = map_response(&quote_mapping(), body, UnixNanos::from(5)).unwrap();
+
+        assert_eq!(data.len(), 2);
+        let PolledData::Quote(quote) = &data[0] else {
+            panic!("expected a quote");
+        };
+        assert_eq!(quote.bid_price, Price::from("100.5"));
+        assert_eq!(quote.ask_size, Quantity::from(3));
+        assert_eq!(quote.ts_event, UnixNanos::from(1_000_000_000));
+        assert_eq!(data[1].ts_event(), UnixNanos::from(2_000_000_000));
+    }
+
+    #[rstest]
+    fn test_map_custom_single_record() {
+        let mapping = ResponseMapping {
+            records: None,
+            target: MappingTarget::Custom {
+                data_type: DataType::new("FearGreedIndex", None),
+            },
+            ts_event: Some("/updated".to_string()),
+            ts_unit: TimestampUnit::Rfc3339,
+        };
+        let body = br#"{"value": 72, "updated": "2024-01-01T00:00:01Z"}"#;
+
+        let data = map_response(&mapping, body, UnixNanos::from(5)).unwrap();
+
+        let PolledData::Custom(custom) = &data[0] else {
+            panic!("expected custom data");
+        };
+        let value: Value = serde_json::from_slice(&custom.value).unwrap();
+        assert_eq!(value["value"], 72);
+        assert_eq!(custom.ts_event, UnixNanos::from(1_704_067_201_000_000_000));
+    }
+
+    #[rstest]
+    fn test_map_missing_field_errors() {
+        let body = br#"{"data": [{"bid": "100.5"}]}"#;
+        assert!(map_response(&quote_mapping(), body, UnixNanos::default()).is_err());
+    }
+
+    #[rstest]
+    fn test_poll_drops_stale_records_and_counts_errors() {
+        let fetcher = Rc::new(StaticFetcher(RefCell::new(vec![
+            r#"{"data": [{"bid": "1.0", "ask": "1.1", "bid_qty": 1, "ask_qty": 1, "time": 1}]}"#,
+            r#"{"data": [{"bid": "1.0", "ask": "1.1", "bid_qty": 1, "ask_qty": 1, "time": 1},
+                         {"bid": "1.0", "ask": "1.2", "bid_qty": 1, "ask_qty": 1, "time": 2}]}"#,
+        ])));
+        let endpoint = PollEndpoint {
+            name: "quotes".to_string(),
+            url: "https://example.com/quotes".to_string(),
+            headers: IndexMap::new(),
+            interval_ns: 1_000_000_000,
+            mapping: quote_mapping(),
+        };
+        let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+        let client = RestPollingDataClient::new(
+            ClientId::from("POLLER"),
+            None,
+            clock,
+            fetcher,
+            vec![endpoint],
+        )
+        .unwrap();
+
+        assert_eq!(client.poll("quotes", UnixNanos::default()).unwrap(), 1);
+        assert_eq!(client.poll("quotes", UnixNanos::default()).unwrap(), 1);
+        assert!(client.poll("quotes", UnixNanos::default()).is_err());
+        assert!(client.poll("unknown", UnixNanos::default()).is_err());
+
+        let stats = client.stats("quotes").unwrap();
+        assert_eq!(stats.polls, 3);
+        assert_eq!(stats.records_published, 2);
+        assert_eq!(stats.records_stale, 1);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.last_ts_event, Some(UnixNanos::from(2_000_000)));
+    }
+
+    #[rstest]
+    fn test_duplicate_endpoint_names_error() {
+        let endpoint = PollEndpoint {
+            name: "quotes".to_string(),
+            url: "https://example.com/quotes".to_string(),
+            headers: IndexMap::new(),
+            interval_ns: 1,
+            mapping: quote_mapping(),
+        };
+        let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+        let fetcher = Rc::new(StaticFetcher(RefCell::new(Vec::new())));
+
+        assert!(
+            RestPollingDataClient::new(
+                ClientId::from("POLLER"),
+                None,
+                clock,
+                fetcher,
+                vec![endpoint.clone(), endpoint],
+            )
+            .is_err()
+        );
+    }
+}
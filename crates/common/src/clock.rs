@@ -24,10 +24,12 @@ use nautilus_core::{
     correctness::{check_positive_u64, check_predicate_true, check_valid_string_utf8},
     formatting::Separable,
 };
+use nautilus_model::identifiers::ActorId;
 use ustr::Ustr;
 
 use crate::timer::{
-    TestTimer, TimeEvent, TimeEventCallback, TimeEventHandler, create_valid_interval,
+    TestTimer, TimeEvent, TimeEventCallback, TimeEventCoalescePolicy, TimeEventCoalescer,
+    TimeEventHandler, create_valid_interval,
 };
 
 /// Represents a type of clock.
@@ -91,8 +93,9 @@ pub trait Clock: Debug + Any {
         alert_time: DateTime<Utc>,
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
-        self.set_time_alert_ns(name, alert_time.into(), callback, allow_past)
+        self.set_time_alert_ns(name, alert_time.into(), callback, allow_past, coalesce)
     }
 
     /// Set a timer to alert at the specified time.
@@ -111,6 +114,11 @@ pub trait Clock: Debug + Any {
     /// - `callback`: Some, then callback handles the time event.
     /// - `callback`: None, then the clock's default time event callback is used.
     ///
+    /// # Coalescing
+    ///
+    /// `coalesce` sets how a burst of pending events from this timer is delivered when they are
+    /// drained together (see [`TimeEventCoalescePolicy`]); `None` delivers every event.
+    ///
     /// # Errors
     ///
     /// Returns an error if `name` is invalid, `alert_time_ns` is earlier than now when not allowed,
@@ -122,6 +130,7 @@ pub trait Clock: Debug + Any {
         alert_time_ns: UnixNanos,
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()>;
 
     /// Set a timer to fire time events at every interval between start and stop time.
@@ -149,6 +158,7 @@ pub trait Clock: Debug + Any {
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
         fire_immediately: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
         self.set_timer_ns(
             name,
@@ -158,6 +168,7 @@ pub trait Clock: Debug + Any {
             callback,
             allow_past,
             fire_immediately,
+            coalesce,
         )
     }
 
@@ -184,6 +195,11 @@ pub trait Clock: Debug + Any {
     /// - `callback`: Some, then callback handles the time event.
     /// - `callback`: None, then the clock's default time event callback is used.
     ///
+    /// # Coalescing
+    ///
+    /// `coalesce` sets how a burst of pending events from this timer is delivered when they are
+    /// drained together (see [`TimeEventCoalescePolicy`]); `None` delivers every event.
+    ///
     /// # Errors
     ///
     /// Returns an error if `name` is invalid, `interval_ns` is not positive,
@@ -198,6 +214,7 @@ pub trait Clock: Debug + Any {
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
         fire_immediately: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()>;
 
     /// Returns the time interval in which the timer `name` is triggered.
@@ -408,7 +425,11 @@ pub struct TestClock {
     time: AtomicTime,
     // Use btree map to ensure stable ordering when scanning for timers in `advance_time`
     timers: BTreeMap<Ustr, TestTimer>,
+    // Registration sequence per timer, used to break ties between events at the same timestamp
+    registrations: AHashMap<Ustr, u64>,
+    next_registration: u64,
     callbacks: CallbackRegistry,
+    coalescer: TimeEventCoalescer,
 }
 
 impl TestClock {
@@ -418,7 +439,10 @@ impl TestClock {
         Self {
             time: AtomicTime::new(false, UnixNanos::default()),
             timers: BTreeMap::new(),
+            registrations: AHashMap::new(),
+            next_registration: 0,
             callbacks: CallbackRegistry::new(),
+            coalescer: TimeEventCoalescer::new(),
         }
     }
 
@@ -428,6 +452,21 @@ impl TestClock {
         &self.timers
     }
 
+    /// Returns the coalescer applying the timers' [`TimeEventCoalescePolicy`]s.
+    #[must_use]
+    pub const fn coalescer(&self) -> &TimeEventCoalescer {
+        &self.coalescer
+    }
+
+    /// Returns the registration sequence number of the timer with the given `name`.
+    ///
+    /// Sequence numbers increase with each timer registration (including replacements), and
+    /// events sharing a timestamp are returned by [`TestClock::advance_time`] in this order.
+    #[must_use]
+    pub fn registration_seq(&self, name: &str) -> Option<u64> {
+        self.registrations.get(&Ustr::from(name)).copied()
+    }
+
     /// Advances the internal clock to the specified `to_time_ns` and optionally sets the clock to that time.
     ///
     /// This function ensures that the clock behaves in a non-decreasing manner. If `set_time` is `true`,
@@ -437,6 +476,10 @@ impl TestClock {
     /// The method processes active timers, advancing them to `to_time_ns`, and collects any `TimeEvent`
     /// objects that are triggered as a result. Only timers that are not expired are processed.
     ///
+    /// Events are ordered by `ts_event`, with events sharing a timestamp ordered by the
+    /// registration sequence of their timers, so the dispatch order is reproducible. The batch
+    /// is then coalesced per timer according to the policy each timer was set with.
+    ///
     /// # Warnings
     ///
     /// Logs a warning if >= 1,000,000 time events are allocated during advancement.
@@ -459,13 +502,20 @@ impl TestClock {
         }
 
         // Iterate and advance timers and collect events, only retain alive timers
-        let mut events: Vec<TimeEvent> = Vec::new();
-        self.timers.retain(|_, timer| {
+        let mut events: Vec<(u64, TimeEvent)> = Vec::new();
+        let mut expired: Vec<Ustr> = Vec::new();
+        self.timers.retain(|name, timer| {
+            let seq = self.registrations.get(name).copied().unwrap_or(u64::MAX);
             timer.advance(to_time_ns).for_each(|event| {
-                events.push(event);
+                events.push((seq, event));
             });
 
-            !timer.is_expired()
+            let alive = !timer.is_expired();
+            if !alive {
+                self.registrations.remove(name);
+                expired.push(*name);
+            }
+            alive
         });
 
         if events.len() >= WARN_TIME_EVENTS_THRESHOLD {
@@ -478,7 +528,15 @@ impl TestClock {
             );
         }
 
-        events.sort_by_key(|a| a.ts_event);
+        events.sort_by_key(|(seq, event)| (event.ts_event, *seq));
+        let events = self
+            .coalescer
+            .coalesce(events.into_iter().map(|(_, event)| event).collect());
+
+        // Expired timers keep their policy for their final batch only
+        for name in &expired {
+            self.coalescer.deregister(name);
+        }
         events
     }
 
@@ -499,6 +557,18 @@ impl TestClock {
             .collect()
     }
 
+    fn insert_timer(
+        &mut self,
+        name: Ustr,
+        timer: TestTimer,
+        coalesce: Option<TimeEventCoalescePolicy>,
+    ) {
+        self.timers.insert(name, timer);
+        self.registrations.insert(name, self.next_registration);
+        self.next_registration += 1;
+        self.coalescer.set_policy(name, coalesce);
+    }
+
     fn replace_existing_timer_if_needed(&mut self, name: &Ustr) {
         if self.timer_exists(name) {
             self.cancel_timer(name.as_str());
@@ -577,6 +647,7 @@ impl Clock for TestClock {
         alert_time_ns: UnixNanos,
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
         let ts_now = self.get_time_ns();
         let (name, alert_time_ns) =
@@ -604,7 +675,7 @@ impl Clock for TestClock {
             Some(alert_time_ns),
             fire_immediately,
         );
-        self.timers.insert(name, timer);
+        self.insert_timer(name, timer, coalesce);
 
         Ok(())
     }
@@ -618,6 +689,7 @@ impl Clock for TestClock {
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
         fire_immediately: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
         let ts_now = self.get_time_ns();
         let (name, start_time_ns, stop_time_ns, _allow_past, fire_immediately) =
@@ -651,7 +723,7 @@ impl Clock for TestClock {
             stop_time_ns,
             fire_immediately,
         );
-        self.timers.insert(name, timer);
+        self.insert_timer(name, timer, coalesce);
 
         Ok(())
     }
@@ -663,27 +735,60 @@ impl Clock for TestClock {
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
+        let name = Ustr::from(name);
+        self.registrations.remove(&name);
+        self.coalescer.deregister(&name);
+        let timer = self.timers.remove(&name);
         if let Some(mut timer) = timer {
             timer.cancel();
         }
     }
 
     fn cancel_timers(&mut self) {
-        for timer in &mut self.timers.values_mut() {
+        for (name, timer) in &mut self.timers {
             timer.cancel();
+            self.coalescer.deregister(name);
         }
 
         self.timers.clear();
+        self.registrations.clear();
     }
 
     fn reset(&mut self) {
         self.time = AtomicTime::new(false, UnixNanos::default());
         self.timers = BTreeMap::new();
+        self.registrations.clear();
+        self.next_registration = 0;
         self.callbacks.clear();
+        self.coalescer = TimeEventCoalescer::new();
     }
 }
 
+/// Merges time events from the clocks of several actors into one deterministic dispatch order.
+///
+/// Events are ordered by `ts_event`, then by actor ID, then by their position within the actor
+/// batch (as returned by [`TestClock::advance_time`], i.e. timer registration order). The result
+/// does not depend on the order in which the batches are supplied. Supply one batch per actor.
+#[must_use]
+pub fn merge_actor_time_events(
+    batches: Vec<(ActorId, Vec<TimeEvent>)>,
+) -> Vec<(ActorId, TimeEvent)> {
+    let mut merged: Vec<(ActorId, usize, TimeEvent)> = batches
+        .into_iter()
+        .flat_map(|(actor_id, events)| {
+            events
+                .into_iter()
+                .enumerate()
+                .map(move |(index, event)| (actor_id, index, event))
+        })
+        .collect();
+    merged.sort_by_key(|(actor_id, index, event)| (event.ts_event, *actor_id, *index));
+    merged
+        .into_iter()
+        .map(|(actor_id, _, event)| (actor_id, event))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -742,6 +847,7 @@ mod tests {
                 (*test_clock.timestamp_ns() + 1000).into(),
                 None,
                 None,
+                None,
             )
             .unwrap();
         assert_eq!(test_clock.timer_count(), 1);
@@ -752,7 +858,7 @@ mod tests {
     fn test_timer_expiration(mut test_clock: TestClock) {
         let alert_time = (*test_clock.timestamp_ns() + 1000).into();
         test_clock
-            .set_time_alert_ns("test_timer", alert_time, None, None)
+            .set_time_alert_ns("test_timer", alert_time, None, None, None)
             .unwrap();
         let events = test_clock.advance_time(alert_time, true);
         assert_eq!(events.len(), 1);
@@ -767,6 +873,7 @@ mod tests {
                 (*test_clock.timestamp_ns() + 1000).into(),
                 None,
                 None,
+                None,
             )
             .unwrap();
         assert_eq!(test_clock.timer_count(), 1);
@@ -778,7 +885,16 @@ mod tests {
     fn test_time_advancement(mut test_clock: TestClock) {
         let start_time = test_clock.timestamp_ns();
         test_clock
-            .set_timer_ns("test_timer", 1000, Some(start_time), None, None, None, None)
+            .set_timer_ns(
+                "test_timer",
+                1000,
+                Some(start_time),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
             .unwrap();
         let events = test_clock.advance_time(UnixNanos::from(*start_time + 2500), true);
         assert_eq!(events.len(), 2);
@@ -786,6 +902,46 @@ mod tests {
         assert_eq!(*events[1].ts_event, *start_time + 2000);
     }
 
+    #[rstest]
+    fn test_advance_time_coalesces_per_timer_policy(mut test_clock: TestClock) {
+        let start_time = test_clock.timestamp_ns();
+        for (name, coalesce) in [
+            ("latest", Some(TimeEventCoalescePolicy::FireLatest)),
+            ("all", None),
+        ] {
+            test_clock
+                .set_timer_ns(
+                    name,
+                    1000,
+                    Some(start_time),
+                    None,
+                    None,
+                    None,
+                    None,
+                    coalesce,
+                )
+                .unwrap();
+        }
+
+        let events = test_clock.advance_time(UnixNanos::from(*start_time + 3500), true);
+
+        let latest: Vec<u64> = events
+            .iter()
+            .filter(|event| event.name.as_str() == "latest")
+            .map(|event| event.ts_event.as_u64())
+            .collect();
+        assert_eq!(latest, vec![*start_time + 3000]);
+        assert_eq!(events.len(), 4);
+        assert_eq!(test_clock.coalescer().dropped_count(), 2);
+
+        // Cancelling the timer drops its policy
+        test_clock.cancel_timer("latest");
+        assert_eq!(
+            test_clock.coalescer().policy(&Ustr::from("latest")),
+            TimeEventCoalescePolicy::FireAll
+        );
+    }
+
     #[rstest]
     fn test_default_and_custom_callbacks() {
         let mut clock = TestClock::new();
@@ -802,6 +958,7 @@ mod tests {
                 (*clock.timestamp_ns() + 1000).into(),
                 None,
                 None,
+                None,
             )
             .unwrap();
         clock
@@ -810,6 +967,7 @@ mod tests {
                 (*clock.timestamp_ns() + 1000).into(),
                 Some(TimeEventCallback::from(custom_callback)),
                 None,
+                None,
             )
             .unwrap();
 
@@ -843,6 +1001,7 @@ mod tests {
                 (*clock.timestamp_ns() + 1000).into(),
                 Some(TimeEventCallback::from(callback)),
                 None,
+                None,
             )
             .unwrap();
 
@@ -860,10 +1019,28 @@ mod tests {
     fn test_multiple_timers(mut test_clock: TestClock) {
         let start_time = test_clock.timestamp_ns();
         test_clock
-            .set_timer_ns("timer1", 1000, Some(start_time), None, None, None, None)
+            .set_timer_ns(
+                "timer1",
+                1000,
+                Some(start_time),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
             .unwrap();
         test_clock
-            .set_timer_ns("timer2", 2000, Some(start_time), None, None, None, None)
+            .set_timer_ns(
+                "timer2",
+                2000,
+                Some(start_time),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
             .unwrap();
         let events = test_clock.advance_time(UnixNanos::from(*start_time + 2000), true);
         assert_eq!(events.len(), 3);
@@ -880,7 +1057,7 @@ mod tests {
 
         // With allow_past=true (default), should adjust to current time and succeed
         test_clock
-            .set_time_alert_ns("past_timer", past_time, None, Some(true))
+            .set_time_alert_ns("past_timer", past_time, None, Some(true), None)
             .unwrap();
 
         // Verify timer was created with adjusted time
@@ -899,7 +1076,7 @@ mod tests {
         let past_time = current_time - 1000;
 
         // With allow_past=false, should fail for past times
-        let result = test_clock.set_time_alert_ns("past_timer", past_time, None, Some(false));
+        let result = test_clock.set_time_alert_ns("past_timer", past_time, None, Some(false), None);
 
         // Verify the operation failed with appropriate error
         assert!(result.is_err());
@@ -926,6 +1103,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         // Verify the operation failed with appropriate error
@@ -950,6 +1128,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -977,6 +1156,7 @@ mod tests {
                 None,
                 None,
                 Some(false),
+                None,
             )
             .unwrap();
 
@@ -1004,6 +1184,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1028,6 +1209,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -1056,6 +1238,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -1069,6 +1252,7 @@ mod tests {
                 None,
                 None,
                 Some(false),
+                None,
             )
             .unwrap();
 
@@ -1100,6 +1284,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1112,6 +1297,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         assert!(result.is_ok());
@@ -1129,8 +1315,16 @@ mod tests {
         let start_time = test_clock.timestamp_ns();
 
         // Attempt to set timer with zero interval should fail
-        let result =
-            test_clock.set_timer_ns("zero_interval", 0, Some(start_time), None, None, None, None);
+        let result = test_clock.set_timer_ns(
+            "zero_interval",
+            0,
+            Some(start_time),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
 
         assert!(result.is_err());
         assert_eq!(test_clock.timer_count(), 0);
@@ -1141,7 +1335,8 @@ mod tests {
         let start_time = test_clock.timestamp_ns();
 
         // Attempt to set timer with empty name should fail
-        let result = test_clock.set_timer_ns("", 1000, Some(start_time), None, None, None, None);
+        let result =
+            test_clock.set_timer_ns("", 1000, Some(start_time), None, None, None, None, None);
 
         assert!(result.is_err());
         assert_eq!(test_clock.timer_count(), 0);
@@ -1158,6 +1353,7 @@ mod tests {
                 (*test_clock.timestamp_ns() + 1_000).into(),
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1177,6 +1373,7 @@ mod tests {
             None,
             Some(false),
             None,
+            None,
         );
 
         let err = result.expect_err("expected stop time validation error");
@@ -1197,6 +1394,7 @@ mod tests {
             None,
             Some(false),
             None,
+            None,
         );
 
         assert!(result.is_ok());
@@ -1217,6 +1415,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -1242,6 +1441,7 @@ mod tests {
                 None,
                 None,
                 Some(false),
+                None,
             )
             .unwrap();
 
@@ -1272,6 +1472,7 @@ mod tests {
             None,
             Some(false), // allow_past = false
             Some(false), // fire_immediately = false
+            None,
         );
 
         // Should succeed because next event time (100_000 + 1000 = 101_000) > current time (100_500)
@@ -1301,6 +1502,7 @@ mod tests {
             None,
             Some(false), // allow_past = false
             Some(false), // fire_immediately = false
+            None,
         );
 
         // Should fail because next event time (101_000) < current time (102_000)
@@ -1330,6 +1532,7 @@ mod tests {
             None,
             Some(false), // allow_past = false
             Some(true),  // fire_immediately = true
+            None,
         );
 
         // Should fail because next event time (100_000) < current time (100_500)
@@ -1355,6 +1558,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1374,13 +1578,13 @@ mod tests {
     fn test_cancel_all_timers(mut test_clock: TestClock) {
         // Create multiple timers
         test_clock
-            .set_timer_ns("timer1", 1000, None, None, None, None, None)
+            .set_timer_ns("timer1", 1000, None, None, None, None, None, None)
             .unwrap();
         test_clock
-            .set_timer_ns("timer2", 1500, None, None, None, None, None)
+            .set_timer_ns("timer2", 1500, None, None, None, None, None, None)
             .unwrap();
         test_clock
-            .set_timer_ns("timer3", 2000, None, None, None, None, None)
+            .set_timer_ns("timer3", 2000, None, None, None, None, None, None)
             .unwrap();
 
         assert_eq!(test_clock.timer_count(), 3);
@@ -1398,7 +1602,7 @@ mod tests {
     #[rstest]
     fn test_clock_reset_clears_timers(mut test_clock: TestClock) {
         test_clock
-            .set_timer_ns("reset_test", 1000, None, None, None, None, None)
+            .set_timer_ns("reset_test", 1000, None, None, None, None, None, None)
             .unwrap();
 
         assert_eq!(test_clock.timer_count(), 1);
@@ -1417,7 +1621,7 @@ mod tests {
 
         // Test the default implementation that delegates to set_time_alert_ns
         test_clock
-            .set_time_alert("alert_test", alert_time, None, None)
+            .set_time_alert("alert_test", alert_time, None, None, None)
             .unwrap();
 
         assert_eq!(test_clock.timer_count(), 1);
@@ -1455,6 +1659,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1491,6 +1696,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1525,6 +1731,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -1546,7 +1753,7 @@ mod tests {
 
         // Set time alert for exactly the current time
         test_clock
-            .set_time_alert_ns("alert_at_current_time", current_time, None, None)
+            .set_time_alert_ns("alert_at_current_time", current_time, None, None, None)
             .unwrap();
 
         assert_eq!(test_clock.timer_count(), 1);
@@ -1565,7 +1772,7 @@ mod tests {
         let start = test_clock.timestamp_ns();
 
         test_clock
-            .set_time_alert_ns("timer", UnixNanos::from(*start + 1000), None, None)
+            .set_time_alert_ns("timer", UnixNanos::from(*start + 1000), None, None, None)
             .unwrap();
         assert_eq!(test_clock.timer_count(), 1);
 
@@ -1573,7 +1780,7 @@ mod tests {
         assert_eq!(test_clock.timer_count(), 0);
 
         test_clock
-            .set_time_alert_ns("timer", UnixNanos::from(*start + 2000), None, None)
+            .set_time_alert_ns("timer", UnixNanos::from(*start + 2000), None, None, None)
             .unwrap();
         assert_eq!(test_clock.timer_count(), 1);
 
@@ -1591,7 +1798,7 @@ mod tests {
 
         for i in 0..5 {
             test_clock
-                .set_time_alert_ns(&format!("timer_{i}"), fire_time, None, None)
+                .set_time_alert_ns(&format!("timer_{i}"), fire_time, None, None, None)
                 .unwrap();
         }
         assert_eq!(test_clock.timer_count(), 5);
@@ -1610,13 +1817,13 @@ mod tests {
         let start = clock.timestamp_ns();
 
         clock
-            .set_time_alert_ns("third", UnixNanos::from(*start + 300), None, None)
+            .set_time_alert_ns("third", UnixNanos::from(*start + 300), None, None, None)
             .unwrap();
         clock
-            .set_time_alert_ns("first", UnixNanos::from(*start + 100), None, None)
+            .set_time_alert_ns("first", UnixNanos::from(*start + 100), None, None, None)
             .unwrap();
         clock
-            .set_time_alert_ns("second", UnixNanos::from(*start + 200), None, None)
+            .set_time_alert_ns("second", UnixNanos::from(*start + 200), None, None, None)
             .unwrap();
 
         let events = clock.advance_time(UnixNanos::from(*start + 400), true);
@@ -1640,6 +1847,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -1653,7 +1861,7 @@ mod tests {
         let start = test_clock.timestamp_ns();
 
         test_clock
-            .set_timer_ns("tiny", 1, Some(start), None, None, None, None)
+            .set_timer_ns("tiny", 1, Some(start), None, None, None, None, None)
             .unwrap();
 
         let events = test_clock.advance_time(UnixNanos::from(*start + 10), true);
@@ -1669,7 +1877,7 @@ mod tests {
         let fire_time = UnixNanos::from(*test_clock.timestamp_ns() + 1000);
 
         test_clock
-            .set_time_alert_ns("once", fire_time, None, None)
+            .set_time_alert_ns("once", fire_time, None, None, None)
             .unwrap();
 
         let events1 = test_clock.advance_time(fire_time, true);
@@ -1687,4 +1895,124 @@ mod tests {
         assert!(events.is_empty());
         assert_eq!(*test_clock.timestamp_ns(), *start + 1000);
     }
+
+    fn alert_names(clock: &mut TestClock, names: &[&str], alert_time: UnixNanos) -> Vec<String> {
+        for name in names {
+            clock
+                .set_time_alert_ns(name, alert_time, None, None, None)
+                .unwrap();
+        }
+        clock
+            .advance_time(alert_time, true)
+            .iter()
+            .map(|event| event.name.to_string())
+            .collect()
+    }
+
+    #[rstest]
+    fn test_same_timestamp_events_in_registration_order(mut test_clock: TestClock) {
+        let alert_time = UnixNanos::from(1_000);
+
+        let names = alert_names(&mut test_clock, &["zeta", "alpha", "mid"], alert_time);
+
+        assert_eq!(names, vec!["zeta", "alpha", "mid"]);
+    }
+
+    #[rstest]
+    fn test_replaced_timer_moves_to_back_of_registration_order(mut test_clock: TestClock) {
+        let alert_time = UnixNanos::from(1_000);
+        test_clock
+            .set_time_alert_ns("first", alert_time, None, None, None)
+            .unwrap();
+        test_clock
+            .set_time_alert_ns("second", alert_time, None, None, None)
+            .unwrap();
+        test_clock
+            .set_time_alert_ns("first", alert_time, None, None, None)
+            .unwrap();
+
+        let names: Vec<String> = test_clock
+            .advance_time(alert_time, true)
+            .iter()
+            .map(|event| event.name.to_string())
+            .collect();
+
+        assert_eq!(names, vec!["second", "first"]);
+        assert_eq!(test_clock.registration_seq("first"), None);
+    }
+
+    #[rstest]
+    fn test_periodic_timers_interleave_by_timestamp_then_registration(mut test_clock: TestClock) {
+        test_clock
+            .set_timer_ns(
+                "b_slow",
+                2_000,
+                Some(UnixNanos::default()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        test_clock
+            .set_timer_ns(
+                "a_fast",
+                1_000,
+                Some(UnixNanos::default()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let sequence: Vec<(u64, String)> = test_clock
+            .advance_time(UnixNanos::from(4_000), true)
+            .iter()
+            .map(|event| (event.ts_event.as_u64(), event.name.to_string()))
+            .collect();
+
+        assert_eq!(
+            sequence,
+            vec![
+                (1_000, "a_fast".to_string()),
+                (2_000, "b_slow".to_string()),
+                (2_000, "a_fast".to_string()),
+                (3_000, "a_fast".to_string()),
+                (4_000, "b_slow".to_string()),
+                (4_000, "a_fast".to_string()),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_merge_actor_time_events_is_reproducible() {
+        let alert_time = UnixNanos::from(500);
+        let run = |reverse: bool| {
+            let mut batches = Vec::new();
+            for (actor, names) in [("ACTOR-B", ["y", "x"]), ("ACTOR-A", ["q", "p"])] {
+                let mut clock = TestClock::new();
+                clock.register_default_handler(TestCallback::default().into());
+                for name in names {
+                    clock
+                        .set_time_alert_ns(name, alert_time, None, None, None)
+                        .unwrap();
+                }
+                batches.push((ActorId::from(actor), clock.advance_time(alert_time, true)));
+            }
+            if reverse {
+                batches.reverse();
+            }
+            merge_actor_time_events(batches)
+                .into_iter()
+                .map(|(actor_id, event)| format!("{actor_id}:{}", event.name))
+                .collect::<Vec<_>>()
+        };
+
+        let expected = vec!["ACTOR-A:q", "ACTOR-A:p", "ACTOR-B:y", "ACTOR-B:x"];
+        assert_eq!(run(false), expected);
+        assert_eq!(run(true), expected);
+    }
 }
@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock synchronization across nodes from externally measured offsets.
+//!
+//! PTP and NTP daemons measure how far the local clock is from a reference clock. The
+//! [`ClockSynchronizer`] ingests those measurements as [`ClockOffsetSample`]s, smooths them with
+//! an exponentially weighted moving average, and slews the correction applied to an
+//! [`AtomicTime`] by at most a configured step per sample, so `ts_init` values stamped on
+//! different nodes are comparable without sudden jumps.
+//!
+//! When the residual error between a measured offset and the correction currently applied exceeds
+//! the alert threshold, a [`ClockCorrectionEvent`] is published on the clock sync topic.
+
+use std::fmt::Display;
+
+use nautilus_core::{UnixNanos, time::AtomicTime};
+use serde::{Deserialize, Serialize};
+
+use crate::msgbus::{self, MStr, Topic};
+
+/// Returns the topic clock correction events are published on.
+#[must_use]
+pub fn get_clock_sync_topic() -> MStr<Topic> {
+    "events.clock.sync".into()
+}
+
+/// The protocol an offset measurement came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum::Display, Serialize, Deserialize)]
+#[strum(serialize_all = "UPPERCASE")]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ClockSyncSource {
+    Ptp,
+    Ntp,
+}
+
+/// An externally measured clock offset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockOffsetSample {
+    pub source: ClockSyncSource,
+    /// The reference time minus the local time (nanoseconds): positive when the local clock is
+    /// behind the reference.
+    pub offset_ns: i64,
+    /// UNIX timestamp (nanoseconds) when the offset was measured.
+    pub ts_measured: UnixNanos,
+}
+
+/// Configuration for the [`ClockSynchronizer`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClockSyncConfig {
+    /// The EWMA weight of each new sample, in (0, 1].
+    pub alpha: f64,
+    /// The maximum change to the applied correction per sample (nanoseconds).
+    pub max_step_ns: i64,
+    /// Samples with an absolute offset above this are rejected as outliers (nanoseconds).
+    pub max_offset_ns: i64,
+    /// The residual error above which a correction event is published (nanoseconds).
+    pub alert_threshold_ns: i64,
+}
+
+impl Default for ClockSyncConfig {
+    /// Creates a new default [`ClockSyncConfig`] instance.
+    fn default() -> Self {
+        Self {
+            alpha: 0.2,
+            max_step_ns: 100_000,
+            max_offset_ns: 1_000_000_000,
+            alert_threshold_ns: 1_000_000,
+        }
+    }
+}
+
+/// The result of ingesting a [`ClockOffsetSample`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClockCorrectionEvent {
+    pub source: ClockSyncSource,
+    /// The measured offset (nanoseconds).
+    pub measured_offset_ns: i64,
+    /// The smoothed offset the correction is converging on (nanoseconds).
+    pub smoothed_offset_ns: i64,
+    /// The correction applied before the sample (nanoseconds).
+    pub previous_offset_ns: i64,
+    /// The correction applied after the sample (nanoseconds).
+    pub applied_offset_ns: i64,
+    pub ts_measured: UnixNanos,
+}
+
+impl ClockCorrectionEvent {
+    /// Returns the measured offset not covered by the correction before the sample (nanoseconds).
+    #[must_use]
+    pub const fn residual_ns(&self) -> i64 {
+        self.measured_offset_ns - self.previous_offset_ns
+    }
+}
+
+impl Display for ClockCorrectionEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(source={}, measured={}ns, applied={}ns, previous={}ns)",
+            stringify!(ClockCorrectionEvent),
+            self.source,
+            self.measured_offset_ns,
+            self.applied_offset_ns,
+            self.previous_offset_ns,
+        )
+    }
+}
+
+/// Applies smoothed external clock offsets to an [`AtomicTime`].
+#[derive(Debug)]
+pub struct ClockSynchronizer {
+    config: ClockSyncConfig,
+    clock: &'static AtomicTime,
+    smoothed_ns: Option<f64>,
+    last_measured: Option<UnixNanos>,
+    alerts: u64,
+}
+
+impl ClockSynchronizer {
+    /// Creates a new [`ClockSynchronizer`] instance correcting `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` is not in (0, 1] or any limit is not positive.
+    pub fn new(config: ClockSyncConfig, clock: &'static AtomicTime) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.alpha > 0.0 && config.alpha <= 1.0,
+            "`alpha` must be in (0, 1], was {}",
+            config.alpha
+        );
+        anyhow::ensure!(config.max_step_ns > 0, "`max_step_ns` must be positive");
+        anyhow::ensure!(config.max_offset_ns > 0, "`max_offset_ns` must be positive");
+        anyhow::ensure!(
+            config.alert_threshold_ns > 0,
+            "`alert_threshold_ns` must be positive"
+        );
+        Ok(Self {
+            config,
+            clock,
+            smoothed_ns: None,
+            last_measured: None,
+            alerts: 0,
+        })
+    }
+
+    /// Returns the correction currently applied to the clock (nanoseconds).
+    #[must_use]
+    pub fn applied_offset_ns(&self) -> i64 {
+        self.clock.offset_ns()
+    }
+
+    /// Returns the number of correction events published.
+    #[must_use]
+    pub const fn alerts(&self) -> u64 {
+        self.alerts
+    }
+
+    /// Ingests an offset `sample`, updating the clock's correction.
+    ///
+    /// Publishes the returned event on the clock sync topic when its residual exceeds the alert
+    /// threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sample is not newer than the last ingested sample, or its offset
+    /// exceeds `max_offset_ns`.
+    pub fn ingest(&mut self, sample: ClockOffsetSample) -> anyhow::Result<ClockCorrectionEvent> {
+        if let Some(last) = self.last_measured {
+            anyhow::ensure!(
+                sample.ts_measured > last,
+                "Clock offset sample at {} was not after the last sample at {last}",
+                sample.ts_measured
+            );
+        }
+        anyhow::ensure!(
+            sample.offset_ns.unsigned_abs() <= self.config.max_offset_ns.unsigned_abs(),
+            "Clock offset {}ns from {} exceeded the maximum {}ns",
+            sample.offset_ns,
+            sample.source,
+            self.config.max_offset_ns
+        );
+
+        let measured = sample.offset_ns as f64;
+        let smoothed = match self.smoothed_ns {
+            Some(previous) => previous + self.config.alpha * (measured - previous),
+            None => measured,
+        };
+        self.smoothed_ns = Some(smoothed);
+        self.last_measured = Some(sample.ts_measured);
+
+        let previous_offset_ns = self.clock.offset_ns();
+        let step = (smoothed.round() as i64 - previous_offset_ns)
+            .clamp(-self.config.max_step_ns, self.config.max_step_ns);
+        let applied_offset_ns = previous_offset_ns + step;
+        self.clock.set_offset_ns(applied_offset_ns);
+
+        let event = ClockCorrectionEvent {
+            source: sample.source,
+            measured_offset_ns: sample.offset_ns,
+            smoothed_offset_ns: smoothed.round() as i64,
+            previous_offset_ns,
+            applied_offset_ns,
+            ts_measured: sample.ts_measured,
+        };
+        if event.residual_ns().unsigned_abs() > self.config.alert_threshold_ns.unsigned_abs() {
+            self.alerts += 1;
+            log::warn!("Clock correction exceeded threshold: {event}");
+            msgbus::publish_any(get_clock_sync_topic(), &event);
+        }
+        Ok(event)
+    }
+
+    /// Clears the smoothing state and removes the correction from the clock.
+    pub fn reset(&mut self) {
+        self.smoothed_ns = None;
+        self.last_measured = None;
+        self.clock.set_offset_ns(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::{ShareableMessageHandler, subscribe_any};
+
+    fn clock() -> &'static AtomicTime {
+        Box::leak(Box::new(AtomicTime::new(true, UnixNanos::default())))
+    }
+
+    fn sample(offset_ns: i64, ts: u64) -> ClockOffsetSample {
+        ClockOffsetSample {
+            source: ClockSyncSource::Ptp,
+            offset_ns,
+            ts_measured: UnixNanos::from(ts),
+        }
+    }
+
+    #[rstest]
+    fn test_ingest_slews_towards_smoothed_offset() {
+        let config = ClockSyncConfig {
+            alpha: 0.5,
+            max_step_ns: 1_000,
+            ..Default::default()
+        };
+        let mut sync = ClockSynchronizer::new(config, clock()).unwrap();
+
+        let first = sync.ingest(sample(4_000, 1)).unwrap();
+        assert_eq!(first.smoothed_offset_ns, 4_000);
+        assert_eq!(first.applied_offset_ns, 1_000);
+
+        let second = sync.ingest(sample(2_000, 2)).unwrap();
+        assert_eq!(second.smoothed_offset_ns, 3_000);
+        assert_eq!(second.previous_offset_ns, 1_000);
+        assert_eq!(second.applied_offset_ns, 2_000);
+        assert_eq!(sync.applied_offset_ns(), 2_000);
+
+        sync.reset();
+        assert_eq!(sync.applied_offset_ns(), 0);
+    }
+
+    #[rstest]
+    fn test_ingest_rejects_stale_and_outlier_samples() {
+        let mut sync = ClockSynchronizer::new(ClockSyncConfig::default(), clock()).unwrap();
+        sync.ingest(sample(10, 5)).unwrap();
+
+        assert!(sync.ingest(sample(10, 5)).is_err());
+        assert!(sync.ingest(sample(2_000_000_000, 6)).is_err());
+        assert_eq!(sync.applied_offset_ns(), 10);
+    }
+
+    #[rstest]
+    fn test_ingest_publishes_when_residual_exceeds_threshold() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let sink = received.clone();
+        subscribe_any(
+            "events.clock.sync".into(),
+            ShareableMessageHandler::from_typed(move |event: &ClockCorrectionEvent| {
+                sink.borrow_mut().push(*event);
+            }),
+            None,
+        );
+
+        let config = ClockSyncConfig {
+            alert_threshold_ns: 500,
+            ..Default::default()
+        };
+        let mut sync = ClockSynchronizer::new(config, clock()).unwrap();
+        sync.ingest(sample(100, 1)).unwrap();
+        sync.ingest(sample(5_000, 2)).unwrap();
+
+        assert_eq!(sync.alerts(), 1);
+        assert_eq!(received.borrow().len(), 1);
+        assert_eq!(received.borrow()[0].measured_offset_ns, 5_000);
+    }
+
+    #[rstest]
+    fn test_new_validates_config() {
+        let config = ClockSyncConfig {
+            alpha: 0.0,
+            ..Default::default()
+        };
+        assert!(ClockSynchronizer::new(config, clock()).is_err());
+    }
+}
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Coalescing of rapid order modifications.
+//!
+//! Strategies which requote aggressively can emit many [`ModifyOrder`] commands per second for
+//! the same order. The [`ModifyCoalescer`] holds modifications for a short window, merging those
+//! for the same order so only the final intent is sent, and skips modifications which would not
+//! change the working order held in the cache.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{identifiers::ClientOrderId, orders::Order};
+
+use crate::{cache::Cache, messages::execution::ModifyOrder};
+
+#[derive(Clone, Debug)]
+struct PendingModify {
+    command: ModifyOrder,
+    first_ts: UnixNanos,
+    merged: usize,
+}
+
+/// Merges pending [`ModifyOrder`] commands per order within a time window.
+pub struct ModifyCoalescer {
+    cache: Rc<RefCell<Cache>>,
+    window_ns: u64,
+    pending: IndexMap<ClientOrderId, PendingModify>,
+    received_count: usize,
+    sent_count: usize,
+    coalesced_count: usize,
+    skipped_count: usize,
+}
+
+impl Debug for ModifyCoalescer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ModifyCoalescer))
+            .field("window_ns", &self.window_ns)
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+impl ModifyCoalescer {
+    /// Creates a new [`ModifyCoalescer`] instance.
+    ///
+    /// A `window_ns` of zero releases every (non no-op) modification on the next flush.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>, window_ns: u64) -> Self {
+        Self {
+            cache,
+            window_ns,
+            pending: IndexMap::new(),
+            received_count: 0,
+            sent_count: 0,
+            coalesced_count: 0,
+            skipped_count: 0,
+        }
+    }
+
+    /// Adds a modification, merging it with any pending modification for the same order.
+    ///
+    /// Fields set on `command` replace those of the pending modification, while unset fields keep
+    /// the pending values. The merged command takes the latest command ID and timestamp, but is
+    /// released relative to the first modification in the window.
+    pub fn push(&mut self, command: ModifyOrder) {
+        self.received_count += 1;
+
+        if let Some(pending) = self.pending.get_mut(&command.client_order_id) {
+            let merged = &mut pending.command;
+            merged.quantity = command.quantity.or(merged.quantity);
+            merged.price = command.price.or(merged.price);
+            merged.trigger_price = command.trigger_price.or(merged.trigger_price);
+            merged.venue_order_id = command.venue_order_id.or(merged.venue_order_id);
+            merged.params = command.params.or(merged.params.take());
+            merged.command_id = command.command_id;
+            merged.ts_init = command.ts_init;
+            pending.merged += 1;
+            self.coalesced_count += 1;
+        } else {
+            self.pending.insert(
+                command.client_order_id,
+                PendingModify {
+                    first_ts: command.ts_init,
+                    command,
+                    merged: 0,
+                },
+            );
+        }
+    }
+
+    /// Returns the modifications whose window has elapsed at `ts_now`, in arrival order.
+    ///
+    /// Modifications which would not change the working order are skipped.
+    pub fn flush(&mut self, ts_now: UnixNanos) -> Vec<ModifyOrder> {
+        let due: Vec<ClientOrderId> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| {
+                ts_now.as_u64() >= pending.first_ts.as_u64().saturating_add(self.window_ns)
+            })
+            .map(|(client_order_id, _)| *client_order_id)
+            .collect();
+
+        self.release(&due)
+    }
+
+    /// Returns all pending modifications regardless of their window.
+    pub fn flush_all(&mut self) -> Vec<ModifyOrder> {
+        let all: Vec<ClientOrderId> = self.pending.keys().copied().collect();
+        self.release(&all)
+    }
+
+    /// Discards any pending modification for `client_order_id`, e.g. when the order is canceled.
+    pub fn discard(&mut self, client_order_id: &ClientOrderId) -> Option<ModifyOrder> {
+        self.pending
+            .shift_remove(client_order_id)
+            .map(|pending| pending.command)
+    }
+
+    /// Returns the pending modification for `client_order_id`.
+    #[must_use]
+    pub fn pending(&self, client_order_id: &ClientOrderId) -> Option<&ModifyOrder> {
+        self.pending
+            .get(client_order_id)
+            .map(|pending| &pending.command)
+    }
+
+    /// Returns the number of orders with a pending modification.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the number of modifications received.
+    #[must_use]
+    pub const fn received_count(&self) -> usize {
+        self.received_count
+    }
+
+    /// Returns the number of modifications released.
+    #[must_use]
+    pub const fn sent_count(&self) -> usize {
+        self.sent_count
+    }
+
+    /// Returns the number of modifications merged into a pending modification.
+    #[must_use]
+    pub const fn coalesced_count(&self) -> usize {
+        self.coalesced_count
+    }
+
+    /// Returns the number of modifications skipped as no-ops.
+    #[must_use]
+    pub const fn skipped_count(&self) -> usize {
+        self.skipped_count
+    }
+
+    fn release(&mut self, client_order_ids: &[ClientOrderId]) -> Vec<ModifyOrder> {
+        let mut released = Vec::with_capacity(client_order_ids.len());
+        for client_order_id in client_order_ids {
+            let Some(pending) = self.pending.shift_remove(client_order_id) else {
+                continue;
+            };
+            if self.is_noop(&pending.command) {
+                log::debug!(
+                    "Skipping no-op modify for {client_order_id} ({} merged)",
+                    pending.merged
+                );
+                self.skipped_count += 1;
+                continue;
+            }
+            self.sent_count += 1;
+            released.push(pending.command);
+        }
+        released
+    }
+
+    fn is_noop(&self, command: &ModifyOrder) -> bool {
+        let cache = self.cache.borrow();
+        let Some(order) = cache.order(&command.client_order_id) else {
+            return command.quantity.is_none()
+                && command.price.is_none()
+                && command.trigger_price.is_none();
+        };
+
+        command.quantity.is_none_or(|qty| qty == order.quantity())
+            && command.price.is_none_or(|px| Some(px) == order.price())
+            && command
+                .trigger_price
+                .is_none_or(|px| Some(px) == order.trigger_price())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::{InstrumentId, StrategyId, TraderId},
+        orders::{OrderAny, builder::OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn working_order() -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("ETHUSDT.BINANCE"))
+            .side(OrderSide::Buy)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from("1"))
+            .build()
+    }
+
+    fn coalescer(window_ns: u64) -> (ModifyCoalescer, ClientOrderId) {
+        let order = working_order();
+        let client_order_id = order.client_order_id();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_order(order, None, None, false)
+            .unwrap();
+        (ModifyCoalescer::new(cache, window_ns), client_order_id)
+    }
+
+    fn modify(
+        client_order_id: ClientOrderId,
+        quantity: Option<&str>,
+        price: Option<&str>,
+        ts: u64,
+    ) -> ModifyOrder {
+        ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            client_order_id,
+            None,
+            quantity.map(Quantity::from),
+            price.map(Price::from),
+            None,
+            UUID4::new(),
+            ts.into(),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_merges_modifications_within_window() {
+        let (mut coalescer, client_order_id) = coalescer(100);
+
+        coalescer.push(modify(client_order_id, None, Some("101.00"), 0));
+        coalescer.push(modify(client_order_id, Some("2"), None, 40));
+        coalescer.push(modify(client_order_id, None, Some("102.00"), 80));
+
+        assert!(coalescer.flush(99.into()).is_empty());
+
+        let released = coalescer.flush(100.into());
+
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].price, Some(Price::from("102.00")));
+        assert_eq!(released[0].quantity, Some(Quantity::from("2")));
+        assert_eq!(released[0].ts_init, UnixNanos::from(80));
+        assert_eq!(coalescer.coalesced_count(), 2);
+        assert_eq!(coalescer.sent_count(), 1);
+        assert_eq!(coalescer.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_skips_noop_modification() {
+        let (mut coalescer, client_order_id) = coalescer(0);
+
+        coalescer.push(modify(client_order_id, None, Some("101.00"), 0));
+        coalescer.push(modify(client_order_id, Some("1"), Some("100.00"), 1));
+
+        assert!(coalescer.flush(1.into()).is_empty());
+        assert_eq!(coalescer.skipped_count(), 1);
+    }
+
+    #[rstest]
+    fn test_discard_pending_modification() {
+        let (mut coalescer, client_order_id) = coalescer(1_000);
+        coalescer.push(modify(client_order_id, None, Some("101.00"), 0));
+
+        let discarded = coalescer.discard(&client_order_id);
+
+        assert!(discarded.is_some());
+        assert!(coalescer.flush_all().is_empty());
+        assert_eq!(coalescer.received_count(), 1);
+    }
+}
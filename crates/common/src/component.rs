@@ -612,6 +612,54 @@ pub fn dispose_component(id: &Ustr) -> anyhow::Result<()> {
     }
 }
 
+/// Safely calls fault() on a component in the global registry.
+///
+/// # Errors
+///
+/// - Returns an error if the component is not found.
+/// - Returns an error if the component is already borrowed.
+/// - Returns an error if fault() fails.
+pub fn fault_component(id: &Ustr) -> anyhow::Result<()> {
+    let registry = get_component_registry();
+    let component_ref = registry
+        .get(id)
+        .ok_or_else(|| anyhow::anyhow!("Component '{id}' not found in global registry"))?;
+
+    if !registry.try_borrow(*id) {
+        anyhow::bail!(
+            "Component '{id}' is already mutably borrowed. \
+             This would create aliasing mutable references (undefined behavior)."
+        );
+    }
+
+    let _guard = BorrowGuard::new(*id);
+
+    // SAFETY: Borrow tracking ensures exclusive access
+    unsafe {
+        let component = &mut *component_ref.get();
+        component.fault()
+    }
+}
+
+/// Returns the state of a component in the global registry.
+///
+/// Returns `None` if the component is not found or is currently mutably borrowed (for example
+/// while one of its lifecycle methods is executing).
+#[must_use]
+pub fn component_state(id: &Ustr) -> Option<ComponentState> {
+    let registry = get_component_registry();
+    let component_ref = registry.get(id)?;
+
+    if !registry.try_borrow(*id) {
+        return None;
+    }
+
+    let _guard = BorrowGuard::new(*id);
+
+    // SAFETY: Borrow tracking ensures exclusive access
+    unsafe { Some((*component_ref.get()).state()) }
+}
+
 /// Returns a component from the global registry by ID.
 pub fn get_component(id: &Ustr) -> Option<Rc<UnsafeCell<dyn Component>>> {
     get_component_registry().get(id)
@@ -746,4 +794,24 @@ mod tests {
             "Borrow was not released after panic"
         );
     }
+
+    #[rstest]
+    fn test_fault_component_after_panic() {
+        clear_component_registry();
+
+        let id = Ustr::from("test-component-fault");
+        let component = TestComponent::new("test-component-fault", &DO_PANIC);
+        let component_ref = register_component(component);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = start_component(&id);
+        }));
+        assert!(result.is_err());
+
+        fault_component(&id).unwrap();
+
+        // SAFETY: No other borrows are active
+        let state = unsafe { (*component_ref.get()).state() };
+        assert_eq!(state, ComponentState::Faulted);
+    }
 }
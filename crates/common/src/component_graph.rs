@@ -0,0 +1,324 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Dependency declarations and ordered startup for registered components.
+//!
+//! A [`ComponentGraph`] records which components depend on which others (for example a strategy
+//! depending on a data client) and derives a topological start order, with the reverse used for
+//! stopping. Components whose dependencies have not reached `Running` are not started, and
+//! [`ComponentGraph::gated`] wraps message handlers so that an actor does not receive data before
+//! its dependencies are ready.
+
+use std::rc::Rc;
+
+use ahash::AHashSet;
+use indexmap::IndexMap;
+use ustr::Ustr;
+
+use crate::{
+    component::{component_state, start_component, stop_component},
+    enums::ComponentState,
+};
+
+/// A directed acyclic graph of dependencies between registered components.
+///
+/// Components are ordered by registration wherever their dependencies allow.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentGraph {
+    dependencies: IndexMap<Ustr, Vec<Ustr>>,
+}
+
+impl ComponentGraph {
+    /// Creates a new empty [`ComponentGraph`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component with no dependencies (no-op if already added).
+    pub fn add_component(&mut self, id: Ustr) {
+        self.dependencies.entry(id).or_default();
+    }
+
+    /// Declares that `component` depends on `dependency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either component has not been added, if `component` would depend on
+    /// itself, or if the dependency would introduce a cycle.
+    pub fn add_dependency(&mut self, component: Ustr, dependency: Ustr) -> anyhow::Result<()> {
+        for id in [component, dependency] {
+            if !self.dependencies.contains_key(&id) {
+                anyhow::bail!("Component '{id}' not found in dependency graph");
+            }
+        }
+        if component == dependency {
+            anyhow::bail!("Component '{component}' cannot depend on itself");
+        }
+        if self.depends_on(dependency, component) {
+            anyhow::bail!("Dependency of '{component}' on '{dependency}' would introduce a cycle");
+        }
+
+        if let Some(dependencies) = self.dependencies.get_mut(&component)
+            && !dependencies.contains(&dependency)
+        {
+            dependencies.push(dependency);
+        }
+        Ok(())
+    }
+
+    /// Returns the direct dependencies of `id`.
+    #[must_use]
+    pub fn dependencies(&self, id: &Ustr) -> &[Ustr] {
+        self.dependencies.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the components that directly depend on `id`.
+    #[must_use]
+    pub fn dependents(&self, id: &Ustr) -> Vec<Ustr> {
+        self.dependencies
+            .iter()
+            .filter(|(_, deps)| deps.contains(id))
+            .map(|(component, _)| *component)
+            .collect()
+    }
+
+    /// Returns whether `component` depends on `dependency`, directly or transitively.
+    #[must_use]
+    pub fn depends_on(&self, component: Ustr, dependency: Ustr) -> bool {
+        let mut stack = vec![component];
+        let mut visited = AHashSet::new();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            for dep in self.dependencies(&id) {
+                if *dep == dependency {
+                    return true;
+                }
+                stack.push(*dep);
+            }
+        }
+        false
+    }
+
+    /// Returns the components in start order: every component after all of its dependencies.
+    #[must_use]
+    pub fn start_order(&self) -> Vec<Ustr> {
+        let mut order = Vec::with_capacity(self.dependencies.len());
+        let mut placed = AHashSet::with_capacity(self.dependencies.len());
+
+        // Cycles are rejected on insertion so each pass places at least one component
+        while order.len() < self.dependencies.len() {
+            for (id, deps) in &self.dependencies {
+                if !placed.contains(id) && deps.iter().all(|dep| placed.contains(dep)) {
+                    placed.insert(*id);
+                    order.push(*id);
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns the components in stop order (the reverse of the start order).
+    #[must_use]
+    pub fn stop_order(&self) -> Vec<Ustr> {
+        let mut order = self.start_order();
+        order.reverse();
+        order
+    }
+
+    /// Returns whether all dependencies of `id` are `Running`.
+    #[must_use]
+    pub fn is_ready(&self, id: &Ustr) -> bool {
+        self.dependencies(id)
+            .iter()
+            .all(|dep| component_state(dep) == Some(ComponentState::Running))
+    }
+
+    /// Starts all components in dependency order.
+    ///
+    /// Components already `Running` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a component fails to start, or if a dependency of a component is not
+    /// `Running` when that component's turn comes; later components are not started.
+    pub fn start_all(&self) -> anyhow::Result<()> {
+        for id in self.start_order() {
+            if component_state(&id) == Some(ComponentState::Running) {
+                continue;
+            }
+            if let Some(dep) = self
+                .dependencies(&id)
+                .iter()
+                .find(|dep| component_state(dep) != Some(ComponentState::Running))
+            {
+                anyhow::bail!("Cannot start '{id}': dependency '{dep}' is not running");
+            }
+            log::debug!("Starting component {id}");
+            start_component(&id)?;
+        }
+        Ok(())
+    }
+
+    /// Stops all `Running` components in reverse dependency order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a component fails to stop; later components are not stopped.
+    pub fn stop_all(&self) -> anyhow::Result<()> {
+        for id in self.stop_order() {
+            if component_state(&id) != Some(ComponentState::Running) {
+                continue;
+            }
+            log::debug!("Stopping component {id}");
+            stop_component(&id)?;
+        }
+        Ok(())
+    }
+
+    /// Wraps `handler` so messages are dropped until all dependencies of `id` are `Running`.
+    pub fn gated<T>(self: &Rc<Self>, id: Ustr, handler: impl Fn(&T)) -> impl Fn(&T) {
+        let graph = self.clone();
+        move |message: &T| {
+            if graph.is_ready(&id) {
+                handler(message);
+            } else {
+                log::trace!("Dropping message for {id}: dependencies not running");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use nautilus_model::identifiers::{ComponentId, TraderId};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        cache::Cache,
+        clock::Clock,
+        component::{Component, clear_component_registry, register_component},
+        enums::ComponentTrigger,
+    };
+
+    thread_local! {
+        static STARTED: RefCell<Vec<Ustr>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct StubComponent {
+        id: ComponentId,
+        state: ComponentState,
+    }
+
+    impl Component for StubComponent {
+        fn component_id(&self) -> ComponentId {
+            self.id
+        }
+
+        fn state(&self) -> ComponentState {
+            self.state
+        }
+
+        fn transition_state(&mut self, trigger: ComponentTrigger) -> anyhow::Result<()> {
+            self.state = self.state.transition(&trigger)?;
+            Ok(())
+        }
+
+        fn register(
+            &mut self,
+            _trader_id: TraderId,
+            _clock: Rc<RefCell<dyn Clock>>,
+            _cache: Rc<RefCell<Cache>>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn on_start(&mut self) -> anyhow::Result<()> {
+            STARTED.with_borrow_mut(|started| started.push(self.id.inner()));
+            Ok(())
+        }
+    }
+
+    fn graph(names: &[&str]) -> ComponentGraph {
+        clear_component_registry();
+        STARTED.with_borrow_mut(Vec::clear);
+        let mut graph = ComponentGraph::new();
+        for name in names {
+            register_component(StubComponent {
+                id: ComponentId::new(name),
+                state: ComponentState::Ready,
+            });
+            graph.add_component(Ustr::from(name));
+        }
+        graph
+    }
+
+    #[rstest]
+    fn test_start_order_respects_dependencies() {
+        let mut graph = graph(&["strategy", "data", "exec"]);
+        let (strategy, data, exec) = ("strategy".into(), "data".into(), "exec".into());
+        graph.add_dependency(strategy, data).unwrap();
+        graph.add_dependency(strategy, exec).unwrap();
+
+        assert_eq!(graph.start_order(), vec![data, exec, strategy]);
+        assert_eq!(graph.stop_order(), vec![strategy, exec, data]);
+        assert_eq!(graph.dependents(&data), vec![strategy]);
+    }
+
+    #[rstest]
+    fn test_add_dependency_rejects_cycles() {
+        let mut graph = graph(&["a", "b", "c"]);
+        let (a, b, c) = ("a".into(), "b".into(), "c".into());
+        graph.add_dependency(a, b).unwrap();
+        graph.add_dependency(b, c).unwrap();
+
+        assert!(graph.add_dependency(c, a).is_err());
+        assert!(graph.add_dependency(a, a).is_err());
+        assert!(graph.add_dependency(a, "unknown".into()).is_err());
+    }
+
+    #[rstest]
+    fn test_start_all_and_readiness_gating() {
+        let mut graph = graph(&["strategy", "data"]);
+        let (strategy, data) = ("strategy".into(), "data".into());
+        graph.add_dependency(strategy, data).unwrap();
+        let graph = Rc::new(graph);
+
+        let received = Rc::new(Cell::new(0));
+        let counter = received.clone();
+        let handler = graph.gated(strategy, move |_m: &u32| counter.set(counter.get() + 1));
+
+        handler(&1);
+        assert!(!graph.is_ready(&strategy));
+        assert_eq!(received.get(), 0);
+
+        graph.start_all().unwrap();
+        assert_eq!(STARTED.with_borrow(Clone::clone), vec![data, strategy]);
+
+        handler(&2);
+        assert!(graph.is_ready(&strategy));
+        assert_eq!(received.get(), 1);
+
+        graph.stop_all().unwrap();
+        assert_eq!(component_state(&data), Some(ComponentState::Stopped));
+        handler(&3);
+        assert_eq!(received.get(), 1);
+    }
+}
@@ -0,0 +1,456 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conditional orders, held locally until a market condition holds.
+//!
+//! A [`MarketCondition`] is an expression over top-of-book and last trade prices, for example
+//! `mid(ESZ4.XCME) >= 4500.25 and last(NQZ4.XCME) < 18000`. Clauses are combined with `and`,
+//! which binds tighter than `or`. The [`ConditionalOrderManager`] holds submit commands until
+//! their condition holds (one-triggers-other on a market condition), then releases them to be
+//! sent to the venue.
+
+use std::{fmt::Display, str::FromStr};
+
+use ahash::AHashMap;
+use indexmap::IndexMap;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    identifiers::{ClientOrderId, InstrumentId},
+};
+use strum::{Display as StrumDisplay, EnumString};
+
+use crate::messages::execution::SubmitOrder;
+
+/// The market price a condition clause compares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, StrumDisplay, EnumString)]
+#[strum(ascii_case_insensitive, serialize_all = "lowercase")]
+pub enum ConditionField {
+    Bid,
+    Ask,
+    Mid,
+    Last,
+    Spread,
+}
+
+/// The comparison of a condition clause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, StrumDisplay, EnumString)]
+pub enum Comparison {
+    #[strum(serialize = ">")]
+    Gt,
+    #[strum(serialize = ">=")]
+    Ge,
+    #[strum(serialize = "<")]
+    Lt,
+    #[strum(serialize = "<=")]
+    Le,
+}
+
+impl Comparison {
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Gt => lhs > rhs,
+            Self::Ge => lhs >= rhs,
+            Self::Lt => lhs < rhs,
+            Self::Le => lhs <= rhs,
+        }
+    }
+}
+
+/// A single comparison of a market price against a value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConditionClause {
+    pub field: ConditionField,
+    pub instrument_id: InstrumentId,
+    pub comparison: Comparison,
+    pub value: f64,
+}
+
+impl ConditionClause {
+    fn holds(&self, markets: &AHashMap<InstrumentId, MarketSnapshot>) -> bool {
+        markets
+            .get(&self.instrument_id)
+            .and_then(|market| market.value(self.field))
+            .is_some_and(|price| self.comparison.holds(price, self.value))
+    }
+}
+
+impl FromStr for ConditionClause {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        let [operand, comparison, value] = tokens.as_slice() else {
+            anyhow::bail!(
+                "Invalid condition clause '{s}', expected `field(instrument_id) op value`"
+            );
+        };
+        let Some((field, instrument_id)) = operand
+            .strip_suffix(')')
+            .and_then(|operand| operand.split_once('('))
+        else {
+            anyhow::bail!("Invalid condition operand '{operand}'");
+        };
+
+        Ok(Self {
+            field: ConditionField::from_str(field)
+                .map_err(|_| anyhow::anyhow!("Invalid condition field '{field}'"))?,
+            instrument_id: InstrumentId::from_str(instrument_id)?,
+            comparison: Comparison::from_str(comparison)
+                .map_err(|_| anyhow::anyhow!("Invalid condition comparison '{comparison}'"))?,
+            value: value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid condition value '{value}'"))?,
+        })
+    }
+}
+
+impl Display for ConditionClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({}) {} {}",
+            self.field, self.instrument_id, self.comparison, self.value
+        )
+    }
+}
+
+/// A market condition expression, held in disjunctive normal form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketCondition {
+    /// The alternatives (joined by `or`), each holding when all of its clauses hold.
+    pub alternatives: Vec<Vec<ConditionClause>>,
+}
+
+impl MarketCondition {
+    /// Returns the instruments the condition depends on.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        let mut instrument_ids: Vec<InstrumentId> = Vec::new();
+        for clause in self.alternatives.iter().flatten() {
+            if !instrument_ids.contains(&clause.instrument_id) {
+                instrument_ids.push(clause.instrument_id);
+            }
+        }
+        instrument_ids
+    }
+
+    fn holds(&self, markets: &AHashMap<InstrumentId, MarketSnapshot>) -> bool {
+        self.alternatives
+            .iter()
+            .any(|clauses| clauses.iter().all(|clause| clause.holds(markets)))
+    }
+
+    fn depends_on(&self, instrument_id: &InstrumentId) -> bool {
+        self.alternatives
+            .iter()
+            .flatten()
+            .any(|clause| clause.instrument_id == *instrument_id)
+    }
+}
+
+impl FromStr for MarketCondition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let mut alternatives = vec![Vec::new()];
+        let mut clause = Vec::new();
+
+        for token in s.split_whitespace().chain(std::iter::once("or")) {
+            let is_or = token.eq_ignore_ascii_case("or");
+            if is_or || token.eq_ignore_ascii_case("and") {
+                anyhow::ensure!(!clause.is_empty(), "Empty clause in condition '{s}'");
+                let parsed = ConditionClause::from_str(&clause.join(" "))?;
+                alternatives
+                    .last_mut()
+                    .expect("always one alternative")
+                    .push(parsed);
+                clause.clear();
+                if is_or {
+                    alternatives.push(Vec::new());
+                }
+            } else {
+                clause.push(token);
+            }
+        }
+        alternatives.pop();
+
+        Ok(Self { alternatives })
+    }
+}
+
+impl Display for MarketCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let alternatives: Vec<String> = self
+            .alternatives
+            .iter()
+            .map(|clauses| {
+                clauses
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            })
+            .collect();
+        write!(f, "{}", alternatives.join(" or "))
+    }
+}
+
+/// The latest market prices of an instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MarketSnapshot {
+    bid: Option<f64>,
+    ask: Option<f64>,
+    last: Option<f64>,
+}
+
+impl MarketSnapshot {
+    fn value(&self, field: ConditionField) -> Option<f64> {
+        match field {
+            ConditionField::Bid => self.bid,
+            ConditionField::Ask => self.ask,
+            ConditionField::Last => self.last,
+            ConditionField::Mid => Some((self.bid? + self.ask?) / 2.0),
+            ConditionField::Spread => Some(self.ask? - self.bid?),
+        }
+    }
+}
+
+/// Holds submit commands locally until their market condition holds.
+#[derive(Debug, Default)]
+pub struct ConditionalOrderManager {
+    pending: IndexMap<ClientOrderId, (MarketCondition, SubmitOrder)>,
+    markets: AHashMap<InstrumentId, MarketSnapshot>,
+}
+
+impl ConditionalOrderManager {
+    /// Creates a new [`ConditionalOrderManager`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Holds the `command` until `condition` holds.
+    ///
+    /// The condition is evaluated on the next market update for any of its instruments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the condition has no clauses, or the order is already held.
+    pub fn register(
+        &mut self,
+        command: SubmitOrder,
+        condition: MarketCondition,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !condition.alternatives.is_empty(),
+            "Condition for order {} has no clauses",
+            command.client_order_id
+        );
+        anyhow::ensure!(
+            !self.pending.contains_key(&command.client_order_id),
+            "Conditional order {} already registered",
+            command.client_order_id
+        );
+        log::debug!(
+            "Holding order {} until {condition}",
+            command.client_order_id
+        );
+        self.pending
+            .insert(command.client_order_id, (condition, command));
+        Ok(())
+    }
+
+    /// Returns the number of held orders.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the condition of the held order with the given client order ID.
+    #[must_use]
+    pub fn condition(&self, client_order_id: &ClientOrderId) -> Option<&MarketCondition> {
+        self.pending
+            .get(client_order_id)
+            .map(|(condition, _)| condition)
+    }
+
+    /// Updates the top of book and returns the commands whose condition now holds.
+    pub fn on_quote(&mut self, quote: &QuoteTick) -> Vec<SubmitOrder> {
+        let market = self.markets.entry(quote.instrument_id).or_default();
+        market.bid = Some(quote.bid_price.as_f64());
+        market.ask = Some(quote.ask_price.as_f64());
+        self.release(&quote.instrument_id)
+    }
+
+    /// Updates the last trade price and returns the commands whose condition now holds.
+    pub fn on_trade(&mut self, trade: &TradeTick) -> Vec<SubmitOrder> {
+        let market = self.markets.entry(trade.instrument_id).or_default();
+        market.last = Some(trade.price.as_f64());
+        self.release(&trade.instrument_id)
+    }
+
+    /// Cancels the held order with the given client order ID, returning its command.
+    pub fn cancel(&mut self, client_order_id: &ClientOrderId) -> Option<SubmitOrder> {
+        self.pending
+            .shift_remove(client_order_id)
+            .map(|(_, command)| command)
+    }
+
+    fn release(&mut self, instrument_id: &InstrumentId) -> Vec<SubmitOrder> {
+        let triggered: Vec<ClientOrderId> = self
+            .pending
+            .iter()
+            .filter(|(_, (condition, _))| {
+                condition.depends_on(instrument_id) && condition.holds(&self.markets)
+            })
+            .map(|(client_order_id, _)| *client_order_id)
+            .collect();
+
+        triggered
+            .iter()
+            .filter_map(|client_order_id| {
+                let (condition, command) = self.pending.shift_remove(client_order_id)?;
+                log::info!("Releasing order {client_order_id}: {condition}");
+                Some(command)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{UUID4, UnixNanos};
+    use nautilus_model::{
+        enums::{AggressorSide, OrderSide, OrderType},
+        identifiers::TradeId,
+        orders::{Order, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn submit(client_order_id: &str) -> SubmitOrder {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("NQZ4.XCME"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(1))
+            .build();
+        SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn trade(instrument_id: &str, price: &str) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(price),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_parse_condition_round_trip() {
+        let expression =
+            "mid(ESZ4.XCME) >= 4500.25 and last(NQZ4.XCME) < 18000 or spread(ESZ4.XCME) > 1";
+        let condition = MarketCondition::from_str(expression).unwrap();
+
+        assert_eq!(condition.alternatives.len(), 2);
+        assert_eq!(condition.alternatives[0].len(), 2);
+        assert_eq!(condition.alternatives[0][0].comparison, Comparison::Ge);
+        assert_eq!(
+            condition.instrument_ids(),
+            vec![
+                InstrumentId::from("ESZ4.XCME"),
+                InstrumentId::from("NQZ4.XCME")
+            ]
+        );
+        assert_eq!(condition.to_string(), expression);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("mid(ESZ4.XCME) >= 4500 and")]
+    #[case("mid(ESZ4.XCME) == 4500")]
+    #[case("vwap(ESZ4.XCME) > 4500")]
+    #[case("mid ESZ4.XCME > 4500")]
+    #[case("mid(ESZ4.XCME) > high")]
+    fn test_parse_condition_errors(#[case] expression: &str) {
+        assert!(MarketCondition::from_str(expression).is_err());
+    }
+
+    #[rstest]
+    fn test_releases_order_when_all_clauses_hold() {
+        let mut manager = ConditionalOrderManager::new();
+        let condition =
+            MarketCondition::from_str("mid(ESZ4.XCME) >= 4500 and last(NQZ4.XCME) < 18000")
+                .unwrap();
+        manager.register(submit("O-1"), condition).unwrap();
+
+        assert!(
+            manager
+                .on_quote(&quote("ESZ4.XCME", "4500.00", "4500.50"))
+                .is_empty()
+        );
+        assert!(manager.on_trade(&trade("NQZ4.XCME", "18000.00")).is_empty());
+        let released = manager.on_trade(&trade("NQZ4.XCME", "17999.75"));
+
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[rstest]
+    fn test_cancel_removes_held_order() {
+        let mut manager = ConditionalOrderManager::new();
+        let condition = MarketCondition::from_str("bid(ESZ4.XCME) > 4500").unwrap();
+        manager.register(submit("O-1"), condition.clone()).unwrap();
+
+        assert!(manager.register(submit("O-1"), condition).is_err());
+        assert!(manager.cancel(&ClientOrderId::from("O-1")).is_some());
+        assert!(
+            manager
+                .on_quote(&quote("ESZ4.XCME", "4501.00", "4501.25"))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,422 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Panic capture for actor callbacks and runner tasks.
+//!
+//! Wrapping a callback with [`catch_panic`] converts a panic into a [`CrashReport`] rather than
+//! unwinding through (and aborting) the event loop. The report carries the panic message,
+//! location, backtrace and the most recent message bus deliveries from the
+//! [recorder](crate::msgbus::recorder), is logged as a structured error, and is optionally written
+//! to a crash dump file. When the panic is attributed to a component, that component is
+//! transitioned to `Faulted` so it no longer receives lifecycle commands as if healthy.
+//!
+//! The panic hook installed by [`install_panic_hook`] (on first use of [`catch_panic`]) captures
+//! the location and backtrace at the panic site, and always chains to the previously installed
+//! hook so the standard panic output is preserved.
+//!
+//! Actor callbacks are dispatched through [`catch_panic`] and tasks spawned through the
+//! [task registry](crate::live::tasks::TaskRegistry) are wrapped with [`catch_panic_task`].
+//!
+//! # Unwinding requirement
+//!
+//! Panics can only be recovered from when compiled with `panic = "unwind"`. The workspace release
+//! profile uses `panic = "abort"`, under which the panic hook logs the crash report and writes the
+//! crash dump (if configured) before the process aborts, but the component cannot be faulted.
+//! Deployments which must fault components rather than abort need a profile with
+//! `panic = "unwind"`; [`PANIC_RECOVERY_SUPPORTED`] reports which applies to the current build.
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    future::Future,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Once,
+    task::{Context, Poll},
+};
+
+use nautilus_core::{UnixNanos, time::get_atomic_clock_realtime};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    component::fault_component,
+    msgbus::recorder::{RecordedMessage, recent_messages},
+};
+
+/// Whether panics can be caught and recovered from in this build (`panic = "unwind"`).
+pub const PANIC_RECOVERY_SUPPORTED: bool = cfg!(panic = "unwind");
+
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    static CRASH_CONFIG: RefCell<CrashConfig> = RefCell::new(CrashConfig::default());
+    // The component of each active catch scope (innermost last)
+    static CATCH_SCOPES: RefCell<Vec<Option<Ustr>>> = const { RefCell::new(Vec::new()) };
+    static LAST_PANIC: RefCell<Option<PanicDetails>> = const { RefCell::new(None) };
+}
+
+/// Configuration for panic capture and crash reporting.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrashConfig {
+    /// The maximum number of recent message bus deliveries to attach to a report.
+    pub recent_messages: usize,
+    /// Whether to capture a backtrace at the panic site (requires the panic hook).
+    pub capture_backtrace: bool,
+    /// The directory to write crash dump files to, if any.
+    pub dump_dir: Option<PathBuf>,
+}
+
+impl Default for CrashConfig {
+    /// Creates a new default [`CrashConfig`] instance.
+    fn default() -> Self {
+        Self {
+            recent_messages: 32,
+            capture_backtrace: true,
+            dump_dir: None,
+        }
+    }
+}
+
+/// A structured report of a captured panic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// The component the panic is attributed to, `None` for runner tasks.
+    pub component_id: Option<Ustr>,
+    pub message: String,
+    pub location: Option<String>,
+    pub thread: String,
+    pub backtrace: Option<String>,
+    pub recent_messages: Vec<RecordedMessage>,
+    /// Whether the component was successfully transitioned to `Faulted`.
+    pub faulted: bool,
+    pub ts: UnixNanos,
+}
+
+impl Display for CrashReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = self.component_id.map_or("task", |id| id.as_str());
+        write!(
+            f,
+            "Panic in {source} on thread '{}': {}",
+            self.thread, self.message
+        )?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        if !self.recent_messages.is_empty() {
+            write!(f, "\nRecent messages:")?;
+            for message in &self.recent_messages {
+                write!(
+                    f,
+                    "\n  {} {} -> {}",
+                    message.ts, message.key, message.handler_id
+                )?;
+            }
+        }
+        if let Some(backtrace) = &self.backtrace {
+            write!(f, "\nBacktrace:\n{backtrace}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct PanicDetails {
+    message: String,
+    location: Option<String>,
+    backtrace: Option<String>,
+}
+
+/// Sets the crash configuration for the current thread.
+pub fn set_crash_config(config: CrashConfig) {
+    CRASH_CONFIG.set(config);
+}
+
+/// Returns the crash configuration for the current thread.
+#[must_use]
+pub fn crash_config() -> CrashConfig {
+    CRASH_CONFIG.with_borrow(Clone::clone)
+}
+
+/// Installs the process-wide panic hook (idempotent).
+///
+/// Panics raised inside [`catch_panic`] have their details captured for the crash report. Every
+/// panic is then passed to the previous hook, so the standard panic output is never suppressed.
+/// Without unwinding support the crash report is emitted from the hook, as the process aborts
+/// once it returns.
+pub fn install_panic_hook() {
+    INSTALL_HOOK.call_once(|| {
+        if !PANIC_RECOVERY_SUPPORTED {
+            log::warn!(
+                "Built with panic = \"abort\": panics will be reported but cannot be recovered from"
+            );
+        }
+
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Some(component_id) = CATCH_SCOPES.with_borrow(|scopes| scopes.last().copied()) {
+                let capture_backtrace = CRASH_CONFIG.with_borrow(|config| config.capture_backtrace);
+                let details = PanicDetails {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                    backtrace: capture_backtrace
+                        .then(|| std::backtrace::Backtrace::force_capture().to_string()),
+                };
+
+                if PANIC_RECOVERY_SUPPORTED {
+                    LAST_PANIC.set(Some(details));
+                } else {
+                    report_panic(component_id, details, false);
+                }
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Invokes `f`, converting a panic into a [`CrashReport`].
+///
+/// On panic the report is logged, written to a crash dump file if configured, and the
+/// component identified by `component_id` (if any) is transitioned to `Faulted`. Any borrow of
+/// the component must be taken inside `f`, so it is released before the component is faulted.
+///
+/// Requires `panic = "unwind"`, see the [module documentation](self).
+///
+/// # Errors
+///
+/// Returns the [`CrashReport`] if `f` panics.
+pub fn catch_panic<R>(
+    component_id: Option<Ustr>,
+    f: impl FnOnce() -> R,
+) -> Result<R, Box<CrashReport>> {
+    install_panic_hook();
+
+    CATCH_SCOPES.with_borrow_mut(|scopes| scopes.push(component_id));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCH_SCOPES.with_borrow_mut(Vec::pop);
+
+    result.map_err(|payload| {
+        let details = take_panic_details(payload.as_ref());
+        Box::new(report_panic(component_id, details, component_id.is_some()))
+    })
+}
+
+/// Wraps `future` so a panic while polling it resolves to a [`CrashReport`] attributed to
+/// `component_id`, rather than unwinding into the executor.
+///
+/// The component is not faulted, as tasks are polled on runtime worker threads rather than the
+/// thread the component is registered on (see
+/// [`TaskRegistry::fault_components`](crate::live::tasks::TaskRegistry::fault_components)). The
+/// crash configuration of the polling thread applies.
+pub fn catch_panic_task<F: Future>(component_id: Ustr, future: F) -> CatchPanicTask<F> {
+    CatchPanicTask {
+        component_id,
+        future: Box::pin(future),
+    }
+}
+
+/// A future capturing panics of its inner future, created by [`catch_panic_task`].
+#[must_use = "futures do nothing unless polled"]
+pub struct CatchPanicTask<F> {
+    component_id: Ustr,
+    future: Pin<Box<F>>,
+}
+
+impl<F> std::fmt::Debug for CatchPanicTask<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(CatchPanicTask))
+            .field("component_id", &self.component_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Future> Future for CatchPanicTask<F> {
+    type Output = Result<F::Output, Box<CrashReport>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        install_panic_hook();
+
+        let component_id = self.component_id;
+        CATCH_SCOPES.with_borrow_mut(|scopes| scopes.push(Some(component_id)));
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| self.future.as_mut().poll(cx)));
+        CATCH_SCOPES.with_borrow_mut(Vec::pop);
+
+        match result {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => {
+                let details = take_panic_details(payload.as_ref());
+                Poll::Ready(Err(Box::new(report_panic(
+                    Some(component_id),
+                    details,
+                    false,
+                ))))
+            }
+        }
+    }
+}
+
+fn take_panic_details(payload: &(dyn std::any::Any + Send)) -> PanicDetails {
+    LAST_PANIC.take().unwrap_or_else(|| PanicDetails {
+        message: payload_message(payload),
+        location: None,
+        backtrace: None,
+    })
+}
+
+fn report_panic(component_id: Option<Ustr>, details: PanicDetails, fault: bool) -> CrashReport {
+    let config = crash_config();
+
+    let faulted = match component_id {
+        Some(id) if fault => match fault_component(&id) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Failed to fault component {id} after panic: {e}");
+                false
+            }
+        },
+        _ => false,
+    };
+
+    let report = CrashReport {
+        component_id,
+        message: details.message,
+        location: details.location,
+        thread: std::thread::current()
+            .name()
+            .unwrap_or("unnamed")
+            .to_string(),
+        backtrace: details.backtrace,
+        recent_messages: recent_messages(config.recent_messages),
+        faulted,
+        ts: get_atomic_clock_realtime().get_time_ns(),
+    };
+    log::error!("{report}");
+
+    if let Some(dir) = &config.dump_dir {
+        match write_crash_dump(&report, dir) {
+            Ok(path) => log::error!("Crash dump written to {}", path.display()),
+            Err(e) => log::error!("Failed to write crash dump: {e}"),
+        }
+    }
+
+    report
+}
+
+/// Writes `report` as JSON to a crash dump file in `dir`, returning the file path.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file cannot be written.
+pub fn write_crash_dump(report: &CrashReport, dir: &Path) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let source = report.component_id.map_or("task", |id| id.as_str());
+    let path = dir.join(format!("crash-{source}-{}.json", report.ts.as_u64()));
+    std::fs::write(&path, serde_json::to_string_pretty(report)?)?;
+    Ok(path)
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::{
+        self, ShareableMessageHandler, disable_message_recorder, enable_message_recorder,
+    };
+
+    #[rstest]
+    fn test_catch_panic_returns_value_without_panic() {
+        assert_eq!(catch_panic(None, || 42), Ok(42));
+    }
+
+    #[rstest]
+    fn test_catch_panic_builds_report_with_recent_messages() {
+        enable_message_recorder(8);
+        let handler = ShareableMessageHandler::from_typed(|_m: &u32| {});
+        msgbus::subscribe_any("crash.test".into(), handler, None);
+        msgbus::publish_any("crash.test".into(), &1_u32);
+
+        let report = catch_panic::<()>(None, || panic!("boom")).unwrap_err();
+        disable_message_recorder();
+
+        assert_eq!(report.message, "boom");
+        assert!(report.location.unwrap().contains("crash.rs"));
+        assert!(report.backtrace.is_some());
+        assert!(!report.faulted);
+        assert_eq!(report.recent_messages.len(), 1);
+        assert_eq!(report.recent_messages[0].key, Ustr::from("crash.test"));
+    }
+
+    #[rstest]
+    fn test_catch_panic_unknown_component_is_not_faulted() {
+        let result: Result<(), Box<CrashReport>> = catch_panic(Some(Ustr::from("missing")), || {
+            panic!("{}", String::from("oops"))
+        });
+        let report = result.unwrap_err();
+
+        assert_eq!(report.component_id, Some(Ustr::from("missing")));
+        assert_eq!(report.message, "oops");
+        assert!(!report.faulted);
+    }
+
+    #[rstest]
+    fn test_catch_panic_task_reports_panic() {
+        let component_id = Ustr::from("DataClient-TEST");
+        let mut task = catch_panic_task(component_id, async {
+            panic!("task boom");
+        });
+        let mut cx = Context::from_waker(std::task::Waker::noop());
+
+        let Poll::Ready(Err(report)) = Pin::new(&mut task).poll(&mut cx) else {
+            panic!("Expected a crash report");
+        };
+
+        assert_eq!(report.component_id, Some(component_id));
+        assert_eq!(report.message, "task boom");
+        assert!(report.location.unwrap().contains("crash.rs"));
+        assert!(!report.faulted);
+    }
+
+    #[rstest]
+    fn test_catch_panic_writes_crash_dump() {
+        let dir = std::env::temp_dir().join(format!("crash-{}", UUID4::new()));
+        set_crash_config(CrashConfig {
+            dump_dir: Some(dir.clone()),
+            ..Default::default()
+        });
+
+        let report = catch_panic::<()>(None, || panic!("dump")).unwrap_err();
+        set_crash_config(CrashConfig::default());
+
+        let path = dir.join(format!("crash-task-{}.json", report.ts.as_u64()));
+        let written: CrashReport =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written, *report);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
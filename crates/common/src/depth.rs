@@ -0,0 +1,265 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Generation of fixed-depth `OrderBookDepth10` snapshots from maintained order books.
+//!
+//! Allows strategies which prefer fixed-depth snapshots to consume any venue's L2/L3 delta feed,
+//! with snapshots published on the standard depth10 topic for the instrument.
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{DEPTH10_LEN, OrderBookDepth10},
+    identifiers::InstrumentId,
+    orderbook::OrderBook,
+};
+
+use crate::msgbus::{self, MStr, Topic, switchboard::get_book_depth10_topic};
+
+/// Determines when a [`BookDepth10Publisher`] emits a new snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Depth10PublishMode {
+    /// Publish whenever the top ten levels change.
+    OnChange,
+    /// Publish at most once per interval (nanoseconds), and only if the top ten levels changed.
+    Interval(u64),
+}
+
+/// Derives `OrderBookDepth10` snapshots from an order book and publishes them on the message bus.
+#[derive(Debug)]
+pub struct BookDepth10Publisher {
+    instrument_id: InstrumentId,
+    topic: MStr<Topic>,
+    mode: Depth10PublishMode,
+    last: Option<OrderBookDepth10>,
+    last_published_ns: Option<UnixNanos>,
+    published_count: u64,
+}
+
+impl BookDepth10Publisher {
+    /// Creates a new [`BookDepth10Publisher`] instance for `instrument_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mode` is [`Depth10PublishMode::Interval`] with a zero interval.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, mode: Depth10PublishMode) -> Self {
+        if let Depth10PublishMode::Interval(interval_ns) = mode {
+            assert!(interval_ns > 0, "`interval_ns` must be positive");
+        }
+
+        Self {
+            instrument_id,
+            topic: get_book_depth10_topic(instrument_id),
+            mode,
+            last: None,
+            last_published_ns: None,
+            published_count: 0,
+        }
+    }
+
+    /// Returns the instrument ID for the publisher.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the topic snapshots are published on.
+    #[must_use]
+    pub const fn topic(&self) -> MStr<Topic> {
+        self.topic
+    }
+
+    /// Returns the publish mode.
+    #[must_use]
+    pub const fn mode(&self) -> Depth10PublishMode {
+        self.mode
+    }
+
+    /// Returns the last snapshot published, if any.
+    #[must_use]
+    pub const fn last(&self) -> Option<&OrderBookDepth10> {
+        self.last.as_ref()
+    }
+
+    /// Returns the number of snapshots published.
+    #[must_use]
+    pub const fn published_count(&self) -> u64 {
+        self.published_count
+    }
+
+    /// Handles an update of `book`, publishing a new snapshot if one is due under the mode.
+    ///
+    /// Returns the published snapshot, or `None` if nothing was published.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the book's instrument ID does not match the publisher's.
+    pub fn on_book_update(
+        &mut self,
+        book: &OrderBook,
+        ts_now: UnixNanos,
+    ) -> Option<OrderBookDepth10> {
+        assert_eq!(
+            book.instrument_id, self.instrument_id,
+            "Book instrument ID mismatch"
+        );
+
+        if let (Depth10PublishMode::Interval(interval_ns), Some(last_ns)) =
+            (self.mode, self.last_published_ns)
+            && ts_now.as_u64().saturating_sub(last_ns.as_u64()) < interval_ns
+        {
+            return None;
+        }
+
+        let depth = book.to_depth10(ts_now);
+        if self
+            .last
+            .as_ref()
+            .is_some_and(|last| levels_equal(last, &depth))
+        {
+            return None;
+        }
+
+        self.publish(depth);
+        Some(depth)
+    }
+
+    /// Publishes a snapshot of `book` regardless of the mode (e.g. from a timer callback).
+    pub fn publish_now(&mut self, book: &OrderBook, ts_now: UnixNanos) -> OrderBookDepth10 {
+        let depth = book.to_depth10(ts_now);
+        self.publish(depth);
+        depth
+    }
+
+    /// Resets the publisher so the next update always publishes.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.last_published_ns = None;
+    }
+
+    fn publish(&mut self, depth: OrderBookDepth10) {
+        msgbus::publish_depth10(self.topic, &depth);
+        self.last = Some(depth);
+        self.last_published_ns = Some(depth.ts_init);
+        self.published_count += 1;
+    }
+}
+
+// `BookOrder` equality only compares order IDs, so the levels are compared field by field
+fn levels_equal(a: &OrderBookDepth10, b: &OrderBookDepth10) -> bool {
+    a.bid_counts == b.bid_counts
+        && a.ask_counts == b.ask_counts
+        && (0..DEPTH10_LEN).all(|i| {
+            a.bids[i].price == b.bids[i].price
+                && a.bids[i].size == b.bids[i].size
+                && a.asks[i].price == b.asks[i].price
+                && a.asks[i].size == b.asks[i].size
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::{
+        data::BookOrder,
+        enums::{BookType, OrderSide},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::TypedHandler;
+
+    fn add_bid(book: &mut OrderBook, price: &str, size: &str, sequence: u64) {
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from(size),
+                sequence,
+            ),
+            0,
+            sequence,
+            sequence.into(),
+        );
+    }
+
+    #[rstest]
+    fn test_on_change_publishes_only_when_levels_change() {
+        let instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut publisher = BookDepth10Publisher::new(instrument_id, Depth10PublishMode::OnChange);
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        msgbus::subscribe_book_depth10(
+            publisher.topic().into(),
+            TypedHandler::from(move |depth: &OrderBookDepth10| {
+                received_clone.borrow_mut().push(*depth);
+            }),
+            None,
+        );
+
+        add_bid(&mut book, "100.00", "10", 1);
+        assert!(publisher.on_book_update(&book, 1.into()).is_some());
+        assert!(publisher.on_book_update(&book, 2.into()).is_none());
+
+        add_bid(&mut book, "99.00", "5", 2);
+        let depth = publisher.on_book_update(&book, 3.into()).unwrap();
+
+        assert_eq!(depth.bids[1].price, Price::from("99.00"));
+        assert_eq!(publisher.published_count(), 2);
+        assert_eq!(received.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_interval_mode_throttles_snapshots() {
+        let instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut publisher =
+            BookDepth10Publisher::new(instrument_id, Depth10PublishMode::Interval(100));
+
+        add_bid(&mut book, "100.00", "10", 1);
+        assert!(publisher.on_book_update(&book, 1_000.into()).is_some());
+
+        add_bid(&mut book, "99.00", "5", 2);
+        assert!(publisher.on_book_update(&book, 1_050.into()).is_none());
+        assert!(publisher.on_book_update(&book, 1_100.into()).is_some());
+        assert_eq!(publisher.published_count(), 2);
+    }
+
+    #[rstest]
+    fn test_publish_now_ignores_mode() {
+        let instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut publisher = BookDepth10Publisher::new(instrument_id, Depth10PublishMode::OnChange);
+
+        publisher.publish_now(&book, 1.into());
+        publisher.publish_now(&book, 2.into());
+
+        assert_eq!(publisher.published_count(), 2);
+        assert_eq!(publisher.last().unwrap().ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`interval_ns` must be positive")]
+    fn test_zero_interval_panics() {
+        let _ = BookDepth10Publisher::new(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            Depth10PublishMode::Interval(0),
+        );
+    }
+}
@@ -371,4 +371,40 @@ pub enum SerializationEncoding {
     /// The JavaScript Object Notation (JSON) encoding.
     #[serde(rename = "json")]
     Json = 1,
+    /// The Protocol Buffers encoding (as the `nexuscore.msgbus.Value` message type).
+    #[serde(rename = "protobuf")]
+    Protobuf = 2,
+}
+
+/// The signing algorithm for message bus external stream frames.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromRepr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.common.enums")
+)]
+pub enum FrameSigningAlgorithm {
+    /// HMAC-SHA256 with a shared secret key.
+    #[serde(rename = "hmac_sha256")]
+    HmacSha256 = 1,
+    /// Ed25519 digital signatures.
+    #[serde(rename = "ed25519")]
+    Ed25519 = 2,
 }
@@ -0,0 +1,439 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! End-of-day mark-to-market and settlement.
+//!
+//! At each session close the [`EndOfDayProcess`] marks every open position to a settlement
+//! price, taken from prices provided by the venue (or another configured source) via
+//! [`EndOfDayProcess::set_settlement_price`], falling back to a cached price. The daily PnL of a
+//! position is the change in its unrealized PnL since the previous settlement. For margin
+//! accounts configured for futures-style daily settlement, that PnL is realized into the account
+//! balances by applying a system-calculated account state. Each run rolls the day's statistics
+//! into the history and publishes an [`EodReport`] on the end-of-day topic.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashMap;
+use indexmap::IndexMap;
+use nautilus_core::{UUID4, UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{
+    accounts::AccountAny,
+    enums::PriceType,
+    identifiers::{AccountId, InstrumentId, PositionId},
+    matching::tif::TradingSession,
+    types::{AccountBalance, Currency, Money, Price},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::{self, MStr, Topic},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// Returns the end-of-day topic.
+#[must_use]
+pub fn get_eod_topic() -> MStr<Topic> {
+    "events.eod".into()
+}
+
+/// Configuration for the [`EndOfDayProcess`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EodConfig {
+    /// The daily session whose close triggers settlement.
+    pub session: TradingSession,
+    /// The cached price used when no settlement price has been provided.
+    pub fallback_price_type: Option<PriceType>,
+    /// Whether daily PnL is realized into margin account balances (futures-style settlement).
+    pub realize_daily_pnl: bool,
+}
+
+/// The settlement of a single open position.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettlementMark {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub account_id: AccountId,
+    pub settlement_price: Price,
+    /// The change in unrealized PnL since the previous settlement.
+    pub daily_pnl: Money,
+}
+
+/// The statistics for one settled trading day.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyStats {
+    pub session_close: UnixNanos,
+    pub positions_marked: usize,
+    /// The open positions which could not be marked for lack of a price.
+    pub positions_unmarked: usize,
+    /// The total daily PnL per currency.
+    pub pnl: Vec<Money>,
+}
+
+/// The result of an end-of-day settlement run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EodReport {
+    pub marks: Vec<SettlementMark>,
+    /// The instruments of open positions for which no settlement price was available.
+    pub missing_prices: Vec<InstrumentId>,
+    /// The daily PnL realized into each account balance.
+    pub realized: Vec<(AccountId, Money)>,
+    pub stats: DailyStats,
+}
+
+#[derive(Debug, Default)]
+struct EodState {
+    settlement_prices: AHashMap<InstrumentId, Price>,
+    /// The unrealized PnL of each position at its last settlement.
+    baselines: AHashMap<PositionId, Money>,
+    history: Vec<DailyStats>,
+}
+
+/// Marks positions to settlement prices at each session close and settles daily PnL.
+#[derive(Clone)]
+pub struct EndOfDayProcess {
+    cache: Rc<RefCell<Cache>>,
+    config: EodConfig,
+    state: Rc<RefCell<EodState>>,
+}
+
+impl Debug for EndOfDayProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(EndOfDayProcess))
+            .field("config", &self.config)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl EndOfDayProcess {
+    /// The name of the timer used to trigger settlement.
+    pub const TIMER_NAME: &str = "EndOfDay";
+
+    /// Creates a new [`EndOfDayProcess`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>, config: EodConfig) -> Self {
+        Self {
+            cache,
+            config,
+            state: Rc::new(RefCell::new(EodState::default())),
+        }
+    }
+
+    /// Sets the settlement price for `instrument_id`, used by the next settlement run.
+    pub fn set_settlement_price(&self, instrument_id: InstrumentId, price: Price) {
+        self.state
+            .borrow_mut()
+            .settlement_prices
+            .insert(instrument_id, price);
+    }
+
+    /// Returns the statistics of all settled days, oldest first.
+    #[must_use]
+    pub fn daily_stats(&self) -> Vec<DailyStats> {
+        self.state.borrow().history.clone()
+    }
+
+    /// Runs settlement for the session closing at `ts`.
+    ///
+    /// Provided settlement prices are consumed by the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if realizing PnL into an account fails.
+    pub fn run(&self, ts: UnixNanos) -> anyhow::Result<EodReport> {
+        let mut state = self.state.borrow_mut();
+        let settlement_prices = std::mem::take(&mut state.settlement_prices);
+
+        let mut marks = Vec::new();
+        let mut missing_prices = Vec::new();
+        let mut baselines = AHashMap::new();
+        {
+            let cache = self.cache.borrow();
+            for position in cache.positions_open(None, None, None, None, None) {
+                let price = settlement_prices
+                    .get(&position.instrument_id)
+                    .copied()
+                    .or_else(|| {
+                        self.config
+                            .fallback_price_type
+                            .and_then(|price_type| cache.price(&position.instrument_id, price_type))
+                    });
+                let Some(settlement_price) = price else {
+                    // Keep the previous baseline so the next mark covers both days
+                    if let Some(baseline) = state.baselines.get(&position.id) {
+                        baselines.insert(position.id, *baseline);
+                    }
+                    if !missing_prices.contains(&position.instrument_id) {
+                        missing_prices.push(position.instrument_id);
+                    }
+                    continue;
+                };
+
+                let unrealized = position.unrealized_pnl(settlement_price);
+                let baseline = state
+                    .baselines
+                    .get(&position.id)
+                    .copied()
+                    .unwrap_or_else(|| Money::new(0.0, unrealized.currency));
+                baselines.insert(position.id, unrealized);
+                marks.push(SettlementMark {
+                    position_id: position.id,
+                    instrument_id: position.instrument_id,
+                    account_id: position.account_id,
+                    settlement_price,
+                    daily_pnl: unrealized - baseline,
+                });
+            }
+        }
+
+        // Replacing the baselines drops those of positions closed since the last settlement
+        state.baselines = baselines;
+
+        let mut account_pnl: IndexMap<(AccountId, Currency), Money> = IndexMap::new();
+        let mut pnl_by_currency: IndexMap<Currency, Money> = IndexMap::new();
+        for mark in &marks {
+            let currency = mark.daily_pnl.currency;
+            account_pnl
+                .entry((mark.account_id, currency))
+                .and_modify(|pnl| *pnl = *pnl + mark.daily_pnl)
+                .or_insert(mark.daily_pnl);
+            pnl_by_currency
+                .entry(currency)
+                .and_modify(|pnl| *pnl = *pnl + mark.daily_pnl)
+                .or_insert(mark.daily_pnl);
+        }
+
+        let mut realized = Vec::new();
+        if self.config.realize_daily_pnl {
+            for ((account_id, _), pnl) in account_pnl {
+                if pnl.is_zero() {
+                    continue;
+                }
+                if self.realize(account_id, pnl, ts)? {
+                    realized.push((account_id, pnl));
+                }
+            }
+        }
+
+        let stats = DailyStats {
+            session_close: ts,
+            positions_marked: marks.len(),
+            positions_unmarked: missing_prices.len(),
+            pnl: pnl_by_currency.into_values().collect(),
+        };
+        state.history.push(stats.clone());
+        drop(state);
+
+        if !missing_prices.is_empty() {
+            log::warn!("No settlement price for {missing_prices:?}");
+        }
+        log::info!(
+            "End-of-day settlement marked {} position(s), realized {} account PnL(s)",
+            marks.len(),
+            realized.len()
+        );
+
+        let report = EodReport {
+            marks,
+            missing_prices,
+            realized,
+            stats,
+        };
+        msgbus::publish_any(get_eod_topic(), &report);
+        Ok(report)
+    }
+
+    /// Starts daily settlement at each session close on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock) -> anyhow::Result<()> {
+        let now = clock.timestamp_ns();
+        let mut next_close = self.config.session.close_for(now);
+        if next_close <= now {
+            next_close += NANOSECONDS_IN_DAY;
+        }
+
+        let process = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            if let Err(e) = process.run(event.ts_event) {
+                log::error!("End-of-day settlement failed: {e}");
+            }
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            NANOSECONDS_IN_DAY,
+            Some(next_close),
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            Some(true),
+            None,
+        )
+    }
+
+    /// Stops daily settlement on `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+    }
+
+    /// Realizes `pnl` into the balance of a margin account, returning whether it was applied.
+    fn realize(&self, account_id: AccountId, pnl: Money, ts: UnixNanos) -> anyhow::Result<bool> {
+        let mut cache = self.cache.borrow_mut();
+        let Some(mut account) = cache.account(&account_id).cloned() else {
+            log::warn!("Cannot realize daily PnL: account {account_id} not found");
+            return Ok(false);
+        };
+        if !matches!(account, AccountAny::Margin(_)) {
+            return Ok(false);
+        }
+        let Some(mut state) = account.last_event() else {
+            return Ok(false);
+        };
+        let Some(balance) = state
+            .balances
+            .iter_mut()
+            .find(|balance| balance.currency == pnl.currency)
+        else {
+            log::warn!(
+                "Cannot realize daily PnL: account {account_id} has no {} balance",
+                pnl.currency
+            );
+            return Ok(false);
+        };
+
+        *balance =
+            AccountBalance::new_checked(balance.total + pnl, balance.locked, balance.free + pnl)?;
+        state.is_reported = false;
+        state.event_id = UUID4::new();
+        state.ts_event = ts;
+        state.ts_init = ts;
+        account.apply(state)?;
+        cache.update_account(account)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        accounts::stubs::margin_account,
+        enums::{OmsType, OrderSide, OrderType},
+        events::{OrderEventAny, account::stubs::margin_account_state},
+        instruments::{Instrument, InstrumentAny, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    const HOUR: u64 = 3_600_000_000_000;
+
+    fn setup(realize: bool) -> (EndOfDayProcess, Rc<RefCell<Cache>>, InstrumentAny) {
+        let mut cache = Cache::default();
+        let account = margin_account(margin_account_state());
+        cache.add_account(AccountAny::Margin(account)).unwrap();
+
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let OrderEventAny::Filled(mut fill) = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::from("P-1")),
+            Some(Price::from("1.00000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        fill.account_id = margin_account_state().account_id;
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(cache));
+        let config = EodConfig {
+            session: TradingSession::new(9 * HOUR, 17 * HOUR),
+            fallback_price_type: None,
+            realize_daily_pnl: realize,
+        };
+        (
+            EndOfDayProcess::new(cache.clone(), config),
+            cache,
+            instrument,
+        )
+    }
+
+    #[rstest]
+    fn test_daily_pnl_is_change_since_previous_settlement() {
+        let (process, _, instrument) = setup(false);
+
+        process.set_settlement_price(instrument.id(), Price::from("1.00010"));
+        let day1 = process.run(1.into()).unwrap();
+        assert_eq!(day1.marks[0].daily_pnl, Money::from("10.00 USD"));
+
+        process.set_settlement_price(instrument.id(), Price::from("1.00005"));
+        let day2 = process.run(2.into()).unwrap();
+        assert_eq!(day2.marks[0].daily_pnl, Money::from("-5.00 USD"));
+        assert!(day2.realized.is_empty());
+
+        // No price provided and no fallback configured
+        let day3 = process.run(3.into()).unwrap();
+        assert!(day3.marks.is_empty());
+        assert_eq!(day3.missing_prices, vec![instrument.id()]);
+        assert_eq!(process.daily_stats().len(), 3);
+    }
+
+    #[rstest]
+    fn test_daily_pnl_realized_into_margin_account_at_session_close() {
+        let (process, cache, instrument) = setup(true);
+        let account_id = margin_account_state().account_id;
+        let usd = Currency::USD();
+        let total_before = cache.borrow().account(&account_id).unwrap().balances()[&usd].total;
+
+        let mut clock = TestClock::new();
+        process.start(&mut clock).unwrap();
+        process.set_settlement_price(instrument.id(), Price::from("1.00010"));
+
+        let events = clock.advance_time((17 * HOUR).into(), true);
+        assert_eq!(events.len(), 1);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let total_after = cache.borrow().account(&account_id).unwrap().balances()[&usd].total;
+        assert_eq!(total_after, total_before + Money::from("10.00 USD"));
+        assert_eq!(
+            process.daily_stats()[0].session_close,
+            UnixNanos::from(17 * HOUR)
+        );
+    }
+}
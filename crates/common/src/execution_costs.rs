@@ -0,0 +1,360 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-fill slippage and spread cost accounting.
+//!
+//! The [`ExecutionCostTracker`] snapshots the top of book when each order is submitted, then
+//! measures every fill of the order against that arrival quote. The slippage of a fill is its
+//! cost versus the arrival mid, of which the spread cost is the part explained by crossing half
+//! the arrival spread. Costs are in the fill currency, signed so that positive values are a cost,
+//! and aggregated per strategy so reports can separate alpha from execution costs.
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::OrderSide,
+    events::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId, TradeId},
+    instruments::Instrument,
+    types::Currency,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{cache::Cache, messages::execution::SubmitOrder};
+
+#[derive(Clone, Copy, Debug)]
+struct Arrival {
+    mid: f64,
+    half_spread: f64,
+    multiplier: f64,
+}
+
+/// The execution costs of a single fill.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FillCost {
+    pub trade_id: TradeId,
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub fill_px: f64,
+    pub arrival_mid: f64,
+    /// The fill notional (including the instrument multiplier).
+    pub notional: f64,
+    /// The cost of the fill versus the arrival mid.
+    pub slippage: f64,
+    /// The cost of crossing half the arrival spread.
+    pub spread_cost: f64,
+    pub currency: Currency,
+    pub ts_event: UnixNanos,
+}
+
+impl FillCost {
+    /// Returns the slippage not explained by the spread (e.g. market impact and latency).
+    #[must_use]
+    pub fn excess_slippage(&self) -> f64 {
+        self.slippage - self.spread_cost
+    }
+
+    /// Returns the slippage in basis points of the fill notional.
+    #[must_use]
+    pub fn slippage_bps(&self) -> f64 {
+        if self.notional == 0.0 {
+            0.0
+        } else {
+            self.slippage / self.notional * 10_000.0
+        }
+    }
+}
+
+/// Aggregate execution costs for a strategy in one currency.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionCostStatistics {
+    pub strategy_id: StrategyId,
+    pub currency: Currency,
+    pub fill_count: usize,
+    pub notional: f64,
+    pub slippage: f64,
+    pub spread_cost: f64,
+}
+
+impl ExecutionCostStatistics {
+    fn new(strategy_id: StrategyId, currency: Currency) -> Self {
+        Self {
+            strategy_id,
+            currency,
+            fill_count: 0,
+            notional: 0.0,
+            slippage: 0.0,
+            spread_cost: 0.0,
+        }
+    }
+
+    /// Returns the slippage not explained by the spread.
+    #[must_use]
+    pub fn excess_slippage(&self) -> f64 {
+        self.slippage - self.spread_cost
+    }
+
+    /// Returns the notional-weighted slippage in basis points.
+    #[must_use]
+    pub fn slippage_bps(&self) -> f64 {
+        if self.notional == 0.0 {
+            0.0
+        } else {
+            self.slippage / self.notional * 10_000.0
+        }
+    }
+
+    /// Returns the PnL before execution costs for the realized `pnl` (the alpha of the strategy).
+    #[must_use]
+    pub fn pnl_before_costs(&self, pnl: f64) -> f64 {
+        pnl + self.slippage
+    }
+}
+
+/// Measures fills against the top of book at order arrival.
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionCostTracker {
+    arrivals: AHashMap<ClientOrderId, Arrival>,
+    fills: Vec<FillCost>,
+    statistics: AHashMap<(StrategyId, Currency), ExecutionCostStatistics>,
+}
+
+impl ExecutionCostTracker {
+    /// Creates a new [`ExecutionCostTracker`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the arrival quote for a submitted order from the latest quote in the `cache`,
+    /// returning whether a quote was available.
+    pub fn on_submit(&mut self, command: &SubmitOrder, cache: &Cache) -> bool {
+        let Some(quote) = cache.quote(&command.instrument_id) else {
+            log::debug!(
+                "No arrival quote for order {}, execution costs not measured",
+                command.client_order_id
+            );
+            return false;
+        };
+        let multiplier = cache
+            .instrument(&command.instrument_id)
+            .map_or(1.0, |instrument| instrument.multiplier().as_f64());
+        let bid = quote.bid_price.as_f64();
+        let ask = quote.ask_price.as_f64();
+
+        self.arrivals.insert(
+            command.client_order_id,
+            Arrival {
+                mid: (bid + ask) / 2.0,
+                half_spread: (ask - bid) / 2.0,
+                multiplier,
+            },
+        );
+        true
+    }
+
+    /// Measures the `fill` against its order's arrival quote, returning the recorded cost.
+    pub fn on_fill(&mut self, fill: &OrderFilled) -> Option<FillCost> {
+        let arrival = self.arrivals.get(&fill.client_order_id)?;
+        let direction = match fill.order_side {
+            OrderSide::Sell => -1.0,
+            _ => 1.0,
+        };
+        let quantity = fill.last_qty.as_f64();
+        let fill_px = fill.last_px.as_f64();
+        let units = quantity * arrival.multiplier;
+
+        let cost = FillCost {
+            trade_id: fill.trade_id,
+            client_order_id: fill.client_order_id,
+            instrument_id: fill.instrument_id,
+            strategy_id: fill.strategy_id,
+            side: fill.order_side,
+            quantity,
+            fill_px,
+            arrival_mid: arrival.mid,
+            notional: fill_px * units,
+            slippage: direction * (fill_px - arrival.mid) * units,
+            spread_cost: arrival.half_spread * units,
+            currency: fill.currency,
+            ts_event: fill.ts_event,
+        };
+
+        let stats = self
+            .statistics
+            .entry((cost.strategy_id, cost.currency))
+            .or_insert_with(|| ExecutionCostStatistics::new(cost.strategy_id, cost.currency));
+        stats.fill_count += 1;
+        stats.notional += cost.notional;
+        stats.slippage += cost.slippage;
+        stats.spread_cost += cost.spread_cost;
+        self.fills.push(cost);
+
+        Some(cost)
+    }
+
+    /// Stops measuring fills of the order (e.g. once closed).
+    pub fn complete(&mut self, client_order_id: &ClientOrderId) {
+        self.arrivals.remove(client_order_id);
+    }
+
+    /// Returns the recorded fill costs, optionally for a single strategy.
+    #[must_use]
+    pub fn fill_costs(&self, strategy_id: Option<&StrategyId>) -> Vec<&FillCost> {
+        self.fills
+            .iter()
+            .filter(|cost| strategy_id.is_none_or(|id| cost.strategy_id == *id))
+            .collect()
+    }
+
+    /// Returns the aggregate execution costs, ordered by strategy and currency.
+    #[must_use]
+    pub fn statistics(&self) -> Vec<ExecutionCostStatistics> {
+        let mut statistics: Vec<ExecutionCostStatistics> =
+            self.statistics.values().copied().collect();
+        statistics.sort_by(|a, b| {
+            (a.strategy_id.as_str(), a.currency.code.as_str())
+                .cmp(&(b.strategy_id.as_str(), b.currency.code.as_str()))
+        });
+        statistics
+    }
+
+    /// Clears all recorded arrivals, fills and statistics.
+    pub fn reset(&mut self) {
+        self.arrivals.clear();
+        self.fills.clear();
+        self.statistics.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::OrderType,
+        instruments::{InstrumentAny, stubs::audusd_sim},
+        orders::{Order, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cache_with_quote() -> Cache {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache
+            .add_quote(QuoteTick::new(
+                instrument.id(),
+                Price::from("1.00000"),
+                Price::from("1.00020"),
+                Quantity::from(1_000_000),
+                Quantity::from(1_000_000),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
+            .unwrap();
+        cache
+    }
+
+    fn submit(side: OrderSide) -> SubmitOrder {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim().id)
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .build();
+        SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn fill(command: &SubmitOrder, px: &str) -> OrderFilled {
+        OrderFilled {
+            client_order_id: command.client_order_id,
+            strategy_id: command.strategy_id,
+            instrument_id: command.instrument_id,
+            order_side: command.order_init.order_side,
+            last_px: Price::from(px),
+            last_qty: Quantity::from(50_000),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1.00030", 10.0, 5.0)]
+    #[case(OrderSide::Sell, "1.00000", 5.0, 5.0)]
+    #[case(OrderSide::Buy, "1.00005", -2.5, 5.0)]
+    fn test_fill_cost_versus_arrival_mid(
+        #[case] side: OrderSide,
+        #[case] px: &str,
+        #[case] slippage: f64,
+        #[case] spread_cost: f64,
+    ) {
+        let cache = cache_with_quote();
+        let mut tracker = ExecutionCostTracker::new();
+        let command = submit(side);
+        assert!(tracker.on_submit(&command, &cache));
+
+        let cost = tracker.on_fill(&fill(&command, px)).unwrap();
+
+        assert!((cost.slippage - slippage).abs() < 1e-6);
+        assert!((cost.spread_cost - spread_cost).abs() < 1e-6);
+        assert!((cost.excess_slippage() - (slippage - spread_cost)).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_statistics_aggregate_per_strategy() {
+        let cache = cache_with_quote();
+        let mut tracker = ExecutionCostTracker::new();
+        let command = submit(OrderSide::Buy);
+        tracker.on_submit(&command, &cache);
+
+        tracker.on_fill(&fill(&command, "1.00020"));
+        tracker.on_fill(&fill(&command, "1.00030"));
+        tracker.complete(&command.client_order_id);
+
+        let statistics = tracker.statistics();
+        assert_eq!(statistics.len(), 1);
+        assert_eq!(statistics[0].fill_count, 2);
+        assert!((statistics[0].slippage - 15.0).abs() < 1e-6);
+        assert!((statistics[0].pnl_before_costs(-20.0) - (-5.0)).abs() < 1e-6);
+        assert_eq!(tracker.fill_costs(Some(&command.strategy_id)).len(), 2);
+        assert!(tracker.on_fill(&fill(&command, "1.00030")).is_none());
+    }
+
+    #[rstest]
+    fn test_on_submit_without_quote() {
+        let mut tracker = ExecutionCostTracker::new();
+        let command = submit(OrderSide::Buy);
+
+        assert!(!tracker.on_submit(&command, &Cache::default()));
+        assert!(tracker.on_fill(&fill(&command, "1.00000")).is_none());
+    }
+}
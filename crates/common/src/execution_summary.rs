@@ -0,0 +1,363 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Aggregation of an order's fills into a single execution summary.
+//!
+//! When an order with at least one fill reaches a terminal state, the [`ExecutionSummarizer`]
+//! folds its partial fills into an [`ExecutionSummary`] (average price, total quantity, total
+//! fees per currency, and the time from the first to the last fill), publishes it on the
+//! strategy's execution summary topic, and persists it in the cache's general store so reporting
+//! can consume one record per order instead of raw fills.
+
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus, OrderType},
+    events::OrderEventAny,
+    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+    orders::{Order, OrderAny},
+    types::{Currency, Money, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    msgbus::{self, MStr, Topic, TypedHandler},
+};
+
+/// The general cache key prefix summaries are persisted under.
+pub const EXECUTION_SUMMARY_KEY_PREFIX: &str = "execution_summary.";
+
+/// Returns the topic execution summaries for `strategy_id` are published on.
+#[must_use]
+pub fn get_execution_summary_topic(strategy_id: StrategyId) -> MStr<Topic> {
+    format!("events.execution_summary.{strategy_id}").into()
+}
+
+/// The aggregated fills of a single order which reached a terminal state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub account_id: Option<AccountId>,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    /// The terminal status of the order.
+    pub status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    /// The fill quantity weighted average price.
+    pub avg_px: f64,
+    pub fill_count: usize,
+    /// The total fees, one entry per currency.
+    pub commissions: Vec<Money>,
+    /// UNIX timestamp (nanoseconds) when the order was initialized.
+    pub ts_init: UnixNanos,
+    /// UNIX timestamp (nanoseconds) of the first fill.
+    pub ts_first_fill: UnixNanos,
+    /// UNIX timestamp (nanoseconds) of the last fill.
+    pub ts_last_fill: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the order reached its terminal state.
+    pub ts_closed: UnixNanos,
+}
+
+impl ExecutionSummary {
+    /// Summarizes the fills of `order`.
+    ///
+    /// Returns `None` if the order is not closed or has no fills.
+    #[must_use]
+    pub fn from_order(order: &OrderAny) -> Option<Self> {
+        if !order.is_closed() {
+            return None;
+        }
+
+        let fills: Vec<_> = order
+            .events()
+            .into_iter()
+            .filter_map(|event| match event {
+                OrderEventAny::Filled(fill) => Some(fill),
+                _ => None,
+            })
+            .collect();
+        let first = fills.first()?;
+        let last = fills.last()?;
+
+        let (notional, qty) = fills.iter().fold((0.0, 0.0), |(notional, qty), fill| {
+            let last_qty = fill.last_qty.as_f64();
+            (notional + fill.last_px.as_f64() * last_qty, qty + last_qty)
+        });
+
+        // Summed from the fills, as the order does not accumulate commissions itself
+        let mut commissions: IndexMap<Currency, Money> = IndexMap::new();
+        for commission in fills.iter().filter_map(|fill| fill.commission) {
+            commissions
+                .entry(commission.currency)
+                .and_modify(|total| *total = *total + commission)
+                .or_insert(commission);
+        }
+
+        Some(Self {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: order.venue_order_id(),
+            account_id: order.account_id(),
+            side: order.order_side(),
+            order_type: order.order_type(),
+            status: order.status(),
+            quantity: order.quantity(),
+            filled_qty: order.filled_qty(),
+            avg_px: notional / qty,
+            fill_count: fills.len(),
+            commissions: commissions.into_values().collect(),
+            ts_init: order.ts_init(),
+            ts_first_fill: first.ts_event,
+            ts_last_fill: last.ts_event,
+            ts_closed: order.ts_last(),
+        })
+    }
+
+    /// Returns the time from the first to the last fill (nanoseconds).
+    #[must_use]
+    pub fn fill_duration_ns(&self) -> u64 {
+        self.ts_last_fill.as_u64() - self.ts_first_fill.as_u64()
+    }
+
+    /// Returns the time from initialization to the terminal state (nanoseconds).
+    #[must_use]
+    pub fn duration_ns(&self) -> u64 {
+        self.ts_closed
+            .as_u64()
+            .saturating_sub(self.ts_init.as_u64())
+    }
+}
+
+impl Display for ExecutionSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(client_order_id={}, status={}, filled_qty={}, avg_px={}, fills={})",
+            stringify!(ExecutionSummary),
+            self.client_order_id,
+            self.status,
+            self.filled_qty,
+            self.avg_px,
+            self.fill_count,
+        )
+    }
+}
+
+/// Summarizes orders as they reach terminal states.
+#[derive(Clone)]
+pub struct ExecutionSummarizer {
+    cache: Rc<RefCell<Cache>>,
+    handler: Rc<RefCell<Option<TypedHandler<OrderEventAny>>>>,
+}
+
+impl std::fmt::Debug for ExecutionSummarizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ExecutionSummarizer))
+            .field("subscribed", &self.handler.borrow().is_some())
+            .finish()
+    }
+}
+
+impl ExecutionSummarizer {
+    const ORDER_EVENTS_PATTERN: &str = "events.order.*";
+
+    /// Creates a new [`ExecutionSummarizer`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            cache,
+            handler: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Subscribes to order events.
+    pub fn subscribe(&self) {
+        if self.handler.borrow().is_some() {
+            log::warn!("Execution summarizer already subscribed");
+            return;
+        }
+
+        let summarizer = self.clone();
+        let handler = TypedHandler::from(move |event: &OrderEventAny| {
+            if let Err(e) = summarizer.on_order_event(event) {
+                log::error!("Failed to summarize {}: {e}", event.client_order_id());
+            }
+        });
+        msgbus::subscribe_order_events(Self::ORDER_EVENTS_PATTERN.into(), handler.clone(), None);
+        *self.handler.borrow_mut() = Some(handler);
+    }
+
+    /// Unsubscribes from order events.
+    pub fn unsubscribe(&self) {
+        if let Some(handler) = self.handler.borrow_mut().take() {
+            msgbus::unsubscribe_order_events(Self::ORDER_EVENTS_PATTERN.into(), &handler);
+        }
+    }
+
+    /// Handles an order `event`, returning the summary if it closed an order with fills.
+    ///
+    /// The summary is persisted and published.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the summary fails.
+    pub fn on_order_event(
+        &self,
+        event: &OrderEventAny,
+    ) -> anyhow::Result<Option<ExecutionSummary>> {
+        if !matches!(
+            event,
+            OrderEventAny::Filled(_)
+                | OrderEventAny::Canceled(_)
+                | OrderEventAny::Expired(_)
+                | OrderEventAny::Rejected(_)
+        ) {
+            return Ok(None);
+        }
+
+        let summary = {
+            let cache = self.cache.borrow();
+            let Some(order) = cache.order(&event.client_order_id()) else {
+                return Ok(None);
+            };
+            match ExecutionSummary::from_order(order) {
+                Some(summary) => summary,
+                None => return Ok(None),
+            }
+        };
+
+        let key = format!("{EXECUTION_SUMMARY_KEY_PREFIX}{}", summary.client_order_id);
+        self.cache
+            .borrow_mut()
+            .add(&key, Bytes::from(serde_json::to_vec(&summary)?))?;
+
+        log::debug!("{summary}");
+        msgbus::publish_any(get_execution_summary_topic(summary.strategy_id), &summary);
+        Ok(Some(summary))
+    }
+}
+
+/// Returns the execution summaries persisted in `cache`, ordered by `ts_closed`.
+///
+/// # Errors
+///
+/// Returns an error if a persisted summary fails to decode.
+pub fn execution_summaries(cache: &Cache) -> anyhow::Result<Vec<ExecutionSummary>> {
+    let mut summaries = cache
+        .general_with_prefix(EXECUTION_SUMMARY_KEY_PREFIX)
+        .into_iter()
+        .map(|(_, value)| Ok(serde_json::from_slice(value)?))
+        .collect::<anyhow::Result<Vec<ExecutionSummary>>>()?;
+    summaries.sort_by_key(|s| s.ts_closed);
+    Ok(summaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::LiquiditySide,
+        identifiers::TradeId,
+        instruments::{CurrencyPair, Instrument, InstrumentAny, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn partially_filled_order(instrument: &InstrumentAny) -> OrderAny {
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(300_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        for (i, (px, ts)) in [("1.00000", 10_u64), ("0.99990", 25)]
+            .into_iter()
+            .enumerate()
+        {
+            let fill = TestOrderEventStubs::filled(
+                &order,
+                instrument,
+                Some(TradeId::new(format!("T-{i}"))),
+                None,
+                Some(Price::from(px)),
+                Some(Quantity::from(100_000)),
+                Some(LiquiditySide::Maker),
+                Some(Money::new(2.0, Currency::USD())),
+                Some(UnixNanos::from(ts)),
+                Some(account_id),
+            );
+            order.apply(fill).unwrap();
+        }
+        order
+    }
+
+    #[rstest]
+    fn test_summary_requires_terminal_order(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = partially_filled_order(&instrument);
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert!(ExecutionSummary::from_order(&order).is_none());
+    }
+
+    #[rstest]
+    fn test_summarizer_on_cancel_after_partial_fills(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut order = partially_filled_order(&instrument);
+        let canceled = TestOrderEventStubs::canceled(&order, AccountId::from("SIM-001"), None);
+        order.apply(canceled.clone()).unwrap();
+
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_order(order, None, None, false)
+            .unwrap();
+        let summarizer = ExecutionSummarizer::new(cache.clone());
+
+        let summary = summarizer.on_order_event(&canceled).unwrap().unwrap();
+
+        assert_eq!(summary.status, OrderStatus::Canceled);
+        assert_eq!(summary.fill_count, 2);
+        assert_eq!(summary.filled_qty, Quantity::from(200_000));
+        assert!((summary.avg_px - 0.99995).abs() < 1e-9);
+        assert_eq!(summary.commissions, vec![Money::new(4.0, Currency::USD())]);
+        assert_eq!(summary.fill_duration_ns(), 15);
+        assert_eq!(execution_summaries(&cache.borrow()).unwrap(), vec![summary]);
+    }
+}
@@ -17,6 +17,10 @@
 
 pub mod event;
 pub mod order;
+pub mod position;
 
 pub use event::OrderEventFactory;
 pub use order::OrderFactory;
+pub use position::{
+    PositionAdjustment, check_reduce_only, net_position, order_qty_to_target_position,
+};
@@ -33,7 +33,9 @@ use nautilus_model::{
 use ustr::Ustr;
 
 use crate::{
+    cache::Cache,
     clock::Clock,
+    factories::position::order_qty_to_target_position,
     generators::{client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator},
 };
 
@@ -153,6 +155,56 @@ impl OrderFactory {
         OrderAny::Market(order)
     }
 
+    /// Creates a market order moving this strategy's net position in `instrument_id` to the
+    /// signed `target` quantity, or `None` if no order is required.
+    ///
+    /// The quantity is rounded down to the instrument's size increment, and the order is flagged
+    /// `reduce_only` when it only reduces the position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instrument is not found in `cache`, or `target` is invalid.
+    pub fn market_to_target_position(
+        &mut self,
+        cache: &Cache,
+        instrument_id: InstrumentId,
+        target: f64,
+        time_in_force: Option<TimeInForce>,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<Option<OrderAny>> {
+        let adjustment =
+            order_qty_to_target_position(cache, &instrument_id, Some(&self.strategy_id), target)?;
+        Ok(adjustment.map(|adjustment| {
+            self.market(
+                instrument_id,
+                adjustment.side,
+                adjustment.quantity,
+                time_in_force,
+                Some(adjustment.reduce_only),
+                None,
+                None,
+                None,
+                tags,
+                None,
+            )
+        }))
+    }
+
+    /// Creates a `reduce_only` market order closing this strategy's net position in
+    /// `instrument_id`, or `None` if the position is already flat.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instrument is not found in `cache`.
+    pub fn flatten_position(
+        &mut self,
+        cache: &Cache,
+        instrument_id: InstrumentId,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<Option<OrderAny>> {
+        self.market_to_target_position(cache, instrument_id, 0.0, None, tags)
+    }
+
     /// Creates a new limit order.
     #[allow(clippy::too_many_arguments)]
     pub fn limit(
@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Position-aware order sizing.
+//!
+//! Computes the order required to move a strategy's net position in an instrument to a target,
+//! rounded down to the instrument's size increment, and enforces `reduce_only` against the
+//! open positions held in the cache.
+
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{InstrumentId, StrategyId},
+    instruments::Instrument,
+    orders::{Order, OrderAny},
+    types::Quantity,
+};
+
+use crate::cache::Cache;
+
+/// The order required to move a net position to a target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionAdjustment {
+    pub instrument_id: InstrumentId,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// Whether the order only reduces the position (never opens or flips it).
+    pub reduce_only: bool,
+}
+
+/// Returns the signed net quantity of open positions for `instrument_id`, optionally filtered by
+/// `strategy_id`.
+#[must_use]
+pub fn net_position(
+    cache: &Cache,
+    instrument_id: &InstrumentId,
+    strategy_id: Option<&StrategyId>,
+) -> f64 {
+    cache
+        .positions_open(None, Some(instrument_id), strategy_id, None, None)
+        .iter()
+        .map(|position| position.signed_qty)
+        .sum()
+}
+
+/// Returns the order required to move the net position for `instrument_id` to the signed
+/// `target` quantity, or `None` if the difference is smaller than the instrument's size
+/// increment.
+///
+/// The quantity is rounded down to the size increment, so the resulting position never
+/// overshoots the target.
+///
+/// # Errors
+///
+/// Returns an error if the instrument is not found in the cache, or `target` is not finite.
+pub fn order_qty_to_target_position(
+    cache: &Cache,
+    instrument_id: &InstrumentId,
+    strategy_id: Option<&StrategyId>,
+    target: f64,
+) -> anyhow::Result<Option<PositionAdjustment>> {
+    if !target.is_finite() {
+        anyhow::bail!("Invalid target position for {instrument_id}: {target}");
+    }
+    let Some(instrument) = cache.instrument(instrument_id) else {
+        anyhow::bail!("Instrument {instrument_id} not found in cache");
+    };
+
+    let current = net_position(cache, instrument_id, strategy_id);
+    let delta = target - current;
+    if delta.abs() < instrument.size_increment().as_f64() {
+        return Ok(None);
+    }
+
+    let side = if delta > 0.0 {
+        OrderSide::Buy
+    } else {
+        OrderSide::Sell
+    };
+    let reduce_only = current != 0.0 && target * current >= 0.0 && target.abs() < current.abs();
+
+    Ok(Some(PositionAdjustment {
+        instrument_id: *instrument_id,
+        side,
+        quantity: instrument.try_make_qty(delta.abs(), Some(true))?,
+        reduce_only,
+    }))
+}
+
+/// Checks that a `reduce_only` order can only reduce the net position of its strategy.
+///
+/// Orders which are not `reduce_only` always pass.
+///
+/// # Errors
+///
+/// Returns an error if the order is on the same side as the position (or there is no position),
+/// or its leaves quantity exceeds the position quantity.
+pub fn check_reduce_only(cache: &Cache, order: &OrderAny) -> anyhow::Result<()> {
+    if !order.is_reduce_only() {
+        return Ok(());
+    }
+
+    let instrument_id = order.instrument_id();
+    let net = net_position(cache, &instrument_id, Some(&order.strategy_id()));
+    let reduces = match order.order_side() {
+        OrderSide::Buy => net < 0.0,
+        OrderSide::Sell => net > 0.0,
+        OrderSide::NoOrderSide => false,
+    };
+    if !reduces {
+        anyhow::bail!(
+            "Reduce-only order {} would increase position for {instrument_id} (net {net})",
+            order.client_order_id()
+        );
+    }
+    if order.leaves_qty().as_f64() > net.abs() {
+        anyhow::bail!(
+            "Reduce-only order {} quantity {} exceeds position for {instrument_id} (net {net})",
+            order.client_order_id(),
+            order.leaves_qty()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::{
+        enums::{OmsType, OrderType},
+        events::OrderEventAny,
+        identifiers::{
+            PositionId,
+            stubs::{strategy_id_ema_cross, trader_id},
+        },
+        instruments::{InstrumentAny, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, factories::OrderFactory};
+
+    fn cache_with_position(side: OrderSide, quantity: &str) -> Cache {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .strategy_id(strategy_id_ema_cross())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        let position = Position::new(&instrument, fill);
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache
+    }
+
+    fn order_factory() -> OrderFactory {
+        OrderFactory::new(
+            trader_id(),
+            strategy_id_ema_cross(),
+            None,
+            None,
+            Rc::new(RefCell::new(TestClock::new())),
+            false,
+            true,
+        )
+    }
+
+    #[rstest]
+    #[case(2_500.7, OrderSide::Buy, "1500", false)]
+    #[case(400.0, OrderSide::Sell, "600", true)]
+    #[case(0.0, OrderSide::Sell, "1000", true)]
+    #[case(-500.0, OrderSide::Sell, "1500", false)] // Flips the position
+    fn test_order_qty_to_target_position(
+        #[case] target: f64,
+        #[case] side: OrderSide,
+        #[case] quantity: &str,
+        #[case] reduce_only: bool,
+    ) {
+        let cache = cache_with_position(OrderSide::Buy, "1000");
+        let instrument_id = audusd_sim().id;
+
+        let adjustment = order_qty_to_target_position(&cache, &instrument_id, None, target)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(adjustment.side, side);
+        assert_eq!(adjustment.quantity, Quantity::from(quantity));
+        assert_eq!(adjustment.reduce_only, reduce_only);
+    }
+
+    #[rstest]
+    fn test_order_qty_to_target_position_below_increment() {
+        let cache = cache_with_position(OrderSide::Buy, "1000");
+
+        let adjustment =
+            order_qty_to_target_position(&cache, &audusd_sim().id, None, 1_000.4).unwrap();
+
+        assert!(adjustment.is_none());
+    }
+
+    #[rstest]
+    fn test_order_qty_to_target_position_unknown_instrument() {
+        let cache = Cache::default();
+
+        let result = order_qty_to_target_position(&cache, &audusd_sim().id, None, 100.0);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_flatten_position_creates_reduce_only_order() {
+        let cache = cache_with_position(OrderSide::Sell, "2000");
+        let mut factory = order_factory();
+
+        let order = factory
+            .flatten_position(&cache, audusd_sim().id, None)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(order.order_side(), OrderSide::Buy);
+        assert_eq!(order.quantity(), Quantity::from("2000"));
+        assert!(order.is_reduce_only());
+        assert!(check_reduce_only(&cache, &order).is_ok());
+    }
+
+    #[rstest]
+    fn test_flatten_flat_position_returns_none() {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let mut factory = order_factory();
+
+        let order = factory
+            .flatten_position(&cache, audusd_sim().id, None)
+            .unwrap();
+
+        assert!(order.is_none());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, "1000", true)]
+    #[case(OrderSide::Sell, "1001", false)] // Exceeds position
+    #[case(OrderSide::Buy, "100", false)] // Increases position
+    fn test_check_reduce_only(#[case] side: OrderSide, #[case] quantity: &str, #[case] ok: bool) {
+        let cache = cache_with_position(OrderSide::Buy, "1000");
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim().id)
+            .strategy_id(strategy_id_ema_cross())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .reduce_only(true)
+            .build();
+
+        assert_eq!(check_reduce_only(&cache, &order).is_ok(), ok);
+    }
+}
@@ -0,0 +1,420 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Failover routing of new orders away from disconnected or degraded execution clients.
+//!
+//! A [`FailoverGroup`] assigns a set of instruments to a primary execution client with ordered
+//! backups. The [`FailoverRouter`] tracks the health of each client and routes new orders for the
+//! group's instruments to the first healthy client, remapping the instrument to its symbol on the
+//! backup venue where configured. Each change of a group's active client is recorded as a
+//! [`FailoverEvent`] and published on the group's failover topic.
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::identifiers::{ClientId, InstrumentId};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use ustr::Ustr;
+
+use crate::{
+    messages::execution::SubmitOrder,
+    msgbus::{self, MStr, Topic},
+};
+
+/// The connectivity health of an execution client.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClientHealth {
+    #[default]
+    Connected,
+    /// Connected but impaired (e.g. elevated latency or rejects), used only if no client is
+    /// fully connected.
+    Degraded,
+    Disconnected,
+}
+
+/// A group of instruments routed to a primary execution client with ordered backups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailoverGroup {
+    pub name: Ustr,
+    pub instruments: Vec<InstrumentId>,
+    pub primary: ClientId,
+    pub backups: Vec<ClientId>,
+    /// The instrument to trade on a given client in place of the configured instrument.
+    pub symbol_map: AHashMap<(InstrumentId, ClientId), InstrumentId>,
+}
+
+impl FailoverGroup {
+    /// Creates a new [`FailoverGroup`] instance.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        instruments: Vec<InstrumentId>,
+        primary: ClientId,
+        backups: Vec<ClientId>,
+    ) -> Self {
+        Self {
+            name: Ustr::from(name),
+            instruments,
+            primary,
+            backups,
+            symbol_map: AHashMap::new(),
+        }
+    }
+
+    /// Maps `instrument_id` to `mapped_instrument_id` when routed to `client_id`.
+    #[must_use]
+    pub fn with_mapping(
+        mut self,
+        instrument_id: InstrumentId,
+        client_id: ClientId,
+        mapped_instrument_id: InstrumentId,
+    ) -> Self {
+        self.symbol_map
+            .insert((instrument_id, client_id), mapped_instrument_id);
+        self
+    }
+
+    fn clients(&self) -> impl Iterator<Item = &ClientId> {
+        std::iter::once(&self.primary).chain(self.backups.iter())
+    }
+}
+
+/// Represents a change of the active execution client for a failover group.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailoverEvent {
+    pub group: Ustr,
+    /// The previously active client, if any.
+    pub from: Option<ClientId>,
+    /// The newly active client, or `None` if no client is available.
+    pub to: Option<ClientId>,
+    /// The client whose health change caused the failover.
+    pub trigger: ClientId,
+    pub health: ClientHealth,
+    pub ts_event: UnixNanos,
+}
+
+/// Returns the failover topic for the group `name`.
+#[must_use]
+pub fn get_failover_topic(name: Ustr) -> MStr<Topic> {
+    format!("events.failover.{name}").into()
+}
+
+/// Routes new orders within failover groups to the first healthy execution client.
+#[derive(Clone, Debug, Default)]
+pub struct FailoverRouter {
+    groups: Vec<FailoverGroup>,
+    by_instrument: AHashMap<InstrumentId, usize>,
+    health: AHashMap<ClientId, ClientHealth>,
+    active: AHashMap<Ustr, Option<ClientId>>,
+    trail: Vec<FailoverEvent>,
+}
+
+impl FailoverRouter {
+    /// Creates a new [`FailoverRouter`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a failover group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a group with the same name exists, or any of its instruments already
+    /// belongs to another group.
+    pub fn add_group(&mut self, group: FailoverGroup) -> anyhow::Result<()> {
+        if self.active.contains_key(&group.name) {
+            anyhow::bail!("Failover group {} already exists", group.name);
+        }
+        if let Some(instrument_id) = group
+            .instruments
+            .iter()
+            .find(|instrument_id| self.by_instrument.contains_key(instrument_id))
+        {
+            anyhow::bail!("Instrument {instrument_id} already belongs to a failover group");
+        }
+
+        let index = self.groups.len();
+        for instrument_id in &group.instruments {
+            self.by_instrument.insert(*instrument_id, index);
+        }
+        self.active.insert(group.name, self.select(&group));
+        self.groups.push(group);
+        Ok(())
+    }
+
+    /// Returns the health of `client_id` (clients are connected until reported otherwise).
+    #[must_use]
+    pub fn health(&self, client_id: &ClientId) -> ClientHealth {
+        self.health.get(client_id).copied().unwrap_or_default()
+    }
+
+    /// Updates the health of `client_id`, failing over any group whose active client changes.
+    ///
+    /// Returns the resulting failover events, which are also recorded in the trail and published.
+    pub fn set_health(
+        &mut self,
+        client_id: ClientId,
+        health: ClientHealth,
+        ts_event: UnixNanos,
+    ) -> Vec<FailoverEvent> {
+        if self.health.insert(client_id, health) == Some(health) {
+            return Vec::new();
+        }
+
+        let mut events = Vec::new();
+        for group in &self.groups {
+            if !group.clients().any(|id| *id == client_id) {
+                continue;
+            }
+            let to = self.select(group);
+            let from = self.active.insert(group.name, to).flatten();
+            if from == to {
+                continue;
+            }
+
+            let event = FailoverEvent {
+                group: group.name,
+                from,
+                to,
+                trigger: client_id,
+                health,
+                ts_event,
+            };
+            log::warn!(
+                "Failover group {} active client {from:?} -> {to:?} ({client_id} {health})",
+                group.name
+            );
+            msgbus::publish_any(get_failover_topic(group.name), &event);
+            events.push(event);
+        }
+
+        self.trail.extend(events.iter().cloned());
+        events
+    }
+
+    /// Returns the active client for the group `name`.
+    #[must_use]
+    pub fn active_client(&self, name: &Ustr) -> Option<ClientId> {
+        self.active.get(name).copied().flatten()
+    }
+
+    /// Routes a new order to the active client of its instrument's failover group.
+    ///
+    /// Sets the command's client ID and remaps its instrument for the chosen client. Returns
+    /// `None` (leaving the command unchanged) if the instrument is not in a failover group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no client in the group is available.
+    pub fn route(&self, command: &mut SubmitOrder) -> anyhow::Result<Option<ClientId>> {
+        let Some(group) = self
+            .by_instrument
+            .get(&command.instrument_id)
+            .map(|index| &self.groups[*index])
+        else {
+            return Ok(None);
+        };
+        let Some(client_id) = self.active_client(&group.name) else {
+            anyhow::bail!(
+                "No execution client available in failover group {} for {}",
+                group.name,
+                command.client_order_id
+            );
+        };
+
+        if let Some(mapped) = group.symbol_map.get(&(command.instrument_id, client_id)) {
+            command.instrument_id = *mapped;
+            command.order_init.instrument_id = *mapped;
+        }
+        command.client_id = Some(client_id);
+        Ok(Some(client_id))
+    }
+
+    /// Returns the trail of failover events in the order they occurred.
+    #[must_use]
+    pub fn trail(&self) -> &[FailoverEvent] {
+        &self.trail
+    }
+
+    fn select(&self, group: &FailoverGroup) -> Option<ClientId> {
+        group
+            .clients()
+            .find(|id| self.health(id) == ClientHealth::Connected)
+            .or_else(|| {
+                group
+                    .clients()
+                    .find(|id| self.health(id) == ClientHealth::Degraded)
+            })
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        events::OrderInitialized,
+        identifiers::{ClientOrderId, StrategyId, TraderId},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::ShareableMessageHandler;
+
+    fn router() -> FailoverRouter {
+        let mut router = FailoverRouter::new();
+        router
+            .add_group(
+                FailoverGroup::new(
+                    "crypto",
+                    vec![InstrumentId::from("BTCUSDT-PERP.BINANCE")],
+                    ClientId::from("BINANCE"),
+                    vec![ClientId::from("BYBIT")],
+                )
+                .with_mapping(
+                    InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+                    ClientId::from("BYBIT"),
+                    InstrumentId::from("BTCUSDT-LINEAR.BYBIT"),
+                ),
+            )
+            .unwrap();
+        router
+    }
+
+    fn submit(instrument_id: &str) -> SubmitOrder {
+        let instrument_id = InstrumentId::from(instrument_id);
+        SubmitOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            instrument_id,
+            ClientOrderId::from("O-1"),
+            OrderInitialized {
+                instrument_id,
+                ..Default::default()
+            },
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_routes_to_primary_when_healthy() {
+        let router = router();
+        let mut command = submit("BTCUSDT-PERP.BINANCE");
+
+        let client_id = router.route(&mut command).unwrap();
+
+        assert_eq!(client_id, Some(ClientId::from("BINANCE")));
+        assert_eq!(
+            command.instrument_id,
+            InstrumentId::from("BTCUSDT-PERP.BINANCE")
+        );
+    }
+
+    #[rstest]
+    fn test_fails_over_with_symbol_remapping() {
+        let mut router = router();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        let handler = ShareableMessageHandler::from_typed(move |event: &FailoverEvent| {
+            received_clone.borrow_mut().push(event.clone());
+        });
+        msgbus::subscribe_any(
+            get_failover_topic(Ustr::from("crypto")).into(),
+            handler,
+            None,
+        );
+
+        let events = router.set_health(
+            ClientId::from("BINANCE"),
+            ClientHealth::Disconnected,
+            1.into(),
+        );
+        let mut command = submit("BTCUSDT-PERP.BINANCE");
+        let client_id = router.route(&mut command).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].from, Some(ClientId::from("BINANCE")));
+        assert_eq!(events[0].to, Some(ClientId::from("BYBIT")));
+        assert_eq!(received.borrow().len(), 1);
+        assert_eq!(client_id, Some(ClientId::from("BYBIT")));
+        assert_eq!(command.client_id, Some(ClientId::from("BYBIT")));
+        assert_eq!(
+            command.instrument_id,
+            InstrumentId::from("BTCUSDT-LINEAR.BYBIT")
+        );
+        assert_eq!(
+            command.order_init.instrument_id,
+            InstrumentId::from("BTCUSDT-LINEAR.BYBIT")
+        );
+    }
+
+    #[rstest]
+    fn test_degraded_client_used_only_as_last_resort() {
+        let mut router = router();
+        router.set_health(ClientId::from("BINANCE"), ClientHealth::Degraded, 1.into());
+        router.set_health(
+            ClientId::from("BYBIT"),
+            ClientHealth::Disconnected,
+            2.into(),
+        );
+
+        assert_eq!(
+            router.active_client(&Ustr::from("crypto")),
+            Some(ClientId::from("BINANCE"))
+        );
+
+        router.set_health(
+            ClientId::from("BINANCE"),
+            ClientHealth::Disconnected,
+            3.into(),
+        );
+        let result = router.route(&mut submit("BTCUSDT-PERP.BINANCE"));
+
+        assert!(result.is_err());
+        assert_eq!(router.trail().len(), 3);
+        assert_eq!(router.trail()[2].to, None);
+    }
+
+    #[rstest]
+    fn test_unmapped_instrument_is_not_routed() {
+        let router = router();
+        let mut command = submit("ETHUSDT.BINANCE");
+
+        assert_eq!(router.route(&mut command).unwrap(), None);
+        assert_eq!(command.client_id, None);
+    }
+
+    #[rstest]
+    fn test_instrument_in_single_group_only() {
+        let mut router = router();
+
+        let result = router.add_group(FailoverGroup::new(
+            "other",
+            vec![InstrumentId::from("BTCUSDT-PERP.BINANCE")],
+            ClientId::from("OKX"),
+            vec![],
+        ));
+
+        assert!(result.is_err());
+    }
+}
@@ -165,7 +165,7 @@ pub unsafe extern "C" fn test_clock_set_time_alert(
     };
 
     clock
-        .set_time_alert_ns(name, alert_time_ns, callback, Some(allow_past != 0))
+        .set_time_alert_ns(name, alert_time_ns, callback, Some(allow_past != 0), None)
         .expect(FAILED);
 }
 
@@ -218,6 +218,7 @@ pub unsafe extern "C" fn test_clock_set_timer(
             callback,
             Some(allow_past != 0),
             Some(fire_immediately != 0),
+            None,
         )
         .expect(FAILED);
 }
@@ -420,7 +421,7 @@ pub unsafe extern "C" fn live_clock_set_time_alert(
     };
 
     clock
-        .set_time_alert_ns(name, alert_time_ns, callback, Some(allow_past != 0))
+        .set_time_alert_ns(name, alert_time_ns, callback, Some(allow_past != 0), None)
         .expect(FAILED);
 }
 
@@ -475,6 +476,7 @@ pub unsafe extern "C" fn live_clock_set_timer(
             callback,
             Some(allow_past != 0),
             Some(fire_immediately != 0),
+            None,
         )
         .expect(FAILED);
 }
@@ -0,0 +1,184 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Publication of rolling order-flow imbalance metrics derived from trades and quotes.
+//!
+//! Metrics are published on the instrument's order flow topic, and can be converted to
+//! [`CustomData`] for persistence alongside other custom data types.
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{DataType, OrderFlowImbalance, OrderFlowImbalanceCalculator, QuoteTick, TradeTick},
+    enums::AggressorSide,
+    identifiers::InstrumentId,
+};
+
+use crate::{
+    custom::CustomData,
+    msgbus::{self, MStr, Topic, switchboard::get_order_flow_topic},
+};
+
+/// The custom data type name for [`OrderFlowImbalance`].
+pub const ORDER_FLOW_IMBALANCE_TYPE: &str = "OrderFlowImbalance";
+
+/// Maintains order-flow imbalance metrics for an instrument and publishes them on each trade.
+#[derive(Debug)]
+pub struct OrderFlowPublisher {
+    calculator: OrderFlowImbalanceCalculator,
+    topic: MStr<Topic>,
+    last: Option<OrderFlowImbalance>,
+}
+
+impl OrderFlowPublisher {
+    /// Creates a new [`OrderFlowPublisher`] instance over a rolling window of `window_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_ns` is zero.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, window_ns: u64) -> Self {
+        Self {
+            calculator: OrderFlowImbalanceCalculator::new(instrument_id, window_ns),
+            topic: get_order_flow_topic(instrument_id),
+            last: None,
+        }
+    }
+
+    /// Returns the topic metrics are published on.
+    #[must_use]
+    pub const fn topic(&self) -> MStr<Topic> {
+        self.topic
+    }
+
+    /// Returns the last metrics published, if any.
+    #[must_use]
+    pub const fn last(&self) -> Option<&OrderFlowImbalance> {
+        self.last.as_ref()
+    }
+
+    /// Handles a quote for the instrument.
+    pub fn on_quote(&mut self, quote: &QuoteTick) {
+        self.calculator.handle_quote(quote);
+    }
+
+    /// Handles a trade for the instrument, publishing the updated metrics.
+    ///
+    /// Returns the inferred initiating side of the trade.
+    pub fn on_trade(&mut self, trade: &TradeTick, ts_now: UnixNanos) -> AggressorSide {
+        let side = self.calculator.handle_trade(trade);
+        let metrics = self.calculator.snapshot(ts_now);
+        msgbus::publish_any(self.topic, &metrics);
+        self.last = Some(metrics);
+        side
+    }
+}
+
+/// Returns the [`DataType`] for order-flow imbalance metrics of `instrument_id`.
+#[must_use]
+pub fn order_flow_data_type(instrument_id: InstrumentId) -> DataType {
+    let mut metadata = IndexMap::new();
+    metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+    DataType::new(ORDER_FLOW_IMBALANCE_TYPE, Some(metadata))
+}
+
+/// Converts `metrics` to [`CustomData`] (JSON encoded) for persistence.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn order_flow_to_custom_data(metrics: &OrderFlowImbalance) -> anyhow::Result<CustomData> {
+    let value = serde_json::to_vec(metrics)?;
+    Ok(CustomData::new(
+        order_flow_data_type(metrics.instrument_id),
+        Bytes::from(value),
+        metrics.ts_event,
+        metrics.ts_init,
+    ))
+}
+
+/// Decodes order-flow imbalance metrics from [`CustomData`].
+///
+/// # Errors
+///
+/// Returns an error if the data is not order-flow imbalance metrics or deserialization fails.
+pub fn order_flow_from_custom_data(data: &CustomData) -> anyhow::Result<OrderFlowImbalance> {
+    if data.data_type.type_name() != ORDER_FLOW_IMBALANCE_TYPE {
+        anyhow::bail!(
+            "Invalid data type '{}', expected '{ORDER_FLOW_IMBALANCE_TYPE}'",
+            data.data_type.type_name()
+        );
+    }
+    Ok(serde_json::from_slice(&data.value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::{
+        identifiers::TradeId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::ShareableMessageHandler;
+
+    fn trade(price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            Price::from(price),
+            Quantity::from("1"),
+            AggressorSide::NoAggressor,
+            TradeId::from("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_on_trade_publishes_metrics() {
+        let instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let mut publisher = OrderFlowPublisher::new(instrument_id, 1_000);
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        let handler = ShareableMessageHandler::from_typed(move |metrics: &OrderFlowImbalance| {
+            received_clone.borrow_mut().push(*metrics);
+        });
+        msgbus::subscribe_any(publisher.topic().into(), handler, None);
+
+        publisher.on_trade(&trade("100.0", 1), 1.into());
+        let side = publisher.on_trade(&trade("101.0", 2), 2.into());
+
+        assert_eq!(side, AggressorSide::Buyer);
+        assert_eq!(received.borrow().len(), 2);
+        assert_eq!(received.borrow()[1].buy_volume, 1.0);
+    }
+
+    #[rstest]
+    fn test_custom_data_round_trip() {
+        let mut publisher = OrderFlowPublisher::new(InstrumentId::from("ETHUSDT.BINANCE"), 1_000);
+        publisher.on_trade(&trade("100.0", 1), 1.into());
+        let metrics = *publisher.last().unwrap();
+
+        let data = order_flow_to_custom_data(&metrics).unwrap();
+        let decoded = order_flow_from_custom_data(&data).unwrap();
+
+        assert_eq!(data.data_type.type_name(), ORDER_FLOW_IMBALANCE_TYPE);
+        assert_eq!(decoded, metrics);
+    }
+}
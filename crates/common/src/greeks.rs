@@ -20,7 +20,10 @@ use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
 use derive_builder::Builder;
 use nautilus_core::UnixNanos;
 use nautilus_model::{
-    data::greeks::{GreeksData, PortfolioGreeks, black_scholes_greeks, imply_vol_and_greeks},
+    data::{
+        VolArbitrageViolation, VolSurface,
+        greeks::{GreeksData, PortfolioGreeks, black_scholes_greeks, imply_vol_and_greeks},
+    },
     enums::{InstrumentClass, OptionKind, PositionSide, PriceType},
     identifiers::{InstrumentId, StrategyId, Venue},
     instruments::Instrument,
@@ -789,6 +792,44 @@ impl GreeksCalculator {
         Ok(portfolio_greeks)
     }
 
+    /// Publishes a batch of greeks for options on a single underlying.
+    ///
+    /// When `check_arbitrage` is set, the implied volatility surface formed by the batch is
+    /// first checked for static arbitrage (see [`VolSurface::check_arbitrage`]) and a warning is
+    /// logged for each violation, identifying the offending strikes and expiries. The greeks are
+    /// published regardless, and the violations are returned to the caller.
+    ///
+    /// This is the only publishing path with a surface check: greeks published one instrument at
+    /// a time through [`Self::instrument_greeks`] are not checked, as a single point does not
+    /// form a surface.
+    pub fn publish_greeks_batch(
+        &self,
+        greeks: &[GreeksData],
+        check_arbitrage: bool,
+        price_tolerance: f64,
+        variance_tolerance: f64,
+    ) -> Vec<VolArbitrageViolation> {
+        let mut violations = Vec::new();
+
+        if check_arbitrage && let Ok(surface) = VolSurface::from_greeks(greeks) {
+            violations = surface.check_arbitrage(price_tolerance, variance_tolerance);
+            for violation in &violations {
+                log::warn!("Volatility surface {violation}");
+            }
+        }
+
+        for greeks_data in greeks {
+            let topic = format!(
+                "data.GreeksData.instrument_id={}",
+                greeks_data.instrument_id.symbol.as_str()
+            )
+            .into();
+            msgbus::publish_greeks(topic, greeks_data);
+        }
+
+        violations
+    }
+
     /// Subscribes to Greeks data for a given underlying instrument.
     ///
     /// Useful for reading greeks from a backtesting data catalog and caching them for later use.
@@ -1294,4 +1335,29 @@ mod tests {
 
         assert!(greeks_filter(&greeks_data));
     }
+
+    #[rstest]
+    fn test_publish_greeks_batch_reports_calendar_arbitrage() {
+        let calculator = create_test_calculator();
+        let option_greeks = |symbol: &str, expiry_in_years: f64, vol: f64| {
+            let mut greeks =
+                GreeksData::from_delta(InstrumentId::from(symbol), 0.5, 1.0, UnixNanos::default());
+            greeks.strike = 100.0;
+            greeks.expiry_in_years = expiry_in_years;
+            greeks.underlying_price = 100.0;
+            greeks.vol = vol;
+            greeks
+        };
+        let batch = vec![
+            option_greeks("ESM4-C100.GLBX", 0.25, 0.5),
+            option_greeks("ESZ4-C100.GLBX", 1.0, 0.2),
+        ];
+
+        let unchecked = calculator.publish_greeks_batch(&batch, false, 1e-9, 1e-12);
+        let checked = calculator.publish_greeks_batch(&batch, true, 1e-9, 1e-12);
+
+        assert!(unchecked.is_empty());
+        assert_eq!(checked.len(), 1);
+        assert_eq!(checked[0].expiries_in_years, vec![0.25, 1.0]);
+    }
 }
@@ -0,0 +1,644 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A configurable grid trading and DCA strategy component.
+//!
+//! The [`GridStrategy`] divides a price range into a grid of levels (see [`GridConfig::prices`])
+//! and works an entry limit order at each level, paired with a take-profit at the adjacent level
+//! in the direction of the grid. Each pair is submitted as a bracket through a [`BracketManager`],
+//! with a common stop-loss beyond the range closing out levels if the market leaves it.
+//!
+//! When a level's take-profit fills the level is rearmed with a new bracket, so the grid keeps
+//! cycling while the market ranges. Levels are only armed while their entry would rest passively
+//! against the latest quote. Scaling the quantity with depth ([`GridConfig::qty_scale`]) turns the
+//! grid into a DCA ladder, adding progressively larger entries as the market moves against it.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::QuoteTick,
+    enums::OrderSide,
+    events::{OrderEventAny, OrderFilled},
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    instruments::Instrument,
+    orders::Order,
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bracket::{BracketManager, BracketSupport},
+    cache::Cache,
+    clock::Clock,
+    factories::OrderFactory,
+    messages::execution::TradingCommand,
+    msgbus::{
+        self, MessagingSwitchboard, TypedHandler,
+        switchboard::{get_event_orders_topic, get_quotes_topic},
+    },
+};
+
+/// The spacing of grid levels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GridSpacing {
+    /// Levels a constant price distance apart.
+    #[default]
+    Arithmetic,
+    /// Levels a constant ratio apart.
+    Geometric,
+}
+
+/// Configuration for a [`GridStrategy`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GridConfig {
+    pub instrument_id: InstrumentId,
+    /// The execution client to route orders to.
+    pub client_id: Option<ClientId>,
+    /// The entry side, `Buy` for a long grid taking profit above and `Sell` for a short grid.
+    pub entry_side: OrderSide,
+    pub lower_price: f64,
+    pub upper_price: f64,
+    /// The number of grid prices, including both bounds.
+    pub levels: usize,
+    pub spacing: GridSpacing,
+    /// The entry quantity at the level nearest the take-profit side of the range.
+    pub order_qty: f64,
+    /// The quantity multiplier applied per level deeper into the range (1.0 for a flat grid).
+    pub qty_scale: f64,
+    /// The stop-loss trigger price for every level, beyond the range on the entry side.
+    pub stop_loss_price: f64,
+    pub support: BracketSupport,
+}
+
+impl GridConfig {
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range, level count, quantities or stop-loss are invalid.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            matches!(self.entry_side, OrderSide::Buy | OrderSide::Sell),
+            "Invalid grid entry side {}",
+            self.entry_side
+        );
+        anyhow::ensure!(
+            self.lower_price.is_finite() && self.lower_price < self.upper_price,
+            "Invalid grid range [{}, {}]",
+            self.lower_price,
+            self.upper_price
+        );
+        anyhow::ensure!(
+            self.spacing == GridSpacing::Arithmetic || self.lower_price > 0.0,
+            "Geometric grid requires a positive lower price, was {}",
+            self.lower_price
+        );
+        anyhow::ensure!(
+            self.levels >= 2,
+            "Grid requires at least 2 levels, was {}",
+            self.levels
+        );
+        anyhow::ensure!(
+            self.order_qty > 0.0 && self.qty_scale > 0.0,
+            "Invalid grid quantity {} with scale {}",
+            self.order_qty,
+            self.qty_scale
+        );
+        let beyond_range = match self.entry_side {
+            OrderSide::Buy => self.stop_loss_price < self.lower_price,
+            _ => self.stop_loss_price > self.upper_price,
+        };
+        anyhow::ensure!(
+            beyond_range,
+            "Grid stop-loss {} must be beyond the range on the entry side",
+            self.stop_loss_price
+        );
+        Ok(())
+    }
+
+    /// Returns the grid prices in ascending order.
+    #[must_use]
+    pub fn prices(&self) -> Vec<f64> {
+        let steps = (self.levels - 1) as f64;
+        (0..self.levels)
+            .map(|i| {
+                let i = i as f64;
+                match self.spacing {
+                    GridSpacing::Arithmetic => {
+                        self.lower_price + (self.upper_price - self.lower_price) * i / steps
+                    }
+                    GridSpacing::Geometric => {
+                        self.lower_price * (self.upper_price / self.lower_price).powf(i / steps)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the (entry price, take-profit price, quantity) of each grid level, ordered from
+    /// the take-profit side of the range to the deepest level.
+    #[must_use]
+    pub fn level_specs(&self) -> Vec<(f64, f64, f64)> {
+        let prices = self.prices();
+        let pairs: Vec<(f64, f64)> = match self.entry_side {
+            OrderSide::Buy => prices.windows(2).rev().map(|w| (w[0], w[1])).collect(),
+            _ => prices.windows(2).map(|w| (w[1], w[0])).collect(),
+        };
+        let mut qty = self.order_qty;
+        pairs
+            .into_iter()
+            .map(|(entry, take_profit)| {
+                let level = (entry, take_profit, qty);
+                qty *= self.qty_scale;
+                level
+            })
+            .collect()
+    }
+}
+
+/// The state of a grid level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GridLevelState {
+    /// No bracket is working at the level.
+    Idle,
+    /// The entry is working.
+    Working,
+    /// The entry has (partially) filled and the take-profit is working.
+    Filled,
+    /// The stop-loss filled, the level is no longer traded.
+    Stopped,
+}
+
+/// A level of the grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridLevel {
+    pub entry_price: Price,
+    pub take_profit_price: Price,
+    pub quantity: Quantity,
+    pub state: GridLevelState,
+    pub entry_id: Option<ClientOrderId>,
+    pub stop_loss_id: Option<ClientOrderId>,
+    pub take_profit_id: Option<ClientOrderId>,
+    /// The quantity of the take-profit filled so far.
+    pub take_profit_filled: f64,
+    /// The number of completed entry and take-profit round trips.
+    pub round_trips: u32,
+}
+
+impl GridLevel {
+    fn reset(&mut self, state: GridLevelState) {
+        self.state = state;
+        self.entry_id = None;
+        self.stop_loss_id = None;
+        self.take_profit_id = None;
+        self.take_profit_filled = 0.0;
+    }
+}
+
+struct GridState {
+    factory: OrderFactory,
+    brackets: BracketManager,
+    levels: Vec<GridLevel>,
+    last_quote: Option<QuoteTick>,
+    handlers: Option<(TypedHandler<QuoteTick>, TypedHandler<OrderEventAny>)>,
+}
+
+/// A grid trading strategy working bracket orders at each level of a price grid.
+#[derive(Clone)]
+pub struct GridStrategy {
+    strategy_id: StrategyId,
+    config: GridConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    state: Rc<RefCell<GridState>>,
+}
+
+impl Debug for GridStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(GridStrategy))
+            .field("strategy_id", &self.strategy_id)
+            .field("config", &self.config)
+            .field("levels", &self.state.borrow().levels)
+            .finish()
+    }
+}
+
+impl GridStrategy {
+    /// Creates a new [`GridStrategy`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        config: GridConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> anyhow::Result<Self> {
+        config.validate()?;
+        let factory = OrderFactory::new(
+            trader_id,
+            strategy_id,
+            None,
+            None,
+            clock.clone(),
+            false,
+            true,
+        );
+        let state = GridState {
+            factory,
+            brackets: BracketManager::new(trader_id),
+            levels: Vec::new(),
+            last_quote: None,
+            handlers: None,
+        };
+        Ok(Self {
+            strategy_id,
+            config,
+            clock,
+            cache,
+            state: Rc::new(RefCell::new(state)),
+        })
+    }
+
+    /// Returns the grid levels.
+    #[must_use]
+    pub fn levels(&self) -> Vec<GridLevel> {
+        self.state.borrow().levels.clone()
+    }
+
+    /// Builds the grid levels for the instrument and subscribes to its quotes and the strategy's
+    /// order events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instrument is not in the cache.
+    pub fn start(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.state.borrow().handlers.is_none(),
+            "Grid strategy {} already started",
+            self.strategy_id
+        );
+
+        let levels = {
+            let cache = self.cache.borrow();
+            let Some(instrument) = cache.instrument(&self.config.instrument_id) else {
+                anyhow::bail!("No instrument {} for grid", self.config.instrument_id);
+            };
+            self.config
+                .level_specs()
+                .into_iter()
+                .map(|(entry, take_profit, qty)| GridLevel {
+                    entry_price: instrument.make_price(entry),
+                    take_profit_price: instrument.make_price(take_profit),
+                    quantity: instrument.make_qty(qty, Some(true)),
+                    state: GridLevelState::Idle,
+                    entry_id: None,
+                    stop_loss_id: None,
+                    take_profit_id: None,
+                    take_profit_filled: 0.0,
+                    round_trips: 0,
+                })
+                .collect()
+        };
+
+        let grid = self.clone();
+        let quotes = TypedHandler::from(move |quote: &QuoteTick| grid.on_quote(quote));
+        let grid = self.clone();
+        let orders = TypedHandler::from(move |event: &OrderEventAny| grid.on_order_event(event));
+        msgbus::subscribe_quotes(
+            get_quotes_topic(self.config.instrument_id).into(),
+            quotes.clone(),
+            None,
+        );
+        msgbus::subscribe_order_events(
+            get_event_orders_topic(self.strategy_id).into(),
+            orders.clone(),
+            None,
+        );
+
+        let mut state = self.state.borrow_mut();
+        state.levels = levels;
+        state.handlers = Some((quotes, orders));
+        Ok(())
+    }
+
+    /// Unsubscribes from quotes and order events.
+    ///
+    /// Working orders are left to the strategy's order management on stop.
+    pub fn stop(&self) {
+        if let Some((quotes, orders)) = self.state.borrow_mut().handlers.take() {
+            msgbus::unsubscribe_quotes(get_quotes_topic(self.config.instrument_id).into(), &quotes);
+            msgbus::unsubscribe_order_events(
+                get_event_orders_topic(self.strategy_id).into(),
+                &orders,
+            );
+        }
+    }
+
+    /// Handles a `quote`, arming idle levels whose entry would rest passively.
+    pub fn on_quote(&self, quote: &QuoteTick) {
+        self.state.borrow_mut().last_quote = Some(*quote);
+        self.arm_levels();
+    }
+
+    /// Handles an order `event` for the strategy, advancing the affected grid level.
+    pub fn on_order_event(&self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        {
+            let mut state = self.state.borrow_mut();
+            let GridState {
+                brackets, levels, ..
+            } = &mut *state;
+            let Some(level) = levels.iter_mut().find(|level| {
+                [level.entry_id, level.stop_loss_id, level.take_profit_id]
+                    .contains(&Some(client_order_id))
+            }) else {
+                return;
+            };
+
+            match event {
+                OrderEventAny::Filled(fill) => Self::on_level_fill(brackets, level, fill),
+                OrderEventAny::Denied(_)
+                | OrderEventAny::Rejected(_)
+                | OrderEventAny::Canceled(_)
+                | OrderEventAny::Expired(_)
+                    if level.entry_id == Some(client_order_id)
+                        && level.state == GridLevelState::Working =>
+                {
+                    if let Some(entry_id) = level.entry_id {
+                        brackets.remove(&entry_id);
+                    }
+                    level.reset(GridLevelState::Idle);
+                }
+                _ => return,
+            }
+        }
+        self.arm_levels();
+    }
+
+    fn on_level_fill(brackets: &mut BracketManager, level: &mut GridLevel, fill: &OrderFilled) {
+        brackets.on_fill(fill);
+        let client_order_id = fill.client_order_id;
+        if level.entry_id == Some(client_order_id) {
+            level.state = GridLevelState::Filled;
+        } else if level.take_profit_id == Some(client_order_id) {
+            level.take_profit_filled += fill.last_qty.as_f64();
+            if level.take_profit_filled >= level.quantity.as_f64() {
+                level.round_trips += 1;
+                log::info!(
+                    "Grid level {} completed round trip {}",
+                    level.entry_price,
+                    level.round_trips
+                );
+                level.reset(GridLevelState::Idle);
+            }
+        } else {
+            log::warn!("Grid level {} stopped out", level.entry_price);
+            level.reset(GridLevelState::Stopped);
+        }
+    }
+
+    fn arm_levels(&self) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let mut commands = Vec::new();
+        {
+            let mut cache = self.cache.borrow_mut();
+            let mut state = self.state.borrow_mut();
+            let Some(quote) = state.last_quote else {
+                return;
+            };
+            let GridState {
+                factory,
+                brackets,
+                levels,
+                ..
+            } = &mut *state;
+
+            for level in levels
+                .iter_mut()
+                .filter(|level| level.state == GridLevelState::Idle)
+            {
+                let passive = match self.config.entry_side {
+                    OrderSide::Buy => level.entry_price < quote.ask_price,
+                    _ => level.entry_price > quote.bid_price,
+                };
+                if !passive {
+                    continue;
+                }
+
+                match self.submit_level(&mut cache, factory, brackets, level, ts_now) {
+                    Ok(command) => commands.push(command),
+                    Err(e) => log::error!("Failed to arm grid level {}: {e}", level.entry_price),
+                }
+            }
+        }
+
+        // Sent once no state is borrowed, as handlers may publish order events synchronously
+        for command in commands {
+            msgbus::send_trading_command(MessagingSwitchboard::risk_engine_execute(), command);
+        }
+    }
+
+    fn submit_level(
+        &self,
+        cache: &mut Cache,
+        factory: &mut OrderFactory,
+        brackets: &mut BracketManager,
+        level: &mut GridLevel,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<TradingCommand> {
+        let instrument = cache
+            .instrument(&self.config.instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No instrument {}", self.config.instrument_id))?;
+        let stop_loss_price = instrument.make_price(self.config.stop_loss_price);
+
+        let order_list = factory.bracket(
+            self.config.instrument_id,
+            self.config.entry_side,
+            level.quantity,
+            Some(level.entry_price),
+            stop_loss_price,
+            None,
+            level.take_profit_price,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            self.config.support.emulation_trigger(),
+            None,
+            None,
+            None,
+            None,
+        );
+        for order in &order_list.orders {
+            cache.add_order(order.clone(), None, self.config.client_id, false)?;
+        }
+        let command = brackets.submit(order_list, self.config.client_id, ts_now)?;
+        let entry_id = command
+            .order_list
+            .orders
+            .first()
+            .map(Order::client_order_id);
+        let bracket = entry_id
+            .and_then(|entry_id| brackets.bracket(&entry_id))
+            .ok_or_else(|| anyhow::anyhow!("Bracket not registered"))?;
+
+        level.state = GridLevelState::Working;
+        level.entry_id = Some(bracket.entry_id);
+        level.stop_loss_id = Some(bracket.stop_loss_id);
+        level.take_profit_id = Some(bracket.take_profit_id);
+        Ok(TradingCommand::SubmitOrderList(command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        instruments::{CurrencyPair, InstrumentAny, stubs::audusd_sim},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, msgbus::TypedIntoHandler};
+
+    fn config(instrument_id: InstrumentId) -> GridConfig {
+        GridConfig {
+            instrument_id,
+            client_id: None,
+            entry_side: OrderSide::Buy,
+            lower_price: 0.99,
+            upper_price: 1.01,
+            levels: 5,
+            spacing: GridSpacing::Arithmetic,
+            order_qty: 100_000.0,
+            qty_scale: 1.0,
+            stop_loss_price: 0.98,
+            support: BracketSupport::Native,
+        }
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn fill(client_order_id: ClientOrderId, px: &str, qty: u64) -> OrderEventAny {
+        OrderEventAny::Filled(OrderFilled {
+            client_order_id,
+            last_px: Price::from(px),
+            last_qty: Quantity::from(qty),
+            ..Default::default()
+        })
+    }
+
+    #[rstest]
+    fn test_level_specs_pair_take_profits_and_scale_quantity() {
+        let mut config = config(InstrumentId::from("AUD/USD.SIM"));
+        config.qty_scale = 2.0;
+
+        let levels = config.level_specs();
+
+        assert_eq!(levels.len(), 4);
+        let (entry, take_profit, qty) = levels[0];
+        assert!((entry - 1.005).abs() < 1e-12 && (take_profit - 1.01).abs() < 1e-12);
+        assert_eq!(qty, 100_000.0);
+        assert_eq!(levels[3].2, 800_000.0);
+    }
+
+    #[rstest]
+    fn test_geometric_prices_have_constant_ratio() {
+        let mut config = config(InstrumentId::from("AUD/USD.SIM"));
+        config.spacing = GridSpacing::Geometric;
+        config.lower_price = 1.0;
+        config.upper_price = 16.0;
+        config.stop_loss_price = 0.5;
+
+        let prices = config.prices();
+
+        assert_eq!(prices.len(), 5);
+        assert!((prices[1] - 2.0).abs() < 1e-12 && (prices[4] - 16.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 0.995)]
+    #[case(OrderSide::Sell, 0.995)]
+    fn test_validate_rejects_stop_loss_inside_range(#[case] side: OrderSide, #[case] sl: f64) {
+        let mut config = config(InstrumentId::from("AUD/USD.SIM"));
+        config.entry_side = side;
+        config.stop_loss_price = sl;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[rstest]
+    fn test_grid_rearms_level_after_take_profit(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let commands = Rc::new(RefCell::new(Vec::new()));
+        let sink = commands.clone();
+        msgbus::register_trading_command_endpoint(
+            MessagingSwitchboard::risk_engine_execute(),
+            TypedIntoHandler::from(move |command: TradingCommand| sink.borrow_mut().push(command)),
+        );
+
+        let grid = GridStrategy::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("GRID-001"),
+            config(instrument_id),
+            Rc::new(RefCell::new(TestClock::new())),
+            cache,
+        )
+        .unwrap();
+        grid.start().unwrap();
+
+        // Only the entries below the ask rest passively
+        msgbus::publish_quote(
+            get_quotes_topic(instrument_id),
+            &quote(instrument_id, "1.00000", "1.00002"),
+        );
+        assert_eq!(commands.borrow().len(), 3);
+
+        let level = grid.levels()[1].clone();
+        assert_eq!(level.entry_price, Price::from("1.00000"));
+        grid.on_order_event(&fill(level.entry_id.unwrap(), "1.00000", 100_000));
+        assert_eq!(grid.levels()[1].state, GridLevelState::Filled);
+
+        grid.on_order_event(&fill(level.take_profit_id.unwrap(), "1.00500", 100_000));
+        let rearmed = &grid.levels()[1];
+        assert_eq!(rearmed.round_trips, 1);
+        assert_eq!(rearmed.state, GridLevelState::Working);
+        assert_ne!(rearmed.entry_id, level.entry_id);
+        assert_eq!(commands.borrow().len(), 4);
+
+        grid.stop();
+    }
+}
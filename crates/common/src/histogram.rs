@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Log-linear histograms for latency measurements.
+//!
+//! Values are recorded into buckets which are linear within each power of two, giving a bounded
+//! relative error (under 0.8%) across the full `u64` nanosecond range. The bucket counts grow
+//! only up to the largest recorded value, so histograms of short latencies stay small.
+
+use serde::{Deserialize, Serialize};
+
+/// The number of linear sub-buckets per power of two (as a power of two).
+const SUB_BUCKET_BITS: u32 = 7;
+const SUB_BUCKET_COUNT: usize = 1 << SUB_BUCKET_BITS;
+const SUB_BUCKET_MASK: u64 = (SUB_BUCKET_COUNT as u64) - 1;
+
+/// The total number of buckets required to cover the `u64` range.
+pub const HISTOGRAM_BUCKET_COUNT: usize = (64 - SUB_BUCKET_BITS as usize + 1) * SUB_BUCKET_COUNT;
+
+/// A fixed size log-linear histogram of nanosecond values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: Vec<u64>,
+    count: u64,
+    sum: u128,
+    min: u64,
+    max: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatencyHistogram {
+    /// Creates a new empty [`LatencyHistogram`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: Vec::new(),
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+
+    /// Records a single value.
+    pub fn record(&mut self, value: u64) {
+        let index = bucket_index(value);
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+        self.count += 1;
+        self.sum += u128::from(value);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Merges all values recorded in `other` into this histogram.
+    pub fn merge(&mut self, other: &Self) {
+        if other.counts.len() > self.counts.len() {
+            self.counts.resize(other.counts.len(), 0);
+        }
+        for (count, other_count) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other_count;
+        }
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Clears all recorded values.
+    pub fn reset(&mut self) {
+        self.counts.clear();
+        self.count = 0;
+        self.sum = 0;
+        self.min = u64::MAX;
+        self.max = 0;
+    }
+
+    /// Returns the number of recorded values.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns whether no values have been recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the minimum recorded value (if any).
+    #[must_use]
+    pub const fn min(&self) -> Option<u64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// Returns the maximum recorded value (if any).
+    #[must_use]
+    pub const fn max(&self) -> Option<u64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// Returns the exact mean of the recorded values (if any).
+    #[must_use]
+    pub fn mean(&self) -> Option<u64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some((self.sum / u128::from(self.count)) as u64)
+        }
+    }
+
+    /// Returns the value at `quantile` (in the range [0, 1]), if any values were recorded.
+    ///
+    /// The result is the upper bound of the bucket containing the quantile, clamped to the
+    /// recorded minimum and maximum.
+    #[must_use]
+    pub fn quantile(&self, quantile: f64) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let quantile = quantile.clamp(0.0, 1.0);
+        let rank = ((quantile * self.count as f64).ceil() as u64).max(1);
+
+        let mut seen = 0;
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(bucket_upper_bound(index).clamp(self.min, self.max));
+            }
+        }
+
+        Some(self.max)
+    }
+
+    /// Returns a summary of the recorded values.
+    #[must_use]
+    pub fn summary(&self) -> HistogramSummary {
+        HistogramSummary {
+            count: self.count,
+            min: self.min().unwrap_or_default(),
+            max: self.max().unwrap_or_default(),
+            mean: self.mean().unwrap_or_default(),
+            p50: self.quantile(0.50).unwrap_or_default(),
+            p90: self.quantile(0.90).unwrap_or_default(),
+            p99: self.quantile(0.99).unwrap_or_default(),
+            p999: self.quantile(0.999).unwrap_or_default(),
+        }
+    }
+}
+
+/// A summary of the values recorded by a [`LatencyHistogram`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistogramSummary {
+    pub count: u64,
+    pub min: u64,
+    pub max: u64,
+    pub mean: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub p999: u64,
+}
+
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKET_COUNT as u64 {
+        return value as usize;
+    }
+
+    let exponent = 63 - value.leading_zeros();
+    let sub_bucket = (value >> (exponent - SUB_BUCKET_BITS)) & SUB_BUCKET_MASK;
+    (exponent - SUB_BUCKET_BITS + 1) as usize * SUB_BUCKET_COUNT + sub_bucket as usize
+}
+
+fn bucket_upper_bound(index: usize) -> u64 {
+    if index < SUB_BUCKET_COUNT {
+        return index as u64;
+    }
+
+    let exponent = (index / SUB_BUCKET_COUNT) as u32 + SUB_BUCKET_BITS - 1;
+    let sub_bucket = (index % SUB_BUCKET_COUNT) as u64;
+    let shift = exponent - SUB_BUCKET_BITS;
+    let lower = (SUB_BUCKET_COUNT as u64 + sub_bucket) << shift;
+    lower + ((1_u64 << shift) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(127, 127)]
+    #[case(128, 128)]
+    #[case(255, 255)]
+    #[case(256, 256)]
+    #[case(257, 256)]
+    #[case(u64::MAX, HISTOGRAM_BUCKET_COUNT - 1)]
+    fn test_bucket_index(#[case] value: u64, #[case] expected: usize) {
+        assert_eq!(bucket_index(value), expected);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(9)]
+    #[case(100)]
+    #[case(1_000_000)]
+    #[case(u64::MAX)]
+    fn test_value_within_bucket_bounds(#[case] value: u64) {
+        let upper = bucket_upper_bound(bucket_index(value));
+        assert!(upper >= value);
+        assert!(upper - value <= value / 128);
+    }
+
+    #[rstest]
+    fn test_empty_histogram() {
+        let histogram = LatencyHistogram::new();
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.min(), None);
+        assert_eq!(histogram.quantile(0.5), None);
+        assert_eq!(histogram.summary(), HistogramSummary::default());
+    }
+
+    #[rstest]
+    fn test_record_and_quantiles() {
+        let mut histogram = LatencyHistogram::new();
+        for value in 1..=100 {
+            histogram.record(value * 1_000);
+        }
+
+        assert_eq!(histogram.count(), 100);
+        assert_eq!(histogram.min(), Some(1_000));
+        assert_eq!(histogram.max(), Some(100_000));
+        assert_eq!(histogram.mean(), Some(50_500));
+
+        let p50 = histogram.quantile(0.5).unwrap();
+        assert!((50_000..=50_000 + 50_000 / 128).contains(&p50));
+        assert_eq!(histogram.quantile(1.0), Some(100_000));
+        let p0 = histogram.quantile(0.0).unwrap();
+        assert!((1_000..=1_000 + 1_000 / 128).contains(&p0));
+    }
+
+    #[rstest]
+    fn test_merge_and_reset() {
+        let mut a = LatencyHistogram::new();
+        let mut b = LatencyHistogram::new();
+        a.record(10);
+        b.record(1_000);
+
+        a.merge(&b);
+        assert_eq!(a.count(), 2);
+        assert_eq!(a.quantile(1.0), Some(1_000));
+        assert_eq!(a.min(), Some(10));
+        assert_eq!(a.max(), Some(1_000));
+
+        a.reset();
+        assert!(a.is_empty());
+        assert_eq!(a, LatencyHistogram::new());
+    }
+}
@@ -0,0 +1,493 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracking of in-flight trading commands awaiting acknowledgement from the venue.
+//!
+//! Each submit, modify and cancel command is tracked per order until an event acknowledges it.
+//! Commands which are not acknowledged before their deadline are escalated according to the
+//! [`TimeoutPolicy`]: an order status query is issued, or the command is resubmitted, and once
+//! escalation is exhausted the command is reported as failed.
+//!
+//! The tracker is driven by [`InFlightTracker::check_timeouts`], typically from a timer set for
+//! [`InFlightTracker::next_deadline`].
+
+use std::fmt::Display;
+
+use ahash::AHashMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    events::OrderEventAny,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+    orders::Order,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::execution::{QueryOrder, TradingCommand};
+
+/// The kind of an in-flight command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InFlightKind {
+    Submit,
+    Modify,
+    Cancel,
+}
+
+impl Display for InFlightKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// The state of an in-flight command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InFlightState {
+    /// Sent and awaiting acknowledgement.
+    Pending,
+    /// Timed out and an order status query was issued.
+    Querying,
+    /// Timed out and the command was resubmitted.
+    Resubmitted,
+}
+
+/// How commands which time out are escalated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeoutPolicy {
+    /// Issue an order status query, then fail if it is not answered.
+    Query,
+    /// Resubmit the command up to `max_attempts` times, then issue a query.
+    Resubmit { max_attempts: u32 },
+}
+
+/// The configuration for an [`InFlightTracker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InFlightConfig {
+    /// The time to wait for each acknowledgement (or query response).
+    pub timeout_ns: u64,
+    pub policy: TimeoutPolicy,
+}
+
+impl Default for InFlightConfig {
+    /// Creates a new default [`InFlightConfig`] instance.
+    fn default() -> Self {
+        Self {
+            timeout_ns: 5_000_000_000,
+            policy: TimeoutPolicy::Query,
+        }
+    }
+}
+
+/// A command awaiting acknowledgement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InFlightCommand {
+    pub kind: InFlightKind,
+    pub client_id: Option<ClientId>,
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub state: InFlightState,
+    pub ts_sent: UnixNanos,
+    pub deadline: UnixNanos,
+    pub attempts: u32,
+    /// The original command, if it can be resubmitted on its own.
+    pub command: Option<TradingCommand>,
+}
+
+/// The escalation required for a timed out command.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeoutAction {
+    /// Send the order status query.
+    Query(QueryOrder),
+    /// Send the command again.
+    Resubmit(TradingCommand),
+    /// Escalation is exhausted and the command is no longer tracked.
+    Failed(InFlightCommand),
+}
+
+/// Tracks in-flight commands and escalates those which time out.
+#[derive(Debug)]
+pub struct InFlightTracker {
+    config: InFlightConfig,
+    commands: AHashMap<(ClientOrderId, InFlightKind), InFlightCommand>,
+}
+
+impl InFlightTracker {
+    /// Creates a new [`InFlightTracker`] instance.
+    #[must_use]
+    pub fn new(config: InFlightConfig) -> Self {
+        Self {
+            config,
+            commands: AHashMap::new(),
+        }
+    }
+
+    /// Starts tracking a sent command.
+    ///
+    /// Submit (including each order of a list), modify and cancel commands are tracked, while
+    /// other commands are ignored. A command replaces any in-flight command of the same kind for
+    /// the same order.
+    pub fn on_command(&mut self, command: &TradingCommand, ts_sent: UnixNanos) {
+        match command {
+            TradingCommand::SubmitOrder(submit) => {
+                let mut tracked = self.in_flight(
+                    InFlightKind::Submit,
+                    command,
+                    submit.client_order_id,
+                    ts_sent,
+                );
+                tracked.command = Some(command.clone());
+                self.insert(tracked);
+            }
+            TradingCommand::SubmitSpreadOrder(submit) => {
+                let mut tracked = self.in_flight(
+                    InFlightKind::Submit,
+                    command,
+                    submit.client_order_id,
+                    ts_sent,
+                );
+                tracked.command = Some(command.clone());
+                self.insert(tracked);
+            }
+            TradingCommand::SubmitOrderList(submit) => {
+                for order in &submit.order_list.orders {
+                    let tracked = self.in_flight(
+                        InFlightKind::Submit,
+                        command,
+                        order.client_order_id(),
+                        ts_sent,
+                    );
+                    self.insert(tracked);
+                }
+            }
+            TradingCommand::ModifyOrder(modify) => {
+                let mut tracked = self.in_flight(
+                    InFlightKind::Modify,
+                    command,
+                    modify.client_order_id,
+                    ts_sent,
+                );
+                tracked.venue_order_id = modify.venue_order_id;
+                tracked.command = Some(command.clone());
+                self.insert(tracked);
+            }
+            TradingCommand::CancelOrder(cancel) => {
+                let mut tracked = self.in_flight(
+                    InFlightKind::Cancel,
+                    command,
+                    cancel.client_order_id,
+                    ts_sent,
+                );
+                tracked.venue_order_id = cancel.venue_order_id;
+                tracked.command = Some(command.clone());
+                self.insert(tracked);
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolves the in-flight commands acknowledged by `event`.
+    pub fn on_event(&mut self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let resolved: &[InFlightKind] = match event {
+            OrderEventAny::Accepted(_) | OrderEventAny::Triggered(_) => &[InFlightKind::Submit],
+            OrderEventAny::Updated(_) | OrderEventAny::ModifyRejected(_) => &[InFlightKind::Modify],
+            OrderEventAny::CancelRejected(_) => &[InFlightKind::Cancel],
+            OrderEventAny::Denied(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => &[
+                InFlightKind::Submit,
+                InFlightKind::Modify,
+                InFlightKind::Cancel,
+            ],
+            OrderEventAny::Filled(_) => &[InFlightKind::Submit],
+            _ => &[],
+        };
+
+        for kind in resolved {
+            self.commands.remove(&(client_order_id, *kind));
+        }
+    }
+
+    /// Escalates every command whose deadline has passed at `ts_now`.
+    pub fn check_timeouts(&mut self, ts_now: UnixNanos) -> Vec<TimeoutAction> {
+        let mut expired: Vec<(ClientOrderId, InFlightKind)> = self
+            .commands
+            .iter()
+            .filter(|(_, command)| command.deadline <= ts_now)
+            .map(|(key, _)| *key)
+            .collect();
+        expired.sort();
+
+        let mut actions = Vec::with_capacity(expired.len());
+        for key in expired {
+            if let Some(action) = self.escalate(key, ts_now) {
+                actions.push(action);
+            }
+        }
+        actions
+    }
+
+    /// Returns the earliest deadline among in-flight commands.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<UnixNanos> {
+        self.commands.values().map(|command| command.deadline).min()
+    }
+
+    /// Returns the in-flight command of `kind` for `client_order_id`.
+    #[must_use]
+    pub fn get(
+        &self,
+        client_order_id: &ClientOrderId,
+        kind: InFlightKind,
+    ) -> Option<&InFlightCommand> {
+        self.commands.get(&(*client_order_id, kind))
+    }
+
+    /// Returns the total number of in-flight commands.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns the number of in-flight commands for `client_id`.
+    #[must_use]
+    pub fn count_for_client(&self, client_id: &ClientId) -> usize {
+        self.commands
+            .values()
+            .filter(|command| command.client_id.as_ref() == Some(client_id))
+            .count()
+    }
+
+    /// Returns the number of in-flight commands per client.
+    #[must_use]
+    pub fn counts_by_client(&self) -> AHashMap<Option<ClientId>, usize> {
+        let mut counts = AHashMap::new();
+        for command in self.commands.values() {
+            *counts.entry(command.client_id).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Clears all tracked commands.
+    pub fn reset(&mut self) {
+        self.commands.clear();
+    }
+
+    fn in_flight(
+        &self,
+        kind: InFlightKind,
+        command: &TradingCommand,
+        client_order_id: ClientOrderId,
+        ts_sent: UnixNanos,
+    ) -> InFlightCommand {
+        let (trader_id, strategy_id) = match command {
+            TradingCommand::SubmitOrder(command) => (command.trader_id, command.strategy_id),
+            TradingCommand::SubmitOrderList(command) => (command.trader_id, command.strategy_id),
+            TradingCommand::SubmitSpreadOrder(command) => (command.trader_id, command.strategy_id),
+            TradingCommand::ModifyOrder(command) => (command.trader_id, command.strategy_id),
+            TradingCommand::CancelOrder(command) => (command.trader_id, command.strategy_id),
+            _ => unreachable!("only order commands are tracked"),
+        };
+
+        InFlightCommand {
+            kind,
+            client_id: command.client_id(),
+            trader_id,
+            strategy_id,
+            instrument_id: command.instrument_id(),
+            client_order_id,
+            venue_order_id: None,
+            state: InFlightState::Pending,
+            ts_sent,
+            deadline: self.deadline(ts_sent),
+            attempts: 1,
+            command: None,
+        }
+    }
+
+    fn insert(&mut self, command: InFlightCommand) {
+        self.commands
+            .insert((command.client_order_id, command.kind), command);
+    }
+
+    fn deadline(&self, ts: UnixNanos) -> UnixNanos {
+        UnixNanos::from(ts.as_u64().saturating_add(self.config.timeout_ns))
+    }
+
+    fn escalate(
+        &mut self,
+        key: (ClientOrderId, InFlightKind),
+        ts_now: UnixNanos,
+    ) -> Option<TimeoutAction> {
+        let deadline = self.deadline(ts_now);
+        let command = self.commands.get_mut(&key)?;
+        log::warn!(
+            "{} for {} timed out after {} attempt(s) ({:?})",
+            command.kind,
+            command.client_order_id,
+            command.attempts,
+            command.state
+        );
+
+        if command.state == InFlightState::Querying {
+            return self.commands.remove(&key).map(TimeoutAction::Failed);
+        }
+
+        if let TimeoutPolicy::Resubmit { max_attempts } = self.config.policy
+            && command.attempts < max_attempts
+            && let Some(resubmit) = command.command.clone()
+        {
+            command.state = InFlightState::Resubmitted;
+            command.attempts += 1;
+            command.deadline = deadline;
+            return Some(TimeoutAction::Resubmit(resubmit));
+        }
+
+        command.state = InFlightState::Querying;
+        command.deadline = deadline;
+        Some(TimeoutAction::Query(QueryOrder::new(
+            command.trader_id,
+            command.client_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            command.venue_order_id,
+            UUID4::new(),
+            ts_now,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::{OrderCanceled, OrderUpdated};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::{CancelOrder, ModifyOrder};
+
+    fn cancel(client_order_id: &str, client_id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            Some(ClientId::from(client_id)),
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::from(client_order_id),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        ))
+    }
+
+    fn modify(client_order_id: &str) -> TradingCommand {
+        TradingCommand::ModifyOrder(ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            Some(ClientId::from("BINANCE")),
+            StrategyId::from("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::from(client_order_id),
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        ))
+    }
+
+    fn tracker(policy: TimeoutPolicy) -> InFlightTracker {
+        InFlightTracker::new(InFlightConfig {
+            timeout_ns: 100,
+            policy,
+        })
+    }
+
+    #[rstest]
+    fn test_counts_per_client_and_resolution() {
+        let mut tracker = tracker(TimeoutPolicy::Query);
+        tracker.on_command(&cancel("O-1", "BINANCE"), 0.into());
+        tracker.on_command(&modify("O-2"), 0.into());
+        tracker.on_command(&cancel("O-3", "BYBIT"), 0.into());
+
+        assert_eq!(tracker.count(), 3);
+        assert_eq!(tracker.count_for_client(&ClientId::from("BINANCE")), 2);
+        assert_eq!(
+            tracker.counts_by_client()[&Some(ClientId::from("BYBIT"))],
+            1
+        );
+
+        tracker.on_event(&OrderEventAny::Canceled(OrderCanceled {
+            client_order_id: ClientOrderId::from("O-1"),
+            ..Default::default()
+        }));
+        tracker.on_event(&OrderEventAny::Updated(OrderUpdated {
+            client_order_id: ClientOrderId::from("O-2"),
+            ..Default::default()
+        }));
+
+        assert_eq!(tracker.count(), 1);
+        assert_eq!(tracker.next_deadline(), Some(UnixNanos::from(100)));
+    }
+
+    #[rstest]
+    fn test_query_policy_escalates_then_fails() {
+        let mut tracker = tracker(TimeoutPolicy::Query);
+        tracker.on_command(&cancel("O-1", "BINANCE"), 0.into());
+
+        assert!(tracker.check_timeouts(99.into()).is_empty());
+
+        let actions = tracker.check_timeouts(100.into());
+        assert!(
+            matches!(&actions[..], [TimeoutAction::Query(query)] if query.client_order_id == ClientOrderId::from("O-1"))
+        );
+        assert_eq!(
+            tracker
+                .get(&ClientOrderId::from("O-1"), InFlightKind::Cancel)
+                .unwrap()
+                .state,
+            InFlightState::Querying
+        );
+
+        let actions = tracker.check_timeouts(200.into());
+        assert!(
+            matches!(&actions[..], [TimeoutAction::Failed(command)] if command.kind == InFlightKind::Cancel)
+        );
+        assert_eq!(tracker.count(), 0);
+    }
+
+    #[rstest]
+    fn test_resubmit_policy_resubmits_before_query() {
+        let mut tracker = tracker(TimeoutPolicy::Resubmit { max_attempts: 2 });
+        let command = modify("O-1");
+        tracker.on_command(&command, 0.into());
+
+        let first = tracker.check_timeouts(100.into());
+        let second = tracker.check_timeouts(200.into());
+
+        assert_eq!(first, vec![TimeoutAction::Resubmit(command)]);
+        assert!(matches!(&second[..], [TimeoutAction::Query(_)]));
+        assert_eq!(
+            tracker
+                .get(&ClientOrderId::from("O-1"), InFlightKind::Modify)
+                .unwrap()
+                .attempts,
+            2
+        );
+    }
+}
@@ -0,0 +1,378 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading journal annotations for post-trade review.
+//!
+//! Strategies and operators attach [`Annotation`]s (free text plus structured tags) to an
+//! instrument, an order, or a time range. Annotations are persisted as general cache entries (and
+//! so to the cache database when one is configured) alongside the trading data they describe,
+//! published on the journal topic for live tooling, and retrieved with an [`AnnotationQuery`].
+//!
+//! The journal is append-only: annotations are never modified or removed once written.
+
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    identifiers::{ClientOrderId, InstrumentId},
+    orders::Order,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    cache::Cache,
+    msgbus::{self, MStr, Topic},
+};
+
+/// The general cache key prefix annotations are stored under.
+pub const JOURNAL_KEY_PREFIX: &str = "journal.annotation.";
+
+/// Returns the topic new annotations are published on.
+#[must_use]
+pub fn get_journal_topic() -> MStr<Topic> {
+    "events.journal".into()
+}
+
+/// What an annotation is attached to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnnotationTarget {
+    Instrument(InstrumentId),
+    Order(ClientOrderId),
+    /// An inclusive time range.
+    TimeRange {
+        start: UnixNanos,
+        end: UnixNanos,
+    },
+}
+
+impl Display for AnnotationTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Instrument(instrument_id) => write!(f, "instrument={instrument_id}"),
+            Self::Order(client_order_id) => write!(f, "order={client_order_id}"),
+            Self::TimeRange { start, end } => write!(f, "range={start}..{end}"),
+        }
+    }
+}
+
+/// A timestamped journal annotation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub annotation_id: UUID4,
+    /// The strategy or operator which wrote the annotation.
+    pub author: Ustr,
+    pub target: AnnotationTarget,
+    pub text: String,
+    pub tags: IndexMap<String, String>,
+    /// UNIX timestamp (nanoseconds) of the moment the annotation refers to.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the annotation was written.
+    pub ts_init: UnixNanos,
+}
+
+impl Display for Annotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(author={}, {}, text={:?}, ts_event={})",
+            stringify!(Annotation),
+            self.author,
+            self.target,
+            self.text,
+            self.ts_event,
+        )
+    }
+}
+
+/// Selects annotations by target, author, tag and time.
+///
+/// All set criteria must match. Instrument and order annotations match the time bounds by their
+/// `ts_event`, time range annotations when the range overlaps the bounds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnnotationQuery {
+    /// Matches annotations on the instrument and on orders for the instrument (resolved from
+    /// the cache).
+    pub instrument_id: Option<InstrumentId>,
+    pub client_order_id: Option<ClientOrderId>,
+    pub author: Option<Ustr>,
+    /// Matches annotations with the tag, and value when given.
+    pub tag: Option<(String, Option<String>)>,
+    pub start: Option<UnixNanos>,
+    pub end: Option<UnixNanos>,
+}
+
+/// Writes and reads trading journal annotations in the cache.
+#[derive(Clone, Debug)]
+pub struct TradingJournal {
+    cache: Rc<RefCell<Cache>>,
+}
+
+impl TradingJournal {
+    /// Creates a new [`TradingJournal`] instance.
+    #[must_use]
+    pub const fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self { cache }
+    }
+
+    /// Writes a new annotation, persisting it and publishing it on the journal topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `text` is empty, a time range ends before it starts, or persisting
+    /// the annotation fails.
+    pub fn annotate(
+        &self,
+        author: Ustr,
+        target: AnnotationTarget,
+        text: &str,
+        tags: IndexMap<String, String>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Annotation> {
+        anyhow::ensure!(!text.trim().is_empty(), "Annotation text was empty");
+        if let AnnotationTarget::TimeRange { start, end } = target {
+            anyhow::ensure!(
+                start <= end,
+                "Annotation range start {start} was after end {end}"
+            );
+        }
+
+        let annotation = Annotation {
+            annotation_id: UUID4::new(),
+            author,
+            target,
+            text: text.to_string(),
+            tags,
+            ts_event,
+            ts_init,
+        };
+        let key = format!("{JOURNAL_KEY_PREFIX}{}", annotation.annotation_id);
+        self.cache
+            .borrow_mut()
+            .add(&key, Bytes::from(serde_json::to_vec(&annotation)?))?;
+
+        log::debug!("Journal {annotation}");
+        msgbus::publish_any(get_journal_topic(), &annotation);
+        Ok(annotation)
+    }
+
+    /// Returns the annotations matching the `query`, ordered by `ts_event` then `ts_init`.
+    ///
+    /// Entries which fail to decode are logged and skipped.
+    #[must_use]
+    pub fn annotations(&self, query: &AnnotationQuery) -> Vec<Annotation> {
+        let cache = self.cache.borrow();
+        let mut annotations: Vec<Annotation> = cache
+            .general_with_prefix(JOURNAL_KEY_PREFIX)
+            .into_iter()
+            .filter_map(|(key, value)| match serde_json::from_slice(value) {
+                Ok(annotation) => Some(annotation),
+                Err(e) => {
+                    log::warn!("Skipping undecodable journal entry {key}: {e}");
+                    None
+                }
+            })
+            .filter(|annotation| matches(&cache, annotation, query))
+            .collect();
+        annotations.sort_by_key(|a| (a.ts_event, a.ts_init));
+        annotations
+    }
+
+    /// Returns the annotation with the `annotation_id` (if found).
+    #[must_use]
+    pub fn annotation(&self, annotation_id: &UUID4) -> Option<Annotation> {
+        let key = format!("{JOURNAL_KEY_PREFIX}{annotation_id}");
+        let cache = self.cache.borrow();
+        let value = cache.get(&key).ok()??;
+        serde_json::from_slice(value).ok()
+    }
+}
+
+fn matches(cache: &Cache, annotation: &Annotation, query: &AnnotationQuery) -> bool {
+    if query
+        .author
+        .is_some_and(|author| author != annotation.author)
+    {
+        return false;
+    }
+    if let Some((name, value)) = &query.tag {
+        match annotation.tags.get(name) {
+            Some(tag_value) if value.as_ref().is_none_or(|v| v == tag_value) => {}
+            _ => return false,
+        }
+    }
+
+    let (first, last) = match annotation.target {
+        AnnotationTarget::TimeRange { start, end } => (start, end),
+        _ => (annotation.ts_event, annotation.ts_event),
+    };
+    if query.start.is_some_and(|start| last < start) || query.end.is_some_and(|end| first > end) {
+        return false;
+    }
+
+    if let Some(client_order_id) = query.client_order_id
+        && annotation.target != AnnotationTarget::Order(client_order_id)
+    {
+        return false;
+    }
+    if let Some(instrument_id) = query.instrument_id {
+        let instrument_matches = match &annotation.target {
+            AnnotationTarget::Instrument(id) => *id == instrument_id,
+            AnnotationTarget::Order(client_order_id) => cache
+                .order(client_order_id)
+                .is_some_and(|order| order.instrument_id() == instrument_id),
+            AnnotationTarget::TimeRange { .. } => false,
+        };
+        if !instrument_matches {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        orders::builder::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn tags(pairs: &[(&str, &str)]) -> IndexMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    fn journal() -> TradingJournal {
+        let mut cache = Cache::default();
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("AUDUSD.SIM"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        cache.add_order(order, None, None, false).unwrap();
+        TradingJournal::new(Rc::new(RefCell::new(cache)))
+    }
+
+    #[rstest]
+    fn test_annotate_and_query() {
+        let journal = journal();
+        let author = Ustr::from("S-001");
+        let audusd = InstrumentId::from("AUDUSD.SIM");
+
+        let note = journal
+            .annotate(
+                author,
+                AnnotationTarget::Instrument(audusd),
+                "Spread widened ahead of RBA",
+                tags(&[("event", "RBA")]),
+                UnixNanos::from(10),
+                UnixNanos::from(11),
+            )
+            .unwrap();
+        journal
+            .annotate(
+                Ustr::from("operator"),
+                AnnotationTarget::Order(ClientOrderId::from("O-1")),
+                "Manually reduced size",
+                tags(&[("action", "override")]),
+                UnixNanos::from(20),
+                UnixNanos::from(21),
+            )
+            .unwrap();
+        journal
+            .annotate(
+                Ustr::from("operator"),
+                AnnotationTarget::TimeRange {
+                    start: UnixNanos::from(5),
+                    end: UnixNanos::from(30),
+                },
+                "Venue degraded",
+                IndexMap::new(),
+                UnixNanos::from(5),
+                UnixNanos::from(31),
+            )
+            .unwrap();
+
+        assert_eq!(journal.annotations(&AnnotationQuery::default()).len(), 3);
+        assert_eq!(journal.annotation(&note.annotation_id), Some(note));
+
+        // Instrument queries include annotations on the instrument's orders
+        let by_instrument = journal.annotations(&AnnotationQuery {
+            instrument_id: Some(audusd),
+            ..Default::default()
+        });
+        assert_eq!(by_instrument.len(), 2);
+        assert_eq!(by_instrument[0].text, "Spread widened ahead of RBA");
+
+        let by_tag = journal.annotations(&AnnotationQuery {
+            tag: Some(("action".to_string(), Some("override".to_string()))),
+            ..Default::default()
+        });
+        assert_eq!(by_tag.len(), 1);
+
+        // The time range overlaps the bounds, the point annotations do not
+        let by_time = journal.annotations(&AnnotationQuery {
+            start: Some(UnixNanos::from(25)),
+            end: Some(UnixNanos::from(40)),
+            ..Default::default()
+        });
+        assert_eq!(by_time.len(), 1);
+        assert_eq!(by_time[0].text, "Venue degraded");
+    }
+
+    #[rstest]
+    fn test_annotate_validation() {
+        let journal = journal();
+        let target = AnnotationTarget::TimeRange {
+            start: UnixNanos::from(2),
+            end: UnixNanos::from(1),
+        };
+
+        assert!(
+            journal
+                .annotate(
+                    Ustr::from("operator"),
+                    target,
+                    "note",
+                    IndexMap::new(),
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                )
+                .is_err()
+        );
+        assert!(
+            journal
+                .annotate(
+                    Ustr::from("operator"),
+                    AnnotationTarget::Instrument(InstrumentId::from("AUDUSD.SIM")),
+                    "  ",
+                    IndexMap::new(),
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                )
+                .is_err()
+        );
+    }
+}
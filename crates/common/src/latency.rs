@@ -0,0 +1,406 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tick-to-trade latency instrumentation.
+//!
+//! The [`LatencyTracker`] correlates the timestamps of a single trading decision as it moves
+//! through the system:
+//!
+//! 1. Market data arrival (the `ts_init` of the triggering data).
+//! 2. Strategy decision.
+//! 3. Command sent to the execution client.
+//! 4. Venue acknowledgement (accepted, rejected or filled).
+//!
+//! Decisions are correlated on the [`ClientOrderId`] of the resulting order, and the elapsed time
+//! between each stage is recorded into a [`LatencyHistogram`]. A [`LatencySnapshot`] of all
+//! histograms can be published on the telemetry topic for live performance monitoring.
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{events::OrderEventAny, identifiers::ClientOrderId, orders::Order};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    histogram::{HistogramSummary, LatencyHistogram},
+    messages::execution::TradingCommand,
+    msgbus::{self, switchboard::MessagingSwitchboard},
+};
+
+/// The default maximum number of in-flight decisions tracked.
+pub const DEFAULT_MAX_PENDING_DECISIONS: usize = 10_000;
+
+/// An interval between two stages of a trading decision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Display, EnumIter, Serialize, Deserialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyInterval {
+    /// Market data arrival to strategy decision.
+    DataToDecision,
+    /// Strategy decision to command sent.
+    DecisionToSend,
+    /// Command sent to venue acknowledgement.
+    SendToAck,
+    /// Market data arrival to command sent (tick-to-order).
+    TickToOrder,
+    /// Market data arrival to venue acknowledgement (tick-to-trade).
+    TickToTrade,
+}
+
+/// The timestamps recorded for a single trading decision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecisionTimestamps {
+    /// UNIX timestamp (nanoseconds) when the triggering market data arrived.
+    pub ts_data: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the strategy made the decision.
+    pub ts_decision: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the command was sent.
+    pub ts_sent: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the venue acknowledgement was received.
+    pub ts_ack: Option<UnixNanos>,
+}
+
+/// A snapshot of the latency histograms for publishing on the telemetry bus.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencySnapshot {
+    /// The number of decisions which completed all stages.
+    pub completed: u64,
+    /// The number of decisions currently in flight.
+    pub pending: usize,
+    /// The number of decisions dropped before completion.
+    pub dropped: u64,
+    /// The summary of each interval histogram (nanoseconds).
+    pub intervals: Vec<(LatencyInterval, HistogramSummary)>,
+    /// UNIX timestamp (nanoseconds) when the snapshot was taken.
+    pub ts_init: UnixNanos,
+}
+
+/// Correlates decision timestamps and records stage latencies into histograms.
+#[derive(Debug)]
+pub struct LatencyTracker {
+    pending: IndexMap<ClientOrderId, DecisionTimestamps>,
+    histograms: IndexMap<LatencyInterval, LatencyHistogram>,
+    max_pending: usize,
+    completed: u64,
+    dropped: u64,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PENDING_DECISIONS)
+    }
+}
+
+impl LatencyTracker {
+    /// Creates a new [`LatencyTracker`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_pending` is zero.
+    #[must_use]
+    pub fn new(max_pending: usize) -> Self {
+        assert!(max_pending > 0, "`max_pending` must be positive");
+        Self {
+            pending: IndexMap::new(),
+            histograms: LatencyInterval::iter()
+                .map(|interval| (interval, LatencyHistogram::new()))
+                .collect(),
+            max_pending,
+            completed: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Records a strategy decision for `client_order_id` triggered by data arriving at `ts_data`.
+    ///
+    /// If the maximum number of in-flight decisions is reached, the oldest is dropped.
+    pub fn record_decision(
+        &mut self,
+        client_order_id: ClientOrderId,
+        ts_data: UnixNanos,
+        ts_decision: UnixNanos,
+    ) {
+        if self.pending.len() >= self.max_pending && !self.pending.contains_key(&client_order_id) {
+            self.pending.shift_remove_index(0);
+            self.dropped += 1;
+        }
+
+        self.pending.insert(
+            client_order_id,
+            DecisionTimestamps {
+                ts_data,
+                ts_decision,
+                ts_sent: None,
+                ts_ack: None,
+            },
+        );
+    }
+
+    /// Records the time the command for `client_order_id` was sent.
+    pub fn record_sent(&mut self, client_order_id: &ClientOrderId, ts_sent: UnixNanos) {
+        if let Some(timestamps) = self.pending.get_mut(client_order_id)
+            && timestamps.ts_sent.is_none()
+        {
+            timestamps.ts_sent = Some(ts_sent);
+        }
+    }
+
+    /// Records the venue acknowledgement for `client_order_id`, completing the decision.
+    ///
+    /// Returns the completed timestamps, or `None` if the decision was not being tracked.
+    pub fn record_ack(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        ts_ack: UnixNanos,
+    ) -> Option<DecisionTimestamps> {
+        let mut timestamps = self.pending.shift_remove(client_order_id)?;
+        timestamps.ts_ack = Some(ts_ack);
+
+        self.record_interval(
+            LatencyInterval::DataToDecision,
+            timestamps.ts_data,
+            timestamps.ts_decision,
+        );
+        self.record_interval(LatencyInterval::TickToTrade, timestamps.ts_data, ts_ack);
+
+        if let Some(ts_sent) = timestamps.ts_sent {
+            self.record_interval(
+                LatencyInterval::DecisionToSend,
+                timestamps.ts_decision,
+                ts_sent,
+            );
+            self.record_interval(LatencyInterval::TickToOrder, timestamps.ts_data, ts_sent);
+            self.record_interval(LatencyInterval::SendToAck, ts_sent, ts_ack);
+        }
+
+        self.completed += 1;
+        Some(timestamps)
+    }
+
+    /// Handles a trading command sent at `ts_sent`, recording the send time for submitted orders.
+    pub fn handle_command(&mut self, command: &TradingCommand, ts_sent: UnixNanos) {
+        match command {
+            TradingCommand::SubmitOrder(cmd) => self.record_sent(&cmd.client_order_id, ts_sent),
+            TradingCommand::SubmitSpreadOrder(cmd) => {
+                self.record_sent(&cmd.client_order_id, ts_sent);
+            }
+            TradingCommand::SubmitOrderList(cmd) => {
+                for order in &cmd.order_list.orders {
+                    self.record_sent(&order.client_order_id(), ts_sent);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles an order event, completing the decision on the first venue acknowledgement.
+    ///
+    /// The event `ts_init` (local receipt time) is used as the acknowledgement time. Orders
+    /// denied before reaching the venue are dropped without recording.
+    pub fn handle_order_event(&mut self, event: &OrderEventAny) {
+        match event {
+            OrderEventAny::Accepted(e) => {
+                self.record_ack(&e.client_order_id, e.ts_init);
+            }
+            OrderEventAny::Rejected(e) => {
+                self.record_ack(&e.client_order_id, e.ts_init);
+            }
+            OrderEventAny::Filled(e) => {
+                self.record_ack(&e.client_order_id, e.ts_init);
+            }
+            OrderEventAny::Denied(e) if self.pending.shift_remove(&e.client_order_id).is_some() => {
+                self.dropped += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the timestamps of an in-flight decision.
+    #[must_use]
+    pub fn pending(&self, client_order_id: &ClientOrderId) -> Option<&DecisionTimestamps> {
+        self.pending.get(client_order_id)
+    }
+
+    /// Returns the number of decisions currently in flight.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the number of completed decisions.
+    #[must_use]
+    pub const fn completed_count(&self) -> u64 {
+        self.completed
+    }
+
+    /// Returns the number of decisions dropped before completion.
+    #[must_use]
+    pub const fn dropped_count(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Returns the histogram for the given `interval`.
+    #[must_use]
+    pub fn histogram(&self, interval: LatencyInterval) -> &LatencyHistogram {
+        &self.histograms[&interval]
+    }
+
+    /// Returns a snapshot of all interval histograms.
+    #[must_use]
+    pub fn snapshot(&self, ts_init: UnixNanos) -> LatencySnapshot {
+        LatencySnapshot {
+            completed: self.completed,
+            pending: self.pending.len(),
+            dropped: self.dropped,
+            intervals: self
+                .histograms
+                .iter()
+                .map(|(interval, histogram)| (*interval, histogram.summary()))
+                .collect(),
+            ts_init,
+        }
+    }
+
+    /// Publishes a snapshot on the latency telemetry topic.
+    pub fn publish(&self, ts_init: UnixNanos) {
+        let snapshot = self.snapshot(ts_init);
+        msgbus::publish_any(MessagingSwitchboard::telemetry_latency_topic(), &snapshot);
+    }
+
+    /// Clears all in-flight decisions, histograms and counters.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.histograms
+            .values_mut()
+            .for_each(LatencyHistogram::reset);
+        self.completed = 0;
+        self.dropped = 0;
+    }
+
+    fn record_interval(&mut self, interval: LatencyInterval, start: UnixNanos, end: UnixNanos) {
+        if let Some(histogram) = self.histograms.get_mut(&interval) {
+            histogram.record(end.as_u64().saturating_sub(start.as_u64()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::ShareableMessageHandler;
+
+    fn coid() -> ClientOrderId {
+        ClientOrderId::from("O-001")
+    }
+
+    #[rstest]
+    fn test_full_decision_records_all_intervals() {
+        let mut tracker = LatencyTracker::default();
+
+        tracker.record_decision(coid(), UnixNanos::from(1_000), UnixNanos::from(1_100));
+        tracker.record_sent(&coid(), UnixNanos::from(1_300));
+        let timestamps = tracker.record_ack(&coid(), UnixNanos::from(2_000)).unwrap();
+
+        assert_eq!(timestamps.ts_ack, Some(UnixNanos::from(2_000)));
+        assert_eq!(tracker.completed_count(), 1);
+        assert_eq!(tracker.pending_count(), 0);
+
+        let expected = [
+            (LatencyInterval::DataToDecision, 100),
+            (LatencyInterval::DecisionToSend, 200),
+            (LatencyInterval::SendToAck, 700),
+            (LatencyInterval::TickToOrder, 300),
+            (LatencyInterval::TickToTrade, 1_000),
+        ];
+        for (interval, value) in expected {
+            assert_eq!(tracker.histogram(interval).count(), 1);
+            assert_eq!(tracker.histogram(interval).max(), Some(value));
+        }
+    }
+
+    #[rstest]
+    fn test_ack_without_send_records_partial_intervals() {
+        let mut tracker = LatencyTracker::default();
+
+        tracker.record_decision(coid(), UnixNanos::from(1_000), UnixNanos::from(1_100));
+        tracker.record_ack(&coid(), UnixNanos::from(2_000));
+
+        assert_eq!(tracker.histogram(LatencyInterval::TickToTrade).count(), 1);
+        assert!(tracker.histogram(LatencyInterval::SendToAck).is_empty());
+    }
+
+    #[rstest]
+    fn test_untracked_ack_is_ignored() {
+        let mut tracker = LatencyTracker::default();
+        assert!(tracker.record_ack(&coid(), UnixNanos::from(1)).is_none());
+        assert_eq!(tracker.completed_count(), 0);
+    }
+
+    #[rstest]
+    fn test_max_pending_drops_oldest() {
+        let mut tracker = LatencyTracker::new(1);
+
+        tracker.record_decision(coid(), UnixNanos::from(1), UnixNanos::from(2));
+        tracker.record_decision(
+            ClientOrderId::from("O-002"),
+            UnixNanos::from(3),
+            UnixNanos::from(4),
+        );
+
+        assert_eq!(tracker.pending_count(), 1);
+        assert_eq!(tracker.dropped_count(), 1);
+        assert!(tracker.pending(&coid()).is_none());
+    }
+
+    #[rstest]
+    fn test_snapshot_and_reset() {
+        let mut tracker = LatencyTracker::default();
+        tracker.record_decision(coid(), UnixNanos::from(1_000), UnixNanos::from(1_100));
+        tracker.record_ack(&coid(), UnixNanos::from(2_000));
+
+        let snapshot = tracker.snapshot(UnixNanos::from(5_000));
+        assert_eq!(snapshot.completed, 1);
+        assert_eq!(snapshot.intervals.len(), 5);
+        assert_eq!(snapshot.ts_init, UnixNanos::from(5_000));
+
+        tracker.reset();
+        assert_eq!(tracker.completed_count(), 0);
+        assert!(tracker.histogram(LatencyInterval::TickToTrade).is_empty());
+    }
+
+    #[rstest]
+    fn test_publish_snapshot() {
+        let received: Rc<RefCell<Vec<LatencySnapshot>>> = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        let handler = ShareableMessageHandler::from_typed(move |snapshot: &LatencySnapshot| {
+            received_clone.borrow_mut().push(snapshot.clone());
+        });
+        msgbus::subscribe_any(
+            MessagingSwitchboard::telemetry_latency_topic().into(),
+            handler,
+            None,
+        );
+
+        let tracker = LatencyTracker::default();
+        tracker.publish(UnixNanos::from(1));
+
+        assert_eq!(received.borrow().len(), 1);
+        assert_eq!(received.borrow()[0].ts_init, UnixNanos::from(1));
+    }
+}
@@ -41,6 +41,8 @@
 //! - `stubs`: Enables type stubs for use in testing scenarios.
 //! - `defi`: Enables DeFi (Decentralized Finance) support.
 //! - `indicators`: Includes the `nautilus-indicators` crate and indicator utilities.
+//! - `arrow`: Enables encoding historical data responses into [Arrow](https://arrow.apache.org) record batches.
+//! - `flight`: Serves the file catalog over [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) gRPC.
 //! - `capnp`: Enables [Cap'n Proto](https://capnproto.org/) serialization support.
 //! - `extension-module`: Builds the crate as a Python extension module.
 
@@ -53,24 +55,70 @@
 #![deny(clippy::missing_panics_doc)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod accounts;
+pub mod accrual;
 pub mod actor;
+pub mod alerts;
+pub mod analytics;
+pub mod auto_cancel;
+pub mod backtest_diff;
+pub mod bandwidth;
+pub mod bracket;
 pub mod cache;
+pub mod catalog;
+pub mod chaos;
 pub mod clients;
 pub mod clock;
+pub mod clock_sync;
+pub mod coalescer;
 pub mod component;
+pub mod component_graph;
+pub mod conditional;
+pub mod crash;
 pub mod custom;
+pub mod depth;
 pub mod enums;
+pub mod eod;
+pub mod execution_costs;
+pub mod execution_summary;
 pub mod factories;
+pub mod failover;
+pub mod flow;
 pub mod generators;
 pub mod greeks;
+pub mod grid;
+pub mod histogram;
+pub mod inflight;
+pub mod journal;
+pub mod latency;
 pub mod logging;
+pub mod market_maker;
 pub mod messages;
 pub mod msgbus;
+pub mod namespace;
+pub mod pacing;
+pub mod paper;
+pub mod parity;
+pub mod peg;
+pub mod portfolio_export;
+pub mod quality;
+pub mod queue_position;
+pub mod reconnect;
+pub mod reference;
+pub mod risk;
 pub mod runner;
+pub mod session_stats;
 pub mod signal;
+pub mod skew;
+pub mod spread;
+pub mod synthetic;
+pub mod tca;
 pub mod testing;
 pub mod throttler;
+pub mod time_bars;
 pub mod timer;
+pub mod timer_store;
+pub mod trade_report;
 pub mod xrate;
 
 #[cfg(feature = "live")]
@@ -87,3 +135,6 @@ pub mod python;
 
 #[cfg(feature = "capnp")]
 pub mod serialization;
+
+#[cfg(feature = "web")]
+pub mod book_viz;
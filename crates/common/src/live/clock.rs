@@ -35,7 +35,8 @@ use crate::{
     clock::{CallbackRegistry, Clock, validate_and_prepare_time_alert, validate_and_prepare_timer},
     runner::{TimeEventSender, try_get_time_event_sender},
     timer::{
-        ScheduledTimeEvent, TimeEvent, TimeEventCallback, TimeEventHandler, create_valid_interval,
+        ScheduledTimeEvent, TimeEvent, TimeEventCallback, TimeEventCoalescePolicy,
+        TimeEventCoalescer, TimeEventHandler, create_valid_interval,
     },
 };
 
@@ -52,6 +53,7 @@ pub struct LiveClock {
     timers: BTreeMap<Ustr, LiveTimer>,
     callbacks: CallbackRegistry,
     sender: Option<Arc<dyn TimeEventSender>>,
+    coalescer: TimeEventCoalescer,
 }
 
 impl LiveClock {
@@ -63,6 +65,7 @@ impl LiveClock {
             timers: BTreeMap::new(),
             callbacks: CallbackRegistry::new(),
             sender,
+            coalescer: TimeEventCoalescer::new(),
         }
     }
 
@@ -71,6 +74,21 @@ impl LiveClock {
         &self.timers
     }
 
+    /// Returns the coalescer applying the timers' [`TimeEventCoalescePolicy`]s.
+    #[must_use]
+    pub const fn coalescer(&self) -> &TimeEventCoalescer {
+        &self.coalescer
+    }
+
+    /// Coalesces a batch of handlers drained from the time event channel, according to the
+    /// policy each timer was set with.
+    ///
+    /// Live timers send every event as it fires, so a runner draining the channel after a stall
+    /// should pass the drained batch through here before dispatching it.
+    pub fn coalesce_handlers(&mut self, handlers: Vec<TimeEventHandler>) -> Vec<TimeEventHandler> {
+        self.coalescer.coalesce_handlers(handlers)
+    }
+
     fn clear_expired_timers(&mut self) {
         self.timers.retain(|_, timer| !timer.is_expired());
     }
@@ -155,6 +173,7 @@ impl Clock for LiveClock {
         alert_time_ns: UnixNanos,
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
         let ts_now = self.get_time_ns();
         let (name, alert_time_ns) =
@@ -193,6 +212,7 @@ impl Clock for LiveClock {
 
         self.clear_expired_timers();
         self.timers.insert(name, timer);
+        self.coalescer.set_policy(name, coalesce);
 
         Ok(())
     }
@@ -206,6 +226,7 @@ impl Clock for LiveClock {
         callback: Option<TimeEventCallback>,
         allow_past: Option<bool>,
         fire_immediately: Option<bool>,
+        coalesce: Option<TimeEventCoalescePolicy>,
     ) -> anyhow::Result<()> {
         let ts_now = self.get_time_ns();
         let (name, start_time_ns, stop_time_ns, _allow_past, fire_immediately) =
@@ -250,6 +271,7 @@ impl Clock for LiveClock {
 
         self.clear_expired_timers();
         self.timers.insert(name, timer);
+        self.coalescer.set_policy(name, coalesce);
 
         Ok(())
     }
@@ -261,15 +283,18 @@ impl Clock for LiveClock {
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
+        let name = Ustr::from(name);
+        self.coalescer.deregister(&name);
+        let timer = self.timers.remove(&name);
         if let Some(mut timer) = timer {
             timer.cancel();
         }
     }
 
     fn cancel_timers(&mut self) {
-        for timer in &mut self.timers.values_mut() {
+        for (name, timer) in &mut self.timers {
             timer.cancel();
+            self.coalescer.deregister(name);
         }
 
         self.timers.clear();
@@ -379,7 +404,7 @@ mod tests {
 
         let fast_interval = Duration::from_millis(10).as_nanos() as u64;
         clock
-            .set_timer_ns("replace", fast_interval, None, None, None, None, None)
+            .set_timer_ns("replace", fast_interval, None, None, None, None, None, None)
             .unwrap();
 
         wait_for_events(&events, 2, Duration::from_millis(200));
@@ -387,7 +412,7 @@ mod tests {
 
         let slow_interval = Duration::from_millis(30).as_nanos() as u64;
         clock
-            .set_timer_ns("replace", slow_interval, None, None, None, None, None)
+            .set_timer_ns("replace", slow_interval, None, None, None, None, None, None)
             .unwrap();
 
         wait_for_events(&events, 3, Duration::from_millis(300));
@@ -418,7 +443,7 @@ mod tests {
         let alert_time = now + 1_000_u64;
 
         clock
-            .set_time_alert_ns("alert-callback", alert_time, None, None)
+            .set_time_alert_ns("alert-callback", alert_time, None, None, None)
             .unwrap();
 
         assert!(
@@ -447,6 +472,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -494,6 +520,7 @@ mod tests {
                 None,
                 None,
                 Some(true),
+                None,
             )
             .unwrap();
 
@@ -22,12 +22,16 @@ pub mod clock;
 pub mod listener;
 pub mod runner;
 pub mod runtime;
+pub mod tasks;
 pub mod timer;
 
 pub use clock::{LiveClock, TimeEventStream};
 pub use listener::MessageBusListener;
 pub use runner::{
-    get_data_event_sender, get_exec_event_sender, set_data_event_sender, set_exec_event_sender,
+    ChannelConfig, ChannelMetrics, EventReceiver, EventSender, OverflowPolicy,
+    data_event_channel_metrics, event_channel, exec_event_channel_metrics, get_data_event_sender,
+    get_exec_event_sender, set_data_event_sender, set_exec_event_sender,
 };
 pub use runtime::{get_runtime, shutdown_runtime};
+pub use tasks::{TaskFault, TaskRegistry, get_task_registry};
 pub use timer::LiveTimer;
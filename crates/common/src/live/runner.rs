@@ -15,19 +15,382 @@
 
 //! Tokio-based channel senders for live trading runtime.
 //!
-//! This module provides thread-local storage for tokio mpsc channels used in live trading.
+//! This module provides thread-local storage for the bounded event channels used in live
+//! trading. Each channel has a fixed capacity and an [`OverflowPolicy`] deciding what happens
+//! when a burst fills it: senders wait for space, the oldest queued event is dropped, or the new
+//! event is dropped. Drops are counted, and [`ChannelMetrics`] reports the queue depth, its high
+//! water mark and the resulting saturation.
+//!
+//! Only [`EventSender::send_async`] ever waits for capacity. Synchronous sends run on the same
+//! thread as the runner draining the channel, so waiting there could never be satisfied;
+//! [`EventSender::try_send`] instead returns [`TrySendError::Full`] under
+//! [`OverflowPolicy::Block`] and leaves the event with the caller.
+
+use std::{
+    cell::OnceCell,
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
-use std::cell::OnceCell;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
 
 use crate::messages::{DataEvent, ExecutionEvent};
 
+/// What a bounded event channel does with an event sent while it is full.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, strum::Display, Serialize, Deserialize,
+)]
+pub enum OverflowPolicy {
+    /// Async senders wait until the receiver frees capacity, synchronous sends are rejected
+    /// with [`TrySendError::Full`].
+    #[default]
+    Block,
+    /// The oldest queued event is dropped to make room.
+    DropOldest,
+    /// The new event is dropped.
+    DropNewest,
+}
+
+/// Configuration for a bounded event channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelConfig {
+    /// The maximum number of queued events.
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for ChannelConfig {
+    /// Creates a new default [`ChannelConfig`] instance.
+    fn default() -> Self {
+        Self {
+            capacity: 65_536,
+            overflow: OverflowPolicy::Block,
+        }
+    }
+}
+
+/// A point-in-time view of a bounded event channel's usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelMetrics {
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+    /// The number of currently queued events.
+    pub len: usize,
+    /// The largest number of events queued at once.
+    pub high_water_mark: usize,
+    /// The number of events accepted into the channel.
+    pub sent: u64,
+    /// The number of queued events dropped by [`OverflowPolicy::DropOldest`].
+    pub dropped_oldest: u64,
+    /// The number of new events dropped by [`OverflowPolicy::DropNewest`].
+    pub dropped_newest: u64,
+    /// The number of async sends which had to wait for capacity under [`OverflowPolicy::Block`].
+    pub blocked: u64,
+    /// The number of synchronous sends rejected as full under [`OverflowPolicy::Block`].
+    pub rejected: u64,
+}
+
+impl ChannelMetrics {
+    /// Returns the fraction of capacity in use, from 0 to 1.
+    #[must_use]
+    pub fn saturation(&self) -> f64 {
+        self.len as f64 / self.capacity as f64
+    }
+
+    /// Returns the total number of dropped events.
+    #[must_use]
+    pub const fn dropped(&self) -> u64 {
+        self.dropped_oldest + self.dropped_newest
+    }
+}
+
+/// The error returned when sending on a closed event channel, holding the unsent event.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Event channel closed")]
+pub struct ChannelClosed<T>(pub T);
+
+/// The error returned by [`EventSender::try_send`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TrySendError<T> {
+    /// The channel is full and its policy is [`OverflowPolicy::Block`].
+    #[error("Event channel full")]
+    Full(T),
+    #[error("Event channel closed")]
+    Closed(T),
+}
+
+#[derive(Debug)]
+struct ChannelState<T> {
+    queue: VecDeque<T>,
+    closed: bool,
+    metrics: ChannelMetrics,
+}
+
+#[derive(Debug)]
+struct ChannelShared<T> {
+    state: Mutex<ChannelState<T>>,
+    senders: AtomicUsize,
+    /// Wakes the receiver when an event is queued or the last sender drops.
+    recv_ready: Notify,
+    /// Wakes async senders when capacity frees up or the channel closes.
+    space_ready: Notify,
+}
+
+impl<T> ChannelShared<T> {
+    fn lock(&self) -> MutexGuard<'_, ChannelState<T>> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Queues `event` if there is capacity (or the policy makes room), otherwise hands it back.
+    fn push(&self, state: &mut ChannelState<T>, event: T) -> Option<T> {
+        let metrics = &mut state.metrics;
+        if state.queue.len() >= metrics.capacity {
+            match metrics.overflow {
+                OverflowPolicy::Block => return Some(event),
+                OverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                    metrics.dropped_oldest += 1;
+                }
+                OverflowPolicy::DropNewest => {
+                    metrics.dropped_newest += 1;
+                    return None;
+                }
+            }
+        }
+        state.queue.push_back(event);
+        metrics.sent += 1;
+        metrics.high_water_mark = metrics.high_water_mark.max(state.queue.len());
+        self.recv_ready.notify_one();
+        None
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.space_ready.notify_waiters();
+    }
+}
+
+/// Creates a bounded event channel with the given `config`.
+///
+/// # Panics
+///
+/// Panics if `config.capacity` is zero.
+#[must_use]
+pub fn event_channel<T>(config: ChannelConfig) -> (EventSender<T>, EventReceiver<T>) {
+    assert!(
+        config.capacity > 0,
+        "Event channel capacity must be positive"
+    );
+    let shared = Arc::new(ChannelShared {
+        state: Mutex::new(ChannelState {
+            queue: VecDeque::new(),
+            closed: false,
+            metrics: ChannelMetrics {
+                capacity: config.capacity,
+                overflow: config.overflow,
+                len: 0,
+                high_water_mark: 0,
+                sent: 0,
+                dropped_oldest: 0,
+                dropped_newest: 0,
+                blocked: 0,
+                rejected: 0,
+            },
+        }),
+        senders: AtomicUsize::new(1),
+        recv_ready: Notify::new(),
+        space_ready: Notify::new(),
+    });
+    (
+        EventSender {
+            shared: shared.clone(),
+        },
+        EventReceiver { shared },
+    )
+}
+
+fn metrics_of<T>(state: &ChannelState<T>) -> ChannelMetrics {
+    ChannelMetrics {
+        len: state.queue.len(),
+        ..state.metrics
+    }
+}
+
+/// The sending half of a bounded event channel.
+pub struct EventSender<T> {
+    shared: Arc<ChannelShared<T>>,
+}
+
+impl<T> Debug for EventSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(EventSender))
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.recv_ready.notify_one();
+        }
+    }
+}
+
+impl<T> EventSender<T> {
+    /// Sends `event` without waiting.
+    ///
+    /// Under the drop policies a full channel drops an event (counted in the metrics) and the
+    /// send succeeds. Under [`OverflowPolicy::Block`] a full channel rejects the send (counted
+    /// as `rejected`) rather than blocking the thread, which may be the one draining it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error holding `event` if the channel is closed, or full under
+    /// [`OverflowPolicy::Block`].
+    pub fn try_send(&self, event: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if state.closed {
+            return Err(TrySendError::Closed(event));
+        }
+        match self.shared.push(&mut state, event) {
+            Some(event) => {
+                state.metrics.rejected += 1;
+                Err(TrySendError::Full(event))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Sends `event`, waiting while the channel is full under [`OverflowPolicy::Block`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error holding `event` if the channel is closed.
+    pub async fn send_async(&self, event: T) -> Result<(), ChannelClosed<T>> {
+        let mut event = event;
+        let mut waited = false;
+        loop {
+            let notified = self.shared.space_ready.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            {
+                let mut state = self.shared.lock();
+                if state.closed {
+                    return Err(ChannelClosed(event));
+                }
+                match self.shared.push(&mut state, event) {
+                    None => return Ok(()),
+                    Some(returned) => {
+                        event = returned;
+                        if !waited {
+                            state.metrics.blocked += 1;
+                            waited = true;
+                        }
+                    }
+                }
+            }
+            notified.await;
+        }
+    }
+
+    /// Returns whether the receiver has closed the channel.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().closed
+    }
+
+    /// Returns the channel's current metrics.
+    #[must_use]
+    pub fn metrics(&self) -> ChannelMetrics {
+        metrics_of(&self.shared.lock())
+    }
+}
+
+/// The receiving half of a bounded event channel.
+///
+/// Dropping the receiver closes the channel.
+pub struct EventReceiver<T> {
+    shared: Arc<ChannelShared<T>>,
+}
+
+impl<T> Debug for EventReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(EventReceiver))
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.close();
+    }
+}
+
+impl<T> EventReceiver<T> {
+    fn pop(&self) -> Option<T> {
+        let event = self.shared.lock().queue.pop_front()?;
+        self.shared.space_ready.notify_one();
+        Some(event)
+    }
+
+    /// Receives the next event, waiting until one is sent.
+    ///
+    /// Returns `None` once all senders have dropped and the queue is drained.
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            if let Some(event) = self.pop() {
+                return Some(event);
+            }
+            if self.shared.senders.load(Ordering::Acquire) == 0 {
+                // A send may have completed between the pop and the sender count check
+                return self.pop();
+            }
+            self.shared.recv_ready.notified().await;
+        }
+    }
+
+    /// Receives the next queued event without waiting.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    /// Closes the channel to further sends, keeping queued events receivable.
+    pub fn close(&mut self) {
+        self.shared.close();
+    }
+
+    /// Returns the channel's current metrics.
+    #[must_use]
+    pub fn metrics(&self) -> ChannelMetrics {
+        metrics_of(&self.shared.lock())
+    }
+}
+
 /// Gets the global data event sender.
 ///
 /// # Panics
 ///
 /// Panics if the sender is uninitialized.
 #[must_use]
-pub fn get_data_event_sender() -> tokio::sync::mpsc::UnboundedSender<DataEvent> {
+pub fn get_data_event_sender() -> EventSender<DataEvent> {
     DATA_EVENT_SENDER.with(|sender| {
         sender
             .get()
@@ -43,7 +406,7 @@ pub fn get_data_event_sender() -> tokio::sync::mpsc::UnboundedSender<DataEvent>
 /// # Panics
 ///
 /// Panics if a sender has already been set.
-pub fn set_data_event_sender(sender: tokio::sync::mpsc::UnboundedSender<DataEvent>) {
+pub fn set_data_event_sender(sender: EventSender<DataEvent>) {
     DATA_EVENT_SENDER.with(|s| {
         assert!(
             s.set(sender).is_ok(),
@@ -52,13 +415,19 @@ pub fn set_data_event_sender(sender: tokio::sync::mpsc::UnboundedSender<DataEven
     });
 }
 
+/// Returns the metrics of the global data event channel (if initialized).
+#[must_use]
+pub fn data_event_channel_metrics() -> Option<ChannelMetrics> {
+    DATA_EVENT_SENDER.with(|sender| sender.get().map(EventSender::metrics))
+}
+
 /// Gets the global execution event sender.
 ///
 /// # Panics
 ///
 /// Panics if the sender is uninitialized.
 #[must_use]
-pub fn get_exec_event_sender() -> tokio::sync::mpsc::UnboundedSender<ExecutionEvent> {
+pub fn get_exec_event_sender() -> EventSender<ExecutionEvent> {
     EXEC_EVENT_SENDER.with(|sender| {
         sender
             .get()
@@ -74,7 +443,7 @@ pub fn get_exec_event_sender() -> tokio::sync::mpsc::UnboundedSender<ExecutionEv
 /// # Panics
 ///
 /// Panics if a sender has already been set.
-pub fn set_exec_event_sender(sender: tokio::sync::mpsc::UnboundedSender<ExecutionEvent>) {
+pub fn set_exec_event_sender(sender: EventSender<ExecutionEvent>) {
     EXEC_EVENT_SENDER.with(|s| {
         assert!(
             s.set(sender).is_ok(),
@@ -83,7 +452,115 @@ pub fn set_exec_event_sender(sender: tokio::sync::mpsc::UnboundedSender<Executio
     });
 }
 
+/// Returns the metrics of the global execution event channel (if initialized).
+#[must_use]
+pub fn exec_event_channel_metrics() -> Option<ChannelMetrics> {
+    EXEC_EVENT_SENDER.with(|sender| sender.get().map(EventSender::metrics))
+}
+
 thread_local! {
-    static DATA_EVENT_SENDER: OnceCell<tokio::sync::mpsc::UnboundedSender<DataEvent>> = const { OnceCell::new() };
-    static EXEC_EVENT_SENDER: OnceCell<tokio::sync::mpsc::UnboundedSender<ExecutionEvent>> = const { OnceCell::new() };
+    static DATA_EVENT_SENDER: OnceCell<EventSender<DataEvent>> = const { OnceCell::new() };
+    static EXEC_EVENT_SENDER: OnceCell<EventSender<ExecutionEvent>> = const { OnceCell::new() };
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn config(capacity: usize, overflow: OverflowPolicy) -> ChannelConfig {
+        ChannelConfig { capacity, overflow }
+    }
+
+    #[rstest]
+    fn test_drop_oldest_keeps_latest_events() {
+        let (tx, mut rx) = event_channel(config(2, OverflowPolicy::DropOldest));
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        assert_eq!(rx.try_recv(), Some(3));
+        assert_eq!(rx.try_recv(), Some(4));
+        assert_eq!(rx.try_recv(), None);
+
+        let metrics = rx.metrics();
+        assert_eq!(metrics.sent, 5);
+        assert_eq!(metrics.dropped_oldest, 3);
+        assert_eq!(metrics.high_water_mark, 2);
+        assert_eq!(metrics.len, 0);
+    }
+
+    #[rstest]
+    fn test_drop_newest_keeps_earliest_events() {
+        let (tx, mut rx) = event_channel(config(2, OverflowPolicy::DropNewest));
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        let metrics = tx.metrics();
+        assert_eq!(metrics.dropped_newest, 3);
+        assert_eq!(metrics.dropped(), 3);
+        assert!((metrics.saturation() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(rx.try_recv(), Some(0));
+        assert_eq!(rx.try_recv(), Some(1));
+    }
+
+    #[rstest]
+    fn test_block_policy_rejects_sync_sends_when_full() {
+        let (tx, mut rx) = event_channel(config(1, OverflowPolicy::Block));
+        tx.try_send(1).unwrap();
+
+        // Returns immediately rather than waiting on the receiver
+        assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
+        assert_eq!(rx.metrics().rejected, 1);
+
+        assert_eq!(rx.try_recv(), Some(1));
+        tx.try_send(2).unwrap();
+        assert_eq!(rx.try_recv(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_block_policy_async_send_waits_for_capacity() {
+        let (tx, mut rx) = event_channel(config(1, OverflowPolicy::Block));
+        tx.try_send(1).unwrap();
+
+        let sender = tokio::spawn(async move { tx.send_async(2).await });
+        while rx.metrics().blocked == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(rx.metrics().len, 1);
+
+        assert_eq!(rx.recv().await, Some(1));
+        sender.await.unwrap().unwrap();
+        assert_eq!(rx.recv().await, Some(2));
+        assert_eq!(rx.metrics().rejected, 0);
+    }
+
+    #[tokio::test]
+    async fn test_async_send_and_recv() {
+        let (tx, mut rx) = event_channel(config(1, OverflowPolicy::Block));
+        let sender = tokio::spawn(async move {
+            for i in 0..3 {
+                tx.send_async(i).await.unwrap();
+            }
+        });
+
+        let mut received = Vec::new();
+        while let Some(event) = rx.recv().await {
+            received.push(event);
+        }
+        sender.await.unwrap();
+        assert_eq!(received, vec![0, 1, 2]);
+    }
+
+    #[rstest]
+    fn test_closed_channel_rejects_sends() {
+        let (tx, rx) = event_channel(config(1, OverflowPolicy::Block));
+        tx.try_send(1).unwrap();
+        drop(rx);
+
+        assert!(tx.is_closed());
+        assert_eq!(tx.try_send(2), Err(TrySendError::Closed(2)));
+    }
 }
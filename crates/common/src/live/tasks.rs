@@ -0,0 +1,353 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-component registry of spawned async tasks.
+//!
+//! Live components spawn their tokio tasks through the [`TaskRegistry`] rather than holding
+//! loose `JoinHandle`s. Tasks are tracked by the ID of the component which owns them, so all of a
+//! component's tasks can be cancelled together when it stops or faults. Each task is wrapped with
+//! [`catch_panic_task`], which logs a crash report for a panic, and a supervising task awaits
+//! each spawned task and records panics as [`TaskFault`]s; because components live on the
+//! thread which registered them, [`TaskRegistry::fault_components`] must be called from that
+//! thread to fault the owning components.
+
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, OnceLock},
+};
+
+use ahash::AHashMap;
+use tokio::task::{AbortHandle, JoinError};
+use ustr::Ustr;
+
+use super::runtime::get_runtime;
+use crate::{component::fault_component, crash::catch_panic_task};
+
+static TASK_REGISTRY: OnceLock<TaskRegistry> = OnceLock::new();
+
+/// Returns a reference to the global task registry.
+pub fn get_task_registry() -> &'static TaskRegistry {
+    TASK_REGISTRY.get_or_init(TaskRegistry::new)
+}
+
+/// A panic raised by a task spawned through the [`TaskRegistry`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaskFault {
+    pub component_id: Ustr,
+    pub task_id: u64,
+    pub task_name: Ustr,
+    pub message: String,
+}
+
+impl Display for TaskFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Task '{}' ({}) of {} panicked: {}",
+            self.task_name, self.task_id, self.component_id, self.message,
+        )
+    }
+}
+
+#[derive(Debug)]
+struct TrackedTask {
+    task_id: u64,
+    name: Ustr,
+    abort: AbortHandle,
+}
+
+#[derive(Debug, Default)]
+struct TaskRegistryInner {
+    next_id: u64,
+    tasks: AHashMap<Ustr, Vec<TrackedTask>>,
+    faults: Vec<TaskFault>,
+}
+
+impl TaskRegistryInner {
+    fn remove(&mut self, component_id: &Ustr, task_id: u64) -> Option<TrackedTask> {
+        let tasks = self.tasks.get_mut(component_id)?;
+        let index = tasks.iter().position(|task| task.task_id == task_id)?;
+        let task = tasks.swap_remove(index);
+        if tasks.is_empty() {
+            self.tasks.remove(component_id);
+        }
+        Some(task)
+    }
+}
+
+/// Tracks the async tasks spawned by each component.
+#[derive(Clone, Debug, Default)]
+pub struct TaskRegistry {
+    inner: Arc<Mutex<TaskRegistryInner>>,
+}
+
+impl TaskRegistry {
+    /// Creates a new empty [`TaskRegistry`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TaskRegistryInner> {
+        // A panic while holding the lock cannot leave the maps inconsistent, so recover
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Spawns `future` on the global runtime as a task named `name` owned by `component_id`,
+    /// returning the task ID.
+    ///
+    /// The task is untracked once it completes; a panic is recorded as a [`TaskFault`].
+    pub fn spawn<F>(&self, component_id: Ustr, name: &str, future: F) -> u64
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let name = Ustr::from(name);
+        let handle = get_runtime().spawn(catch_panic_task(component_id, future));
+
+        let task_id = {
+            let mut inner = self.lock();
+            let task_id = inner.next_id;
+            inner.next_id += 1;
+            inner
+                .tasks
+                .entry(component_id)
+                .or_default()
+                .push(TrackedTask {
+                    task_id,
+                    name,
+                    abort: handle.abort_handle(),
+                });
+            task_id
+        };
+        log::debug!("Spawned task '{name}' ({task_id}) for {component_id}");
+
+        let registry = self.clone();
+        get_runtime().spawn(async move {
+            let message = match handle.await {
+                Ok(Err(report)) => Some(report.message),
+                Err(e) if e.is_panic() => Some(panic_message(e)),
+                Ok(Ok(())) | Err(_) => None,
+            };
+            let mut inner = registry.lock();
+            inner.remove(&component_id, task_id);
+            if let Some(message) = message {
+                let fault = TaskFault {
+                    component_id,
+                    task_id,
+                    task_name: name,
+                    message,
+                };
+                log::error!("{fault}");
+                inner.faults.push(fault);
+            }
+        });
+        task_id
+    }
+
+    /// Returns the number of running tasks owned by `component_id`.
+    #[must_use]
+    pub fn task_count(&self, component_id: &Ustr) -> usize {
+        self.lock().tasks.get(component_id).map_or(0, Vec::len)
+    }
+
+    /// Returns the names of the running tasks owned by `component_id`, ordered by task ID.
+    #[must_use]
+    pub fn task_names(&self, component_id: &Ustr) -> Vec<Ustr> {
+        let inner = self.lock();
+        let mut tasks: Vec<(u64, Ustr)> = inner
+            .tasks
+            .get(component_id)
+            .map(|tasks| tasks.iter().map(|t| (t.task_id, t.name)).collect())
+            .unwrap_or_default();
+        tasks.sort_unstable();
+        tasks.into_iter().map(|(_, name)| name).collect()
+    }
+
+    /// Cancels the task with `task_id` owned by `component_id`.
+    ///
+    /// Returns `true` if the task was running.
+    pub fn cancel_task(&self, component_id: &Ustr, task_id: u64) -> bool {
+        match self.lock().remove(component_id, task_id) {
+            Some(task) => {
+                task.abort.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancels all running tasks owned by `component_id`, returning the number cancelled.
+    ///
+    /// Call this when the component stops or faults.
+    pub fn cancel_component(&self, component_id: &Ustr) -> usize {
+        let tasks = self.lock().tasks.remove(component_id).unwrap_or_default();
+        for task in &tasks {
+            task.abort.abort();
+        }
+        if !tasks.is_empty() {
+            log::debug!("Cancelled {} task(s) for {component_id}", tasks.len());
+        }
+        tasks.len()
+    }
+
+    /// Cancels all running tasks of all components, returning the number cancelled.
+    pub fn cancel_all(&self) -> usize {
+        let tasks = std::mem::take(&mut self.lock().tasks);
+        let mut count = 0;
+        for task in tasks.into_values().flatten() {
+            task.abort.abort();
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns and clears the recorded task faults.
+    pub fn drain_faults(&self) -> Vec<TaskFault> {
+        std::mem::take(&mut self.lock().faults)
+    }
+
+    /// Faults the owning component of each recorded task panic, cancelling its remaining tasks,
+    /// and returns the faults handled.
+    ///
+    /// Must be called from the thread the components are registered on. Failures to fault a
+    /// component (for example one not found in the component registry) are logged.
+    pub fn fault_components(&self) -> Vec<TaskFault> {
+        let faults = self.drain_faults();
+        let mut faulted: Vec<Ustr> = Vec::new();
+        for fault in &faults {
+            if faulted.contains(&fault.component_id) {
+                continue;
+            }
+            faulted.push(fault.component_id);
+            self.cancel_component(&fault.component_id);
+            if let Err(e) = fault_component(&fault.component_id) {
+                log::error!(
+                    "Failed to fault {} after task panic: {e}",
+                    fault.component_id
+                );
+            }
+        }
+        faults
+    }
+}
+
+fn panic_message(error: JoinError) -> String {
+    let payload = error.into_panic();
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn wait_until(condition: impl Fn() -> bool) {
+        for _ in 0..1_000 {
+            if condition() {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("Timed out waiting for condition");
+    }
+
+    #[rstest]
+    fn test_spawn_tracks_until_complete() {
+        let registry = TaskRegistry::new();
+        let component_id = Ustr::from("DataClient-TEST");
+        let (tx, rx) = std::sync::mpsc::channel::<()>();
+
+        registry.spawn(component_id, "stream", async move {
+            while rx.try_recv().is_err() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+        registry.spawn(component_id, "heartbeat", async {});
+
+        wait_until(|| registry.task_count(&component_id) == 1);
+        assert_eq!(
+            registry.task_names(&component_id),
+            vec![Ustr::from("stream")]
+        );
+
+        tx.send(()).unwrap();
+        wait_until(|| registry.task_count(&component_id) == 0);
+        assert!(registry.drain_faults().is_empty());
+    }
+
+    #[rstest]
+    fn test_cancel_component_aborts_group() {
+        let registry = TaskRegistry::new();
+        let component_id = Ustr::from("DataClient-TEST");
+        let other_id = Ustr::from("ExecClient-TEST");
+        for (id, name) in [(component_id, "a"), (component_id, "b"), (other_id, "c")] {
+            registry.spawn(id, name, std::future::pending());
+        }
+
+        assert_eq!(registry.cancel_component(&component_id), 2);
+        assert_eq!(registry.task_count(&component_id), 0);
+        assert_eq!(registry.task_count(&other_id), 1);
+
+        let task_id = registry.spawn(other_id, "d", std::future::pending());
+        assert!(registry.cancel_task(&other_id, task_id));
+        assert!(!registry.cancel_task(&other_id, task_id));
+        assert_eq!(registry.cancel_all(), 1);
+
+        // Aborted tasks are not faults
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(registry.drain_faults().is_empty());
+    }
+
+    #[rstest]
+    fn test_panic_recorded_as_fault() {
+        let registry = TaskRegistry::new();
+        let component_id = Ustr::from("DataClient-TEST");
+
+        let task_id = registry.spawn(component_id, "decoder", async {
+            panic!("decode failed");
+        });
+        registry.spawn(component_id, "stream", std::future::pending());
+
+        wait_until(|| registry.task_count(&component_id) == 1);
+        wait_until(|| !registry.lock().faults.is_empty());
+
+        // The component is not registered, so faulting it is logged and its tasks are still
+        // cancelled
+        let faults = registry.fault_components();
+        assert_eq!(
+            faults,
+            vec![TaskFault {
+                component_id,
+                task_id,
+                task_name: Ustr::from("decoder"),
+                message: "decode failed".to_string(),
+            }]
+        );
+        assert_eq!(registry.task_count(&component_id), 0);
+        assert!(registry.drain_faults().is_empty());
+    }
+}
@@ -0,0 +1,601 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tamper-evident audit log for trading commands and order events.
+//!
+//! When enabled through [`LoggerConfig::audit`](super::logger::LoggerConfig), every
+//! [`TradingCommand`] and [`OrderEventAny`] passing through the message bus is forwarded to the
+//! logging thread as an [`AuditEntry`]. The logging thread chains each entry onto the previous
+//! record by hashing it together with the previous record hash, and appends the resulting
+//! [`AuditRecord`] as a JSON line to a dedicated audit file.
+//!
+//! The hash covers every record field in a canonical encoding (fixed-width integers and
+//! length-prefixed strings), so no two distinct records share a preimage. Each record also names
+//! its message type explicitly, since [`TradingCommand`] serializes untagged and its payload alone
+//! does not identify the command.
+//!
+//! An order event is typically sent to the execution engine and then published to subscribers,
+//! so order events are recorded once per event ID: the first time the event passes through the
+//! bus, on whichever channel that is.
+//!
+//! Because every record commits to its predecessor, editing, removing, or reordering any record
+//! breaks the chain from that point onwards, which [`verify_audit_log`] detects.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::atomic::Ordering,
+};
+
+use ahash::AHashSet;
+use chrono::Utc;
+use nautilus_core::{
+    UUID4, UnixNanos,
+    consts::NAUTILUS_PREFIX,
+    time::{get_atomic_clock_realtime, get_atomic_clock_static},
+};
+use nautilus_cryptography::digest::sha256_hex;
+use nautilus_model::events::OrderEventAny;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use ustr::Ustr;
+
+use super::{AUDIT_ENABLED, LOGGING_REALTIME, logger::LogEvent, writer::FileWriterConfig};
+use crate::messages::execution::TradingCommand;
+
+/// The previous hash used for the first record of an audit chain.
+pub const AUDIT_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// The number of recently audited order event IDs retained to suppress duplicate records.
+const AUDITED_EVENT_IDS_CAPACITY: usize = 65_536;
+
+thread_local! {
+    static AUDITED_EVENT_IDS: RefCell<RecentEventIds> =
+        RefCell::new(RecentEventIds::new(AUDITED_EVENT_IDS_CAPACITY));
+}
+
+/// The kind of message captured by an audit record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Display, EnumString, Serialize, Deserialize)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditRecordKind {
+    /// A trading command sent to an execution endpoint.
+    Command,
+    /// An order event sent to an endpoint or published on a topic.
+    Event,
+}
+
+/// An audited message awaiting chaining on the logging thread.
+#[derive(Clone, Debug)]
+pub struct AuditEntry {
+    /// UNIX timestamp (nanoseconds) when the message was captured.
+    pub timestamp: UnixNanos,
+    /// The kind of message.
+    pub kind: AuditRecordKind,
+    /// The message type name (e.g. `SubmitOrder`, `Filled`).
+    pub message_type: Ustr,
+    /// The endpoint or topic the message was routed through.
+    pub channel: Ustr,
+    /// The JSON encoded message.
+    pub payload: String,
+}
+
+/// A single record of the audit chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// The position of the record in the chain (starting at 1).
+    pub sequence: u64,
+    /// UNIX timestamp (nanoseconds) when the message was captured.
+    pub timestamp: UnixNanos,
+    /// The kind of message.
+    pub kind: AuditRecordKind,
+    /// The message type name (e.g. `SubmitOrder`, `Filled`).
+    pub message_type: String,
+    /// The endpoint or topic the message was routed through.
+    pub channel: String,
+    /// The JSON encoded message.
+    pub payload: String,
+    /// The hash of the previous record in the chain.
+    pub prev_hash: String,
+    /// The hash of this record.
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// Computes the hash for a record with the given contents.
+    ///
+    /// The preimage encodes `sequence` and `timestamp` as big-endian `u64`s followed by each
+    /// string field prefixed with its big-endian `u64` byte length, so field boundaries are
+    /// unambiguous whatever the field contents.
+    #[must_use]
+    pub fn compute_hash(
+        sequence: u64,
+        timestamp: UnixNanos,
+        kind: AuditRecordKind,
+        message_type: &str,
+        channel: &str,
+        payload: &str,
+        prev_hash: &str,
+    ) -> String {
+        let kind = kind.to_string();
+        let fields = [kind.as_str(), message_type, channel, payload, prev_hash];
+
+        let mut preimage =
+            Vec::with_capacity(16 + fields.iter().map(|f| 8 + f.len()).sum::<usize>());
+        preimage.extend_from_slice(&sequence.to_be_bytes());
+        preimage.extend_from_slice(&timestamp.as_u64().to_be_bytes());
+        for field in fields {
+            preimage.extend_from_slice(&(field.len() as u64).to_be_bytes());
+            preimage.extend_from_slice(field.as_bytes());
+        }
+        sha256_hex(&preimage)
+    }
+
+    /// Returns whether the stored hash matches the record contents.
+    #[must_use]
+    pub fn is_hash_valid(&self) -> bool {
+        self.hash
+            == Self::compute_hash(
+                self.sequence,
+                self.timestamp,
+                self.kind,
+                &self.message_type,
+                &self.channel,
+                &self.payload,
+                &self.prev_hash,
+            )
+    }
+}
+
+/// Maintains the running state of an audit hash chain.
+#[derive(Clone, Debug)]
+pub struct AuditChain {
+    sequence: u64,
+    last_hash: String,
+}
+
+impl Default for AuditChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuditChain {
+    /// Creates a new [`AuditChain`] instance starting from the genesis hash.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sequence: 0,
+            last_hash: AUDIT_GENESIS_HASH.to_string(),
+        }
+    }
+
+    /// Returns the sequence number of the last appended record.
+    #[must_use]
+    pub const fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the hash of the last appended record.
+    #[must_use]
+    pub fn last_hash(&self) -> &str {
+        &self.last_hash
+    }
+
+    /// Chains the given entry onto the last record and returns the new record.
+    pub fn append(&mut self, entry: AuditEntry) -> AuditRecord {
+        self.sequence += 1;
+
+        let hash = AuditRecord::compute_hash(
+            self.sequence,
+            entry.timestamp,
+            entry.kind,
+            &entry.message_type,
+            &entry.channel,
+            &entry.payload,
+            &self.last_hash,
+        );
+        let prev_hash = std::mem::replace(&mut self.last_hash, hash.clone());
+
+        AuditRecord {
+            sequence: self.sequence,
+            timestamp: entry.timestamp,
+            kind: entry.kind,
+            message_type: entry.message_type.to_string(),
+            channel: entry.channel.to_string(),
+            payload: entry.payload,
+            prev_hash,
+            hash,
+        }
+    }
+}
+
+/// Writes chained audit records as JSON lines to a dedicated audit file.
+#[derive(Debug)]
+pub struct AuditWriter {
+    buf: BufWriter<File>,
+    path: PathBuf,
+    chain: AuditChain,
+}
+
+impl AuditWriter {
+    /// Creates a new [`AuditWriter`] instance.
+    ///
+    /// The audit file is created in the configured log directory and named after the trader
+    /// and instance, so each run starts a fresh chain.
+    pub fn new(trader_id: &str, instance_id: &str, file_config: &FileWriterConfig) -> Option<Self> {
+        let utc_component = Utc::now().format("%Y-%m-%d_%H%M%S");
+        let mut path = PathBuf::new();
+
+        if let Some(directory) = file_config.directory.as_ref() {
+            path.push(directory);
+            if let Err(e) = std::fs::create_dir_all(&path) {
+                eprintln!("{NAUTILUS_PREFIX} Error creating audit log directory: {e}");
+                return None;
+            }
+        }
+
+        path.push(format!("{trader_id}_{utc_component}_{instance_id}_audit"));
+        path.set_extension("jsonl");
+
+        match File::options().create(true).append(true).open(&path) {
+            Ok(file) => Some(Self {
+                buf: BufWriter::new(file),
+                path,
+                chain: AuditChain::new(),
+            }),
+            Err(e) => {
+                eprintln!("{NAUTILUS_PREFIX} Error creating audit log file: {e}");
+                None
+            }
+        }
+    }
+
+    /// Returns the path of the audit file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Chains the entry and writes the resulting record.
+    pub fn write(&mut self, entry: AuditEntry) {
+        let record = self.chain.append(entry);
+
+        let line = match serde_json::to_string(&record) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("{NAUTILUS_PREFIX} Error serializing audit record: {e}");
+                return;
+            }
+        };
+
+        if let Err(e) = writeln!(self.buf, "{line}") {
+            eprintln!("{NAUTILUS_PREFIX} Error writing audit record: {e:?}");
+        }
+    }
+
+    /// Flushes buffered records and syncs the audit file to disk.
+    pub fn flush(&mut self) {
+        if let Err(e) = self.buf.flush() {
+            eprintln!("{NAUTILUS_PREFIX} Error flushing audit file: {e:?}");
+        }
+
+        if let Err(e) = self.buf.get_ref().sync_all() {
+            eprintln!("{NAUTILUS_PREFIX} Error syncing audit file: {e:?}");
+        }
+    }
+}
+
+/// Returns whether the audit log is enabled.
+pub fn audit_is_enabled() -> bool {
+    AUDIT_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records a trading command routed through `channel` in the audit log (if enabled).
+pub fn audit_trading_command(channel: &str, command: &TradingCommand) {
+    if audit_is_enabled() {
+        send_audit_entry(
+            AuditRecordKind::Command,
+            &command.to_string(),
+            channel,
+            command,
+        );
+    }
+}
+
+/// Records an order event routed through `channel` in the audit log (if enabled).
+///
+/// An event already recorded under the same event ID is skipped, so an event both sent to an
+/// endpoint and published on a topic appears in the chain once.
+pub fn audit_order_event(channel: &str, event: &OrderEventAny) {
+    if audit_is_enabled() && AUDITED_EVENT_IDS.with_borrow_mut(|ids| ids.insert(event.event_id())) {
+        let message_type = format!("{:?}", event.event_type());
+        send_audit_entry(AuditRecordKind::Event, &message_type, channel, event);
+    }
+}
+
+/// A bounded set of the most recently seen event IDs.
+#[derive(Debug)]
+struct RecentEventIds {
+    capacity: usize,
+    ids: AHashSet<UUID4>,
+    order: VecDeque<UUID4>,
+}
+
+impl RecentEventIds {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ids: AHashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts `id`, evicting the oldest ID at capacity, and returns whether it was not present.
+    fn insert(&mut self, id: UUID4) -> bool {
+        if !self.ids.insert(id) {
+            return false;
+        }
+
+        if self.order.len() == self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.ids.remove(&oldest);
+        }
+        self.order.push_back(id);
+        true
+    }
+}
+
+fn send_audit_entry<T: Serialize>(
+    kind: AuditRecordKind,
+    message_type: &str,
+    channel: &str,
+    message: &T,
+) {
+    let payload = match serde_json::to_string(message) {
+        Ok(json) => json,
+        Err(e) => {
+            log::error!("Error serializing {kind} for audit log: {e}");
+            return;
+        }
+    };
+
+    let timestamp = if LOGGING_REALTIME.load(Ordering::Relaxed) {
+        get_atomic_clock_realtime().get_time_ns()
+    } else {
+        get_atomic_clock_static().get_time_ns()
+    };
+
+    super::logger::send_log_event(LogEvent::Audit(AuditEntry {
+        timestamp,
+        kind,
+        message_type: Ustr::from(message_type),
+        channel: Ustr::from(channel),
+        payload,
+    }));
+}
+
+/// Verifies the hash chain of the audit file at `path`.
+///
+/// Returns the number of verified records.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, a record cannot be parsed, or the chain is
+/// broken (out of sequence record, previous hash mismatch, or tampered record contents).
+pub fn verify_audit_log<P: AsRef<Path>>(path: P) -> anyhow::Result<u64> {
+    let file = File::open(path.as_ref())?;
+    verify_audit_records(BufReader::new(file))
+}
+
+/// Verifies the hash chain of JSON line audit records read from `reader`.
+///
+/// Returns the number of verified records.
+///
+/// # Errors
+///
+/// Returns an error if a line cannot be read or parsed, or the chain is broken.
+pub fn verify_audit_records<R: BufRead>(reader: R) -> anyhow::Result<u64> {
+    let mut expected_sequence = 1;
+    let mut prev_hash = AUDIT_GENESIS_HASH.to_string();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let line_number = index + 1;
+        let record: AuditRecord = serde_json::from_str(&line)
+            .map_err(|e| anyhow::anyhow!("Invalid audit record at line {line_number}: {e}"))?;
+
+        if record.sequence != expected_sequence {
+            anyhow::bail!(
+                "Audit chain broken at line {line_number}: expected sequence {expected_sequence}, was {}",
+                record.sequence
+            );
+        }
+
+        if record.prev_hash != prev_hash {
+            anyhow::bail!(
+                "Audit chain broken at line {line_number}: previous hash mismatch for sequence {}",
+                record.sequence
+            );
+        }
+
+        if !record.is_hash_valid() {
+            anyhow::bail!(
+                "Audit chain broken at line {line_number}: hash mismatch for sequence {}",
+                record.sequence
+            );
+        }
+
+        prev_hash = record.hash;
+        expected_sequence += 1;
+    }
+
+    Ok(expected_sequence - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn entry(payload: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: UnixNanos::from(1_000),
+            kind: AuditRecordKind::Event,
+            message_type: Ustr::from("Filled"),
+            channel: Ustr::from("events.order.S-001"),
+            payload: payload.to_string(),
+        }
+    }
+
+    fn chain_to_lines(payloads: &[&str]) -> Vec<String> {
+        let mut chain = AuditChain::new();
+        payloads
+            .iter()
+            .map(|p| serde_json::to_string(&chain.append(entry(p))).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    fn test_chain_links_records() {
+        let mut chain = AuditChain::new();
+        let first = chain.append(entry("{\"a\":1}"));
+        let second = chain.append(entry("{\"a\":2}"));
+
+        assert_eq!(first.sequence, 1);
+        assert_eq!(first.prev_hash, AUDIT_GENESIS_HASH);
+        assert_eq!(second.sequence, 2);
+        assert_eq!(second.prev_hash, first.hash);
+        assert_eq!(chain.last_hash(), second.hash);
+        assert!(first.is_hash_valid());
+        assert!(second.is_hash_valid());
+    }
+
+    #[rstest]
+    fn test_verify_valid_chain() {
+        let lines = chain_to_lines(&["{\"a\":1}", "{\"a\":2}", "{\"a\":3}"]);
+        let result = verify_audit_records(Cursor::new(lines.join("\n")));
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[rstest]
+    fn test_verify_empty_log() {
+        assert_eq!(verify_audit_records(Cursor::new("")).unwrap(), 0);
+    }
+
+    #[rstest]
+    fn test_verify_detects_tampered_payload() {
+        let mut lines = chain_to_lines(&["{\"a\":1}", "{\"a\":2}", "{\"a\":3}"]);
+        lines[1] = lines[1].replace("{\\\"a\\\":2}", "{\\\"a\\\":9}");
+
+        let err = verify_audit_records(Cursor::new(lines.join("\n"))).unwrap_err();
+        assert!(err.to_string().contains("hash mismatch for sequence 2"));
+    }
+
+    #[rstest]
+    fn test_verify_detects_tampered_message_type() {
+        let mut lines = chain_to_lines(&["{\"a\":1}", "{\"a\":2}"]);
+        lines[0] = lines[0].replace("\"Filled\"", "\"Canceled\"");
+
+        let err = verify_audit_records(Cursor::new(lines.join("\n"))).unwrap_err();
+        assert!(err.to_string().contains("hash mismatch for sequence 1"));
+    }
+
+    #[rstest]
+    fn test_hash_field_boundaries_are_unambiguous() {
+        let ts = UnixNanos::from(1);
+        let kind = AuditRecordKind::Command;
+
+        assert_ne!(
+            AuditRecord::compute_hash(1, ts, kind, "SubmitOrder", "a|b", "c", AUDIT_GENESIS_HASH),
+            AuditRecord::compute_hash(1, ts, kind, "SubmitOrder", "a", "b|c", AUDIT_GENESIS_HASH),
+        );
+        assert_ne!(
+            AuditRecord::compute_hash(1, ts, kind, "Submit", "Order", "{}", AUDIT_GENESIS_HASH),
+            AuditRecord::compute_hash(1, ts, kind, "SubmitOrder", "", "{}", AUDIT_GENESIS_HASH),
+        );
+    }
+
+    #[rstest]
+    fn test_verify_detects_removed_record() {
+        let mut lines = chain_to_lines(&["{\"a\":1}", "{\"a\":2}", "{\"a\":3}"]);
+        lines.remove(1);
+
+        let err = verify_audit_records(Cursor::new(lines.join("\n"))).unwrap_err();
+        assert!(err.to_string().contains("expected sequence 2"));
+    }
+
+    #[rstest]
+    fn test_verify_detects_reordered_records() {
+        let mut lines = chain_to_lines(&["{\"a\":1}", "{\"a\":2}"]);
+        lines.swap(0, 1);
+
+        let err = verify_audit_records(Cursor::new(lines.join("\n"))).unwrap_err();
+        assert!(err.to_string().contains("expected sequence 1"));
+    }
+
+    #[rstest]
+    fn test_recent_event_ids_rejects_duplicates() {
+        let mut ids = RecentEventIds::new(2);
+        let first = UUID4::new();
+        let second = UUID4::new();
+
+        assert!(ids.insert(first));
+        assert!(!ids.insert(first));
+        assert!(ids.insert(second));
+        assert!(!ids.insert(second));
+    }
+
+    #[rstest]
+    fn test_recent_event_ids_evicts_oldest_at_capacity() {
+        let mut ids = RecentEventIds::new(2);
+        let first = UUID4::new();
+        ids.insert(first);
+        ids.insert(UUID4::new());
+        ids.insert(UUID4::new());
+
+        assert_eq!(ids.order.len(), 2);
+        assert!(ids.insert(first));
+    }
+
+    #[rstest]
+    fn test_audit_writer_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let mut writer = AuditWriter::new("TRADER-001", "instance", &file_config).unwrap();
+        writer.write(entry("{\"a\":1}"));
+        writer.write(entry("{\"a\":2}"));
+        writer.flush();
+
+        assert_eq!(verify_audit_log(writer.path()).unwrap(), 2);
+    }
+}
@@ -36,6 +36,7 @@
 //! | `print_config`        | Boolean   | Print config to stdout at startup.           |
 //! | `log_components_only` | Boolean   | Only log components with explicit filters.   |
 //! | `use_tracing`         | Boolean   | Enable tracing subscriber for external libs. |
+//! | `audit`               | Boolean   | Write the hash chained audit log.            |
 //! | `<component>`         | Log level | Component-specific log level (exact match).  |
 //! | `<module::path>`      | Log level | Module-specific log level (prefix match).    |
 //!
@@ -84,6 +85,8 @@ pub struct LoggerConfig {
     pub print_config: bool,
     /// Initialize the tracing subscriber for external Rust crate logs.
     pub use_tracing: bool,
+    /// Write trading commands and order events to the hash chained audit log.
+    pub audit: bool,
 }
 
 impl Default for LoggerConfig {
@@ -98,6 +101,7 @@ impl Default for LoggerConfig {
             is_colored: true,
             print_config: false,
             use_tracing: false,
+            audit: false,
         }
     }
 }
@@ -115,6 +119,7 @@ impl LoggerConfig {
         is_colored: bool,
         print_config: bool,
         use_tracing: bool,
+        audit: bool,
     ) -> Self {
         Self {
             stdout_level,
@@ -125,6 +130,7 @@ impl LoggerConfig {
             is_colored,
             print_config,
             use_tracing,
+            audit,
         }
     }
 
@@ -158,6 +164,7 @@ impl LoggerConfig {
                     "is_colored" => config.is_colored = true,
                     "print_config" => config.print_config = true,
                     "use_tracing" => config.use_tracing = true,
+                    "audit" => config.audit = true,
                     _ => anyhow::bail!("Invalid spec pair: {kv}"),
                 }
                 continue;
@@ -185,6 +192,9 @@ impl LoggerConfig {
                 "use_tracing" => {
                     config.use_tracing = parse_bool_value(v);
                 }
+                "audit" => {
+                    config.audit = parse_bool_value(v);
+                }
                 "stdout" => {
                     config.stdout_level = parse_level(v)?;
                 }
@@ -333,6 +343,18 @@ mod tests {
         assert!(!config.print_config);
     }
 
+    #[rstest]
+    fn test_from_spec_audit() {
+        let config = LoggerConfig::from_spec("audit").unwrap();
+        assert!(config.audit);
+    }
+
+    #[rstest]
+    fn test_from_spec_audit_false() {
+        let config = LoggerConfig::from_spec("audit=false").unwrap();
+        assert!(!config.audit);
+    }
+
     #[rstest]
     fn test_from_spec_log_components_only() {
         let config = LoggerConfig::from_spec("log_components_only").unwrap();
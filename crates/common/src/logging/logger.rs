@@ -35,10 +35,15 @@ use serde::{Deserialize, Serialize, Serializer};
 use ustr::Ustr;
 
 pub use super::config::LoggerConfig;
-use super::{LOGGING_BYPASSED, LOGGING_GUARDS_ACTIVE, LOGGING_INITIALIZED, LOGGING_REALTIME};
+use super::{
+    AUDIT_ENABLED, LOGGING_BYPASSED, LOGGING_GUARDS_ACTIVE, LOGGING_INITIALIZED, LOGGING_REALTIME,
+};
 use crate::{
     enums::{LogColor, LogLevel},
-    logging::writer::{FileWriter, FileWriterConfig, LogWriter, StderrWriter, StdoutWriter},
+    logging::{
+        audit::{AuditEntry, AuditWriter},
+        writer::{FileWriter, FileWriterConfig, LogWriter, StderrWriter, StdoutWriter},
+    },
 };
 
 const LOGGING: &str = "logging";
@@ -69,6 +74,8 @@ pub struct Logger {
 pub enum LogEvent {
     /// A log line event.
     Log(LogLine),
+    /// An audit entry to chain onto the audit log.
+    Audit(AuditEntry),
     /// A command to flush all logger buffers.
     Flush,
     /// A command to close the logger.
@@ -299,6 +306,7 @@ impl Logger {
         }
 
         let is_colored = config.is_colored;
+        let is_audit = config.audit;
 
         let print_config = config.print_config;
         if print_config {
@@ -336,6 +344,7 @@ impl Logger {
 
         super::LOGGING_INITIALIZED.store(true, Ordering::SeqCst);
         super::LOGGING_COLORED.store(is_colored, Ordering::SeqCst);
+        AUDIT_ENABLED.store(is_audit, Ordering::SeqCst);
 
         LogGuard::new()
             .ok_or_else(|| anyhow::anyhow!("Failed to create LogGuard from global sender"))
@@ -357,6 +366,7 @@ impl Logger {
             is_colored,
             print_config: _,
             use_tracing: _,
+            audit,
         } = config;
 
         // Pre-sort module filters by descending path length for O(n) longest-prefix lookup
@@ -370,6 +380,13 @@ impl Logger {
         let mut stdout_writer = StdoutWriter::new(stdout_level, is_colored);
         let mut stderr_writer = StderrWriter::new(is_colored);
 
+        // The audit log is written to a dedicated file independent of fileout_level
+        let mut audit_writer_opt = if audit {
+            AuditWriter::new(&trader_id, &instance_id, &file_config)
+        } else {
+            None
+        };
+
         // Conditionally create file writer based on fileout_level
         let mut file_writer_opt = if fileout_level == LevelFilter::Off {
             None
@@ -377,72 +394,84 @@ impl Logger {
             FileWriter::new(trader_id, instance_id, file_config, fileout_level)
         };
 
-        let process_event = |event: LogEvent,
-                             stdout_writer: &mut StdoutWriter,
-                             stderr_writer: &mut StderrWriter,
-                             file_writer_opt: &mut Option<FileWriter>| {
-            match event {
-                LogEvent::Log(line) => {
-                    if should_filter_log(
-                        &line.component,
-                        line.level,
-                        &module_filters_sorted,
-                        &component_level,
-                        log_components_only,
-                    ) {
-                        return;
-                    }
+        let process_event =
+            |event: LogEvent,
+             stdout_writer: &mut StdoutWriter,
+             stderr_writer: &mut StderrWriter,
+             file_writer_opt: &mut Option<FileWriter>,
+             audit_writer_opt: &mut Option<AuditWriter>| {
+                match event {
+                    LogEvent::Log(line) => {
+                        if should_filter_log(
+                            &line.component,
+                            line.level,
+                            &module_filters_sorted,
+                            &component_level,
+                            log_components_only,
+                        ) {
+                            return;
+                        }
 
-                    let mut wrapper = LogLineWrapper::new(line, trader_id_cache);
+                        let mut wrapper = LogLineWrapper::new(line, trader_id_cache);
 
-                    if stderr_writer.enabled(&wrapper.line) {
-                        if is_colored {
-                            stderr_writer.write(wrapper.get_colored());
-                        } else {
-                            stderr_writer.write(wrapper.get_string());
+                        if stderr_writer.enabled(&wrapper.line) {
+                            if is_colored {
+                                stderr_writer.write(wrapper.get_colored());
+                            } else {
+                                stderr_writer.write(wrapper.get_string());
+                            }
                         }
-                    }
 
-                    if stdout_writer.enabled(&wrapper.line) {
-                        if is_colored {
-                            stdout_writer.write(wrapper.get_colored());
-                        } else {
-                            stdout_writer.write(wrapper.get_string());
+                        if stdout_writer.enabled(&wrapper.line) {
+                            if is_colored {
+                                stdout_writer.write(wrapper.get_colored());
+                            } else {
+                                stdout_writer.write(wrapper.get_string());
+                            }
                         }
-                    }
 
-                    if let Some(file_writer) = file_writer_opt
-                        && file_writer.enabled(&wrapper.line)
-                    {
-                        if file_writer.json_format {
-                            file_writer.write(&wrapper.get_json());
-                        } else {
-                            file_writer.write(wrapper.get_string());
+                        if let Some(file_writer) = file_writer_opt
+                            && file_writer.enabled(&wrapper.line)
+                        {
+                            if file_writer.json_format {
+                                file_writer.write(&wrapper.get_json());
+                            } else {
+                                file_writer.write(wrapper.get_string());
+                            }
                         }
                     }
-                }
-                LogEvent::Flush => {
-                    stdout_writer.flush();
-                    stderr_writer.flush();
+                    LogEvent::Audit(entry) => {
+                        if let Some(audit_writer) = audit_writer_opt {
+                            audit_writer.write(entry);
+                        }
+                    }
+                    LogEvent::Flush => {
+                        stdout_writer.flush();
+                        stderr_writer.flush();
 
-                    if let Some(file_writer) = file_writer_opt {
-                        file_writer.flush();
+                        if let Some(file_writer) = file_writer_opt {
+                            file_writer.flush();
+                        }
+
+                        if let Some(audit_writer) = audit_writer_opt {
+                            audit_writer.flush();
+                        }
+                    }
+                    LogEvent::Close => {
+                        // Close handled in the main loop; ignore here.
                     }
                 }
-                LogEvent::Close => {
-                    // Close handled in the main loop; ignore here.
-                }
-            }
-        };
+            };
 
         // Continue to receive and handle log events until channel is hung up
         while let Ok(event) = rx.recv() {
             match event {
-                LogEvent::Log(_) | LogEvent::Flush => process_event(
+                LogEvent::Log(_) | LogEvent::Audit(_) | LogEvent::Flush => process_event(
                     event,
                     &mut stdout_writer,
                     &mut stderr_writer,
                     &mut file_writer_opt,
+                    &mut audit_writer_opt,
                 ),
                 LogEvent::Close => {
                     // First flush what's been written so far
@@ -453,6 +482,10 @@ impl Logger {
                         file_writer.flush();
                     }
 
+                    if let Some(ref mut audit_writer) = audit_writer_opt {
+                        audit_writer.flush();
+                    }
+
                     // Drain any remaining events that may have raced with shutdown
                     // This ensures logs enqueued just before/around shutdown aren't lost.
                     while let Ok(evt) = rx.try_recv() {
@@ -463,6 +496,7 @@ impl Logger {
                                 &mut stdout_writer,
                                 &mut stderr_writer,
                                 &mut file_writer_opt,
+                                &mut audit_writer_opt,
                             ),
                         }
                     }
@@ -475,6 +509,10 @@ impl Logger {
                         file_writer.flush();
                     }
 
+                    if let Some(ref mut audit_writer) = audit_writer_opt {
+                        audit_writer.flush();
+                    }
+
                     break;
                 }
             }
@@ -522,6 +560,19 @@ pub fn should_filter_log(
     false
 }
 
+/// Sends an event to the logging thread, if logging has been initialized.
+pub(crate) fn send_log_event(event: LogEvent) {
+    if LOGGING_BYPASSED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    if let Some(tx) = LOGGER_TX.get()
+        && let Err(e) = tx.send(event)
+    {
+        eprintln!("Error sending log event (receiver closed): {e}");
+    }
+}
+
 /// Gracefully shuts down the logging subsystem.
 ///
 /// Performs the same shutdown sequence as dropping the last `LogGuard`, but can be called
@@ -533,6 +584,7 @@ pub fn should_filter_log(
 pub(crate) fn shutdown_graceful() {
     // Prevent further logging
     LOGGING_BYPASSED.store(true, Ordering::SeqCst);
+    AUDIT_ENABLED.store(false, Ordering::SeqCst);
     log::set_max_level(log::LevelFilter::Off);
 
     // Signal Close if the sender exists
@@ -605,7 +657,7 @@ pub fn log<T: AsRef<str>>(level: LogLevel, color: LogColor, component: Ustr, mes
 )]
 #[derive(Debug)]
 pub struct LogGuard {
-    tx: std::sync::mpsc::Sender<LogEvent>,
+    tx: Option<std::sync::mpsc::Sender<LogEvent>>,
 }
 
 impl LogGuard {
@@ -629,17 +681,32 @@ impl LogGuard {
                 })
                 .expect("Maximum number of active LogGuards (255) exceeded");
 
-            Self { tx: tx.clone() }
+            Self {
+                tx: Some(tx.clone()),
+            }
         })
     }
-}
 
-impl Drop for LogGuard {
-    /// Handles cleanup when a `LogGuard` is dropped.
+    /// Returns whether this guard has already been released.
+    #[must_use]
+    pub fn is_released(&self) -> bool {
+        self.tx.is_none()
+    }
+
+    /// Releases this guard without waiting for it to be dropped.
     ///
     /// Sends `Flush` if other guards remain active, otherwise sends `Close`, joins the
-    /// logging thread, and resets the subsystem state.
-    fn drop(&mut self) {
+    /// logging thread, and resets the subsystem state. Subsequent calls (and the eventual drop)
+    /// are no-ops, which allows scoped release from garbage collected frontends such as Python.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the active guard count would underflow.
+    pub fn release(&mut self) {
+        let Some(tx) = self.tx.take() else {
+            return; // Already released
+        };
+
         let previous_count = LOGGING_GUARDS_ACTIVE
             .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
                 assert!(count != 0, "LogGuard reference count underflow");
@@ -653,12 +720,13 @@ impl Drop for LogGuard {
             // to ensure all log messages are written before the process terminates.
             // Prevent any new log events from being accepted while shutting down.
             LOGGING_BYPASSED.store(true, Ordering::SeqCst);
+            AUDIT_ENABLED.store(false, Ordering::SeqCst);
 
             // Disable all log levels to reduce overhead on late calls
             log::set_max_level(log::LevelFilter::Off);
 
             // Ensure Close is delivered before joining (critical for shutdown)
-            let _ = self.tx.send(LogEvent::Close);
+            let _ = tx.send(LogEvent::Close);
 
             // Join the logging thread to ensure all pending logs are written
             if let Ok(mut handle_guard) = LOGGER_HANDLE.lock()
@@ -674,11 +742,18 @@ impl Drop for LogGuard {
             LOGGING_INITIALIZED.store(false, Ordering::SeqCst);
         } else {
             // Other LogGuards are still active, just flush our logs
-            let _ = self.tx.send(LogEvent::Flush);
+            let _ = tx.send(LogEvent::Flush);
         }
     }
 }
 
+impl Drop for LogGuard {
+    /// Handles cleanup when a `LogGuard` is dropped, see [`LogGuard::release`].
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -736,6 +811,7 @@ mod tests {
                 is_colored: true,
                 print_config: false,
                 use_tracing: false,
+                audit: false,
             }
         );
     }
@@ -754,6 +830,7 @@ mod tests {
                 is_colored: true,
                 print_config: true,
                 use_tracing: false,
+                audit: false,
             }
         );
     }
@@ -776,6 +853,7 @@ mod tests {
                 is_colored: true,
                 print_config: false,
                 use_tracing: false,
+                audit: false,
             }
         );
     }
@@ -1355,6 +1433,26 @@ mod tests {
             assert!(!logging_is_initialized());
         }
 
+        #[rstest]
+        fn test_release_guard_before_drop() {
+            let mut guard = Logger::init_with_config(
+                TraderId::from("TRADER-001"),
+                UUID4::new(),
+                LoggerConfig::default(),
+                FileWriterConfig::default(),
+            )
+            .unwrap();
+            assert!(!guard.is_released());
+
+            guard.release();
+            assert!(guard.is_released());
+            assert!(!logging_is_initialized());
+
+            guard.release(); // Idempotent
+            drop(guard); // Does not underflow the guard count
+            assert!(!logging_is_initialized());
+        }
+
         #[rstest]
         fn test_reinit_after_guard_drop_fails() {
             let config = LoggerConfig::default();
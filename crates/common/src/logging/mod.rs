@@ -31,6 +31,7 @@
 //! The system supports a maximum of 255 concurrent `LogGuard` instances. Attempting to create
 //! more will cause a panic.
 
+pub mod audit;
 pub mod config;
 pub mod headers;
 pub mod logger;
@@ -77,6 +78,7 @@ static LOGGING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 static LOGGING_BYPASSED: AtomicBool = AtomicBool::new(false);
 static LOGGING_REALTIME: AtomicBool = AtomicBool::new(true);
 static LOGGING_COLORED: AtomicBool = AtomicBool::new(true);
+static AUDIT_ENABLED: AtomicBool = AtomicBool::new(false);
 static LOGGING_GUARDS_ACTIVE: AtomicU8 = AtomicU8::new(0);
 static LAZY_GUARD: OnceLock<Option<LogGuard>> = OnceLock::new();
 
@@ -0,0 +1,768 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A reference inventory-based market maker.
+//!
+//! The [`InventoryMarketMaker`] quotes both sides of an instrument around the Avellaneda-Stoikov
+//! reservation price, which is skewed away from the mid price against the current inventory, with
+//! an optimal spread widening with volatility and risk aversion. Volatility is either configured
+//! or measured from trade prices over a trailing window.
+//!
+//! The component maintains its own order book from the instrument's book deltas, tracks inventory
+//! from the strategy's fills, and requotes through the regular order management commands: quotes
+//! are created with the [`OrderFactory`], added to the cache and submitted via the risk engine,
+//! and stale quotes are canceled via the execution engine. It is intended as a template for
+//! strategies and as an integration check of the data, cache and execution paths.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, rc::Rc};
+
+use nautilus_core::{UUID4, UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{
+    data::{OrderBookDeltas, TradeTick},
+    enums::{BookType, OrderSide},
+    events::OrderEventAny,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    instruments::{Instrument, InstrumentAny},
+    orderbook::OrderBook,
+    orders::{Order, OrderAny},
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    factories::OrderFactory,
+    messages::{
+        data::{
+            DataCommand, SubscribeBookDeltas, SubscribeCommand, SubscribeTrades,
+            UnsubscribeBookDeltas, UnsubscribeCommand, UnsubscribeTrades,
+        },
+        execution::{CancelOrder, SubmitOrder, TradingCommand},
+    },
+    msgbus::{
+        self, MessagingSwitchboard, TypedHandler,
+        switchboard::{get_book_deltas_topic, get_event_orders_topic, get_trades_topic},
+    },
+};
+
+/// Configuration for the [`InventoryMarketMaker`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InventoryMarketMakerConfig {
+    pub instrument_id: InstrumentId,
+    /// The execution client to route quotes to.
+    pub client_id: Option<ClientId>,
+    /// The quantity of each quote.
+    pub order_qty: Quantity,
+    /// The risk aversion parameter (gamma).
+    pub risk_aversion: f64,
+    /// The order book liquidity parameter (kappa), the decay of fill intensity with distance.
+    pub liquidity: f64,
+    /// The quoting horizon (seconds), standing in for the time to the end of the session.
+    pub horizon_secs: f64,
+    /// The absolute inventory beyond which the side increasing it is no longer quoted.
+    pub max_inventory: f64,
+    /// The volatility (price units per square root second), measured from trades if `None`.
+    pub volatility: Option<f64>,
+    /// The number of trades the volatility is measured over.
+    pub volatility_window: usize,
+    /// The number of ticks a quote may drift from its target before it is replaced.
+    pub requote_ticks: u32,
+}
+
+impl InventoryMarketMakerConfig {
+    /// Creates a new [`InventoryMarketMakerConfig`] instance with default model parameters.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, order_qty: Quantity, max_inventory: f64) -> Self {
+        Self {
+            instrument_id,
+            client_id: None,
+            order_qty,
+            risk_aversion: 0.1,
+            liquidity: 1.5,
+            horizon_secs: 60.0,
+            max_inventory,
+            volatility: None,
+            volatility_window: 100,
+            requote_ticks: 1,
+        }
+    }
+}
+
+/// The Avellaneda-Stoikov quoting model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AvellanedaStoikov {
+    pub risk_aversion: f64,
+    pub liquidity: f64,
+    pub horizon_secs: f64,
+}
+
+impl AvellanedaStoikov {
+    /// Returns the reservation price for the `mid` price, `inventory` and variance per second.
+    #[must_use]
+    pub fn reservation_price(&self, mid: f64, inventory: f64, variance_rate: f64) -> f64 {
+        mid - inventory * self.risk_aversion * variance_rate * self.horizon_secs
+    }
+
+    /// Returns the optimal total spread for the variance per second.
+    #[must_use]
+    pub fn optimal_spread(&self, variance_rate: f64) -> f64 {
+        self.risk_aversion * variance_rate * self.horizon_secs
+            + (2.0 / self.risk_aversion) * (1.0 + self.risk_aversion / self.liquidity).ln()
+    }
+}
+
+/// The target quotes of an [`InventoryMarketMaker`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarketMakerQuotes {
+    pub reservation_price: f64,
+    pub spread: f64,
+    /// The bid price, or `None` while the inventory is at its long limit.
+    pub bid: Option<Price>,
+    /// The ask price, or `None` while the inventory is at its short limit.
+    pub ask: Option<Price>,
+}
+
+impl MarketMakerQuotes {
+    /// Computes the target quotes for `book`, rounded outward to the instrument ticks and kept
+    /// passive against the opposite side of the book.
+    ///
+    /// Returns `None` unless the book has both a bid and an ask.
+    #[must_use]
+    pub fn compute(
+        config: &InventoryMarketMakerConfig,
+        instrument: &InstrumentAny,
+        book: &OrderBook,
+        inventory: f64,
+        variance_rate: f64,
+    ) -> Option<Self> {
+        let best_bid = book.best_bid_price()?;
+        let best_ask = book.best_ask_price()?;
+        let model = AvellanedaStoikov {
+            risk_aversion: config.risk_aversion,
+            liquidity: config.liquidity,
+            horizon_secs: config.horizon_secs,
+        };
+
+        let mid = book.midpoint()?;
+        let reservation_price = model.reservation_price(mid, inventory, variance_rate);
+        let spread = model.optimal_spread(variance_rate);
+
+        let bid = (inventory < config.max_inventory)
+            .then(|| instrument.next_bid_price(reservation_price - spread / 2.0, 0))
+            .flatten()
+            .map(|bid| {
+                bid.min(
+                    instrument
+                        .next_bid_price(best_ask.as_f64(), 1)
+                        .unwrap_or(bid),
+                )
+            });
+        let ask = (inventory > -config.max_inventory)
+            .then(|| instrument.next_ask_price(reservation_price + spread / 2.0, 0))
+            .flatten()
+            .map(|ask| {
+                ask.max(
+                    instrument
+                        .next_ask_price(best_bid.as_f64(), 1)
+                        .unwrap_or(ask),
+                )
+            });
+
+        Some(Self {
+            reservation_price,
+            spread,
+            bid,
+            ask,
+        })
+    }
+}
+
+/// Measures the variance per second of trade price changes over a trailing window.
+#[derive(Clone, Debug)]
+struct TradeVolatility {
+    window: usize,
+    prices: VecDeque<(UnixNanos, f64)>,
+}
+
+impl TradeVolatility {
+    fn new(window: usize) -> Self {
+        Self {
+            window: window.max(2),
+            prices: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self, ts: UnixNanos, price: f64) {
+        if self.prices.len() == self.window {
+            self.prices.pop_front();
+        }
+        self.prices.push_back((ts, price));
+    }
+
+    fn variance_rate(&self) -> f64 {
+        let (Some((first_ts, _)), Some((last_ts, _))) = (self.prices.front(), self.prices.back())
+        else {
+            return 0.0;
+        };
+        let elapsed_secs =
+            (last_ts.as_u64() - first_ts.as_u64()) as f64 / NANOSECONDS_IN_SECOND as f64;
+        if elapsed_secs <= 0.0 {
+            return 0.0;
+        }
+
+        let sum_squares: f64 = self
+            .prices
+            .iter()
+            .zip(self.prices.iter().skip(1))
+            .map(|((_, prev), (_, next))| (next - prev).powi(2))
+            .sum();
+        sum_squares / elapsed_secs
+    }
+}
+
+struct MarketMakerState {
+    factory: OrderFactory,
+    book: OrderBook,
+    volatility: TradeVolatility,
+    inventory: f64,
+    bid: Option<ClientOrderId>,
+    ask: Option<ClientOrderId>,
+    handlers: Option<(
+        TypedHandler<OrderBookDeltas>,
+        TypedHandler<TradeTick>,
+        TypedHandler<OrderEventAny>,
+    )>,
+}
+
+/// A reference market maker quoting around an inventory-skewed reservation price.
+#[derive(Clone)]
+pub struct InventoryMarketMaker {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    config: InventoryMarketMakerConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    state: Rc<RefCell<MarketMakerState>>,
+}
+
+impl Debug for InventoryMarketMaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct(stringify!(InventoryMarketMaker))
+            .field("strategy_id", &self.strategy_id)
+            .field("config", &self.config)
+            .field("inventory", &state.inventory)
+            .field("bid", &state.bid)
+            .field("ask", &state.ask)
+            .finish()
+    }
+}
+
+impl InventoryMarketMaker {
+    /// Creates a new [`InventoryMarketMaker`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        config: InventoryMarketMakerConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        let factory = OrderFactory::new(
+            trader_id,
+            strategy_id,
+            None,
+            None,
+            clock.clone(),
+            false,
+            true,
+        );
+        let state = MarketMakerState {
+            factory,
+            book: OrderBook::new(config.instrument_id, BookType::L2_MBP),
+            volatility: TradeVolatility::new(config.volatility_window),
+            inventory: 0.0,
+            bid: None,
+            ask: None,
+            handlers: None,
+        };
+        Self {
+            trader_id,
+            strategy_id,
+            config,
+            clock,
+            cache,
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// Returns the current signed inventory.
+    #[must_use]
+    pub fn inventory(&self) -> f64 {
+        self.state.borrow().inventory
+    }
+
+    /// Returns the client order IDs of the working bid and ask quotes.
+    #[must_use]
+    pub fn working_quotes(&self) -> (Option<ClientOrderId>, Option<ClientOrderId>) {
+        let state = self.state.borrow();
+        (state.bid, state.ask)
+    }
+
+    /// Subscribes to the instrument's book deltas and trades, and the strategy's order events.
+    ///
+    /// The book deltas and trades subscriptions are also requested from the data engine, routed
+    /// by the instrument's venue.
+    pub fn start(&self) {
+        if self.state.borrow().handlers.is_some() {
+            log::warn!("Market maker already started");
+            return;
+        }
+
+        let maker = self.clone();
+        let deltas =
+            TypedHandler::from(move |deltas: &OrderBookDeltas| maker.on_book_deltas(deltas));
+        let maker = self.clone();
+        let trades = TypedHandler::from(move |trade: &TradeTick| maker.on_trade(trade));
+        let maker = self.clone();
+        let orders = TypedHandler::from(move |event: &OrderEventAny| maker.on_order_event(event));
+
+        let instrument_id = self.config.instrument_id;
+        msgbus::subscribe_book_deltas(
+            get_book_deltas_topic(instrument_id).into(),
+            deltas.clone(),
+            None,
+        );
+        msgbus::subscribe_trades(get_trades_topic(instrument_id).into(), trades.clone(), None);
+        msgbus::subscribe_order_events(
+            get_event_orders_topic(self.strategy_id).into(),
+            orders.clone(),
+            None,
+        );
+        self.state.borrow_mut().handlers = Some((deltas, trades, orders));
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        Self::send_data([
+            DataCommand::Subscribe(SubscribeCommand::BookDeltas(SubscribeBookDeltas {
+                instrument_id,
+                book_type: BookType::L2_MBP,
+                client_id: None,
+                venue: Some(instrument_id.venue),
+                command_id: UUID4::new(),
+                ts_init,
+                depth: None,
+                managed: false,
+                correlation_id: None,
+                params: None,
+            })),
+            DataCommand::Subscribe(SubscribeCommand::Trades(SubscribeTrades {
+                instrument_id,
+                client_id: None,
+                venue: Some(instrument_id.venue),
+                command_id: UUID4::new(),
+                ts_init,
+                correlation_id: None,
+                params: None,
+            })),
+        ]);
+    }
+
+    /// Unsubscribes from all data and cancels the working quotes.
+    pub fn stop(&self) {
+        let (handlers, bid, ask) = {
+            let mut state = self.state.borrow_mut();
+            (state.handlers.take(), state.bid.take(), state.ask.take())
+        };
+        if let Some((deltas, trades, orders)) = handlers {
+            let instrument_id = self.config.instrument_id;
+            msgbus::unsubscribe_book_deltas(get_book_deltas_topic(instrument_id).into(), &deltas);
+            msgbus::unsubscribe_trades(get_trades_topic(instrument_id).into(), &trades);
+            msgbus::unsubscribe_order_events(
+                get_event_orders_topic(self.strategy_id).into(),
+                &orders,
+            );
+
+            let ts_init = self.clock.borrow().timestamp_ns();
+            Self::send_data([
+                DataCommand::Unsubscribe(UnsubscribeCommand::BookDeltas(UnsubscribeBookDeltas {
+                    instrument_id,
+                    client_id: None,
+                    venue: Some(instrument_id.venue),
+                    command_id: UUID4::new(),
+                    ts_init,
+                    correlation_id: None,
+                    params: None,
+                })),
+                DataCommand::Unsubscribe(UnsubscribeCommand::Trades(UnsubscribeTrades {
+                    instrument_id,
+                    client_id: None,
+                    venue: Some(instrument_id.venue),
+                    command_id: UUID4::new(),
+                    ts_init,
+                    correlation_id: None,
+                    params: None,
+                })),
+            ]);
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let commands: Vec<TradingCommand> = {
+            let cache = self.cache.borrow();
+            [bid, ask]
+                .into_iter()
+                .flatten()
+                .filter_map(|client_order_id| cache.order(&client_order_id))
+                .filter(|order| !order.is_closed())
+                .map(|order| self.cancel_command(order, ts_now))
+                .collect()
+        };
+        Self::send(commands);
+    }
+
+    /// Handles book `deltas` for the instrument, requoting against the updated book.
+    pub fn on_book_deltas(&self, deltas: &OrderBookDeltas) {
+        if let Err(e) = self.state.borrow_mut().book.apply_deltas(deltas) {
+            log::error!("Failed to apply book deltas: {e}");
+            return;
+        }
+        self.requote();
+    }
+
+    /// Handles a `trade` for the instrument, updating the measured volatility.
+    pub fn on_trade(&self, trade: &TradeTick) {
+        self.state
+            .borrow_mut()
+            .volatility
+            .update(trade.ts_event, trade.price.as_f64());
+    }
+
+    /// Handles an order `event` for the strategy, updating the inventory on fills.
+    pub fn on_order_event(&self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let closed = match event {
+            OrderEventAny::Filled(fill) => {
+                let qty = fill.last_qty.as_f64();
+                let mut state = self.state.borrow_mut();
+                match fill.order_side {
+                    OrderSide::Buy => state.inventory += qty,
+                    _ => state.inventory -= qty,
+                }
+                self.cache
+                    .borrow()
+                    .order(&client_order_id)
+                    .is_none_or(Order::is_closed)
+            }
+            OrderEventAny::Denied(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => true,
+            _ => return,
+        };
+
+        if closed {
+            let mut state = self.state.borrow_mut();
+            if state.bid == Some(client_order_id) {
+                state.bid = None;
+            }
+            if state.ask == Some(client_order_id) {
+                state.ask = None;
+            }
+        }
+        self.requote();
+    }
+
+    /// Replaces quotes which drifted from their targets and places missing ones.
+    pub fn requote(&self) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let mut commands = Vec::new();
+        {
+            let mut cache = self.cache.borrow_mut();
+            let mut state = self.state.borrow_mut();
+            let Some(instrument) = cache.instrument(&self.config.instrument_id).cloned() else {
+                log::warn!("No instrument {} to quote", self.config.instrument_id);
+                return;
+            };
+            let variance_rate = match self.config.volatility {
+                Some(volatility) => volatility.powi(2),
+                None => state.volatility.variance_rate(),
+            };
+            let Some(quotes) = MarketMakerQuotes::compute(
+                &self.config,
+                &instrument,
+                &state.book,
+                state.inventory,
+                variance_rate,
+            ) else {
+                return;
+            };
+
+            let tolerance =
+                instrument.price_increment().as_f64() * f64::from(self.config.requote_ticks);
+            for side in [OrderSide::Buy, OrderSide::Sell] {
+                let (working, target) = match side {
+                    OrderSide::Buy => (state.bid, quotes.bid),
+                    _ => (state.ask, quotes.ask),
+                };
+
+                if let Some(client_order_id) = working {
+                    let Some(order) = cache.order(&client_order_id) else {
+                        continue;
+                    };
+                    // Leave quotes alone until acknowledged, they cannot be replaced safely
+                    if !order.is_open() || order.is_pending_cancel() {
+                        continue;
+                    }
+                    let on_target = match (order.price(), target) {
+                        (Some(price), Some(target)) => {
+                            (price.as_f64() - target.as_f64()).abs() < tolerance
+                        }
+                        _ => false,
+                    };
+                    if on_target {
+                        continue;
+                    }
+                    commands.push(self.cancel_command(order, ts_now));
+                }
+
+                let slot = match side {
+                    OrderSide::Buy => &mut state.bid,
+                    _ => &mut state.ask,
+                };
+                *slot = None;
+                let Some(price) = target else {
+                    continue;
+                };
+
+                let order = state.factory.limit(
+                    self.config.instrument_id,
+                    side,
+                    self.config.order_qty,
+                    price,
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                );
+                let client_order_id = order.client_order_id();
+                let submit = SubmitOrder::new(
+                    self.trader_id,
+                    self.config.client_id,
+                    self.strategy_id,
+                    self.config.instrument_id,
+                    client_order_id,
+                    order.init_event().clone(),
+                    None,
+                    None,
+                    None,
+                    UUID4::new(),
+                    ts_now,
+                );
+                if let Err(e) = cache.add_order(order, None, self.config.client_id, false) {
+                    log::error!("Failed to add quote {client_order_id}: {e}");
+                    continue;
+                }
+                match side {
+                    OrderSide::Buy => state.bid = Some(client_order_id),
+                    _ => state.ask = Some(client_order_id),
+                }
+                commands.push(TradingCommand::SubmitOrder(submit));
+            }
+        }
+
+        Self::send(commands);
+    }
+
+    fn cancel_command(&self, order: &OrderAny, ts_now: UnixNanos) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder::new(
+            self.trader_id,
+            self.config.client_id,
+            self.strategy_id,
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id(),
+            UUID4::new(),
+            ts_now,
+            None,
+        ))
+    }
+
+    // Commands are sent once no state is borrowed, as handlers may publish order events
+    // synchronously back to this component.
+    fn send(commands: Vec<TradingCommand>) {
+        for command in commands {
+            let endpoint = match command {
+                TradingCommand::SubmitOrder(_) => MessagingSwitchboard::risk_engine_execute(),
+                _ => MessagingSwitchboard::exec_engine_execute(),
+            };
+            msgbus::send_trading_command(endpoint, command);
+        }
+    }
+
+    fn send_data(commands: [DataCommand; 2]) {
+        for command in commands {
+            msgbus::send_data_command(MessagingSwitchboard::data_engine_queue_execute(), command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{BookOrder, OrderBookDelta},
+        enums::BookAction,
+        instruments::{CurrencyPair, stubs::audusd_sim},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, msgbus::TypedIntoHandler};
+
+    fn book(instrument_id: InstrumentId, bid: &str, ask: &str) -> OrderBookDeltas {
+        let deltas = [(OrderSide::Buy, bid, 1), (OrderSide::Sell, ask, 2)]
+            .into_iter()
+            .map(|(side, price, order_id)| {
+                OrderBookDelta::new(
+                    instrument_id,
+                    BookAction::Add,
+                    BookOrder::new(
+                        side,
+                        Price::from(price),
+                        Quantity::from(1_000_000),
+                        order_id,
+                    ),
+                    0,
+                    order_id,
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                )
+            })
+            .collect();
+        OrderBookDeltas::new(instrument_id, deltas)
+    }
+
+    fn config(instrument_id: InstrumentId) -> InventoryMarketMakerConfig {
+        let mut config =
+            InventoryMarketMakerConfig::new(instrument_id, Quantity::from(100_000), 200_000.0);
+        config.risk_aversion = 1.0;
+        config.liquidity = 20_000.0;
+        config.volatility = Some(0.000_001);
+        config
+    }
+
+    #[rstest]
+    fn test_reservation_price_skews_against_inventory() {
+        let model = AvellanedaStoikov {
+            risk_aversion: 0.1,
+            liquidity: 1.5,
+            horizon_secs: 60.0,
+        };
+
+        assert_eq!(model.reservation_price(100.0, 0.0, 0.01), 100.0);
+        assert!(model.reservation_price(100.0, 5.0, 0.01) < 100.0);
+        assert!(model.reservation_price(100.0, -5.0, 0.01) > 100.0);
+        assert!(model.optimal_spread(0.02) > model.optimal_spread(0.01));
+    }
+
+    #[rstest]
+    fn test_quotes_stop_increasing_inventory_at_limit(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let config = config(instrument.id());
+        let mut book = OrderBook::new(instrument.id(), BookType::L2_MBP);
+        book.apply_deltas(&self::book(instrument.id(), "0.99990", "1.00010"))
+            .unwrap();
+
+        let flat = MarketMakerQuotes::compute(&config, &instrument, &book, 0.0, 0.0).unwrap();
+        let long = MarketMakerQuotes::compute(&config, &instrument, &book, 200_000.0, 0.0).unwrap();
+
+        let (bid, ask) = (flat.bid.unwrap(), flat.ask.unwrap());
+        assert!(bid < ask);
+        assert!(bid < Price::from("1.00010") && ask > Price::from("0.99990"));
+        assert!(long.bid.is_none());
+        assert!(long.ask.is_some());
+    }
+
+    #[rstest]
+    fn test_quotes_submitted_through_risk_engine(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let commands = Rc::new(RefCell::new(Vec::new()));
+        let sink = commands.clone();
+        msgbus::register_trading_command_endpoint(
+            MessagingSwitchboard::risk_engine_execute(),
+            TypedIntoHandler::from(move |command: TradingCommand| sink.borrow_mut().push(command)),
+        );
+        let data_commands = Rc::new(RefCell::new(Vec::new()));
+        let sink = data_commands.clone();
+        msgbus::register_data_command_endpoint(
+            MessagingSwitchboard::data_engine_queue_execute(),
+            TypedIntoHandler::from(move |command: DataCommand| sink.borrow_mut().push(command)),
+        );
+
+        let maker = InventoryMarketMaker::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("MM-001"),
+            config(instrument_id),
+            Rc::new(RefCell::new(TestClock::new())),
+            cache.clone(),
+        );
+        maker.start();
+        assert!(matches!(
+            data_commands.borrow().as_slice(),
+            [
+                DataCommand::Subscribe(SubscribeCommand::BookDeltas(deltas)),
+                DataCommand::Subscribe(SubscribeCommand::Trades(trades)),
+            ] if deltas.instrument_id == instrument_id && trades.instrument_id == instrument_id
+        ));
+        msgbus::publish_deltas(
+            get_book_deltas_topic(instrument_id),
+            &book(instrument_id, "0.99990", "1.00010"),
+        );
+
+        let commands = commands.borrow();
+        let sides: Vec<OrderSide> = commands
+            .iter()
+            .map(|command| match command {
+                TradingCommand::SubmitOrder(submit) => submit.order_init.order_side,
+                other => panic!("Unexpected command {other:?}"),
+            })
+            .collect();
+        assert_eq!(sides, vec![OrderSide::Buy, OrderSide::Sell]);
+        let (bid, ask) = maker.working_quotes();
+        assert!(cache.borrow().order(&bid.unwrap()).is_some());
+        assert!(cache.borrow().order(&ask.unwrap()).is_some());
+
+        maker.stop();
+        assert!(matches!(
+            &data_commands.borrow()[2..],
+            [
+                DataCommand::Unsubscribe(UnsubscribeCommand::BookDeltas(_)),
+                DataCommand::Unsubscribe(UnsubscribeCommand::Trades(_)),
+            ]
+        ));
+    }
+}
@@ -15,8 +15,14 @@
 
 use std::{any::Any, sync::Arc};
 
+#[cfg(feature = "arrow")]
+use arrow::record_batch::RecordBatch;
 use indexmap::IndexMap;
 use nautilus_core::{UUID4, UnixNanos};
+#[cfg(feature = "arrow")]
+use nautilus_model::data::arrow::{
+    bars_to_record_batch, quotes_to_record_batch, trades_to_record_batch,
+};
 use nautilus_model::{
     data::{Bar, BarType, DataType, FundingRateUpdate, QuoteTick, TradeTick},
     identifiers::{ClientId, InstrumentId, Venue},
@@ -242,6 +248,16 @@ impl QuotesResponse {
             params,
         }
     }
+
+    /// Encodes the response quotes into a single Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the quotes are for multiple instruments or encoding fails.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> anyhow::Result<RecordBatch> {
+        quotes_to_record_batch(&self.data)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -285,6 +301,16 @@ impl TradesResponse {
             params,
         }
     }
+
+    /// Encodes the response trades into a single Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trades are for multiple instruments or encoding fails.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> anyhow::Result<RecordBatch> {
+        trades_to_record_batch(&self.data)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -371,4 +397,14 @@ impl BarsResponse {
             params,
         }
     }
+
+    /// Encodes the response bars into a single Arrow record batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bars are for multiple bar types or encoding fails.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> anyhow::Result<RecordBatch> {
+        bars_to_record_batch(&self.data)
+    }
 }
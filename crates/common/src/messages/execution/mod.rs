@@ -23,11 +23,12 @@ pub mod submit;
 
 // Re-exports
 pub use nautilus_core::Params;
-use nautilus_core::UnixNanos;
+use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
     identifiers::{ClientId, InstrumentId, StrategyId},
     reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
 };
+use serde::Serialize;
 use strum::Display;
 
 pub use self::{
@@ -40,7 +41,7 @@ pub use self::{
         GenerateOrderStatusReports, GenerateOrderStatusReportsBuilder,
         GeneratePositionStatusReports, GeneratePositionStatusReportsBuilder,
     },
-    submit::{SubmitOrder, SubmitOrderList},
+    submit::{SpreadLeg, SubmitOrder, SubmitOrderList, SubmitSpreadOrder},
 };
 
 /// Execution report variants for reconciliation.
@@ -54,10 +55,12 @@ pub enum ExecutionReport {
 
 // TODO
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, Eq, PartialEq, Display)]
+#[derive(Clone, Debug, Eq, PartialEq, Display, Serialize)]
+#[serde(untagged)]
 pub enum TradingCommand {
     SubmitOrder(SubmitOrder),
     SubmitOrderList(SubmitOrderList),
+    SubmitSpreadOrder(SubmitSpreadOrder),
     ModifyOrder(ModifyOrder),
     CancelOrder(CancelOrder),
     CancelAllOrders(CancelAllOrders),
@@ -72,6 +75,7 @@ impl TradingCommand {
         match self {
             Self::SubmitOrder(command) => command.client_id,
             Self::SubmitOrderList(command) => command.client_id,
+            Self::SubmitSpreadOrder(command) => command.client_id,
             Self::ModifyOrder(command) => command.client_id,
             Self::CancelOrder(command) => command.client_id,
             Self::CancelAllOrders(command) => command.client_id,
@@ -91,6 +95,7 @@ impl TradingCommand {
         match self {
             Self::SubmitOrder(command) => command.instrument_id,
             Self::SubmitOrderList(command) => command.instrument_id,
+            Self::SubmitSpreadOrder(command) => command.instrument_id,
             Self::ModifyOrder(command) => command.instrument_id,
             Self::CancelOrder(command) => command.instrument_id,
             Self::CancelAllOrders(command) => command.instrument_id,
@@ -100,11 +105,27 @@ impl TradingCommand {
         }
     }
 
+    #[must_use]
+    pub const fn command_id(&self) -> UUID4 {
+        match self {
+            Self::SubmitOrder(command) => command.command_id,
+            Self::SubmitOrderList(command) => command.command_id,
+            Self::SubmitSpreadOrder(command) => command.command_id,
+            Self::ModifyOrder(command) => command.command_id,
+            Self::CancelOrder(command) => command.command_id,
+            Self::CancelAllOrders(command) => command.command_id,
+            Self::BatchCancelOrders(command) => command.command_id,
+            Self::QueryOrder(command) => command.command_id,
+            Self::QueryAccount(command) => command.command_id,
+        }
+    }
+
     #[must_use]
     pub const fn ts_init(&self) -> UnixNanos {
         match self {
             Self::SubmitOrder(command) => command.ts_init,
             Self::SubmitOrderList(command) => command.ts_init,
+            Self::SubmitSpreadOrder(command) => command.ts_init,
             Self::ModifyOrder(command) => command.ts_init,
             Self::CancelOrder(command) => command.ts_init,
             Self::CancelAllOrders(command) => command.ts_init,
@@ -119,6 +140,7 @@ impl TradingCommand {
         match self {
             Self::SubmitOrder(command) => Some(command.strategy_id),
             Self::SubmitOrderList(command) => Some(command.strategy_id),
+            Self::SubmitSpreadOrder(command) => Some(command.strategy_id),
             Self::ModifyOrder(command) => Some(command.strategy_id),
             Self::CancelOrder(command) => Some(command.strategy_id),
             Self::CancelAllOrders(command) => Some(command.strategy_id),
@@ -16,8 +16,9 @@
 use std::fmt::Display;
 
 use indexmap::IndexMap;
-use nautilus_core::{UUID4, UnixNanos};
+use nautilus_core::{UUID4, UnixNanos, correctness::FAILED};
 use nautilus_model::{
+    enums::OrderSide,
     events::OrderInitialized,
     identifiers::{
         ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
@@ -145,3 +146,158 @@ impl Display for SubmitOrderList {
         )
     }
 }
+
+/// A leg of a spread order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct SpreadLeg {
+    pub instrument_id: InstrumentId,
+    /// The side of the leg when buying the spread (reversed when selling the spread).
+    pub side: OrderSide,
+    /// The leg quantity per unit of spread quantity.
+    pub ratio: u32,
+}
+
+impl SpreadLeg {
+    /// Creates a new [`SpreadLeg`] instance.
+    #[must_use]
+    pub const fn new(instrument_id: InstrumentId, side: OrderSide, ratio: u32) -> Self {
+        Self {
+            instrument_id,
+            side,
+            ratio,
+        }
+    }
+
+    /// Returns the side of the leg for a spread order on `spread_side`.
+    #[must_use]
+    pub fn side_for(&self, spread_side: OrderSide) -> OrderSide {
+        match spread_side {
+            OrderSide::Sell => self.side.as_specified().opposite().as_order_side(),
+            _ => self.side,
+        }
+    }
+}
+
+/// Submits an order for a multi-leg spread instrument (e.g. an options combo or futures spread).
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct SubmitSpreadOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    /// The spread instrument ID.
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    /// The spread order, priced and sized in spread units.
+    pub order_init: OrderInitialized,
+    pub legs: Vec<SpreadLeg>,
+    pub params: Option<IndexMap<String, String>>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl SubmitSpreadOrder {
+    /// Creates a new [`SubmitSpreadOrder`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than two legs, a leg has no side or a zero ratio, or an
+    /// instrument appears in more than one leg.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_init: OrderInitialized,
+        legs: Vec<SpreadLeg>,
+        params: Option<IndexMap<String, String>>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            legs.len() >= 2,
+            "Spread order {client_order_id} requires at least two legs, was {}",
+            legs.len()
+        );
+        for (i, leg) in legs.iter().enumerate() {
+            anyhow::ensure!(
+                leg.side != OrderSide::NoOrderSide,
+                "Spread leg {} has no side",
+                leg.instrument_id
+            );
+            anyhow::ensure!(
+                leg.ratio > 0,
+                "Spread leg {} has zero ratio",
+                leg.instrument_id
+            );
+            anyhow::ensure!(
+                legs[..i]
+                    .iter()
+                    .all(|other| other.instrument_id != leg.instrument_id),
+                "Duplicate spread leg {}",
+                leg.instrument_id
+            );
+        }
+
+        Ok(Self {
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_init,
+            legs,
+            params,
+            command_id,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`SubmitSpreadOrder`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the legs are invalid (see [`Self::new_checked`]).
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_init: OrderInitialized,
+        legs: Vec<SpreadLeg>,
+        params: Option<IndexMap<String, String>>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_init,
+            legs,
+            params,
+            command_id,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+}
+
+impl Display for SubmitSpreadOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SubmitSpreadOrder(instrument_id={}, client_order_id={}, legs={})",
+            self.instrument_id,
+            self.client_order_id,
+            self.legs.len(),
+        )
+    }
+}
@@ -0,0 +1,362 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Optional topic access control for the message bus.
+//!
+//! In multi-strategy nodes it can be desirable to restrict which topics an actor may publish
+//! to or subscribe from (e.g. strategies should not publish order events). A [`TopicAcl`]
+//! grants or denies [`AclPermission`]s on topic patterns per principal (typically an actor ID).
+//!
+//! The ACL is enforced by the principal aware message bus functions such as
+//! [`publish_any_as`](super::publish_any_as) and [`subscribe_any_as`](super::subscribe_any_as),
+//! and by every typed `subscribe_*` / `publish_*` function called within a
+//! [`with_principal`](super::with_principal) scope. Data actors subscribe and run their handlers
+//! within a scope of their actor ID. Every violation is logged and retained for audit.
+//!
+//! Endpoint sends (`send_*`) are out of scope: endpoints are point-to-point handlers registered
+//! by the node's own components (such as the execution engine), so access to them is controlled
+//! by which components are wired to them rather than by topic permissions.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ahash::AHashMap;
+use strum::Display as StrumDisplay;
+use ustr::Ustr;
+
+use super::{
+    matching::{is_matching, pattern_contains, patterns_overlap},
+    mstr::{MStr, Pattern},
+};
+
+/// The default maximum number of violations retained for audit.
+pub const DEFAULT_MAX_ACL_VIOLATIONS: usize = 1_000;
+
+/// A permission which can be granted on a topic pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, StrumDisplay)]
+#[strum(serialize_all = "lowercase")]
+pub enum AclPermission {
+    /// Permission to publish messages to matching topics.
+    Publish,
+    /// Permission to subscribe to matching topics.
+    Subscribe,
+}
+
+/// A recorded ACL violation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AclViolation {
+    /// The principal which attempted the operation.
+    pub principal: Ustr,
+    /// The permission which was required.
+    pub permission: AclPermission,
+    /// The topic (publish) or pattern (subscribe) of the attempted operation.
+    pub target: Ustr,
+}
+
+impl Display for AclViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is not permitted to {} '{}'",
+            self.principal, self.permission, self.target
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct PrincipalRules {
+    allow: Vec<(AclPermission, MStr<Pattern>)>,
+    deny: Vec<(AclPermission, MStr<Pattern>)>,
+}
+
+/// Access control list of topic permissions per principal.
+///
+/// Deny rules take precedence over allow rules. Principals without any matching rule fall back
+/// to the default policy, which allows everything unless configured as deny by default.
+#[derive(Clone, Debug)]
+pub struct TopicAcl {
+    rules: AHashMap<Ustr, PrincipalRules>,
+    default_allow: bool,
+    max_violations: usize,
+    violations: VecDeque<AclViolation>,
+    violation_count: u64,
+}
+
+impl Default for TopicAcl {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl TopicAcl {
+    /// Creates a new [`TopicAcl`] instance with the given default policy.
+    #[must_use]
+    pub fn new(default_allow: bool) -> Self {
+        Self {
+            rules: AHashMap::new(),
+            default_allow,
+            max_violations: DEFAULT_MAX_ACL_VIOLATIONS,
+            violations: VecDeque::new(),
+            violation_count: 0,
+        }
+    }
+
+    /// Sets the maximum number of violations retained for audit.
+    #[must_use]
+    pub const fn with_max_violations(mut self, max_violations: usize) -> Self {
+        self.max_violations = max_violations;
+        self
+    }
+
+    /// Returns whether principals without a matching rule are allowed.
+    #[must_use]
+    pub const fn default_allow(&self) -> bool {
+        self.default_allow
+    }
+
+    /// Grants `principal` the `permission` on topics matching `pattern`.
+    pub fn allow<P: Into<MStr<Pattern>>>(
+        &mut self,
+        principal: &str,
+        permission: AclPermission,
+        pattern: P,
+    ) {
+        self.rules
+            .entry(Ustr::from(principal))
+            .or_default()
+            .allow
+            .push((permission, pattern.into()));
+    }
+
+    /// Denies `principal` the `permission` on topics matching `pattern`.
+    pub fn deny<P: Into<MStr<Pattern>>>(
+        &mut self,
+        principal: &str,
+        permission: AclPermission,
+        pattern: P,
+    ) {
+        self.rules
+            .entry(Ustr::from(principal))
+            .or_default()
+            .deny
+            .push((permission, pattern.into()));
+    }
+
+    /// Removes all rules for `principal`.
+    pub fn revoke_all(&mut self, principal: &str) {
+        self.rules.remove(&Ustr::from(principal));
+    }
+
+    /// Returns whether `principal` has `permission` on `target` (without recording violations).
+    ///
+    /// For subscriptions `target` is the subscription pattern, which is permitted when the
+    /// pattern itself is matched by a granted pattern (so `data.*` does not fall within a grant
+    /// of `data.quotes.*`), and denied when it overlaps any denied pattern (so a deny on
+    /// `data.quotes.*` also denies subscribing to `data.*` or `*`).
+    #[must_use]
+    pub fn is_permitted(&self, principal: &str, permission: AclPermission, target: &str) -> bool {
+        let Some(rules) = self.rules.get(&Ustr::from(principal)) else {
+            return self.default_allow;
+        };
+
+        // A subscription pattern must be contained in a grant, wildcards included
+        let matches = |(perm, pattern): &(AclPermission, MStr<Pattern>)| {
+            *perm == permission
+                && match permission {
+                    AclPermission::Publish => is_matching(target.as_bytes(), pattern.as_bytes()),
+                    AclPermission::Subscribe => {
+                        pattern_contains(pattern.as_bytes(), target.as_bytes())
+                    }
+                }
+        };
+
+        // A subscription receives every topic its pattern matches, so any overlap is denied
+        let denied = |(perm, pattern): &(AclPermission, MStr<Pattern>)| {
+            *perm == permission
+                && match permission {
+                    AclPermission::Publish => is_matching(target.as_bytes(), pattern.as_bytes()),
+                    AclPermission::Subscribe => {
+                        patterns_overlap(target.as_bytes(), pattern.as_bytes())
+                    }
+                }
+        };
+
+        if rules.deny.iter().any(denied) {
+            return false;
+        }
+
+        if rules.allow.iter().any(matches) {
+            return true;
+        }
+
+        // A principal with explicit grants for this permission is restricted to those grants
+        let has_grants = rules.allow.iter().any(|(perm, _)| *perm == permission);
+        !has_grants && self.default_allow
+    }
+
+    /// Checks `principal` has `permission` on `target`, recording a violation if not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the violation if the operation is not permitted.
+    pub fn check(
+        &mut self,
+        principal: &str,
+        permission: AclPermission,
+        target: &str,
+    ) -> anyhow::Result<()> {
+        if self.is_permitted(principal, permission, target) {
+            return Ok(());
+        }
+
+        let violation = AclViolation {
+            principal: Ustr::from(principal),
+            permission,
+            target: Ustr::from(target),
+        };
+        log::error!("Message bus ACL violation: {violation}");
+
+        let err = anyhow::anyhow!("Message bus ACL violation: {violation}");
+        self.violation_count += 1;
+        if self.max_violations > 0 {
+            if self.violations.len() == self.max_violations {
+                self.violations.pop_front();
+            }
+            self.violations.push_back(violation);
+        }
+
+        Err(err)
+    }
+
+    /// Returns the retained violations (oldest first).
+    #[must_use]
+    pub fn violations(&self) -> Vec<AclViolation> {
+        self.violations.iter().cloned().collect()
+    }
+
+    /// Returns the total number of violations recorded.
+    #[must_use]
+    pub const fn violation_count(&self) -> u64 {
+        self.violation_count
+    }
+
+    /// Clears the retained violations and the violation count.
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+        self.violation_count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_default_allow_without_rules() {
+        let acl = TopicAcl::new(true);
+        assert!(acl.is_permitted("S-001", AclPermission::Publish, "events.order.S-001"));
+
+        let acl = TopicAcl::new(false);
+        assert!(!acl.is_permitted("S-001", AclPermission::Publish, "events.order.S-001"));
+    }
+
+    #[rstest]
+    fn test_deny_takes_precedence() {
+        let mut acl = TopicAcl::default();
+        acl.allow("S-001", AclPermission::Publish, "*");
+        acl.deny("S-001", AclPermission::Publish, "events.order.*");
+
+        assert!(acl.is_permitted("S-001", AclPermission::Publish, "data.custom"));
+        assert!(!acl.is_permitted("S-001", AclPermission::Publish, "events.order.S-001"));
+    }
+
+    #[rstest]
+    fn test_grants_restrict_permission() {
+        let mut acl = TopicAcl::default();
+        acl.allow("S-001", AclPermission::Publish, "signals.S-001.*");
+
+        assert!(acl.is_permitted("S-001", AclPermission::Publish, "signals.S-001.alpha"));
+        assert!(!acl.is_permitted("S-001", AclPermission::Publish, "signals.S-002.alpha"));
+        // Subscribe has no grants so falls back to the default policy
+        assert!(acl.is_permitted("S-001", AclPermission::Subscribe, "data.quotes.*"));
+    }
+
+    #[rstest]
+    fn test_subscription_pattern_must_fall_within_grant() {
+        let mut acl = TopicAcl::default();
+        acl.allow("S-001", AclPermission::Subscribe, "data.quotes.*");
+
+        assert!(acl.is_permitted("S-001", AclPermission::Subscribe, "data.quotes.*"));
+        assert!(acl.is_permitted("S-001", AclPermission::Subscribe, "data.quotes.BINANCE.*"));
+        assert!(!acl.is_permitted("S-001", AclPermission::Subscribe, "data.*"));
+    }
+
+    #[rstest]
+    fn test_single_character_grant_does_not_cover_wildcard() {
+        let mut acl = TopicAcl::default();
+        acl.allow("S-001", AclPermission::Subscribe, "signals.?");
+
+        assert!(acl.is_permitted("S-001", AclPermission::Subscribe, "signals.a"));
+        assert!(acl.is_permitted("S-001", AclPermission::Subscribe, "signals.?"));
+        assert!(!acl.is_permitted("S-001", AclPermission::Subscribe, "signals.*"));
+    }
+
+    #[rstest]
+    #[case("data.quotes.*", false)]
+    #[case("data.quotes.BINANCE.*", false)]
+    #[case("data.*", false)]
+    #[case("*", false)]
+    #[case("data.*.BINANCE", false)]
+    #[case("data.trades.*", true)]
+    #[case("events.order.*", true)]
+    fn test_subscription_overlapping_deny_is_denied(#[case] pattern: &str, #[case] expected: bool) {
+        let mut acl = TopicAcl::default();
+        acl.deny("S-001", AclPermission::Subscribe, "data.quotes.*");
+
+        assert_eq!(
+            acl.is_permitted("S-001", AclPermission::Subscribe, pattern),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_check_records_violations() {
+        let mut acl = TopicAcl::default().with_max_violations(1);
+        acl.deny("S-001", AclPermission::Publish, "events.*");
+
+        assert!(
+            acl.check("S-001", AclPermission::Publish, "data.custom")
+                .is_ok()
+        );
+
+        let err = acl
+            .check("S-001", AclPermission::Publish, "events.order.S-001")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Message bus ACL violation: 'S-001' is not permitted to publish 'events.order.S-001'"
+        );
+
+        let _ = acl.check("S-001", AclPermission::Publish, "events.position.S-001");
+        assert_eq!(acl.violation_count(), 2);
+        assert_eq!(acl.violations().len(), 1);
+        assert_eq!(acl.violations()[0].target.as_str(), "events.position.S-001");
+
+        acl.clear_violations();
+        assert_eq!(acl.violation_count(), 0);
+        assert!(acl.violations().is_empty());
+    }
+}
@@ -32,8 +32,8 @@ use nautilus_model::defi::{
 };
 use nautilus_model::{
     data::{
-        Bar, Data, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
-        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        Bar, Data, EconomicEvent, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick, YieldCurveData,
     },
     events::{AccountState, OrderEventAny, PositionEvent},
     orderbook::OrderBook,
@@ -41,16 +41,21 @@ use nautilus_model::{
     position::Position,
 };
 use smallvec::SmallVec;
+use ustr::Ustr;
 
 use super::{
-    ACCOUNT_STATE_HANDLERS, ANY_HANDLERS, BAR_HANDLERS, BOOK_HANDLERS, DELTAS_HANDLERS,
-    DEPTH10_HANDLERS, FUNDING_RATE_HANDLERS, GREEKS_HANDLERS, HANDLER_BUFFER_CAP,
-    INDEX_PRICE_HANDLERS, MARK_PRICE_HANDLERS, MESSAGE_BUS, ORDER_EVENT_HANDLERS,
-    POSITION_EVENT_HANDLERS, QUOTE_HANDLERS, TRADE_HANDLERS,
+    ACCOUNT_STATE_HANDLERS, ACL_PRINCIPAL, ANY_HANDLERS, BAR_HANDLERS, BOOK_HANDLERS,
+    DELTAS_HANDLERS, DEPTH10_HANDLERS, ECONOMIC_EVENT_HANDLERS, FUNDING_RATE_HANDLERS,
+    GREEKS_HANDLERS, HANDLER_BUFFER_CAP, INDEX_PRICE_HANDLERS, MARK_PRICE_HANDLERS, MESSAGE_BUS,
+    ORDER_EVENT_HANDLERS, POSITION_EVENT_HANDLERS, QUOTE_HANDLERS, TRADE_HANDLERS,
+    YIELD_CURVE_HANDLERS,
+    acl::{AclPermission, AclViolation, TopicAcl},
     core::{MessageBus, Subscription},
     get_message_bus,
     matching::is_matching_backtracking,
     mstr::{Endpoint, MStr, Pattern, Topic},
+    profiling::profiled,
+    recorder,
     typed_handler::{ShareableMessageHandler, TypedHandler, TypedIntoHandler},
 };
 #[cfg(feature = "defi")]
@@ -58,9 +63,12 @@ use super::{
     DEFI_BLOCK_HANDLERS, DEFI_COLLECT_HANDLERS, DEFI_FLASH_HANDLERS, DEFI_LIQUIDITY_HANDLERS,
     DEFI_POOL_HANDLERS, DEFI_SWAP_HANDLERS,
 };
-use crate::messages::{
-    data::{DataCommand, DataResponse},
-    execution::{ExecutionReport, TradingCommand},
+use crate::{
+    logging::audit::{audit_order_event, audit_trading_command},
+    messages::{
+        data::{DataCommand, DataResponse},
+        execution::{ExecutionReport, TradingCommand},
+    },
 };
 
 /// Registers a handler for an endpoint using runtime type dispatch (Any).
@@ -216,6 +224,10 @@ pub fn subscribe_any(
     handler: ShareableMessageHandler,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     let msgbus = get_message_bus();
     let mut msgbus_ref_mut = msgbus.borrow_mut();
     let sub = Subscription::new(pattern, handler, priority);
@@ -266,6 +278,10 @@ pub fn subscribe_book_deltas(
     handler: TypedHandler<OrderBookDeltas>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_deltas
@@ -278,6 +294,10 @@ pub fn subscribe_book_depth10(
     handler: TypedHandler<OrderBookDepth10>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_depth10.subscribe(
         pattern,
         handler,
@@ -291,6 +311,10 @@ pub fn subscribe_book_snapshots(
     handler: TypedHandler<OrderBook>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_book_snapshots
@@ -303,6 +327,10 @@ pub fn subscribe_quotes(
     handler: TypedHandler<QuoteTick>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_quotes
@@ -315,6 +343,10 @@ pub fn subscribe_trades(
     handler: TypedHandler<TradeTick>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_trades
@@ -323,6 +355,10 @@ pub fn subscribe_trades(
 
 /// Subscribes a handler to bars matching a pattern.
 pub fn subscribe_bars(pattern: MStr<Pattern>, handler: TypedHandler<Bar>, priority: Option<u8>) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_bars
@@ -335,6 +371,10 @@ pub fn subscribe_mark_prices(
     handler: TypedHandler<MarkPriceUpdate>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_mark_prices.subscribe(
         pattern,
         handler,
@@ -348,6 +388,10 @@ pub fn subscribe_index_prices(
     handler: TypedHandler<IndexPriceUpdate>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_index_prices
@@ -360,6 +404,10 @@ pub fn subscribe_funding_rates(
     handler: TypedHandler<FundingRateUpdate>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_funding_rates
@@ -372,18 +420,58 @@ pub fn subscribe_greeks(
     handler: TypedHandler<GreeksData>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_greeks
         .subscribe(pattern, handler, priority.unwrap_or(0));
 }
 
+/// Subscribes a handler to yield curve data matching a pattern.
+pub fn subscribe_yield_curves(
+    pattern: MStr<Pattern>,
+    handler: TypedHandler<YieldCurveData>,
+    priority: Option<u8>,
+) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
+    get_message_bus()
+        .borrow_mut()
+        .router_yield_curves
+        .subscribe(pattern, handler, priority.unwrap_or(0));
+}
+
+/// Subscribes a handler to economic events matching a pattern.
+pub fn subscribe_economic_events(
+    pattern: MStr<Pattern>,
+    handler: TypedHandler<EconomicEvent>,
+    priority: Option<u8>,
+) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
+    get_message_bus()
+        .borrow_mut()
+        .router_economic_events
+        .subscribe(pattern, handler, priority.unwrap_or(0));
+}
+
 /// Subscribes a handler to order events matching a pattern.
 pub fn subscribe_order_events(
     pattern: MStr<Pattern>,
     handler: TypedHandler<OrderEventAny>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_order_events
@@ -396,6 +484,10 @@ pub fn subscribe_position_events(
     handler: TypedHandler<PositionEvent>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_position_events
@@ -408,6 +500,10 @@ pub fn subscribe_account_state(
     handler: TypedHandler<AccountState>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_account_state
@@ -420,6 +516,10 @@ pub fn subscribe_positions(
     handler: TypedHandler<Position>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_positions.subscribe(
         pattern,
         handler,
@@ -434,6 +534,10 @@ pub fn subscribe_defi_blocks(
     handler: TypedHandler<Block>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_defi_blocks.subscribe(
         pattern,
         handler,
@@ -448,6 +552,10 @@ pub fn subscribe_defi_pools(
     handler: TypedHandler<Pool>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_defi_pools.subscribe(
         pattern,
         handler,
@@ -462,6 +570,10 @@ pub fn subscribe_defi_swaps(
     handler: TypedHandler<PoolSwap>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_defi_swaps.subscribe(
         pattern,
         handler,
@@ -476,6 +588,10 @@ pub fn subscribe_defi_liquidity(
     handler: TypedHandler<PoolLiquidityUpdate>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_defi_liquidity
@@ -489,6 +605,10 @@ pub fn subscribe_defi_collects(
     handler: TypedHandler<PoolFeeCollect>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus()
         .borrow_mut()
         .router_defi_collects
@@ -502,6 +622,10 @@ pub fn subscribe_defi_flash(
     handler: TypedHandler<PoolFlash>,
     priority: Option<u8>,
 ) {
+    if !acl_permits(AclPermission::Subscribe, pattern.as_str()) {
+        return;
+    }
+
     get_message_bus().borrow_mut().router_defi_flash.subscribe(
         pattern,
         handler,
@@ -642,6 +766,22 @@ pub fn unsubscribe_greeks(pattern: MStr<Pattern>, handler: &TypedHandler<GreeksD
         .unsubscribe(pattern, handler);
 }
 
+/// Unsubscribes a handler from yield curve data.
+pub fn unsubscribe_yield_curves(pattern: MStr<Pattern>, handler: &TypedHandler<YieldCurveData>) {
+    get_message_bus()
+        .borrow_mut()
+        .router_yield_curves
+        .unsubscribe(pattern, handler);
+}
+
+/// Unsubscribes a handler from economic events.
+pub fn unsubscribe_economic_events(pattern: MStr<Pattern>, handler: &TypedHandler<EconomicEvent>) {
+    get_message_bus()
+        .borrow_mut()
+        .router_economic_events
+        .unsubscribe(pattern, handler);
+}
+
 /// Unsubscribes a handler from DeFi blocks.
 #[cfg(feature = "defi")]
 pub fn unsubscribe_defi_blocks(pattern: MStr<Pattern>, handler: &TypedHandler<Block>) {
@@ -761,8 +901,132 @@ pub fn subscriber_count_book_snapshots(topic: MStr<Topic>) -> usize {
         .subscriber_count(topic)
 }
 
+/// Enables topic access control on the thread-local message bus.
+pub fn set_acl(acl: TopicAcl) {
+    get_message_bus().borrow_mut().set_acl(acl);
+}
+
+/// Disables topic access control on the thread-local message bus.
+pub fn clear_acl() -> Option<TopicAcl> {
+    get_message_bus().borrow_mut().clear_acl()
+}
+
+/// Returns the retained ACL violations (empty if access control is not enabled).
+pub fn acl_violations() -> Vec<AclViolation> {
+    get_message_bus()
+        .borrow()
+        .acl()
+        .map(TopicAcl::violations)
+        .unwrap_or_default()
+}
+
+/// Checks whether `principal` may publish to `topic`.
+///
+/// Always succeeds when access control is not enabled.
+///
+/// # Errors
+///
+/// Returns an error if the ACL denies the publish (the violation is recorded).
+pub fn check_publish(principal: &str, topic: MStr<Topic>) -> anyhow::Result<()> {
+    check_acl(principal, AclPermission::Publish, topic.as_str())
+}
+
+/// Checks whether `principal` may subscribe to `pattern`.
+///
+/// Always succeeds when access control is not enabled.
+///
+/// # Errors
+///
+/// Returns an error if the ACL denies the subscription (the violation is recorded).
+pub fn check_subscribe(principal: &str, pattern: MStr<Pattern>) -> anyhow::Result<()> {
+    check_acl(principal, AclPermission::Subscribe, pattern.as_str())
+}
+
+fn check_acl(principal: &str, permission: AclPermission, target: &str) -> anyhow::Result<()> {
+    match get_message_bus().borrow_mut().acl.as_mut() {
+        Some(acl) => acl.check(principal, permission, target),
+        None => Ok(()),
+    }
+}
+
+/// Runs `f` with `principal` as the principal of the message bus calls made within it.
+///
+/// The typed `subscribe_*` and `publish_*` functions carry no principal of their own, so the
+/// topic ACL is enforced against the innermost principal set here (a denied call is logged,
+/// recorded as a violation and has no effect). Calls made outside any principal scope are
+/// system component calls and are not checked.
+///
+/// The principal is cleared while handlers are dispatched, so messages published by a
+/// subscriber are not checked against the grants of the original publisher.
+pub fn with_principal<R>(principal: &str, f: impl FnOnce() -> R) -> R {
+    scoped_principal(Some(Ustr::from(principal)), f)
+}
+
+fn scoped_principal<R>(principal: Option<Ustr>, f: impl FnOnce() -> R) -> R {
+    struct RestorePrincipal(Option<Ustr>);
+
+    impl Drop for RestorePrincipal {
+        fn drop(&mut self) {
+            ACL_PRINCIPAL.set(self.0);
+        }
+    }
+
+    let _restore = RestorePrincipal(ACL_PRINCIPAL.replace(principal));
+    f()
+}
+
+/// Returns the principal set by the innermost enclosing [`with_principal`] scope, if any.
+#[must_use]
+pub fn current_principal() -> Option<Ustr> {
+    ACL_PRINCIPAL.get()
+}
+
+/// Returns whether the current principal (if any) is permitted `permission` on `target`.
+#[inline]
+fn acl_permits(permission: AclPermission, target: &str) -> bool {
+    match ACL_PRINCIPAL.get() {
+        Some(principal) => check_acl(&principal, permission, target).is_ok(),
+        None => true,
+    }
+}
+
+/// Subscribes a handler to a pattern on behalf of `principal`, enforcing the topic ACL.
+///
+/// # Errors
+///
+/// Returns an error if the ACL denies the subscription.
+pub fn subscribe_any_as(
+    principal: &str,
+    pattern: MStr<Pattern>,
+    handler: ShareableMessageHandler,
+    priority: Option<u8>,
+) -> anyhow::Result<()> {
+    check_subscribe(principal, pattern)?;
+    with_principal(principal, || subscribe_any(pattern, handler, priority));
+    Ok(())
+}
+
+/// Publishes a message to the topic on behalf of `principal`, enforcing the topic ACL.
+///
+/// # Errors
+///
+/// Returns an error if the ACL denies the publish, in which case no handlers are called.
+pub fn publish_any_as(
+    principal: &str,
+    topic: MStr<Topic>,
+    message: &dyn Any,
+) -> anyhow::Result<()> {
+    check_publish(principal, topic)?;
+    with_principal(principal, || publish_any(topic, message));
+    Ok(())
+}
+
 /// Publishes a message to the topic using runtime type dispatch (Any).
 pub fn publish_any(topic: MStr<Topic>, message: &dyn Any) {
+    if !acl_permits(AclPermission::Publish, topic.as_str()) {
+        return;
+    }
+
     // SAFETY: Take buffer (re-entrancy safe)
     let mut handlers = ANY_HANDLERS.with_borrow_mut(std::mem::take);
 
@@ -771,7 +1035,7 @@ pub fn publish_any(topic: MStr<Topic>, message: &dyn Any) {
         .fill_matching_any_handlers(topic, &mut handlers);
 
     for handler in &handlers {
-        handler.0.handle(message);
+        dispatch(handler.0.id(), *topic, || handler.0.handle(message));
     }
 
     handlers.clear(); // Release refs before restore
@@ -781,6 +1045,7 @@ pub fn publish_any(topic: MStr<Topic>, message: &dyn Any) {
 /// Publishes order book deltas to subscribers on a topic.
 pub fn publish_deltas(topic: MStr<Topic>, deltas: &OrderBookDeltas) {
     publish_typed(
+        topic,
         &DELTAS_HANDLERS,
         |bus, h| bus.router_deltas.fill_matching_handlers(topic, h),
         deltas,
@@ -790,6 +1055,7 @@ pub fn publish_deltas(topic: MStr<Topic>, deltas: &OrderBookDeltas) {
 /// Publishes order book depth10 to subscribers on a topic.
 pub fn publish_depth10(topic: MStr<Topic>, depth: &OrderBookDepth10) {
     publish_typed(
+        topic,
         &DEPTH10_HANDLERS,
         |bus, h| bus.router_depth10.fill_matching_handlers(topic, h),
         depth,
@@ -799,6 +1065,7 @@ pub fn publish_depth10(topic: MStr<Topic>, depth: &OrderBookDepth10) {
 /// Publishes an order book snapshot to subscribers on a topic.
 pub fn publish_book(topic: MStr<Topic>, book: &OrderBook) {
     publish_typed(
+        topic,
         &BOOK_HANDLERS,
         |bus, h| bus.router_book_snapshots.fill_matching_handlers(topic, h),
         book,
@@ -808,6 +1075,7 @@ pub fn publish_book(topic: MStr<Topic>, book: &OrderBook) {
 /// Publishes a quote tick to subscribers on a topic.
 pub fn publish_quote(topic: MStr<Topic>, quote: &QuoteTick) {
     publish_typed(
+        topic,
         &QUOTE_HANDLERS,
         |bus, h| bus.router_quotes.fill_matching_handlers(topic, h),
         quote,
@@ -817,6 +1085,7 @@ pub fn publish_quote(topic: MStr<Topic>, quote: &QuoteTick) {
 /// Publishes a trade tick to subscribers on a topic.
 pub fn publish_trade(topic: MStr<Topic>, trade: &TradeTick) {
     publish_typed(
+        topic,
         &TRADE_HANDLERS,
         |bus, h| bus.router_trades.fill_matching_handlers(topic, h),
         trade,
@@ -826,6 +1095,7 @@ pub fn publish_trade(topic: MStr<Topic>, trade: &TradeTick) {
 /// Publishes a bar to subscribers on a topic.
 pub fn publish_bar(topic: MStr<Topic>, bar: &Bar) {
     publish_typed(
+        topic,
         &BAR_HANDLERS,
         |bus, h| bus.router_bars.fill_matching_handlers(topic, h),
         bar,
@@ -835,6 +1105,7 @@ pub fn publish_bar(topic: MStr<Topic>, bar: &Bar) {
 /// Publishes a mark price update to subscribers on a topic.
 pub fn publish_mark_price(topic: MStr<Topic>, mark_price: &MarkPriceUpdate) {
     publish_typed(
+        topic,
         &MARK_PRICE_HANDLERS,
         |bus, h| bus.router_mark_prices.fill_matching_handlers(topic, h),
         mark_price,
@@ -844,6 +1115,7 @@ pub fn publish_mark_price(topic: MStr<Topic>, mark_price: &MarkPriceUpdate) {
 /// Publishes an index price update to subscribers on a topic.
 pub fn publish_index_price(topic: MStr<Topic>, index_price: &IndexPriceUpdate) {
     publish_typed(
+        topic,
         &INDEX_PRICE_HANDLERS,
         |bus, h| bus.router_index_prices.fill_matching_handlers(topic, h),
         index_price,
@@ -853,6 +1125,7 @@ pub fn publish_index_price(topic: MStr<Topic>, index_price: &IndexPriceUpdate) {
 /// Publishes a funding rate update to subscribers on a topic.
 pub fn publish_funding_rate(topic: MStr<Topic>, funding_rate: &FundingRateUpdate) {
     publish_typed(
+        topic,
         &FUNDING_RATE_HANDLERS,
         |bus, h| bus.router_funding_rates.fill_matching_handlers(topic, h),
         funding_rate,
@@ -862,15 +1135,37 @@ pub fn publish_funding_rate(topic: MStr<Topic>, funding_rate: &FundingRateUpdate
 /// Publishes greeks data to subscribers on a topic.
 pub fn publish_greeks(topic: MStr<Topic>, greeks: &GreeksData) {
     publish_typed(
+        topic,
         &GREEKS_HANDLERS,
         |bus, h| bus.router_greeks.fill_matching_handlers(topic, h),
         greeks,
     );
 }
 
+/// Publishes yield curve data to subscribers on a topic.
+pub fn publish_yield_curve(topic: MStr<Topic>, yield_curve: &YieldCurveData) {
+    publish_typed(
+        topic,
+        &YIELD_CURVE_HANDLERS,
+        |bus, h| bus.router_yield_curves.fill_matching_handlers(topic, h),
+        yield_curve,
+    );
+}
+
+/// Publishes an economic event to subscribers on a topic.
+pub fn publish_economic_event(topic: MStr<Topic>, event: &EconomicEvent) {
+    publish_typed(
+        topic,
+        &ECONOMIC_EVENT_HANDLERS,
+        |bus, h| bus.router_economic_events.fill_matching_handlers(topic, h),
+        event,
+    );
+}
+
 /// Publishes an account state to subscribers on a topic.
 pub fn publish_account_state(topic: MStr<Topic>, state: &AccountState) {
     publish_typed(
+        topic,
         &ACCOUNT_STATE_HANDLERS,
         |bus, h| bus.router_account_state.fill_matching_handlers(topic, h),
         state,
@@ -879,16 +1174,20 @@ pub fn publish_account_state(topic: MStr<Topic>, state: &AccountState) {
 
 /// Publishes an order event to subscribers on a topic.
 pub fn publish_order_event(topic: MStr<Topic>, event: &OrderEventAny) {
-    publish_typed(
+    publish_typed_then(
+        topic,
         &ORDER_EVENT_HANDLERS,
         |bus, h| bus.router_order_events.fill_matching_handlers(topic, h),
         event,
+        // Audited once the ACL has permitted the publish, before any handler runs
+        || audit_order_event(topic.as_str(), event),
     );
 }
 
 /// Publishes a position event to subscribers on a topic.
 pub fn publish_position_event(topic: MStr<Topic>, event: &PositionEvent) {
     publish_typed(
+        topic,
         &POSITION_EVENT_HANDLERS,
         |bus, h| bus.router_position_events.fill_matching_handlers(topic, h),
         event,
@@ -899,6 +1198,7 @@ pub fn publish_position_event(topic: MStr<Topic>, event: &PositionEvent) {
 #[cfg(feature = "defi")]
 pub fn publish_defi_block(topic: MStr<Topic>, block: &Block) {
     publish_typed(
+        topic,
         &DEFI_BLOCK_HANDLERS,
         |bus, h| bus.router_defi_blocks.fill_matching_handlers(topic, h),
         block,
@@ -909,6 +1209,7 @@ pub fn publish_defi_block(topic: MStr<Topic>, block: &Block) {
 #[cfg(feature = "defi")]
 pub fn publish_defi_pool(topic: MStr<Topic>, pool: &Pool) {
     publish_typed(
+        topic,
         &DEFI_POOL_HANDLERS,
         |bus, h| bus.router_defi_pools.fill_matching_handlers(topic, h),
         pool,
@@ -919,6 +1220,7 @@ pub fn publish_defi_pool(topic: MStr<Topic>, pool: &Pool) {
 #[cfg(feature = "defi")]
 pub fn publish_defi_swap(topic: MStr<Topic>, swap: &PoolSwap) {
     publish_typed(
+        topic,
         &DEFI_SWAP_HANDLERS,
         |bus, h| bus.router_defi_swaps.fill_matching_handlers(topic, h),
         swap,
@@ -929,6 +1231,7 @@ pub fn publish_defi_swap(topic: MStr<Topic>, swap: &PoolSwap) {
 #[cfg(feature = "defi")]
 pub fn publish_defi_liquidity(topic: MStr<Topic>, update: &PoolLiquidityUpdate) {
     publish_typed(
+        topic,
         &DEFI_LIQUIDITY_HANDLERS,
         |bus, h| bus.router_defi_liquidity.fill_matching_handlers(topic, h),
         update,
@@ -939,6 +1242,7 @@ pub fn publish_defi_liquidity(topic: MStr<Topic>, update: &PoolLiquidityUpdate)
 #[cfg(feature = "defi")]
 pub fn publish_defi_collect(topic: MStr<Topic>, collect: &PoolFeeCollect) {
     publish_typed(
+        topic,
         &DEFI_COLLECT_HANDLERS,
         |bus, h| bus.router_defi_collects.fill_matching_handlers(topic, h),
         collect,
@@ -949,12 +1253,21 @@ pub fn publish_defi_collect(topic: MStr<Topic>, collect: &PoolFeeCollect) {
 #[cfg(feature = "defi")]
 pub fn publish_defi_flash(topic: MStr<Topic>, flash: &PoolFlash) {
     publish_typed(
+        topic,
         &DEFI_FLASH_HANDLERS,
         |bus, h| bus.router_defi_flash.fill_matching_handlers(topic, h),
         flash,
     );
 }
 
+/// Invokes a handler for `key`, recording the delivery and profiling it when enabled.
+#[inline]
+fn dispatch<R>(handler_id: Ustr, key: Ustr, f: impl FnOnce() -> R) -> R {
+    recorder::record(handler_id, key);
+    // Handlers run outside the publisher's principal scope
+    scoped_principal(None, || profiled(handler_id, key, f))
+}
+
 /// Publishes a message to typed handlers using thread-local buffer reuse.
 ///
 /// The `fill_fn` receives a mutable reference to the MessageBus, avoiding
@@ -966,10 +1279,28 @@ pub fn publish_defi_flash(topic: MStr<Topic>, flash: &PoolFlash) {
 /// - Handler panics drop the buffer, losing reuse optimization (acceptable as panics are fatal).
 #[inline]
 fn publish_typed<T: 'static>(
+    topic: MStr<Topic>,
     tls: &'static LocalKey<RefCell<SmallVec<[TypedHandler<T>; HANDLER_BUFFER_CAP]>>>,
     fill_fn: impl FnOnce(&mut MessageBus, &mut SmallVec<[TypedHandler<T>; HANDLER_BUFFER_CAP]>),
     message: &T,
 ) {
+    publish_typed_then(topic, tls, fill_fn, message, || {});
+}
+
+/// Publishes like [`publish_typed`], calling `before_dispatch` once the ACL has permitted the
+/// publish and the bus borrow has ended, before any handler runs.
+#[inline]
+fn publish_typed_then<T: 'static>(
+    topic: MStr<Topic>,
+    tls: &'static LocalKey<RefCell<SmallVec<[TypedHandler<T>; HANDLER_BUFFER_CAP]>>>,
+    fill_fn: impl FnOnce(&mut MessageBus, &mut SmallVec<[TypedHandler<T>; HANDLER_BUFFER_CAP]>),
+    message: &T,
+    before_dispatch: impl FnOnce(),
+) {
+    if !acl_permits(AclPermission::Publish, topic.as_str()) {
+        return;
+    }
+
     // SAFETY: Take buffer (re-entrancy safe)
     let mut handlers = tls.with_borrow_mut(std::mem::take);
 
@@ -979,8 +1310,10 @@ fn publish_typed<T: 'static>(
         fill_fn(&mut rc.borrow_mut(), &mut handlers);
     });
 
+    before_dispatch();
+
     for handler in &handlers {
-        handler.handle(message);
+        dispatch(handler.id(), *topic, || handler.handle(message));
     }
 
     handlers.clear(); // Release refs before restore
@@ -992,7 +1325,7 @@ pub fn send_any(endpoint: MStr<Endpoint>, message: &dyn Any) {
     let handler = get_message_bus().borrow().get_endpoint(endpoint).cloned();
 
     if let Some(handler) = handler {
-        handler.0.handle(message);
+        dispatch(handler.0.id(), *endpoint, || handler.0.handle(message));
     } else {
         log::error!("send_any: no registered endpoint '{endpoint}'");
     }
@@ -1003,7 +1336,7 @@ pub fn send_any_value<T: 'static>(endpoint: MStr<Endpoint>, message: T) {
     let handler = get_message_bus().borrow().get_endpoint(endpoint).cloned();
 
     if let Some(handler) = handler {
-        handler.0.handle(&message);
+        dispatch(handler.0.id(), *endpoint, || handler.0.handle(&message));
     } else {
         log::error!("send_any_value: no registered endpoint '{endpoint}'");
     }
@@ -1064,6 +1397,7 @@ pub fn send_bar(endpoint: MStr<Endpoint>, bar: &Bar) {
 
 /// Sends an order event to an endpoint handler, transferring ownership.
 pub fn send_order_event(endpoint: MStr<Endpoint>, event: OrderEventAny) {
+    audit_order_event(endpoint.as_str(), &event);
     send_endpoint_owned(
         endpoint,
         event,
@@ -1084,6 +1418,7 @@ pub fn send_account_state(endpoint: MStr<Endpoint>, state: &AccountState) {
 
 /// Sends a trading command to an endpoint handler, transferring ownership.
 pub fn send_trading_command(endpoint: MStr<Endpoint>, command: TradingCommand) {
+    audit_trading_command(endpoint.as_str(), &command);
     send_endpoint_owned(
         endpoint,
         command,
@@ -1158,7 +1493,7 @@ fn send_endpoint_ref<T: 'static, F>(
     };
 
     if let Some(handler) = handler {
-        handler.handle(message);
+        dispatch(handler.id(), *endpoint, || handler.handle(message));
     } else {
         log::error!("{fn_name}: no registered endpoint '{endpoint}'");
     }
@@ -1179,7 +1514,7 @@ fn send_endpoint_owned<T: 'static, F>(
     };
 
     if let Some(handler) = handler {
-        handler.handle(message);
+        dispatch(handler.id(), *endpoint, || handler.handle(message));
     } else {
         log::error!("{fn_name}: no registered endpoint '{endpoint}'");
     }
@@ -1610,4 +1945,133 @@ mod tests {
 
         assert!(*topic_retrieved.borrow());
     }
+
+    #[rstest]
+    fn test_acl_enforced_on_publish_and_subscribe() {
+        let mut acl = TopicAcl::default();
+        acl.deny("S-001", AclPermission::Publish, "events.order.*");
+        acl.allow("S-001", AclPermission::Subscribe, "data.quotes.*");
+        set_acl(acl);
+
+        let received = Rc::new(RefCell::new(0));
+        let received_clone = received.clone();
+        let handler = ShareableMessageHandler::from_any(move |_: &dyn Any| {
+            *received_clone.borrow_mut() += 1;
+        });
+
+        assert!(subscribe_any_as("S-001", "data.trades.*".into(), handler.clone(), None).is_err());
+        assert!(subscribe_any_as("S-001", "*".into(), handler.clone(), None).is_err());
+        subscribe_any("events.order.*".into(), handler, None);
+
+        assert!(publish_any_as("S-001", "events.order.S-001".into(), &1_u8).is_err());
+        assert_eq!(*received.borrow(), 0);
+
+        assert!(publish_any_as("RiskEngine", "events.order.S-001".into(), &1_u8).is_ok());
+        assert_eq!(*received.borrow(), 1);
+
+        assert_eq!(acl_violations().len(), 3);
+        assert!(clear_acl().is_some());
+        assert!(check_publish("S-001", "events.order.S-001".into()).is_ok());
+        assert!(acl_violations().is_empty());
+    }
+
+    #[rstest]
+    fn test_acl_enforced_on_typed_functions_within_principal_scope() {
+        let mut acl = TopicAcl::default();
+        acl.deny("S-001", AclPermission::Subscribe, "data.quotes.*");
+        acl.deny("S-001", AclPermission::Publish, "data.quotes.*");
+        set_acl(acl);
+
+        let received = Rc::new(RefCell::new(0));
+        let received_clone = received.clone();
+        let handler = TypedHandler::from(move |_: &QuoteTick| {
+            *received_clone.borrow_mut() += 1;
+        });
+
+        // Broader patterns overlapping the denied pattern are denied as well
+        with_principal("S-001", || {
+            assert_eq!(current_principal(), Some(Ustr::from("S-001")));
+            subscribe_quotes("data.*".into(), handler.clone(), None);
+            subscribe_quotes("*".into(), handler.clone(), None);
+        });
+        assert_eq!(current_principal(), None);
+        assert_eq!(acl_violations().len(), 2);
+
+        let topic: MStr<Topic> = "data.quotes.SIM.AUDUSD".into();
+        publish_quote(topic, &QuoteTick::default());
+        assert_eq!(*received.borrow(), 0);
+
+        // Unattributed calls are made by system components and are not checked
+        subscribe_quotes("data.*".into(), handler, None);
+        with_principal("S-001", || publish_quote(topic, &QuoteTick::default()));
+        assert_eq!(*received.borrow(), 0);
+        assert_eq!(acl_violations().len(), 3);
+
+        publish_quote(topic, &QuoteTick::default());
+        assert_eq!(*received.borrow(), 1);
+
+        assert!(clear_acl().is_some());
+    }
+
+    #[rstest]
+    fn test_acl_principal_cleared_for_nested_publish() {
+        let mut acl = TopicAcl::default();
+        acl.allow("S-001", AclPermission::Publish, "signals.*");
+        set_acl(acl);
+
+        let received = Rc::new(RefCell::new(0));
+        let received_clone = received.clone();
+        subscribe_any(
+            "data.custom".into(),
+            ShareableMessageHandler::from_any(move |_: &dyn Any| {
+                *received_clone.borrow_mut() += 1;
+            }),
+            None,
+        );
+        subscribe_any(
+            "signals.*".into(),
+            ShareableMessageHandler::from_any(|_: &dyn Any| {
+                assert_eq!(current_principal(), None);
+                publish_any("data.custom".into(), &1_u8);
+            }),
+            None,
+        );
+
+        assert!(publish_any_as("S-001", "signals.S-001".into(), &1_u8).is_ok());
+        with_principal("S-001", || {
+            publish_any("signals.S-001".into(), &1_u8);
+            assert_eq!(current_principal(), Some(Ustr::from("S-001")));
+        });
+
+        assert_eq!(*received.borrow(), 2);
+        assert!(acl_violations().is_empty());
+        assert!(clear_acl().is_some());
+    }
+
+    #[rstest]
+    fn test_publish_before_dispatch_runs_outside_bus_borrow() {
+        let _msgbus = get_message_bus();
+        let received = Rc::new(RefCell::new(0));
+        let received_clone = received.clone();
+        subscribe_quotes(
+            "data.quotes.*".into(),
+            TypedHandler::from(move |_: &QuoteTick| *received_clone.borrow_mut() += 1),
+            None,
+        );
+
+        let topic: MStr<Topic> = "data.quotes.TEST".into();
+        publish_typed_then(
+            topic,
+            &QUOTE_HANDLERS,
+            |bus, h| bus.router_quotes.fill_matching_handlers(topic, h),
+            &QuoteTick::default(),
+            || {
+                // As the order event audit does, touches the bus before handlers run
+                assert!(get_message_bus().try_borrow_mut().is_ok());
+                assert_eq!(*received.borrow(), 0);
+            },
+        );
+
+        assert_eq!(*received.borrow(), 1);
+    }
 }
@@ -96,8 +96,8 @@ use indexmap::IndexMap;
 use nautilus_core::{UUID4, correctness::FAILED};
 use nautilus_model::{
     data::{
-        Bar, Data, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
-        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        Bar, Data, EconomicEvent, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick, YieldCurveData,
     },
     events::{AccountState, OrderEventAny, PositionEvent},
     identifiers::TraderId,
@@ -110,6 +110,7 @@ use ustr::Ustr;
 
 use super::{
     ShareableMessageHandler,
+    acl::TopicAcl,
     matching::is_matching_backtracking,
     mstr::{Endpoint, MStr, Pattern, Topic},
     set_message_bus,
@@ -220,6 +221,7 @@ pub struct MessageBus {
     /// If the message bus is backed by a database.
     pub has_backing: bool,
     pub(crate) switchboard: MessagingSwitchboard,
+    pub(crate) acl: Option<TopicAcl>,
     pub(crate) subscriptions: AHashSet<Subscription>,
     pub(crate) topics: IndexMap<MStr<Topic>, Vec<Subscription>>,
     pub(crate) endpoints: IndexMap<MStr<Endpoint>, ShareableMessageHandler>,
@@ -239,6 +241,8 @@ pub struct MessageBus {
     pub(crate) router_orders: TopicRouter<OrderAny>,
     pub(crate) router_positions: TopicRouter<Position>,
     pub(crate) router_greeks: TopicRouter<GreeksData>,
+    pub(crate) router_yield_curves: TopicRouter<YieldCurveData>,
+    pub(crate) router_economic_events: TopicRouter<EconomicEvent>,
     #[cfg(feature = "defi")]
     pub(crate) router_defi_blocks: TopicRouter<nautilus_model::defi::Block>, // nautilus-import-ok
     #[cfg(feature = "defi")]
@@ -288,6 +292,7 @@ impl MessageBus {
             instance_id,
             name: name.unwrap_or(stringify!(MessageBus).to_owned()),
             switchboard: MessagingSwitchboard::default(),
+            acl: None,
             subscriptions: AHashSet::new(),
             topics: IndexMap::new(),
             endpoints: IndexMap::new(),
@@ -308,6 +313,8 @@ impl MessageBus {
             router_orders: TopicRouter::new(),
             router_positions: TopicRouter::new(),
             router_greeks: TopicRouter::new(),
+            router_yield_curves: TopicRouter::new(),
+            router_economic_events: TopicRouter::new(),
             #[cfg(feature = "defi")]
             router_defi_blocks: TopicRouter::new(),
             #[cfg(feature = "defi")]
@@ -382,6 +389,22 @@ impl MessageBus {
         &self.switchboard
     }
 
+    /// Returns a reference to the topic ACL (if enabled).
+    #[must_use]
+    pub const fn acl(&self) -> Option<&TopicAcl> {
+        self.acl.as_ref()
+    }
+
+    /// Enables topic access control with the given `acl`, replacing any existing ACL.
+    pub fn set_acl(&mut self, acl: TopicAcl) {
+        self.acl = Some(acl);
+    }
+
+    /// Disables topic access control, returning the previous ACL (if any).
+    pub fn clear_acl(&mut self) -> Option<TopicAcl> {
+        self.acl.take()
+    }
+
     /// Returns the registered endpoint addresses.
     #[must_use]
     pub fn endpoints(&self) -> Vec<&str> {
@@ -19,6 +19,7 @@ use nautilus_model::identifiers::TraderId;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
+use super::signing::FrameSigningConfig;
 use crate::enums::SerializationEncoding;
 
 /// Configuration for database connections.
@@ -112,6 +113,8 @@ pub struct MessageBusConfig {
     pub types_filter: Option<Vec<String>>,
     /// The heartbeat interval (seconds).
     pub heartbeat_interval_secs: Option<u16>,
+    /// The configuration for signing external stream frames (and verifying them on ingest).
+    pub frame_signing: Option<FrameSigningConfig>,
 }
 
 impl Default for MessageBusConfig {
@@ -131,6 +134,7 @@ impl Default for MessageBusConfig {
             external_streams: None,
             types_filter: None,
             heartbeat_interval_secs: None,
+            frame_signing: None,
         }
     }
 }
@@ -226,6 +230,7 @@ mod tests {
         assert!(config.stream_per_topic);
         assert_eq!(config.external_streams, None);
         assert_eq!(config.types_filter, None);
+        assert_eq!(config.frame_signing, None);
     }
 
     #[rstest]
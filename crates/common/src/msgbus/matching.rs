@@ -76,6 +76,75 @@ fn is_matching_greedy(topic: &[u8], pattern: &[u8]) -> bool {
     j == pattern.len()
 }
 
+/// Returns whether some topic exists which is matched by both patterns.
+///
+/// Used to detect subscriptions which would receive messages on a denied topic even though
+/// the subscription pattern itself is broader (e.g. `data.*` overlaps `data.quotes.*`).
+///
+/// Bottom-up DP over pattern suffixes, O(n*m) time and O(m) space.
+#[must_use]
+pub fn patterns_overlap(a: &[u8], b: &[u8]) -> bool {
+    let (n, m) = (a.len(), b.len());
+
+    // next[j] holds whether a[i + 1..] overlaps b[j..]
+    let mut next = vec![false; m + 1];
+    let mut curr = vec![false; m + 1];
+
+    for i in (0..=n).rev() {
+        for j in (0..=m).rev() {
+            curr[j] = if i == n && j == m {
+                true
+            } else if i < n && a[i] == b'*' {
+                // Star matches nothing, or absorbs one character of b
+                next[j] || (j < m && curr[j + 1])
+            } else if j < m && b[j] == b'*' {
+                curr[j + 1] || (i < n && next[j])
+            } else if i < n && j < m {
+                (a[i] == b'?' || b[j] == b'?' || a[i] == b[j]) && next[j + 1]
+            } else {
+                false
+            };
+        }
+        std::mem::swap(&mut next, &mut curr);
+    }
+
+    next[0]
+}
+
+/// Returns whether every topic matched by pattern `b` is also matched by pattern `a`.
+///
+/// Unlike [`is_matching`], wildcards in `b` are not treated as literal characters: a `?` in
+/// `a` covers a single character or `?` in `b` but never a `*`, which may match any number of
+/// characters. The check is conservative, so may reject some contained patterns.
+///
+/// Bottom-up DP over pattern suffixes, O(n*m) time and O(m) space.
+#[must_use]
+pub fn pattern_contains(a: &[u8], b: &[u8]) -> bool {
+    let (n, m) = (a.len(), b.len());
+
+    // next[j] holds whether a[i + 1..] contains b[j..]
+    let mut next = vec![false; m + 1];
+    let mut curr = vec![false; m + 1];
+
+    for i in (0..=n).rev() {
+        for j in (0..=m).rev() {
+            curr[j] = if i == n {
+                j == m
+            } else if a[i] == b'*' {
+                // Star matches nothing, or absorbs one character or wildcard of b
+                next[j] || (j < m && curr[j + 1])
+            } else if j == m || b[j] == b'*' {
+                false
+            } else {
+                (a[i] == b'?' || (b[j] != b'?' && a[i] == b[j])) && next[j + 1]
+            };
+        }
+        std::mem::swap(&mut next, &mut curr);
+    }
+
+    next[0]
+}
+
 #[cfg(test)]
 mod tests {
     use rand::{Rng, SeedableRng, rngs::StdRng};
@@ -207,4 +276,40 @@ mod tests {
             );
         }
     }
+
+    #[rstest]
+    #[case("data.quotes.*", "data.quotes.*", true)]
+    #[case("data.*", "data.quotes.*", true)]
+    #[case("*", "data.quotes.*", true)]
+    #[case("data.quotes.BINANCE.*", "data.quotes.*", true)]
+    #[case("data.*.BINANCE", "data.quotes.*", true)]
+    #[case("data.trades.*", "data.quotes.*", false)]
+    #[case("events.*", "data.*", false)]
+    #[case("data.quotes.???", "data.quotes.BINANCE", false)]
+    #[case("data.quotes.???????", "data.quotes.BINANCE", true)]
+    #[case("data.quotes.BINANCE", "data.quotes.BINANCE", true)]
+    #[case("data.quotes.BINANCE", "data.quotes.BYBIT", false)]
+    #[case("", "*", true)]
+    #[case("", "?", false)]
+    fn test_patterns_overlap(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        assert_eq!(patterns_overlap(a.as_bytes(), b.as_bytes()), expected);
+        assert_eq!(patterns_overlap(b.as_bytes(), a.as_bytes()), expected);
+    }
+
+    #[rstest]
+    #[case("data.quotes.*", "data.quotes.*", true)]
+    #[case("data.quotes.*", "data.quotes.BINANCE.*", true)]
+    #[case("data.quotes.*", "data.*", false)]
+    #[case("data.*", "data.quotes.???", true)]
+    #[case("data.?", "data.?", true)]
+    #[case("data.?", "data.a", true)]
+    #[case("data.?", "data.*", false)]
+    #[case("data.??", "data.?*", false)]
+    #[case("data.a", "data.?", false)]
+    #[case("*", "*", true)]
+    #[case("", "", true)]
+    #[case("", "*", false)]
+    fn test_pattern_contains(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        assert_eq!(pattern_contains(a.as_bytes(), b.as_bytes()), expected);
+    }
 }
@@ -36,12 +36,17 @@
 //!
 //! See [`core`] module documentation for design decisions and performance details.
 
+pub mod acl;
 mod api;
 pub mod core;
 pub mod database;
 pub mod matching;
 pub mod message;
 pub mod mstr;
+pub mod profiling;
+pub mod recorder;
+pub mod serializer;
+pub mod signing;
 pub mod stubs;
 pub mod switchboard;
 pub mod typed_endpoints;
@@ -49,7 +54,7 @@ pub mod typed_handler;
 pub mod typed_router;
 
 use std::{
-    cell::{OnceCell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     rc::Rc,
 };
 
@@ -57,19 +62,32 @@ use std::{
 use nautilus_model::defi::{Block, Pool, PoolFeeCollect, PoolFlash, PoolLiquidityUpdate, PoolSwap};
 use nautilus_model::{
     data::{
-        Bar, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate, OrderBookDeltas,
-        OrderBookDepth10, QuoteTick, TradeTick,
+        Bar, EconomicEvent, FundingRateUpdate, GreeksData, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick, YieldCurveData,
     },
     events::{AccountState, OrderEventAny, PositionEvent},
     orderbook::OrderBook,
 };
 use smallvec::SmallVec;
+use ustr::Ustr;
 
 pub use self::{
+    acl::{AclPermission, AclViolation, TopicAcl},
     api::*,
     core::{MessageBus, Subscription},
     message::BusMessage,
     mstr::{Endpoint, MStr, Pattern, Topic},
+    profiling::{
+        HandlerProfile, disable_handler_profiling, enable_handler_profiling,
+        handler_profile_report, is_handler_profiling_enabled, log_handler_profile_report,
+        reset_handler_profiling,
+    },
+    recorder::{
+        RecordedMessage, disable_message_recorder, enable_message_recorder,
+        is_message_recorder_enabled, recent_messages,
+    },
+    serializer::{MessageSerializer, PROTOBUF_SCHEMA, SchemaRegistry, serializer_for},
+    signing::{FrameSigner, FrameSigningConfig, FrameVerifier},
     switchboard::MessagingSwitchboard,
     typed_endpoints::{EndpointMap, IntoEndpointMap},
     typed_handler::{
@@ -93,6 +111,9 @@ pub(super) const HANDLER_BUFFER_CAP: usize = 64;
 thread_local! {
     pub(super) static MESSAGE_BUS: OnceCell<Rc<RefCell<MessageBus>>> = const { OnceCell::new() };
 
+    // Principal on whose behalf unattributed subscribe and publish calls are made (for the ACL)
+    pub(super) static ACL_PRINCIPAL: Cell<Option<Ustr>> = const { Cell::new(None) };
+
     pub(super) static ANY_HANDLERS: RefCell<SmallVec<[ShareableMessageHandler; HANDLER_BUFFER_CAP]>> =
         RefCell::new(SmallVec::new());
 
@@ -116,6 +137,10 @@ thread_local! {
         RefCell::new(SmallVec::new());
     pub(super) static GREEKS_HANDLERS: RefCell<SmallVec<[TypedHandler<GreeksData>; HANDLER_BUFFER_CAP]>> =
         RefCell::new(SmallVec::new());
+    pub(super) static YIELD_CURVE_HANDLERS: RefCell<SmallVec<[TypedHandler<YieldCurveData>; HANDLER_BUFFER_CAP]>> =
+        RefCell::new(SmallVec::new());
+    pub(super) static ECONOMIC_EVENT_HANDLERS: RefCell<SmallVec<[TypedHandler<EconomicEvent>; HANDLER_BUFFER_CAP]>> =
+        RefCell::new(SmallVec::new());
     pub(super) static ACCOUNT_STATE_HANDLERS: RefCell<SmallVec<[TypedHandler<AccountState>; HANDLER_BUFFER_CAP]>> =
         RefCell::new(SmallVec::new());
     pub(super) static ORDER_EVENT_HANDLERS: RefCell<SmallVec<[TypedHandler<OrderEventAny>; HANDLER_BUFFER_CAP]>> =
@@ -0,0 +1,159 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Opt-in profiling of message handler invocations.
+//!
+//! When enabled, every handler invoked by a publish or send is timed and the duration recorded
+//! in a [`LatencyHistogram`] keyed by handler ID and topic (or endpoint). Reports of percentile
+//! durations can be taken on demand to find slow callbacks.
+//!
+//! Profiling state is thread-local, matching the message bus. When disabled the overhead is a
+//! single thread-local flag check per handler invocation.
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Write,
+    time::Instant,
+};
+
+use ahash::AHashMap;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::histogram::{HistogramSummary, LatencyHistogram};
+
+thread_local! {
+    static PROFILING_ENABLED: Cell<bool> = const { Cell::new(false) };
+    static PROFILES: RefCell<AHashMap<(Ustr, Ustr), LatencyHistogram>> =
+        RefCell::new(AHashMap::new());
+}
+
+/// The handler invocation durations (nanoseconds) for a handler on a topic or endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandlerProfile {
+    pub handler_id: Ustr,
+    /// The topic published to, or endpoint sent to.
+    pub key: Ustr,
+    pub summary: HistogramSummary,
+}
+
+/// Enables handler profiling on the current thread.
+pub fn enable_handler_profiling() {
+    PROFILING_ENABLED.set(true);
+}
+
+/// Disables handler profiling on the current thread, retaining recorded durations.
+pub fn disable_handler_profiling() {
+    PROFILING_ENABLED.set(false);
+}
+
+/// Returns whether handler profiling is enabled on the current thread.
+#[must_use]
+pub fn is_handler_profiling_enabled() -> bool {
+    PROFILING_ENABLED.get()
+}
+
+/// Clears all recorded handler durations on the current thread.
+pub fn reset_handler_profiling() {
+    PROFILES.with_borrow_mut(|p| p.clear());
+}
+
+/// Returns the recorded handler profiles, slowest (by p99) first.
+#[must_use]
+pub fn handler_profile_report() -> Vec<HandlerProfile> {
+    let mut report: Vec<HandlerProfile> = PROFILES.with_borrow(|profiles| {
+        profiles
+            .iter()
+            .map(|((handler_id, key), histogram)| HandlerProfile {
+                handler_id: *handler_id,
+                key: *key,
+                summary: histogram.summary(),
+            })
+            .collect()
+    });
+    report.sort_by(|a, b| {
+        b.summary
+            .p99
+            .cmp(&a.summary.p99)
+            .then_with(|| a.handler_id.cmp(&b.handler_id))
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    report
+}
+
+/// Logs the handler profile report at info level, limited to the `top` slowest handlers.
+pub fn log_handler_profile_report(top: usize) {
+    let report = handler_profile_report();
+    let mut output = format!("Handler profile ({} handler(s)):", report.len());
+    for profile in report.iter().take(top) {
+        let s = &profile.summary;
+        let _ = write!(
+            output,
+            "\n  {} [{}] count={} mean={}ns p50={}ns p90={}ns p99={}ns max={}ns",
+            profile.handler_id, profile.key, s.count, s.mean, s.p50, s.p90, s.p99, s.max,
+        );
+    }
+    log::info!("{output}");
+}
+
+/// Invokes `f`, recording its duration against `handler_id` and `key` if profiling is enabled.
+#[inline]
+pub(super) fn profiled<R>(handler_id: Ustr, key: Ustr, f: impl FnOnce() -> R) -> R {
+    if !PROFILING_ENABLED.get() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+    PROFILES.with_borrow_mut(|profiles| {
+        profiles
+            .entry((handler_id, key))
+            .or_default()
+            .record(elapsed);
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_profiled_records_only_when_enabled() {
+        reset_handler_profiling();
+        let handler_id = Ustr::from("handler");
+        let key = Ustr::from("data.quotes.SIM");
+
+        profiled(handler_id, key, || {});
+        assert!(handler_profile_report().is_empty());
+
+        enable_handler_profiling();
+        let result = profiled(handler_id, key, || 42);
+        profiled(handler_id, key, || {
+            std::thread::sleep(std::time::Duration::from_millis(1))
+        });
+        disable_handler_profiling();
+
+        let report = handler_profile_report();
+        assert_eq!(result, 42);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].handler_id, handler_id);
+        assert_eq!(report[0].summary.count, 2);
+        assert!(report[0].summary.max >= 1_000_000);
+    }
+}
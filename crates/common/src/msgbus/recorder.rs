@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Opt-in ring buffer of recently dispatched message bus deliveries.
+//!
+//! When enabled, every handler invocation made by a publish or send is recorded with its
+//! handler ID, topic (or endpoint) and a wall-clock timestamp. Only the most recent deliveries
+//! are retained, making the buffer suitable for attaching to crash reports without unbounded
+//! memory growth.
+//!
+//! Recorder state is thread-local, matching the message bus.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+};
+
+use nautilus_core::{UnixNanos, time::get_atomic_clock_realtime};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+thread_local! {
+    static RECORDER_CAPACITY: Cell<usize> = const { Cell::new(0) };
+    static RECORDED: RefCell<VecDeque<RecordedMessage>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// A single message delivery to a handler.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedMessage {
+    pub handler_id: Ustr,
+    /// The topic published to, or endpoint sent to.
+    pub key: Ustr,
+    pub ts: UnixNanos,
+}
+
+/// Enables the message recorder on the current thread, retaining the last `capacity` deliveries.
+///
+/// A `capacity` of zero disables recording.
+pub fn enable_message_recorder(capacity: usize) {
+    RECORDER_CAPACITY.set(capacity);
+    RECORDED.with_borrow_mut(|recorded| {
+        while recorded.len() > capacity {
+            recorded.pop_front();
+        }
+    });
+}
+
+/// Disables the message recorder on the current thread and clears recorded deliveries.
+pub fn disable_message_recorder() {
+    RECORDER_CAPACITY.set(0);
+    RECORDED.with_borrow_mut(VecDeque::clear);
+}
+
+/// Returns whether the message recorder is enabled on the current thread.
+#[must_use]
+pub fn is_message_recorder_enabled() -> bool {
+    RECORDER_CAPACITY.get() > 0
+}
+
+/// Returns up to the last `n` recorded deliveries on the current thread, oldest first.
+#[must_use]
+pub fn recent_messages(n: usize) -> Vec<RecordedMessage> {
+    RECORDED.with_borrow(|recorded| {
+        let skip = recorded.len().saturating_sub(n);
+        recorded.iter().skip(skip).cloned().collect()
+    })
+}
+
+/// Records a delivery of `key` to `handler_id` if the recorder is enabled.
+#[inline]
+pub(super) fn record(handler_id: Ustr, key: Ustr) {
+    let capacity = RECORDER_CAPACITY.get();
+    if capacity == 0 {
+        return;
+    }
+
+    let ts = get_atomic_clock_realtime().get_time_ns();
+    RECORDED.with_borrow_mut(|recorded| {
+        if recorded.len() >= capacity {
+            recorded.pop_front();
+        }
+        recorded.push_back(RecordedMessage {
+            handler_id,
+            key,
+            ts,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_recorder_retains_most_recent_deliveries() {
+        let handler_id = Ustr::from("handler");
+
+        record(handler_id, Ustr::from("ignored"));
+        assert!(recent_messages(10).is_empty());
+
+        enable_message_recorder(2);
+        record(handler_id, Ustr::from("a"));
+        record(handler_id, Ustr::from("b"));
+        record(handler_id, Ustr::from("c"));
+
+        let keys: Vec<Ustr> = recent_messages(10).iter().map(|m| m.key).collect();
+        assert_eq!(keys, vec![Ustr::from("b"), Ustr::from("c")]);
+        assert_eq!(recent_messages(1)[0].key, Ustr::from("c"));
+
+        disable_message_recorder();
+        assert!(!is_message_recorder_enabled());
+        assert!(recent_messages(10).is_empty());
+    }
+}
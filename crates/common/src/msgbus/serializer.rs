@@ -0,0 +1,667 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pluggable serializers for external message streaming.
+//!
+//! A [`MessageSerializer`] encodes message payloads for external streams in a given
+//! [`SerializationEncoding`]. The [`SchemaRegistry`] maps topic patterns to message type names
+//! so consumers in other languages know how to decode each stream.
+//!
+//! The Protobuf serializer encodes messages as the dynamic `nexuscore.msgbus.Value` type (see
+//! [`PROTOBUF_SCHEMA`]), so consumers compile a single schema rather than one per message type.
+
+use std::fmt::Debug;
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Number, Value};
+use ustr::Ustr;
+
+use super::{
+    matching::is_matching,
+    mstr::{MStr, Pattern},
+};
+use crate::enums::SerializationEncoding;
+
+/// Encodes and decodes message payloads for external streams.
+pub trait MessageSerializer: Debug {
+    /// Returns the encoding of the serializer.
+    fn encoding(&self) -> SerializationEncoding;
+
+    /// Returns the MIME content type of encoded payloads.
+    fn content_type(&self) -> &'static str;
+
+    /// Encodes the `value` to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails.
+    fn encode_value(&self, value: &Value) -> anyhow::Result<Bytes>;
+
+    /// Decodes a value from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid payload for this encoding.
+    fn decode_value(&self, bytes: &[u8]) -> anyhow::Result<Value>;
+}
+
+impl dyn MessageSerializer {
+    /// Serializes `message` to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn serialize<T: Serialize>(&self, message: &T) -> anyhow::Result<Bytes> {
+        self.encode_value(&serde_json::to_value(message)?)
+    }
+
+    /// Deserializes a message from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserialization fails.
+    pub fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> anyhow::Result<T> {
+        // Round trip through JSON text, as `from_value` cannot hand out the borrowed strings
+        // which identifier types deserialize from
+        let json = serde_json::to_vec(&self.decode_value(bytes)?)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+/// The Protobuf schema of the payloads produced by [`ProtobufMessageSerializer`].
+///
+/// The layout mirrors `google.protobuf.Value` with additional `int_value` and `uint_value` cases,
+/// so integers (such as `UnixNanos` timestamps) are carried exactly rather than as `double`.
+pub const PROTOBUF_SCHEMA: &str = r#"syntax = "proto3";
+
+package nexuscore.msgbus;
+
+enum NullValue {
+  NULL_VALUE = 0;
+}
+
+message Value {
+  oneof kind {
+    NullValue null_value = 1;
+    double number_value = 2;
+    string string_value = 3;
+    bool bool_value = 4;
+    Struct struct_value = 5;
+    ListValue list_value = 6;
+    sint64 int_value = 7;
+    uint64 uint_value = 8;
+  }
+}
+
+message Struct {
+  map<string, Value> fields = 1;
+}
+
+message ListValue {
+  repeated Value values = 1;
+}
+"#;
+
+/// Returns the [`MessageSerializer`] for the given `encoding`.
+#[must_use]
+pub fn serializer_for(encoding: SerializationEncoding) -> Box<dyn MessageSerializer> {
+    match encoding {
+        SerializationEncoding::MsgPack => Box::new(MsgPackMessageSerializer),
+        SerializationEncoding::Json => Box::new(JsonMessageSerializer),
+        SerializationEncoding::Protobuf => Box::new(ProtobufMessageSerializer),
+    }
+}
+
+/// A JSON [`MessageSerializer`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonMessageSerializer;
+
+impl MessageSerializer for JsonMessageSerializer {
+    fn encoding(&self) -> SerializationEncoding {
+        SerializationEncoding::Json
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn encode_value(&self, value: &Value) -> anyhow::Result<Bytes> {
+        Ok(serde_json::to_vec(value).map(Bytes::from)?)
+    }
+
+    fn decode_value(&self, bytes: &[u8]) -> anyhow::Result<Value> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// A MessagePack [`MessageSerializer`] (maps are encoded with named fields).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MsgPackMessageSerializer;
+
+impl MessageSerializer for MsgPackMessageSerializer {
+    fn encoding(&self) -> SerializationEncoding {
+        SerializationEncoding::MsgPack
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/msgpack"
+    }
+
+    fn encode_value(&self, value: &Value) -> anyhow::Result<Bytes> {
+        Ok(rmp_serde::to_vec_named(value).map(Bytes::from)?)
+    }
+
+    fn decode_value(&self, bytes: &[u8]) -> anyhow::Result<Value> {
+        Ok(rmp_serde::from_slice(bytes)?)
+    }
+}
+
+/// A Protobuf [`MessageSerializer`] encoding messages as `nexuscore.msgbus.Value`.
+///
+/// Integers are carried as `int_value` (negative) or `uint_value` and all other numbers as
+/// `number_value`, so round trips are lossless for every `i64` and `u64`. See
+/// [`PROTOBUF_SCHEMA`] for the message definitions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtobufMessageSerializer;
+
+impl MessageSerializer for ProtobufMessageSerializer {
+    fn encoding(&self) -> SerializationEncoding {
+        SerializationEncoding::Protobuf
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/x-protobuf; messageType=nexuscore.msgbus.Value"
+    }
+
+    fn encode_value(&self, value: &Value) -> anyhow::Result<Bytes> {
+        let mut buf = Vec::new();
+        protobuf::encode_value(value, &mut buf);
+        Ok(Bytes::from(buf))
+    }
+
+    fn decode_value(&self, bytes: &[u8]) -> anyhow::Result<Value> {
+        protobuf::decode_value(bytes)
+    }
+}
+
+/// Protobuf wire format primitives, shared by the Protobuf serializer and other hand-rolled
+/// Protobuf codecs in the crate (such as the catalog Flight service).
+pub(crate) mod wire {
+    pub(crate) const WIRE_VARINT: u8 = 0;
+    pub(crate) const WIRE_FIXED64: u8 = 1;
+    pub(crate) const WIRE_LEN: u8 = 2;
+    pub(crate) const WIRE_FIXED32: u8 = 5;
+
+    pub(crate) fn put_varint(mut value: u64, buf: &mut Vec<u8>) {
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    pub(crate) fn put_tag(field: u32, wire_type: u8, buf: &mut Vec<u8>) {
+        put_varint(u64::from((field << 3) | u32::from(wire_type)), buf);
+    }
+
+    pub(crate) fn put_len_delimited(field: u32, bytes: &[u8], buf: &mut Vec<u8>) {
+        put_tag(field, WIRE_LEN, buf);
+        put_varint(bytes.len() as u64, buf);
+        buf.extend_from_slice(bytes);
+    }
+
+    pub(crate) struct Reader<'a> {
+        buf: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Reader<'a> {
+        pub(crate) const fn new(buf: &'a [u8]) -> Self {
+            Self { buf, pos: 0 }
+        }
+
+        pub(crate) const fn is_empty(&self) -> bool {
+            self.pos >= self.buf.len()
+        }
+
+        pub(crate) fn varint(&mut self) -> anyhow::Result<u64> {
+            let mut value = 0u64;
+            for shift in (0..64).step_by(7) {
+                let byte = *self
+                    .buf
+                    .get(self.pos)
+                    .ok_or_else(|| anyhow::anyhow!("Truncated protobuf varint"))?;
+                self.pos += 1;
+                value |= u64::from(byte & 0x7F) << shift;
+                if byte & 0x80 == 0 {
+                    return Ok(value);
+                }
+            }
+            anyhow::bail!("Invalid protobuf varint")
+        }
+
+        pub(crate) fn tag(&mut self) -> anyhow::Result<(u32, u8)> {
+            let tag = self.varint()?;
+            Ok(((tag >> 3) as u32, (tag & 0x07) as u8))
+        }
+
+        pub(crate) fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+            let end = self
+                .pos
+                .checked_add(len)
+                .filter(|end| *end <= self.buf.len())
+                .ok_or_else(|| anyhow::anyhow!("Truncated protobuf field"))?;
+            let bytes = &self.buf[self.pos..end];
+            self.pos = end;
+            Ok(bytes)
+        }
+
+        pub(crate) fn len_delimited(&mut self) -> anyhow::Result<&'a [u8]> {
+            let len = usize::try_from(self.varint()?)?;
+            self.take(len)
+        }
+
+        pub(crate) fn skip(&mut self, wire_type: u8) -> anyhow::Result<()> {
+            match wire_type {
+                WIRE_VARINT => self.varint().map(|_| ()),
+                WIRE_FIXED64 => self.take(8).map(|_| ()),
+                WIRE_LEN => self.len_delimited().map(|_| ()),
+                WIRE_FIXED32 => self.take(4).map(|_| ()),
+                _ => anyhow::bail!("Unsupported protobuf wire type {wire_type}"),
+            }
+        }
+    }
+}
+
+/// Minimal wire format codec for the `nexuscore.msgbus.Value` message type.
+mod protobuf {
+    use super::{
+        Map, Number, Value,
+        wire::{
+            Reader, WIRE_FIXED64, WIRE_LEN, WIRE_VARINT, put_len_delimited, put_tag, put_varint,
+        },
+    };
+
+    // nexuscore.msgbus.Value field numbers
+    const NULL_VALUE: u32 = 1;
+    const NUMBER_VALUE: u32 = 2;
+    const STRING_VALUE: u32 = 3;
+    const BOOL_VALUE: u32 = 4;
+    const STRUCT_VALUE: u32 = 5;
+    const LIST_VALUE: u32 = 6;
+    const INT_VALUE: u32 = 7;
+    const UINT_VALUE: u32 = 8;
+
+    pub(super) fn encode_value(value: &Value, buf: &mut Vec<u8>) {
+        match value {
+            Value::Null => {
+                put_tag(NULL_VALUE, WIRE_VARINT, buf);
+                put_varint(0, buf);
+            }
+            Value::Bool(b) => {
+                put_tag(BOOL_VALUE, WIRE_VARINT, buf);
+                put_varint(u64::from(*b), buf);
+            }
+            Value::Number(n) => {
+                if let Some(value) = n.as_u64() {
+                    put_tag(UINT_VALUE, WIRE_VARINT, buf);
+                    put_varint(value, buf);
+                } else if let Some(value) = n.as_i64() {
+                    // sint64 (zigzag) encoding
+                    put_tag(INT_VALUE, WIRE_VARINT, buf);
+                    put_varint(((value << 1) ^ (value >> 63)) as u64, buf);
+                } else {
+                    put_tag(NUMBER_VALUE, WIRE_FIXED64, buf);
+                    buf.extend_from_slice(&n.as_f64().unwrap_or(f64::NAN).to_le_bytes());
+                }
+            }
+            Value::String(s) => put_len_delimited(STRING_VALUE, s.as_bytes(), buf),
+            Value::Array(values) => {
+                // ListValue { repeated Value values = 1; }
+                let mut list = Vec::new();
+                for value in values {
+                    let mut item = Vec::new();
+                    encode_value(value, &mut item);
+                    put_len_delimited(1, &item, &mut list);
+                }
+                put_len_delimited(LIST_VALUE, &list, buf);
+            }
+            Value::Object(fields) => {
+                // Struct { map<string, Value> fields = 1; }
+                let mut object = Vec::new();
+                for (key, value) in fields {
+                    let mut entry = Vec::new();
+                    put_len_delimited(1, key.as_bytes(), &mut entry);
+                    let mut item = Vec::new();
+                    encode_value(value, &mut item);
+                    put_len_delimited(2, &item, &mut entry);
+                    put_len_delimited(1, &entry, &mut object);
+                }
+                put_len_delimited(STRUCT_VALUE, &object, buf);
+            }
+        }
+    }
+
+    pub(super) fn decode_value(bytes: &[u8]) -> anyhow::Result<Value> {
+        let mut reader = Reader::new(bytes);
+        let mut value = Value::Null;
+
+        // Fields of a oneof are mutually exclusive, the last one on the wire wins
+        while !reader.is_empty() {
+            let (field, wire_type) = reader.tag()?;
+            value = match (field, wire_type) {
+                (INT_VALUE, WIRE_VARINT) => {
+                    let raw = reader.varint()?;
+                    Value::Number(Number::from((raw >> 1) as i64 ^ -((raw & 1) as i64)))
+                }
+                (UINT_VALUE, WIRE_VARINT) => Value::Number(Number::from(reader.varint()?)),
+                (NULL_VALUE, WIRE_VARINT) => {
+                    reader.varint()?;
+                    Value::Null
+                }
+                (NUMBER_VALUE, WIRE_FIXED64) => {
+                    let raw: [u8; 8] = reader.take(8)?.try_into()?;
+                    Number::from_f64(f64::from_le_bytes(raw)).map_or(Value::Null, Value::Number)
+                }
+                (STRING_VALUE, WIRE_LEN) => {
+                    Value::String(String::from_utf8(reader.len_delimited()?.to_vec())?)
+                }
+                (BOOL_VALUE, WIRE_VARINT) => Value::Bool(reader.varint()? != 0),
+                (STRUCT_VALUE, WIRE_LEN) => decode_struct(reader.len_delimited()?)?,
+                (LIST_VALUE, WIRE_LEN) => decode_list(reader.len_delimited()?)?,
+                _ => {
+                    reader.skip(wire_type)?;
+                    continue;
+                }
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn decode_list(bytes: &[u8]) -> anyhow::Result<Value> {
+        let mut reader = Reader::new(bytes);
+        let mut values = Vec::new();
+
+        while !reader.is_empty() {
+            match reader.tag()? {
+                (1, WIRE_LEN) => values.push(decode_value(reader.len_delimited()?)?),
+                (_, wire_type) => reader.skip(wire_type)?,
+            }
+        }
+
+        Ok(Value::Array(values))
+    }
+
+    fn decode_struct(bytes: &[u8]) -> anyhow::Result<Value> {
+        let mut reader = Reader::new(bytes);
+        let mut fields = Map::new();
+
+        while !reader.is_empty() {
+            match reader.tag()? {
+                (1, WIRE_LEN) => {
+                    let mut entry = Reader::new(reader.len_delimited()?);
+                    let mut key = String::new();
+                    let mut value = Value::Null;
+                    while !entry.is_empty() {
+                        match entry.tag()? {
+                            (1, WIRE_LEN) => {
+                                key = String::from_utf8(entry.len_delimited()?.to_vec())?;
+                            }
+                            (2, WIRE_LEN) => value = decode_value(entry.len_delimited()?)?,
+                            (_, wire_type) => entry.skip(wire_type)?,
+                        }
+                    }
+                    fields.insert(key, value);
+                }
+                (_, wire_type) => reader.skip(wire_type)?,
+            }
+        }
+
+        Ok(Value::Object(fields))
+    }
+}
+
+/// The message type schema for a topic pattern.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageSchema {
+    /// The topic pattern the schema applies to.
+    pub pattern: Ustr,
+    /// The fully qualified message type name (e.g. `OrderFilled`).
+    pub type_name: Ustr,
+    /// The schema version of the message type.
+    pub version: u32,
+}
+
+/// A registry of topic patterns to message types for external stream consumers.
+///
+/// Patterns are resolved in registration order, so more specific patterns should be registered
+/// before broader ones.
+#[derive(Clone, Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: IndexMap<MStr<Pattern>, MessageSchema>,
+}
+
+impl SchemaRegistry {
+    /// Creates a new empty [`SchemaRegistry`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the message `type_name` (at `version`) for topics matching `pattern`.
+    ///
+    /// Replaces any existing schema for the same pattern.
+    pub fn register<P: Into<MStr<Pattern>>>(&mut self, pattern: P, type_name: &str, version: u32) {
+        let pattern = pattern.into();
+        self.schemas.insert(
+            pattern,
+            MessageSchema {
+                pattern: Ustr::from(pattern.as_str()),
+                type_name: Ustr::from(type_name),
+                version,
+            },
+        );
+    }
+
+    /// Removes the schema registered for `pattern`.
+    pub fn deregister<P: Into<MStr<Pattern>>>(&mut self, pattern: P) -> Option<MessageSchema> {
+        self.schemas.shift_remove(&pattern.into())
+    }
+
+    /// Returns the schema for the first pattern matching `topic`.
+    #[must_use]
+    pub fn resolve(&self, topic: &str) -> Option<&MessageSchema> {
+        self.schemas
+            .iter()
+            .find(|(pattern, _)| is_matching(topic.as_bytes(), pattern.as_bytes()))
+            .map(|(_, schema)| schema)
+    }
+
+    /// Returns all registered schemas in registration order.
+    #[must_use]
+    pub fn schemas(&self) -> Vec<&MessageSchema> {
+        self.schemas.values().collect()
+    }
+
+    /// Returns the number of registered schemas.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    /// Returns whether the registry is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+
+    /// Exports the registry as a JSON document describing the stream encoding and schemas.
+    ///
+    /// External consumers use this to select the decoder for each stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self, serializer: &dyn MessageSerializer) -> anyhow::Result<String> {
+        let document = serde_json::json!({
+            "encoding": serializer.encoding(),
+            "content_type": serializer.content_type(),
+            "schemas": self.schemas(),
+        });
+        Ok(serde_json::to_string(&document)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TestMessage {
+        id: String,
+        qty: u64,
+        px: f64,
+        tags: Vec<String>,
+        reduce_only: bool,
+        parent: Option<String>,
+    }
+
+    fn test_message() -> TestMessage {
+        TestMessage {
+            id: "O-001".to_string(),
+            qty: 100,
+            px: 1.2345,
+            tags: vec!["ENTRY".to_string()],
+            reduce_only: false,
+            parent: None,
+        }
+    }
+
+    #[rstest]
+    #[case(SerializationEncoding::Json)]
+    #[case(SerializationEncoding::MsgPack)]
+    #[case(SerializationEncoding::Protobuf)]
+    fn test_serializer_roundtrip(#[case] encoding: SerializationEncoding) {
+        let serializer = serializer_for(encoding);
+        assert_eq!(serializer.encoding(), encoding);
+
+        let bytes = serializer.serialize(&test_message()).unwrap();
+        let decoded: TestMessage = serializer.deserialize(&bytes).unwrap();
+
+        assert_eq!(decoded, test_message());
+    }
+
+    #[rstest]
+    fn test_protobuf_encodes_string_value() {
+        let bytes = ProtobufMessageSerializer
+            .encode_value(&json!("abc"))
+            .unwrap();
+        // Field 3 (string_value), wire type 2, length 3
+        assert_eq!(bytes.as_ref(), &[0x1A, 0x03, b'a', b'b', b'c']);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TimestampedMessage {
+        ts_event: u64,
+        ts_init: u64,
+        offset_ns: i64,
+        max: u64,
+        min: i64,
+        small: u64,
+    }
+
+    #[rstest]
+    fn test_protobuf_roundtrip_nanosecond_timestamps() {
+        let message = TimestampedMessage {
+            ts_event: 1_700_000_000_123_456_789,
+            ts_init: 1_700_000_000_123_456_790,
+            offset_ns: -1_700_000_000_123_456_789,
+            max: u64::MAX,
+            min: i64::MIN,
+            small: (1 << 53) + 1,
+        };
+
+        let serializer: &dyn MessageSerializer = &ProtobufMessageSerializer;
+        let bytes = serializer.serialize(&message).unwrap();
+        let decoded: TimestampedMessage = serializer.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[rstest]
+    fn test_protobuf_encodes_integers_as_varints() {
+        // Field 8 (uint_value), wire type 0
+        let bytes = ProtobufMessageSerializer
+            .encode_value(&json!(1_700_000_000_123_456_789_u64))
+            .unwrap();
+        assert_eq!(bytes[0], 0x40);
+
+        // Field 7 (int_value), wire type 0, zigzag encoded
+        let bytes = ProtobufMessageSerializer.encode_value(&json!(-1)).unwrap();
+        assert_eq!(bytes.as_ref(), &[0x38, 0x01]);
+
+        // Field 2 (number_value), wire type 1
+        let bytes = ProtobufMessageSerializer.encode_value(&json!(0.5)).unwrap();
+        assert_eq!(bytes[0], 0x11);
+    }
+
+    #[rstest]
+    fn test_protobuf_decode_truncated() {
+        assert!(
+            ProtobufMessageSerializer
+                .decode_value(&[0x1A, 0x05, b'a'])
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_schema_registry_resolve() {
+        let mut registry = SchemaRegistry::new();
+        registry.register("events.order.*", "OrderEventAny", 1);
+        registry.register("events.*", "Event", 1);
+
+        assert_eq!(
+            registry.resolve("events.order.S-001").unwrap().type_name,
+            "OrderEventAny"
+        );
+        assert_eq!(
+            registry.resolve("events.position.S-001").unwrap().type_name,
+            "Event"
+        );
+        assert!(registry.resolve("data.quotes.BINANCE").is_none());
+
+        assert!(registry.deregister("events.*").is_some());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[rstest]
+    fn test_schema_registry_to_json() {
+        let mut registry = SchemaRegistry::new();
+        registry.register("events.order.*", "OrderEventAny", 2);
+
+        let json = registry.to_json(&JsonMessageSerializer).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["encoding"], "json");
+        assert_eq!(value["content_type"], "application/json");
+        assert_eq!(value["schemas"][0]["pattern"], "events.order.*");
+        assert_eq!(value["schemas"][0]["version"], 2);
+    }
+}
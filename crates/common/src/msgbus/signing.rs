@@ -0,0 +1,458 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Signing and verification of message bus external stream frames.
+//!
+//! When [`BusMessage`]s are streamed to external systems, a [`FrameSigner`] wraps each payload
+//! in a signed frame so downstream consumers can authenticate the origin of trading events.
+//! A [`FrameVerifier`] checks and strips the frame on ingest.
+//!
+//! The signature covers the topic and payload, so a frame cannot be replayed onto another topic.
+//! The frame layout is:
+//!
+//! ```text
+//! | magic (4) | algorithm (1) | signature length (1) | signature | payload |
+//! ```
+
+use bytes::{BufMut, Bytes, BytesMut};
+use nautilus_cryptography::{
+    secrets::{SecretSource, SecretString},
+    signing::{
+        ed25519_public_key, ed25519_sign_bytes, ed25519_verify, hmac_sha256_bytes,
+        hmac_sha256_verify,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use super::message::BusMessage;
+use crate::enums::FrameSigningAlgorithm;
+
+/// The magic bytes identifying a signed message bus frame.
+pub const SIGNED_FRAME_MAGIC: &[u8; 4] = b"NBS1";
+
+const FRAME_HEADER_LEN: usize = SIGNED_FRAME_MAGIC.len() + 2;
+
+/// Configuration for signing message bus external stream frames.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameSigningConfig {
+    /// The signing algorithm.
+    pub algorithm: FrameSigningAlgorithm,
+    /// The secret source for the hex encoded signing key (e.g. `env:NAUTILUS_BUS_SIGNING_KEY`).
+    /// For Ed25519 this is the 32 byte private key seed. If `None`, frames are not signed.
+    pub signing_key: Option<String>,
+    /// The hex encoded Ed25519 public key for verifying ingested frames.
+    /// For HMAC-SHA256 the signing key is used for verification.
+    pub verify_key: Option<String>,
+    /// If unsigned frames on external streams should be rejected. When `false`, unsigned frames
+    /// are passed through unverified (signed frames are always verified).
+    pub require_signed: bool,
+}
+
+/// Signs [`BusMessage`] payloads for external streaming.
+#[derive(Clone)]
+pub struct FrameSigner {
+    algorithm: FrameSigningAlgorithm,
+    key: SecretString,
+}
+
+impl std::fmt::Debug for FrameSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FrameSigner))
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FrameSigner {
+    /// Creates a new HMAC-SHA256 [`FrameSigner`] from a hex encoded shared `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is not valid hex or is empty.
+    pub fn hmac_sha256(key: SecretString) -> anyhow::Result<Self> {
+        decode_key(&key)?;
+        Ok(Self {
+            algorithm: FrameSigningAlgorithm::HmacSha256,
+            key,
+        })
+    }
+
+    /// Creates a new Ed25519 [`FrameSigner`] from a hex encoded 32 byte private key seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is not a valid hex encoded Ed25519 private key seed.
+    pub fn ed25519(key: SecretString) -> anyhow::Result<Self> {
+        ed25519_public_key(&decode_key(&key)?)?;
+        Ok(Self {
+            algorithm: FrameSigningAlgorithm::Ed25519,
+            key,
+        })
+    }
+
+    /// Creates a new [`FrameSigner`] from the given `config`.
+    ///
+    /// Returns `Ok(None)` when no signing key is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signing key cannot be resolved or is invalid.
+    pub fn from_config(config: &FrameSigningConfig) -> anyhow::Result<Option<Self>> {
+        let Some(source) = &config.signing_key else {
+            return Ok(None);
+        };
+
+        let key = source.parse::<SecretSource>()?.resolve()?;
+        let signer = match config.algorithm {
+            FrameSigningAlgorithm::HmacSha256 => Self::hmac_sha256(key)?,
+            FrameSigningAlgorithm::Ed25519 => Self::ed25519(key)?,
+        };
+        Ok(Some(signer))
+    }
+
+    /// Returns the signing algorithm.
+    #[must_use]
+    pub const fn algorithm(&self) -> FrameSigningAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns a [`FrameVerifier`] which verifies frames produced by this signer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is invalid (validated on construction).
+    #[must_use]
+    pub fn verifier(&self) -> FrameVerifier {
+        let key = decode_key(&self.key).expect("key validated on construction");
+        match self.algorithm {
+            FrameSigningAlgorithm::HmacSha256 => FrameVerifier {
+                algorithm: self.algorithm,
+                key: self.key.clone(),
+                require_signed: true,
+            },
+            FrameSigningAlgorithm::Ed25519 => {
+                let public_key = ed25519_public_key(&key).expect("key validated on construction");
+                FrameVerifier {
+                    algorithm: self.algorithm,
+                    key: SecretString::new(hex::encode(public_key)),
+                    require_signed: true,
+                }
+            }
+        }
+    }
+
+    /// Signs the `message`, returning a new message with the payload wrapped in a signed frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails.
+    pub fn sign(&self, message: &BusMessage) -> anyhow::Result<BusMessage> {
+        let key = decode_key(&self.key)?;
+        let data = signed_data(message.topic.as_str(), &message.payload);
+        let signature = match self.algorithm {
+            FrameSigningAlgorithm::HmacSha256 => hmac_sha256_bytes(&key, &data),
+            FrameSigningAlgorithm::Ed25519 => ed25519_sign_bytes(&key, &data)?,
+        };
+        let signature_len = u8::try_from(signature.len())?;
+
+        let mut frame =
+            BytesMut::with_capacity(FRAME_HEADER_LEN + signature.len() + message.payload.len());
+        frame.put_slice(SIGNED_FRAME_MAGIC);
+        frame.put_u8(self.algorithm as u8);
+        frame.put_u8(signature_len);
+        frame.put_slice(&signature);
+        frame.put_slice(&message.payload);
+
+        Ok(BusMessage::new(message.topic, frame.freeze()))
+    }
+}
+
+/// Verifies signed [`BusMessage`] frames ingested from external streams.
+#[derive(Clone)]
+pub struct FrameVerifier {
+    algorithm: FrameSigningAlgorithm,
+    key: SecretString,
+    require_signed: bool,
+}
+
+impl std::fmt::Debug for FrameVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FrameVerifier))
+            .field("algorithm", &self.algorithm)
+            .field("require_signed", &self.require_signed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FrameVerifier {
+    /// Creates a new HMAC-SHA256 [`FrameVerifier`] from a hex encoded shared `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is not valid hex or is empty.
+    pub fn hmac_sha256(key: SecretString) -> anyhow::Result<Self> {
+        decode_key(&key)?;
+        Ok(Self {
+            algorithm: FrameSigningAlgorithm::HmacSha256,
+            key,
+            require_signed: true,
+        })
+    }
+
+    /// Creates a new Ed25519 [`FrameVerifier`] from a hex encoded 32 byte public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `public_key` is not valid hex or has an invalid length.
+    pub fn ed25519(public_key: &str) -> anyhow::Result<Self> {
+        let key = SecretString::from(public_key);
+        if decode_key(&key)?.len() != 32 {
+            anyhow::bail!("Invalid Ed25519 public key length");
+        }
+        Ok(Self {
+            algorithm: FrameSigningAlgorithm::Ed25519,
+            key,
+            require_signed: true,
+        })
+    }
+
+    /// Sets whether unsigned frames are rejected (the default) or passed through unverified.
+    #[must_use]
+    pub const fn with_require_signed(mut self, require_signed: bool) -> Self {
+        self.require_signed = require_signed;
+        self
+    }
+
+    /// Creates a new [`FrameVerifier`] from the given `config`.
+    ///
+    /// Returns `Ok(None)` when no verification key is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the verification key cannot be resolved or is invalid.
+    pub fn from_config(config: &FrameSigningConfig) -> anyhow::Result<Option<Self>> {
+        let verifier = match config.algorithm {
+            FrameSigningAlgorithm::HmacSha256 => {
+                let Some(source) = &config.signing_key else {
+                    return Ok(None);
+                };
+                let key = source.parse::<SecretSource>()?.resolve()?;
+                Some(Self::hmac_sha256(key)?)
+            }
+            FrameSigningAlgorithm::Ed25519 => match &config.verify_key {
+                Some(public_key) => Some(Self::ed25519(public_key)?),
+                None => FrameSigner::from_config(config)?.map(|s| s.verifier()),
+            },
+        };
+        Ok(verifier.map(|v| v.with_require_signed(config.require_signed)))
+    }
+
+    /// Returns the signing algorithm.
+    #[must_use]
+    pub const fn algorithm(&self) -> FrameSigningAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns whether unsigned frames are rejected.
+    #[must_use]
+    pub const fn require_signed(&self) -> bool {
+        self.require_signed
+    }
+
+    /// Returns whether the `payload` is a signed frame.
+    #[must_use]
+    pub fn is_signed(payload: &[u8]) -> bool {
+        payload.starts_with(SIGNED_FRAME_MAGIC)
+    }
+
+    /// Verifies the signed frame `message`, returning the message with the original payload.
+    ///
+    /// Unsigned messages are returned unchanged when signing is not required.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not a signed frame (and signing is required), was
+    /// signed with a different algorithm, or the signature does not authenticate the topic and
+    /// payload.
+    pub fn verify(&self, message: &BusMessage) -> anyhow::Result<BusMessage> {
+        let frame = &message.payload;
+        if !Self::is_signed(frame) {
+            if self.require_signed {
+                anyhow::bail!("Unsigned frame on topic '{}'", message.topic);
+            }
+            return Ok(message.clone());
+        }
+
+        if frame.len() < FRAME_HEADER_LEN {
+            anyhow::bail!("Truncated signed frame on topic '{}'", message.topic);
+        }
+
+        let algorithm = FrameSigningAlgorithm::from_repr(frame[SIGNED_FRAME_MAGIC.len()] as usize)
+            .ok_or_else(|| anyhow::anyhow!("Unknown frame signing algorithm"))?;
+        if algorithm != self.algorithm {
+            anyhow::bail!(
+                "Frame signing algorithm mismatch: expected {}, was {algorithm}",
+                self.algorithm
+            );
+        }
+
+        let signature_len = frame[SIGNED_FRAME_MAGIC.len() + 1] as usize;
+        let payload_start = FRAME_HEADER_LEN + signature_len;
+        if frame.len() < payload_start {
+            anyhow::bail!("Truncated signed frame on topic '{}'", message.topic);
+        }
+
+        let signature = &frame[FRAME_HEADER_LEN..payload_start];
+        let payload = frame.slice(payload_start..);
+        let data = signed_data(message.topic.as_str(), &payload);
+        let key = decode_key(&self.key)?;
+
+        match self.algorithm {
+            FrameSigningAlgorithm::HmacSha256 => hmac_sha256_verify(&key, &data, signature),
+            FrameSigningAlgorithm::Ed25519 => ed25519_verify(&key, &data, signature),
+        }
+        .map_err(|e| {
+            anyhow::anyhow!("Invalid frame signature on topic '{}': {e}", message.topic)
+        })?;
+
+        Ok(BusMessage::new(message.topic, payload))
+    }
+}
+
+fn signed_data(topic: &str, payload: &Bytes) -> Vec<u8> {
+    let mut data = Vec::with_capacity(topic.len() + 1 + payload.len());
+    data.extend_from_slice(topic.as_bytes());
+    data.push(0);
+    data.extend_from_slice(payload);
+    data
+}
+
+fn decode_key(key: &SecretString) -> anyhow::Result<zeroize::Zeroizing<Vec<u8>>> {
+    let bytes = hex::decode(key.expose().trim())
+        .map_err(|e| anyhow::anyhow!("Invalid hex frame signing key: {e}"))?;
+    if bytes.is_empty() {
+        anyhow::bail!("Frame signing key cannot be empty");
+    }
+    Ok(zeroize::Zeroizing::new(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const ED25519_SEED: &str = "0c7418926b5de98fe2b6478a51f997319acd2dbcf994ea8fc31b65241f91d86f";
+
+    fn message() -> BusMessage {
+        BusMessage::with_str_topic("events.order.S-001", Bytes::from_static(b"payload"))
+    }
+
+    #[rstest]
+    #[case(FrameSigner::hmac_sha256(SecretString::from("6b6579")).unwrap())]
+    #[case(FrameSigner::ed25519(SecretString::from(ED25519_SEED)).unwrap())]
+    fn test_sign_verify_roundtrip(#[case] signer: FrameSigner) {
+        let signed = signer.sign(&message()).unwrap();
+        assert!(FrameVerifier::is_signed(&signed.payload));
+
+        let verified = signer.verifier().verify(&signed).unwrap();
+        assert_eq!(verified.topic, message().topic);
+        assert_eq!(verified.payload, message().payload);
+    }
+
+    #[rstest]
+    fn test_verify_rejects_tampered_payload_and_topic() {
+        let signer = FrameSigner::ed25519(SecretString::from(ED25519_SEED)).unwrap();
+        let verifier = signer.verifier();
+        let signed = signer.sign(&message()).unwrap();
+
+        let mut tampered = signed.payload.to_vec();
+        *tampered.last_mut().unwrap() ^= 0xFF;
+        let tampered = BusMessage::new(signed.topic, Bytes::from(tampered));
+        assert!(verifier.verify(&tampered).is_err());
+
+        let moved = BusMessage::with_str_topic("events.order.S-002", signed.payload);
+        assert!(verifier.verify(&moved).is_err());
+    }
+
+    #[rstest]
+    fn test_verify_rejects_unsigned_and_wrong_algorithm() {
+        let hmac = FrameSigner::hmac_sha256(SecretString::from("6b6579")).unwrap();
+        let ed25519 = FrameSigner::ed25519(SecretString::from(ED25519_SEED)).unwrap();
+
+        assert!(hmac.verifier().verify(&message()).is_err());
+
+        let signed = ed25519.sign(&message()).unwrap();
+        let err = hmac.verifier().verify(&signed).unwrap_err();
+        assert!(err.to_string().contains("algorithm mismatch"));
+    }
+
+    #[rstest]
+    fn test_ed25519_verifier_from_public_key() {
+        let signer = FrameSigner::ed25519(SecretString::from(ED25519_SEED)).unwrap();
+        let public_key =
+            hex::encode(ed25519_public_key(&hex::decode(ED25519_SEED).unwrap()).unwrap());
+        let config = FrameSigningConfig {
+            algorithm: FrameSigningAlgorithm::Ed25519,
+            signing_key: None,
+            verify_key: Some(public_key),
+            require_signed: true,
+        };
+
+        assert!(FrameSigner::from_config(&config).unwrap().is_none());
+        let verifier = FrameVerifier::from_config(&config).unwrap().unwrap();
+        assert!(verifier.verify(&signer.sign(&message()).unwrap()).is_ok());
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn test_verify_honours_require_signed(#[case] require_signed: bool) {
+        let signer = FrameSigner::ed25519(SecretString::from(ED25519_SEED)).unwrap();
+        let public_key =
+            hex::encode(ed25519_public_key(&hex::decode(ED25519_SEED).unwrap()).unwrap());
+        let config = FrameSigningConfig {
+            algorithm: FrameSigningAlgorithm::Ed25519,
+            signing_key: None,
+            verify_key: Some(public_key),
+            require_signed,
+        };
+        let verifier = FrameVerifier::from_config(&config).unwrap().unwrap();
+        assert_eq!(verifier.require_signed(), require_signed);
+
+        let result = verifier.verify(&message());
+        if require_signed {
+            assert!(result.unwrap_err().to_string().contains("Unsigned frame"));
+        } else {
+            assert_eq!(result.unwrap().payload, message().payload);
+        }
+
+        // Signed frames are always verified
+        let signed = signer.sign(&message()).unwrap();
+        assert!(verifier.verify(&signed).is_ok());
+        let mut tampered = signed.payload.to_vec();
+        *tampered.last_mut().unwrap() ^= 0xFF;
+        let tampered = BusMessage::new(signed.topic, Bytes::from(tampered));
+        assert!(verifier.verify(&tampered).is_err());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("not-hex")]
+    fn test_invalid_keys(#[case] key: &str) {
+        assert!(FrameSigner::hmac_sha256(SecretString::from(key)).is_err());
+        assert!(FrameSigner::ed25519(SecretString::from(key)).is_err());
+    }
+}
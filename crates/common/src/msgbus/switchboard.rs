@@ -21,6 +21,8 @@ use nautilus_model::{
     identifiers::{ClientOrderId, InstrumentId, PositionId, StrategyId, Venue},
 };
 
+use ustr::Ustr;
+
 use super::mstr::{Endpoint, MStr, Topic};
 use crate::msgbus::get_message_bus;
 
@@ -40,6 +42,8 @@ static RISK_EXECUTE_ENDPOINT: OnceLock<MStr<Endpoint>> = OnceLock::new();
 static RISK_PROCESS_ENDPOINT: OnceLock<MStr<Endpoint>> = OnceLock::new();
 static ORDER_EMULATOR_ENDPOINT: OnceLock<MStr<Endpoint>> = OnceLock::new();
 static PORTFOLIO_ACCOUNT_ENDPOINT: OnceLock<MStr<Endpoint>> = OnceLock::new();
+static TELEMETRY_LATENCY_TOPIC: OnceLock<MStr<Topic>> = OnceLock::new();
+static TELEMETRY_BANDWIDTH_TOPIC: OnceLock<MStr<Topic>> = OnceLock::new();
 
 macro_rules! define_switchboard {
     ($(
@@ -153,6 +157,19 @@ macro_rules! define_switchboard {
                 *PORTFOLIO_ACCOUNT_ENDPOINT.get_or_init(|| "Portfolio.update_account".into())
             }
 
+            // Static topics
+            #[inline]
+            #[must_use]
+            pub fn telemetry_latency_topic() -> MStr<Topic> {
+                *TELEMETRY_LATENCY_TOPIC.get_or_init(|| "telemetry.latency".into())
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn telemetry_bandwidth_topic() -> MStr<Topic> {
+                *TELEMETRY_BANDWIDTH_TOPIC.get_or_init(|| "telemetry.bandwidth".into())
+            }
+
             // Dynamic topics
             $(
                 #[must_use]
@@ -224,6 +241,26 @@ define_switchboard! {
     get_instrument_close_topic(instrument_id: InstrumentId) -> instrument_id,
     "data.close.{}.{}", instrument_id.venue, instrument_id.symbol;
 
+    order_flow_topics: InstrumentId,
+    get_order_flow_topic(instrument_id: InstrumentId) -> instrument_id,
+    "data.order_flow.{}.{}", instrument_id.venue, instrument_id.symbol;
+
+    price_band_topics: InstrumentId,
+    get_price_band_topic(instrument_id: InstrumentId) -> instrument_id,
+    "data.price_bands.{}.{}", instrument_id.venue, instrument_id.symbol;
+
+    session_stats_topics: InstrumentId,
+    get_session_stats_topic(instrument_id: InstrumentId) -> instrument_id,
+    "data.session_stats.{}.{}", instrument_id.venue, instrument_id.symbol;
+
+    yield_curve_topics: Ustr,
+    get_yield_curve_topic(curve_name: Ustr) -> curve_name,
+    "data.YieldCurveData.curve_name={}", curve_name;
+
+    economic_events_topics: Ustr,
+    get_economic_events_topic(country: Ustr) -> country,
+    "data.EconomicEvent.country={}", country;
+
     order_fills_topics: InstrumentId,
     get_order_fills_topic(instrument_id: InstrumentId) -> instrument_id,
     "events.fills.{}", instrument_id;
@@ -284,6 +321,11 @@ define_wrappers! {
     get_funding_rate_topic(instrument_id: InstrumentId) -> MStr<Topic>,
     get_instrument_status_topic(instrument_id: InstrumentId) -> MStr<Topic>,
     get_instrument_close_topic(instrument_id: InstrumentId) -> MStr<Topic>,
+    get_order_flow_topic(instrument_id: InstrumentId) -> MStr<Topic>,
+    get_price_band_topic(instrument_id: InstrumentId) -> MStr<Topic>,
+    get_session_stats_topic(instrument_id: InstrumentId) -> MStr<Topic>,
+    get_yield_curve_topic(curve_name: Ustr) -> MStr<Topic>,
+    get_economic_events_topic(country: Ustr) -> MStr<Topic>,
     get_order_fills_topic(instrument_id: InstrumentId) -> MStr<Topic>,
     get_order_cancels_topic(instrument_id: InstrumentId) -> MStr<Topic>,
     get_order_snapshots_topic(client_order_id: ClientOrderId) -> MStr<Topic>,
@@ -387,6 +429,43 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_order_flow_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = "data.order_flow.XCME.ESZ24".into();
+        let result = switchboard.get_order_flow_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.order_flow_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_price_band_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = "data.price_bands.XCME.ESZ24".into();
+        let result = switchboard.get_price_band_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.price_band_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_session_stats_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = "data.session_stats.XCME.ESZ24".into();
+        let result = switchboard.get_session_stats_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(
+            switchboard
+                .session_stats_topics
+                .contains_key(&instrument_id)
+        );
+    }
+
     #[rstest]
     fn test_get_bars_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
@@ -396,6 +475,24 @@ mod tests {
         assert!(switchboard.bar_topics.contains_key(&bar_type));
     }
 
+    #[rstest]
+    fn test_get_yield_curve_topic(mut switchboard: MessagingSwitchboard) {
+        let curve_name = Ustr::from("USD");
+        let expected_topic = "data.YieldCurveData.curve_name=USD".into();
+        let result = switchboard.get_yield_curve_topic(curve_name);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.yield_curve_topics.contains_key(&curve_name));
+    }
+
+    #[rstest]
+    fn test_get_economic_events_topic(mut switchboard: MessagingSwitchboard) {
+        let country = Ustr::from("US");
+        let expected_topic = "data.EconomicEvent.country=US".into();
+        let result = switchboard.get_economic_events_topic(country);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.economic_events_topics.contains_key(&country));
+    }
+
     #[rstest]
     fn test_get_order_snapshots_topic(mut switchboard: MessagingSwitchboard) {
         let client_order_id = ClientOrderId::from("O-123456789");
@@ -0,0 +1,537 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Strategy namespacing and multi-tenant isolation.
+//!
+//! Orders, positions and strategy topics are owned by a [`StrategyId`]. A strategy may always
+//! observe and control what it owns; access to another strategy's resources requires an explicit
+//! grant in [`StrategyNamespaces`]. Cache queries are scoped through [`ScopedCache`], and
+//! strategy-owned message bus topics live under the `strategy.{strategy_id}.` prefix so
+//! subscriptions and publications can be checked with [`StrategyNamespaces::check_topic`].
+
+use std::fmt::Display;
+
+use ahash::AHashMap;
+use nautilus_model::{
+    enums::{OrderSide, PositionSide},
+    identifiers::{ClientOrderId, InstrumentId, PositionId, StrategyId, Venue},
+    orders::{Order, OrderAny},
+    position::Position,
+};
+use ustr::Ustr;
+
+use crate::{
+    cache::Cache,
+    msgbus::{
+        acl::AclPermission,
+        mstr::{MStr, Pattern, Topic},
+    },
+};
+
+/// The message bus topic prefix of strategy namespaces.
+pub const STRATEGY_TOPIC_PREFIX: &str = "strategy";
+
+/// The access one strategy may be granted to another strategy's resources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StrategyAccess {
+    /// Read orders and positions, and subscribe to namespaced topics.
+    Observe,
+    /// Observe, plus modify or cancel orders and publish to namespaced topics.
+    Control,
+}
+
+impl Display for StrategyAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Observe => write!(f, "observe"),
+            Self::Control => write!(f, "control"),
+        }
+    }
+}
+
+/// An attempt to access a resource outside the strategy's namespace without a grant.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{strategy_id} is not permitted to {access} {target} owned by {owner}")]
+pub struct NamespaceViolation {
+    /// The strategy which attempted the access.
+    pub strategy_id: StrategyId,
+    /// The owner of the resource (`*` for wildcard topic patterns).
+    pub owner: Ustr,
+    /// The access which was required.
+    pub access: StrategyAccess,
+    /// The resource accessed.
+    pub target: String,
+}
+
+/// Returns the namespaced topic `strategy.{strategy_id}.{topic}`.
+#[must_use]
+pub fn strategy_topic(strategy_id: &StrategyId, topic: &str) -> MStr<Topic> {
+    format!("{STRATEGY_TOPIC_PREFIX}.{strategy_id}.{topic}").into()
+}
+
+/// Returns the pattern matching every topic in the namespace of `strategy_id`.
+#[must_use]
+pub fn strategy_pattern(strategy_id: &StrategyId) -> MStr<Pattern> {
+    format!("{STRATEGY_TOPIC_PREFIX}.{strategy_id}.*").into()
+}
+
+/// Returns the owner segment of a namespaced topic or pattern, or `None` if not namespaced.
+#[must_use]
+pub fn topic_owner(topic: &str) -> Option<&str> {
+    let rest = topic
+        .strip_prefix(STRATEGY_TOPIC_PREFIX)?
+        .strip_prefix('.')?;
+    Some(rest.split('.').next().unwrap_or(rest))
+}
+
+/// The grants allowing strategies to access each other's namespaces.
+#[derive(Clone, Debug, Default)]
+pub struct StrategyNamespaces {
+    grants: AHashMap<(StrategyId, StrategyId), StrategyAccess>,
+}
+
+impl StrategyNamespaces {
+    /// Creates a new [`StrategyNamespaces`] instance without any grants.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grants `grantee` the `access` to resources owned by `owner`, replacing any prior grant.
+    pub fn grant(&mut self, grantee: StrategyId, owner: StrategyId, access: StrategyAccess) {
+        self.grants.insert((grantee, owner), access);
+    }
+
+    /// Revokes any grant of `grantee` to resources owned by `owner`.
+    pub fn revoke(&mut self, grantee: &StrategyId, owner: &StrategyId) {
+        self.grants.remove(&(*grantee, *owner));
+    }
+
+    /// Returns the access `grantee` has to resources owned by `owner` (if any).
+    #[must_use]
+    pub fn access(&self, grantee: &StrategyId, owner: &StrategyId) -> Option<StrategyAccess> {
+        if grantee == owner {
+            return Some(StrategyAccess::Control);
+        }
+        self.grants.get(&(*grantee, *owner)).copied()
+    }
+
+    /// Returns whether `grantee` has at least `access` to resources owned by `owner`.
+    #[must_use]
+    pub fn is_permitted(
+        &self,
+        grantee: &StrategyId,
+        owner: &StrategyId,
+        access: StrategyAccess,
+    ) -> bool {
+        self.access(grantee, owner)
+            .is_some_and(|granted| granted >= access)
+    }
+
+    /// Checks `strategy_id` has `access` to the `order`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order belongs to another strategy without a sufficient grant.
+    pub fn check_order(
+        &self,
+        strategy_id: &StrategyId,
+        order: &OrderAny,
+        access: StrategyAccess,
+    ) -> Result<(), NamespaceViolation> {
+        self.check(strategy_id, &order.strategy_id(), access, || {
+            format!("order {}", order.client_order_id())
+        })
+    }
+
+    /// Checks `strategy_id` has `access` to the `position`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position belongs to another strategy without a sufficient grant.
+    pub fn check_position(
+        &self,
+        strategy_id: &StrategyId,
+        position: &Position,
+        access: StrategyAccess,
+    ) -> Result<(), NamespaceViolation> {
+        self.check(strategy_id, &position.strategy_id, access, || {
+            format!("position {}", position.id)
+        })
+    }
+
+    /// Checks `strategy_id` may perform `permission` on a message bus topic or pattern.
+    ///
+    /// Topics outside any strategy namespace are not restricted here (see
+    /// [`TopicAcl`](crate::msgbus::acl::TopicAcl)). Subscribing requires observe access and
+    /// publishing requires control access. Patterns with a wildcard owner are always rejected
+    /// since they would span every namespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topic belongs to another namespace without a sufficient grant.
+    pub fn check_topic(
+        &self,
+        strategy_id: &StrategyId,
+        permission: AclPermission,
+        topic: &str,
+    ) -> Result<(), NamespaceViolation> {
+        let Some(owner) = topic_owner(topic) else {
+            return Ok(());
+        };
+
+        let access = match permission {
+            AclPermission::Subscribe => StrategyAccess::Observe,
+            AclPermission::Publish => StrategyAccess::Control,
+        };
+        let permitted = StrategyId::new_checked(owner)
+            .is_ok_and(|owner| self.is_permitted(strategy_id, &owner, access));
+
+        if permitted {
+            Ok(())
+        } else {
+            Err(NamespaceViolation {
+                strategy_id: *strategy_id,
+                owner: Ustr::from(owner),
+                access,
+                target: format!("topic {topic}"),
+            })
+        }
+    }
+
+    /// Returns a view of `cache` scoped to the namespace of `strategy_id`.
+    #[must_use]
+    pub const fn scoped<'a>(
+        &'a self,
+        cache: &'a Cache,
+        strategy_id: StrategyId,
+    ) -> ScopedCache<'a> {
+        ScopedCache {
+            cache,
+            namespaces: self,
+            strategy_id,
+        }
+    }
+
+    fn check(
+        &self,
+        strategy_id: &StrategyId,
+        owner: &StrategyId,
+        access: StrategyAccess,
+        target: impl FnOnce() -> String,
+    ) -> Result<(), NamespaceViolation> {
+        if self.is_permitted(strategy_id, owner, access) {
+            return Ok(());
+        }
+        Err(NamespaceViolation {
+            strategy_id: *strategy_id,
+            owner: owner.inner(),
+            access,
+            target: target(),
+        })
+    }
+}
+
+/// A read-only view of the [`Cache`] restricted to one strategy's namespace.
+///
+/// Collection queries return only the strategy's own orders and positions; lookups by ID of
+/// resources owned by other strategies fail unless observe access was granted.
+#[derive(Debug)]
+pub struct ScopedCache<'a> {
+    cache: &'a Cache,
+    namespaces: &'a StrategyNamespaces,
+    strategy_id: StrategyId,
+}
+
+impl<'a> ScopedCache<'a> {
+    /// Returns the strategy ID of the scope.
+    #[must_use]
+    pub const fn strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    /// Returns the strategy's orders matching the optional filters.
+    #[must_use]
+    pub fn orders(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&'a OrderAny> {
+        self.cache
+            .orders(venue, instrument_id, Some(&self.strategy_id), None, side)
+    }
+
+    /// Returns the strategy's open orders matching the optional filters.
+    #[must_use]
+    pub fn orders_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&'a OrderAny> {
+        self.cache
+            .orders_open(venue, instrument_id, Some(&self.strategy_id), None, side)
+    }
+
+    /// Returns the strategy's positions matching the optional filters.
+    #[must_use]
+    pub fn positions(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        side: Option<PositionSide>,
+    ) -> Vec<&'a Position> {
+        self.cache
+            .positions(venue, instrument_id, Some(&self.strategy_id), None, side)
+    }
+
+    /// Returns the strategy's open positions matching the optional filters.
+    #[must_use]
+    pub fn positions_open(
+        &self,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        side: Option<PositionSide>,
+    ) -> Vec<&'a Position> {
+        self.cache
+            .positions_open(venue, instrument_id, Some(&self.strategy_id), None, side)
+    }
+
+    /// Returns the orders of `owner`, which requires observe access for other strategies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `owner` is another strategy without an observe grant.
+    pub fn orders_of(
+        &self,
+        owner: &StrategyId,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        side: Option<OrderSide>,
+    ) -> Result<Vec<&'a OrderAny>, NamespaceViolation> {
+        self.namespaces
+            .check(&self.strategy_id, owner, StrategyAccess::Observe, || {
+                format!("orders of {owner}")
+            })?;
+        Ok(self
+            .cache
+            .orders(venue, instrument_id, Some(owner), None, side))
+    }
+
+    /// Returns the order with `client_order_id` (if found).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order belongs to another strategy without an observe grant.
+    pub fn order(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> Result<Option<&'a OrderAny>, NamespaceViolation> {
+        let Some(order) = self.cache.order(client_order_id) else {
+            return Ok(None);
+        };
+        self.namespaces
+            .check_order(&self.strategy_id, order, StrategyAccess::Observe)?;
+        Ok(Some(order))
+    }
+
+    /// Returns the position with `position_id` (if found).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position belongs to another strategy without an observe grant.
+    pub fn position(
+        &self,
+        position_id: &PositionId,
+    ) -> Result<Option<&'a Position>, NamespaceViolation> {
+        let Some(position) = self.cache.position(position_id) else {
+            return Ok(None);
+        };
+        self.namespaces
+            .check_position(&self.strategy_id, position, StrategyAccess::Observe)?;
+        Ok(Some(position))
+    }
+
+    /// Checks the strategy may modify or cancel the order with `client_order_id`.
+    ///
+    /// Unknown orders pass, leaving not-found handling to the execution path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order belongs to another strategy without a control grant.
+    pub fn check_control(&self, client_order_id: &ClientOrderId) -> Result<(), NamespaceViolation> {
+        match self.cache.order(client_order_id) {
+            Some(order) => {
+                self.namespaces
+                    .check_order(&self.strategy_id, order, StrategyAccess::Control)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        instruments::{CurrencyPair, stubs::audusd_sim},
+        orders::builder::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cache_with_orders(instrument: &CurrencyPair) -> Cache {
+        let mut cache = Cache::default();
+        for (strategy, id) in [("S-001", "O-1"), ("S-001", "O-2"), ("S-002", "O-3")] {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id)
+                .strategy_id(StrategyId::from(strategy))
+                .client_order_id(ClientOrderId::from(id))
+                .quantity(Quantity::from(100_000))
+                .build();
+            cache.add_order(order, None, None, false).unwrap();
+        }
+        cache
+    }
+
+    #[rstest]
+    fn test_scoped_queries_only_return_own_orders(audusd_sim: CurrencyPair) {
+        let cache = cache_with_orders(&audusd_sim);
+        let namespaces = StrategyNamespaces::new();
+        let scope = namespaces.scoped(&cache, StrategyId::from("S-001"));
+
+        let mut ids: Vec<String> = scope
+            .orders(None, None, None)
+            .iter()
+            .map(|o| o.client_order_id().to_string())
+            .collect();
+        ids.sort();
+
+        assert_eq!(ids, vec!["O-1", "O-2"]);
+        assert!(scope.order(&ClientOrderId::from("O-1")).unwrap().is_some());
+        assert!(
+            scope
+                .order(&ClientOrderId::from("O-404"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[rstest]
+    fn test_foreign_order_requires_grant(audusd_sim: CurrencyPair) {
+        let cache = cache_with_orders(&audusd_sim);
+        let mut namespaces = StrategyNamespaces::new();
+        let s1 = StrategyId::from("S-001");
+        let s2 = StrategyId::from("S-002");
+        let foreign = ClientOrderId::from("O-3");
+
+        let err = namespaces.scoped(&cache, s1).order(&foreign).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "S-001 is not permitted to observe order O-3 owned by S-002"
+        );
+        assert!(
+            namespaces
+                .scoped(&cache, s1)
+                .check_control(&foreign)
+                .is_err()
+        );
+
+        namespaces.grant(s1, s2, StrategyAccess::Observe);
+        let scope = namespaces.scoped(&cache, s1);
+        assert!(scope.order(&foreign).unwrap().is_some());
+        assert_eq!(scope.orders_of(&s2, None, None, None).unwrap().len(), 1);
+        assert!(scope.check_control(&foreign).is_err());
+
+        namespaces.grant(s1, s2, StrategyAccess::Control);
+        assert!(
+            namespaces
+                .scoped(&cache, s1)
+                .check_control(&foreign)
+                .is_ok()
+        );
+
+        namespaces.revoke(&s1, &s2);
+        assert!(
+            namespaces
+                .scoped(&cache, s1)
+                .orders_of(&s2, None, None, None)
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_strategy_topics() {
+        let s1 = StrategyId::from("S-001");
+
+        assert_eq!(
+            strategy_topic(&s1, "signals.alpha").as_str(),
+            "strategy.S-001.signals.alpha"
+        );
+        assert_eq!(strategy_pattern(&s1).as_str(), "strategy.S-001.*");
+        assert_eq!(topic_owner("strategy.S-001.signals.alpha"), Some("S-001"));
+        assert_eq!(topic_owner("data.quotes.BINANCE"), None);
+        assert_eq!(topic_owner("strategy.*"), Some("*"));
+    }
+
+    #[rstest]
+    #[case(AclPermission::Subscribe, "data.quotes.*", None, true)]
+    #[case(AclPermission::Subscribe, "strategy.S-001.*", None, true)]
+    #[case(AclPermission::Subscribe, "strategy.S-002.*", None, false)]
+    #[case(
+        AclPermission::Subscribe,
+        "strategy.S-002.*",
+        Some(StrategyAccess::Observe),
+        true
+    )]
+    #[case(
+        AclPermission::Publish,
+        "strategy.S-002.cmd",
+        Some(StrategyAccess::Observe),
+        false
+    )]
+    #[case(
+        AclPermission::Publish,
+        "strategy.S-002.cmd",
+        Some(StrategyAccess::Control),
+        true
+    )]
+    #[case(
+        AclPermission::Subscribe,
+        "strategy.*",
+        Some(StrategyAccess::Control),
+        false
+    )]
+    fn test_check_topic(
+        #[case] permission: AclPermission,
+        #[case] topic: &str,
+        #[case] grant: Option<StrategyAccess>,
+        #[case] expected: bool,
+    ) {
+        let s1 = StrategyId::from("S-001");
+        let mut namespaces = StrategyNamespaces::new();
+        if let Some(access) = grant {
+            namespaces.grant(s1, StrategyId::from("S-002"), access);
+        }
+
+        assert_eq!(
+            namespaces.check_topic(&s1, permission, topic).is_ok(),
+            expected
+        );
+    }
+}
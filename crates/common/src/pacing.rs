@@ -0,0 +1,376 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Paced replay of historical data for demos, UI development and operator training.
+//!
+//! A [`ReplayPacer`] maps simulated time onto wall-clock time at a configurable speed: a runner
+//! calls [`ReplayPacer::advance`] before handling each data point, which sleeps until the point
+//! is due and advances the [`TestClock`] to it, firing any timers along the way at their own
+//! paced wall-clock times.
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use nautilus_core::UnixNanos;
+
+use crate::{
+    clock::{Clock, TestClock},
+    timer::TimeEvent,
+};
+
+/// The replay speed relative to the original recording.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplaySpeed {
+    /// Replays as fast as possible without pacing.
+    Unlimited,
+    /// Replays at the given multiple of real time (e.g. `10.0` plays ten times faster).
+    Multiplier(f64),
+}
+
+impl ReplaySpeed {
+    /// Real-time replay.
+    pub const REAL_TIME: Self = Self::Multiplier(1.0);
+}
+
+/// Configuration for a [`ReplayPacer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PacingConfig {
+    /// The replay speed.
+    pub speed: ReplaySpeed,
+    /// The maximum wall-clock lag tolerated before the pacer re-anchors rather than bursting
+    /// through the backlog (e.g. after a slow handler or a long pause in the data).
+    pub max_lag: Duration,
+}
+
+impl Default for PacingConfig {
+    /// Creates a new default [`PacingConfig`] instance.
+    fn default() -> Self {
+        Self {
+            speed: ReplaySpeed::REAL_TIME,
+            max_lag: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Anchor {
+    sim_ns: UnixNanos,
+    wall: Instant,
+}
+
+/// Paces simulated time against the wall clock.
+#[derive(Debug)]
+pub struct ReplayPacer {
+    config: PacingConfig,
+    anchor: Option<Anchor>,
+    paused_at: Option<Instant>,
+    slept: Duration,
+    reanchors: u64,
+}
+
+impl ReplayPacer {
+    /// Creates a new [`ReplayPacer`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the speed multiplier is not positive and finite.
+    pub fn new_checked(config: PacingConfig) -> anyhow::Result<Self> {
+        validate_speed(config.speed)?;
+        Ok(Self {
+            config,
+            anchor: None,
+            paused_at: None,
+            slept: Duration::ZERO,
+            reanchors: 0,
+        })
+    }
+
+    /// Creates a new [`ReplayPacer`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the speed multiplier is not positive and finite.
+    #[must_use]
+    pub fn new(config: PacingConfig) -> Self {
+        Self::new_checked(config).expect(nautilus_core::correctness::FAILED)
+    }
+
+    /// Returns the current replay speed.
+    #[must_use]
+    pub const fn speed(&self) -> ReplaySpeed {
+        self.config.speed
+    }
+
+    /// Returns the total wall-clock time spent sleeping.
+    #[must_use]
+    pub const fn slept(&self) -> Duration {
+        self.slept
+    }
+
+    /// Returns how many times the pacer re-anchored after exceeding the maximum lag.
+    #[must_use]
+    pub const fn reanchors(&self) -> u64 {
+        self.reanchors
+    }
+
+    /// Returns whether the pacer is paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Changes the replay speed, taking effect from the last paced point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the speed multiplier is not positive and finite.
+    pub fn set_speed(&mut self, speed: ReplaySpeed) -> anyhow::Result<()> {
+        validate_speed(speed)?;
+        self.config.speed = speed;
+        Ok(())
+    }
+
+    /// Pauses pacing; the wall time until [`ReplayPacer::resume`] is excluded from the schedule.
+    ///
+    /// The runner should stop advancing while paused.
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(Instant::now());
+        }
+    }
+
+    /// Resumes pacing after [`ReplayPacer::pause`].
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take()
+            && let Some(anchor) = &mut self.anchor
+        {
+            anchor.wall += paused_at.elapsed();
+        }
+    }
+
+    /// Returns how long to wait at wall time `now` before simulated time `ts` is due.
+    ///
+    /// The first call anchors simulated time to `now`. If the schedule lags `now` by more than
+    /// the configured maximum, the pacer re-anchors at `ts` instead of replaying a burst.
+    pub fn delay_until(&mut self, ts: UnixNanos, now: Instant) -> Duration {
+        let ReplaySpeed::Multiplier(speed) = self.config.speed else {
+            return Duration::ZERO;
+        };
+
+        let anchor = *self.anchor.get_or_insert(Anchor {
+            sim_ns: ts,
+            wall: now,
+        });
+
+        let sim_elapsed = ts.as_u64().saturating_sub(anchor.sim_ns.as_u64());
+        let due = anchor.wall + Duration::from_secs_f64(sim_elapsed as f64 / 1e9 / speed);
+
+        if due >= now {
+            return due - now;
+        }
+
+        if now - due > self.config.max_lag {
+            self.anchor = Some(Anchor {
+                sim_ns: ts,
+                wall: now,
+            });
+            self.reanchors += 1;
+        }
+        Duration::ZERO
+    }
+
+    /// Sleeps until simulated time `ts` is due.
+    ///
+    /// The schedule is re-based on each paced point, so speed changes apply from the last point
+    /// onwards and sleep overshoot does not accumulate.
+    pub fn wait_until(&mut self, ts: UnixNanos) {
+        let delay = self.delay_until(ts, Instant::now());
+        if !delay.is_zero() {
+            thread::sleep(delay);
+            self.slept += delay;
+        }
+
+        if let Some(anchor) = &mut self.anchor
+            && let ReplaySpeed::Multiplier(speed) = self.config.speed
+        {
+            let sim_elapsed = ts.as_u64().saturating_sub(anchor.sim_ns.as_u64());
+            anchor.wall += Duration::from_secs_f64(sim_elapsed as f64 / 1e9 / speed);
+            anchor.sim_ns = anchor.sim_ns.max(ts);
+        }
+    }
+
+    /// Paces the `clock` forward to `ts`, returning the fired time events in order.
+    ///
+    /// Timers due before `ts` are paced individually, so their events are released at the
+    /// wall-clock time matching their own timestamps.
+    pub fn advance(&mut self, clock: &mut TestClock, ts: UnixNanos) -> Vec<TimeEvent> {
+        let mut events = Vec::new();
+        while let Some(next) = next_timer_ns(clock).filter(|next| *next < ts) {
+            let next = next.max(clock.timestamp_ns());
+            self.wait_until(next);
+            events.extend(clock.advance_time(next, true));
+        }
+
+        self.wait_until(ts);
+        events.extend(clock.advance_time(ts.max(clock.timestamp_ns()), true));
+        events
+    }
+}
+
+fn validate_speed(speed: ReplaySpeed) -> anyhow::Result<()> {
+    if let ReplaySpeed::Multiplier(multiplier) = speed {
+        anyhow::ensure!(
+            multiplier.is_finite() && multiplier > 0.0,
+            "Invalid replay speed multiplier {multiplier}, expected positive and finite"
+        );
+    }
+    Ok(())
+}
+
+fn next_timer_ns(clock: &TestClock) -> Option<UnixNanos> {
+    clock
+        .timer_names()
+        .into_iter()
+        .filter_map(|name| clock.next_time_ns(name))
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::timer::TimeEventCallback;
+
+    fn pacer(speed: ReplaySpeed) -> ReplayPacer {
+        ReplayPacer::new(PacingConfig {
+            speed,
+            max_lag: Duration::from_millis(100),
+        })
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-1.0)]
+    #[case(f64::NAN)]
+    #[case(f64::INFINITY)]
+    fn test_invalid_speed(#[case] speed: f64) {
+        assert!(
+            ReplayPacer::new_checked(PacingConfig {
+                speed: ReplaySpeed::Multiplier(speed),
+                ..Default::default()
+            })
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    #[case(1.0, 1_000_000_000, Duration::from_secs(1))]
+    #[case(10.0, 1_000_000_000, Duration::from_millis(100))]
+    #[case(0.5, 1_000_000, Duration::from_millis(2))]
+    fn test_delay_scales_with_speed(
+        #[case] speed: f64,
+        #[case] sim_elapsed_ns: u64,
+        #[case] expected: Duration,
+    ) {
+        let mut pacer = pacer(ReplaySpeed::Multiplier(speed));
+        let now = Instant::now();
+
+        assert_eq!(
+            pacer.delay_until(UnixNanos::from(1_000), now),
+            Duration::ZERO
+        );
+        let delay = pacer.delay_until(UnixNanos::from(1_000 + sim_elapsed_ns), now);
+
+        assert!(delay.abs_diff(expected) < Duration::from_micros(1));
+    }
+
+    #[rstest]
+    fn test_unlimited_never_waits() {
+        let mut pacer = pacer(ReplaySpeed::Unlimited);
+        let now = Instant::now();
+
+        pacer.delay_until(UnixNanos::from(0), now);
+
+        assert_eq!(
+            pacer.delay_until(UnixNanos::from(u64::MAX), now),
+            Duration::ZERO
+        );
+    }
+
+    #[rstest]
+    fn test_reanchors_when_lag_exceeds_max() {
+        let mut pacer = pacer(ReplaySpeed::REAL_TIME);
+        let start = Instant::now();
+        pacer.delay_until(UnixNanos::from(0), start);
+
+        // Handler stalled for 1s while the next point was due after 1ms
+        let late = start + Duration::from_secs(1);
+        assert_eq!(
+            pacer.delay_until(UnixNanos::from(1_000_000), late),
+            Duration::ZERO
+        );
+        assert_eq!(pacer.reanchors(), 1);
+
+        // Subsequent points are paced from the new anchor rather than replayed as a burst
+        let delay = pacer.delay_until(UnixNanos::from(11_000_000), late);
+        assert!(delay.abs_diff(Duration::from_millis(10)) < Duration::from_micros(1));
+    }
+
+    #[rstest]
+    fn test_resume_shifts_schedule_by_pause() {
+        let mut pacer = pacer(ReplaySpeed::REAL_TIME);
+        let start = Instant::now();
+        pacer.delay_until(UnixNanos::from(0), start);
+
+        pacer.pause();
+        assert!(pacer.is_paused());
+        thread::sleep(Duration::from_millis(5));
+        pacer.resume();
+
+        let delay = pacer.delay_until(UnixNanos::from(20_000_000), start);
+        assert!(delay >= Duration::from_millis(25));
+    }
+
+    #[rstest]
+    fn test_advance_fires_timers_and_syncs_clock() {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(TimeEventCallback::from(|_event: TimeEvent| {}));
+        clock
+            .set_timer_ns(
+                "tick",
+                1_000_000,
+                Some(UnixNanos::default()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let mut pacer = pacer(ReplaySpeed::Multiplier(1_000.0));
+
+        let started = Instant::now();
+        let events = pacer.advance(&mut clock, UnixNanos::from(3_500_000));
+
+        let ts: Vec<u64> = events.iter().map(|e| e.ts_event.as_u64()).collect();
+        assert_eq!(ts, vec![1_000_000, 2_000_000, 3_000_000]);
+        assert_eq!(clock.timestamp_ns(), UnixNanos::from(3_500_000));
+        // 3.5ms of simulated time at 1000x is ~3.5us of wall time
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,644 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Paper trading for signal-only mode.
+//!
+//! In signal-only mode no execution clients are connected. The [`PaperTrader`] takes the risk and
+//! execution engine command endpoints, so orders submitted by strategies and research actors are
+//! intercepted and filled virtually at reference prices from the cache. Paper positions and PnL
+//! are maintained per strategy and instrument, and each virtual fill is published as a
+//! [`PaperFill`] on the strategy's paper fill topic.
+//!
+//! Market orders fill immediately at the reference price. Limit orders fill at the reference price
+//! once marketable. Stop orders fill at the reference price once triggered, while triggered
+//! stop-limit orders rest as limit orders until marketable. Until filled, orders rest and are
+//! rechecked on each quote, and can be modified or canceled.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::QuoteTick,
+    enums::OrderSide,
+    events::OrderInitialized,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId, TraderId},
+    instruments::Instrument,
+    types::{Price, Quantity, fixed::FIXED_PRECISION},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::TradingCommand,
+    msgbus::{self, MStr, MessagingSwitchboard, Topic, TypedHandler, TypedIntoHandler},
+};
+
+const QUOTES_PATTERN: &str = "data.quotes.*";
+
+/// Returns the topic paper fills for `strategy_id` are published on.
+#[must_use]
+pub fn get_paper_fill_topic(strategy_id: StrategyId) -> MStr<Topic> {
+    format!("events.paper.fill.{strategy_id}").into()
+}
+
+/// The reference price paper orders are filled at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaperPriceSource {
+    /// The ask for buys and the bid for sells, falling back to the last trade.
+    #[default]
+    Touch,
+    /// The quote mid price, falling back to the last trade.
+    Mid,
+    /// The last trade price, falling back to the quote mid price.
+    Last,
+}
+
+/// A virtual fill of a paper order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaperFill {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub price: Price,
+    pub ts_event: UnixNanos,
+}
+
+/// A paper position of a strategy in an instrument.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaperPosition {
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    /// The signed position quantity (negative when short).
+    pub quantity: f64,
+    /// The average open price.
+    pub avg_px: f64,
+    pub realized_pnl: f64,
+    pub multiplier: f64,
+}
+
+impl PaperPosition {
+    /// Creates a new flat [`PaperPosition`] instance.
+    #[must_use]
+    pub const fn new(
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        multiplier: f64,
+    ) -> Self {
+        Self {
+            strategy_id,
+            instrument_id,
+            quantity: 0.0,
+            avg_px: 0.0,
+            realized_pnl: 0.0,
+            multiplier,
+        }
+    }
+
+    /// Applies a fill of `qty` at `px` on `side`.
+    pub fn apply(&mut self, side: OrderSide, qty: f64, px: f64) {
+        let signed_qty = match side {
+            OrderSide::Buy => qty,
+            _ => -qty,
+        };
+
+        if self.quantity == 0.0 || self.quantity.signum() == signed_qty.signum() {
+            let open_qty = self.quantity.abs();
+            self.avg_px = (self.avg_px * open_qty + px * qty) / (open_qty + qty);
+            self.quantity += signed_qty;
+            return;
+        }
+
+        let closed_qty = qty.min(self.quantity.abs());
+        self.realized_pnl +=
+            closed_qty * (px - self.avg_px) * self.quantity.signum() * self.multiplier;
+        let reversed = qty > self.quantity.abs();
+        self.quantity += signed_qty;
+        if reversed {
+            self.avg_px = px;
+        } else if self.quantity == 0.0 {
+            self.avg_px = 0.0;
+        }
+    }
+
+    /// Returns the unrealized PnL marked at `price`.
+    #[must_use]
+    pub fn unrealized_pnl(&self, price: f64) -> f64 {
+        self.quantity * (price - self.avg_px) * self.multiplier
+    }
+}
+
+#[derive(Default)]
+struct PaperState {
+    resting: IndexMap<ClientOrderId, OrderInitialized>,
+    positions: IndexMap<(StrategyId, InstrumentId), PaperPosition>,
+    fills: Vec<PaperFill>,
+    quote_handler: Option<TypedHandler<QuoteTick>>,
+}
+
+/// Intercepts trading commands and fills orders virtually for signal-only mode.
+#[derive(Clone)]
+pub struct PaperTrader {
+    price_source: PaperPriceSource,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    state: Rc<RefCell<PaperState>>,
+}
+
+impl Debug for PaperTrader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct(stringify!(PaperTrader))
+            .field("price_source", &self.price_source)
+            .field("resting", &state.resting.len())
+            .field("positions", &state.positions.len())
+            .finish()
+    }
+}
+
+impl PaperTrader {
+    /// Creates a new [`PaperTrader`] instance.
+    #[must_use]
+    pub fn new(
+        price_source: PaperPriceSource,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        Self {
+            price_source,
+            clock,
+            cache,
+            state: Rc::new(RefCell::new(PaperState::default())),
+        }
+    }
+
+    /// Registers as the risk and execution engine command endpoints and subscribes to quotes.
+    ///
+    /// This must only be enabled when no risk or execution engine is running, as their endpoints
+    /// are replaced.
+    pub fn enable(&self) {
+        if self.state.borrow().quote_handler.is_some() {
+            log::warn!("Paper trader already enabled");
+            return;
+        }
+
+        for endpoint in [
+            MessagingSwitchboard::risk_engine_execute(),
+            MessagingSwitchboard::exec_engine_execute(),
+        ] {
+            let trader = self.clone();
+            msgbus::register_trading_command_endpoint(
+                endpoint,
+                TypedIntoHandler::from(move |command: TradingCommand| {
+                    trader.handle_command(command);
+                }),
+            );
+        }
+
+        let trader = self.clone();
+        let handler = TypedHandler::from(move |quote: &QuoteTick| trader.on_quote(quote));
+        msgbus::subscribe_quotes(QUOTES_PATTERN.into(), handler.clone(), None);
+        self.state.borrow_mut().quote_handler = Some(handler);
+        log::info!("Paper trading enabled, orders will be filled virtually");
+    }
+
+    /// Returns the paper position of `strategy_id` in `instrument_id`, if any.
+    #[must_use]
+    pub fn position(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+    ) -> Option<PaperPosition> {
+        self.state
+            .borrow()
+            .positions
+            .get(&(strategy_id, instrument_id))
+            .cloned()
+    }
+
+    /// Returns all paper positions.
+    #[must_use]
+    pub fn positions(&self) -> Vec<PaperPosition> {
+        self.state.borrow().positions.values().cloned().collect()
+    }
+
+    /// Returns all paper fills in the order they occurred.
+    #[must_use]
+    pub fn fills(&self) -> Vec<PaperFill> {
+        self.state.borrow().fills.clone()
+    }
+
+    /// Returns the client order IDs of the resting paper orders.
+    #[must_use]
+    pub fn resting_orders(&self) -> Vec<ClientOrderId> {
+        self.state.borrow().resting.keys().copied().collect()
+    }
+
+    /// Returns the total realized and unrealized PnL of `strategy_id`, marking open positions at
+    /// the mid price (or last trade) in the cache.
+    #[must_use]
+    pub fn total_pnl(&self, strategy_id: StrategyId) -> f64 {
+        let state = self.state.borrow();
+        state
+            .positions
+            .values()
+            .filter(|position| position.strategy_id == strategy_id)
+            .map(|position| {
+                let mark =
+                    self.reference_price(position.instrument_id, None, PaperPriceSource::Mid);
+                position.realized_pnl
+                    + mark.map_or(0.0, |mark| position.unrealized_pnl(mark.as_f64()))
+            })
+            .sum()
+    }
+
+    /// Handles a trading `command` in place of the risk and execution engines.
+    pub fn handle_command(&self, command: TradingCommand) {
+        match command {
+            TradingCommand::SubmitOrder(submit) => self.process_order(submit.order_init),
+            TradingCommand::SubmitOrderList(submit) => {
+                for order in &submit.order_list.orders {
+                    self.process_order(order.init_event().clone());
+                }
+            }
+            TradingCommand::ModifyOrder(modify) => {
+                {
+                    let mut state = self.state.borrow_mut();
+                    let Some(order) = state.resting.get_mut(&modify.client_order_id) else {
+                        return;
+                    };
+                    order.quantity = modify.quantity.unwrap_or(order.quantity);
+                    order.price = modify.price.or(order.price);
+                    // A triggered stop-limit order rests as a limit order, so is not re-armed
+                    if order.trigger_price.is_some() {
+                        order.trigger_price = modify.trigger_price.or(order.trigger_price);
+                    }
+                }
+                self.check_resting(modify.instrument_id);
+            }
+            TradingCommand::CancelOrder(cancel) => {
+                self.state
+                    .borrow_mut()
+                    .resting
+                    .shift_remove(&cancel.client_order_id);
+            }
+            TradingCommand::CancelAllOrders(cancel) => {
+                self.state.borrow_mut().resting.retain(|_, order| {
+                    order.strategy_id != cancel.strategy_id
+                        || order.instrument_id != cancel.instrument_id
+                        || (cancel.order_side != OrderSide::NoOrderSide
+                            && order.order_side != cancel.order_side)
+                });
+            }
+            other => log::debug!("Paper trader ignoring {other:?}"),
+        }
+    }
+
+    /// Handles a `quote`, filling resting orders which became marketable or triggered.
+    pub fn on_quote(&self, quote: &QuoteTick) {
+        self.check_resting(quote.instrument_id);
+    }
+
+    fn process_order(&self, mut order: OrderInitialized) {
+        if !self.try_fill(&mut order) {
+            log::debug!("Paper order {} resting", order.client_order_id);
+            self.state
+                .borrow_mut()
+                .resting
+                .insert(order.client_order_id, order);
+        }
+    }
+
+    fn check_resting(&self, instrument_id: InstrumentId) {
+        let orders: Vec<OrderInitialized> = self
+            .state
+            .borrow()
+            .resting
+            .values()
+            .filter(|order| order.instrument_id == instrument_id)
+            .cloned()
+            .collect();
+        for mut order in orders {
+            let filled = self.try_fill(&mut order);
+            let mut state = self.state.borrow_mut();
+            if filled {
+                state.resting.shift_remove(&order.client_order_id);
+            } else if let Some(resting) = state.resting.get_mut(&order.client_order_id) {
+                resting.trigger_price = order.trigger_price;
+            }
+        }
+    }
+
+    /// Fills `order` if triggered and marketable, returning whether it was filled.
+    ///
+    /// A triggered stop-limit order has its trigger price cleared, so it rests as a limit order.
+    fn try_fill(&self, order: &mut OrderInitialized) -> bool {
+        let Some(price) = self.reference_price(
+            order.instrument_id,
+            Some(order.order_side),
+            self.price_source,
+        ) else {
+            return false;
+        };
+
+        let is_buy = order.order_side == OrderSide::Buy;
+        let triggered = order.trigger_price.is_none_or(|trigger| {
+            if is_buy {
+                price >= trigger
+            } else {
+                price <= trigger
+            }
+        });
+        if !triggered {
+            return false;
+        }
+        if order.price.is_some() {
+            order.trigger_price = None;
+        }
+
+        let marketable = order.price.is_none_or(|limit| {
+            if is_buy {
+                price <= limit
+            } else {
+                price >= limit
+            }
+        });
+        if !marketable {
+            return false;
+        }
+
+        let fill = PaperFill {
+            trader_id: order.trader_id,
+            strategy_id: order.strategy_id,
+            instrument_id: order.instrument_id,
+            client_order_id: order.client_order_id,
+            order_side: order.order_side,
+            quantity: order.quantity,
+            price,
+            ts_event: self.clock.borrow().timestamp_ns(),
+        };
+        let multiplier = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id)
+            .map_or(1.0, |instrument| instrument.multiplier().as_f64());
+        {
+            let mut state = self.state.borrow_mut();
+            state
+                .positions
+                .entry((order.strategy_id, order.instrument_id))
+                .or_insert_with(|| {
+                    PaperPosition::new(order.strategy_id, order.instrument_id, multiplier)
+                })
+                .apply(order.order_side, order.quantity.as_f64(), price.as_f64());
+            state.fills.push(fill.clone());
+        }
+
+        log::info!(
+            "Paper fill {} {} {} @ {}",
+            fill.client_order_id,
+            fill.order_side,
+            fill.quantity,
+            fill.price
+        );
+        msgbus::publish_any(get_paper_fill_topic(fill.strategy_id), &fill);
+        true
+    }
+
+    fn reference_price(
+        &self,
+        instrument_id: InstrumentId,
+        side: Option<OrderSide>,
+        source: PaperPriceSource,
+    ) -> Option<Price> {
+        let cache = self.cache.borrow();
+        let quote = cache.quote(&instrument_id);
+        let last = cache.trade(&instrument_id).map(|trade| trade.price);
+        let mid = quote.map(|quote| {
+            let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+            let precision = quote.bid_price.precision.max(quote.ask_price.precision) + 1;
+            Price::new(mid, precision.min(FIXED_PRECISION))
+        });
+
+        match source {
+            PaperPriceSource::Touch => quote
+                .map(|quote| match side {
+                    Some(OrderSide::Buy) => quote.ask_price,
+                    Some(OrderSide::Sell) => quote.bid_price,
+                    _ => mid.unwrap_or(quote.bid_price),
+                })
+                .or(last),
+            PaperPriceSource::Mid => mid.or(last),
+            PaperPriceSource::Last => last.or(mid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        enums::OrderType,
+        instruments::{CurrencyPair, InstrumentAny, stubs::audusd_sim},
+        orders::{Order, OrderTestBuilder},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        clock::TestClock, messages::execution::SubmitOrder, msgbus::switchboard::get_quotes_topic,
+    };
+
+    fn setup(audusd_sim: CurrencyPair) -> (PaperTrader, Rc<RefCell<Cache>>) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let trader = PaperTrader::new(
+            PaperPriceSource::Touch,
+            Rc::new(RefCell::new(TestClock::new())),
+            cache.clone(),
+        );
+        trader.enable();
+        (trader, cache)
+    }
+
+    fn quote(cache: &Rc<RefCell<Cache>>, instrument_id: InstrumentId, bid: &str, ask: &str) {
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        cache.borrow_mut().add_quote(quote).unwrap();
+        msgbus::publish_quote(get_quotes_topic(instrument_id), &quote);
+    }
+
+    fn submit(instrument_id: InstrumentId, side: OrderSide, price: Option<&str>) {
+        let mut builder = OrderTestBuilder::new(if price.is_some() {
+            OrderType::Limit
+        } else {
+            OrderType::Market
+        });
+        builder
+            .instrument_id(instrument_id)
+            .side(side)
+            .quantity(Quantity::from(100_000));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        let order = builder.build();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        );
+        msgbus::send_trading_command(
+            MessagingSwitchboard::risk_engine_execute(),
+            TradingCommand::SubmitOrder(command),
+        );
+    }
+
+    #[rstest]
+    fn test_market_orders_fill_at_touch_and_realize_pnl(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (trader, cache) = setup(audusd_sim);
+
+        quote(&cache, instrument_id, "1.00000", "1.00010");
+        submit(instrument_id, OrderSide::Buy, None);
+        quote(&cache, instrument_id, "1.00110", "1.00120");
+        submit(instrument_id, OrderSide::Sell, None);
+
+        let fills = trader.fills();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].price, Price::from("1.00010"));
+        assert_eq!(fills[1].price, Price::from("1.00110"));
+        let position = &trader.positions()[0];
+        assert_eq!(position.quantity, 0.0);
+        assert!((position.realized_pnl - 100.0).abs() < 1e-6);
+        assert!((trader.total_pnl(position.strategy_id) - 100.0).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_limit_order_rests_until_marketable(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (trader, cache) = setup(audusd_sim);
+
+        quote(&cache, instrument_id, "1.00000", "1.00010");
+        submit(instrument_id, OrderSide::Buy, Some("0.99990"));
+        assert_eq!(trader.resting_orders().len(), 1);
+        assert!(trader.fills().is_empty());
+
+        quote(&cache, instrument_id, "0.99980", "0.99985");
+
+        assert!(trader.resting_orders().is_empty());
+        assert_eq!(trader.fills()[0].price, Price::from("0.99985"));
+    }
+
+    #[rstest]
+    fn test_triggered_stop_limit_rests_until_marketable(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (trader, cache) = setup(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(instrument_id)
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("1.00050"))
+            .price(Price::from("1.00040"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        quote(&cache, instrument_id, "1.00000", "1.00010");
+        trader.handle_command(TradingCommand::SubmitOrder(SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )));
+        quote(&cache, instrument_id, "1.00060", "1.00070");
+        assert_eq!(trader.resting_orders(), vec![order.client_order_id()]);
+        assert!(trader.fills().is_empty());
+
+        quote(&cache, instrument_id, "1.00030", "1.00035");
+
+        assert!(trader.resting_orders().is_empty());
+        assert_eq!(trader.fills()[0].price, Price::from("1.00035"));
+    }
+
+    #[rstest]
+    fn test_mid_precision_clamped_to_fixed_precision(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (trader, cache) = setup(audusd_sim);
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::new(1.0, FIXED_PRECISION),
+            Price::new(1.5, FIXED_PRECISION),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        cache.borrow_mut().add_quote(quote).unwrap();
+
+        let mid = trader
+            .reference_price(instrument_id, None, PaperPriceSource::Mid)
+            .unwrap();
+
+        assert_eq!(mid.precision, FIXED_PRECISION);
+        assert_eq!(mid, Price::new(1.25, FIXED_PRECISION));
+    }
+
+    #[rstest]
+    fn test_position_reversal_resets_average_price() {
+        let mut position = PaperPosition::new(
+            StrategyId::from("S-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            1.0,
+        );
+
+        position.apply(OrderSide::Buy, 10.0, 100.0);
+        position.apply(OrderSide::Buy, 10.0, 110.0);
+        assert_eq!(position.avg_px, 105.0);
+
+        position.apply(OrderSide::Sell, 30.0, 120.0);
+
+        assert_eq!(position.quantity, -10.0);
+        assert_eq!(position.avg_px, 120.0);
+        assert_eq!(position.realized_pnl, 300.0);
+        assert_eq!(position.unrealized_pnl(110.0), 100.0);
+    }
+}
@@ -0,0 +1,402 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Backtest-to-live parity checking of emitted command and event sequences.
+//!
+//! The same strategy is run over the same recorded data once in the backtest engine and once in
+//! the live engine (in sandbox mode), with a [`ParityRecorder`] capturing the trading commands and
+//! order events of each run. [`diff_records`] then aligns the two sequences and reports every
+//! divergence in a [`ParityReport`].
+//!
+//! Records are normalized so that the runs compare equal when the trading behavior matches:
+//! command IDs and timestamps are excluded, and client order IDs (which embed the clock time they
+//! were generated at) are replaced with their order of first appearance.
+
+use std::fmt::Display;
+
+use ahash::{AHashMap, AHashSet};
+use nautilus_model::{
+    events::OrderEventAny,
+    identifiers::{ClientOrderId, InstrumentId},
+    orders::Order,
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::messages::execution::TradingCommand;
+
+/// A normalized trading command or order event.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ParityRecord {
+    /// The command or event type name.
+    pub kind: Ustr,
+    pub instrument_id: InstrumentId,
+    /// The order's position in the order of first appearance within its run.
+    pub order_seq: Option<usize>,
+    /// The behavior-relevant fields (side, quantities, prices).
+    pub detail: String,
+}
+
+impl Display for ParityRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}", self.kind, self.instrument_id)?;
+        if let Some(seq) = self.order_seq {
+            write!(f, ", order=#{seq}")?;
+        }
+        if !self.detail.is_empty() {
+            write!(f, ", {}", self.detail)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Records the normalized commands and events of one run.
+#[derive(Clone, Debug, Default)]
+pub struct ParityRecorder {
+    records: Vec<ParityRecord>,
+    order_seqs: AHashMap<ClientOrderId, usize>,
+    ignored: AHashSet<Ustr>,
+}
+
+impl ParityRecorder {
+    /// Creates a new [`ParityRecorder`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Excludes records of `kind` (e.g. `Submitted`, which live adapters may not emit in the same
+    /// sequence position as the simulated venue).
+    pub fn ignore(&mut self, kind: &str) {
+        self.ignored.insert(Ustr::from(kind));
+    }
+
+    /// Records a trading command.
+    pub fn on_command(&mut self, command: &TradingCommand) {
+        let (order_id, detail) = match command {
+            TradingCommand::SubmitOrder(submit) => {
+                let init = &submit.order_init;
+                let mut detail = format!(
+                    "{:?} {:?} {}",
+                    init.order_type, init.order_side, init.quantity
+                );
+                push_price(&mut detail, "px", init.price);
+                push_price(&mut detail, "trigger", init.trigger_price);
+                (Some(submit.client_order_id), detail)
+            }
+            TradingCommand::SubmitOrderList(submit) => {
+                for order in &submit.order_list.orders {
+                    self.order_seq(order.client_order_id());
+                }
+                (None, format!("orders={}", submit.order_list.orders.len()))
+            }
+            TradingCommand::SubmitSpreadOrder(submit) => {
+                let init = &submit.order_init;
+                let mut detail = format!(
+                    "{:?} {:?} {} legs={}",
+                    init.order_type,
+                    init.order_side,
+                    init.quantity,
+                    submit.legs.len()
+                );
+                push_price(&mut detail, "px", init.price);
+                (Some(submit.client_order_id), detail)
+            }
+            TradingCommand::ModifyOrder(modify) => {
+                let mut detail = modify
+                    .quantity
+                    .map_or_else(String::new, |qty| format!("qty={qty}"));
+                push_price(&mut detail, "px", modify.price);
+                push_price(&mut detail, "trigger", modify.trigger_price);
+                (Some(modify.client_order_id), detail)
+            }
+            TradingCommand::CancelOrder(cancel) => (Some(cancel.client_order_id), String::new()),
+            TradingCommand::CancelAllOrders(cancel) => (None, format!("{:?}", cancel.order_side)),
+            TradingCommand::BatchCancelOrders(cancel) => {
+                let seqs: Vec<String> = cancel
+                    .cancels
+                    .iter()
+                    .map(|c| format!("#{}", self.order_seq(c.client_order_id)))
+                    .collect();
+                (None, seqs.join(","))
+            }
+            TradingCommand::QueryOrder(query) => (Some(query.client_order_id), String::new()),
+            TradingCommand::QueryAccount(_) => return,
+        };
+
+        self.push(
+            Ustr::from(&command.to_string()),
+            command.instrument_id(),
+            order_id,
+            detail,
+        );
+    }
+
+    /// Records an order event.
+    pub fn on_event(&mut self, event: &OrderEventAny) {
+        let detail = match event {
+            OrderEventAny::Filled(fill) => {
+                format!("{:?} {} @ {}", fill.order_side, fill.last_qty, fill.last_px)
+            }
+            OrderEventAny::Updated(updated) => {
+                let mut detail = format!("qty={}", updated.quantity);
+                push_price(&mut detail, "px", updated.price);
+                push_price(&mut detail, "trigger", updated.trigger_price);
+                detail
+            }
+            _ => String::new(),
+        };
+
+        self.push(
+            Ustr::from(&format!("{:?}", event.event_type())),
+            event.instrument_id(),
+            Some(event.client_order_id()),
+            detail,
+        );
+    }
+
+    /// Returns the recorded sequence.
+    #[must_use]
+    pub fn records(&self) -> &[ParityRecord] {
+        &self.records
+    }
+
+    fn order_seq(&mut self, client_order_id: ClientOrderId) -> usize {
+        let next = self.order_seqs.len() + 1;
+        *self.order_seqs.entry(client_order_id).or_insert(next)
+    }
+
+    fn push(
+        &mut self,
+        kind: Ustr,
+        instrument_id: InstrumentId,
+        order_id: Option<ClientOrderId>,
+        detail: String,
+    ) {
+        if self.ignored.contains(&kind) {
+            return;
+        }
+        let order_seq = order_id.map(|id| self.order_seq(id));
+        self.records.push(ParityRecord {
+            kind,
+            instrument_id,
+            order_seq,
+            detail,
+        });
+    }
+}
+
+fn push_price(detail: &mut String, label: &str, price: Option<Price>) {
+    if let Some(price) = price {
+        if !detail.is_empty() {
+            detail.push(' ');
+        }
+        detail.push_str(&format!("{label}={price}"));
+    }
+}
+
+/// A difference between the backtest and live sequences.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Divergence {
+    /// A record emitted only by the backtest run, at its index in that run.
+    BacktestOnly { index: usize, record: ParityRecord },
+    /// A record emitted only by the live run, at its index in that run.
+    LiveOnly { index: usize, record: ParityRecord },
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BacktestOnly { index, record } => write!(f, "- backtest[{index}] {record}"),
+            Self::LiveOnly { index, record } => write!(f, "+ live[{index}] {record}"),
+        }
+    }
+}
+
+/// The result of comparing a backtest run with a live run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParityReport {
+    pub backtest_count: usize,
+    pub live_count: usize,
+    /// The number of records common to both runs, in order.
+    pub matched_count: usize,
+    pub divergences: Vec<Divergence>,
+}
+
+impl ParityReport {
+    /// Returns whether the runs emitted identical sequences.
+    #[must_use]
+    pub fn is_parity(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+impl Display for ParityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "ParityReport(backtest={}, live={}, matched={}, divergences={})",
+            self.backtest_count,
+            self.live_count,
+            self.matched_count,
+            self.divergences.len()
+        )?;
+        for divergence in &self.divergences {
+            writeln!(f, "  {divergence}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Aligns the backtest and live sequences (by longest common subsequence) and reports the
+/// records not common to both.
+///
+/// This is `O(n * m)` in time and memory for sequences of length `n` and `m`.
+#[must_use]
+pub fn diff_records(backtest: &[ParityRecord], live: &[ParityRecord]) -> ParityReport {
+    let (n, m) = (backtest.len(), live.len());
+    let width = m + 1;
+    let mut lcs = vec![0usize; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if backtest[i] == live[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut divergences = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && backtest[i] == live[j] {
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            divergences.push(Divergence::BacktestOnly {
+                index: i,
+                record: backtest[i].clone(),
+            });
+            i += 1;
+        } else {
+            divergences.push(Divergence::LiveOnly {
+                index: j,
+                record: live[j].clone(),
+            });
+            j += 1;
+        }
+    }
+
+    ParityReport {
+        backtest_count: n,
+        live_count: m,
+        matched_count: lcs[0],
+        divergences,
+    }
+}
+
+/// Runs `backtest` and `live` with fresh recorders (each ignoring `ignored` kinds) and diffs the
+/// recorded sequences.
+pub fn run_parity<B, L>(ignored: &[&str], backtest: B, live: L) -> ParityReport
+where
+    B: FnOnce(&mut ParityRecorder),
+    L: FnOnce(&mut ParityRecorder),
+{
+    let recorder = || {
+        let mut recorder = ParityRecorder::new();
+        for kind in ignored {
+            recorder.ignore(kind);
+        }
+        recorder
+    };
+
+    let mut backtest_recorder = recorder();
+    backtest(&mut backtest_recorder);
+    let mut live_recorder = recorder();
+    live(&mut live_recorder);
+
+    diff_records(backtest_recorder.records(), live_recorder.records())
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::{OrderAccepted, OrderFilled, OrderSubmitted},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn run(recorder: &mut ParityRecorder, prefix: &str, fill_px: &str, fill_second: bool) {
+        for n in 1..=2 {
+            let client_order_id = ClientOrderId::from(format!("{prefix}-{n}").as_str());
+            recorder.on_event(&OrderEventAny::Submitted(OrderSubmitted {
+                client_order_id,
+                ..Default::default()
+            }));
+            recorder.on_event(&OrderEventAny::Accepted(OrderAccepted {
+                client_order_id,
+                ..Default::default()
+            }));
+            if n == 1 || fill_second {
+                recorder.on_event(&OrderEventAny::Filled(OrderFilled {
+                    client_order_id,
+                    last_px: Price::from(fill_px),
+                    last_qty: Quantity::from("1"),
+                    ..Default::default()
+                }));
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_identical_behavior_is_parity_despite_different_ids() {
+        let report = run_parity(
+            &[],
+            |recorder| run(recorder, "O-BACKTEST", "100.0", true),
+            |recorder| run(recorder, "O-LIVE", "100.0", true),
+        );
+
+        assert!(report.is_parity());
+        assert_eq!(report.matched_count, 6);
+    }
+
+    #[rstest]
+    fn test_reports_missing_and_differing_records() {
+        let report = run_parity(
+            &["Submitted"],
+            |recorder| run(recorder, "O-BACKTEST", "100.0", true),
+            |recorder| run(recorder, "O-LIVE", "100.5", false),
+        );
+
+        assert!(!report.is_parity());
+        assert_eq!(report.backtest_count, 4);
+        assert_eq!(report.live_count, 3);
+        assert_eq!(report.matched_count, 2);
+        assert_eq!(report.divergences.len(), 3);
+        assert!(matches!(
+            &report.divergences[0],
+            Divergence::BacktestOnly { index: 1, record } if record.detail.contains("100.0")
+        ));
+        assert!(matches!(
+            &report.divergences[1],
+            Divergence::LiveOnly { index: 1, record } if record.detail.contains("100.5")
+        ));
+        assert!(report.to_string().contains("- backtest[3] Filled"));
+    }
+}
@@ -0,0 +1,381 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pegged orders, repriced relative to the top of book.
+//!
+//! A pegged order is a limit order whose price follows the best bid, best ask or mid price by a
+//! fixed offset. Venues with native peg support receive the peg as order params (see
+//! [`PegSpec::params`]), otherwise the order is registered with a [`PegManager`] which reprices it
+//! locally on each quote, emitting [`PegAdjustment`]s to be sent as modify commands.
+
+use ahash::AHashMap;
+use indexmap::IndexMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    data::QuoteTick,
+    enums::OrderSide,
+    events::OrderEventAny,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::messages::execution::{ModifyOrder, SubmitOrder};
+
+/// The top-of-book price a pegged order follows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Display, EnumString, Serialize, Deserialize)]
+#[strum(ascii_case_insensitive, serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum PegReference {
+    Bid,
+    Ask,
+    Mid,
+}
+
+/// Whether a venue supports pegged orders natively.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PegSupport {
+    /// The venue reprices the order.
+    #[default]
+    Native,
+    /// The order is repriced locally by a [`PegManager`].
+    Local,
+}
+
+/// The peg of an order to a top-of-book reference price.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PegSpec {
+    pub reference: PegReference,
+    /// The offset added to the reference price (negative to peg below it).
+    pub offset: f64,
+}
+
+impl PegSpec {
+    /// Creates a new [`PegSpec`] instance.
+    #[must_use]
+    pub const fn new(reference: PegReference, offset: f64) -> Self {
+        Self { reference, offset }
+    }
+
+    /// Returns the pegged price for the `quote`.
+    #[must_use]
+    pub fn price(&self, quote: &QuoteTick) -> f64 {
+        let reference = match self.reference {
+            PegReference::Bid => quote.bid_price.as_f64(),
+            PegReference::Ask => quote.ask_price.as_f64(),
+            PegReference::Mid => (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0,
+        };
+        reference + self.offset
+    }
+
+    /// Returns the order params describing the peg to a venue with native support.
+    #[must_use]
+    pub fn params(&self) -> IndexMap<String, String> {
+        IndexMap::from([
+            ("peg_reference".to_string(), self.reference.to_string()),
+            ("peg_offset".to_string(), self.offset.to_string()),
+        ])
+    }
+}
+
+/// A pegged order price change produced by a [`PegManager`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PegAdjustment {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub price: Price,
+}
+
+/// The tracked state of a locally pegged order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeggedOrder {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub order_side: OrderSide,
+    pub spec: PegSpec,
+    /// The current limit price of the order.
+    pub price: Price,
+    pub quantity: f64,
+    pub filled_qty: f64,
+}
+
+/// Reprices locally pegged orders as the top of book moves.
+#[derive(Debug)]
+pub struct PegManager {
+    trader_id: TraderId,
+    orders: AHashMap<ClientOrderId, PeggedOrder>,
+}
+
+impl PegManager {
+    /// Creates a new [`PegManager`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            orders: AHashMap::new(),
+        }
+    }
+
+    /// Registers the limit order of the `command` to be pegged per `spec`.
+    ///
+    /// The order keeps its initial limit price until the first quote for the instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order has no limit price, or is already registered.
+    pub fn register(&mut self, command: &SubmitOrder, spec: PegSpec) -> anyhow::Result<()> {
+        let init = &command.order_init;
+        let Some(price) = init.price else {
+            anyhow::bail!(
+                "Cannot peg order {} without a limit price",
+                command.client_order_id
+            );
+        };
+        anyhow::ensure!(
+            spec.offset.is_finite(),
+            "Invalid peg offset {}",
+            spec.offset
+        );
+        anyhow::ensure!(
+            !self.orders.contains_key(&command.client_order_id),
+            "Pegged order {} already registered",
+            command.client_order_id
+        );
+
+        self.orders.insert(
+            command.client_order_id,
+            PeggedOrder {
+                client_order_id: command.client_order_id,
+                instrument_id: command.instrument_id,
+                strategy_id: command.strategy_id,
+                order_side: init.order_side,
+                spec,
+                price,
+                quantity: init.quantity.as_f64(),
+                filled_qty: 0.0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the state of the pegged order with the given client order ID.
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&PeggedOrder> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Returns the number of tracked pegged orders.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Updates tracked orders for the `quote` instrument, returning the resulting price changes.
+    ///
+    /// Pegged prices are rounded to the precision of the order price.
+    pub fn on_quote(&mut self, quote: &QuoteTick) -> Vec<PegAdjustment> {
+        self.orders
+            .values_mut()
+            .filter(|order| order.instrument_id == quote.instrument_id)
+            .filter_map(|order| {
+                let price = Price::new(order.spec.price(quote), order.price.precision);
+                if price == order.price {
+                    return None;
+                }
+                order.price = price;
+                Some(PegAdjustment {
+                    client_order_id: order.client_order_id,
+                    instrument_id: order.instrument_id,
+                    strategy_id: order.strategy_id,
+                    price,
+                })
+            })
+            .collect()
+    }
+
+    /// Handles an order event, no longer tracking the order once it is completely filled or
+    /// otherwise closed.
+    pub fn on_event(&mut self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let closed = match event {
+            OrderEventAny::Filled(fill) => self.orders.get_mut(&client_order_id).is_some_and(|o| {
+                o.filled_qty += fill.last_qty.as_f64();
+                o.filled_qty >= o.quantity
+            }),
+            OrderEventAny::Denied(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => true,
+            _ => false,
+        };
+        if closed {
+            self.remove(&client_order_id);
+        }
+    }
+
+    /// Returns the command modifying the order price for `adjustment`.
+    #[must_use]
+    pub fn modify_command(
+        &self,
+        adjustment: &PegAdjustment,
+        client_id: Option<ClientId>,
+        venue_order_id: Option<VenueOrderId>,
+        ts_init: UnixNanos,
+    ) -> ModifyOrder {
+        ModifyOrder::new(
+            self.trader_id,
+            client_id,
+            adjustment.strategy_id,
+            adjustment.instrument_id,
+            adjustment.client_order_id,
+            venue_order_id,
+            None,
+            Some(adjustment.price),
+            None,
+            UUID4::new(),
+            ts_init,
+            None,
+        )
+    }
+
+    /// Stops tracking the pegged order with the given client order ID, returning its final state.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<PeggedOrder> {
+        self.orders.remove(client_order_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        events::{OrderCanceled, OrderFilled},
+        identifiers::stubs::trader_id,
+        orders::{Order, OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT.BINANCE")
+    }
+
+    fn submit(side: OrderSide) -> SubmitOrder {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .side(side)
+            .price(Price::from("100.00"))
+            .quantity(Quantity::from(2))
+            .build();
+        SubmitOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.init_event().clone(),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    #[case(PegReference::Bid, -0.05, "100.95")]
+    #[case(PegReference::Ask, 0.0, "101.20")]
+    #[case(PegReference::Mid, 0.01, "101.11")]
+    fn test_on_quote_reprices_to_reference(
+        #[case] reference: PegReference,
+        #[case] offset: f64,
+        #[case] expected: &str,
+    ) {
+        let mut manager = PegManager::new(trader_id());
+        let command = submit(OrderSide::Buy);
+        manager
+            .register(&command, PegSpec::new(reference, offset))
+            .unwrap();
+
+        let adjustments = manager.on_quote(&quote("101.00", "101.20"));
+
+        assert_eq!(adjustments.len(), 1);
+        assert_eq!(adjustments[0].price, Price::from(expected));
+        assert!(manager.on_quote(&quote("101.00", "101.20")).is_empty());
+    }
+
+    #[rstest]
+    fn test_register_rejects_market_orders() {
+        let mut manager = PegManager::new(trader_id());
+        let mut command = submit(OrderSide::Sell);
+        command.order_init.price = None;
+
+        assert!(
+            manager
+                .register(&command, PegSpec::new(PegReference::Ask, 0.0))
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_on_event_removes_filled_and_canceled_orders() {
+        let mut manager = PegManager::new(trader_id());
+        let command = submit(OrderSide::Buy);
+        let spec = PegSpec::new(PegReference::Bid, 0.0);
+        manager.register(&command, spec).unwrap();
+        let fill = |qty| {
+            OrderEventAny::Filled(OrderFilled {
+                client_order_id: command.client_order_id,
+                last_qty: Quantity::from(qty),
+                ..Default::default()
+            })
+        };
+
+        manager.on_event(&fill(1));
+        assert_eq!(manager.count(), 1);
+        manager.on_event(&fill(1));
+        assert_eq!(manager.count(), 0);
+
+        manager.register(&command, spec).unwrap();
+        manager.on_event(&OrderEventAny::Canceled(OrderCanceled {
+            client_order_id: command.client_order_id,
+            ..Default::default()
+        }));
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[rstest]
+    fn test_params_for_native_peg() {
+        let params = PegSpec::new(PegReference::Mid, -0.5).params();
+
+        assert_eq!(params["peg_reference"], "MID");
+        assert_eq!(params["peg_offset"], "-0.5");
+    }
+}
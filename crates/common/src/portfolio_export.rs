@@ -0,0 +1,726 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Periodic export of portfolio snapshots to external risk systems.
+//!
+//! The [`PortfolioExporter`] serializes the cached portfolio (open positions, account balances,
+//! margins and greeks) into a [`PortfolioSnapshot`] with a stable schema, encodes it with one of
+//! the message bus serializers (JSON, MessagePack, or Protobuf as `nexuscore.msgbus.Value`), and
+//! writes it to a [`SnapshotSink`] on a clock timer. Sections of the snapshot not selected in the
+//! [`PortfolioExportConfig`] are omitted.
+//!
+//! Sinks are provided for files ([`FileSink`]), plain HTTP POST endpoints ([`HttpPostSink`]) and
+//! message bus topics ([`TopicSink`]).
+
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    io::{ErrorKind, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    accounts::AccountAny,
+    enums::PositionSide,
+    identifiers::{AccountId, InstrumentId, PositionId, StrategyId},
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    enums::SerializationEncoding,
+    msgbus::{self, MStr, Topic, serializer::serializer_for},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// A section of the portfolio snapshot.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum PortfolioField {
+    Positions,
+    Balances,
+    Margins,
+    Greeks,
+}
+
+/// An open position in a portfolio snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PositionRecord {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub account_id: AccountId,
+    pub side: PositionSide,
+    /// The signed position quantity (negative for short positions).
+    pub quantity: f64,
+    pub avg_px_open: f64,
+    pub realized_pnl: Option<f64>,
+    pub unrealized_pnl: Option<f64>,
+    pub currency: String,
+}
+
+/// An account balance in a portfolio snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BalanceRecord {
+    pub account_id: AccountId,
+    pub currency: String,
+    pub total: f64,
+    pub locked: f64,
+    pub free: f64,
+}
+
+/// A per-instrument margin requirement in a portfolio snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarginRecord {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub currency: String,
+    pub initial: f64,
+    pub maintenance: f64,
+}
+
+/// The greeks of an open position's instrument in a portfolio snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GreeksRecord {
+    pub instrument_id: InstrumentId,
+    /// The signed quantity held across open positions.
+    pub quantity: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub vol: f64,
+    pub underlying_price: f64,
+}
+
+/// A point-in-time snapshot of the portfolio for external consumers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    /// The schema version of the snapshot.
+    pub version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub positions: Option<Vec<PositionRecord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balances: Option<Vec<BalanceRecord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margins: Option<Vec<MarginRecord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub greeks: Option<Vec<GreeksRecord>>,
+    pub ts_init: UnixNanos,
+}
+
+impl PortfolioSnapshot {
+    /// The current schema version.
+    pub const VERSION: u32 = 1;
+}
+
+/// A destination for encoded portfolio snapshots.
+pub trait SnapshotSink: Debug {
+    /// Writes an encoded snapshot `payload` of `content_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be delivered.
+    fn write(&mut self, payload: &Bytes, content_type: &str) -> anyhow::Result<()>;
+}
+
+/// Writes snapshots to a file, either replacing it or appending to it.
+///
+/// When appending, JSON snapshots are written one per line and binary snapshots are prefixed
+/// with their length as a big-endian `u32`.
+#[derive(Clone, Debug)]
+pub struct FileSink {
+    path: PathBuf,
+    append: bool,
+}
+
+impl FileSink {
+    /// Creates a new [`FileSink`] instance.
+    #[must_use]
+    pub const fn new(path: PathBuf, append: bool) -> Self {
+        Self { path, append }
+    }
+}
+
+impl SnapshotSink for FileSink {
+    fn write(&mut self, payload: &Bytes, content_type: &str) -> anyhow::Result<()> {
+        if !self.append {
+            let tmp = self.path.with_extension("tmp");
+            std::fs::write(&tmp, payload)?;
+            std::fs::rename(&tmp, &self.path)?;
+            return Ok(());
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if content_type.starts_with("application/json") {
+            file.write_all(payload)?;
+            file.write_all(b"\n")?;
+        } else {
+            file.write_all(&u32::try_from(payload.len())?.to_be_bytes())?;
+            file.write_all(payload)?;
+        }
+        Ok(())
+    }
+}
+
+/// POSTs snapshots to a plain `http://` endpoint.
+///
+/// Requests are blocking, with the connect, write and response read together bounded by the
+/// timeout, so the export interval should comfortably exceed the endpoint's response time.
+/// Host name resolution is performed by the system resolver and is not covered by the timeout;
+/// use an IP address where resolution latency matters.
+#[derive(Clone, Debug)]
+pub struct HttpPostSink {
+    host: String,
+    port: u16,
+    path: String,
+    timeout: Duration,
+}
+
+impl HttpPostSink {
+    /// Creates a new [`HttpPostSink`] instance for `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `url` is not a valid `http://` URL.
+    pub fn new(url: &str, timeout: Duration) -> anyhow::Result<Self> {
+        let Some(rest) = url.strip_prefix("http://") else {
+            anyhow::bail!("Only `http://` URLs are supported, was {url}");
+        };
+        let (authority, path) = rest
+            .split_once('/')
+            .map_or((rest, "/".to_string()), |(a, p)| (a, format!("/{p}")));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse()?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            anyhow::bail!("Missing host in URL {url}");
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            path,
+            timeout,
+        })
+    }
+
+    fn connect(&self, deadline: Instant) -> anyhow::Result<TcpStream> {
+        let mut last_error = None;
+        for addr in (self.host.as_str(), self.port).to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, remaining(deadline)?) {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e.into()),
+            None => anyhow::bail!("No addresses resolved for {}", self.host),
+        }
+    }
+}
+
+/// Returns the time left until `deadline`, or an error once it has passed.
+fn remaining(deadline: Instant) -> anyhow::Result<Duration> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    anyhow::ensure!(!remaining.is_zero(), "Snapshot POST timed out");
+    Ok(remaining)
+}
+
+impl SnapshotSink for HttpPostSink {
+    fn write(&mut self, payload: &Bytes, content_type: &str) -> anyhow::Result<()> {
+        let deadline = Instant::now() + self.timeout;
+        let mut stream = self.connect(deadline)?;
+        stream.set_write_timeout(Some(remaining(deadline)?))?;
+
+        let header = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: {content_type}\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n",
+            self.path,
+            self.host,
+            payload.len(),
+        );
+        stream.write_all(header.as_bytes())?;
+        stream.write_all(payload)?;
+        stream.flush()?;
+
+        // A per-read timeout alone would let a slowly trickling response hold the caller
+        // indefinitely, so each read is bounded by the time left until the deadline
+        let mut response = Vec::new();
+        let mut buf = [0_u8; 1024];
+        loop {
+            stream.set_read_timeout(Some(remaining(deadline)?))?;
+            match stream.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => response.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let status_line = response
+            .split(|b| *b == b'\n')
+            .next()
+            .map(String::from_utf8_lossy)
+            .unwrap_or_default();
+        let status: u16 = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid HTTP response: {status_line}"))?;
+        if !(200..300).contains(&status) {
+            anyhow::bail!("Snapshot POST to {} failed with status {status}", self.path);
+        }
+        Ok(())
+    }
+}
+
+/// Publishes encoded snapshots on a message bus topic.
+#[derive(Clone, Debug)]
+pub struct TopicSink {
+    topic: MStr<Topic>,
+}
+
+impl TopicSink {
+    /// Creates a new [`TopicSink`] instance.
+    #[must_use]
+    pub const fn new(topic: MStr<Topic>) -> Self {
+        Self { topic }
+    }
+}
+
+impl SnapshotSink for TopicSink {
+    fn write(&mut self, payload: &Bytes, _content_type: &str) -> anyhow::Result<()> {
+        msgbus::publish_any(self.topic, payload);
+        Ok(())
+    }
+}
+
+/// Configuration for the [`PortfolioExporter`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioExportConfig {
+    /// The interval between exports (nanoseconds).
+    pub interval_ns: u64,
+    /// The sections to include in each snapshot.
+    pub fields: Vec<PortfolioField>,
+    pub encoding: SerializationEncoding,
+}
+
+impl Default for PortfolioExportConfig {
+    /// Creates a new default [`PortfolioExportConfig`] instance.
+    fn default() -> Self {
+        Self {
+            interval_ns: 60_000_000_000,
+            fields: PortfolioField::iter().collect(),
+            encoding: SerializationEncoding::Json,
+        }
+    }
+}
+
+/// Periodically exports portfolio snapshots from the cache to a sink.
+#[derive(Clone)]
+pub struct PortfolioExporter {
+    cache: Rc<RefCell<Cache>>,
+    config: PortfolioExportConfig,
+    sink: Rc<RefCell<dyn SnapshotSink>>,
+}
+
+impl Debug for PortfolioExporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(PortfolioExporter))
+            .field("config", &self.config)
+            .field("sink", &self.sink)
+            .finish()
+    }
+}
+
+impl PortfolioExporter {
+    /// The name of the timer used for periodic exports.
+    pub const TIMER_NAME: &str = "PortfolioExport";
+
+    /// Creates a new [`PortfolioExporter`] instance.
+    #[must_use]
+    pub fn new(
+        cache: Rc<RefCell<Cache>>,
+        config: PortfolioExportConfig,
+        sink: impl SnapshotSink + 'static,
+    ) -> Self {
+        Self {
+            cache,
+            config,
+            sink: Rc::new(RefCell::new(sink)),
+        }
+    }
+
+    /// Returns a snapshot of the selected portfolio sections as of `ts_init`.
+    #[must_use]
+    pub fn snapshot(&self, ts_init: UnixNanos) -> PortfolioSnapshot {
+        let cache = self.cache.borrow();
+        let selected = |field| self.config.fields.contains(&field);
+        let positions = cache.positions_open(None, None, None, None, None);
+        let mut account_ids = cache.account_ids();
+        account_ids.sort();
+        let accounts: Vec<&AccountAny> = account_ids
+            .into_iter()
+            .filter_map(|id| cache.account(id))
+            .collect();
+
+        let position_records = selected(PortfolioField::Positions).then(|| {
+            positions
+                .iter()
+                .map(|position| PositionRecord {
+                    position_id: position.id,
+                    instrument_id: position.instrument_id,
+                    strategy_id: position.strategy_id,
+                    account_id: position.account_id,
+                    side: position.side,
+                    quantity: position.signed_qty,
+                    avg_px_open: position.avg_px_open,
+                    realized_pnl: position.realized_pnl.map(|pnl| pnl.as_f64()),
+                    unrealized_pnl: cache
+                        .calculate_unrealized_pnl(position)
+                        .map(|pnl| pnl.as_f64()),
+                    currency: position.settlement_currency.code.to_string(),
+                })
+                .collect()
+        });
+
+        let balance_records = selected(PortfolioField::Balances).then(|| {
+            let mut records = Vec::new();
+            for account in &accounts {
+                let mut balances: Vec<_> = account.balances().into_values().collect();
+                balances.sort_by_key(|balance| balance.currency.code);
+                records.extend(balances.into_iter().map(|balance| BalanceRecord {
+                    account_id: account.id(),
+                    currency: balance.currency.code.to_string(),
+                    total: balance.total.as_f64(),
+                    locked: balance.locked.as_f64(),
+                    free: balance.free.as_f64(),
+                }));
+            }
+            records
+        });
+
+        let margin_records = selected(PortfolioField::Margins).then(|| {
+            let mut records = Vec::new();
+            for account in &accounts {
+                let AccountAny::Margin(margin) = account else {
+                    continue;
+                };
+                let maintenance = margin.maintenance_margins();
+                let mut initial: Vec<_> = margin.initial_margins().into_iter().collect();
+                initial.sort_by_key(|(instrument_id, _)| *instrument_id);
+                records.extend(initial.into_iter().map(|(instrument_id, initial)| {
+                    MarginRecord {
+                        account_id: account.id(),
+                        instrument_id,
+                        currency: initial.currency.code.to_string(),
+                        initial: initial.as_f64(),
+                        maintenance: maintenance
+                            .get(&instrument_id)
+                            .map_or(0.0, |money| money.as_f64()),
+                    }
+                }));
+            }
+            records
+        });
+
+        let greeks_records = selected(PortfolioField::Greeks).then(|| {
+            let mut quantities: Vec<(InstrumentId, f64)> = Vec::new();
+            for position in &positions {
+                match quantities
+                    .iter_mut()
+                    .find(|(id, _)| *id == position.instrument_id)
+                {
+                    Some((_, quantity)) => *quantity += position.signed_qty,
+                    None => quantities.push((position.instrument_id, position.signed_qty)),
+                }
+            }
+            quantities
+                .into_iter()
+                .filter_map(|(instrument_id, quantity)| {
+                    cache.greeks(&instrument_id).map(|greeks| GreeksRecord {
+                        instrument_id,
+                        quantity,
+                        delta: greeks.delta,
+                        gamma: greeks.gamma,
+                        vega: greeks.vega,
+                        theta: greeks.theta,
+                        vol: greeks.vol,
+                        underlying_price: greeks.underlying_price,
+                    })
+                })
+                .collect()
+        });
+
+        PortfolioSnapshot {
+            version: PortfolioSnapshot::VERSION,
+            positions: position_records,
+            balances: balance_records,
+            margins: margin_records,
+            greeks: greeks_records,
+            ts_init,
+        }
+    }
+
+    /// Encodes a snapshot as of `ts_init` and writes it to the sink.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding or writing the snapshot fails.
+    pub fn export(&self, ts_init: UnixNanos) -> anyhow::Result<()> {
+        let snapshot = self.snapshot(ts_init);
+        let serializer = serializer_for(self.config.encoding);
+        let payload = serializer.serialize(&snapshot)?;
+        self.sink
+            .borrow_mut()
+            .write(&payload, serializer.content_type())
+    }
+
+    /// Starts periodic exports on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock) -> anyhow::Result<()> {
+        let exporter = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            if let Err(e) = exporter.export(event.ts_event) {
+                log::error!("Failed to export portfolio snapshot: {e}");
+            }
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            self.config.interval_ns,
+            None,
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Stops periodic exports on `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        accounts::stubs::cash_account,
+        enums::{OmsType, OrderSide, OrderType},
+        events::{OrderEventAny, account::stubs::cash_account_state},
+        instruments::{Instrument, InstrumentAny, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+        types::Quantity,
+    };
+    use rstest::rstest;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::msgbus::ShareableMessageHandler;
+
+    fn cache_with_portfolio() -> Rc<RefCell<Cache>> {
+        let mut cache = Cache::default();
+        cache
+            .add_account(AccountAny::Cash(cash_account(cash_account_state())))
+            .unwrap();
+
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::from("P-1")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        cache
+            .add_position(Position::new(&instrument, fill), OmsType::Netting)
+            .unwrap();
+        Rc::new(RefCell::new(cache))
+    }
+
+    #[rstest]
+    fn test_snapshot_includes_only_selected_fields() {
+        let config = PortfolioExportConfig {
+            fields: vec![PortfolioField::Positions],
+            ..Default::default()
+        };
+        let topic: MStr<Topic> = "exports.portfolio".into();
+        let exporter =
+            PortfolioExporter::new(cache_with_portfolio(), config, TopicSink::new(topic));
+
+        let snapshot = exporter.snapshot(1.into());
+        let positions = snapshot.positions.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].position_id, PositionId::from("P-1"));
+        assert_eq!(positions[0].quantity, -100_000.0);
+        assert!(snapshot.balances.is_none());
+        assert!(snapshot.greeks.is_none());
+    }
+
+    #[rstest]
+    fn test_export_to_topic_on_timer() {
+        let topic: MStr<Topic> = "exports.portfolio.timer".into();
+        let payloads = Rc::new(RefCell::new(Vec::new()));
+        let captured = payloads.clone();
+        let handler = ShareableMessageHandler::from_typed(move |payload: &Bytes| {
+            captured.borrow_mut().push(payload.clone());
+        });
+        msgbus::subscribe_any(topic.into(), handler, None);
+
+        let config = PortfolioExportConfig {
+            interval_ns: 1_000,
+            ..Default::default()
+        };
+        let exporter =
+            PortfolioExporter::new(cache_with_portfolio(), config, TopicSink::new(topic));
+        let mut clock = crate::clock::TestClock::new();
+        exporter.start(&mut clock).unwrap();
+
+        let events = clock.advance_time(1_000.into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let payloads = payloads.borrow();
+        assert_eq!(payloads.len(), 1);
+        let value: Value = serde_json::from_slice(&payloads[0]).unwrap();
+        assert_eq!(value["version"], 1);
+        assert_eq!(value["positions"].as_array().unwrap().len(), 1);
+        assert!(!value["balances"].as_array().unwrap().is_empty());
+        assert!(value["margins"].as_array().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_file_sink_appends_json_lines() {
+        let path = std::env::temp_dir().join(format!("portfolio-{}.jsonl", UUID4::new()));
+        let exporter = PortfolioExporter::new(
+            cache_with_portfolio(),
+            PortfolioExportConfig::default(),
+            FileSink::new(path.clone(), true),
+        );
+
+        exporter.export(1.into()).unwrap();
+        exporter.export(2.into()).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let snapshot: PortfolioSnapshot = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(snapshot.ts_init, UnixNanos::from(2));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_http_post_sink() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0_u8; 1024];
+            while !request.ends_with(b"\r\n\r\n{}") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let mut sink = HttpPostSink::new(
+            &format!("http://127.0.0.1:{port}/risk"),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        sink.write(&Bytes::from_static(b"{}"), "application/json")
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /risk HTTP/1.1\r\n"));
+        assert!(request.contains("Content-Type: application/json\r\n"));
+        assert!(HttpPostSink::new("https://example.com", Duration::from_secs(1)).is_err());
+    }
+
+    #[rstest]
+    fn test_http_post_sink_times_out_on_unresponsive_endpoint() {
+        // Accepts the connection but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || listener.accept().unwrap());
+
+        let mut sink = HttpPostSink::new(
+            &format!("http://127.0.0.1:{port}/risk"),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        let start = Instant::now();
+        assert!(
+            sink.write(&Bytes::from_static(b"{}"), "application/json")
+                .is_err()
+        );
+        assert!(start.elapsed() < Duration::from_secs(5));
+        drop(server.join().unwrap());
+    }
+
+    #[rstest]
+    fn test_protobuf_snapshot_preserves_nanosecond_timestamp() {
+        let config = PortfolioExportConfig {
+            encoding: SerializationEncoding::Protobuf,
+            ..Default::default()
+        };
+        let topic: MStr<Topic> = "exports.portfolio.protobuf".into();
+        let exporter =
+            PortfolioExporter::new(cache_with_portfolio(), config, TopicSink::new(topic));
+
+        let ts_init = UnixNanos::from(1_700_000_000_123_456_789);
+        let snapshot = exporter.snapshot(ts_init);
+        let serializer = serializer_for(SerializationEncoding::Protobuf);
+        let payload = serializer.serialize(&snapshot).unwrap();
+        let decoded: PortfolioSnapshot = serializer.deserialize(&payload).unwrap();
+
+        assert_eq!(decoded.ts_init, ts_init);
+        assert_eq!(decoded, snapshot);
+    }
+}
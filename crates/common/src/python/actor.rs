@@ -23,6 +23,7 @@ use std::{
     rc::Rc,
 };
 
+use arrow::record_batch::RecordBatch;
 use indexmap::IndexMap;
 use nautilus_core::{
     nanos::UnixNanos,
@@ -35,15 +36,24 @@ use nautilus_model::defi::{
 use nautilus_model::{
     data::{
         Bar, BarType, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
-        MarkPriceUpdate, OrderBookDeltas, QuoteTick, TradeTick, close::InstrumentClose,
+        MarkPriceUpdate, OrderBookDeltas, QuoteTick, TradeTick, YieldCurveData,
+        arrow::{bars_to_record_batch, quotes_to_record_batch, trades_to_record_batch},
+        close::InstrumentClose,
     },
     enums::BookType,
     identifiers::{ActorId, ClientId, InstrumentId, TraderId, Venue},
     instruments::InstrumentAny,
     orderbook::OrderBook,
-    python::instruments::instrument_any_to_pyobject,
+    python::{
+        data::arrow::{record_batch_to_polars, record_batch_to_pyarrow},
+        instruments::instrument_any_to_pyobject,
+    },
+};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PyDict},
 };
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
 
 use crate::{
     actor::{
@@ -55,6 +65,8 @@ use crate::{
     clock::Clock,
     component::{Component, get_component_registry},
     enums::ComponentState,
+    logging::RECV,
+    messages::data::{BarsResponse, QuotesResponse, TradesResponse},
     python::{cache::PyCache, clock::PyClock, logging::PyLogger},
     signal::Signal,
     timer::{TimeEvent, TimeEventCallback},
@@ -63,12 +75,19 @@ use crate::{
 #[pyo3::pymethods]
 impl DataActorConfig {
     #[new]
-    #[pyo3(signature = (actor_id=None, log_events=true, log_commands=true))]
-    fn py_new(actor_id: Option<ActorId>, log_events: bool, log_commands: bool) -> Self {
+    #[pyo3(signature = (actor_id=None, log_events=true, log_commands=true, manage_state=true))]
+    fn py_new(
+        actor_id: Option<ActorId>,
+        log_events: bool,
+        log_commands: bool,
+        manage_state: bool,
+    ) -> Self {
         Self {
             actor_id,
             log_events,
             log_commands,
+            manage_state,
+            subscriptions: Vec::new(),
         }
     }
 }
@@ -124,6 +143,31 @@ impl ImportableActorConfig {
     }
 }
 
+/// The form in which historical quotes, trades and bars are passed to Python handlers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum HistoricalDataFormat {
+    /// A list of Python data objects (one per row).
+    #[default]
+    Objects,
+    /// A single `pyarrow.Table`.
+    PyArrow,
+    /// A single `polars.DataFrame`.
+    Polars,
+}
+
+impl HistoricalDataFormat {
+    fn from_str_py(value: &str) -> PyResult<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "objects" => Ok(Self::Objects),
+            "pyarrow" => Ok(Self::PyArrow),
+            "polars" => Ok(Self::Polars),
+            _ => Err(to_pyvalue_err(format!(
+                "Invalid historical data format '{value}', expected 'objects', 'pyarrow' or 'polars'"
+            ))),
+        }
+    }
+}
+
 /// Inner state of PyDataActor, shared between Python wrapper and Rust registries.
 ///
 /// This type holds the actual actor state and implements all the actor traits.
@@ -134,6 +178,7 @@ pub struct PyDataActorInner {
     py_self: Option<Py<PyAny>>,
     clock: PyClock,
     logger: PyLogger,
+    historical_format: HistoricalDataFormat,
 }
 
 impl Debug for PyDataActorInner {
@@ -143,6 +188,7 @@ impl Debug for PyDataActorInner {
             .field("py_self", &self.py_self.as_ref().map(|_| "<Py<PyAny>>"))
             .field("clock", &self.clock)
             .field("logger", &self.logger)
+            .field("historical_format", &self.historical_format)
             .finish()
     }
 }
@@ -162,6 +208,33 @@ impl DerefMut for PyDataActorInner {
 }
 
 impl PyDataActorInner {
+    fn dispatch_on_save(&self) -> PyResult<IndexMap<String, Vec<u8>>> {
+        let Some(ref py_self) = self.py_self else {
+            return Ok(IndexMap::new());
+        };
+
+        Python::attach(|py| {
+            let state = py_self.call_method0(py, "on_save")?;
+            if state.is_none(py) {
+                return Ok(IndexMap::new());
+            }
+            state.extract(py)
+        })
+    }
+
+    fn dispatch_on_load(&mut self, state: IndexMap<String, Vec<u8>>) -> PyResult<()> {
+        if let Some(ref py_self) = self.py_self {
+            Python::attach(|py| {
+                let py_state = PyDict::new(py);
+                for (key, value) in state {
+                    py_state.set_item(key, PyBytes::new(py, &value))?;
+                }
+                py_self.call_method1(py, "on_load", (py_state,))
+            })?;
+        }
+        Ok(())
+    }
+
     fn dispatch_on_start(&self) -> PyResult<()> {
         if let Some(ref py_self) = self.py_self {
             Python::attach(|py| py_self.call_method0(py, "on_start"))?;
@@ -335,6 +408,15 @@ impl PyDataActorInner {
         Ok(())
     }
 
+    fn dispatch_on_yield_curve(&mut self, yield_curve: YieldCurveData) -> PyResult<()> {
+        if let Some(ref py_self) = self.py_self {
+            Python::attach(|py| {
+                py_self.call_method1(py, "on_yield_curve", (yield_curve.into_py_any_unwrap(py),))
+            })?;
+        }
+        Ok(())
+    }
+
     fn dispatch_on_historical_data(&mut self, data: Py<PyAny>) -> PyResult<()> {
         if let Some(ref py_self) = self.py_self {
             Python::attach(|py| py_self.call_method1(py, "on_historical_data", (data,)))?;
@@ -342,36 +424,79 @@ impl PyDataActorInner {
         Ok(())
     }
 
-    fn dispatch_on_historical_quotes(&mut self, quotes: Vec<QuoteTick>) -> PyResult<()> {
+    /// Converts `batch` into the configured columnar Python representation.
+    fn historical_frame(
+        &self,
+        py: Python<'_>,
+        batch: anyhow::Result<RecordBatch>,
+    ) -> PyResult<Py<PyAny>> {
+        let batch = batch.map_err(to_pyvalue_err)?;
+        match self.historical_format {
+            HistoricalDataFormat::Polars => record_batch_to_polars(py, &batch),
+            HistoricalDataFormat::Objects | HistoricalDataFormat::PyArrow => {
+                record_batch_to_pyarrow(py, &batch)
+            }
+        }
+    }
+
+    /// Passes historical data to the Python `method` as a single columnar frame.
+    fn dispatch_on_historical_frame(
+        &self,
+        method: &str,
+        batch: anyhow::Result<RecordBatch>,
+    ) -> PyResult<()> {
         if let Some(ref py_self) = self.py_self {
             Python::attach(|py| {
-                let py_quotes: Vec<_> = quotes
-                    .into_iter()
-                    .map(|q| q.into_py_any_unwrap(py))
-                    .collect();
+                let frame = self.historical_frame(py, batch)?;
+                py_self.call_method1(py, method, (frame,))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn dispatch_on_historical_quotes(&mut self, quotes: &[QuoteTick]) -> PyResult<()> {
+        if self.historical_format != HistoricalDataFormat::Objects {
+            return self.dispatch_on_historical_frame(
+                "on_historical_quotes",
+                quotes_to_record_batch(quotes),
+            );
+        }
+
+        if let Some(ref py_self) = self.py_self {
+            Python::attach(|py| {
+                let py_quotes: Vec<_> = quotes.iter().map(|q| q.into_py_any_unwrap(py)).collect();
                 py_self.call_method1(py, "on_historical_quotes", (py_quotes,))
             })?;
         }
         Ok(())
     }
 
-    fn dispatch_on_historical_trades(&mut self, trades: Vec<TradeTick>) -> PyResult<()> {
+    fn dispatch_on_historical_trades(&mut self, trades: &[TradeTick]) -> PyResult<()> {
+        if self.historical_format != HistoricalDataFormat::Objects {
+            return self.dispatch_on_historical_frame(
+                "on_historical_trades",
+                trades_to_record_batch(trades),
+            );
+        }
+
         if let Some(ref py_self) = self.py_self {
             Python::attach(|py| {
-                let py_trades: Vec<_> = trades
-                    .into_iter()
-                    .map(|t| t.into_py_any_unwrap(py))
-                    .collect();
+                let py_trades: Vec<_> = trades.iter().map(|t| t.into_py_any_unwrap(py)).collect();
                 py_self.call_method1(py, "on_historical_trades", (py_trades,))
             })?;
         }
         Ok(())
     }
 
-    fn dispatch_on_historical_bars(&mut self, bars: Vec<Bar>) -> PyResult<()> {
+    fn dispatch_on_historical_bars(&mut self, bars: &[Bar]) -> PyResult<()> {
+        if self.historical_format != HistoricalDataFormat::Objects {
+            return self
+                .dispatch_on_historical_frame("on_historical_bars", bars_to_record_batch(bars));
+        }
+
         if let Some(ref py_self) = self.py_self {
             Python::attach(|py| {
-                let py_bars: Vec<_> = bars.into_iter().map(|b| b.into_py_any_unwrap(py)).collect();
+                let py_bars: Vec<_> = bars.iter().map(|b| b.into_py_any_unwrap(py)).collect();
                 py_self.call_method1(py, "on_historical_bars", (py_bars,))
             })?;
         }
@@ -552,6 +677,7 @@ impl PyDataActor {
             py_self: None,
             clock,
             logger,
+            historical_format: HistoricalDataFormat::default(),
         };
 
         Self {
@@ -656,6 +782,16 @@ impl PyDataActor {
 }
 
 impl DataActor for PyDataActorInner {
+    fn on_save(&self) -> anyhow::Result<IndexMap<String, Vec<u8>>> {
+        self.dispatch_on_save()
+            .map_err(|e| anyhow::anyhow!("Python on_save failed: {e}"))
+    }
+
+    fn on_load(&mut self, state: IndexMap<String, Vec<u8>>) -> anyhow::Result<()> {
+        self.dispatch_on_load(state)
+            .map_err(|e| anyhow::anyhow!("Python on_load failed: {e}"))
+    }
+
     fn on_start(&mut self) -> anyhow::Result<()> {
         self.dispatch_on_start()
             .map_err(|e| anyhow::anyhow!("Python on_start failed: {e}"))
@@ -772,6 +908,11 @@ impl DataActor for PyDataActorInner {
             .map_err(|e| anyhow::anyhow!("Python on_instrument_close failed: {e}"))
     }
 
+    fn on_yield_curve(&mut self, yield_curve: &YieldCurveData) -> anyhow::Result<()> {
+        self.dispatch_on_yield_curve(yield_curve.clone())
+            .map_err(|e| anyhow::anyhow!("Python on_yield_curve failed: {e}"))
+    }
+
     #[cfg(feature = "defi")]
     fn on_block(&mut self, block: &Block) -> anyhow::Result<()> {
         self.dispatch_on_block(block.clone())
@@ -817,20 +958,59 @@ impl DataActor for PyDataActorInner {
     }
 
     fn on_historical_quotes(&mut self, quotes: &[QuoteTick]) -> anyhow::Result<()> {
-        self.dispatch_on_historical_quotes(quotes.to_vec())
+        self.dispatch_on_historical_quotes(quotes)
             .map_err(|e| anyhow::anyhow!("Python on_historical_quotes failed: {e}"))
     }
 
     fn on_historical_trades(&mut self, trades: &[TradeTick]) -> anyhow::Result<()> {
-        self.dispatch_on_historical_trades(trades.to_vec())
+        self.dispatch_on_historical_trades(trades)
             .map_err(|e| anyhow::anyhow!("Python on_historical_trades failed: {e}"))
     }
 
     fn on_historical_bars(&mut self, bars: &[Bar]) -> anyhow::Result<()> {
-        self.dispatch_on_historical_bars(bars.to_vec())
+        self.dispatch_on_historical_bars(bars)
             .map_err(|e| anyhow::anyhow!("Python on_historical_bars failed: {e}"))
     }
 
+    fn handle_quotes_response(&mut self, resp: &QuotesResponse) {
+        log::debug!("{RECV} {resp:?}");
+
+        let result = if self.historical_format == HistoricalDataFormat::Objects {
+            self.dispatch_on_historical_quotes(&resp.data)
+        } else {
+            self.dispatch_on_historical_frame("on_historical_quotes", resp.to_record_batch())
+        };
+        if let Err(e) = result {
+            log::error!("Python on_historical_quotes failed: {e}");
+        }
+    }
+
+    fn handle_trades_response(&mut self, resp: &TradesResponse) {
+        log::debug!("{RECV} {resp:?}");
+
+        let result = if self.historical_format == HistoricalDataFormat::Objects {
+            self.dispatch_on_historical_trades(&resp.data)
+        } else {
+            self.dispatch_on_historical_frame("on_historical_trades", resp.to_record_batch())
+        };
+        if let Err(e) = result {
+            log::error!("Python on_historical_trades failed: {e}");
+        }
+    }
+
+    fn handle_bars_response(&mut self, resp: &BarsResponse) {
+        log::debug!("{RECV} {resp:?}");
+
+        let result = if self.historical_format == HistoricalDataFormat::Objects {
+            self.dispatch_on_historical_bars(&resp.data)
+        } else {
+            self.dispatch_on_historical_frame("on_historical_bars", resp.to_record_batch())
+        };
+        if let Err(e) = result {
+            log::error!("Python on_historical_bars failed: {e}");
+        }
+    }
+
     fn on_historical_mark_prices(&mut self, mark_prices: &[MarkPriceUpdate]) -> anyhow::Result<()> {
         self.dispatch_on_historical_mark_prices(mark_prices.to_vec())
             .map_err(|e| anyhow::anyhow!("Python on_historical_mark_prices failed: {e}"))
@@ -885,6 +1065,14 @@ impl PyDataActor {
         self.inner().logger.clone()
     }
 
+    /// Sets the form in which historical quotes, trades and bars are passed to the
+    /// `on_historical_*` handlers: `"objects"` (default), `"pyarrow"` or `"polars"`.
+    #[pyo3(name = "set_historical_data_format")]
+    fn py_set_historical_data_format(&self, format: &str) -> PyResult<()> {
+        self.inner_mut().historical_format = HistoricalDataFormat::from_str_py(format)?;
+        Ok(())
+    }
+
     #[getter]
     #[pyo3(name = "actor_id")]
     fn py_actor_id(&self) -> ActorId {
@@ -974,6 +1162,17 @@ impl PyDataActor {
         Ok(())
     }
 
+    #[pyo3(name = "on_save")]
+    fn py_on_save(&self) -> IndexMap<String, Vec<u8>> {
+        IndexMap::new() // Default: no state to save (override in subclass)
+    }
+
+    #[pyo3(name = "on_load")]
+    #[allow(unused_variables)]
+    fn py_on_load(&mut self, state: IndexMap<String, Vec<u8>>) {
+        // Default: ignore saved state (override in subclass)
+    }
+
     #[pyo3(name = "on_start")]
     fn py_on_start(&self) -> PyResult<()> {
         self.inner().dispatch_on_start()
@@ -1079,6 +1278,11 @@ impl PyDataActor {
         self.inner_mut().dispatch_on_instrument_close(close)
     }
 
+    #[pyo3(name = "on_yield_curve")]
+    fn py_on_yield_curve(&mut self, yield_curve: YieldCurveData) -> PyResult<()> {
+        self.inner_mut().dispatch_on_yield_curve(yield_curve)
+    }
+
     #[cfg(feature = "defi")]
     #[pyo3(name = "on_block")]
     fn py_on_block(&mut self, block: Block) -> PyResult<()> {
@@ -1300,6 +1504,12 @@ impl PyDataActor {
         Ok(())
     }
 
+    #[pyo3(name = "subscribe_yield_curve")]
+    fn py_subscribe_yield_curve(&mut self, curve_name: &str) -> PyResult<()> {
+        DataActor::subscribe_yield_curve(self.inner_mut(), curve_name);
+        Ok(())
+    }
+
     #[cfg(feature = "defi")]
     #[pyo3(name = "subscribe_blocks")]
     #[pyo3(signature = (chain, client_id=None, params=None))]
@@ -1734,6 +1944,12 @@ impl PyDataActor {
         Ok(())
     }
 
+    #[pyo3(name = "unsubscribe_yield_curve")]
+    fn py_unsubscribe_yield_curve(&mut self, curve_name: &str) -> PyResult<()> {
+        DataActor::unsubscribe_yield_curve(self.inner_mut(), curve_name);
+        Ok(())
+    }
+
     #[cfg(feature = "defi")]
     #[pyo3(name = "unsubscribe_blocks")]
     #[pyo3(signature = (chain, client_id=None, params=None))]
@@ -1836,21 +2052,21 @@ impl PyDataActor {
 
     #[allow(unused_variables)]
     #[pyo3(name = "on_historical_quotes")]
-    fn py_on_historical_quotes(&mut self, quotes: Vec<QuoteTick>) -> PyResult<()> {
+    fn py_on_historical_quotes(&mut self, quotes: Py<PyAny>) -> PyResult<()> {
         // Default implementation - can be overridden in Python subclasses
         Ok(())
     }
 
     #[allow(unused_variables)]
     #[pyo3(name = "on_historical_trades")]
-    fn py_on_historical_trades(&mut self, trades: Vec<TradeTick>) -> PyResult<()> {
+    fn py_on_historical_trades(&mut self, trades: Py<PyAny>) -> PyResult<()> {
         // Default implementation - can be overridden in Python subclasses
         Ok(())
     }
 
     #[allow(unused_variables)]
     #[pyo3(name = "on_historical_bars")]
-    fn py_on_historical_bars(&mut self, bars: Vec<Bar>) -> PyResult<()> {
+    fn py_on_historical_bars(&mut self, bars: Py<PyAny>) -> PyResult<()> {
         // Default implementation - can be overridden in Python subclasses
         Ok(())
     }
@@ -1896,7 +2112,8 @@ mod tests {
     use nautilus_model::{
         data::{
             Bar, BarType, DataType, IndexPriceUpdate, InstrumentStatus, MarkPriceUpdate,
-            OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, close::InstrumentClose,
+            OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, YieldCurveData,
+            close::InstrumentClose,
         },
         enums::{AggressorSide, BookType, InstrumentCloseType, MarketStatusAction},
         identifiers::{ClientId, TradeId, TraderId, Venue},
@@ -2770,6 +2987,9 @@ class TrackingActor:
     def on_instrument_close(self, close):
         self._record("on_instrument_close", close)
 
+    def on_yield_curve(self, curve):
+        self._record("on_yield_curve", curve)
+
     def on_historical_data(self, data):
         self._record("on_historical_data", data)
 
@@ -3318,6 +3538,29 @@ class TrackingActor:
         });
     }
 
+    #[rstest]
+    fn test_python_dispatch_on_yield_curve(
+        clock: Rc<RefCell<TestClock>>,
+        cache: Rc<RefCell<Cache>>,
+        trader_id: TraderId,
+    ) {
+        pyo3::Python::initialize();
+        Python::attach(|py| {
+            let py_actor = create_tracking_python_actor(py).unwrap();
+
+            let mut rust_actor = PyDataActor::new(None);
+            rust_actor.set_python_instance(py_actor.clone_ref(py));
+            rust_actor.register(trader_id, clock, cache).unwrap();
+
+            let result = rust_actor
+                .inner_mut()
+                .on_yield_curve(&YieldCurveData::default());
+
+            assert!(result.is_ok());
+            assert!(python_method_was_called(&py_actor, py, "on_yield_curve"));
+        });
+    }
+
     #[rstest]
     fn test_python_dispatch_multiple_calls_tracked(
         clock: Rc<RefCell<TestClock>>,
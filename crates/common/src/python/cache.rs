@@ -15,9 +15,9 @@
 
 //! Python bindings for the [`Cache`] component.
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use nautilus_core::python::to_pyvalue_err;
+use nautilus_core::{python::to_pyvalue_err, time::get_atomic_clock_realtime};
 #[cfg(feature = "defi")]
 use nautilus_model::defi::{Pool, PoolProfiler};
 use nautilus_model::{
@@ -38,11 +38,13 @@ use nautilus_model::{
     },
     types::Currency,
 };
-use pyo3::prelude::*;
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{prelude::*, types::PyDict};
 
 use crate::{
-    cache::{Cache, CacheConfig},
+    cache::{Cache, CacheConfig, compression::CompressionCodec},
     enums::SerializationEncoding,
+    risk::{PortfolioVarCalculator, VarConfig, VarMethod},
 };
 
 /// Wrapper providing shared access to [`Cache`] from Python.
@@ -62,6 +64,36 @@ impl PyCache {
     }
 }
 
+/// Selects up to `limit` of the most recent cached items (stored newest first), optionally
+/// reordered oldest first.
+fn select_window<T>(mut items: Vec<T>, limit: Option<usize>, chronological: bool) -> Vec<T> {
+    if let Some(limit) = limit {
+        items.truncate(limit);
+    }
+    if chronological {
+        items.reverse();
+    }
+    items
+}
+
+/// Builds a dict of named NumPy column arrays.
+fn columns_to_pydict(
+    py: Python<'_>,
+    floats: Vec<(&str, Vec<f64>)>,
+    timestamps: Vec<(&str, Vec<u64>)>,
+) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, values) in floats {
+        let array: Bound<'_, PyArray1<f64>> = values.into_pyarray(py);
+        dict.set_item(name, array)?;
+    }
+    for (name, values) in timestamps {
+        let array: Bound<'_, PyArray1<u64>> = values.into_pyarray(py);
+        dict.set_item(name, array)?;
+    }
+    Ok(dict.unbind())
+}
+
 #[pymethods]
 impl PyCache {
     #[pyo3(name = "instrument")]
@@ -97,6 +129,184 @@ impl PyCache {
         self.0.borrow().order_book(&instrument_id).cloned()
     }
 
+    /// Returns the cached bars for `bar_type` as a dict of NumPy arrays keyed by column
+    /// (`open`, `high`, `low`, `close`, `volume`, `ts_event`, `ts_init`).
+    ///
+    /// At most `limit` of the most recent bars are returned, ordered oldest first when
+    /// `chronological` is true (for indicator calculations) or newest first otherwise.
+    /// Returns `None` if no bars are cached for `bar_type`.
+    #[pyo3(name = "bars_as_arrays", signature = (bar_type, limit=None, chronological=true))]
+    fn py_bars_as_arrays(
+        &self,
+        py: Python,
+        bar_type: BarType,
+        limit: Option<usize>,
+        chronological: bool,
+    ) -> PyResult<Option<Py<PyDict>>> {
+        let Some(bars) = self.0.borrow().bars(&bar_type) else {
+            return Ok(None);
+        };
+        let bars = select_window(bars, limit, chronological);
+
+        columns_to_pydict(
+            py,
+            vec![
+                ("open", bars.iter().map(|b| b.open.as_f64()).collect()),
+                ("high", bars.iter().map(|b| b.high.as_f64()).collect()),
+                ("low", bars.iter().map(|b| b.low.as_f64()).collect()),
+                ("close", bars.iter().map(|b| b.close.as_f64()).collect()),
+                ("volume", bars.iter().map(|b| b.volume.as_f64()).collect()),
+            ],
+            vec![
+                (
+                    "ts_event",
+                    bars.iter().map(|b| b.ts_event.as_u64()).collect(),
+                ),
+                ("ts_init", bars.iter().map(|b| b.ts_init.as_u64()).collect()),
+            ],
+        )
+        .map(Some)
+    }
+
+    /// Returns the cached quotes for `instrument_id` as a dict of NumPy arrays keyed by column
+    /// (`bid_price`, `ask_price`, `bid_size`, `ask_size`, `ts_event`, `ts_init`).
+    ///
+    /// See `bars_as_arrays` for the `limit` and `chronological` semantics.
+    #[pyo3(name = "quotes_as_arrays", signature = (instrument_id, limit=None, chronological=true))]
+    fn py_quotes_as_arrays(
+        &self,
+        py: Python,
+        instrument_id: InstrumentId,
+        limit: Option<usize>,
+        chronological: bool,
+    ) -> PyResult<Option<Py<PyDict>>> {
+        let Some(quotes) = self.0.borrow().quotes(&instrument_id) else {
+            return Ok(None);
+        };
+        let quotes = select_window(quotes, limit, chronological);
+
+        columns_to_pydict(
+            py,
+            vec![
+                (
+                    "bid_price",
+                    quotes.iter().map(|q| q.bid_price.as_f64()).collect(),
+                ),
+                (
+                    "ask_price",
+                    quotes.iter().map(|q| q.ask_price.as_f64()).collect(),
+                ),
+                (
+                    "bid_size",
+                    quotes.iter().map(|q| q.bid_size.as_f64()).collect(),
+                ),
+                (
+                    "ask_size",
+                    quotes.iter().map(|q| q.ask_size.as_f64()).collect(),
+                ),
+            ],
+            vec![
+                (
+                    "ts_event",
+                    quotes.iter().map(|q| q.ts_event.as_u64()).collect(),
+                ),
+                (
+                    "ts_init",
+                    quotes.iter().map(|q| q.ts_init.as_u64()).collect(),
+                ),
+            ],
+        )
+        .map(Some)
+    }
+
+    /// Returns the cached trades for `instrument_id` as a dict of NumPy arrays keyed by column
+    /// (`price`, `size`, `ts_event`, `ts_init`).
+    ///
+    /// See `bars_as_arrays` for the `limit` and `chronological` semantics.
+    #[pyo3(name = "trades_as_arrays", signature = (instrument_id, limit=None, chronological=true))]
+    fn py_trades_as_arrays(
+        &self,
+        py: Python,
+        instrument_id: InstrumentId,
+        limit: Option<usize>,
+        chronological: bool,
+    ) -> PyResult<Option<Py<PyDict>>> {
+        let Some(trades) = self.0.borrow().trades(&instrument_id) else {
+            return Ok(None);
+        };
+        let trades = select_window(trades, limit, chronological);
+
+        columns_to_pydict(
+            py,
+            vec![
+                ("price", trades.iter().map(|t| t.price.as_f64()).collect()),
+                ("size", trades.iter().map(|t| t.size.as_f64()).collect()),
+            ],
+            vec![
+                (
+                    "ts_event",
+                    trades.iter().map(|t| t.ts_event.as_u64()).collect(),
+                ),
+                (
+                    "ts_init",
+                    trades.iter().map(|t| t.ts_init.as_u64()).collect(),
+                ),
+            ],
+        )
+        .map(Some)
+    }
+
+    /// Calculates portfolio VaR and expected shortfall for the open positions in the cache.
+    ///
+    /// `returns` maps each instrument to its chronological return series. Returns one dict per
+    /// confidence level and horizon combination.
+    #[pyo3(
+        name = "portfolio_var",
+        signature = (returns, confidence_levels=vec![0.95, 0.99], horizons=vec![1], parametric=false, lookback=None)
+    )]
+    fn py_portfolio_var(
+        &self,
+        py: Python,
+        returns: HashMap<InstrumentId, Vec<f64>>,
+        confidence_levels: Vec<f64>,
+        horizons: Vec<u32>,
+        parametric: bool,
+        lookback: Option<usize>,
+    ) -> PyResult<Vec<Py<PyDict>>> {
+        let config = VarConfig {
+            method: if parametric {
+                VarMethod::Parametric
+            } else {
+                VarMethod::Historical
+            },
+            confidence_levels,
+            horizons,
+            lookback,
+        };
+        let mut calculator = PortfolioVarCalculator::new(self.0.clone(), config);
+        for (instrument_id, series) in returns {
+            calculator.set_returns(instrument_id, series);
+        }
+
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+        let reports = calculator.calculate(ts_init).map_err(to_pyvalue_err)?;
+
+        reports
+            .into_iter()
+            .map(|report| {
+                let dict = PyDict::new(py);
+                dict.set_item("method", format!("{:?}", report.method))?;
+                dict.set_item("confidence", report.confidence)?;
+                dict.set_item("horizon", report.horizon)?;
+                dict.set_item("value_at_risk", report.value_at_risk)?;
+                dict.set_item("expected_shortfall", report.expected_shortfall)?;
+                dict.set_item("gross_exposure", report.gross_exposure)?;
+                dict.set_item("observations", report.observations)?;
+                Ok(dict.unbind())
+            })
+            .collect()
+    }
+
     #[cfg(feature = "defi")]
     #[pyo3(name = "pool")]
     fn py_pool(&self, instrument_id: InstrumentId) -> Option<Pool> {
@@ -131,10 +341,12 @@ impl CacheConfig {
         tick_capacity: Option<usize>,
         bar_capacity: Option<usize>,
         save_market_data: Option<bool>,
+        event_sourcing: Option<bool>,
     ) -> Self {
         Self::new(
             None, // database is None since we can't expose it to Python yet
             encoding.unwrap_or(SerializationEncoding::MsgPack),
+            CompressionCodec::None,
             timestamps_as_iso8601.unwrap_or(false),
             buffer_interval_ms,
             use_trader_prefix.unwrap_or(true),
@@ -144,6 +356,7 @@ impl CacheConfig {
             tick_capacity.unwrap_or(10_000),
             bar_capacity.unwrap_or(10_000),
             save_market_data.unwrap_or(false),
+            event_sourcing.unwrap_or(false),
         )
     }
 
@@ -204,6 +417,11 @@ impl CacheConfig {
     fn save_market_data(&self) -> bool {
         self.save_market_data
     }
+
+    #[getter]
+    fn event_sourcing(&self) -> bool {
+        self.event_sourcing
+    }
 }
 
 #[pymethods]
@@ -645,3 +863,25 @@ impl Cache {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::select_window;
+
+    #[rstest]
+    #[case(None, true, vec![1, 2, 3])]
+    #[case(None, false, vec![3, 2, 1])]
+    #[case(Some(2), true, vec![2, 3])]
+    #[case(Some(2), false, vec![3, 2])]
+    #[case(Some(10), true, vec![1, 2, 3])]
+    fn test_select_window(
+        #[case] limit: Option<usize>,
+        #[case] chronological: bool,
+        #[case] expected: Vec<u32>,
+    ) {
+        // Cache stores items newest first
+        assert_eq!(select_window(vec![3, 2, 1], limit, chronological), expected);
+    }
+}
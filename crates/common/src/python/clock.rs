@@ -70,6 +70,7 @@ impl PyClock {
                 alert_time,
                 callback.map(TimeEventCallback::from),
                 allow_past,
+                None,
             )
             .map_err(to_pyvalue_err)
     }
@@ -92,6 +93,7 @@ impl PyClock {
                 alert_time_ns.into(),
                 callback.map(TimeEventCallback::from),
                 allow_past,
+                None,
             )
             .map_err(to_pyvalue_err)
     }
@@ -131,6 +133,7 @@ impl PyClock {
                 callback.map(TimeEventCallback::from),
                 allow_past,
                 fire_immediately,
+                None,
             )
             .map_err(to_pyvalue_err)
     }
@@ -160,6 +163,7 @@ impl PyClock {
                 callback.map(TimeEventCallback::from),
                 allow_past,
                 fire_immediately,
+                None,
             )
             .map_err(to_pyvalue_err)
     }
@@ -336,7 +340,7 @@ mod tests {
 
             let timer_name = "TEST_TIME1";
             test_clock
-                .set_timer_ns(timer_name, 10, None, None, None, None, None)
+                .set_timer_ns(timer_name, 10, None, None, None, None, None, None)
                 .unwrap();
 
             assert_eq!(test_clock.timer_names(), [timer_name]);
@@ -353,7 +357,7 @@ mod tests {
 
             let timer_name = "TEST_TIME1";
             test_clock
-                .set_timer_ns(timer_name, 10, None, None, None, None, None)
+                .set_timer_ns(timer_name, 10, None, None, None, None, None, None)
                 .unwrap();
             test_clock.cancel_timer(timer_name);
 
@@ -371,7 +375,7 @@ mod tests {
 
             let timer_name = "TEST_TIME1";
             test_clock
-                .set_timer_ns(timer_name, 10, None, None, None, None, None)
+                .set_timer_ns(timer_name, 10, None, None, None, None, None, None)
                 .unwrap();
             test_clock.cancel_timers();
 
@@ -397,6 +401,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    None,
                 )
                 .unwrap();
             test_clock.advance_time(2.into(), true);
@@ -422,6 +427,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    None,
                 )
                 .unwrap();
             test_clock.advance_time(3.into(), true);
@@ -448,6 +454,7 @@ mod tests {
                     None,
                     None,
                     None,
+                    None,
                 )
                 .unwrap();
             test_clock.advance_time(3.into(), false);
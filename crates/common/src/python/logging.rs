@@ -80,7 +80,7 @@ impl FileWriterConfig {
 #[pyfunction]
 #[pyo3(name = "init_logging")]
 #[allow(clippy::too_many_arguments)]
-#[pyo3(signature = (trader_id, instance_id, level_stdout, level_file=None, component_levels=None, directory=None, file_name=None, file_format=None, file_rotate=None, is_colored=None, is_bypassed=None, print_config=None, log_components_only=None))]
+#[pyo3(signature = (trader_id, instance_id, level_stdout, level_file=None, component_levels=None, directory=None, file_name=None, file_format=None, file_rotate=None, is_colored=None, is_bypassed=None, print_config=None, log_components_only=None, audit=None))]
 pub fn py_init_logging(
     trader_id: TraderId,
     instance_id: UUID4,
@@ -95,6 +95,7 @@ pub fn py_init_logging(
     is_bypassed: Option<bool>,
     print_config: Option<bool>,
     log_components_only: Option<bool>,
+    audit: Option<bool>,
 ) -> PyResult<LogGuard> {
     let level_file = level_file.map_or(LevelFilter::Off, map_log_level_to_filter);
 
@@ -109,6 +110,7 @@ pub fn py_init_logging(
         is_colored.unwrap_or(true),
         print_config.unwrap_or(false),
         false, // use_tracing - Python handles this separately in kernel
+        audit.unwrap_or(false),
     );
 
     let file_config = FileWriterConfig::new(directory, file_name, file_format, file_rotate);
@@ -120,6 +122,41 @@ pub fn py_init_logging(
     logging::init_logging(trader_id, instance_id, config, file_config).map_err(to_pyvalue_err)
 }
 
+#[pymethods]
+impl LogGuard {
+    /// Enters the guard context, `with init_logging(...) as guard:`.
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Releases the guard on leaving the context, including when an exception is raised, so
+    /// buffered log lines are written before the script continues or exits.
+    ///
+    /// Returns `False` so any exception is propagated.
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> bool {
+        self.release();
+        false
+    }
+
+    /// Releases the guard, flushing (or closing the logging thread if this is the last guard).
+    #[pyo3(name = "close")]
+    fn py_close(&mut self) {
+        self.release();
+    }
+
+    #[getter]
+    #[pyo3(name = "is_released")]
+    fn py_is_released(&self) -> bool {
+        self.is_released()
+    }
+}
+
 #[pyfunction()]
 #[pyo3(name = "logger_flush")]
 pub fn py_logger_flush() {
@@ -0,0 +1,370 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data quality monitoring for incoming market data feeds.
+//!
+//! The [`DataQualityMonitor`] validates quotes, trades and order books as they arrive, detecting
+//! crossed markets, non-positive prices, frozen feeds and extreme message bursts. Offending
+//! updates are quarantined (the check returns `false` so the caller can drop them) and a
+//! [`DataQualityEvent`] is published on the instrument's data quality topic, so strategies can
+//! degrade gracefully rather than act on bad data.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+    orderbook::OrderBook,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::msgbus::{self, MStr, Topic};
+
+/// The kind of data quality issue detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DataQualityIssue {
+    /// The best bid is above the best ask.
+    CrossedMarket,
+    /// A price was zero or negative.
+    NonPositivePrice,
+    /// No update has been received within the stale threshold.
+    FrozenFeed,
+    /// Updates are arriving faster than the burst limit.
+    MessageBurst,
+    /// A previously frozen or bursting feed has returned to normal.
+    FeedRecovered,
+}
+
+impl Display for DataQualityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// An event describing a data quality issue for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataQualityEvent {
+    pub instrument_id: InstrumentId,
+    pub issue: DataQualityIssue,
+    /// Whether the offending update was quarantined.
+    pub quarantined: bool,
+    pub ts_event: UnixNanos,
+}
+
+/// Configuration for a [`DataQualityMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataQualityConfig {
+    /// The maximum updates per instrument within `burst_interval_ns` before a burst is flagged.
+    pub max_messages_per_interval: usize,
+    /// The burst measurement interval (nanoseconds).
+    pub burst_interval_ns: u64,
+    /// Whether updates exceeding the burst limit are quarantined.
+    pub quarantine_bursts: bool,
+    /// The time without updates after which a feed is considered frozen (nanoseconds).
+    pub stale_threshold_ns: u64,
+}
+
+impl Default for DataQualityConfig {
+    fn default() -> Self {
+        Self {
+            max_messages_per_interval: 1_000,
+            burst_interval_ns: 1_000_000_000,
+            quarantine_bursts: false,
+            stale_threshold_ns: 60_000_000_000,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct FeedState {
+    last_ts: Option<UnixNanos>,
+    recent: VecDeque<UnixNanos>,
+    bursting: bool,
+    frozen: bool,
+}
+
+/// Returns the data quality topic for `instrument_id`.
+#[must_use]
+pub fn get_data_quality_topic(instrument_id: InstrumentId) -> MStr<Topic> {
+    format!("events.data_quality.{instrument_id}").into()
+}
+
+/// Monitors incoming market data for anomalies, quarantining bad updates.
+#[derive(Clone, Debug, Default)]
+pub struct DataQualityMonitor {
+    config: DataQualityConfig,
+    feeds: AHashMap<InstrumentId, FeedState>,
+    quarantined_count: u64,
+    issue_counts: AHashMap<DataQualityIssue, u64>,
+}
+
+impl DataQualityMonitor {
+    /// Creates a new [`DataQualityMonitor`] instance.
+    #[must_use]
+    pub fn new(config: DataQualityConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the configuration.
+    #[must_use]
+    pub const fn config(&self) -> &DataQualityConfig {
+        &self.config
+    }
+
+    /// Returns the number of quarantined updates.
+    #[must_use]
+    pub const fn quarantined_count(&self) -> u64 {
+        self.quarantined_count
+    }
+
+    /// Returns the number of times `issue` has been detected.
+    #[must_use]
+    pub fn issue_count(&self, issue: DataQualityIssue) -> u64 {
+        self.issue_counts.get(&issue).copied().unwrap_or_default()
+    }
+
+    /// Checks a quote, returning whether it should be accepted.
+    pub fn check_quote(&mut self, quote: &QuoteTick) -> bool {
+        let instrument_id = quote.instrument_id;
+        let ts = quote.ts_event;
+
+        if quote.bid_price.as_f64() <= 0.0 || quote.ask_price.as_f64() <= 0.0 {
+            self.report(instrument_id, DataQualityIssue::NonPositivePrice, true, ts);
+            return false;
+        }
+        if quote.bid_price > quote.ask_price {
+            self.report(instrument_id, DataQualityIssue::CrossedMarket, true, ts);
+            return false;
+        }
+        self.record_update(instrument_id, ts)
+    }
+
+    /// Checks a trade, returning whether it should be accepted.
+    pub fn check_trade(&mut self, trade: &TradeTick) -> bool {
+        if trade.price.as_f64() <= 0.0 {
+            self.report(
+                trade.instrument_id,
+                DataQualityIssue::NonPositivePrice,
+                true,
+                trade.ts_event,
+            );
+            return false;
+        }
+        self.record_update(trade.instrument_id, trade.ts_event)
+    }
+
+    /// Checks an order book after an update, returning whether it is usable.
+    ///
+    /// A crossed book is reported but not counted as a feed update.
+    pub fn check_book(&mut self, book: &OrderBook, ts_event: UnixNanos) -> bool {
+        if let (Some(bid), Some(ask)) = (book.best_bid_price(), book.best_ask_price())
+            && bid > ask
+        {
+            self.report(
+                book.instrument_id,
+                DataQualityIssue::CrossedMarket,
+                true,
+                ts_event,
+            );
+            return false;
+        }
+        self.record_update(book.instrument_id, ts_event)
+    }
+
+    /// Checks all monitored feeds for staleness at `ts_now` (e.g. from a timer), returning the
+    /// events for feeds which have newly frozen.
+    pub fn check_frozen(&mut self, ts_now: UnixNanos) -> Vec<DataQualityEvent> {
+        let threshold = self.config.stale_threshold_ns;
+        let newly_frozen: Vec<InstrumentId> = self
+            .feeds
+            .iter_mut()
+            .filter_map(|(instrument_id, feed)| {
+                let last_ts = feed.last_ts?;
+                let stale = ts_now.as_u64().saturating_sub(last_ts.as_u64()) >= threshold;
+                if stale && !feed.frozen {
+                    feed.frozen = true;
+                    Some(*instrument_id)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        newly_frozen
+            .into_iter()
+            .map(|instrument_id| {
+                self.report(instrument_id, DataQualityIssue::FrozenFeed, false, ts_now)
+            })
+            .collect()
+    }
+
+    /// Returns whether the feed for `instrument_id` is currently considered healthy.
+    #[must_use]
+    pub fn is_healthy(&self, instrument_id: &InstrumentId) -> bool {
+        self.feeds
+            .get(instrument_id)
+            .is_none_or(|feed| !feed.frozen && !feed.bursting)
+    }
+
+    /// Clears all feed state and counters.
+    pub fn reset(&mut self) {
+        self.feeds.clear();
+        self.quarantined_count = 0;
+        self.issue_counts.clear();
+    }
+
+    fn record_update(&mut self, instrument_id: InstrumentId, ts: UnixNanos) -> bool {
+        let config = self.config;
+        let feed = self.feeds.entry(instrument_id).or_default();
+        let was_frozen = feed.frozen;
+        let was_bursting = feed.bursting;
+
+        feed.last_ts = Some(feed.last_ts.map_or(ts, |last| last.max(ts)));
+        feed.frozen = false;
+        feed.recent.push_back(ts);
+        while feed.recent.front().is_some_and(|front| {
+            ts.as_u64().saturating_sub(front.as_u64()) >= config.burst_interval_ns
+        }) {
+            feed.recent.pop_front();
+        }
+        feed.bursting = feed.recent.len() > config.max_messages_per_interval;
+        let bursting = feed.bursting;
+
+        if bursting && !was_bursting {
+            self.report(
+                instrument_id,
+                DataQualityIssue::MessageBurst,
+                config.quarantine_bursts,
+                ts,
+            );
+        } else if (was_frozen || was_bursting) && !bursting {
+            self.report(instrument_id, DataQualityIssue::FeedRecovered, false, ts);
+        }
+
+        if bursting && config.quarantine_bursts {
+            if was_bursting {
+                self.quarantined_count += 1;
+            }
+            return false;
+        }
+        true
+    }
+
+    fn report(
+        &mut self,
+        instrument_id: InstrumentId,
+        issue: DataQualityIssue,
+        quarantined: bool,
+        ts_event: UnixNanos,
+    ) -> DataQualityEvent {
+        if quarantined {
+            self.quarantined_count += 1;
+        }
+        *self.issue_counts.entry(issue).or_default() += 1;
+
+        let event = DataQualityEvent {
+            instrument_id,
+            issue,
+            quarantined,
+            ts_event,
+        };
+        log::warn!("Data quality {issue} for {instrument_id} at {ts_event}");
+        msgbus::publish_any(get_data_quality_topic(instrument_id), &event);
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::{Price, Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    #[case("1.00010", "1.00000", DataQualityIssue::CrossedMarket)]
+    #[case("0.00000", "1.00000", DataQualityIssue::NonPositivePrice)]
+    fn test_bad_quotes_quarantined(
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] issue: DataQualityIssue,
+    ) {
+        let mut monitor = DataQualityMonitor::default();
+
+        assert!(!monitor.check_quote(&quote(bid, ask, 1)));
+        assert_eq!(monitor.issue_count(issue), 1);
+        assert_eq!(monitor.quarantined_count(), 1);
+    }
+
+    #[rstest]
+    fn test_frozen_feed_and_recovery() {
+        let mut monitor = DataQualityMonitor::new(DataQualityConfig {
+            stale_threshold_ns: 10,
+            ..Default::default()
+        });
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        assert!(monitor.check_quote(&quote("1.00000", "1.00010", 1)));
+
+        assert!(monitor.check_frozen(5.into()).is_empty());
+        let events = monitor.check_frozen(11.into());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].issue, DataQualityIssue::FrozenFeed);
+        assert!(monitor.check_frozen(20.into()).is_empty());
+        assert!(!monitor.is_healthy(&instrument_id));
+
+        assert!(monitor.check_quote(&quote("1.00000", "1.00010", 21)));
+
+        assert!(monitor.is_healthy(&instrument_id));
+        assert_eq!(monitor.issue_count(DataQualityIssue::FeedRecovered), 1);
+    }
+
+    #[rstest]
+    fn test_message_burst_quarantine() {
+        let mut monitor = DataQualityMonitor::new(DataQualityConfig {
+            max_messages_per_interval: 2,
+            burst_interval_ns: 100,
+            quarantine_bursts: true,
+            ..Default::default()
+        });
+
+        let accepted: Vec<bool> = (1..=4)
+            .map(|ts| monitor.check_quote(&quote("1.00000", "1.00010", ts)))
+            .collect();
+
+        assert_eq!(accepted, vec![true, true, false, false]);
+        assert_eq!(monitor.issue_count(DataQualityIssue::MessageBurst), 1);
+        assert_eq!(monitor.quarantined_count(), 2);
+
+        assert!(monitor.check_quote(&quote("1.00000", "1.00010", 500)));
+        assert_eq!(monitor.issue_count(DataQualityIssue::FeedRecovered), 1);
+    }
+}
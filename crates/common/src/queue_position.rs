@@ -0,0 +1,709 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Queue position and fill probability estimation for resting limit orders.
+//!
+//! The [`QueuePositionEstimator`] tracks the quantity queued ahead of each of a strategy's
+//! accepted limit orders. With L3 (MBO) books the individual orders ahead are known and are
+//! removed as they cancel or execute. With L2 (MBP) books only level sizes are known, so
+//! decreases not explained by trades at the level are attributed pro-rata between the quantity
+//! ahead of and behind the order, and increases are assumed to join behind it.
+//!
+//! Fill probability over the configured horizon assumes traded volume against the order's side
+//! is exponentially distributed with the mean observed over a trailing window. Estimates are
+//! published as [`Signal`]s named [`QUEUE_FILL_SIGNAL_NAME`] with a JSON encoded
+//! [`QueueEstimate`] value.
+
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use ahash::AHashMap;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{OrderBookDelta, OrderBookDeltas, TradeTick},
+    enums::{AggressorSide, BookAction, BookType, OrderSide},
+    events::OrderEventAny,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId},
+    orderbook::OrderBook,
+    orders::{Order, OrderAny},
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    cache::Cache,
+    msgbus::{
+        self, MStr, Topic, TypedHandler,
+        switchboard::{get_book_deltas_topic, get_event_orders_topic, get_trades_topic},
+    },
+    signal::Signal,
+};
+
+/// The name of the published fill probability signals.
+pub const QUEUE_FILL_SIGNAL_NAME: &str = "queue_fill_probability";
+
+/// Returns the topic queue fill probability signals for `strategy_id` are published on.
+#[must_use]
+pub fn get_queue_signal_topic(strategy_id: StrategyId) -> MStr<Topic> {
+    format!("data.signal.{QUEUE_FILL_SIGNAL_NAME}.{strategy_id}").into()
+}
+
+/// Configuration for the [`QueuePositionEstimator`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueueEstimatorConfig {
+    /// The horizon the fill probability is estimated over (nanoseconds).
+    pub horizon_ns: u64,
+    /// The trailing window used to measure the traded volume rate (nanoseconds).
+    pub rate_window_ns: u64,
+    /// The minimum change in fill probability before an estimate is republished.
+    pub min_probability_change: f64,
+}
+
+impl Default for QueueEstimatorConfig {
+    fn default() -> Self {
+        Self {
+            horizon_ns: 60_000_000_000,
+            rate_window_ns: 300_000_000_000,
+            min_probability_change: 0.01,
+        }
+    }
+}
+
+/// The estimated queue position and fill probability of a resting order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueueEstimate {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub side: OrderSide,
+    pub price: Price,
+    /// The quantity estimated to be queued ahead of the order.
+    pub qty_ahead: f64,
+    /// The order's remaining quantity.
+    pub leaves_qty: f64,
+    /// Whether the estimate is derived from individual orders (L3) rather than level sizes.
+    pub is_exact: bool,
+    /// The probability of a complete fill within the configured horizon.
+    pub fill_probability: f64,
+    /// UNIX timestamp (nanoseconds) of the data the estimate was derived from.
+    pub ts_event: UnixNanos,
+}
+
+#[derive(Debug)]
+struct TrackedOrder {
+    instrument_id: InstrumentId,
+    side: OrderSide,
+    price: Price,
+    leaves_qty: f64,
+    /// The individual orders ahead with their sizes, for L3 books.
+    orders_ahead: Option<IndexMap<u64, f64>>,
+    /// The quantity ahead, for L2 books.
+    qty_ahead: f64,
+    /// The last observed level size, for L2 books.
+    level_size: f64,
+    /// Traded quantity at the order's price not yet reflected in a level update.
+    unmatched_trade_qty: f64,
+    last_published: Option<f64>,
+}
+
+impl TrackedOrder {
+    fn from_book(order: &OrderAny, price: Price, book: Option<&OrderBook>) -> Self {
+        let side = order.order_side();
+        let level = book.and_then(|book| match side {
+            OrderSide::Buy => book.bids(None).find(|level| level.price.value == price),
+            _ => book.asks(None).find(|level| level.price.value == price),
+        });
+        let is_l3 = book.is_some_and(|book| book.book_type == BookType::L3_MBO);
+
+        let orders_ahead = is_l3.then(|| {
+            level
+                .map(|level| {
+                    level
+                        .iter()
+                        .map(|o| (o.order_id, o.size.as_f64()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        });
+        let level_size = level.map_or(0.0, |level| level.size());
+
+        Self {
+            instrument_id: order.instrument_id(),
+            side,
+            price,
+            leaves_qty: order.leaves_qty().as_f64(),
+            orders_ahead,
+            qty_ahead: level_size,
+            level_size,
+            unmatched_trade_qty: 0.0,
+            last_published: None,
+        }
+    }
+
+    fn qty_ahead(&self) -> f64 {
+        match &self.orders_ahead {
+            Some(orders) => orders.values().sum(),
+            None => self.qty_ahead,
+        }
+    }
+
+    fn apply_delta(&mut self, delta: &OrderBookDelta) -> bool {
+        if delta.action == BookAction::Clear {
+            if let Some(orders) = &mut self.orders_ahead {
+                orders.clear();
+            }
+            self.qty_ahead = 0.0;
+            self.level_size = 0.0;
+            return true;
+        }
+        if delta.order.side != self.side || delta.order.price != self.price {
+            return false;
+        }
+
+        if let Some(orders) = &mut self.orders_ahead {
+            let order_id = delta.order.order_id;
+            let Some(size) = orders.get_mut(&order_id) else {
+                return false;
+            };
+            let new_size = delta.order.size.as_f64();
+            match delta.action {
+                // An order which grows loses its priority and joins behind
+                BookAction::Update if new_size > 0.0 && new_size <= *size => *size = new_size,
+                _ => {
+                    orders.shift_remove(&order_id);
+                }
+            }
+            return true;
+        }
+
+        let new_size = match delta.action {
+            BookAction::Delete => 0.0,
+            _ => delta.order.size.as_f64(),
+        };
+        let decrease = self.level_size - new_size;
+        self.level_size = new_size;
+        if decrease <= 0.0 {
+            return false;
+        }
+
+        // Decreases are first explained by trades already consumed from the front
+        let traded = decrease.min(self.unmatched_trade_qty);
+        self.unmatched_trade_qty -= traded;
+        let canceled = decrease - traded;
+        let others = (new_size + canceled - self.leaves_qty).max(0.0);
+        if canceled > 0.0 && others > 0.0 {
+            let share = (self.qty_ahead / others).min(1.0);
+            self.qty_ahead = (self.qty_ahead - canceled * share).max(0.0);
+        }
+        self.qty_ahead = self.qty_ahead.min(new_size);
+        true
+    }
+
+    fn apply_trade(&mut self, trade: &TradeTick) -> bool {
+        if self.orders_ahead.is_some() || trade.price != self.price {
+            return false;
+        }
+        let qty = trade.size.as_f64();
+        self.qty_ahead = (self.qty_ahead - qty).max(0.0);
+        self.unmatched_trade_qty += qty;
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct EstimatorState {
+    orders: IndexMap<ClientOrderId, TrackedOrder>,
+    /// Trailing trades per instrument as (ts_event, aggressor_side, size).
+    trades: AHashMap<InstrumentId, VecDeque<(UnixNanos, AggressorSide, f64)>>,
+    data_handlers: AHashMap<InstrumentId, (TypedHandler<OrderBookDeltas>, TypedHandler<TradeTick>)>,
+    order_handler: Option<TypedHandler<OrderEventAny>>,
+}
+
+/// Estimates queue positions and fill probabilities of a strategy's resting limit orders.
+#[derive(Clone)]
+pub struct QueuePositionEstimator {
+    strategy_id: StrategyId,
+    config: QueueEstimatorConfig,
+    cache: Rc<RefCell<Cache>>,
+    state: Rc<RefCell<EstimatorState>>,
+}
+
+impl std::fmt::Debug for QueuePositionEstimator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(QueuePositionEstimator))
+            .field("strategy_id", &self.strategy_id)
+            .field("config", &self.config)
+            .field("tracked", &self.state.borrow().orders.len())
+            .finish()
+    }
+}
+
+impl QueuePositionEstimator {
+    /// Creates a new [`QueuePositionEstimator`] instance.
+    #[must_use]
+    pub fn new(
+        strategy_id: StrategyId,
+        config: QueueEstimatorConfig,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        Self {
+            strategy_id,
+            config,
+            cache,
+            state: Rc::new(RefCell::new(EstimatorState::default())),
+        }
+    }
+
+    /// Subscribes to the strategy's order events.
+    ///
+    /// Book deltas and trades are subscribed per instrument while orders for it are tracked.
+    pub fn subscribe(&self) {
+        if self.state.borrow().order_handler.is_some() {
+            log::warn!("Queue position estimator already subscribed");
+            return;
+        }
+
+        let estimator = self.clone();
+        let handler = TypedHandler::from(move |event: &OrderEventAny| {
+            estimator.on_order_event(event);
+        });
+        let topic = get_event_orders_topic(self.strategy_id);
+        msgbus::subscribe_order_events(topic.into(), handler.clone(), None);
+        self.state.borrow_mut().order_handler = Some(handler);
+    }
+
+    /// Unsubscribes from all order events and market data.
+    pub fn unsubscribe(&self) {
+        let mut state = self.state.borrow_mut();
+        if let Some(handler) = state.order_handler.take() {
+            let topic = get_event_orders_topic(self.strategy_id);
+            msgbus::unsubscribe_order_events(topic.into(), &handler);
+        }
+        for (instrument_id, (deltas, trades)) in state.data_handlers.drain() {
+            msgbus::unsubscribe_book_deltas(get_book_deltas_topic(instrument_id).into(), &deltas);
+            msgbus::unsubscribe_trades(get_trades_topic(instrument_id).into(), &trades);
+        }
+    }
+
+    /// Returns the current estimate for `client_order_id`, if tracked.
+    #[must_use]
+    pub fn estimate(&self, client_order_id: &ClientOrderId) -> Option<QueueEstimate> {
+        let state = self.state.borrow();
+        let order = state.orders.get(client_order_id)?;
+        Some(self.build_estimate(&state, *client_order_id, order, UnixNanos::default()))
+    }
+
+    /// Handles an order `event` for the strategy.
+    pub fn on_order_event(&self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        match event {
+            OrderEventAny::Accepted(_) | OrderEventAny::Updated(_) => {
+                let tracked = {
+                    let cache = self.cache.borrow();
+                    cache.order(&client_order_id).and_then(|order| {
+                        let price = order.price()?;
+                        let mut state = self.state.borrow_mut();
+                        match state.orders.get_mut(&client_order_id) {
+                            // An amendment keeping the price and not increasing size keeps priority
+                            Some(existing)
+                                if existing.price == price
+                                    && order.leaves_qty().as_f64() <= existing.leaves_qty =>
+                            {
+                                existing.leaves_qty = order.leaves_qty().as_f64();
+                                None
+                            }
+                            _ => Some(TrackedOrder::from_book(
+                                order,
+                                price,
+                                cache.order_book(&order.instrument_id()),
+                            )),
+                        }
+                    })
+                };
+                if let Some(tracked) = tracked {
+                    self.ensure_data_subscriptions(tracked.instrument_id);
+                    self.state
+                        .borrow_mut()
+                        .orders
+                        .insert(client_order_id, tracked);
+                }
+                self.publish_if_changed(client_order_id, event.ts_event());
+            }
+            OrderEventAny::Filled(fill) => {
+                let closed = {
+                    let mut state = self.state.borrow_mut();
+                    let Some(tracked) = state.orders.get_mut(&client_order_id) else {
+                        return;
+                    };
+                    tracked.leaves_qty = (tracked.leaves_qty - fill.last_qty.as_f64()).max(0.0);
+                    tracked.qty_ahead = 0.0;
+                    tracked.leaves_qty <= 0.0
+                };
+                if closed {
+                    self.untrack(&client_order_id);
+                } else {
+                    self.publish_if_changed(client_order_id, fill.ts_event);
+                }
+            }
+            OrderEventAny::Canceled(_) | OrderEventAny::Expired(_) | OrderEventAny::Rejected(_) => {
+                self.untrack(&client_order_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles order book `deltas`, updating the queue positions at affected prices.
+    pub fn on_book_deltas(&self, deltas: &OrderBookDeltas) {
+        let changed: Vec<ClientOrderId> = {
+            let mut state = self.state.borrow_mut();
+            state
+                .orders
+                .iter_mut()
+                .filter(|(_, order)| order.instrument_id == deltas.instrument_id)
+                .filter_map(|(id, order)| {
+                    let mut changed = false;
+                    for delta in &deltas.deltas {
+                        changed |= order.apply_delta(delta);
+                    }
+                    changed.then_some(*id)
+                })
+                .collect()
+        };
+        for client_order_id in changed {
+            self.publish_if_changed(client_order_id, deltas.ts_event);
+        }
+    }
+
+    /// Handles a `trade`, updating the traded volume rate and L2 queue positions.
+    pub fn on_trade(&self, trade: &TradeTick) {
+        let changed: Vec<ClientOrderId> = {
+            let mut state = self.state.borrow_mut();
+            let window = self.config.rate_window_ns;
+            let trades = state.trades.entry(trade.instrument_id).or_default();
+            trades.push_back((trade.ts_event, trade.aggressor_side, trade.size.as_f64()));
+            while trades.front().is_some_and(|(ts, _, _)| {
+                trade.ts_event.as_u64().saturating_sub(ts.as_u64()) > window
+            }) {
+                trades.pop_front();
+            }
+
+            state
+                .orders
+                .iter_mut()
+                .filter(|(_, order)| order.instrument_id == trade.instrument_id)
+                .filter_map(|(id, order)| order.apply_trade(trade).then_some(*id))
+                .collect()
+        };
+        for client_order_id in changed {
+            self.publish_if_changed(client_order_id, trade.ts_event);
+        }
+    }
+
+    fn ensure_data_subscriptions(&self, instrument_id: InstrumentId) {
+        if self
+            .state
+            .borrow()
+            .data_handlers
+            .contains_key(&instrument_id)
+        {
+            return;
+        }
+
+        let estimator = self.clone();
+        let deltas = TypedHandler::from(move |deltas: &OrderBookDeltas| {
+            estimator.on_book_deltas(deltas);
+        });
+        let estimator = self.clone();
+        let trades = TypedHandler::from(move |trade: &TradeTick| {
+            estimator.on_trade(trade);
+        });
+        msgbus::subscribe_book_deltas(
+            get_book_deltas_topic(instrument_id).into(),
+            deltas.clone(),
+            None,
+        );
+        msgbus::subscribe_trades(get_trades_topic(instrument_id).into(), trades.clone(), None);
+        self.state
+            .borrow_mut()
+            .data_handlers
+            .insert(instrument_id, (deltas, trades));
+    }
+
+    fn untrack(&self, client_order_id: &ClientOrderId) {
+        let mut state = self.state.borrow_mut();
+        let Some(order) = state.orders.shift_remove(client_order_id) else {
+            return;
+        };
+
+        let instrument_id = order.instrument_id;
+        if state
+            .orders
+            .values()
+            .any(|o| o.instrument_id == instrument_id)
+        {
+            return;
+        }
+        state.trades.remove(&instrument_id);
+        if let Some((deltas, trades)) = state.data_handlers.remove(&instrument_id) {
+            msgbus::unsubscribe_book_deltas(get_book_deltas_topic(instrument_id).into(), &deltas);
+            msgbus::unsubscribe_trades(get_trades_topic(instrument_id).into(), &trades);
+        }
+    }
+
+    fn build_estimate(
+        &self,
+        state: &EstimatorState,
+        client_order_id: ClientOrderId,
+        order: &TrackedOrder,
+        ts_event: UnixNanos,
+    ) -> QueueEstimate {
+        // Resting bids are filled by sellers and resting asks by buyers
+        let filling_aggressor = match order.side {
+            OrderSide::Buy => AggressorSide::Seller,
+            _ => AggressorSide::Buyer,
+        };
+        let window_volume: f64 = state
+            .trades
+            .get(&order.instrument_id)
+            .map(|trades| {
+                trades
+                    .iter()
+                    .filter(|(_, side, _)| *side == filling_aggressor)
+                    .map(|(_, _, size)| size)
+                    .sum()
+            })
+            .unwrap_or_default();
+
+        let qty_ahead = order.qty_ahead();
+        let expected_volume =
+            window_volume * self.config.horizon_ns as f64 / self.config.rate_window_ns as f64;
+        let fill_probability = if expected_volume > 0.0 {
+            (-(qty_ahead + order.leaves_qty) / expected_volume).exp()
+        } else {
+            0.0
+        };
+
+        QueueEstimate {
+            client_order_id,
+            instrument_id: order.instrument_id,
+            side: order.side,
+            price: order.price,
+            qty_ahead,
+            leaves_qty: order.leaves_qty,
+            is_exact: order.orders_ahead.is_some(),
+            fill_probability,
+            ts_event,
+        }
+    }
+
+    fn publish_if_changed(&self, client_order_id: ClientOrderId, ts_event: UnixNanos) {
+        let estimate = {
+            let mut state = self.state.borrow_mut();
+            let Some(order) = state.orders.get(&client_order_id) else {
+                return;
+            };
+            let estimate = self.build_estimate(&state, client_order_id, order, ts_event);
+            let order = state
+                .orders
+                .get_mut(&client_order_id)
+                .expect("order was tracked");
+            if order.last_published.is_some_and(|last| {
+                (estimate.fill_probability - last).abs() < self.config.min_probability_change
+            }) {
+                return;
+            }
+            order.last_published = Some(estimate.fill_probability);
+            estimate
+        };
+
+        let value = match serde_json::to_string(&estimate) {
+            Ok(value) => value,
+            Err(e) => {
+                log::error!("Failed to encode queue estimate: {e}");
+                return;
+            }
+        };
+        let signal = Signal::new(
+            Ustr::from(QUEUE_FILL_SIGNAL_NAME),
+            value,
+            ts_event,
+            ts_event,
+        );
+        msgbus::publish_any(get_queue_signal_topic(self.strategy_id), &signal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BookOrder,
+        enums::OrderType,
+        identifiers::{AccountId, TradeId, VenueOrderId},
+        instruments::{CurrencyPair, stubs::audusd_sim},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn setup(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        level: &[(u64, u64)],
+    ) -> (QueuePositionEstimator, OrderEventAny) {
+        let mut book = OrderBook::new(instrument_id, book_type);
+        for (order_id, size) in level {
+            let order = BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00000"),
+                Quantity::from(*size),
+                *order_id,
+            );
+            book.add(order, 0, 0, UnixNanos::default());
+        }
+
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        let accepted = TestOrderEventStubs::accepted(&order, account_id, VenueOrderId::from("V-1"));
+        order.apply(accepted.clone()).unwrap();
+
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache.borrow_mut().add_order_book(book).unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        let estimator = QueuePositionEstimator::new(
+            order.strategy_id(),
+            QueueEstimatorConfig::default(),
+            cache,
+        );
+        estimator.on_order_event(&accepted);
+        (estimator, accepted)
+    }
+
+    fn delta(
+        instrument_id: InstrumentId,
+        action: BookAction,
+        order_id: u64,
+        size: u64,
+    ) -> OrderBookDeltas {
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(size),
+            order_id,
+        );
+        let delta = OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            0,
+            1,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        OrderBookDeltas::new(instrument_id, vec![delta])
+    }
+
+    fn trade(instrument_id: InstrumentId, size: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from("1.00000"),
+            Quantity::from(size),
+            AggressorSide::Seller,
+            TradeId::from("T-1"),
+            UnixNanos::from(2),
+            UnixNanos::from(2),
+        )
+    }
+
+    #[rstest]
+    fn test_l3_queue_tracks_orders_ahead(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (estimator, accepted) = setup(
+            instrument_id,
+            BookType::L3_MBO,
+            &[(1, 100_000), (2, 50_000)],
+        );
+        let client_order_id = accepted.client_order_id();
+
+        let estimate = estimator.estimate(&client_order_id).unwrap();
+        assert!(estimate.is_exact);
+        assert_eq!(estimate.qty_ahead, 150_000.0);
+        assert_eq!(estimate.fill_probability, 0.0);
+
+        estimator.on_book_deltas(&delta(instrument_id, BookAction::Delete, 1, 100_000));
+        estimator.on_book_deltas(&delta(instrument_id, BookAction::Update, 2, 20_000));
+        estimator.on_trade(&trade(instrument_id, 100_000));
+
+        let estimate = estimator.estimate(&client_order_id).unwrap();
+        assert_eq!(estimate.qty_ahead, 20_000.0);
+        assert!(estimate.fill_probability > 0.0);
+    }
+
+    #[rstest]
+    fn test_l2_queue_approximated_from_level_changes(audusd_sim: CurrencyPair) {
+        let instrument_id = audusd_sim.id;
+        let (estimator, accepted) = setup(instrument_id, BookType::L2_MBP, &[(1, 300_000)]);
+        let client_order_id = accepted.client_order_id();
+
+        assert_eq!(
+            estimator.estimate(&client_order_id).unwrap().qty_ahead,
+            300_000.0
+        );
+
+        // The trade consumes from the front, the following level decrease is then explained by it
+        estimator.on_trade(&trade(instrument_id, 100_000));
+        estimator.on_book_deltas(&delta(instrument_id, BookAction::Update, 1, 200_000));
+        assert_eq!(
+            estimator.estimate(&client_order_id).unwrap().qty_ahead,
+            200_000.0
+        );
+
+        estimator.on_book_deltas(&delta(instrument_id, BookAction::Delete, 1, 300_000));
+        assert_eq!(estimator.estimate(&client_order_id).unwrap().qty_ahead, 0.0);
+    }
+
+    #[rstest]
+    fn test_cancel_untracks_order(audusd_sim: CurrencyPair) {
+        let (estimator, accepted) = setup(audusd_sim.id, BookType::L2_MBP, &[(1, 300_000)]);
+        let client_order_id = accepted.client_order_id();
+        let order = estimator
+            .cache
+            .borrow()
+            .order(&client_order_id)
+            .cloned()
+            .unwrap();
+
+        estimator.on_order_event(&TestOrderEventStubs::canceled(
+            &order,
+            AccountId::from("SIM-001"),
+            None,
+        ));
+
+        assert!(estimator.estimate(&client_order_id).is_none());
+        assert!(estimator.state.borrow().data_handlers.is_empty());
+    }
+}
@@ -0,0 +1,576 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconnection protocol for live data clients with subscription restoration.
+//!
+//! The [`ReconnectManager`] tracks the active subscriptions of each data client and the time of
+//! the last data received per instrument. When a client disconnects its instruments are marked
+//! stale. On reconnection a [`ReconnectPlan`] is produced: order book snapshots to re-request,
+//! subscriptions to restore and, for clients that support it, historical requests to gap-fill
+//! the quotes, trades and bars missed while disconnected. Instruments are no longer stale once
+//! fresh data (or, for order books, a snapshot) arrives, after which the client is recovered.
+//!
+//! Each stage transition is recorded as a [`ReconnectEvent`] and published on the client's
+//! reconnect topic for observability.
+
+use ahash::{AHashMap, AHashSet};
+use indexmap::{IndexMap, IndexSet};
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::identifiers::{ClientId, InstrumentId};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::{
+    messages::data::{
+        RequestBars, RequestBookSnapshot, RequestCommand, RequestQuotes, RequestTrades,
+        SubscribeCommand, UnsubscribeCommand,
+    },
+    msgbus::{self, MStr, Topic},
+};
+
+/// The stage of a data client's reconnection.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReconnectStage {
+    #[default]
+    Connected,
+    Disconnected,
+    /// Order book snapshots have been re-requested.
+    Resnapshotting,
+    /// Subscriptions have been restored.
+    Resubscribing,
+    /// Missed history has been requested.
+    GapFilling,
+    /// All instruments have received fresh data since reconnecting.
+    Recovered,
+}
+
+/// Represents a reconnection stage transition for a data client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconnectEvent {
+    pub client_id: ClientId,
+    pub stage: ReconnectStage,
+    /// The instruments affected by the stage.
+    pub instruments: Vec<InstrumentId>,
+    pub ts_event: UnixNanos,
+}
+
+/// The commands to issue to a data client after it reconnects.
+#[derive(Clone, Debug)]
+pub struct ReconnectPlan {
+    pub client_id: ClientId,
+    pub snapshots: Vec<RequestBookSnapshot>,
+    pub subscriptions: Vec<SubscribeCommand>,
+    pub gap_fills: Vec<RequestCommand>,
+}
+
+/// Returns the reconnect topic for the data client `client_id`.
+#[must_use]
+pub fn get_reconnect_topic(client_id: ClientId) -> MStr<Topic> {
+    format!("events.reconnect.{client_id}").into()
+}
+
+/// Tracks data client subscriptions and coordinates their restoration after reconnects.
+#[derive(Debug, Default)]
+pub struct ReconnectManager {
+    subscriptions: AHashMap<ClientId, IndexMap<String, SubscribeCommand>>,
+    gap_fill_clients: AHashSet<ClientId>,
+    stages: AHashMap<ClientId, ReconnectStage>,
+    disconnected_at: AHashMap<ClientId, UnixNanos>,
+    last_data: AHashMap<InstrumentId, UnixNanos>,
+    stale: AHashMap<ClientId, IndexSet<InstrumentId>>,
+    pending_snapshots: AHashMap<ClientId, AHashSet<InstrumentId>>,
+    trail: Vec<ReconnectEvent>,
+}
+
+impl ReconnectManager {
+    /// Creates a new [`ReconnectManager`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the venue behind `client_id` supports historical gap-fill requests.
+    pub fn set_gap_fill_supported(&mut self, client_id: ClientId, supported: bool) {
+        if supported {
+            self.gap_fill_clients.insert(client_id);
+        } else {
+            self.gap_fill_clients.remove(&client_id);
+        }
+    }
+
+    /// Records a subscription routed to `client_id`, replacing any equivalent subscription.
+    pub fn on_subscribe(&mut self, client_id: ClientId, command: SubscribeCommand) {
+        let (key, _) = subscribe_key(&command);
+        self.subscriptions
+            .entry(client_id)
+            .or_default()
+            .insert(key, command);
+    }
+
+    /// Removes the subscription matching `command` for `client_id`.
+    pub fn on_unsubscribe(&mut self, client_id: ClientId, command: &UnsubscribeCommand) {
+        if let Some(subscriptions) = self.subscriptions.get_mut(&client_id) {
+            subscriptions.shift_remove(&unsubscribe_key(command));
+        }
+    }
+
+    /// Returns the active subscriptions for `client_id` in subscription order.
+    #[must_use]
+    pub fn subscriptions(&self, client_id: &ClientId) -> Vec<&SubscribeCommand> {
+        self.subscriptions
+            .get(client_id)
+            .map(|subscriptions| subscriptions.values().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the reconnection stage of `client_id`.
+    #[must_use]
+    pub fn stage(&self, client_id: &ClientId) -> ReconnectStage {
+        self.stages.get(client_id).copied().unwrap_or_default()
+    }
+
+    /// Returns whether `instrument_id` is stale on any client.
+    #[must_use]
+    pub fn is_stale(&self, instrument_id: &InstrumentId) -> bool {
+        self.stale
+            .values()
+            .any(|instruments| instruments.contains(instrument_id))
+    }
+
+    /// Returns the recorded stage transitions, oldest first.
+    #[must_use]
+    pub fn trail(&self) -> &[ReconnectEvent] {
+        &self.trail
+    }
+
+    /// Handles `client_id` disconnecting, marking its subscribed instruments stale.
+    pub fn on_disconnect(&mut self, client_id: ClientId, ts: UnixNanos) {
+        let instruments: IndexSet<InstrumentId> = self
+            .subscriptions(&client_id)
+            .into_iter()
+            .filter_map(|command| subscribe_key(command).1)
+            .collect();
+
+        self.disconnected_at.insert(client_id, ts);
+        self.pending_snapshots.remove(&client_id);
+        self.stale.insert(client_id, instruments.clone());
+        self.transition(
+            client_id,
+            ReconnectStage::Disconnected,
+            instruments.into_iter().collect(),
+            ts,
+        );
+    }
+
+    /// Handles `client_id` reconnecting, returning the commands to restore its data.
+    ///
+    /// Gap-fill requests span from the last data received for each instrument (or the time of
+    /// disconnection) to `ts`.
+    pub fn on_reconnect(&mut self, client_id: ClientId, ts: UnixNanos) -> ReconnectPlan {
+        let disconnected_at = self.disconnected_at.remove(&client_id).unwrap_or(ts);
+        let gap_fill = self.gap_fill_clients.contains(&client_id);
+        let subscriptions: Vec<SubscribeCommand> = self
+            .subscriptions(&client_id)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let mut snapshots = Vec::new();
+        let mut gap_fills = Vec::new();
+        for command in &subscriptions {
+            let start = |instrument_id: &InstrumentId| {
+                let last = self
+                    .last_data
+                    .get(instrument_id)
+                    .copied()
+                    .unwrap_or(disconnected_at);
+                Some(last.to_datetime_utc())
+            };
+            let end = Some(ts.to_datetime_utc());
+            let client = Some(client_id);
+
+            match command {
+                SubscribeCommand::BookDeltas(cmd) => snapshots.push(RequestBookSnapshot::new(
+                    cmd.instrument_id,
+                    cmd.depth,
+                    client,
+                    UUID4::new(),
+                    ts,
+                    None,
+                )),
+                SubscribeCommand::BookDepth10(cmd) => snapshots.push(RequestBookSnapshot::new(
+                    cmd.instrument_id,
+                    None,
+                    client,
+                    UUID4::new(),
+                    ts,
+                    None,
+                )),
+                SubscribeCommand::BookSnapshots(cmd) => {
+                    snapshots.push(RequestBookSnapshot::new(
+                        cmd.instrument_id,
+                        None,
+                        client,
+                        UUID4::new(),
+                        ts,
+                        None,
+                    ));
+                }
+                SubscribeCommand::Quotes(cmd) if gap_fill => {
+                    gap_fills.push(RequestCommand::Quotes(RequestQuotes::new(
+                        cmd.instrument_id,
+                        start(&cmd.instrument_id),
+                        end,
+                        None,
+                        client,
+                        UUID4::new(),
+                        ts,
+                        None,
+                    )));
+                }
+                SubscribeCommand::Trades(cmd) if gap_fill => {
+                    gap_fills.push(RequestCommand::Trades(RequestTrades::new(
+                        cmd.instrument_id,
+                        start(&cmd.instrument_id),
+                        end,
+                        None,
+                        client,
+                        UUID4::new(),
+                        ts,
+                        None,
+                    )));
+                }
+                SubscribeCommand::Bars(cmd) if gap_fill => {
+                    gap_fills.push(RequestCommand::Bars(RequestBars::new(
+                        cmd.bar_type,
+                        start(&cmd.bar_type.instrument_id()),
+                        end,
+                        None,
+                        client,
+                        UUID4::new(),
+                        ts,
+                        None,
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        let snapshot_instruments: Vec<InstrumentId> =
+            snapshots.iter().map(|r| r.instrument_id).collect();
+        self.pending_snapshots
+            .insert(client_id, snapshot_instruments.iter().copied().collect());
+        if !snapshots.is_empty() {
+            self.transition(
+                client_id,
+                ReconnectStage::Resnapshotting,
+                snapshot_instruments,
+                ts,
+            );
+        }
+
+        let instruments: Vec<InstrumentId> = subscriptions
+            .iter()
+            .filter_map(|command| subscribe_key(command).1)
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect();
+        self.transition(client_id, ReconnectStage::Resubscribing, instruments, ts);
+
+        if !gap_fills.is_empty() {
+            let instruments = gap_fills
+                .iter()
+                .filter_map(|request| match request {
+                    RequestCommand::Quotes(r) => Some(r.instrument_id),
+                    RequestCommand::Trades(r) => Some(r.instrument_id),
+                    RequestCommand::Bars(r) => Some(r.bar_type.instrument_id()),
+                    _ => None,
+                })
+                .collect();
+            self.transition(client_id, ReconnectStage::GapFilling, instruments, ts);
+        }
+
+        self.check_recovered(client_id, ts);
+
+        ReconnectPlan {
+            client_id,
+            snapshots,
+            subscriptions,
+            gap_fills,
+        }
+    }
+
+    /// Records live data received for `instrument_id` from `client_id`.
+    ///
+    /// Clears the instrument's staleness unless an order book snapshot is still pending.
+    pub fn on_data(&mut self, client_id: ClientId, instrument_id: InstrumentId, ts: UnixNanos) {
+        self.last_data.insert(instrument_id, ts);
+
+        if self.stage(&client_id) == ReconnectStage::Disconnected {
+            return;
+        }
+        let snapshot_pending = self
+            .pending_snapshots
+            .get(&client_id)
+            .is_some_and(|pending| pending.contains(&instrument_id));
+        if !snapshot_pending && let Some(stale) = self.stale.get_mut(&client_id) {
+            stale.shift_remove(&instrument_id);
+            self.check_recovered(client_id, ts);
+        }
+    }
+
+    /// Records an order book snapshot received for `instrument_id` from `client_id`.
+    pub fn on_snapshot(&mut self, client_id: ClientId, instrument_id: InstrumentId, ts: UnixNanos) {
+        if let Some(pending) = self.pending_snapshots.get_mut(&client_id) {
+            pending.remove(&instrument_id);
+        }
+        self.on_data(client_id, instrument_id, ts);
+    }
+
+    fn check_recovered(&mut self, client_id: ClientId, ts: UnixNanos) {
+        let stage = self.stage(&client_id);
+        if matches!(
+            stage,
+            ReconnectStage::Connected | ReconnectStage::Disconnected | ReconnectStage::Recovered
+        ) {
+            return;
+        }
+        if self.stale.get(&client_id).is_none_or(IndexSet::is_empty) {
+            self.stale.remove(&client_id);
+            self.transition(client_id, ReconnectStage::Recovered, Vec::new(), ts);
+        }
+    }
+
+    fn transition(
+        &mut self,
+        client_id: ClientId,
+        stage: ReconnectStage,
+        instruments: Vec<InstrumentId>,
+        ts: UnixNanos,
+    ) {
+        self.stages.insert(client_id, stage);
+        let event = ReconnectEvent {
+            client_id,
+            stage,
+            instruments,
+            ts_event: ts,
+        };
+        log::info!("Data client {client_id} reconnect stage: {stage}");
+        msgbus::publish_any(get_reconnect_topic(client_id), &event);
+        self.trail.push(event);
+    }
+}
+
+fn subscribe_key(command: &SubscribeCommand) -> (String, Option<InstrumentId>) {
+    match command {
+        SubscribeCommand::Data(cmd) => (format!("data:{}", cmd.data_type), None),
+        SubscribeCommand::Instrument(cmd) => (
+            format!("instrument:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::Instruments(cmd) => (format!("instruments:{}", cmd.venue), None),
+        SubscribeCommand::BookDeltas(cmd) => (
+            format!("book_deltas:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::BookDepth10(cmd) => (
+            format!("book_depth10:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::BookSnapshots(cmd) => (
+            format!("book_snapshots:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::Quotes(cmd) => (
+            format!("quotes:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::Trades(cmd) => (
+            format!("trades:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::Bars(cmd) => (
+            format!("bars:{}", cmd.bar_type),
+            Some(cmd.bar_type.instrument_id()),
+        ),
+        SubscribeCommand::MarkPrices(cmd) => (
+            format!("mark_prices:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::IndexPrices(cmd) => (
+            format!("index_prices:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::FundingRates(cmd) => (
+            format!("funding_rates:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::InstrumentStatus(cmd) => (
+            format!("instrument_status:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+        SubscribeCommand::InstrumentClose(cmd) => (
+            format!("instrument_close:{}", cmd.instrument_id),
+            Some(cmd.instrument_id),
+        ),
+    }
+}
+
+fn unsubscribe_key(command: &UnsubscribeCommand) -> String {
+    match command {
+        UnsubscribeCommand::Data(cmd) => format!("data:{}", cmd.data_type),
+        UnsubscribeCommand::Instrument(cmd) => format!("instrument:{}", cmd.instrument_id),
+        UnsubscribeCommand::Instruments(cmd) => format!("instruments:{}", cmd.venue),
+        UnsubscribeCommand::BookDeltas(cmd) => format!("book_deltas:{}", cmd.instrument_id),
+        UnsubscribeCommand::BookDepth10(cmd) => format!("book_depth10:{}", cmd.instrument_id),
+        UnsubscribeCommand::BookSnapshots(cmd) => {
+            format!("book_snapshots:{}", cmd.instrument_id)
+        }
+        UnsubscribeCommand::Quotes(cmd) => format!("quotes:{}", cmd.instrument_id),
+        UnsubscribeCommand::Trades(cmd) => format!("trades:{}", cmd.instrument_id),
+        UnsubscribeCommand::Bars(cmd) => format!("bars:{}", cmd.bar_type),
+        UnsubscribeCommand::MarkPrices(cmd) => format!("mark_prices:{}", cmd.instrument_id),
+        UnsubscribeCommand::IndexPrices(cmd) => format!("index_prices:{}", cmd.instrument_id),
+        UnsubscribeCommand::FundingRates(cmd) => format!("funding_rates:{}", cmd.instrument_id),
+        UnsubscribeCommand::InstrumentStatus(cmd) => {
+            format!("instrument_status:{}", cmd.instrument_id)
+        }
+        UnsubscribeCommand::InstrumentClose(cmd) => {
+            format!("instrument_close:{}", cmd.instrument_id)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::enums::BookType;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        messages::data::{SubscribeBookDeltas, SubscribeQuotes, UnsubscribeQuotes},
+        msgbus::ShareableMessageHandler,
+    };
+
+    fn client() -> ClientId {
+        ClientId::from("BINANCE")
+    }
+
+    fn subscribe_quotes(instrument_id: InstrumentId) -> SubscribeCommand {
+        SubscribeCommand::Quotes(SubscribeQuotes::new(
+            instrument_id,
+            Some(client()),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+            None,
+        ))
+    }
+
+    fn subscribe_deltas(instrument_id: InstrumentId) -> SubscribeCommand {
+        SubscribeCommand::BookDeltas(SubscribeBookDeltas::new(
+            instrument_id,
+            BookType::L2_MBP,
+            Some(client()),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+            true,
+            None,
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_unsubscribe_removes_subscription() {
+        let btc = InstrumentId::from("BTCUSDT.BINANCE");
+        let mut manager = ReconnectManager::new();
+        manager.on_subscribe(client(), subscribe_quotes(btc));
+        manager.on_subscribe(client(), subscribe_quotes(btc));
+        assert_eq!(manager.subscriptions(&client()).len(), 1);
+
+        let unsubscribe = UnsubscribeCommand::Quotes(UnsubscribeQuotes::new(
+            btc,
+            Some(client()),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+            None,
+        ));
+        manager.on_unsubscribe(client(), &unsubscribe);
+        assert!(manager.subscriptions(&client()).is_empty());
+    }
+
+    #[rstest]
+    fn test_reconnect_plan_and_recovery() {
+        let btc = InstrumentId::from("BTCUSDT.BINANCE");
+        let eth = InstrumentId::from("ETHUSDT.BINANCE");
+        let mut manager = ReconnectManager::new();
+        manager.set_gap_fill_supported(client(), true);
+        manager.on_subscribe(client(), subscribe_quotes(btc));
+        manager.on_subscribe(client(), subscribe_deltas(eth));
+
+        let stages = Rc::new(RefCell::new(Vec::new()));
+        let captured = stages.clone();
+        let handler = ShareableMessageHandler::from_typed(move |e: &ReconnectEvent| {
+            captured.borrow_mut().push(e.stage);
+        });
+        msgbus::subscribe_any(get_reconnect_topic(client()).into(), handler, None);
+
+        manager.on_data(client(), btc, 100.into());
+        manager.on_disconnect(client(), 150.into());
+        assert!(manager.is_stale(&btc));
+        assert!(manager.is_stale(&eth));
+
+        let plan = manager.on_reconnect(client(), 500.into());
+        assert_eq!(plan.subscriptions.len(), 2);
+        assert_eq!(plan.snapshots.len(), 1);
+        assert_eq!(plan.snapshots[0].instrument_id, eth);
+        assert_eq!(plan.gap_fills.len(), 1);
+        match &plan.gap_fills[0] {
+            RequestCommand::Quotes(request) => {
+                assert_eq!(request.start, Some(UnixNanos::from(100).to_datetime_utc()));
+                assert_eq!(request.end, Some(UnixNanos::from(500).to_datetime_utc()));
+            }
+            other => panic!("unexpected gap-fill request {other:?}"),
+        }
+        assert_eq!(manager.stage(&client()), ReconnectStage::GapFilling);
+
+        // Deltas before the snapshot do not clear staleness
+        manager.on_data(client(), btc, 600.into());
+        manager.on_data(client(), eth, 600.into());
+        assert!(!manager.is_stale(&btc));
+        assert!(manager.is_stale(&eth));
+
+        manager.on_snapshot(client(), eth, 700.into());
+        assert!(!manager.is_stale(&eth));
+        assert_eq!(manager.stage(&client()), ReconnectStage::Recovered);
+        assert_eq!(
+            *stages.borrow(),
+            vec![
+                ReconnectStage::Disconnected,
+                ReconnectStage::Resnapshotting,
+                ReconnectStage::Resubscribing,
+                ReconnectStage::GapFilling,
+                ReconnectStage::Recovered,
+            ]
+        );
+    }
+}
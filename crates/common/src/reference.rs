@@ -0,0 +1,371 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Static reference data enrichment for instruments.
+//!
+//! Reference files map instrument IDs to static fields which the instrument definitions from
+//! venues do not carry: the underlying, a sector code, and contract specifications (any further
+//! named fields). At startup [`load_reference_data`] reads CSV and JSON reference files and merges
+//! the records into the cache, where they are queried through
+//! [`Cache::instrument_reference`](crate::cache::Cache::instrument_reference) and related methods.
+//!
+//! CSV files have a header row with an `instrument_id` column, and optional `underlying` and
+//! `sector` columns; every other column is a contract specification field. JSON files hold an
+//! array of flat objects with the same keys. Empty values are ignored, and records for the same
+//! instrument are merged with later values taking precedence.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use indexmap::IndexMap;
+use nautilus_model::identifiers::InstrumentId;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::cache::Cache;
+
+const KEY_INSTRUMENT_ID: &str = "instrument_id";
+const KEY_UNDERLYING: &str = "underlying";
+const KEY_SECTOR: &str = "sector";
+
+/// Static reference data for an instrument.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstrumentReference {
+    pub instrument_id: InstrumentId,
+    /// The underlying symbol (e.g. `AAPL` for an equity option).
+    pub underlying: Option<Ustr>,
+    /// The sector code (e.g. a GICS code).
+    pub sector: Option<Ustr>,
+    /// The contract specification fields.
+    pub specs: IndexMap<String, String>,
+}
+
+impl InstrumentReference {
+    /// Creates a new [`InstrumentReference`] instance without any fields.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            underlying: None,
+            sector: None,
+            specs: IndexMap::new(),
+        }
+    }
+
+    /// Returns the contract specification `field` (if set).
+    #[must_use]
+    pub fn spec(&self, field: &str) -> Option<&str> {
+        self.specs.get(field).map(String::as_str)
+    }
+
+    /// Merges `other` into this reference, with the fields set in `other` taking precedence.
+    pub fn merge(&mut self, other: Self) {
+        if other.underlying.is_some() {
+            self.underlying = other.underlying;
+        }
+        if other.sector.is_some() {
+            self.sector = other.sector;
+        }
+        self.specs.extend(other.specs);
+    }
+
+    fn from_fields<'a>(
+        fields: impl IntoIterator<Item = (&'a str, String)>,
+    ) -> anyhow::Result<Self> {
+        let mut instrument_id = None;
+        let mut underlying = None;
+        let mut sector = None;
+        let mut specs = IndexMap::new();
+
+        for (key, value) in fields {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match key {
+                KEY_INSTRUMENT_ID => instrument_id = Some(InstrumentId::from_str(value)?),
+                KEY_UNDERLYING => underlying = Some(Ustr::from(value)),
+                KEY_SECTOR => sector = Some(Ustr::from(value)),
+                _ => {
+                    specs.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+
+        let Some(instrument_id) = instrument_id else {
+            anyhow::bail!("Missing `{KEY_INSTRUMENT_ID}`");
+        };
+        Ok(Self {
+            instrument_id,
+            underlying,
+            sector,
+            specs,
+        })
+    }
+}
+
+/// The outcome of loading reference data into the cache.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReferenceLoadReport {
+    /// The number of files read.
+    pub files: usize,
+    /// The number of records merged.
+    pub records: usize,
+    /// The referenced instruments not (yet) in the cache.
+    pub unknown_instruments: Vec<InstrumentId>,
+}
+
+/// Parses reference records from CSV `content`.
+///
+/// # Errors
+///
+/// Returns an error if the header has no `instrument_id` column, or a row has the wrong number of
+/// fields or an invalid instrument ID.
+pub fn parse_reference_csv(content: &str) -> anyhow::Result<Vec<InstrumentReference>> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let header: Vec<String> = split_csv_line(header)?
+        .into_iter()
+        .map(|column| column.trim().to_string())
+        .collect();
+    anyhow::ensure!(
+        header.iter().any(|column| column == KEY_INSTRUMENT_ID),
+        "CSV header has no `{KEY_INSTRUMENT_ID}` column"
+    );
+
+    lines
+        .map(|(index, line)| {
+            let line_no = index + 1;
+            let values =
+                split_csv_line(line).map_err(|e| anyhow::anyhow!("Line {line_no}: {e}"))?;
+            anyhow::ensure!(
+                values.len() == header.len(),
+                "Line {line_no}: expected {} fields, was {}",
+                header.len(),
+                values.len()
+            );
+            InstrumentReference::from_fields(header.iter().map(String::as_str).zip(values))
+                .map_err(|e| anyhow::anyhow!("Line {line_no}: {e}"))
+        })
+        .collect()
+}
+
+/// Parses reference records from JSON `content` (an array of flat objects).
+///
+/// # Errors
+///
+/// Returns an error if the JSON is invalid, not an array of objects, a value is nested, or a
+/// record has a missing or invalid instrument ID.
+pub fn parse_reference_json(content: &str) -> anyhow::Result<Vec<InstrumentReference>> {
+    let records: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_str(content)?;
+
+    records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| {
+            let mut fields = Vec::with_capacity(record.len());
+            for (key, value) in record {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(s) => s.clone(),
+                    serde_json::Value::Bool(_) | serde_json::Value::Number(_) => value.to_string(),
+                    _ => anyhow::bail!("Record {index}: field `{key}` must be a scalar value"),
+                };
+                fields.push((key.as_str(), value));
+            }
+            InstrumentReference::from_fields(fields)
+                .map_err(|e| anyhow::anyhow!("Record {index}: {e}"))
+        })
+        .collect()
+}
+
+/// Loads the reference records of a `.csv` or `.json` file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed, or has another extension.
+pub fn load_reference_file(path: &Path) -> anyhow::Result<Vec<InstrumentReference>> {
+    let parse = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("csv") => parse_reference_csv,
+        Some(ext) if ext.eq_ignore_ascii_case("json") => parse_reference_json,
+        _ => anyhow::bail!("Unsupported reference file {}", path.display()),
+    };
+    let content = fs::read_to_string(path)?;
+    let records = parse(&content);
+    records.map_err(|e| anyhow::anyhow!("Invalid reference file {}: {e}", path.display()))
+}
+
+/// Loads the reference files at `paths` (in order) and merges their records into the `cache`.
+///
+/// # Errors
+///
+/// Returns an error if any file cannot be loaded, in which case nothing is merged.
+pub fn load_reference_data(
+    cache: &mut Cache,
+    paths: &[PathBuf],
+) -> anyhow::Result<ReferenceLoadReport> {
+    let mut records = Vec::new();
+    for path in paths {
+        records.extend(load_reference_file(path)?);
+    }
+
+    let mut report = ReferenceLoadReport {
+        files: paths.len(),
+        records: records.len(),
+        unknown_instruments: Vec::new(),
+    };
+    for record in records {
+        let instrument_id = record.instrument_id;
+        if cache.instrument(&instrument_id).is_none()
+            && !report.unknown_instruments.contains(&instrument_id)
+        {
+            report.unknown_instruments.push(instrument_id);
+        }
+        cache.add_instrument_reference(record);
+    }
+
+    if !report.unknown_instruments.is_empty() {
+        log::warn!(
+            "Reference data loaded for {} instruments not in the cache",
+            report.unknown_instruments.len()
+        );
+    }
+    Ok(report)
+}
+
+/// Splits a CSV line into its fields, handling double-quoted fields with escaped quotes.
+fn split_csv_line(line: &str) -> anyhow::Result<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    anyhow::ensure!(!quoted, "Unterminated quoted field");
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::{Instrument, InstrumentAny, stubs::audusd_sim};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_csv_with_quoted_fields() {
+        let content = "instrument_id,underlying,sector,exchange,description\n\
+                       AAPL.XNAS,,4520,XNAS,\"Apple, Inc. \"\"Common\"\"\"\n\
+                       \n\
+                       ESZ4.XCME,ES,,XCME,\n";
+        let records = parse_reference_csv(content).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].sector, Some(Ustr::from("4520")));
+        assert_eq!(records[0].underlying, None);
+        assert_eq!(
+            records[0].spec("description"),
+            Some("Apple, Inc. \"Common\"")
+        );
+        assert_eq!(records[1].underlying, Some(Ustr::from("ES")));
+        assert_eq!(records[1].spec("description"), None);
+    }
+
+    #[rstest]
+    #[case("symbol\nAAPL.XNAS\n")]
+    #[case("instrument_id,sector\nAAPL.XNAS\n")]
+    #[case("instrument_id\nAAPL\n")]
+    #[case("instrument_id,sector\nAAPL.XNAS,\"45\n")]
+    fn test_parse_csv_errors(#[case] content: &str) {
+        assert!(parse_reference_csv(content).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_json() {
+        let content = r#"[
+            {"instrument_id": "ESZ4.XCME", "underlying": "ES", "multiplier": 50, "tick_value": "12.5", "note": null},
+            {"instrument_id": "AAPL.XNAS", "sector": "4520"}
+        ]"#;
+        let records = parse_reference_json(content).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].spec("multiplier"), Some("50"));
+        assert_eq!(records[0].spec("note"), None);
+        assert!(parse_reference_json(r#"[{"instrument_id": "A.B", "x": {}}]"#).is_err());
+        assert!(parse_reference_json(r#"[{"sector": "4520"}]"#).is_err());
+    }
+
+    #[rstest]
+    fn test_load_reference_data_merges_files_into_cache() {
+        let dir = std::env::temp_dir().join(format!("reference-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let instrument_id = audusd_sim().id();
+        let csv_path = dir.join("reference.csv");
+        fs::write(
+            &csv_path,
+            format!("instrument_id,sector,settlement\n{instrument_id},FX,T+2\nEURUSD.SIM,FX,T+2\n"),
+        )
+        .unwrap();
+        let json_path = dir.join("overrides.json");
+        fs::write(
+            &json_path,
+            format!(r#"[{{"instrument_id": "{instrument_id}", "settlement": "T+1"}}]"#),
+        )
+        .unwrap();
+
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let report = load_reference_data(&mut cache, &[csv_path, json_path]).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.files, 2);
+        assert_eq!(report.records, 3);
+        assert_eq!(
+            report.unknown_instruments,
+            vec![InstrumentId::from("EURUSD.SIM")]
+        );
+        let reference = cache.instrument_reference(&instrument_id).unwrap();
+        assert_eq!(reference.sector, Some(Ustr::from("FX")));
+        assert_eq!(reference.spec("settlement"), Some("T+1"));
+        assert_eq!(cache.instrument_ids_by_sector("FX").len(), 2);
+    }
+
+    #[rstest]
+    fn test_load_reference_file_rejects_unknown_extension() {
+        let path = Path::new("reference.txt");
+        assert!(load_reference_file(path).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Price band validation for the execution path.
+//!
+//! Before an order command is sent for execution, the [`PriceBandValidator`] checks its limit
+//! price against the current exchange price bands held in the cache. Band updates arrive as
+//! [`PriceBandUpdate`] data events on the price band topic and are cached as they flow through
+//! the message bus.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use nautilus_model::{
+    data::{PriceBandKind, PriceBandUpdate},
+    enums::OrderSide,
+    identifiers::{ClientOrderId, InstrumentId},
+    orders::Order,
+    types::Price,
+};
+
+use crate::{
+    cache::Cache,
+    messages::execution::TradingCommand,
+    msgbus::{self, ShareableMessageHandler, switchboard::get_price_band_topic},
+};
+
+/// An error for a command rejected for pricing outside the exchange price bands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PriceBandError {
+    #[error("{side} order {client_order_id} at {price} breaches {kind:?} limit {limit}")]
+    OutsideBand {
+        client_order_id: ClientOrderId,
+        side: OrderSide,
+        price: Price,
+        kind: PriceBandKind,
+        limit: Price,
+    },
+}
+
+/// Validates order commands against the current exchange price bands.
+pub struct PriceBandValidator {
+    cache: Rc<RefCell<Cache>>,
+}
+
+impl Debug for PriceBandValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(PriceBandValidator)).finish()
+    }
+}
+
+impl PriceBandValidator {
+    /// Creates a new [`PriceBandValidator`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self { cache }
+    }
+
+    /// Subscribes to band updates for `instrument_id`, caching each as it arrives.
+    ///
+    /// Returns the handler, for unsubscribing.
+    pub fn subscribe(&self, instrument_id: InstrumentId) -> ShareableMessageHandler {
+        let cache = self.cache.clone();
+        let handler = ShareableMessageHandler::from_typed(move |band: &PriceBandUpdate| {
+            cache.borrow_mut().add_price_band(*band);
+        });
+        msgbus::subscribe_any(
+            get_price_band_topic(instrument_id).into(),
+            handler.clone(),
+            None,
+        );
+        handler
+    }
+
+    /// Checks an order on `side` at `price` for `instrument_id` against the cached bands.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the price breaches any band limit.
+    pub fn check_price(
+        &self,
+        instrument_id: &InstrumentId,
+        client_order_id: ClientOrderId,
+        side: OrderSide,
+        price: Price,
+    ) -> Result<(), PriceBandError> {
+        for band in self.cache.borrow().price_bands(instrument_id) {
+            if let Some(limit) = band.breached_limit(side, price) {
+                return Err(PriceBandError::OutsideBand {
+                    client_order_id,
+                    side,
+                    price,
+                    kind: band.kind,
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks `command` against the bands. Commands without a limit price always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the limit price of any order in the command breaches a band limit.
+    pub fn check(&self, command: &TradingCommand) -> Result<(), PriceBandError> {
+        match command {
+            TradingCommand::SubmitOrder(cmd) => match cmd.order_init.price {
+                Some(price) => self.check_price(
+                    &cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.order_init.order_side,
+                    price,
+                ),
+                None => Ok(()),
+            },
+            TradingCommand::SubmitSpreadOrder(cmd) => match cmd.order_init.price {
+                Some(price) => self.check_price(
+                    &cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.order_init.order_side,
+                    price,
+                ),
+                None => Ok(()),
+            },
+            TradingCommand::SubmitOrderList(cmd) => {
+                for order in &cmd.order_list.orders {
+                    if let Some(price) = order.price() {
+                        self.check_price(
+                            &cmd.instrument_id,
+                            order.client_order_id(),
+                            order.order_side(),
+                            price,
+                        )?;
+                    }
+                }
+                Ok(())
+            }
+            TradingCommand::ModifyOrder(cmd) => {
+                let Some(price) = cmd.price else {
+                    return Ok(());
+                };
+                let side = self
+                    .cache
+                    .borrow()
+                    .order(&cmd.client_order_id)
+                    .map_or(OrderSide::NoOrderSide, |order| order.order_side());
+                self.check_price(&cmd.instrument_id, cmd.client_order_id, side, price)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        enums::OrderType,
+        identifiers::{StrategyId, TraderId},
+        orders::{OrderAny, builder::OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::ModifyOrder;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ESZ24.XCME")
+    }
+
+    fn band() -> PriceBandUpdate {
+        PriceBandUpdate::new(
+            instrument_id(),
+            PriceBandKind::DailyLimit,
+            Price::from("4900.00"),
+            Price::from("5100.00"),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn limit_order(side: OrderSide, price: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(1))
+            .build()
+    }
+
+    fn modify(client_order_id: ClientOrderId, price: &str) -> TradingCommand {
+        TradingCommand::ModifyOrder(ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            instrument_id(),
+            client_order_id,
+            None,
+            None,
+            Some(Price::from(price)),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_no_bands_allows_any_price() {
+        let validator = PriceBandValidator::new(Rc::new(RefCell::new(Cache::default())));
+        assert!(
+            validator
+                .check_price(
+                    &instrument_id(),
+                    ClientOrderId::from("O-1"),
+                    OrderSide::Buy,
+                    Price::from("9999.00"),
+                )
+                .is_ok()
+        );
+    }
+
+    #[rstest]
+    fn test_modify_checked_against_order_side() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache.borrow_mut().add_price_band(band());
+        let order = limit_order(OrderSide::Sell, "5000.00");
+        let client_order_id = order.client_order_id();
+        cache
+            .borrow_mut()
+            .add_order(order, None, None, false)
+            .unwrap();
+        let validator = PriceBandValidator::new(cache);
+
+        // A sell above the upper limit is not restricted
+        assert!(validator.check(&modify(client_order_id, "5200.00")).is_ok());
+        assert_eq!(
+            validator.check(&modify(client_order_id, "4850.00")),
+            Err(PriceBandError::OutsideBand {
+                client_order_id,
+                side: OrderSide::Sell,
+                price: Price::from("4850.00"),
+                kind: PriceBandKind::DailyLimit,
+                limit: Price::from("4900.00"),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_band_updates_cached_from_topic() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let validator = PriceBandValidator::new(cache.clone());
+        validator.subscribe(instrument_id());
+
+        msgbus::publish_any(get_price_band_topic(instrument_id()), &band());
+
+        assert_eq!(cache.borrow().price_bands(&instrument_id()), vec![band()]);
+        assert!(
+            validator
+                .check_price(
+                    &instrument_id(),
+                    ClientOrderId::from("O-1"),
+                    OrderSide::Buy,
+                    Price::from("5100.25"),
+                )
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,382 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Strategy-level drawdown circuit breaker.
+//!
+//! Tracks each strategy's total (realized + unrealized) PnL and halts the strategy when its
+//! drawdown from the intraday peak or the all-time peak exceeds the configured limits. A halted
+//! strategy has its open orders canceled, new submissions blocked, and a [`DrawdownBreached`]
+//! event published until it is explicitly reset.
+
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use ahash::{AHashMap, AHashSet};
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{InstrumentId, StrategyId, TraderId},
+    orders::Order,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    messages::execution::{CancelAllOrders, TradingCommand},
+    msgbus::{self, MessagingSwitchboard},
+    risk::mark_price,
+};
+
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// Drawdown limits for a strategy, in PnL units (`None` for unlimited).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DrawdownLimits {
+    /// The maximum drawdown from the peak PnL within the current UTC day.
+    pub max_daily_drawdown: Option<f64>,
+    /// The maximum drawdown from the highest PnL ever observed.
+    pub max_trailing_drawdown: Option<f64>,
+}
+
+/// The drawdown limit which was breached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DrawdownLimitKind {
+    Daily,
+    Trailing,
+}
+
+/// Represents an event where a strategy breached a drawdown limit and was halted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DrawdownBreached {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub kind: DrawdownLimitKind,
+    pub drawdown: f64,
+    pub limit: f64,
+    pub pnl: f64,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for DrawdownBreached {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, kind={:?}, drawdown={}, limit={}, pnl={})",
+            stringify!(DrawdownBreached),
+            self.strategy_id,
+            self.kind,
+            self.drawdown,
+            self.limit,
+            self.pnl,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DrawdownState {
+    day: u64,
+    day_peak: f64,
+    peak: f64,
+    pnl: f64,
+    halted: bool,
+}
+
+/// Halts strategies whose PnL drawdown exceeds their configured limits.
+pub struct DrawdownCircuitBreaker {
+    trader_id: TraderId,
+    cache: Rc<RefCell<Cache>>,
+    limits: AHashMap<StrategyId, DrawdownLimits>,
+    states: AHashMap<StrategyId, DrawdownState>,
+}
+
+impl Debug for DrawdownCircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(DrawdownCircuitBreaker))
+            .field("trader_id", &self.trader_id)
+            .field("limits", &self.limits)
+            .field("states", &self.states)
+            .finish()
+    }
+}
+
+impl DrawdownCircuitBreaker {
+    /// Creates a new [`DrawdownCircuitBreaker`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            trader_id,
+            cache,
+            limits: AHashMap::new(),
+            states: AHashMap::new(),
+        }
+    }
+
+    /// Sets the drawdown limits for `strategy_id`.
+    pub fn set_limits(&mut self, strategy_id: StrategyId, limits: DrawdownLimits) {
+        self.limits.insert(strategy_id, limits);
+    }
+
+    /// Returns whether `strategy_id` is currently halted.
+    #[must_use]
+    pub fn is_halted(&self, strategy_id: &StrategyId) -> bool {
+        self.states
+            .get(strategy_id)
+            .is_some_and(|state| state.halted)
+    }
+
+    /// Returns the current drawdowns (daily, trailing) for `strategy_id`, if tracked.
+    #[must_use]
+    pub fn drawdowns(&self, strategy_id: &StrategyId) -> Option<(f64, f64)> {
+        self.states
+            .get(strategy_id)
+            .map(|state| (state.day_peak - state.pnl, state.peak - state.pnl))
+    }
+
+    /// Checks whether `strategy_id` may submit new orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy has been halted by a drawdown breach.
+    pub fn check_submit(&self, strategy_id: &StrategyId) -> anyhow::Result<()> {
+        if self.is_halted(strategy_id) {
+            anyhow::bail!("Strategy {strategy_id} halted by drawdown circuit breaker");
+        }
+        Ok(())
+    }
+
+    /// Resumes a halted strategy, resetting its peaks to the current PnL.
+    pub fn reset(&mut self, strategy_id: &StrategyId) {
+        if let Some(state) = self.states.get_mut(strategy_id) {
+            state.halted = false;
+            state.day_peak = state.pnl;
+            state.peak = state.pnl;
+        }
+    }
+
+    /// Returns the total realized and unrealized PnL for `strategy_id` from the cache.
+    ///
+    /// Open positions are marked at the last or mid price when available. PnL is summed across
+    /// settlement currencies, so strategies are assumed to settle in a single currency.
+    #[must_use]
+    pub fn strategy_pnl(&self, strategy_id: &StrategyId) -> f64 {
+        let cache = self.cache.borrow();
+        cache
+            .positions(None, None, Some(strategy_id), None, None)
+            .into_iter()
+            .map(|position| {
+                let realized = position.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
+                let unrealized = if position.is_open() {
+                    mark_price(&cache, &position.instrument_id)
+                        .map_or(0.0, |price| position.unrealized_pnl(price).as_f64())
+                } else {
+                    0.0
+                };
+                realized + unrealized
+            })
+            .sum()
+    }
+
+    /// Updates every strategy with limits from the cache, halting any in breach.
+    pub fn update(&mut self, ts_now: UnixNanos) -> Vec<DrawdownBreached> {
+        let strategy_ids: Vec<StrategyId> = self.limits.keys().copied().collect();
+        strategy_ids
+            .into_iter()
+            .filter_map(|strategy_id| {
+                let pnl = self.strategy_pnl(&strategy_id);
+                self.update_pnl(strategy_id, pnl, ts_now)
+            })
+            .collect()
+    }
+
+    /// Records the current `pnl` for `strategy_id`, halting the strategy on a breach.
+    ///
+    /// Returns the breach event if the strategy was halted by this update.
+    pub fn update_pnl(
+        &mut self,
+        strategy_id: StrategyId,
+        pnl: f64,
+        ts_now: UnixNanos,
+    ) -> Option<DrawdownBreached> {
+        let limits = self.limits.get(&strategy_id).copied().unwrap_or_default();
+        let day = ts_now.as_u64() / NANOS_PER_DAY;
+
+        let state = self.states.entry(strategy_id).or_insert(DrawdownState {
+            day,
+            day_peak: pnl,
+            peak: pnl,
+            pnl,
+            halted: false,
+        });
+        if state.day != day {
+            state.day = day;
+            state.day_peak = pnl;
+        }
+        state.pnl = pnl;
+        state.day_peak = state.day_peak.max(pnl);
+        state.peak = state.peak.max(pnl);
+
+        if state.halted {
+            return None;
+        }
+
+        let daily = state.day_peak - pnl;
+        let trailing = state.peak - pnl;
+        let (kind, drawdown, limit) =
+            match (limits.max_daily_drawdown, limits.max_trailing_drawdown) {
+                (Some(limit), _) if daily > limit => (DrawdownLimitKind::Daily, daily, limit),
+                (_, Some(limit)) if trailing > limit => {
+                    (DrawdownLimitKind::Trailing, trailing, limit)
+                }
+                _ => return None,
+            };
+        state.halted = true;
+
+        let event = DrawdownBreached {
+            trader_id: self.trader_id,
+            strategy_id,
+            kind,
+            drawdown,
+            limit,
+            pnl,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        log::error!("{event}");
+
+        self.cancel_open_orders(strategy_id, ts_now);
+        msgbus::publish_any(format!("events.risk.drawdown.{strategy_id}").into(), &event);
+
+        Some(event)
+    }
+
+    fn cancel_open_orders(&self, strategy_id: StrategyId, ts_now: UnixNanos) {
+        let instrument_ids: AHashSet<InstrumentId> = self
+            .cache
+            .borrow()
+            .orders_open(None, None, Some(&strategy_id), None, None)
+            .into_iter()
+            .map(|order| order.instrument_id())
+            .collect();
+
+        for instrument_id in instrument_ids {
+            let command = CancelAllOrders::new(
+                self.trader_id,
+                None,
+                strategy_id,
+                instrument_id,
+                OrderSide::NoOrderSide,
+                UUID4::new(),
+                ts_now,
+                None,
+            );
+            msgbus::send_trading_command(
+                MessagingSwitchboard::exec_engine_execute(),
+                TradingCommand::CancelAllOrders(command),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const DAY: u64 = NANOS_PER_DAY;
+
+    fn breaker(limits: DrawdownLimits) -> DrawdownCircuitBreaker {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut breaker = DrawdownCircuitBreaker::new(TraderId::from("TRADER-001"), cache);
+        breaker.set_limits(StrategyId::from("S-001"), limits);
+        breaker
+    }
+
+    #[rstest]
+    fn test_daily_drawdown_breach_halts_strategy() {
+        let strategy_id = StrategyId::from("S-001");
+        let mut breaker = breaker(DrawdownLimits {
+            max_daily_drawdown: Some(100.0),
+            max_trailing_drawdown: None,
+        });
+
+        assert!(breaker.update_pnl(strategy_id, 50.0, 1.into()).is_none());
+        assert!(breaker.update_pnl(strategy_id, -40.0, 2.into()).is_none());
+        let event = breaker.update_pnl(strategy_id, -60.0, 3.into()).unwrap();
+
+        assert_eq!(event.kind, DrawdownLimitKind::Daily);
+        assert_eq!(event.drawdown, 110.0);
+        assert!(breaker.is_halted(&strategy_id));
+        assert!(breaker.check_submit(&strategy_id).is_err());
+
+        // No repeated events while halted
+        assert!(breaker.update_pnl(strategy_id, -80.0, 4.into()).is_none());
+    }
+
+    #[rstest]
+    fn test_daily_peak_resets_on_new_day() {
+        let strategy_id = StrategyId::from("S-001");
+        let mut breaker = breaker(DrawdownLimits {
+            max_daily_drawdown: Some(100.0),
+            max_trailing_drawdown: Some(150.0),
+        });
+
+        assert!(breaker.update_pnl(strategy_id, 100.0, 1.into()).is_none());
+        assert!(breaker.update_pnl(strategy_id, 10.0, DAY.into()).is_none());
+        assert!(
+            breaker
+                .update_pnl(strategy_id, -40.0, (DAY + 1).into())
+                .is_none()
+        );
+        assert_eq!(breaker.drawdowns(&strategy_id), Some((50.0, 140.0)));
+
+        let event = breaker
+            .update_pnl(strategy_id, -60.0, (DAY + 2).into())
+            .unwrap();
+
+        assert_eq!(event.kind, DrawdownLimitKind::Trailing);
+        assert_eq!(event.drawdown, 160.0);
+    }
+
+    #[rstest]
+    fn test_reset_resumes_strategy() {
+        let strategy_id = StrategyId::from("S-001");
+        let mut breaker = breaker(DrawdownLimits {
+            max_daily_drawdown: None,
+            max_trailing_drawdown: Some(10.0),
+        });
+        breaker.update_pnl(strategy_id, 0.0, 1.into());
+        breaker.update_pnl(strategy_id, -20.0, 2.into()).unwrap();
+
+        breaker.reset(&strategy_id);
+
+        assert!(breaker.check_submit(&strategy_id).is_ok());
+        assert_eq!(breaker.drawdowns(&strategy_id), Some((0.0, 0.0)));
+    }
+
+    #[rstest]
+    fn test_update_without_positions_has_zero_pnl() {
+        let mut breaker = breaker(DrawdownLimits::default());
+
+        assert!(breaker.update(1.into()).is_empty());
+        assert_eq!(breaker.strategy_pnl(&StrategyId::from("S-001")), 0.0);
+    }
+}
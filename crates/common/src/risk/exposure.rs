@@ -0,0 +1,349 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Aggregate exposure limits for groups of instruments (asset class, sector, country).
+//!
+//! Instruments are tagged with any number of groups, and gross/net notional limits are enforced
+//! per group. Orders which would increase a group's exposure beyond a limit are rejected, while
+//! orders which reduce exposure are always allowed.
+
+use std::{
+    cell::RefCell,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+use ahash::AHashMap;
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::InstrumentId,
+    instruments::Instrument,
+    orders::{Order, OrderAny},
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    cache::Cache,
+    risk::{mark_price, open_exposures},
+};
+
+/// The dimension an exposure group is defined along.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GroupKind {
+    AssetClass,
+    Sector,
+    Country,
+}
+
+/// A named group of instruments along one dimension, e.g. `Sector("Technology")`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ExposureGroup {
+    pub kind: GroupKind,
+    pub name: Ustr,
+}
+
+impl ExposureGroup {
+    /// Creates a new [`ExposureGroup`] instance.
+    #[must_use]
+    pub fn new(kind: GroupKind, name: &str) -> Self {
+        Self {
+            kind,
+            name: Ustr::from(name),
+        }
+    }
+}
+
+impl Display for ExposureGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}({})", self.kind, self.name)
+    }
+}
+
+/// Gross and net notional limits for an exposure group (`None` for unlimited).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExposureLimit {
+    /// The maximum sum of absolute instrument exposures.
+    pub max_gross: Option<f64>,
+    /// The maximum absolute sum of signed instrument exposures.
+    pub max_net: Option<f64>,
+}
+
+/// The current exposure of a group relative to its limits.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupUtilization {
+    pub group: ExposureGroup,
+    pub gross: f64,
+    pub net: f64,
+    pub limit: ExposureLimit,
+}
+
+impl GroupUtilization {
+    /// Returns the fraction of the gross limit in use, if limited.
+    #[must_use]
+    pub fn gross_utilization(&self) -> Option<f64> {
+        self.limit.max_gross.map(|max| self.gross / max)
+    }
+
+    /// Returns the fraction of the net limit in use, if limited.
+    #[must_use]
+    pub fn net_utilization(&self) -> Option<f64> {
+        self.limit.max_net.map(|max| self.net.abs() / max)
+    }
+}
+
+/// Enforces aggregate exposure limits per instrument group against open positions in the cache.
+pub struct ExposureLimiter {
+    cache: Rc<RefCell<Cache>>,
+    tags: AHashMap<InstrumentId, Vec<ExposureGroup>>,
+    limits: AHashMap<ExposureGroup, ExposureLimit>,
+}
+
+impl Debug for ExposureLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ExposureLimiter))
+            .field("tags", &self.tags)
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
+
+impl ExposureLimiter {
+    /// Creates a new [`ExposureLimiter`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            cache,
+            tags: AHashMap::new(),
+            limits: AHashMap::new(),
+        }
+    }
+
+    /// Tags `instrument_id` as a member of `group`.
+    pub fn tag(&mut self, instrument_id: InstrumentId, group: ExposureGroup) {
+        let groups = self.tags.entry(instrument_id).or_default();
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    /// Returns the groups `instrument_id` is tagged with.
+    #[must_use]
+    pub fn groups(&self, instrument_id: &InstrumentId) -> &[ExposureGroup] {
+        self.tags.get(instrument_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Sets the limit for `group`, replacing any existing limit.
+    pub fn set_limit(&mut self, group: ExposureGroup, limit: ExposureLimit) {
+        self.limits.insert(group, limit);
+    }
+
+    /// Removes the limit for `group`.
+    pub fn remove_limit(&mut self, group: &ExposureGroup) {
+        self.limits.remove(group);
+    }
+
+    /// Returns the current utilization of every limited group.
+    #[must_use]
+    pub fn utilizations(&self) -> Vec<GroupUtilization> {
+        let exposures = open_exposures(&self.cache.borrow());
+        self.limits
+            .iter()
+            .map(|(group, limit)| {
+                let (gross, net) = self.group_exposure(group, &exposures);
+                GroupUtilization {
+                    group: *group,
+                    gross,
+                    net,
+                    limit: *limit,
+                }
+            })
+            .collect()
+    }
+
+    /// Checks whether an order would breach the limit of any group its instrument belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first breached group limit.
+    pub fn check_order(
+        &self,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+    ) -> anyhow::Result<()> {
+        let groups = self.groups(&instrument_id);
+        if groups.iter().all(|group| !self.limits.contains_key(group)) {
+            return Ok(());
+        }
+
+        let cache = self.cache.borrow();
+        let multiplier = cache
+            .instrument(&instrument_id)
+            .map_or(1.0, |instrument| instrument.multiplier().as_f64());
+        let sign = match side {
+            OrderSide::Buy => 1.0,
+            OrderSide::Sell => -1.0,
+            OrderSide::NoOrderSide => anyhow::bail!("Order side must be specified"),
+        };
+        let order_exposure = sign * quantity.as_f64() * price.as_f64() * multiplier;
+
+        let current = open_exposures(&cache);
+        let mut proposed = current.clone();
+        *proposed.entry(instrument_id).or_default() += order_exposure;
+
+        for group in groups {
+            let Some(limit) = self.limits.get(group) else {
+                continue;
+            };
+            let (gross, net) = self.group_exposure(group, &current);
+            let (new_gross, new_net) = self.group_exposure(group, &proposed);
+
+            if let Some(max) = limit.max_gross
+                && new_gross > max
+                && new_gross > gross
+            {
+                anyhow::bail!(
+                    "Order for {instrument_id} would breach {group} gross exposure limit: {new_gross} > {max}"
+                );
+            }
+            if let Some(max) = limit.max_net
+                && new_net.abs() > max
+                && new_net.abs() > net.abs()
+            {
+                anyhow::bail!(
+                    "Order for {instrument_id} would breach {group} net exposure limit: {} > {max}",
+                    new_net.abs()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks an order's remaining quantity against the limits (see [`Self::check_order`]).
+    ///
+    /// Orders without a price are valued at the trigger price, then the instrument's mark price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no price is available to value the order, or a limit would be breached.
+    pub fn check_order_any(&self, order: &OrderAny) -> anyhow::Result<()> {
+        let instrument_id = order.instrument_id();
+        let Some(price) = order
+            .price()
+            .or_else(|| order.trigger_price())
+            .or_else(|| mark_price(&self.cache.borrow(), &instrument_id))
+        else {
+            anyhow::bail!("No price available to value order for {instrument_id}");
+        };
+        self.check_order(instrument_id, order.order_side(), order.leaves_qty(), price)
+    }
+
+    fn group_exposure(
+        &self,
+        group: &ExposureGroup,
+        exposures: &AHashMap<InstrumentId, f64>,
+    ) -> (f64, f64) {
+        exposures
+            .iter()
+            .filter(|(instrument_id, _)| self.groups(instrument_id).contains(group))
+            .fold((0.0, 0.0), |(gross, net), (_, exposure)| {
+                (gross + exposure.abs(), net + exposure)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limiter() -> ExposureLimiter {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut limiter = ExposureLimiter::new(cache);
+        let tech = ExposureGroup::new(GroupKind::Sector, "Technology");
+        limiter.tag(InstrumentId::from("AAPL.XNAS"), tech);
+        limiter.tag(
+            InstrumentId::from("AAPL.XNAS"),
+            ExposureGroup::new(GroupKind::Country, "US"),
+        );
+        limiter.set_limit(
+            tech,
+            ExposureLimit {
+                max_gross: Some(1_000.0),
+                max_net: Some(800.0),
+            },
+        );
+        limiter
+    }
+
+    #[rstest]
+    fn test_tagging_is_idempotent() {
+        let mut limiter = limiter();
+        limiter.tag(
+            InstrumentId::from("AAPL.XNAS"),
+            ExposureGroup::new(GroupKind::Sector, "Technology"),
+        );
+
+        assert_eq!(limiter.groups(&InstrumentId::from("AAPL.XNAS")).len(), 2);
+        assert!(limiter.groups(&InstrumentId::from("MSFT.XNAS")).is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "5", true)]
+    #[case(OrderSide::Buy, "9", false)] // Net limit breached
+    #[case(OrderSide::Sell, "11", false)] // Gross limit breached
+    fn test_check_order(#[case] side: OrderSide, #[case] quantity: &str, #[case] ok: bool) {
+        let limiter = limiter();
+
+        let result = limiter.check_order(
+            InstrumentId::from("AAPL.XNAS"),
+            side,
+            Quantity::from(quantity),
+            Price::from("100.00"),
+        );
+
+        assert_eq!(result.is_ok(), ok);
+    }
+
+    #[rstest]
+    fn test_untagged_instrument_is_unrestricted() {
+        let limiter = limiter();
+
+        let result = limiter.check_order(
+            InstrumentId::from("MSFT.XNAS"),
+            OrderSide::Buy,
+            Quantity::from("1000000"),
+            Price::from("100.00"),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    fn test_utilizations_report_limited_groups() {
+        let limiter = limiter();
+
+        let utilizations = limiter.utilizations();
+
+        assert_eq!(utilizations.len(), 1);
+        assert_eq!(utilizations[0].gross, 0.0);
+        assert_eq!(utilizations[0].gross_utilization(), Some(0.0));
+    }
+}
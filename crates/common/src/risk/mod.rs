@@ -0,0 +1,66 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Portfolio risk analytics and controls.
+
+pub mod bands;
+pub mod drawdown;
+pub mod exposure;
+pub mod monte_carlo;
+pub mod order_validation;
+pub mod stale;
+pub mod var;
+
+use ahash::AHashMap;
+use nautilus_model::{enums::PriceType, identifiers::InstrumentId, types::Price};
+
+use crate::cache::Cache;
+
+// Re-exports
+pub use crate::risk::{
+    bands::{PriceBandError, PriceBandValidator},
+    drawdown::{DrawdownBreached, DrawdownCircuitBreaker, DrawdownLimitKind, DrawdownLimits},
+    exposure::{ExposureGroup, ExposureLimit, ExposureLimiter, GroupKind, GroupUtilization},
+    monte_carlo::{
+        MonteCarloConfig, MonteCarloEngine, MonteCarloReport, MonteCarloTail, PathModel,
+    },
+    order_validation::OrderValidator,
+    stale::{StalePriceAction, StalePriceDecision, StalePriceError, StalePriceGuard},
+    var::{PortfolioVarCalculator, VarConfig, VarEstimate, VarMethod, VarReport},
+};
+
+/// Returns the signed notional exposure per instrument across all open positions in `cache`.
+///
+/// Positions are marked at the last traded price, falling back to the mid price and then the
+/// average open price.
+#[must_use]
+pub fn open_exposures(cache: &Cache) -> AHashMap<InstrumentId, f64> {
+    let mut exposures: AHashMap<InstrumentId, f64> = AHashMap::new();
+
+    for position in cache.positions_open(None, None, None, None, None) {
+        let price = mark_price(cache, &position.instrument_id)
+            .map_or(position.avg_px_open, |price| price.as_f64());
+        let exposure = position.signed_qty * position.multiplier.as_f64() * price;
+        *exposures.entry(position.instrument_id).or_default() += exposure;
+    }
+
+    exposures
+}
+
+fn mark_price(cache: &Cache, instrument_id: &InstrumentId) -> Option<Price> {
+    cache
+        .price(instrument_id, PriceType::Last)
+        .or_else(|| cache.price(instrument_id, PriceType::Mid))
+}
@@ -0,0 +1,531 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Monte Carlo scenario simulation of portfolio profit and loss.
+//!
+//! Correlated price paths are simulated for the instruments with open positions, either as
+//! geometric Brownian motion fitted to the registered return series (drift and covariance via a
+//! Cholesky factor) or by bootstrapping whole historical return vectors, which preserves the
+//! empirical cross-correlation. Each path is evaluated for terminal profit and loss and the
+//! initial margin of the terminal positions, and summarized as a [`MonteCarloReport`].
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{identifiers::InstrumentId, instruments::Instrument};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::{self, MStr, Topic},
+    risk::{open_exposures, var::historical_var},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// The default topic Monte Carlo risk reports are published on.
+pub const MONTE_CARLO_TELEMETRY_TOPIC: &str = "risk.telemetry.monte_carlo";
+
+/// The model used to simulate price paths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PathModel {
+    /// Correlated geometric Brownian motion fitted to the historical log returns.
+    #[default]
+    Gbm,
+    /// Resampling of historical return vectors (all instruments at the same period).
+    Bootstrap,
+}
+
+/// Configuration for a [`MonteCarloEngine`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// The path model.
+    pub model: PathModel,
+    /// The number of simulated paths.
+    pub paths: usize,
+    /// The number of return periods simulated per path.
+    pub horizon: u32,
+    /// The confidence levels to report VaR and expected shortfall at.
+    pub confidence_levels: Vec<f64>,
+    /// The maximum number of most recent return observations to fit (`None` for all).
+    pub lookback: Option<usize>,
+    /// The random seed, so runs are reproducible.
+    pub seed: u64,
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            model: PathModel::Gbm,
+            paths: 10_000,
+            horizon: 1,
+            confidence_levels: vec![0.95, 0.99],
+            lookback: None,
+            seed: 42,
+        }
+    }
+}
+
+/// Terminal loss statistics at a single confidence level (losses reported as positive numbers).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloTail {
+    pub confidence: f64,
+    pub value_at_risk: f64,
+    pub expected_shortfall: f64,
+    /// The terminal initial margin at the confidence quantile.
+    pub margin: f64,
+}
+
+/// The simulated terminal profit and loss and margin distribution of the portfolio.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloReport {
+    pub model: PathModel,
+    pub paths: usize,
+    pub horizon: u32,
+    pub mean_pnl: f64,
+    pub std_pnl: f64,
+    /// The fraction of paths ending with a loss.
+    pub probability_of_loss: f64,
+    pub tails: Vec<MonteCarloTail>,
+    /// The initial margin of the current positions.
+    pub margin_initial: f64,
+    /// The mean initial margin of the terminal positions.
+    pub margin_mean: f64,
+    pub gross_exposure: f64,
+    pub ts_init: UnixNanos,
+}
+
+/// Simulates portfolio profit and loss over correlated price paths for the open positions in
+/// the cache.
+///
+/// Return series are registered per instrument as for the
+/// [`PortfolioVarCalculator`](crate::risk::PortfolioVarCalculator) and aligned on their most
+/// recent observations. Simulations can be run on demand with [`Self::run`] or scheduled on a
+/// clock with [`Self::start`].
+#[derive(Clone)]
+pub struct MonteCarloEngine {
+    cache: Rc<RefCell<Cache>>,
+    config: MonteCarloConfig,
+    topic: MStr<Topic>,
+    returns: Rc<RefCell<AHashMap<InstrumentId, Vec<f64>>>>,
+}
+
+impl Debug for MonteCarloEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(MonteCarloEngine))
+            .field("config", &self.config)
+            .field("topic", &self.topic)
+            .field("instruments", &self.returns.borrow().len())
+            .finish()
+    }
+}
+
+impl MonteCarloEngine {
+    pub const TIMER_NAME: &str = "MonteCarloRisk";
+
+    /// Creates a new [`MonteCarloEngine`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths` or `horizon` is zero, or a confidence level is not in (0, 1).
+    pub fn new(cache: Rc<RefCell<Cache>>, config: MonteCarloConfig) -> anyhow::Result<Self> {
+        anyhow::ensure!(config.paths > 0, "`paths` must be positive");
+        anyhow::ensure!(config.horizon > 0, "`horizon` must be positive");
+        for &confidence in &config.confidence_levels {
+            anyhow::ensure!(
+                confidence > 0.0 && confidence < 1.0,
+                "`confidence` must be in (0, 1), was {confidence}"
+            );
+        }
+
+        Ok(Self {
+            cache,
+            config,
+            topic: MONTE_CARLO_TELEMETRY_TOPIC.into(),
+            returns: Rc::new(RefCell::new(AHashMap::new())),
+        })
+    }
+
+    /// Returns the engine configuration.
+    #[must_use]
+    pub const fn config(&self) -> &MonteCarloConfig {
+        &self.config
+    }
+
+    /// Sets the topic reports are published on.
+    pub fn set_topic(&mut self, topic: MStr<Topic>) {
+        self.topic = topic;
+    }
+
+    /// Sets the chronological return series (simple returns) for `instrument_id`.
+    pub fn set_returns(&self, instrument_id: InstrumentId, returns: Vec<f64>) {
+        self.returns.borrow_mut().insert(instrument_id, returns);
+    }
+
+    /// Returns the current exposure per instrument across all open positions.
+    #[must_use]
+    pub fn exposures(&self) -> AHashMap<InstrumentId, f64> {
+        open_exposures(&self.cache.borrow())
+    }
+
+    /// Simulates the terminal profit and loss and margin of the open positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no open positions, an exposed instrument has no registered
+    /// return series, or fewer than two aligned observations are available.
+    pub fn run(&self, ts_init: UnixNanos) -> anyhow::Result<MonteCarloReport> {
+        let exposures: Vec<(InstrumentId, f64)> = {
+            let mut exposures: Vec<_> = self.exposures().into_iter().collect();
+            exposures.sort_by_key(|(instrument_id, _)| instrument_id.to_string());
+            exposures
+        };
+        anyhow::ensure!(!exposures.is_empty(), "No open positions to simulate");
+
+        let history = self.aligned_returns(&exposures)?;
+        let margin_rates = self.margin_rates(&exposures);
+        let notionals: Vec<f64> = exposures.iter().map(|(_, exposure)| *exposure).collect();
+
+        let mut rng = Xorshift::new(self.config.seed);
+        let model = match self.config.model {
+            PathModel::Gbm => Some(GbmModel::fit(&history)?),
+            PathModel::Bootstrap => None,
+        };
+
+        let mut pnls = Vec::with_capacity(self.config.paths);
+        let mut margins = Vec::with_capacity(self.config.paths);
+        let mut log_growth = vec![0.0; notionals.len()];
+        for _ in 0..self.config.paths {
+            log_growth.fill(0.0);
+            for _ in 0..self.config.horizon {
+                match &model {
+                    Some(model) => model.step(&mut rng, &mut log_growth),
+                    None => {
+                        let row = &history[rng.next_index(history.len())];
+                        for (growth, r) in log_growth.iter_mut().zip(row) {
+                            *growth += r.ln_1p();
+                        }
+                    }
+                }
+            }
+
+            let mut pnl = 0.0;
+            let mut margin = 0.0;
+            for ((notional, growth), rate) in notionals.iter().zip(&log_growth).zip(&margin_rates) {
+                let terminal = notional * growth.exp();
+                pnl += terminal - notional;
+                margin += terminal.abs() * rate;
+            }
+            pnls.push(pnl);
+            margins.push(margin);
+        }
+
+        let paths = pnls.len() as f64;
+        let mean_pnl = pnls.iter().sum::<f64>() / paths;
+        let std_pnl = if pnls.len() > 1 {
+            (pnls.iter().map(|x| (x - mean_pnl).powi(2)).sum::<f64>() / (paths - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+        let probability_of_loss = pnls.iter().filter(|x| **x < 0.0).count() as f64 / paths;
+
+        let mut sorted_margins = margins.clone();
+        sorted_margins.sort_by(f64::total_cmp);
+        let mut tails = Vec::with_capacity(self.config.confidence_levels.len());
+        for &confidence in &self.config.confidence_levels {
+            let estimate = historical_var(&pnls, confidence)?;
+            let index = ((confidence * paths).ceil() as usize).clamp(1, pnls.len()) - 1;
+            tails.push(MonteCarloTail {
+                confidence,
+                value_at_risk: estimate.value_at_risk,
+                expected_shortfall: estimate.expected_shortfall,
+                margin: sorted_margins[index],
+            });
+        }
+
+        Ok(MonteCarloReport {
+            model: self.config.model,
+            paths: self.config.paths,
+            horizon: self.config.horizon,
+            mean_pnl,
+            std_pnl,
+            probability_of_loss,
+            tails,
+            margin_initial: notionals
+                .iter()
+                .zip(&margin_rates)
+                .map(|(notional, rate)| notional.abs() * rate)
+                .sum(),
+            margin_mean: margins.iter().sum::<f64>() / paths,
+            gross_exposure: notionals.iter().map(|x| x.abs()).sum(),
+            ts_init,
+        })
+    }
+
+    /// Runs a simulation and publishes the report as risk telemetry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the simulation fails.
+    pub fn publish(&self, ts_init: UnixNanos) -> anyhow::Result<MonteCarloReport> {
+        let report = self.run(ts_init)?;
+        msgbus::publish_any(self.topic, &report);
+        Ok(report)
+    }
+
+    /// Starts publishing a report every `interval_ns` on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock, interval_ns: u64) -> anyhow::Result<()> {
+        let engine = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            if let Err(e) = engine.publish(event.ts_event) {
+                log::error!("Monte Carlo risk simulation failed: {e}");
+            }
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            interval_ns,
+            None,
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Stops scheduled simulations on `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+    }
+
+    /// Returns the return vectors per period (one return per exposed instrument).
+    fn aligned_returns(&self, exposures: &[(InstrumentId, f64)]) -> anyhow::Result<Vec<Vec<f64>>> {
+        let returns = self.returns.borrow();
+        let mut len = self.config.lookback.unwrap_or(usize::MAX);
+        for (instrument_id, _) in exposures {
+            match returns.get(instrument_id) {
+                Some(series) => len = len.min(series.len()),
+                None => anyhow::bail!("No return series for {instrument_id}"),
+            }
+        }
+        anyhow::ensure!(
+            len >= 2,
+            "At least 2 aligned return observations are required, had {len}"
+        );
+
+        let recent: Vec<&[f64]> = exposures
+            .iter()
+            .map(|(instrument_id, _)| {
+                let series = &returns[instrument_id];
+                &series[series.len() - len..]
+            })
+            .collect();
+        Ok((0..len)
+            .map(|t| recent.iter().map(|series| series[t]).collect())
+            .collect())
+    }
+
+    /// Returns the initial margin rate per exposed instrument (zero when not cached).
+    fn margin_rates(&self, exposures: &[(InstrumentId, f64)]) -> Vec<f64> {
+        let cache = self.cache.borrow();
+        exposures
+            .iter()
+            .map(|(instrument_id, _)| {
+                cache.instrument(instrument_id).map_or(0.0, |instrument| {
+                    instrument.margin_init().try_into().unwrap_or(0.0)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Correlated normal log returns with the historical mean and covariance.
+struct GbmModel {
+    drift: Vec<f64>,
+    /// The lower triangular Cholesky factor of the covariance (row major).
+    cholesky: Vec<Vec<f64>>,
+}
+
+impl GbmModel {
+    fn fit(history: &[Vec<f64>]) -> anyhow::Result<Self> {
+        let n = history[0].len();
+        let periods = history.len() as f64;
+        let logs: Vec<Vec<f64>> = history
+            .iter()
+            .map(|row| row.iter().map(|r| r.ln_1p()).collect())
+            .collect();
+        anyhow::ensure!(
+            logs.iter().flatten().all(|x| x.is_finite()),
+            "Return series contain returns of -100% or less"
+        );
+
+        let drift: Vec<f64> = (0..n)
+            .map(|i| logs.iter().map(|row| row[i]).sum::<f64>() / periods)
+            .collect();
+        let mut covariance = vec![vec![0.0; n]; n];
+        for row in &logs {
+            let deviations: Vec<f64> = row.iter().zip(&drift).map(|(x, mean)| x - mean).collect();
+            for (i, cov_row) in covariance.iter_mut().enumerate() {
+                for (j, cov) in cov_row.iter_mut().enumerate().take(i + 1) {
+                    *cov += deviations[i] * deviations[j] / (periods - 1.0);
+                }
+            }
+        }
+
+        Ok(Self {
+            drift,
+            cholesky: cholesky(&covariance),
+        })
+    }
+
+    fn step(&self, rng: &mut Xorshift, log_growth: &mut [f64]) {
+        let shocks: Vec<f64> = (0..self.drift.len()).map(|_| rng.next_normal()).collect();
+        for (i, growth) in log_growth.iter_mut().enumerate() {
+            let shock: f64 = (0..=i).map(|j| self.cholesky[i][j] * shocks[j]).sum();
+            *growth += self.drift[i] + shock;
+        }
+    }
+}
+
+/// Returns the Cholesky factor of the lower triangle of `covariance`.
+///
+/// Non-positive pivots (perfectly correlated or flat series) are clamped to zero, so the factor
+/// degrades gracefully to a positive semi-definite approximation.
+fn cholesky(covariance: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = covariance.len();
+    let mut l = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                l[i][j] = (covariance[i][i] - sum).max(0.0).sqrt();
+            } else if l[j][j] > 0.0 {
+                l[i][j] = (covariance[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+    l
+}
+
+/// A seeded xorshift generator, so simulations are reproducible.
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn new(seed: u64) -> Self {
+        // Scramble the seed, as xorshift state must be non-zero and small seeds start poorly
+        Self((seed ^ 0x9E37_79B9_7F4A_7C15).max(1))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Returns a uniform sample in (0, 1).
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+
+    /// Returns a standard normal sample (Box-Muller).
+    fn next_normal(&mut self) -> f64 {
+        let u1 = self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::approx_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_cholesky_reconstructs_covariance() {
+        let covariance = vec![vec![4.0, 0.0], vec![2.0, 5.0]];
+        let l = cholesky(&covariance);
+
+        assert_eq!(l[0][0], 2.0);
+        assert_eq!(l[1][0], 1.0);
+        assert_eq!(l[1][1], 2.0);
+    }
+
+    #[rstest]
+    fn test_normal_samples_are_standard() {
+        let mut rng = Xorshift::new(7);
+        let samples: Vec<f64> = (0..50_000).map(|_| rng.next_normal()).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        assert!(mean.abs() < 0.02);
+        assert!(approx_eq!(f64, var, 1.0, epsilon = 0.03));
+    }
+
+    #[rstest]
+    fn test_gbm_fit_recovers_correlation() {
+        // Perfectly correlated series, the second with twice the volatility
+        let history: Vec<Vec<f64>> = (0..100)
+            .map(|i| {
+                let r = if i % 2 == 0 { 0.01 } else { -0.01 };
+                vec![r, 2.0 * r]
+            })
+            .collect();
+        let model = GbmModel::fit(&history).unwrap();
+        let l = &model.cholesky;
+
+        assert!(approx_eq!(f64, l[1][0], 2.0 * l[0][0], epsilon = 1e-3));
+        assert!(l[1][1].abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(PathModel::Gbm)]
+    #[case(PathModel::Bootstrap)]
+    fn test_run_without_positions_errors(#[case] model: PathModel) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let config = MonteCarloConfig {
+            model,
+            paths: 100,
+            ..Default::default()
+        };
+        let engine = MonteCarloEngine::new(cache, config).unwrap();
+
+        assert!(engine.run(UnixNanos::default()).is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_config() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let config = MonteCarloConfig {
+            confidence_levels: vec![1.0],
+            ..Default::default()
+        };
+
+        assert!(MonteCarloEngine::new(cache, config).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Instrument constraint validation for the execution path.
+//!
+//! Before an order command is sent for execution, the [`OrderValidator`] checks every order it
+//! carries against the cached instrument's min/max quantity, quantity increment, min/max notional
+//! and price precision (using any variable tick size table registered for the instrument), so
+//! that orders a venue would reject are rejected locally with specific reasons.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashMap;
+use nautilus_model::{
+    identifiers::InstrumentId,
+    instruments::{schedule::InstrumentDefinition, tick_table::TickSizeTable},
+    orders::{
+        Order,
+        validation::{OrderValidationError, validate_order, validate_order_params},
+    },
+    types::{Price, Quantity},
+};
+
+use crate::{cache::Cache, messages::execution::TradingCommand};
+
+/// Validates order commands against instrument trading constraints.
+pub struct OrderValidator {
+    cache: Rc<RefCell<Cache>>,
+    tick_tables: AHashMap<InstrumentId, TickSizeTable>,
+}
+
+impl Debug for OrderValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(OrderValidator))
+            .field("tick_tables", &self.tick_tables.len())
+            .finish()
+    }
+}
+
+impl OrderValidator {
+    /// Creates a new [`OrderValidator`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self {
+            cache,
+            tick_tables: AHashMap::new(),
+        }
+    }
+
+    /// Registers the tick size table of `definition`, if any, for price validation.
+    pub fn add_definition(&mut self, definition: &InstrumentDefinition) {
+        if let Some(table) = &definition.tick_table {
+            self.tick_tables.insert(definition.id(), table.clone());
+        }
+    }
+
+    fn check_params(
+        &self,
+        instrument_id: &InstrumentId,
+        quantity: Quantity,
+        price: Option<Price>,
+    ) -> Vec<OrderValidationError> {
+        let cache = self.cache.borrow();
+        let Some(instrument) = cache.instrument(instrument_id) else {
+            log::warn!("Cannot validate order: instrument {instrument_id} not found");
+            return Vec::new();
+        };
+        validate_order_params(
+            instrument,
+            quantity,
+            price,
+            self.tick_tables.get(instrument_id),
+        )
+    }
+
+    /// Checks `command`. Cancel and query commands always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns every constraint violation of the orders in the command.
+    pub fn check(&self, command: &TradingCommand) -> Result<(), Vec<OrderValidationError>> {
+        let errors = match command {
+            TradingCommand::SubmitOrder(cmd) => {
+                let init = &cmd.order_init;
+                let mut errors = self.check_params(
+                    &cmd.instrument_id,
+                    init.quantity,
+                    init.price.or(init.trigger_price),
+                );
+                if init.price.is_some()
+                    && let Some(trigger_price) = init.trigger_price
+                {
+                    errors.extend(
+                        self.check_params(&cmd.instrument_id, init.quantity, Some(trigger_price))
+                            .into_iter()
+                            .filter(|e| {
+                                matches!(
+                                    e,
+                                    OrderValidationError::PricePrecision { .. }
+                                        | OrderValidationError::PriceOffTick { .. }
+                                )
+                            }),
+                    );
+                }
+                errors
+            }
+            TradingCommand::SubmitSpreadOrder(cmd) => self.check_params(
+                &cmd.instrument_id,
+                cmd.order_init.quantity,
+                cmd.order_init.price,
+            ),
+            TradingCommand::SubmitOrderList(cmd) => {
+                let cache = self.cache.borrow();
+                match cache.instrument(&cmd.instrument_id) {
+                    Some(instrument) => cmd
+                        .order_list
+                        .orders
+                        .iter()
+                        .flat_map(|order| {
+                            validate_order(
+                                instrument,
+                                order,
+                                self.tick_tables.get(&cmd.instrument_id),
+                            )
+                        })
+                        .collect(),
+                    None => Vec::new(),
+                }
+            }
+            TradingCommand::ModifyOrder(cmd) => {
+                let order = self.cache.borrow().order(&cmd.client_order_id).cloned();
+                let quantity = cmd
+                    .quantity
+                    .or_else(|| order.as_ref().map(|o| o.quantity()));
+                let price = cmd
+                    .price
+                    .or(cmd.trigger_price)
+                    .or_else(|| order.as_ref().and_then(|o| o.price()));
+                match quantity {
+                    Some(quantity) => self.check_params(&cmd.instrument_id, quantity, price),
+                    None => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            log::warn!(
+                "Order command {} failed validation: {}",
+                command.command_id(),
+                errors
+                    .iter()
+                    .map(OrderValidationError::rejection_reason)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            );
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        identifiers::{ClientOrderId, StrategyId, TraderId},
+        instruments::{
+            Instrument, InstrumentAny, stubs::currency_pair_btcusdt, tick_table::TickTier,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::ModifyOrder;
+
+    fn validator() -> (OrderValidator, InstrumentId) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let instrument_id = instrument.id();
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument).unwrap();
+        (
+            OrderValidator::new(Rc::new(RefCell::new(cache))),
+            instrument_id,
+        )
+    }
+
+    fn modify(instrument_id: InstrumentId, quantity: &str, price: &str) -> TradingCommand {
+        TradingCommand::ModifyOrder(ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            instrument_id,
+            ClientOrderId::from("O-1"),
+            None,
+            Some(Quantity::from(quantity)),
+            Some(Price::from(price)),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_modify_validated_against_instrument() {
+        let (validator, instrument_id) = validator();
+
+        assert!(
+            validator
+                .check(&modify(instrument_id, "1.000000", "50000.00"))
+                .is_ok()
+        );
+        let errors = validator
+            .check(&modify(instrument_id, "10000.000000", "50000.00"))
+            .unwrap_err();
+        assert_eq!(errors[0].reason(), "QUANTITY_ABOVE_MAX");
+    }
+
+    #[rstest]
+    fn test_tick_table_applies_to_prices() {
+        let (mut validator, instrument_id) = validator();
+        let table = TickSizeTable::new(vec![
+            TickTier {
+                start: Price::from("0.00"),
+                increment: Price::from("0.01"),
+            },
+            TickTier {
+                start: Price::from("10000.00"),
+                increment: Price::from("0.50"),
+            },
+        ]);
+        let cache = validator.cache.clone();
+        let instrument = cache.borrow().instrument(&instrument_id).cloned().unwrap();
+        validator
+            .add_definition(&InstrumentDefinition::new(instrument, vec![]).with_tick_table(table));
+
+        let errors = validator
+            .check(&modify(instrument_id, "1.000000", "50000.25"))
+            .unwrap_err();
+        assert_eq!(errors[0].reason(), "PRICE_OFF_TICK");
+        assert!(
+            validator
+                .check(&modify(instrument_id, "1.000000", "9999.99"))
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Stale price protection for the execution path.
+//!
+//! Before an order command is sent for execution, the [`StalePriceGuard`] compares the age of the
+//! latest cached quote (or trade, if no quote) for the instrument against a threshold. Commands
+//! for instruments with stale data are either held until fresh data arrives (up to a maximum
+//! hold time) or rejected with a [`StalePriceError`]. Cancel and query commands always pass.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, rc::Rc};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::identifiers::InstrumentId;
+use serde::{Deserialize, Serialize};
+
+use crate::{cache::Cache, messages::execution::TradingCommand};
+
+/// The action taken for commands on stale data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StalePriceAction {
+    /// Hold the command until fresh data arrives, rejecting after the maximum hold (nanoseconds).
+    Hold { max_hold_ns: u64 },
+    /// Reject the command immediately.
+    Reject,
+}
+
+/// An error for a command rejected due to stale market data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum StalePriceError {
+    #[error("No market data for {instrument_id}")]
+    NoMarketData { instrument_id: InstrumentId },
+    #[error("Stale market data for {instrument_id}: age {age_ns}ns exceeds {threshold_ns}ns")]
+    Stale {
+        instrument_id: InstrumentId,
+        age_ns: u64,
+        threshold_ns: u64,
+    },
+}
+
+/// The outcome of checking a command against the stale price guard.
+#[derive(Debug)]
+pub enum StalePriceDecision {
+    /// The command may proceed.
+    Allow(TradingCommand),
+    /// The command is held pending fresh data.
+    Held,
+    /// The command is rejected.
+    Rejected(TradingCommand, StalePriceError),
+}
+
+/// Holds or rejects order commands for instruments whose cached market data is stale.
+pub struct StalePriceGuard {
+    cache: Rc<RefCell<Cache>>,
+    threshold_ns: u64,
+    action: StalePriceAction,
+    held: VecDeque<(UnixNanos, TradingCommand)>,
+}
+
+impl Debug for StalePriceGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(StalePriceGuard))
+            .field("threshold_ns", &self.threshold_ns)
+            .field("action", &self.action)
+            .field("held", &self.held.len())
+            .finish()
+    }
+}
+
+impl StalePriceGuard {
+    /// Creates a new [`StalePriceGuard`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>, threshold_ns: u64, action: StalePriceAction) -> Self {
+        Self {
+            cache,
+            threshold_ns,
+            action,
+            held: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of held commands.
+    #[must_use]
+    pub fn held_count(&self) -> usize {
+        self.held.len()
+    }
+
+    /// Checks the freshness of cached market data for `instrument_id` at `ts_now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no market data, or it is older than the threshold.
+    pub fn check_instrument(
+        &self,
+        instrument_id: &InstrumentId,
+        ts_now: UnixNanos,
+    ) -> Result<(), StalePriceError> {
+        let cache = self.cache.borrow();
+        let ts_last = cache
+            .quote(instrument_id)
+            .map(|quote| quote.ts_event)
+            .or_else(|| cache.trade(instrument_id).map(|trade| trade.ts_event))
+            .ok_or(StalePriceError::NoMarketData {
+                instrument_id: *instrument_id,
+            })?;
+
+        let age_ns = ts_now.as_u64().saturating_sub(ts_last.as_u64());
+        if age_ns > self.threshold_ns {
+            return Err(StalePriceError::Stale {
+                instrument_id: *instrument_id,
+                age_ns,
+                threshold_ns: self.threshold_ns,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks `command` at `ts_now`, allowing, holding or rejecting it.
+    pub fn check(&mut self, command: TradingCommand, ts_now: UnixNanos) -> StalePriceDecision {
+        let instrument_id = match &command {
+            TradingCommand::SubmitOrder(cmd) => cmd.instrument_id,
+            TradingCommand::SubmitOrderList(cmd) => cmd.instrument_id,
+            TradingCommand::SubmitSpreadOrder(cmd) => cmd.instrument_id,
+            TradingCommand::ModifyOrder(cmd) => cmd.instrument_id,
+            _ => return StalePriceDecision::Allow(command),
+        };
+
+        match (self.check_instrument(&instrument_id, ts_now), self.action) {
+            (Ok(()), _) => StalePriceDecision::Allow(command),
+            (Err(e), StalePriceAction::Reject) => {
+                log::warn!("Rejecting command {}: {e}", command.command_id());
+                StalePriceDecision::Rejected(command, e)
+            }
+            (Err(e), StalePriceAction::Hold { .. }) => {
+                log::warn!("Holding command {}: {e}", command.command_id());
+                self.held.push_back((ts_now, command));
+                StalePriceDecision::Held
+            }
+        }
+    }
+
+    /// Re-checks held commands at `ts_now` (e.g. on each quote or a timer).
+    ///
+    /// Returns the commands released for execution, and those rejected after exceeding the
+    /// maximum hold time.
+    pub fn release(
+        &mut self,
+        ts_now: UnixNanos,
+    ) -> (Vec<TradingCommand>, Vec<(TradingCommand, StalePriceError)>) {
+        let max_hold_ns = match self.action {
+            StalePriceAction::Hold { max_hold_ns } => max_hold_ns,
+            StalePriceAction::Reject => 0,
+        };
+
+        let mut released = Vec::new();
+        let mut rejected = Vec::new();
+        let mut still_held = VecDeque::new();
+
+        let held = std::mem::take(&mut self.held);
+        for (ts_held, command) in held {
+            let instrument_id = command.instrument_id();
+            match self.check_instrument(&instrument_id, ts_now) {
+                Ok(()) => released.push(command),
+                Err(e) if ts_now.as_u64().saturating_sub(ts_held.as_u64()) >= max_hold_ns => {
+                    rejected.push((command, e));
+                }
+                Err(_) => still_held.push_back((ts_held, command)),
+            }
+        }
+
+        self.held = still_held;
+        (released, rejected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::OrderSide,
+        identifiers::{ClientOrderId, StrategyId, TraderId},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::execution::{CancelAllOrders, ModifyOrder};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AUD/USD.SIM")
+    }
+
+    fn quote(ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id(),
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from(1),
+            Quantity::from(1),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn guard(action: StalePriceAction) -> StalePriceGuard {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache.borrow_mut().add_quote(quote(100)).unwrap();
+        StalePriceGuard::new(cache, 50, action)
+    }
+
+    fn modify_command() -> TradingCommand {
+        TradingCommand::ModifyOrder(ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            instrument_id(),
+            ClientOrderId::from("O-1"),
+            None,
+            None,
+            Some(Price::from("1.00005")),
+            None,
+            UUID4::new(),
+            0.into(),
+            None,
+        ))
+    }
+
+    #[rstest]
+    #[case(120, true)]
+    #[case(150, true)]
+    #[case(151, false)]
+    fn test_check_instrument(#[case] ts_now: u64, #[case] fresh: bool) {
+        let guard = guard(StalePriceAction::Reject);
+
+        assert_eq!(
+            guard
+                .check_instrument(&instrument_id(), ts_now.into())
+                .is_ok(),
+            fresh
+        );
+        assert!(matches!(
+            guard.check_instrument(&InstrumentId::from("EUR/USD.SIM"), ts_now.into()),
+            Err(StalePriceError::NoMarketData { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_cancel_commands_always_allowed() {
+        let mut guard = guard(StalePriceAction::Reject);
+        let command = TradingCommand::CancelAllOrders(CancelAllOrders::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            instrument_id(),
+            OrderSide::NoOrderSide,
+            UUID4::new(),
+            0.into(),
+            None,
+        ));
+
+        assert!(matches!(
+            guard.check(command, 1_000.into()),
+            StalePriceDecision::Allow(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_reject_stale_command() {
+        let mut guard = guard(StalePriceAction::Reject);
+
+        assert!(matches!(
+            guard.check(modify_command(), 1_000.into()),
+            StalePriceDecision::Rejected(_, StalePriceError::Stale { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_hold_then_release_or_reject() {
+        let mut guard = guard(StalePriceAction::Hold { max_hold_ns: 100 });
+        assert!(matches!(
+            guard.check(modify_command(), 200.into()),
+            StalePriceDecision::Held
+        ));
+        assert!(matches!(
+            guard.check(modify_command(), 200.into()),
+            StalePriceDecision::Held
+        ));
+
+        let (released, rejected) = guard.release(250.into());
+        assert!(released.is_empty() && rejected.is_empty());
+
+        guard.cache.borrow_mut().add_quote(quote(260)).unwrap();
+        let (released, rejected) = guard.release(270.into());
+
+        assert_eq!(released.len(), 2);
+        assert!(rejected.is_empty());
+        assert_eq!(guard.held_count(), 0);
+
+        guard.check(modify_command(), 400.into());
+        let (released, rejected) = guard.release(500.into());
+
+        assert!(released.is_empty());
+        assert_eq!(rejected.len(), 1);
+    }
+}
@@ -0,0 +1,395 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Value at risk (VaR) and expected shortfall (ES) estimation.
+//!
+//! Losses are expressed as positive numbers in the portfolio's quote units. One-period estimates
+//! are scaled to longer horizons using the square-root-of-time rule.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use ahash::AHashMap;
+use nautilus_core::{
+    UnixNanos,
+    math::{norm_inv_cdf, norm_pdf},
+};
+use nautilus_model::{
+    data::{Bar, BarType},
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    msgbus::{self, MStr, Topic},
+    risk::open_exposures,
+};
+
+/// The default topic risk telemetry for VaR is published on.
+pub const VAR_TELEMETRY_TOPIC: &str = "risk.telemetry.var";
+
+/// The method used to estimate VaR and expected shortfall.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VarMethod {
+    /// Empirical quantiles of the historical profit and loss distribution.
+    #[default]
+    Historical,
+    /// Quantiles of a normal distribution fitted to the historical profit and loss.
+    Parametric,
+}
+
+/// A VaR and expected shortfall estimate (both reported as positive losses).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VarEstimate {
+    pub value_at_risk: f64,
+    pub expected_shortfall: f64,
+}
+
+impl VarEstimate {
+    /// Scales a one-period estimate to `horizon` periods using the square-root-of-time rule.
+    #[must_use]
+    pub fn scaled(self, horizon: u32) -> Self {
+        let factor = f64::from(horizon).sqrt();
+        Self {
+            value_at_risk: self.value_at_risk * factor,
+            expected_shortfall: self.expected_shortfall * factor,
+        }
+    }
+}
+
+/// Computes historical-simulation VaR and expected shortfall from a profit and loss series.
+///
+/// # Errors
+///
+/// Returns an error if `pnl` is empty or `confidence` is not in (0, 1).
+pub fn historical_var(pnl: &[f64], confidence: f64) -> anyhow::Result<VarEstimate> {
+    check_inputs(pnl, confidence)?;
+
+    let mut losses: Vec<f64> = pnl.iter().map(|x| -x).collect();
+    losses.sort_by(f64::total_cmp);
+
+    let n = losses.len();
+    let index = ((confidence * n as f64).ceil() as usize).clamp(1, n) - 1;
+    let value_at_risk = losses[index];
+    let tail = &losses[index..];
+    let expected_shortfall = tail.iter().sum::<f64>() / tail.len() as f64;
+
+    Ok(VarEstimate {
+        value_at_risk,
+        expected_shortfall,
+    })
+}
+
+/// Computes parametric (normal) VaR and expected shortfall from a profit and loss series.
+///
+/// # Errors
+///
+/// Returns an error if `pnl` has fewer than two observations or `confidence` is not in (0, 1).
+pub fn parametric_var(pnl: &[f64], confidence: f64) -> anyhow::Result<VarEstimate> {
+    check_inputs(pnl, confidence)?;
+    if pnl.len() < 2 {
+        anyhow::bail!("Parametric VaR requires at least 2 observations");
+    }
+
+    let n = pnl.len() as f64;
+    let mean = pnl.iter().sum::<f64>() / n;
+    let variance = pnl.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std = variance.sqrt();
+    let z = norm_inv_cdf(confidence);
+
+    Ok(VarEstimate {
+        value_at_risk: z.mul_add(std, -mean),
+        expected_shortfall: (norm_pdf(z) / (1.0 - confidence)).mul_add(std, -mean),
+    })
+}
+
+fn check_inputs(pnl: &[f64], confidence: f64) -> anyhow::Result<()> {
+    if pnl.is_empty() {
+        anyhow::bail!("Cannot compute VaR from an empty series");
+    }
+    if !(confidence > 0.0 && confidence < 1.0) {
+        anyhow::bail!("`confidence` must be in (0, 1), was {confidence}");
+    }
+    Ok(())
+}
+
+/// Computes simple returns from `bars` ordered as held in the cache (most recent first).
+///
+/// The returns are ordered chronologically.
+#[must_use]
+pub fn returns_from_bars(bars: &[Bar]) -> Vec<f64> {
+    let closes: Vec<f64> = bars.iter().rev().map(|bar| bar.close.as_f64()).collect();
+    closes
+        .windows(2)
+        .filter(|pair| pair[0] != 0.0)
+        .map(|pair| pair[1] / pair[0] - 1.0)
+        .collect()
+}
+
+/// Configuration for a [`PortfolioVarCalculator`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VarConfig {
+    /// The estimation method.
+    pub method: VarMethod,
+    /// The confidence levels to report, e.g. 0.95 and 0.99.
+    pub confidence_levels: Vec<f64>,
+    /// The horizons (in return periods) to report.
+    pub horizons: Vec<u32>,
+    /// The maximum number of most recent return observations to use (`None` for all).
+    pub lookback: Option<usize>,
+}
+
+impl Default for VarConfig {
+    fn default() -> Self {
+        Self {
+            method: VarMethod::Historical,
+            confidence_levels: vec![0.95, 0.99],
+            horizons: vec![1],
+            lookback: None,
+        }
+    }
+}
+
+/// Portfolio VaR and expected shortfall for a single confidence level and horizon.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VarReport {
+    pub method: VarMethod,
+    pub confidence: f64,
+    pub horizon: u32,
+    pub value_at_risk: f64,
+    pub expected_shortfall: f64,
+    pub gross_exposure: f64,
+    pub observations: usize,
+    pub ts_init: UnixNanos,
+}
+
+/// Calculates portfolio VaR and expected shortfall from open positions in the cache.
+///
+/// Each open position's exposure (signed quantity x multiplier x last price) is combined with
+/// the return series registered for its instrument to form the portfolio profit and loss
+/// series, which is then evaluated for every configured confidence level and horizon.
+pub struct PortfolioVarCalculator {
+    cache: Rc<RefCell<Cache>>,
+    config: VarConfig,
+    topic: MStr<Topic>,
+    returns: AHashMap<InstrumentId, Vec<f64>>,
+}
+
+impl Debug for PortfolioVarCalculator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(PortfolioVarCalculator))
+            .field("config", &self.config)
+            .field("topic", &self.topic)
+            .field("instruments", &self.returns.len())
+            .finish()
+    }
+}
+
+impl PortfolioVarCalculator {
+    /// Creates a new [`PortfolioVarCalculator`] instance.
+    #[must_use]
+    pub fn new(cache: Rc<RefCell<Cache>>, config: VarConfig) -> Self {
+        Self {
+            cache,
+            config,
+            topic: VAR_TELEMETRY_TOPIC.into(),
+            returns: AHashMap::new(),
+        }
+    }
+
+    /// Returns the calculator configuration.
+    #[must_use]
+    pub const fn config(&self) -> &VarConfig {
+        &self.config
+    }
+
+    /// Sets the topic reports are published on.
+    pub fn set_topic(&mut self, topic: MStr<Topic>) {
+        self.topic = topic;
+    }
+
+    /// Sets the chronological return series for `instrument_id`.
+    pub fn set_returns(&mut self, instrument_id: InstrumentId, returns: Vec<f64>) {
+        self.returns.insert(instrument_id, returns);
+    }
+
+    /// Sets the return series for `instrument_id` from the bars currently held in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no bars are cached for `bar_type`.
+    pub fn set_returns_from_cache(&mut self, bar_type: &BarType) -> anyhow::Result<()> {
+        let bars = self
+            .cache
+            .borrow()
+            .bars(bar_type)
+            .ok_or_else(|| anyhow::anyhow!("No bars cached for {bar_type}"))?;
+        self.set_returns(bar_type.instrument_id(), returns_from_bars(&bars));
+        Ok(())
+    }
+
+    /// Returns the current exposure per instrument across all open positions.
+    #[must_use]
+    pub fn exposures(&self) -> AHashMap<InstrumentId, f64> {
+        open_exposures(&self.cache.borrow())
+    }
+
+    /// Returns the portfolio profit and loss series implied by current exposures.
+    ///
+    /// Series are aligned on their most recent observations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exposed instrument has no registered return series.
+    pub fn pnl_series(&self) -> anyhow::Result<Vec<f64>> {
+        let exposures = self.exposures();
+
+        let mut len = self.config.lookback.unwrap_or(usize::MAX);
+        for instrument_id in exposures.keys() {
+            match self.returns.get(instrument_id) {
+                Some(returns) => len = len.min(returns.len()),
+                None => anyhow::bail!("No return series for {instrument_id}"),
+            }
+        }
+        if exposures.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut pnl = vec![0.0; len];
+        for (instrument_id, exposure) in &exposures {
+            let returns = &self.returns[instrument_id];
+            let recent = &returns[returns.len() - len..];
+            for (total, r) in pnl.iter_mut().zip(recent) {
+                *total += exposure * r;
+            }
+        }
+
+        Ok(pnl)
+    }
+
+    /// Calculates reports for every configured confidence level and horizon.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profit and loss series cannot be formed or estimated.
+    pub fn calculate(&self, ts_init: UnixNanos) -> anyhow::Result<Vec<VarReport>> {
+        let pnl = self.pnl_series()?;
+        let gross_exposure = self.exposures().values().map(|x| x.abs()).sum();
+
+        let mut reports = Vec::new();
+        for &confidence in &self.config.confidence_levels {
+            let estimate = match self.config.method {
+                VarMethod::Historical => historical_var(&pnl, confidence)?,
+                VarMethod::Parametric => parametric_var(&pnl, confidence)?,
+            };
+            for &horizon in &self.config.horizons {
+                let scaled = estimate.scaled(horizon);
+                reports.push(VarReport {
+                    method: self.config.method,
+                    confidence,
+                    horizon,
+                    value_at_risk: scaled.value_at_risk,
+                    expected_shortfall: scaled.expected_shortfall,
+                    gross_exposure,
+                    observations: pnl.len(),
+                    ts_init,
+                });
+            }
+        }
+
+        Ok(reports)
+    }
+
+    /// Calculates and publishes reports as risk telemetry, intended to be called from a timer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the calculation fails.
+    pub fn publish(&self, ts_init: UnixNanos) -> anyhow::Result<Vec<VarReport>> {
+        let reports = self.calculate(ts_init)?;
+        msgbus::publish_any(self.topic, &reports);
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::approx_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_historical_var_and_es() {
+        let pnl: Vec<f64> = (1..=100).map(|i| f64::from(i) - 50.0).collect();
+
+        let estimate = historical_var(&pnl, 0.95).unwrap();
+
+        // Losses range from -50 to 49, the 95th percentile is the 95th smallest
+        assert_eq!(estimate.value_at_risk, 44.0);
+        assert!(approx_eq!(
+            f64,
+            estimate.expected_shortfall,
+            46.5,
+            epsilon = 1e-12
+        ));
+    }
+
+    #[rstest]
+    fn test_parametric_var_and_es() {
+        let pnl = vec![-1.0, 1.0, -1.0, 1.0];
+        let std = (4.0_f64 / 3.0).sqrt();
+
+        let estimate = parametric_var(&pnl, 0.99).unwrap();
+
+        assert!(approx_eq!(
+            f64,
+            estimate.value_at_risk,
+            2.326_347_874 * std,
+            epsilon = 1e-6
+        ));
+        assert!(estimate.expected_shortfall > estimate.value_at_risk);
+    }
+
+    #[rstest]
+    fn test_estimate_scaled_by_sqrt_time() {
+        let estimate = VarEstimate {
+            value_at_risk: 2.0,
+            expected_shortfall: 3.0,
+        }
+        .scaled(4);
+
+        assert_eq!(estimate.value_at_risk, 4.0);
+        assert_eq!(estimate.expected_shortfall, 6.0);
+    }
+
+    #[rstest]
+    #[case(vec![], 0.95)]
+    #[case(vec![1.0], 1.0)]
+    #[case(vec![1.0], 0.0)]
+    fn test_invalid_inputs_error(#[case] pnl: Vec<f64>, #[case] confidence: f64) {
+        assert!(historical_var(&pnl, confidence).is_err());
+    }
+
+    #[rstest]
+    fn test_calculator_without_positions_errors_on_empty_series() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let calculator = PortfolioVarCalculator::new(cache, VarConfig::default());
+
+        assert!(calculator.exposures().is_empty());
+        assert!(calculator.pnl_series().unwrap().is_empty());
+        assert!(calculator.calculate(UnixNanos::default()).is_err());
+    }
+}
@@ -14,6 +14,9 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Cap'n Proto serialization for trading commands.
+//!
+//! Spread orders (`TradingCommand::SubmitSpreadOrder`) are not part of the trading schema yet and
+//! cannot be serialized.
 
 use indexmap::IndexMap;
 use nautilus_core::{UUID4, UnixNanos};
@@ -324,6 +327,10 @@ impl<'a> ToCapnp<'a> for TradingCommand {
                 let submit_list_builder = builder.init_submit_order_list();
                 command.to_capnp(submit_list_builder);
             }
+            Self::SubmitSpreadOrder(command) => {
+                // The trading schema has no spread order command yet
+                unimplemented!("Cap'n Proto serialization of {command}")
+            }
             Self::ModifyOrder(command) => {
                 let modify_builder = builder.init_modify_order();
                 command.to_capnp(modify_builder);
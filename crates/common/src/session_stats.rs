@@ -0,0 +1,278 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Publication of session-anchored VWAP, high/low and cumulative volume statistics.
+//!
+//! The [`SessionStatsPublisher`] accumulates [`SessionStatistics`] per instrument from trades,
+//! anchored at the most recent open of a [`TradingSession`]. Statistics are reset as each new
+//! session opens and published on the instrument's session statistics topic at a configurable
+//! interval.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::{UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{
+    data::{SessionStatistics, SessionStatisticsCalculator, TradeTick},
+    identifiers::InstrumentId,
+    matching::TradingSession,
+};
+
+use crate::{
+    clock::Clock,
+    msgbus::{self, switchboard::get_session_stats_topic},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// Accumulates and periodically publishes session statistics for a set of instruments.
+#[derive(Clone)]
+pub struct SessionStatsPublisher {
+    session: TradingSession,
+    interval_ns: u64,
+    calculators: Rc<RefCell<IndexMap<InstrumentId, SessionStatisticsCalculator>>>,
+}
+
+impl Debug for SessionStatsPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(SessionStatsPublisher))
+            .field("session", &self.session)
+            .field("interval_ns", &self.interval_ns)
+            .field("instruments", &self.calculators.borrow().len())
+            .finish()
+    }
+}
+
+impl SessionStatsPublisher {
+    /// The name of the timer used to publish statistics.
+    pub const TIMER_NAME: &str = "SessionStats";
+
+    /// Creates a new [`SessionStatsPublisher`] instance, publishing every `interval_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval_ns` is zero.
+    #[must_use]
+    pub fn new(session: TradingSession, interval_ns: u64) -> Self {
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        Self {
+            session,
+            interval_ns,
+            calculators: Rc::new(RefCell::new(IndexMap::new())),
+        }
+    }
+
+    /// Returns the session open at or before `ts`, which anchors the statistics at `ts`.
+    #[must_use]
+    pub fn anchor_for(&self, ts: UnixNanos) -> UnixNanos {
+        let open = self.session.open_for(ts);
+        if open > ts {
+            UnixNanos::from(open.as_u64().saturating_sub(NANOSECONDS_IN_DAY))
+        } else {
+            open
+        }
+    }
+
+    /// Adds `instrument_id`, anchored at the session open at or before `ts_now`.
+    pub fn add_instrument(&self, instrument_id: InstrumentId, ts_now: UnixNanos) {
+        let anchor = self.anchor_for(ts_now);
+        self.calculators
+            .borrow_mut()
+            .entry(instrument_id)
+            .or_insert_with(|| SessionStatisticsCalculator::new(instrument_id, anchor));
+    }
+
+    /// Removes `instrument_id`.
+    pub fn remove_instrument(&self, instrument_id: &InstrumentId) {
+        self.calculators.borrow_mut().shift_remove(instrument_id);
+    }
+
+    /// Handles a trade, resetting the instrument statistics if a new session has opened.
+    pub fn on_trade(&self, trade: &TradeTick) {
+        let anchor = self.anchor_for(trade.ts_event);
+        let mut calculators = self.calculators.borrow_mut();
+        if let Some(calculator) = calculators.get_mut(&trade.instrument_id) {
+            if anchor > calculator.anchor() {
+                calculator.reset(anchor);
+            }
+            calculator.handle_trade(trade);
+        }
+    }
+
+    /// Resets the statistics for `instrument_id`, anchoring them at `anchor`.
+    pub fn reset(&self, instrument_id: &InstrumentId, anchor: UnixNanos) {
+        if let Some(calculator) = self.calculators.borrow_mut().get_mut(instrument_id) {
+            calculator.reset(anchor);
+        }
+    }
+
+    /// Returns the current statistics for `instrument_id` at `ts_now`.
+    #[must_use]
+    pub fn statistics(
+        &self,
+        instrument_id: &InstrumentId,
+        ts_now: UnixNanos,
+    ) -> Option<SessionStatistics> {
+        self.calculators
+            .borrow()
+            .get(instrument_id)
+            .map(|calculator| calculator.snapshot(ts_now))
+    }
+
+    /// Publishes the statistics of all instruments at `ts_now`, first resetting those whose
+    /// session has rolled over.
+    pub fn publish(&self, ts_now: UnixNanos) {
+        let anchor = self.anchor_for(ts_now);
+        let snapshots: Vec<_> = self
+            .calculators
+            .borrow_mut()
+            .values_mut()
+            .map(|calculator| {
+                if anchor > calculator.anchor() {
+                    calculator.reset(anchor);
+                }
+                calculator.snapshot(ts_now)
+            })
+            .collect();
+
+        for stats in snapshots {
+            msgbus::publish_any(get_session_stats_topic(stats.instrument_id), &stats);
+        }
+    }
+
+    /// Starts publishing statistics on `clock` every interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer.
+    pub fn start(&self, clock: &mut dyn Clock) -> anyhow::Result<()> {
+        let publisher = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            publisher.publish(event.ts_event);
+        });
+        clock.set_timer_ns(
+            Self::TIMER_NAME,
+            self.interval_ns,
+            None,
+            None,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Stops publishing statistics on `clock`.
+    pub fn stop(&self, clock: &mut dyn Clock) {
+        clock.cancel_timer(Self::TIMER_NAME);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::TradeId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{clock::TestClock, msgbus::ShareableMessageHandler};
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ESZ24.XCME")
+    }
+
+    fn trade(price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id(),
+            Price::from(price),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn publisher() -> SessionStatsPublisher {
+        let publisher = SessionStatsPublisher::new(TradingSession::new(9 * HOUR, 17 * HOUR), HOUR);
+        publisher.add_instrument(instrument_id(), (10 * HOUR).into());
+        publisher
+    }
+
+    #[rstest]
+    #[case(10 * HOUR, 9 * HOUR)]
+    #[case(9 * HOUR, 9 * HOUR)]
+    #[case(33 * HOUR, 33 * HOUR)]
+    #[case(32 * HOUR, 9 * HOUR)]
+    fn test_anchor_for(#[case] ts: u64, #[case] expected: u64) {
+        assert_eq!(publisher().anchor_for(ts.into()), UnixNanos::from(expected));
+    }
+
+    #[rstest]
+    fn test_statistics_reset_at_next_session_open() {
+        let publisher = publisher();
+        publisher.on_trade(&trade("5000.00", 10 * HOUR));
+        publisher.on_trade(&trade("5010.00", 11 * HOUR));
+        let stats = publisher
+            .statistics(&instrument_id(), (12 * HOUR).into())
+            .unwrap();
+        assert_eq!(stats.vwap, Some(5005.0));
+        assert_eq!(stats.trade_count, 2);
+
+        publisher.on_trade(&trade("5020.00", 34 * HOUR));
+        let stats = publisher
+            .statistics(&instrument_id(), (34 * HOUR).into())
+            .unwrap();
+        assert_eq!(stats.anchor, UnixNanos::from(33 * HOUR));
+        assert_eq!(stats.vwap, Some(5020.0));
+        assert_eq!(stats.trade_count, 1);
+    }
+
+    #[rstest]
+    fn test_publishes_on_interval() {
+        let publisher = publisher();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        let handler = ShareableMessageHandler::from_typed(move |stats: &SessionStatistics| {
+            received_clone.borrow_mut().push(*stats);
+        });
+        msgbus::subscribe_any(
+            get_session_stats_topic(instrument_id()).into(),
+            handler,
+            None,
+        );
+
+        let mut clock = TestClock::new();
+        clock.advance_time((10 * HOUR).into(), true);
+        publisher.start(&mut clock).unwrap();
+        publisher.on_trade(&trade("5000.00", 10 * HOUR));
+
+        let events = clock.advance_time((12 * HOUR).into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let received = received.borrow();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[1].high, Some(Price::from("5000.00")));
+        assert_eq!(received[1].ts_init, UnixNanos::from(12 * HOUR));
+    }
+}
@@ -0,0 +1,351 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock skew detection against venue provided timestamps.
+//!
+//! Venue events such as quotes and fills carry the venue's own `ts_event`. Comparing these with
+//! the local clock at receipt gives an observed offset per client, which combines the true clock
+//! skew with one-way network latency. The [`ClockSkewMonitor`] keeps a rolling window of these
+//! offsets per client, exposes a robust (median based) estimate, and warns when the estimate
+//! drifts beyond the configured threshold.
+
+use std::collections::VecDeque;
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{data::QuoteTick, events::OrderFilled, identifiers::ClientId};
+
+/// The default number of samples in the rolling window per client.
+pub const DEFAULT_SKEW_WINDOW: usize = 256;
+
+/// The default drift threshold (nanoseconds) above which a warning is logged (250ms).
+pub const DEFAULT_SKEW_WARN_THRESHOLD_NS: u64 = 250_000_000;
+
+/// Configuration for a [`ClockSkewMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockSkewConfig {
+    /// The number of samples in the rolling window per client.
+    pub window: usize,
+    /// The absolute skew (nanoseconds) above which a warning is logged.
+    pub warn_threshold_ns: u64,
+    /// The minimum number of samples required before warnings are evaluated.
+    pub min_samples: usize,
+}
+
+impl Default for ClockSkewConfig {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_SKEW_WINDOW,
+            warn_threshold_ns: DEFAULT_SKEW_WARN_THRESHOLD_NS,
+            min_samples: 10,
+        }
+    }
+}
+
+/// A rolling clock skew estimate for a single client.
+///
+/// Offsets are `local - venue` in nanoseconds, so a positive value means events are received
+/// after their venue timestamp (latency and/or the local clock running ahead), and a negative
+/// value means the venue clock is ahead of the local clock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SkewEstimate {
+    /// The client the estimate applies to.
+    pub client_id: ClientId,
+    /// The number of samples in the window.
+    pub samples: usize,
+    /// The median offset (nanoseconds), the primary estimate.
+    pub median_ns: i64,
+    /// The mean offset (nanoseconds).
+    pub mean_ns: i64,
+    /// The minimum offset (nanoseconds), a lower bound on skew plus latency.
+    pub min_ns: i64,
+    /// The maximum offset (nanoseconds).
+    pub max_ns: i64,
+    /// UNIX timestamp (nanoseconds) of the last local receipt time.
+    pub ts_last: UnixNanos,
+}
+
+impl SkewEstimate {
+    /// Returns whether the absolute median offset exceeds `threshold_ns`.
+    #[must_use]
+    pub const fn exceeds(&self, threshold_ns: u64) -> bool {
+        self.median_ns.unsigned_abs() > threshold_ns
+    }
+}
+
+#[derive(Debug, Default)]
+struct ClientSkew {
+    offsets: VecDeque<i64>,
+    sum: i128,
+    ts_last: UnixNanos,
+    is_warning: bool,
+}
+
+/// Monitors the offset between local time and venue event timestamps per client.
+#[derive(Debug, Default)]
+pub struct ClockSkewMonitor {
+    config: ClockSkewConfig,
+    clients: AHashMap<ClientId, ClientSkew>,
+}
+
+impl ClockSkewMonitor {
+    /// Creates a new [`ClockSkewMonitor`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `config.window` is zero.
+    #[must_use]
+    pub fn new(config: ClockSkewConfig) -> Self {
+        assert!(config.window > 0, "`window` must be positive");
+        Self {
+            config,
+            clients: AHashMap::new(),
+        }
+    }
+
+    /// Returns the monitor configuration.
+    #[must_use]
+    pub const fn config(&self) -> &ClockSkewConfig {
+        &self.config
+    }
+
+    /// Records a venue timestamp `ts_venue` received at local time `ts_local` for `client_id`.
+    ///
+    /// Returns the updated estimate for the client.
+    pub fn record(
+        &mut self,
+        client_id: ClientId,
+        ts_venue: UnixNanos,
+        ts_local: UnixNanos,
+    ) -> SkewEstimate {
+        let offset = ts_local.as_i64() - ts_venue.as_i64();
+        let window = self.config.window;
+
+        let state = self.clients.entry(client_id).or_default();
+        state.offsets.push_back(offset);
+        state.sum += i128::from(offset);
+        state.ts_last = ts_local;
+
+        while state.offsets.len() > window {
+            if let Some(expired) = state.offsets.pop_front() {
+                state.sum -= i128::from(expired);
+            }
+        }
+
+        let estimate = compute_estimate(client_id, state);
+
+        if estimate.samples >= self.config.min_samples {
+            let exceeds = estimate.exceeds(self.config.warn_threshold_ns);
+            if exceeds && !state.is_warning {
+                log::warn!(
+                    "Clock skew for {client_id} exceeds threshold: median={}ns, threshold={}ns",
+                    estimate.median_ns,
+                    self.config.warn_threshold_ns,
+                );
+            } else if !exceeds && state.is_warning {
+                log::info!(
+                    "Clock skew for {client_id} back within threshold: median={}ns",
+                    estimate.median_ns,
+                );
+            }
+            state.is_warning = exceeds;
+        }
+
+        estimate
+    }
+
+    /// Records the venue timestamp of a `quote` received from `client_id` at `ts_local`.
+    pub fn record_quote(
+        &mut self,
+        client_id: ClientId,
+        quote: &QuoteTick,
+        ts_local: UnixNanos,
+    ) -> SkewEstimate {
+        self.record(client_id, quote.ts_event, ts_local)
+    }
+
+    /// Records the venue timestamp of a `fill` received from `client_id` at `ts_local`.
+    pub fn record_fill(
+        &mut self,
+        client_id: ClientId,
+        fill: &OrderFilled,
+        ts_local: UnixNanos,
+    ) -> SkewEstimate {
+        self.record(client_id, fill.ts_event, ts_local)
+    }
+
+    /// Returns the current estimate for `client_id` (if any samples were recorded).
+    #[must_use]
+    pub fn estimate(&self, client_id: &ClientId) -> Option<SkewEstimate> {
+        self.clients
+            .get(client_id)
+            .filter(|state| !state.offsets.is_empty())
+            .map(|state| compute_estimate(*client_id, state))
+    }
+
+    /// Returns the current estimates for all clients.
+    #[must_use]
+    pub fn estimates(&self) -> Vec<SkewEstimate> {
+        self.clients
+            .iter()
+            .filter(|(_, state)| !state.offsets.is_empty())
+            .map(|(client_id, state)| compute_estimate(*client_id, state))
+            .collect()
+    }
+
+    /// Returns whether the estimate for `client_id` currently exceeds the warning threshold.
+    #[must_use]
+    pub fn is_warning(&self, client_id: &ClientId) -> bool {
+        self.clients
+            .get(client_id)
+            .is_some_and(|state| state.is_warning)
+    }
+
+    /// Clears all samples for `client_id` (e.g. after a reconnect).
+    pub fn reset_client(&mut self, client_id: &ClientId) {
+        self.clients.remove(client_id);
+    }
+
+    /// Clears all samples for all clients.
+    pub fn reset(&mut self) {
+        self.clients.clear();
+    }
+}
+
+fn compute_estimate(client_id: ClientId, state: &ClientSkew) -> SkewEstimate {
+    let samples = state.offsets.len();
+    let mut sorted: Vec<i64> = state.offsets.iter().copied().collect();
+    sorted.sort_unstable();
+
+    let median_ns = if samples.is_multiple_of(2) {
+        let (lo, hi) = (sorted[samples / 2 - 1], sorted[samples / 2]);
+        lo + (hi - lo) / 2
+    } else {
+        sorted[samples / 2]
+    };
+
+    SkewEstimate {
+        client_id,
+        samples,
+        median_ns,
+        mean_ns: (state.sum / samples as i128) as i64,
+        min_ns: sorted[0],
+        max_ns: sorted[samples - 1],
+        ts_last: state.ts_last,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn client() -> ClientId {
+        ClientId::from("BINANCE")
+    }
+
+    #[rstest]
+    fn test_no_estimate_without_samples() {
+        let monitor = ClockSkewMonitor::default();
+        assert!(monitor.estimate(&client()).is_none());
+        assert!(monitor.estimates().is_empty());
+    }
+
+    #[rstest]
+    fn test_record_positive_and_negative_offsets() {
+        let mut monitor = ClockSkewMonitor::new(ClockSkewConfig::default());
+
+        monitor.record(client(), UnixNanos::from(1_000), UnixNanos::from(1_100));
+        monitor.record(client(), UnixNanos::from(2_000), UnixNanos::from(1_900));
+        let estimate = monitor.record(client(), UnixNanos::from(3_000), UnixNanos::from(3_300));
+
+        assert_eq!(estimate.samples, 3);
+        assert_eq!(estimate.median_ns, 100);
+        assert_eq!(estimate.mean_ns, 100);
+        assert_eq!(estimate.min_ns, -100);
+        assert_eq!(estimate.max_ns, 300);
+        assert_eq!(estimate.ts_last, UnixNanos::from(3_300));
+    }
+
+    #[rstest]
+    fn test_rolling_window_drops_old_samples() {
+        let config = ClockSkewConfig {
+            window: 2,
+            ..Default::default()
+        };
+        let mut monitor = ClockSkewMonitor::new(config);
+
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(1_000));
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(10));
+        let estimate = monitor.record(client(), UnixNanos::from(0), UnixNanos::from(20));
+
+        assert_eq!(estimate.samples, 2);
+        assert_eq!(estimate.median_ns, 15);
+        assert_eq!(estimate.max_ns, 20);
+    }
+
+    #[rstest]
+    fn test_warning_state_transitions() {
+        let config = ClockSkewConfig {
+            window: 1,
+            warn_threshold_ns: 100,
+            min_samples: 1,
+        };
+        let mut monitor = ClockSkewMonitor::new(config);
+
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(50));
+        assert!(!monitor.is_warning(&client()));
+
+        monitor.record(client(), UnixNanos::from(1_000), UnixNanos::from(800));
+        assert!(monitor.is_warning(&client()));
+
+        monitor.record(client(), UnixNanos::from(1_000), UnixNanos::from(1_010));
+        assert!(!monitor.is_warning(&client()));
+    }
+
+    #[rstest]
+    fn test_warning_requires_min_samples() {
+        let config = ClockSkewConfig {
+            warn_threshold_ns: 100,
+            min_samples: 3,
+            ..Default::default()
+        };
+        let mut monitor = ClockSkewMonitor::new(config);
+
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(1_000));
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(1_000));
+        assert!(!monitor.is_warning(&client()));
+
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(1_000));
+        assert!(monitor.is_warning(&client()));
+    }
+
+    #[rstest]
+    fn test_clients_are_tracked_independently() {
+        let mut monitor = ClockSkewMonitor::default();
+        let other = ClientId::from("BYBIT");
+
+        monitor.record(client(), UnixNanos::from(0), UnixNanos::from(100));
+        monitor.record(other, UnixNanos::from(0), UnixNanos::from(500));
+
+        assert_eq!(monitor.estimate(&client()).unwrap().median_ns, 100);
+        assert_eq!(monitor.estimate(&other).unwrap().median_ns, 500);
+        assert_eq!(monitor.estimates().len(), 2);
+
+        monitor.reset_client(&other);
+        assert!(monitor.estimate(&other).is_none());
+    }
+}
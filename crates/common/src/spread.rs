@@ -0,0 +1,718 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution of multi-leg spread orders.
+//!
+//! A [`SubmitSpreadOrder`] is routed by a [`SpreadOrderManager`] according to the venue's
+//! [`SpreadSupport`]: venues with native combo orders receive the spread order as is, while for
+//! other venues the spread is legged into synchronized single orders, one per leg, sized by the
+//! leg ratios. Fills of either form are combined into [`SpreadFill`]s reported in spread units.
+//!
+//! Fills are deduplicated by trade ID, and fill quantity beyond the spread (or leg) quantity is
+//! recorded as over-filled rather than combined, including for fills arriving after the spread
+//! has completely filled.
+
+use std::collections::VecDeque;
+
+use ahash::{AHashMap, AHashSet};
+use nautilus_core::UUID4;
+use nautilus_model::{
+    enums::{OrderSide, OrderType},
+    events::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId, TradeId},
+    types::{Quantity, quantity::QuantityRaw},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::execution::{SpreadLeg, SubmitOrder, SubmitSpreadOrder};
+
+/// The number of completely filled spreads kept to detect late fills.
+const MAX_COMPLETED_SPREADS: usize = 1_024;
+
+/// Whether a venue supports spread (combo) orders natively.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpreadSupport {
+    /// The venue executes the spread as a single combo order.
+    #[default]
+    Native,
+    /// The spread is executed as synchronized orders for each leg.
+    Legged,
+}
+
+/// The commands to send for a spread order.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpreadRouting {
+    /// The spread order for a venue-native combo order.
+    Native(SubmitSpreadOrder),
+    /// The leg orders, to be submitted together.
+    Legged(Vec<SubmitOrder>),
+}
+
+/// The tracked state of a single spread leg.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpreadLegState {
+    pub leg: SpreadLeg,
+    /// The leg order client order ID (the spread order ID when executed natively).
+    pub client_order_id: ClientOrderId,
+    pub filled_qty: f64,
+    pub avg_px: Option<f64>,
+    /// The leg fill quantity beyond the leg quantity, which is not combined.
+    pub overfilled_qty: f64,
+}
+
+/// The tracked state of a single spread order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpreadState {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub order_side: OrderSide,
+    pub support: SpreadSupport,
+    /// The spread order quantity in spread units.
+    pub quantity: f64,
+    /// The combined filled quantity in spread units.
+    pub filled_qty: f64,
+    /// The average net spread price of the combined fills.
+    pub avg_px: Option<f64>,
+    /// The native fill quantity beyond the spread quantity, which is not combined.
+    pub overfilled_qty: f64,
+    pub legs: Vec<SpreadLegState>,
+    /// The trades applied, by (native or leg) client order ID.
+    pub trade_ids: AHashSet<(ClientOrderId, TradeId)>,
+}
+
+impl SpreadState {
+    /// Returns whether the spread order is completely filled.
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        self.filled_qty >= self.quantity - f64::EPSILON * self.quantity.max(1.0)
+    }
+
+    /// Records the unfilled part of `qty` for the native order or leg `client_order_id`, returning
+    /// the part to combine, with the remainder recorded as over-filled.
+    fn apply_qty(&mut self, client_order_id: ClientOrderId, qty: f64) -> f64 {
+        let quantity = self.quantity;
+        let (filled_qty, overfilled_qty, expected_qty) = match self.support {
+            SpreadSupport::Native => (self.filled_qty, &mut self.overfilled_qty, quantity),
+            SpreadSupport::Legged => {
+                let Some(leg) = self
+                    .legs
+                    .iter_mut()
+                    .find(|leg| leg.client_order_id == client_order_id)
+                else {
+                    return 0.0;
+                };
+                (
+                    leg.filled_qty,
+                    &mut leg.overfilled_qty,
+                    quantity * f64::from(leg.leg.ratio),
+                )
+            }
+        };
+
+        let applied = qty.min((expected_qty - filled_qty).max(0.0));
+        let excess = qty - applied;
+        if excess > f64::EPSILON * expected_qty.max(1.0) {
+            *overfilled_qty += excess;
+            log::warn!(
+                "Over-fill of {excess} for {client_order_id} of spread order {}",
+                self.client_order_id
+            );
+        }
+        applied
+    }
+
+    /// Returns the net spread price from the average leg prices (buy legs positive).
+    fn legged_net_px(&self) -> Option<f64> {
+        self.legs.iter().try_fold(0.0, |net, state| {
+            let sign: f64 = match state.leg.side {
+                OrderSide::Sell => -1.0,
+                _ => 1.0,
+            };
+            state
+                .avg_px
+                .map(|px| sign.mul_add(px * f64::from(state.leg.ratio), net))
+        })
+    }
+
+    /// Returns the spread quantity covered by the fills of every leg.
+    fn legged_filled_qty(&self) -> f64 {
+        self.legs
+            .iter()
+            .map(|state| state.filled_qty / f64::from(state.leg.ratio))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// A combined fill of a spread order, in spread units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpreadFill {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    /// The newly filled spread quantity.
+    pub last_qty: f64,
+    /// The net spread price of the new fill.
+    pub last_px: f64,
+    /// The total filled spread quantity.
+    pub filled_qty: f64,
+    /// The average net spread price of all fills.
+    pub avg_px: f64,
+    /// Whether the spread order is now completely filled.
+    pub is_filled: bool,
+}
+
+/// Routes spread orders natively or as synchronized legs, and combines their fills.
+#[derive(Debug, Default)]
+pub struct SpreadOrderManager {
+    spreads: AHashMap<ClientOrderId, SpreadState>,
+    leg_orders: AHashMap<ClientOrderId, ClientOrderId>,
+    /// The most recently completed spreads, oldest first.
+    completed: VecDeque<SpreadState>,
+}
+
+impl SpreadOrderManager {
+    /// Creates a new [`SpreadOrderManager`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the spread order and returns the commands to send for the venue `support`.
+    ///
+    /// Legged spreads are submitted as one order per leg with client order IDs suffixed `-L1`,
+    /// `-L2`, etc., sized by the leg ratios. Since the net spread price cannot be split into leg
+    /// prices, only market spread orders can be legged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spread order is already registered, or a non-market order is to be
+    /// legged.
+    pub fn submit(
+        &mut self,
+        command: SubmitSpreadOrder,
+        support: SpreadSupport,
+    ) -> anyhow::Result<SpreadRouting> {
+        let spread_id = command.client_order_id;
+        anyhow::ensure!(
+            !self.spreads.contains_key(&spread_id) && self.completed_spread(&spread_id).is_none(),
+            "Spread order {spread_id} already registered"
+        );
+        let init = &command.order_init;
+        anyhow::ensure!(
+            support == SpreadSupport::Native || init.order_type == OrderType::Market,
+            "Cannot leg {:?} spread order {spread_id}, only MARKET orders can be legged",
+            init.order_type
+        );
+
+        let leg_ids: Vec<ClientOrderId> = (1..=command.legs.len())
+            .map(|i| match support {
+                SpreadSupport::Native => spread_id,
+                SpreadSupport::Legged => ClientOrderId::new(format!("{spread_id}-L{i}")),
+            })
+            .collect();
+        let state = SpreadState {
+            client_order_id: spread_id,
+            instrument_id: command.instrument_id,
+            strategy_id: command.strategy_id,
+            order_side: init.order_side,
+            support,
+            quantity: init.quantity.as_f64(),
+            filled_qty: 0.0,
+            avg_px: None,
+            overfilled_qty: 0.0,
+            legs: command
+                .legs
+                .iter()
+                .zip(&leg_ids)
+                .map(|(leg, client_order_id)| SpreadLegState {
+                    leg: *leg,
+                    client_order_id: *client_order_id,
+                    filled_qty: 0.0,
+                    avg_px: None,
+                    overfilled_qty: 0.0,
+                })
+                .collect(),
+            trade_ids: AHashSet::new(),
+        };
+
+        let routing = match support {
+            SpreadSupport::Native => SpreadRouting::Native(command),
+            SpreadSupport::Legged => {
+                for leg_id in &leg_ids {
+                    self.leg_orders.insert(*leg_id, spread_id);
+                }
+                SpreadRouting::Legged(leg_commands(&command, &leg_ids))
+            }
+        };
+        self.spreads.insert(spread_id, state);
+
+        Ok(routing)
+    }
+
+    /// Returns the state of the spread order with the given client order ID.
+    #[must_use]
+    pub fn spread(&self, client_order_id: &ClientOrderId) -> Option<&SpreadState> {
+        self.spreads.get(client_order_id)
+    }
+
+    /// Returns the final state of a recently completed spread order with the given client order
+    /// ID, including any fills received after completion as over-filled quantity.
+    #[must_use]
+    pub fn completed_spread(&self, client_order_id: &ClientOrderId) -> Option<&SpreadState> {
+        self.completed
+            .iter()
+            .find(|spread| spread.client_order_id == *client_order_id)
+    }
+
+    /// Returns the number of tracked spread orders.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.spreads.len()
+    }
+
+    /// Handles a fill of a native spread order or a leg order, returning the combined fill once
+    /// the filled spread quantity has increased.
+    ///
+    /// A legged spread only fills as far as every leg has filled in ratio. Repeated trades are
+    /// ignored, and fill quantity beyond the spread (or leg) quantity is recorded as over-filled.
+    /// Completely filled spreads are no longer tracked, but are kept as
+    /// [`completed_spread`](Self::completed_spread)s to record late fills.
+    pub fn on_fill(&mut self, fill: &OrderFilled) -> Option<SpreadFill> {
+        let spread_id = match self.leg_orders.get(&fill.client_order_id) {
+            Some(spread_id) => *spread_id,
+            None => fill.client_order_id,
+        };
+        let trade = (fill.client_order_id, fill.trade_id);
+        let qty = fill.last_qty.as_f64();
+        let px = fill.last_px.as_f64();
+
+        let Some(spread) = self.spreads.get_mut(&spread_id) else {
+            if let Some(spread) = self
+                .completed
+                .iter_mut()
+                .find(|spread| spread.client_order_id == spread_id)
+                && spread.trade_ids.insert(trade)
+            {
+                spread.apply_qty(fill.client_order_id, qty);
+            }
+            return None;
+        };
+        if spread.support == SpreadSupport::Native && fill.client_order_id != spread_id {
+            return None;
+        }
+        if !spread.trade_ids.insert(trade) {
+            log::warn!(
+                "Ignoring duplicate fill {} for spread order {spread_id}",
+                fill.trade_id
+            );
+            return None;
+        }
+        let qty = spread.apply_qty(fill.client_order_id, qty);
+        if qty <= 0.0 {
+            return None;
+        }
+
+        let (last_qty, last_px) = match spread.support {
+            SpreadSupport::Native => (qty, px),
+            SpreadSupport::Legged => {
+                let leg = spread
+                    .legs
+                    .iter_mut()
+                    .find(|leg| leg.client_order_id == fill.client_order_id)?;
+                let notional = leg.avg_px.unwrap_or(0.0).mul_add(leg.filled_qty, px * qty);
+                leg.filled_qty += qty;
+                leg.avg_px = Some(notional / leg.filled_qty);
+
+                let filled_qty = spread.legged_filled_qty();
+                let net_px = spread.legged_net_px()?;
+                if filled_qty <= spread.filled_qty {
+                    return None;
+                }
+                // The net price of the increment, keeping the average at the current net price
+                let last_qty = filled_qty - spread.filled_qty;
+                let last_px = net_px.mul_add(
+                    filled_qty,
+                    -spread.avg_px.unwrap_or(0.0) * spread.filled_qty,
+                ) / last_qty;
+                (last_qty, last_px)
+            }
+        };
+
+        let notional = spread
+            .avg_px
+            .unwrap_or(0.0)
+            .mul_add(spread.filled_qty, last_px * last_qty);
+        spread.filled_qty += last_qty;
+        spread.avg_px = Some(notional / spread.filled_qty);
+
+        let spread_fill = SpreadFill {
+            client_order_id: spread_id,
+            instrument_id: spread.instrument_id,
+            strategy_id: spread.strategy_id,
+            last_qty,
+            last_px,
+            filled_qty: spread.filled_qty,
+            avg_px: notional / spread.filled_qty,
+            is_filled: spread.is_filled(),
+        };
+        if spread_fill.is_filled
+            && let Some(spread) = self.spreads.remove(&spread_id)
+        {
+            if self.completed.len() == MAX_COMPLETED_SPREADS
+                && let Some(oldest) = self.completed.pop_front()
+            {
+                self.remove_leg_orders(&oldest);
+            }
+            self.completed.push_back(spread);
+        }
+
+        Some(spread_fill)
+    }
+
+    /// Stops tracking the spread order with the given client order ID, returning its final state.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<SpreadState> {
+        let spread = match self.spreads.remove(client_order_id) {
+            Some(spread) => spread,
+            None => {
+                let index = self
+                    .completed
+                    .iter()
+                    .position(|spread| spread.client_order_id == *client_order_id)?;
+                self.completed.remove(index)?
+            }
+        };
+        self.remove_leg_orders(&spread);
+        Some(spread)
+    }
+
+    fn remove_leg_orders(&mut self, spread: &SpreadState) {
+        for leg in &spread.legs {
+            self.leg_orders.remove(&leg.client_order_id);
+        }
+    }
+}
+
+fn leg_commands(command: &SubmitSpreadOrder, leg_ids: &[ClientOrderId]) -> Vec<SubmitOrder> {
+    let spread_init = &command.order_init;
+    let linked_ids = leg_ids.to_vec();
+
+    command
+        .legs
+        .iter()
+        .zip(leg_ids)
+        .map(|(leg, client_order_id)| {
+            let mut order_init = spread_init.clone();
+            order_init.instrument_id = leg.instrument_id;
+            order_init.client_order_id = *client_order_id;
+            order_init.order_side = leg.side_for(spread_init.order_side);
+            order_init.quantity = Quantity::from_raw(
+                spread_init.quantity.raw * QuantityRaw::from(leg.ratio),
+                spread_init.quantity.precision,
+            );
+            order_init.linked_order_ids = Some(
+                linked_ids
+                    .iter()
+                    .filter(|id| *id != client_order_id)
+                    .copied()
+                    .collect(),
+            );
+            order_init.event_id = UUID4::new();
+
+            SubmitOrder::new(
+                command.trader_id,
+                command.client_id,
+                command.strategy_id,
+                leg.instrument_id,
+                *client_order_id,
+                order_init,
+                None,
+                None,
+                command.params.clone(),
+                UUID4::new(),
+                command.ts_init,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{events::OrderInitialized, identifiers::TraderId, types::Price};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn spread_order(side: OrderSide, order_type: OrderType) -> SubmitSpreadOrder {
+        let order_init = OrderInitialized {
+            instrument_id: InstrumentId::from("ESZ4-ESH5.XCME"),
+            client_order_id: ClientOrderId::from("O-1"),
+            order_side: side,
+            order_type,
+            quantity: Quantity::from(2),
+            ..Default::default()
+        };
+        SubmitSpreadOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            order_init.instrument_id,
+            order_init.client_order_id,
+            order_init,
+            vec![
+                SpreadLeg::new(InstrumentId::from("ESZ4.XCME"), OrderSide::Buy, 1),
+                SpreadLeg::new(InstrumentId::from("ESH5.XCME"), OrderSide::Sell, 2),
+            ],
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn fill(client_order_id: &str, trade_id: &str, px: &str, qty: u64) -> OrderFilled {
+        OrderFilled {
+            client_order_id: ClientOrderId::from(client_order_id),
+            trade_id: TradeId::from(trade_id),
+            last_px: Price::from(px),
+            last_qty: Quantity::from(qty),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_submit_spread_order_validates_legs() {
+        let command = spread_order(OrderSide::Buy, OrderType::Market);
+        let result = SubmitSpreadOrder::new_checked(
+            command.trader_id,
+            None,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            command.order_init.clone(),
+            vec![command.legs[0], command.legs[0]],
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_native_routing_passes_command_through() {
+        let mut manager = SpreadOrderManager::new();
+        let command = spread_order(OrderSide::Buy, OrderType::Limit);
+
+        let routing = manager
+            .submit(command.clone(), SpreadSupport::Native)
+            .unwrap();
+
+        assert_eq!(routing, SpreadRouting::Native(command));
+        assert_eq!(manager.count(), 1);
+    }
+
+    #[rstest]
+    fn test_legged_routing_sizes_and_sides_legs() {
+        let mut manager = SpreadOrderManager::new();
+
+        let SpreadRouting::Legged(legs) = manager
+            .submit(
+                spread_order(OrderSide::Sell, OrderType::Market),
+                SpreadSupport::Legged,
+            )
+            .unwrap()
+        else {
+            panic!("expected legged routing");
+        };
+
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].client_order_id, ClientOrderId::from("O-1-L1"));
+        assert_eq!(legs[0].order_init.order_side, OrderSide::Sell);
+        assert_eq!(legs[0].order_init.quantity, Quantity::from(2));
+        assert_eq!(legs[1].instrument_id, InstrumentId::from("ESH5.XCME"));
+        assert_eq!(legs[1].order_init.order_side, OrderSide::Buy);
+        assert_eq!(legs[1].order_init.quantity, Quantity::from(4));
+        assert_eq!(
+            legs[1].order_init.linked_order_ids,
+            Some(vec![ClientOrderId::from("O-1-L1")])
+        );
+    }
+
+    #[rstest]
+    fn test_legged_routing_rejects_limit_orders() {
+        let mut manager = SpreadOrderManager::new();
+        let result = manager.submit(
+            spread_order(OrderSide::Buy, OrderType::Limit),
+            SpreadSupport::Legged,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[rstest]
+    fn test_legged_fills_are_combined_in_ratio() {
+        let mut manager = SpreadOrderManager::new();
+        manager
+            .submit(
+                spread_order(OrderSide::Buy, OrderType::Market),
+                SpreadSupport::Legged,
+            )
+            .unwrap();
+
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L1", "T-1", "5000.00", 2))
+                .is_none()
+        );
+        let first = manager
+            .on_fill(&fill("O-1-L2", "T-2", "2490.00", 2))
+            .unwrap();
+        assert_eq!(first.last_qty, 1.0);
+        assert_eq!(first.last_px, 20.0);
+        assert!(!first.is_filled);
+
+        let second = manager
+            .on_fill(&fill("O-1-L2", "T-3", "2480.00", 2))
+            .unwrap();
+        assert_eq!(second.last_qty, 1.0);
+        assert_eq!(second.last_px, 40.0);
+        assert_eq!(second.avg_px, 30.0);
+        assert!(second.is_filled);
+        assert_eq!(manager.count(), 0);
+    }
+
+    #[rstest]
+    fn test_native_fills_are_reported_in_spread_units() {
+        let mut manager = SpreadOrderManager::new();
+        manager
+            .submit(
+                spread_order(OrderSide::Buy, OrderType::Limit),
+                SpreadSupport::Native,
+            )
+            .unwrap();
+
+        let spread_fill = manager.on_fill(&fill("O-1", "T-1", "25.00", 2)).unwrap();
+
+        assert_eq!(spread_fill.filled_qty, 2.0);
+        assert_eq!(spread_fill.avg_px, 25.0);
+        assert!(spread_fill.is_filled);
+        assert!(manager.on_fill(&fill("O-2", "T-2", "25.00", 1)).is_none());
+    }
+
+    #[rstest]
+    fn test_duplicate_leg_fills_are_ignored() {
+        let mut manager = SpreadOrderManager::new();
+        manager
+            .submit(
+                spread_order(OrderSide::Buy, OrderType::Market),
+                SpreadSupport::Legged,
+            )
+            .unwrap();
+
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L1", "T-1", "5000.00", 1))
+                .is_none()
+        );
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L1", "T-1", "5000.00", 1))
+                .is_none()
+        );
+        let spread_fill = manager
+            .on_fill(&fill("O-1-L2", "T-1", "2490.00", 4))
+            .unwrap();
+
+        // The repeated leg trade is not counted, so only one spread unit is filled
+        assert_eq!(spread_fill.last_qty, 1.0);
+        assert!(!spread_fill.is_filled);
+        let spread = manager.spread(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(spread.legs[0].filled_qty, 1.0);
+        assert_eq!(spread.legs[0].overfilled_qty, 0.0);
+    }
+
+    #[rstest]
+    fn test_over_fills_are_clamped_and_recorded() {
+        let mut manager = SpreadOrderManager::new();
+        manager
+            .submit(
+                spread_order(OrderSide::Buy, OrderType::Market),
+                SpreadSupport::Legged,
+            )
+            .unwrap();
+
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L1", "T-1", "5000.00", 3))
+                .is_none()
+        );
+        let spread_fill = manager
+            .on_fill(&fill("O-1-L2", "T-2", "2490.00", 4))
+            .unwrap();
+        assert_eq!(spread_fill.last_qty, 2.0);
+        assert_eq!(spread_fill.filled_qty, 2.0);
+        assert!(spread_fill.is_filled);
+        assert_eq!(manager.count(), 0);
+
+        // A late leg fill after completion is recorded against the completed spread
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L2", "T-3", "2490.00", 1))
+                .is_none()
+        );
+        assert!(
+            manager
+                .on_fill(&fill("O-1-L2", "T-3", "2490.00", 1))
+                .is_none()
+        );
+        let spread = manager
+            .completed_spread(&ClientOrderId::from("O-1"))
+            .unwrap();
+        assert_eq!(spread.filled_qty, 2.0);
+        assert_eq!(spread.legs[0].filled_qty, 2.0);
+        assert_eq!(spread.legs[0].overfilled_qty, 1.0);
+        assert_eq!(spread.legs[1].filled_qty, 4.0);
+        assert_eq!(spread.legs[1].overfilled_qty, 1.0);
+    }
+
+    #[rstest]
+    fn test_native_over_fill_is_clamped() {
+        let mut manager = SpreadOrderManager::new();
+        manager
+            .submit(
+                spread_order(OrderSide::Buy, OrderType::Limit),
+                SpreadSupport::Native,
+            )
+            .unwrap();
+
+        let spread_fill = manager.on_fill(&fill("O-1", "T-1", "25.00", 3)).unwrap();
+
+        assert_eq!(spread_fill.last_qty, 2.0);
+        assert_eq!(spread_fill.filled_qty, 2.0);
+        assert!(spread_fill.is_filled);
+        let spread = manager
+            .completed_spread(&ClientOrderId::from("O-1"))
+            .unwrap();
+        assert_eq!(spread.overfilled_qty, 1.0);
+        assert!(manager.remove(&ClientOrderId::from("O-1")).is_some());
+        assert!(
+            manager
+                .completed_spread(&ClientOrderId::from("O-1"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,523 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Synthetic market data generation.
+//!
+//! A [`SyntheticMarket`] simulates a mid price with a [`MidProcess`] (geometric Brownian motion
+//! or an Ornstein-Uhlenbeck process), quotes around it with a randomized spread, and generates
+//! trades as a Poisson process against the prevailing quote. Each quote is accompanied by an
+//! order book snapshot shaped per the [`BookShape`], so the stream can drive quote, trade and
+//! book consumers alike for engine stress testing and strategy prototyping without real data.
+//!
+//! Generation is deterministic for a given seed.
+
+use nautilus_core::{UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{AggressorSide, BookAction, OrderSide, RecordFlag},
+    identifiers::{InstrumentId, TradeId},
+    instruments::{Instrument, InstrumentAny},
+    types::Quantity,
+};
+use serde::{Deserialize, Serialize};
+
+/// The stochastic process driving the simulated mid price.
+///
+/// Parameters are per second of simulated time.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MidProcess {
+    /// Geometric Brownian motion, for trending prices with proportional volatility.
+    Gbm { drift: f64, volatility: f64 },
+    /// An Ornstein-Uhlenbeck process, for prices reverting to `mean`.
+    OrnsteinUhlenbeck {
+        mean: f64,
+        reversion: f64,
+        volatility: f64,
+    },
+}
+
+impl MidProcess {
+    /// Returns the price after `dt_secs` from `price`, given a standard normal `shock`.
+    #[must_use]
+    pub fn step(&self, price: f64, dt_secs: f64, shock: f64) -> f64 {
+        match *self {
+            Self::Gbm { drift, volatility } => {
+                price
+                    * ((drift - volatility.powi(2) / 2.0) * dt_secs
+                        + volatility * dt_secs.sqrt() * shock)
+                        .exp()
+            }
+            Self::OrnsteinUhlenbeck {
+                mean,
+                reversion,
+                volatility,
+            } => price + reversion * (mean - price) * dt_secs + volatility * dt_secs.sqrt() * shock,
+        }
+    }
+}
+
+/// The shape of the generated order book on each side.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BookShape {
+    /// The number of levels per side.
+    pub levels: usize,
+    /// The size at the top level.
+    pub top_size: f64,
+    /// The size multiplier per level away from the top (above 1.0 for deeper books).
+    pub size_growth: f64,
+    /// The ticks between adjacent levels.
+    pub level_ticks: u32,
+}
+
+impl Default for BookShape {
+    fn default() -> Self {
+        Self {
+            levels: 10,
+            top_size: 100.0,
+            size_growth: 1.2,
+            level_ticks: 1,
+        }
+    }
+}
+
+/// Configuration for a [`SyntheticMarket`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticConfig {
+    pub seed: u64,
+    /// UNIX timestamp (nanoseconds) of the first generated event.
+    pub start: UnixNanos,
+    pub initial_mid: f64,
+    pub process: MidProcess,
+    /// The interval between quotes (nanoseconds).
+    pub quote_interval_ns: u64,
+    /// The mean number of trades per second.
+    pub trade_rate: f64,
+    /// The mean trade size.
+    pub trade_size_mean: f64,
+    /// The minimum quoted spread (ticks).
+    pub spread_min_ticks: u32,
+    /// The maximum quoted spread (ticks).
+    pub spread_max_ticks: u32,
+    /// The book generated with each quote, or `None` to generate quotes and trades only.
+    pub book: Option<BookShape>,
+}
+
+impl SyntheticConfig {
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is out of range.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.initial_mid.is_finite() && self.initial_mid > 0.0,
+            "Invalid initial mid {}",
+            self.initial_mid
+        );
+        anyhow::ensure!(
+            self.quote_interval_ns > 0,
+            "Quote interval must be positive"
+        );
+        anyhow::ensure!(
+            self.trade_rate >= 0.0 && self.trade_size_mean > 0.0,
+            "Invalid trade rate {} or mean size {}",
+            self.trade_rate,
+            self.trade_size_mean
+        );
+        anyhow::ensure!(
+            self.spread_min_ticks >= 1 && self.spread_min_ticks <= self.spread_max_ticks,
+            "Invalid spread range [{}, {}] ticks",
+            self.spread_min_ticks,
+            self.spread_max_ticks
+        );
+        if let Some(book) = &self.book {
+            anyhow::ensure!(
+                book.levels >= 1 && book.top_size > 0.0 && book.size_growth > 0.0,
+                "Invalid book shape {book:?}"
+            );
+            anyhow::ensure!(book.level_ticks >= 1, "Book level ticks must be positive");
+        }
+        Ok(())
+    }
+}
+
+/// An event generated by a [`SyntheticMarket`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyntheticEvent {
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    /// A snapshot of the book, generated just before the quote at the same time.
+    Deltas(OrderBookDeltas),
+}
+
+impl SyntheticEvent {
+    /// Returns the UNIX timestamp (nanoseconds) of the event.
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Quote(quote) => quote.ts_event,
+            Self::Trade(trade) => trade.ts_event,
+            Self::Deltas(deltas) => deltas.ts_event,
+        }
+    }
+}
+
+/// A seeded generator of synthetic quotes, trades and book snapshots for an instrument.
+///
+/// Implements [`Iterator`], yielding events in time order indefinitely.
+#[derive(Debug)]
+pub struct SyntheticMarket {
+    instrument: InstrumentAny,
+    config: SyntheticConfig,
+    rng_state: u64,
+    mid: f64,
+    ts_mid: UnixNanos,
+    next_quote: UnixNanos,
+    next_trade: Option<UnixNanos>,
+    last_quote: Option<QuoteTick>,
+    pending_quote: Option<QuoteTick>,
+    sequence: u64,
+    trade_count: u64,
+}
+
+impl SyntheticMarket {
+    /// Creates a new [`SyntheticMarket`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn new(instrument: InstrumentAny, config: SyntheticConfig) -> anyhow::Result<Self> {
+        config.validate()?;
+        let mut market = Self {
+            instrument,
+            rng_state: config.seed.max(1),
+            mid: config.initial_mid,
+            ts_mid: config.start,
+            next_quote: config.start,
+            next_trade: None,
+            last_quote: None,
+            pending_quote: None,
+            sequence: 0,
+            trade_count: 0,
+            config,
+        };
+        market.next_trade = market.schedule_trade(market.config.start);
+        Ok(market)
+    }
+
+    /// Returns the instrument ID the events are generated for.
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        self.instrument.id()
+    }
+
+    /// Returns the current simulated mid price.
+    #[must_use]
+    pub const fn mid(&self) -> f64 {
+        self.mid
+    }
+
+    /// Returns the generated quotes within the next `count` events.
+    pub fn quotes(&mut self, count: usize) -> Vec<QuoteTick> {
+        self.by_ref()
+            .take(count)
+            .filter_map(|event| match event {
+                SyntheticEvent::Quote(quote) => Some(quote),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        // Xorshift64
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_normal(&mut self) -> f64 {
+        // Box-Muller transform
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    fn next_exponential(&mut self, mean: f64) -> f64 {
+        -mean * (1.0 - self.next_f64()).ln()
+    }
+
+    // Sizes are at least one size increment, as smaller values would round to zero
+    fn make_size(&self, value: f64) -> Quantity {
+        let increment = self.instrument.size_increment().as_f64();
+        self.instrument.make_qty(value.max(increment), None)
+    }
+
+    fn schedule_trade(&mut self, from: UnixNanos) -> Option<UnixNanos> {
+        if self.config.trade_rate <= 0.0 {
+            return None;
+        }
+        let wait_secs = self.next_exponential(1.0 / self.config.trade_rate);
+        Some(from + (wait_secs * NANOSECONDS_IN_SECOND as f64) as u64)
+    }
+
+    fn advance_mid(&mut self, ts: UnixNanos) {
+        let dt_secs =
+            ts.as_u64().saturating_sub(self.ts_mid.as_u64()) as f64 / NANOSECONDS_IN_SECOND as f64;
+        if dt_secs > 0.0 {
+            let shock = self.next_normal();
+            let tick = self.instrument.price_increment().as_f64();
+            self.mid = self.config.process.step(self.mid, dt_secs, shock).max(tick);
+        }
+        self.ts_mid = ts;
+    }
+
+    fn generate_quote(&mut self, ts: UnixNanos) -> QuoteTick {
+        self.advance_mid(ts);
+        let tick = self.instrument.price_increment().as_f64();
+        let range = self.config.spread_max_ticks - self.config.spread_min_ticks + 1;
+        let spread_ticks = self.config.spread_min_ticks
+            + ((self.next_f64() * f64::from(range)) as u32).min(range - 1);
+        let bid = self
+            .instrument
+            .make_price((self.mid / tick - f64::from(spread_ticks) / 2.0).floor() * tick);
+        let ask = self
+            .instrument
+            .make_price(bid.as_f64() + f64::from(spread_ticks) * tick);
+        let size = self.make_size(
+            self.config
+                .book
+                .map_or(self.config.trade_size_mean, |book| book.top_size),
+        );
+        QuoteTick::new(self.instrument.id(), bid, ask, size, size, ts, ts)
+    }
+
+    fn generate_book(&mut self, quote: &QuoteTick, shape: BookShape) -> OrderBookDeltas {
+        let instrument_id = self.instrument.id();
+        let ts = quote.ts_event;
+        self.sequence += 1;
+        let mut deltas = vec![OrderBookDelta::clear(instrument_id, self.sequence, ts, ts)];
+
+        let tick = self.instrument.price_increment().as_f64();
+        let step = f64::from(shape.level_ticks) * tick;
+        let mut order_id = 0;
+        for (side, top, direction) in [
+            (OrderSide::Buy, quote.bid_price, -1.0),
+            (OrderSide::Sell, quote.ask_price, 1.0),
+        ] {
+            let mut size = shape.top_size;
+            for level in 0..shape.levels {
+                let price = top.as_f64() + direction * step * level as f64;
+                if price <= 0.0 {
+                    break;
+                }
+                order_id += 1;
+                let order = BookOrder::new(
+                    side,
+                    self.instrument.make_price(price),
+                    self.make_size(size),
+                    order_id,
+                );
+                deltas.push(OrderBookDelta::new(
+                    instrument_id,
+                    BookAction::Add,
+                    order,
+                    RecordFlag::F_SNAPSHOT as u8,
+                    self.sequence,
+                    ts,
+                    ts,
+                ));
+                size *= shape.size_growth;
+            }
+        }
+
+        if let Some(last) = deltas.last_mut() {
+            last.flags |= RecordFlag::F_LAST as u8;
+        }
+        OrderBookDeltas::new(instrument_id, deltas)
+    }
+
+    fn generate_trade(&mut self, ts: UnixNanos) -> TradeTick {
+        self.advance_mid(ts);
+        let aggressor = if self.next_f64() < 0.5 {
+            AggressorSide::Buyer
+        } else {
+            AggressorSide::Seller
+        };
+        let price = match (aggressor, &self.last_quote) {
+            (AggressorSide::Buyer, Some(quote)) => quote.ask_price,
+            (_, Some(quote)) => quote.bid_price,
+            (_, None) => self.instrument.make_price(self.mid),
+        };
+        let size = self.next_exponential(self.config.trade_size_mean);
+        let size = self.make_size(size);
+
+        self.trade_count += 1;
+        let trade_id = TradeId::new(format!("SYN-{}", self.trade_count));
+        TradeTick::new(
+            self.instrument.id(),
+            price,
+            size,
+            aggressor,
+            trade_id,
+            ts,
+            ts,
+        )
+    }
+}
+
+impl Iterator for SyntheticMarket {
+    type Item = SyntheticEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(quote) = self.pending_quote.take() {
+            self.last_quote = Some(quote);
+            return Some(SyntheticEvent::Quote(quote));
+        }
+
+        if let Some(ts) = self.next_trade.filter(|ts| *ts < self.next_quote) {
+            self.next_trade = self.schedule_trade(ts);
+            return Some(SyntheticEvent::Trade(self.generate_trade(ts)));
+        }
+
+        let ts = self.next_quote;
+        self.next_quote = ts + self.config.quote_interval_ns;
+        let quote = self.generate_quote(ts);
+        match self.config.book {
+            Some(shape) => {
+                let deltas = self.generate_book(&quote, shape);
+                self.pending_quote = Some(quote);
+                Some(SyntheticEvent::Deltas(deltas))
+            }
+            None => {
+                self.last_quote = Some(quote);
+                Some(SyntheticEvent::Quote(quote))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::BookType,
+        instruments::{CurrencyPair, stubs::audusd_sim},
+        orderbook::OrderBook,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn config(process: MidProcess) -> SyntheticConfig {
+        SyntheticConfig {
+            seed: 42,
+            start: UnixNanos::default(),
+            initial_mid: 1.0,
+            process,
+            quote_interval_ns: 100_000_000,
+            trade_rate: 5.0,
+            trade_size_mean: 100_000.0,
+            spread_min_ticks: 1,
+            spread_max_ticks: 4,
+            book: Some(BookShape {
+                levels: 5,
+                top_size: 100_000.0,
+                size_growth: 1.5,
+                level_ticks: 1,
+            }),
+        }
+    }
+
+    fn gbm() -> MidProcess {
+        MidProcess::Gbm {
+            drift: 0.0,
+            volatility: 0.001,
+        }
+    }
+
+    #[rstest]
+    fn test_events_are_ordered_and_consistent(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut book = OrderBook::new(instrument.id(), BookType::L2_MBP);
+        let market = SyntheticMarket::new(instrument, config(gbm())).unwrap();
+
+        let mut last_ts = UnixNanos::default();
+        let (mut quotes, mut trades) = (0, 0);
+        for event in market.take(1_000) {
+            assert!(event.ts_event() >= last_ts);
+            last_ts = event.ts_event();
+            match event {
+                SyntheticEvent::Deltas(deltas) => book.apply_deltas(&deltas).unwrap(),
+                SyntheticEvent::Quote(quote) => {
+                    quotes += 1;
+                    assert!(quote.bid_price < quote.ask_price);
+                    assert_eq!(book.best_bid_price(), Some(quote.bid_price));
+                    assert_eq!(book.best_ask_price(), Some(quote.ask_price));
+                    assert_eq!(book.bids(None).count(), 5);
+                }
+                SyntheticEvent::Trade(trade) => {
+                    trades += 1;
+                    assert!(trade.size.is_positive());
+                }
+            }
+        }
+
+        assert!(quotes > 0 && trades > 0);
+    }
+
+    #[rstest]
+    fn test_generation_is_deterministic_per_seed(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let run = |seed| {
+            let mut config = config(gbm());
+            config.seed = seed;
+            SyntheticMarket::new(instrument.clone(), config)
+                .unwrap()
+                .take(200)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    #[rstest]
+    fn test_ornstein_uhlenbeck_reverts_to_mean(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut config = config(MidProcess::OrnsteinUhlenbeck {
+            mean: 1.1,
+            reversion: 1.0,
+            volatility: 0.0001,
+        });
+        config.book = None;
+        config.trade_rate = 0.0;
+        let mut market = SyntheticMarket::new(instrument, config).unwrap();
+
+        let quotes = market.quotes(200);
+
+        assert_eq!(quotes.len(), 200);
+        assert!((market.mid() - 1.1).abs() < 0.01);
+    }
+
+    #[rstest]
+    fn test_validate_rejects_inverted_spread_range(audusd_sim: CurrencyPair) {
+        let mut config = config(gbm());
+        config.spread_min_ticks = 5;
+
+        assert!(SyntheticMarket::new(InstrumentAny::CurrencyPair(audusd_sim), config).is_err());
+    }
+}
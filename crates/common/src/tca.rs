@@ -0,0 +1,422 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Transaction cost analysis (TCA) against VWAP and TWAP benchmarks.
+//!
+//! For each tracked parent order the [`TcaTracker`] records the arrival price, accumulates the
+//! interval market VWAP (from trades) and TWAP (time-weighted from quote mids) while the order is
+//! working, and measures the order's fills against them. Child orders spawned by an execution
+//! algorithm can be linked to their parent so all fills are attributed to it.
+//!
+//! Slippage values are in basis points, signed so that positive values are a cost.
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    enums::OrderSide,
+    events::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId},
+    types::{Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug)]
+struct ParentState {
+    instrument_id: InstrumentId,
+    side: OrderSide,
+    quantity: f64,
+    arrival_price: f64,
+    start_ns: UnixNanos,
+    end_ns: Option<UnixNanos>,
+    filled_qty: f64,
+    filled_notional: f64,
+    market_volume: f64,
+    market_notional: f64,
+    twap_weighted: f64,
+    twap_duration: f64,
+    last_mid: Option<(f64, UnixNanos)>,
+}
+
+impl ParentState {
+    fn accrue_mid(&mut self, ts: UnixNanos) {
+        if let Some((mid, last_ts)) = self.last_mid {
+            let elapsed = ts.as_u64().saturating_sub(last_ts.as_u64()) as f64;
+            self.twap_weighted += mid * elapsed;
+            self.twap_duration += elapsed;
+        }
+    }
+
+    fn is_working(&self) -> bool {
+        self.end_ns.is_none()
+    }
+}
+
+/// Transaction cost analysis for a single parent order.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderTca {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub filled_qty: f64,
+    pub arrival_price: f64,
+    pub avg_fill_price: Option<f64>,
+    pub interval_vwap: Option<f64>,
+    pub interval_twap: Option<f64>,
+    /// The implementation shortfall versus the arrival price (bps).
+    pub implementation_shortfall_bps: Option<f64>,
+    /// The slippage versus the interval VWAP (bps).
+    pub vwap_slippage_bps: Option<f64>,
+    /// The slippage versus the interval TWAP (bps).
+    pub twap_slippage_bps: Option<f64>,
+    /// The filled quantity as a fraction of market volume over the interval.
+    pub participation_rate: Option<f64>,
+    pub start_ns: UnixNanos,
+    pub end_ns: Option<UnixNanos>,
+}
+
+/// Aggregate transaction cost analysis across parent orders, weighted by filled notional.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TcaSummary {
+    pub order_count: usize,
+    pub filled_notional: f64,
+    pub implementation_shortfall_bps: Option<f64>,
+    pub vwap_slippage_bps: Option<f64>,
+    pub twap_slippage_bps: Option<f64>,
+}
+
+/// A transaction cost analysis report of per-order and aggregate results.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TcaReport {
+    pub ts_generated: UnixNanos,
+    pub orders: Vec<OrderTca>,
+    pub summary: TcaSummary,
+}
+
+/// Tracks parent orders and market data to produce transaction cost analysis.
+#[derive(Clone, Debug, Default)]
+pub struct TcaTracker {
+    parents: AHashMap<ClientOrderId, ParentState>,
+    children: AHashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl TcaTracker {
+    /// Creates a new [`TcaTracker`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking a parent order with its arrival (decision) price.
+    pub fn track(
+        &mut self,
+        client_order_id: ClientOrderId,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        quantity: Quantity,
+        arrival_price: Price,
+        ts_start: UnixNanos,
+    ) {
+        self.parents.insert(
+            client_order_id,
+            ParentState {
+                instrument_id,
+                side,
+                quantity: quantity.as_f64(),
+                arrival_price: arrival_price.as_f64(),
+                start_ns: ts_start,
+                end_ns: None,
+                filled_qty: 0.0,
+                filled_notional: 0.0,
+                market_volume: 0.0,
+                market_notional: 0.0,
+                twap_weighted: 0.0,
+                twap_duration: 0.0,
+                last_mid: None,
+            },
+        );
+    }
+
+    /// Links a child order so its fills are attributed to `parent_id`.
+    pub fn link_child(&mut self, child_id: ClientOrderId, parent_id: ClientOrderId) {
+        self.children.insert(child_id, parent_id);
+    }
+
+    /// Handles a quote, accruing the TWAP of working parent orders for the instrument.
+    pub fn on_quote(&mut self, quote: &QuoteTick) {
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        for state in self.working_mut(&quote.instrument_id) {
+            state.accrue_mid(quote.ts_event);
+            state.last_mid = Some((mid, quote.ts_event));
+        }
+    }
+
+    /// Handles a market trade, accruing the VWAP and volume of working parent orders.
+    pub fn on_trade(&mut self, trade: &TradeTick) {
+        let size = trade.size.as_f64();
+        let notional = size * trade.price.as_f64();
+        for state in self.working_mut(&trade.instrument_id) {
+            state.market_volume += size;
+            state.market_notional += notional;
+        }
+    }
+
+    /// Handles a fill of a tracked parent order (or linked child), completing the parent once
+    /// fully filled.
+    ///
+    /// Returns the parent order ID the fill was attributed to, if tracked.
+    pub fn on_fill(&mut self, fill: &OrderFilled) -> Option<ClientOrderId> {
+        let parent_id = self
+            .children
+            .get(&fill.client_order_id)
+            .copied()
+            .unwrap_or(fill.client_order_id);
+        let state = self.parents.get_mut(&parent_id)?;
+
+        let qty = fill.last_qty.as_f64();
+        state.filled_qty += qty;
+        state.filled_notional += qty * fill.last_px.as_f64();
+        if state.is_working() && state.filled_qty >= state.quantity {
+            state.accrue_mid(fill.ts_event);
+            state.end_ns = Some(fill.ts_event);
+        }
+        Some(parent_id)
+    }
+
+    /// Completes a parent order (e.g. canceled or expired), ending its benchmark interval.
+    pub fn complete(&mut self, client_order_id: &ClientOrderId, ts_end: UnixNanos) {
+        if let Some(state) = self.parents.get_mut(client_order_id)
+            && state.is_working()
+        {
+            state.accrue_mid(ts_end);
+            state.end_ns = Some(ts_end);
+        }
+    }
+
+    /// Returns the analysis for a parent order, if tracked.
+    #[must_use]
+    pub fn order_tca(&self, client_order_id: &ClientOrderId) -> Option<OrderTca> {
+        let state = self.parents.get(client_order_id)?;
+        let sign = match state.side {
+            OrderSide::Sell => -1.0,
+            _ => 1.0,
+        };
+        let avg_fill_price =
+            (state.filled_qty > 0.0).then(|| state.filled_notional / state.filled_qty);
+        let interval_vwap =
+            (state.market_volume > 0.0).then(|| state.market_notional / state.market_volume);
+        let interval_twap = if state.twap_duration > 0.0 {
+            Some(state.twap_weighted / state.twap_duration)
+        } else {
+            state.last_mid.map(|(mid, _)| mid)
+        };
+        let slippage = |benchmark: Option<f64>| {
+            avg_fill_price
+                .zip(benchmark)
+                .map(|(avg, bench)| sign * (avg - bench) / bench * 10_000.0)
+        };
+
+        Some(OrderTca {
+            client_order_id: *client_order_id,
+            instrument_id: state.instrument_id,
+            side: state.side,
+            quantity: state.quantity,
+            filled_qty: state.filled_qty,
+            arrival_price: state.arrival_price,
+            avg_fill_price,
+            interval_vwap,
+            interval_twap,
+            implementation_shortfall_bps: slippage(Some(state.arrival_price)),
+            vwap_slippage_bps: slippage(interval_vwap),
+            twap_slippage_bps: slippage(interval_twap),
+            participation_rate: (state.market_volume > 0.0)
+                .then(|| state.filled_qty / state.market_volume),
+            start_ns: state.start_ns,
+            end_ns: state.end_ns,
+        })
+    }
+
+    /// Returns a report of all tracked parent orders (sorted by start time) with the aggregate
+    /// summary, e.g. at end of day.
+    #[must_use]
+    pub fn report(&self, ts_now: UnixNanos) -> TcaReport {
+        let mut orders: Vec<OrderTca> = self
+            .parents
+            .keys()
+            .filter_map(|client_order_id| self.order_tca(client_order_id))
+            .collect();
+        orders.sort_by_key(|tca| (tca.start_ns, tca.client_order_id));
+
+        let weighted = |metric: fn(&OrderTca) -> Option<f64>| {
+            let (sum, weight) = orders
+                .iter()
+                .filter_map(|tca| {
+                    let notional = tca.filled_qty * tca.avg_fill_price?;
+                    Some((metric(tca)? * notional, notional))
+                })
+                .fold((0.0, 0.0), |(s, w), (v, n)| (s + v, w + n));
+            (weight > 0.0).then(|| sum / weight)
+        };
+
+        let summary = TcaSummary {
+            order_count: orders.len(),
+            filled_notional: orders
+                .iter()
+                .filter_map(|tca| tca.avg_fill_price.map(|px| px * tca.filled_qty))
+                .sum(),
+            implementation_shortfall_bps: weighted(|tca| tca.implementation_shortfall_bps),
+            vwap_slippage_bps: weighted(|tca| tca.vwap_slippage_bps),
+            twap_slippage_bps: weighted(|tca| tca.twap_slippage_bps),
+        };
+
+        TcaReport {
+            ts_generated: ts_now,
+            orders,
+            summary,
+        }
+    }
+
+    /// Clears all completed parent orders (e.g. after the end of day report).
+    pub fn purge_completed(&mut self) {
+        self.parents.retain(|_, state| state.is_working());
+        let parents = &self.parents;
+        self.children
+            .retain(|_, parent_id| parents.contains_key(parent_id));
+    }
+
+    fn working_mut(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> impl Iterator<Item = &mut ParentState> {
+        self.parents
+            .values_mut()
+            .filter(move |state| state.is_working() && &state.instrument_id == instrument_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{enums::AggressorSide, identifiers::TradeId};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.XNAS")
+    }
+
+    fn quote(mid: &str, ts: u64) -> QuoteTick {
+        let price = Price::from(mid);
+        QuoteTick::new(
+            instrument_id(),
+            price,
+            price,
+            Quantity::from(100),
+            Quantity::from(100),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn trade(price: &str, size: u64, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id(),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn fill(client_order_id: &str, qty: u64, px: &str, ts: u64) -> OrderFilled {
+        OrderFilled {
+            client_order_id: ClientOrderId::from(client_order_id),
+            instrument_id: instrument_id(),
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from(qty),
+            last_px: Price::from(px),
+            ts_event: ts.into(),
+            ..Default::default()
+        }
+    }
+
+    fn tracker() -> TcaTracker {
+        let mut tracker = TcaTracker::new();
+        tracker.track(
+            ClientOrderId::from("P-1"),
+            instrument_id(),
+            OrderSide::Buy,
+            Quantity::from(100),
+            Price::from("100.00"),
+            0.into(),
+        );
+        tracker.link_child(ClientOrderId::from("C-1"), ClientOrderId::from("P-1"));
+        tracker.link_child(ClientOrderId::from("C-2"), ClientOrderId::from("P-1"));
+        tracker
+    }
+
+    #[rstest]
+    fn test_order_tca_benchmarks() {
+        let mut tracker = tracker();
+        tracker.on_quote(&quote("100.00", 0));
+        tracker.on_trade(&trade("100.00", 200, 5));
+        tracker.on_fill(&fill("C-1", 50, "100.10", 5));
+        tracker.on_quote(&quote("101.00", 10));
+        tracker.on_trade(&trade("101.00", 200, 15));
+        tracker.on_fill(&fill("C-2", 50, "100.90", 20));
+
+        let tca = tracker.order_tca(&ClientOrderId::from("P-1")).unwrap();
+
+        assert_eq!(tca.end_ns, Some(20.into()));
+        assert!((tca.avg_fill_price.unwrap() - 100.5).abs() < 1e-9);
+        assert!((tca.interval_vwap.unwrap() - 100.5).abs() < 1e-9);
+        assert!((tca.interval_twap.unwrap() - 100.5).abs() < 1e-9);
+        assert!((tca.implementation_shortfall_bps.unwrap() - 50.0).abs() < 1e-9);
+        assert!(tca.vwap_slippage_bps.unwrap().abs() < 1e-9);
+        assert_eq!(tca.participation_rate, Some(0.25));
+    }
+
+    #[rstest]
+    fn test_market_data_after_completion_is_ignored() {
+        let mut tracker = tracker();
+        tracker.on_trade(&trade("100.00", 100, 1));
+        tracker.complete(&ClientOrderId::from("P-1"), 2.into());
+        tracker.on_trade(&trade("200.00", 100, 3));
+
+        let tca = tracker.order_tca(&ClientOrderId::from("P-1")).unwrap();
+
+        assert_eq!(tca.interval_vwap, Some(100.0));
+        assert_eq!(tca.avg_fill_price, None);
+    }
+
+    #[rstest]
+    fn test_report_and_purge() {
+        let mut tracker = tracker();
+        tracker.on_fill(&fill("P-1", 100, "101.00", 1));
+        assert_eq!(tracker.on_fill(&fill("UNKNOWN", 1, "1.00", 1)), None);
+
+        let report = tracker.report(2.into());
+
+        assert_eq!(report.summary.order_count, 1);
+        assert!((report.summary.implementation_shortfall_bps.unwrap() - 100.0).abs() < 1e-9);
+
+        tracker.purge_completed();
+
+        assert!(tracker.report(3.into()).orders.is_empty());
+    }
+}
@@ -170,7 +170,7 @@ where
         let alert_ts = clock.timestamp_ns() + delta;
 
         clock
-            .set_time_alert_ns(&self.timer_name, alert_ts, callback, None)
+            .set_time_alert_ns(&self.timer_name, alert_ts, callback, None, None)
             .expect(FAILED);
     }
 
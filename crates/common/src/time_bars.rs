@@ -0,0 +1,269 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock-driven closing of internally aggregated time bars.
+//!
+//! The [`TimeBarScheduler`] feeds trades and quotes into a [`TimeBarBuilder`] and sets a clock
+//! alert at each bar boundary, so bars close on time even when no data arrives in the interval.
+//! Driven by a `TestClock` the bars close in backtest time, driven by a `LiveClock` in real time.
+//! Each bar is passed to the registered handlers and published on the bars topic of its type.
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::data::{Bar, QuoteTick, TimeBarBuilder, TradeTick};
+
+use crate::{
+    clock::Clock,
+    msgbus::{self, switchboard::get_bars_topic},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// A handler called with each closed bar.
+pub type BarHandler = Rc<dyn Fn(&Bar)>;
+
+struct SchedulerState {
+    builder: TimeBarBuilder,
+    handlers: Vec<BarHandler>,
+    running: bool,
+}
+
+/// Closes the bars of a [`TimeBarBuilder`] at each interval boundary through clock alerts.
+#[derive(Clone)]
+pub struct TimeBarScheduler {
+    clock: Rc<RefCell<dyn Clock>>,
+    state: Rc<RefCell<SchedulerState>>,
+}
+
+impl Debug for TimeBarScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct(stringify!(TimeBarScheduler))
+            .field("bar_type", &state.builder.bar_type())
+            .field("policy", &state.builder.policy())
+            .field("running", &state.running)
+            .finish()
+    }
+}
+
+impl TimeBarScheduler {
+    /// Creates a new [`TimeBarScheduler`] instance.
+    #[must_use]
+    pub fn new(clock: Rc<RefCell<dyn Clock>>, builder: TimeBarBuilder) -> Self {
+        Self {
+            clock,
+            state: Rc::new(RefCell::new(SchedulerState {
+                builder,
+                handlers: Vec::new(),
+                running: false,
+            })),
+        }
+    }
+
+    /// Registers a `handler` called with each closed bar.
+    pub fn register_handler(&self, handler: BarHandler) {
+        self.state.borrow_mut().handlers.push(handler);
+    }
+
+    /// Returns whether the scheduler is running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.state.borrow().running
+    }
+
+    /// Returns the close time of the current bar, if aligned.
+    #[must_use]
+    pub fn next_close_ns(&self) -> Option<UnixNanos> {
+        self.state.borrow().builder.next_close_ns()
+    }
+
+    /// Starts closing bars at each boundary from the interval containing the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the alert cannot be set.
+    pub fn start(&self) -> anyhow::Result<()> {
+        let now = self.clock.borrow().timestamp_ns();
+        {
+            let mut state = self.state.borrow_mut();
+            state.running = true;
+            state.builder.start(now);
+        }
+        self.arm()
+    }
+
+    /// Stops the scheduler, cancelling the alert. The partial bar is kept.
+    pub fn stop(&self) {
+        self.state.borrow_mut().running = false;
+        self.clock.borrow_mut().cancel_timer(&self.alert_name());
+    }
+
+    /// Handles a trade, emitting any bars it closes.
+    pub fn handle_trade(&self, trade: &TradeTick) {
+        let bars = self.state.borrow_mut().builder.handle_trade(trade);
+        self.emit(&bars);
+    }
+
+    /// Handles a quote, emitting any bars it closes.
+    pub fn handle_quote(&self, quote: &QuoteTick) {
+        let bars = self.state.borrow_mut().builder.handle_quote(quote);
+        self.emit(&bars);
+    }
+
+    fn alert_name(&self) -> String {
+        format!("TimeBar-{}", self.state.borrow().builder.bar_type())
+    }
+
+    fn arm(&self) -> anyhow::Result<()> {
+        let Some(next_close) = self.next_close_ns() else {
+            return Ok(());
+        };
+
+        let scheduler = self.clone();
+        let callback: Rc<dyn Fn(TimeEvent)> = Rc::new(move |event: TimeEvent| {
+            scheduler.on_alert(event.ts_event);
+        });
+
+        self.clock.borrow_mut().set_time_alert_ns(
+            &self.alert_name(),
+            next_close,
+            Some(TimeEventCallback::from(callback)),
+            None,
+            None,
+        )
+    }
+
+    fn on_alert(&self, ts: UnixNanos) {
+        let bars = {
+            let mut state = self.state.borrow_mut();
+            if !state.running {
+                return;
+            }
+            state.builder.close_until(ts)
+        };
+        self.emit(&bars);
+
+        if let Err(e) = self.arm() {
+            log::error!("Failed to re-arm time bar close alert: {e}");
+        }
+    }
+
+    fn emit(&self, bars: &[Bar]) {
+        if bars.is_empty() {
+            return;
+        }
+        let handlers = self.state.borrow().handlers.clone();
+        for bar in bars {
+            for handler in &handlers {
+                handler(bar);
+            }
+            msgbus::publish_bar(get_bars_topic(bar.bar_type), bar);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
+    use nautilus_model::{
+        data::{BarType, EmptyBarPolicy},
+        enums::AggressorSide,
+        identifiers::TradeId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    fn advance(clock: &Rc<RefCell<TestClock>>, to: u64) {
+        let events = clock.borrow_mut().advance_time(to.into(), true);
+        let handlers = clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+    }
+
+    fn trade(price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            "AUD/USD.SIM".parse().unwrap(),
+            Price::from(price),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn scheduler(
+        clock: &Rc<RefCell<TestClock>>,
+        policy: EmptyBarPolicy,
+    ) -> (TimeBarScheduler, Rc<RefCell<Vec<Bar>>>) {
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-LAST-INTERNAL");
+        let builder = TimeBarBuilder::new(bar_type, 5, 0, policy, 0).unwrap();
+        let scheduler = TimeBarScheduler::new(clock.clone(), builder);
+        let bars = Rc::new(RefCell::new(Vec::new()));
+        let sink = bars.clone();
+        scheduler.register_handler(Rc::new(move |bar: &Bar| sink.borrow_mut().push(*bar)));
+        (scheduler, bars)
+    }
+
+    #[rstest]
+    fn test_bars_close_at_boundaries_when_feed_is_silent() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        advance(&clock, NANOSECONDS_IN_SECOND);
+        let (scheduler, bars) = scheduler(&clock, EmptyBarPolicy::RepeatClose);
+        scheduler.start().unwrap();
+
+        scheduler.handle_trade(&trade("1.00000", NANOSECONDS_IN_SECOND + 10));
+        for second in 2..=4 {
+            advance(&clock, second * NANOSECONDS_IN_SECOND);
+        }
+
+        let bars = bars.borrow();
+        let closes: Vec<(u64, String)> = bars
+            .iter()
+            .map(|bar| (bar.ts_event.as_u64(), bar.close.to_string()))
+            .collect();
+        assert_eq!(
+            closes,
+            vec![
+                (2 * NANOSECONDS_IN_SECOND, "1.00000".to_string()),
+                (3 * NANOSECONDS_IN_SECOND, "1.00000".to_string()),
+                (4 * NANOSECONDS_IN_SECOND, "1.00000".to_string()),
+            ]
+        );
+        assert_eq!(bars[1].volume, Quantity::from(0));
+    }
+
+    #[rstest]
+    fn test_skip_policy_and_stop() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let (scheduler, bars) = scheduler(&clock, EmptyBarPolicy::Skip);
+        scheduler.start().unwrap();
+
+        scheduler.handle_trade(&trade("1.00000", 10));
+        advance(&clock, NANOSECONDS_IN_SECOND);
+        advance(&clock, 2 * NANOSECONDS_IN_SECOND);
+        assert_eq!(bars.borrow().len(), 1);
+
+        scheduler.stop();
+        assert!(!scheduler.is_running());
+        scheduler.handle_trade(&trade("1.00010", 2 * NANOSECONDS_IN_SECOND + 10));
+        advance(&clock, 3 * NANOSECONDS_IN_SECOND);
+        assert_eq!(bars.borrow().len(), 1);
+    }
+}
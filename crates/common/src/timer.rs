@@ -23,6 +23,7 @@ use std::{
     sync::Arc,
 };
 
+use ahash::AHashMap;
 use nautilus_core::{
     UUID4, UnixNanos,
     correctness::{FAILED, check_valid_string_utf8},
@@ -322,6 +323,118 @@ impl Ord for TimeEventHandler {
     }
 }
 
+/// The policy for coalescing a burst of pending events from the same timer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TimeEventCoalescePolicy {
+    /// Deliver every pending event.
+    #[default]
+    FireAll,
+    /// Deliver only the most recent pending event.
+    FireLatest,
+    /// Deliver only the earliest pending event.
+    FireFirst,
+}
+
+/// Coalesces bursts of pending time events per timer according to the policy registered for it.
+///
+/// When event delivery stalls, many events from the same timer can become pending at once. Each
+/// clock keeps a coalescer holding the policy passed when a timer is set: `TestClock` applies it
+/// to every batch returned by `advance_time`, and `LiveClock` applies it to batches of handlers
+/// drained from the time event channel through `LiveClock::coalesce_handlers`. Timers set with
+/// [`TimeEventCoalescePolicy::FireLatest`] or [`TimeEventCoalescePolicy::FireFirst`] deliver a
+/// single event per batch, and timers without a registered policy deliver every event.
+#[derive(Clone, Debug, Default)]
+pub struct TimeEventCoalescer {
+    policies: AHashMap<Ustr, TimeEventCoalescePolicy>,
+    dropped_count: usize,
+}
+
+impl TimeEventCoalescer {
+    /// Creates a new [`TimeEventCoalescer`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the coalescing `policy` for the timer `name`.
+    pub fn register(&mut self, name: Ustr, policy: TimeEventCoalescePolicy) {
+        self.policies.insert(name, policy);
+    }
+
+    /// Registers `policy` for the timer `name`, or removes its policy when `None`.
+    pub fn set_policy(&mut self, name: Ustr, policy: Option<TimeEventCoalescePolicy>) {
+        match policy {
+            Some(policy) if policy != TimeEventCoalescePolicy::FireAll => {
+                self.register(name, policy);
+            }
+            _ => self.deregister(&name),
+        }
+    }
+
+    /// Removes the policy for the timer `name`.
+    pub fn deregister(&mut self, name: &Ustr) {
+        self.policies.remove(name);
+    }
+
+    /// Returns the policy for the timer `name`.
+    #[must_use]
+    pub fn policy(&self, name: &Ustr) -> TimeEventCoalescePolicy {
+        self.policies.get(name).copied().unwrap_or_default()
+    }
+
+    /// Returns the total number of events dropped by coalescing.
+    #[must_use]
+    pub const fn dropped_count(&self) -> usize {
+        self.dropped_count
+    }
+
+    /// Coalesces a batch of pending events, preserving the order of the events kept.
+    pub fn coalesce(&mut self, events: Vec<TimeEvent>) -> Vec<TimeEvent> {
+        self.coalesce_by(events, |event| event.name)
+    }
+
+    /// Coalesces a batch of pending event handlers, preserving the order of the handlers kept.
+    pub fn coalesce_handlers(&mut self, handlers: Vec<TimeEventHandler>) -> Vec<TimeEventHandler> {
+        self.coalesce_by(handlers, |handler| handler.event.name)
+    }
+
+    fn coalesce_by<T>(&mut self, items: Vec<T>, name: impl Fn(&T) -> Ustr) -> Vec<T> {
+        if self.policies.is_empty() {
+            return items;
+        }
+
+        // Index of the item to keep for each coalesced timer
+        let mut keep: AHashMap<Ustr, usize> = AHashMap::new();
+        for (index, item) in items.iter().enumerate() {
+            let name = name(item);
+            match self.policy(&name) {
+                TimeEventCoalescePolicy::FireAll => {}
+                TimeEventCoalescePolicy::FireLatest => {
+                    keep.insert(name, index);
+                }
+                TimeEventCoalescePolicy::FireFirst => {
+                    keep.entry(name).or_insert(index);
+                }
+            }
+        }
+
+        let count = items.len();
+        let kept: Vec<T> = items
+            .into_iter()
+            .enumerate()
+            .filter(|(index, item)| keep.get(&name(item)).is_none_or(|keep| keep == index))
+            .map(|(_, item)| item)
+            .collect();
+
+        let dropped = count - kept.len();
+        if dropped > 0 {
+            log::debug!("Coalesced {dropped} stale time event(s)");
+            self.dropped_count += dropped;
+        }
+        kept
+    }
+}
+
 /// A test timer for user with a `TestClock`.
 ///
 /// `TestTimer` simulates time progression in a controlled environment,
@@ -472,7 +585,7 @@ mod tests {
     use rstest::*;
     use ustr::Ustr;
 
-    use super::{TestTimer, TimeEvent};
+    use super::{TestTimer, TimeEvent, TimeEventCoalescePolicy, TimeEventCoalescer};
 
     #[rstest]
     fn test_test_timer_pop_event() {
@@ -804,4 +917,38 @@ mod tests {
             }
         }
     }
+
+    fn burst(names: &[&str]) -> Vec<TimeEvent> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                TimeEvent::new(
+                    Ustr::from(name),
+                    nautilus_core::UUID4::new(),
+                    UnixNanos::from(i as u64),
+                    UnixNanos::from(i as u64),
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(TimeEventCoalescePolicy::FireAll, vec![0, 1, 2, 3, 4])]
+    #[case(TimeEventCoalescePolicy::FireLatest, vec![1, 3, 4])]
+    #[case(TimeEventCoalescePolicy::FireFirst, vec![0, 1, 3])]
+    fn test_time_event_coalescer(
+        #[case] policy: TimeEventCoalescePolicy,
+        #[case] expected: Vec<u64>,
+    ) {
+        let mut coalescer = TimeEventCoalescer::new();
+        coalescer.register(Ustr::from("BAR"), policy);
+        let events = burst(&["BAR", "ALERT", "BAR", "ALERT", "BAR"]);
+
+        let kept = coalescer.coalesce(events);
+
+        let kept_ts: Vec<u64> = kept.iter().map(|event| event.ts_event.as_u64()).collect();
+        assert_eq!(kept_ts, expected);
+        assert_eq!(coalescer.dropped_count(), 5 - expected.len());
+    }
 }
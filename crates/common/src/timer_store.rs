@@ -0,0 +1,379 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Persistence of named clock alerts and timers across restarts.
+//!
+//! Alerts and timers set through a [`TimerStore`] are set on the clock and recorded in the
+//! cache's general store (and so in the cache database when one is configured), keyed by the
+//! owning component. On startup [`TimerStore::rehydrate`] reloads them, rescheduling future
+//! alerts and timers, and handling alerts missed while stopped per the [`MissedAlertPolicy`].
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::Cache,
+    clock::Clock,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// How alerts whose time passed while the system was stopped are handled on rehydration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MissedAlertPolicy {
+    /// Fire missed alerts immediately.
+    #[default]
+    Fire,
+    /// Discard missed alerts.
+    Skip,
+}
+
+/// A persisted alert or timer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PersistedTimer {
+    Alert {
+        alert_time_ns: UnixNanos,
+    },
+    Timer {
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    },
+}
+
+/// The outcome of rehydrating persisted timers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RehydrationReport {
+    /// Names of future alerts and timers which were rescheduled.
+    pub rescheduled: Vec<String>,
+    /// Names of missed alerts which were fired.
+    pub fired: Vec<String>,
+    /// Names of missed alerts and finished timers which were discarded.
+    pub discarded: Vec<String>,
+}
+
+/// Sets clock alerts and timers for a component, persisting them in the cache.
+pub struct TimerStore {
+    owner: String,
+    cache: Rc<RefCell<Cache>>,
+    policy: MissedAlertPolicy,
+    timers: IndexMap<String, PersistedTimer>,
+}
+
+impl Debug for TimerStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(TimerStore))
+            .field("owner", &self.owner)
+            .field("policy", &self.policy)
+            .field("timers", &self.timers)
+            .finish()
+    }
+}
+
+impl TimerStore {
+    /// Creates a new [`TimerStore`] instance for the component `owner`.
+    #[must_use]
+    pub fn new(owner: &str, cache: Rc<RefCell<Cache>>, policy: MissedAlertPolicy) -> Self {
+        Self {
+            owner: owner.to_string(),
+            cache,
+            policy,
+            timers: IndexMap::new(),
+        }
+    }
+
+    /// Returns the general cache key the timers are persisted under.
+    #[must_use]
+    pub fn key(&self) -> String {
+        format!("timers:{}", self.owner)
+    }
+
+    /// Returns the persisted alerts and timers.
+    #[must_use]
+    pub fn timers(&self) -> &IndexMap<String, PersistedTimer> {
+        &self.timers
+    }
+
+    /// Sets a persistent time alert on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the alert, or persisting it fails.
+    pub fn set_time_alert_ns(
+        &mut self,
+        clock: &mut dyn Clock,
+        name: &str,
+        alert_time_ns: UnixNanos,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        clock.set_time_alert_ns(name, alert_time_ns, callback, None, None)?;
+        self.timers
+            .insert(name.to_string(), PersistedTimer::Alert { alert_time_ns });
+        self.persist()
+    }
+
+    /// Sets a persistent timer on `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock rejects the timer, or persisting it fails.
+    pub fn set_timer_ns(
+        &mut self,
+        clock: &mut dyn Clock,
+        name: &str,
+        interval_ns: u64,
+        start_time_ns: Option<UnixNanos>,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        let start_time_ns = start_time_ns.unwrap_or_else(|| clock.timestamp_ns());
+        clock.set_timer_ns(
+            name,
+            interval_ns,
+            Some(start_time_ns),
+            stop_time_ns,
+            callback,
+            None,
+            None,
+            None,
+        )?;
+        self.timers.insert(
+            name.to_string(),
+            PersistedTimer::Timer {
+                interval_ns,
+                start_time_ns,
+                stop_time_ns,
+            },
+        );
+        self.persist()
+    }
+
+    /// Cancels the alert or timer `name` on `clock` and removes it from the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the change fails.
+    pub fn cancel(&mut self, clock: &mut dyn Clock, name: &str) -> anyhow::Result<()> {
+        clock.cancel_timer(name);
+        if self.timers.shift_remove(name).is_some() {
+            self.persist()?;
+        }
+        Ok(())
+    }
+
+    /// Handles a fired time event, removing fired alerts and finished timers from the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the change fails.
+    pub fn on_time_event(&mut self, event: &TimeEvent) -> anyhow::Result<()> {
+        let finished = match self.timers.get(event.name.as_str()) {
+            Some(PersistedTimer::Alert { .. }) => true,
+            Some(PersistedTimer::Timer {
+                interval_ns,
+                stop_time_ns: Some(stop),
+                ..
+            }) => event.ts_event.as_u64() + interval_ns > stop.as_u64(),
+            _ => false,
+        };
+        if finished {
+            self.timers.shift_remove(event.name.as_str());
+            self.persist()?;
+        }
+        Ok(())
+    }
+
+    /// Reloads persisted alerts and timers from the cache and schedules them on `clock`.
+    ///
+    /// Future alerts are rescheduled, and missed alerts fired or discarded per the policy.
+    /// Timers resume in phase with their original schedule, unless their stop time has passed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the persisted timers cannot be decoded, or the clock rejects one.
+    pub fn rehydrate(
+        &mut self,
+        clock: &mut dyn Clock,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<RehydrationReport> {
+        let persisted: IndexMap<String, PersistedTimer> =
+            match self.cache.borrow().get(&self.key())? {
+                Some(bytes) => serde_json::from_slice(bytes)?,
+                None => IndexMap::new(),
+            };
+
+        let ts_now = clock.timestamp_ns();
+        let mut report = RehydrationReport::default();
+        self.timers.clear();
+
+        for (name, timer) in persisted {
+            match timer {
+                PersistedTimer::Alert { alert_time_ns } => {
+                    if alert_time_ns >= ts_now {
+                        report.rescheduled.push(name.clone());
+                    } else if self.policy == MissedAlertPolicy::Fire {
+                        log::warn!("Firing missed alert '{name}' from {alert_time_ns}");
+                        report.fired.push(name.clone());
+                    } else {
+                        log::warn!("Discarding missed alert '{name}' from {alert_time_ns}");
+                        report.discarded.push(name);
+                        continue;
+                    }
+                    clock.set_time_alert_ns(
+                        &name,
+                        alert_time_ns,
+                        callback.clone(),
+                        Some(true),
+                        None,
+                    )?;
+                    self.timers.insert(name, timer);
+                }
+                PersistedTimer::Timer {
+                    interval_ns,
+                    start_time_ns,
+                    stop_time_ns,
+                } => {
+                    let start = resume_start(start_time_ns, interval_ns, ts_now);
+                    if stop_time_ns.is_some_and(|stop| stop <= start || stop <= ts_now) {
+                        report.discarded.push(name);
+                        continue;
+                    }
+                    clock.set_timer_ns(
+                        &name,
+                        interval_ns,
+                        Some(start),
+                        stop_time_ns,
+                        callback.clone(),
+                        Some(true),
+                        None,
+                        None,
+                    )?;
+                    report.rescheduled.push(name.clone());
+                    self.timers.insert(name, timer);
+                }
+            }
+        }
+
+        self.persist()?;
+        Ok(report)
+    }
+
+    fn persist(&self) -> anyhow::Result<()> {
+        let bytes = serde_json::to_vec(&self.timers)?;
+        self.cache.borrow_mut().add(&self.key(), Bytes::from(bytes))
+    }
+}
+
+/// Returns the interval boundary at or before `ts_now` to resume a timer from, so that it keeps
+/// its original phase without replaying the intervals missed while stopped.
+fn resume_start(start_time_ns: UnixNanos, interval_ns: u64, ts_now: UnixNanos) -> UnixNanos {
+    if ts_now <= start_time_ns || interval_ns == 0 {
+        return start_time_ns;
+    }
+    let elapsed = ts_now.as_u64() - start_time_ns.as_u64();
+    UnixNanos::from(start_time_ns.as_u64() + elapsed / interval_ns * interval_ns)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    fn clock_at(ts: u64) -> TestClock {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(TimeEventCallback::from(|_event: TimeEvent| {}));
+        clock.advance_time(ts.into(), true);
+        clock
+    }
+
+    fn persisted_store(cache: &Rc<RefCell<Cache>>) -> TimerStore {
+        let mut clock = clock_at(0);
+        let mut store = TimerStore::new("S-001", cache.clone(), MissedAlertPolicy::Fire);
+        store
+            .set_time_alert_ns(&mut clock, "missed", 100.into(), None)
+            .unwrap();
+        store
+            .set_time_alert_ns(&mut clock, "future", 500.into(), None)
+            .unwrap();
+        store
+            .set_timer_ns(&mut clock, "heartbeat", 100, None, Some(1_000.into()), None)
+            .unwrap();
+        store
+    }
+
+    #[rstest]
+    #[case(MissedAlertPolicy::Fire, vec!["missed", "future", "heartbeat"])]
+    #[case(MissedAlertPolicy::Skip, vec!["future", "heartbeat"])]
+    fn test_rehydrate_after_restart(
+        #[case] policy: MissedAlertPolicy,
+        #[case] expected_timers: Vec<&str>,
+    ) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        persisted_store(&cache);
+        let mut clock = clock_at(350);
+        let mut store = TimerStore::new("S-001", cache, policy);
+
+        let report = store.rehydrate(&mut clock, None).unwrap();
+
+        assert_eq!(report.rescheduled, vec!["future", "heartbeat"]);
+        assert_eq!(store.timers().keys().collect::<Vec<_>>(), expected_timers);
+
+        let events = clock.advance_time(500.into(), true);
+        let names: Vec<&str> = events.iter().map(|event| event.name.as_str()).collect();
+        assert_eq!(names.contains(&"missed"), policy == MissedAlertPolicy::Fire);
+        assert_eq!(names.iter().filter(|name| **name == "heartbeat").count(), 2);
+        assert!(names.contains(&"future"));
+    }
+
+    #[rstest]
+    fn test_fired_alert_and_cancel_are_removed() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut store = persisted_store(&cache);
+        let mut clock = clock_at(0);
+
+        let event = TimeEvent::new(
+            "missed".into(),
+            nautilus_core::UUID4::new(),
+            100.into(),
+            100.into(),
+        );
+        store.on_time_event(&event).unwrap();
+        store.cancel(&mut clock, "heartbeat").unwrap();
+
+        let mut restarted = TimerStore::new("S-001", cache, MissedAlertPolicy::Fire);
+        let report = restarted.rehydrate(&mut clock, None).unwrap();
+
+        assert_eq!(report.rescheduled, vec!["future"]);
+        assert!(report.fired.is_empty());
+    }
+
+    #[rstest]
+    fn test_rehydrate_discards_finished_timer() {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        persisted_store(&cache);
+        let mut clock = clock_at(2_000);
+        let mut store = TimerStore::new("S-001", cache, MissedAlertPolicy::Skip);
+
+        let report = store.rehydrate(&mut clock, None).unwrap();
+
+        assert!(report.rescheduled.is_empty());
+        assert_eq!(report.discarded, vec!["missed", "future", "heartbeat"]);
+    }
+}
@@ -0,0 +1,539 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Regulatory-style trade reports from persisted fills.
+//!
+//! [`trade_reports`] builds a [`TradeReport`] for each fill held in the cache, assigning each
+//! trade a unique transaction identifier (UTI) prefixed with the reporting party's LEI. Reports
+//! are rendered as FIX 4.4 `TradeCaptureReport` (35=AE) messages, carrying the UTI in the
+//! FIX 5.0 SP2 regulatory trade ID group, or as CSV for compliance pipelines, with timestamps
+//! truncated to the [`TimestampGranularity`] the regime requires.
+
+use std::{fmt::Write as _, fs, path::Path};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide},
+    events::{OrderEventAny, OrderFilled},
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, VenueOrderId,
+    },
+    orders::Order,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::cache::Cache;
+
+const SOH: char = '\x01';
+const FIX_BEGIN_STRING: &str = "FIX.4.4";
+const UTI_MAX_LEN: usize = 52;
+
+/// The granularity of report timestamps.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimestampGranularity {
+    Seconds,
+    #[default]
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimestampGranularity {
+    const fn fraction(self) -> &'static str {
+        match self {
+            Self::Seconds => "",
+            Self::Milliseconds => "%.3f",
+            Self::Microseconds => "%.6f",
+            Self::Nanoseconds => "%.9f",
+        }
+    }
+
+    /// Formats `ts` as a FIX `UTCTimestamp` (`YYYYMMDD-HH:MM:SS[.fff]`).
+    #[must_use]
+    pub fn format_fix(self, ts: UnixNanos) -> String {
+        let format = format!("%Y%m%d-%H:%M:%S{}", self.fraction());
+        ts.to_datetime_utc().format(&format).to_string()
+    }
+
+    /// Formats `ts` as an ISO 8601 UTC timestamp.
+    #[must_use]
+    pub fn format_iso(self, ts: UnixNanos) -> String {
+        let format = format!("%Y-%m-%dT%H:%M:%S{}Z", self.fraction());
+        ts.to_datetime_utc().format(&format).to_string()
+    }
+}
+
+/// The output format of a trade report export.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TradeReportFormat {
+    /// FIX `TradeCaptureReport` messages, one per line.
+    Fix,
+    Csv,
+}
+
+/// Configuration for trade report generation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeReportConfig {
+    /// The LEI of the reporting party, used as the UTI prefix.
+    pub reporting_lei: String,
+    /// The FIX `SenderCompID` (49).
+    pub sender_comp_id: String,
+    /// The FIX `TargetCompID` (56).
+    pub target_comp_id: String,
+    pub granularity: TimestampGranularity,
+}
+
+/// A regulatory-style report of a single trade (fill).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeReport {
+    /// The unique transaction identifier.
+    pub uti: String,
+    pub trade_id: TradeId,
+    pub instrument_id: InstrumentId,
+    pub side: OrderSide,
+    pub quantity: String,
+    pub price: String,
+    pub currency: String,
+    pub commission: Option<String>,
+    pub liquidity_side: LiquiditySide,
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub account_id: AccountId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+    /// When the trade was executed at the venue.
+    pub ts_execution: UnixNanos,
+    /// When the trade was recorded by the system.
+    pub ts_recorded: UnixNanos,
+}
+
+impl TradeReport {
+    /// Creates a new [`TradeReport`] for the `fill`.
+    #[must_use]
+    pub fn from_fill(fill: &OrderFilled, config: &TradeReportConfig) -> Self {
+        Self {
+            uti: generate_uti(&config.reporting_lei, &fill.instrument_id, &fill.trade_id),
+            trade_id: fill.trade_id,
+            instrument_id: fill.instrument_id,
+            side: fill.order_side,
+            quantity: fill.last_qty.to_string(),
+            price: fill.last_px.to_string(),
+            currency: fill.currency.code.to_string(),
+            commission: fill.commission.map(|c| c.as_decimal().to_string()),
+            liquidity_side: fill.liquidity_side,
+            trader_id: fill.trader_id,
+            strategy_id: fill.strategy_id,
+            account_id: fill.account_id,
+            client_order_id: fill.client_order_id,
+            venue_order_id: fill.venue_order_id,
+            ts_execution: fill.ts_event,
+            ts_recorded: fill.ts_init,
+        }
+    }
+
+    /// Renders the report as a FIX `TradeCaptureReport` with message sequence number `seq_num`.
+    #[must_use]
+    pub fn to_fix(&self, config: &TradeReportConfig, seq_num: u64) -> String {
+        let granularity = config.granularity;
+        let mut fields: Vec<(u32, String)> = vec![
+            (35, "AE".to_string()),
+            (49, config.sender_comp_id.clone()),
+            (56, config.target_comp_id.clone()),
+            (34, seq_num.to_string()),
+            (52, granularity.format_fix(self.ts_recorded)),
+            (571, self.uti.clone()),
+            (487, "0".to_string()), // TradeReportTransType: New
+            (856, "0".to_string()), // TradeReportType: Submit
+            (570, "N".to_string()), // PreviouslyReported
+            (1003, self.trade_id.to_string()),
+            (17, self.trade_id.to_string()),
+            (55, self.instrument_id.symbol.to_string()),
+            (207, self.instrument_id.venue.to_string()),
+            (32, self.quantity.clone()),
+            (31, self.price.clone()),
+            (15, self.currency.clone()),
+            (
+                75,
+                self.ts_execution
+                    .to_datetime_utc()
+                    .format("%Y%m%d")
+                    .to_string(),
+            ),
+            (60, granularity.format_fix(self.ts_execution)),
+            (1907, "1".to_string()), // NoRegulatoryTradeIDs
+            (1903, self.uti.clone()),
+            (1905, config.reporting_lei.clone()),
+            (1906, "0".to_string()), // RegulatoryTradeIDType: Current
+            (552, "1".to_string()),  // NoSides
+            (54, fix_side(self.side).to_string()),
+            (37, self.venue_order_id.to_string()),
+            (11, self.client_order_id.to_string()),
+            (1, self.account_id.to_string()),
+        ];
+        if let Some(liquidity) = fix_liquidity(self.liquidity_side) {
+            fields.push((851, liquidity.to_string()));
+        }
+        if let Some(commission) = &self.commission {
+            fields.push((12, commission.clone()));
+        }
+
+        let body: String = fields
+            .iter()
+            .map(|(tag, value)| format!("{tag}={value}{SOH}"))
+            .collect();
+        let mut message = format!("8={FIX_BEGIN_STRING}{SOH}9={}{SOH}{body}", body.len());
+        let checksum = message.bytes().map(u32::from).sum::<u32>() % 256;
+        write!(message, "10={checksum:03}{SOH}").expect("writing to a string");
+        message
+    }
+
+    /// Returns the CSV header row matching [`Self::to_csv_row`].
+    #[must_use]
+    pub fn csv_header() -> &'static str {
+        "uti,trade_id,instrument_id,side,quantity,price,currency,commission,liquidity_side,\
+         trader_id,strategy_id,account_id,client_order_id,venue_order_id,execution_time,\
+         recorded_time"
+    }
+
+    /// Renders the report as a CSV row.
+    #[must_use]
+    pub fn to_csv_row(&self, config: &TradeReportConfig) -> String {
+        [
+            self.uti.clone(),
+            self.trade_id.to_string(),
+            self.instrument_id.to_string(),
+            self.side.to_string(),
+            self.quantity.clone(),
+            self.price.clone(),
+            self.currency.clone(),
+            self.commission.clone().unwrap_or_default(),
+            self.liquidity_side.to_string(),
+            self.trader_id.to_string(),
+            self.strategy_id.to_string(),
+            self.account_id.to_string(),
+            self.client_order_id.to_string(),
+            self.venue_order_id.to_string(),
+            config.granularity.format_iso(self.ts_execution),
+            config.granularity.format_iso(self.ts_recorded),
+        ]
+        .iter()
+        .map(|field| escape_csv(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+/// Returns the UTI for a trade: the reporting party's LEI followed by the uppercase
+/// alphanumeric venue and trade ID, truncated to 52 characters.
+#[must_use]
+pub fn generate_uti(
+    reporting_lei: &str,
+    instrument_id: &InstrumentId,
+    trade_id: &TradeId,
+) -> String {
+    format!("{reporting_lei}{}{trade_id}", instrument_id.venue)
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase())
+        .take(UTI_MAX_LEN)
+        .collect()
+}
+
+/// Returns reports for the fills in the cache executed within `[start, end)`, ordered by
+/// execution time.
+#[must_use]
+pub fn trade_reports(
+    cache: &Cache,
+    config: &TradeReportConfig,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> Vec<TradeReport> {
+    let mut reports: Vec<TradeReport> = cache
+        .orders(None, None, None, None, None)
+        .into_iter()
+        .flat_map(|order| order.events())
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(fill) => Some(fill),
+            _ => None,
+        })
+        .filter(|fill| start.is_none_or(|start| fill.ts_event >= start))
+        .filter(|fill| end.is_none_or(|end| fill.ts_event < end))
+        .map(|fill| TradeReport::from_fill(fill, config))
+        .collect();
+    reports.sort_by(|a, b| {
+        (a.ts_execution, a.trade_id.as_str()).cmp(&(b.ts_execution, b.trade_id.as_str()))
+    });
+    reports
+}
+
+/// Renders the `reports` in the given `format`, one message or row per line.
+#[must_use]
+pub fn render_trade_reports(
+    reports: &[TradeReport],
+    config: &TradeReportConfig,
+    format: TradeReportFormat,
+) -> String {
+    let mut output = String::new();
+    match format {
+        TradeReportFormat::Fix => {
+            for (seq_num, report) in (1..).zip(reports) {
+                output.push_str(&report.to_fix(config, seq_num));
+                output.push('\n');
+            }
+        }
+        TradeReportFormat::Csv => {
+            output.push_str(TradeReport::csv_header());
+            output.push('\n');
+            for report in reports {
+                output.push_str(&report.to_csv_row(config));
+                output.push('\n');
+            }
+        }
+    }
+    output
+}
+
+/// Writes the reports for the cached fills within `[start, end)` to `path`, returning the number
+/// of reports written.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn export_trade_reports(
+    cache: &Cache,
+    config: &TradeReportConfig,
+    format: TradeReportFormat,
+    path: &Path,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<usize> {
+    let reports = trade_reports(cache, config, start, end);
+    fs::write(path, render_trade_reports(&reports, config, format))?;
+    log::info!(
+        "Exported {} trade reports to {}",
+        reports.len(),
+        path.display()
+    );
+    Ok(reports.len())
+}
+
+const fn fix_side(side: OrderSide) -> char {
+    match side {
+        OrderSide::Sell => '2',
+        _ => '1',
+    }
+}
+
+const fn fix_liquidity(liquidity_side: LiquiditySide) -> Option<char> {
+    match liquidity_side {
+        LiquiditySide::Maker => Some('1'),
+        LiquiditySide::Taker => Some('2'),
+        LiquiditySide::NoLiquiditySide => None,
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        instruments::{Instrument, InstrumentAny, stubs::audusd_sim},
+        orders::{OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{Money, Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    // 2024-01-05 14:30:00.123456789 UTC
+    const TS_EXECUTION: u64 = 1_704_465_000_123_456_789;
+
+    fn config() -> TradeReportConfig {
+        TradeReportConfig {
+            reporting_lei: "529900T8BM49AURSDO55".to_string(),
+            sender_comp_id: "NAUTILUS".to_string(),
+            target_comp_id: "ARM".to_string(),
+            granularity: TimestampGranularity::Microseconds,
+        }
+    }
+
+    fn fill() -> OrderFilled {
+        OrderFilled {
+            instrument_id: InstrumentId::from("ESH4.XCME"),
+            trade_id: TradeId::from("T-123"),
+            order_side: OrderSide::Sell,
+            last_qty: Quantity::from(2),
+            last_px: Price::from("4500.25"),
+            liquidity_side: LiquiditySide::Maker,
+            commission: Some(Money::from("4.20 USD")),
+            ts_event: UnixNanos::from(TS_EXECUTION),
+            ts_init: UnixNanos::from(TS_EXECUTION + 100_000_000),
+            ..Default::default()
+        }
+    }
+
+    fn fix_field(message: &str, tag: u32) -> Option<&str> {
+        message
+            .split(SOH)
+            .find_map(|field| field.strip_prefix(&format!("{tag}=")))
+    }
+
+    #[rstest]
+    #[case(
+        TimestampGranularity::Seconds,
+        "20240105-14:30:00",
+        "2024-01-05T14:30:00Z"
+    )]
+    #[case(
+        TimestampGranularity::Milliseconds,
+        "20240105-14:30:00.123",
+        "2024-01-05T14:30:00.123Z"
+    )]
+    #[case(
+        TimestampGranularity::Nanoseconds,
+        "20240105-14:30:00.123456789",
+        "2024-01-05T14:30:00.123456789Z"
+    )]
+    fn test_timestamp_granularity(
+        #[case] granularity: TimestampGranularity,
+        #[case] fix: &str,
+        #[case] iso: &str,
+    ) {
+        let ts = UnixNanos::from(TS_EXECUTION);
+
+        assert_eq!(granularity.format_fix(ts), fix);
+        assert_eq!(granularity.format_iso(ts), iso);
+    }
+
+    #[rstest]
+    fn test_generate_uti_is_sanitized_and_truncated() {
+        let uti = generate_uti(
+            "529900T8BM49AURSDO55",
+            &InstrumentId::from("ESH4.XCME"),
+            &TradeId::from("t-123"),
+        );
+        assert_eq!(uti, "529900T8BM49AURSDO55XCMET123");
+
+        let long_trade_id = TradeId::from("A".repeat(30).as_str());
+        let uti = generate_uti(
+            "529900T8BM49AURSDO55",
+            &InstrumentId::from("ESH4.XCME"),
+            &long_trade_id,
+        );
+        assert_eq!(uti.len(), UTI_MAX_LEN);
+    }
+
+    #[rstest]
+    fn test_fix_trade_capture_report() {
+        let message = TradeReport::from_fill(&fill(), &config()).to_fix(&config(), 7);
+        let body_start = message.find("35=").unwrap();
+        let checksum_start = message.find("10=").unwrap();
+        let checksum = message[..checksum_start]
+            .bytes()
+            .map(u32::from)
+            .sum::<u32>()
+            % 256;
+
+        assert_eq!(
+            fix_field(&message, 9).unwrap(),
+            (checksum_start - body_start).to_string()
+        );
+        assert_eq!(fix_field(&message, 10).unwrap(), format!("{checksum:03}"));
+        assert_eq!(fix_field(&message, 35), Some("AE"));
+        assert_eq!(fix_field(&message, 34), Some("7"));
+        assert_eq!(
+            fix_field(&message, 1903),
+            Some("529900T8BM49AURSDO55XCMET123")
+        );
+        assert_eq!(fix_field(&message, 54), Some("2"));
+        assert_eq!(fix_field(&message, 31), Some("4500.25"));
+        assert_eq!(fix_field(&message, 60), Some("20240105-14:30:00.123456"));
+        assert_eq!(fix_field(&message, 851), Some("1"));
+        assert_eq!(fix_field(&message, 12), Some("4.20"));
+    }
+
+    #[rstest]
+    fn test_csv_row_matches_header() {
+        let row = TradeReport::from_fill(&fill(), &config()).to_csv_row(&config());
+        let fields: Vec<&str> = row.split(',').collect();
+
+        assert_eq!(fields.len(), TradeReport::csv_header().split(',').count());
+        assert_eq!(fields[3], "SELL");
+        assert_eq!(fields[14], "2024-01-05T14:30:00.123456Z");
+        assert_eq!(escape_csv("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[rstest]
+    fn test_trade_reports_from_cached_fills() {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let mut cache = Cache::default();
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::filled(
+                &order,
+                &instrument,
+                Some(TradeId::from("T-1")),
+                None,
+                Some(Price::from("1.00010")),
+                None,
+                None,
+                None,
+                Some(UnixNanos::from(TS_EXECUTION)),
+                None,
+            ))
+            .unwrap();
+        cache.update_order(&order).unwrap();
+
+        let reports = trade_reports(&cache, &config(), None, None);
+        let output = render_trade_reports(&reports, &config(), TradeReportFormat::Csv);
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].trade_id, TradeId::from("T-1"));
+        assert_eq!(output.lines().count(), 2);
+        assert!(
+            trade_reports(
+                &cache,
+                &config(),
+                Some(UnixNanos::from(TS_EXECUTION + 1)),
+                None
+            )
+            .is_empty()
+        );
+    }
+}
@@ -47,6 +47,7 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from [cbindgen](https://github.com/mozilla/cbindgen).
 //! - `python`: Enables Python bindings from [PyO3](https://pyo3.rs).
 //! - `extension-module`: Builds the crate as a Python extension module.
+//! - `wasm`: Enables building for `wasm32-unknown-unknown` (JavaScript time source and randomness).
 
 #![warn(rustc::all)]
 #![deny(unsafe_code)]
@@ -82,8 +83,15 @@ pub mod ffi;
 #[cfg(feature = "python")]
 pub mod python;
 
-#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
-compile_error!("Unsupported platform: Nautilus supports only Linux, macOS, and Windows");
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "windows",
+    all(target_arch = "wasm32", feature = "wasm")
+)))]
+compile_error!(
+    "Unsupported platform: Nautilus supports only Linux, macOS, Windows, and WebAssembly (with the `wasm` feature)"
+);
 
 // Re-exports
 pub use crate::{
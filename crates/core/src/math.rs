@@ -17,7 +17,7 @@
 //!
 //! This module provides essential mathematical operations for quantitative trading,
 //! including linear and quadratic interpolation functions commonly used in financial
-//! data processing and analysis.
+//! data processing and analysis, as well as standard normal distribution helpers.
 //!
 //! # Epsilon Values
 //!
@@ -228,6 +228,69 @@ pub fn quadratic_interpolation(x: f64, xs: &[f64], ys: &[f64]) -> f64 {
     )
 }
 
+/// Returns the standard normal probability density at `x`.
+#[inline]
+#[must_use]
+pub fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Returns the quantile of the standard normal distribution for probability `p`.
+///
+/// Uses the rational approximation of Acklam with a relative error below 1.15e-9.
+///
+/// # Panics
+///
+/// Panics if `p` is not in the open interval (0, 1).
+#[must_use]
+pub fn norm_inv_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    assert!(p > 0.0 && p < 1.0, "`p` must be in (0, 1), was {p}");
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -norm_inv_cdf(1.0 - p)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::*;
@@ -450,4 +513,23 @@ mod tests {
     fn test_quad_polynomial_infinity_panics() {
         let _ = quad_polynomial(0.5, f64::INFINITY, 1.0, 2.0, 0.0, 1.0, 4.0);
     }
+
+    #[rstest]
+    #[case(0.5, 0.0)]
+    #[case(0.95, 1.644_853_626_951_472)]
+    #[case(0.99, 2.326_347_874_040_841)]
+    #[case(0.01, -2.326_347_874_040_841)]
+    fn test_norm_inv_cdf(#[case] p: f64, #[case] expected: f64) {
+        let result = norm_inv_cdf(p);
+        assert!(
+            approx_eq!(f64, result, expected, epsilon = 1e-8),
+            "Expected {expected}, was {result}"
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "must be in (0, 1)")]
+    fn test_norm_inv_cdf_out_of_range() {
+        let _ = norm_inv_cdf(1.0);
+    }
 }
@@ -32,12 +32,16 @@
 //!   acquire/release semantics so that updates from one thread can be observed by another;
 //!   however, we do not enforce strict global ordering for manual updates. If you need strong,
 //!   multi-threaded ordering in **static mode**, you must coordinate higher-level synchronization yourself.
+//!
+//! In **real-time mode** a correction offset (see [`AtomicTime::set_offset_ns`]) is added to the
+//! system time, so an externally measured offset (for example from a PTP or NTP daemon) can align
+//! timestamps across nodes. Monotonicity is preserved when the offset decreases.
 
 use std::{
     ops::Deref,
     sync::{
         OnceLock,
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
     },
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -80,6 +84,7 @@ pub fn get_atomic_clock_static() -> &'static AtomicTime {
 /// # Panics
 ///
 /// Panics if the system time is set before the UNIX epoch.
+#[cfg(not(all(target_arch = "wasm32", feature = "wasm")))]
 #[inline(always)]
 #[must_use]
 pub fn duration_since_unix_epoch() -> Duration {
@@ -93,6 +98,17 @@ pub fn duration_since_unix_epoch() -> Duration {
         .expect("Error calling `SystemTime`")
 }
 
+/// Returns the duration since the UNIX epoch based on the JavaScript `Date.now()`.
+///
+/// `SystemTime::now()` is unavailable on `wasm32-unknown-unknown`, so the host clock is read
+/// instead (millisecond resolution).
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[inline(always)]
+#[must_use]
+pub fn duration_since_unix_epoch() -> Duration {
+    Duration::from_secs_f64(js_sys::Date::now() / 1_000.0)
+}
+
 /// Returns the current UNIX time in nanoseconds, based on [`SystemTime::now()`].
 ///
 /// # Panics
@@ -127,6 +143,8 @@ pub struct AtomicTime {
     /// The last recorded time (in UNIX nanoseconds). Updated atomically with compare-and-exchange
     /// in **real-time mode**, or simple store/fetch in **static mode**.
     pub timestamp_ns: AtomicU64,
+    /// The correction (nanoseconds) added to the system time in **real-time mode**.
+    pub offset_ns: AtomicI64,
 }
 
 impl Deref for AtomicTime {
@@ -156,6 +174,7 @@ impl AtomicTime {
         Self {
             realtime: AtomicBool::new(realtime),
             timestamp_ns: AtomicU64::new(time.into()),
+            offset_ns: AtomicI64::new(0),
         }
     }
 
@@ -297,7 +316,7 @@ impl AtomicTime {
     pub fn time_since_epoch(&self) -> UnixNanos {
         // This method guarantees strict consistency but may incur a performance cost under
         // high contention due to retries in the `compare_exchange` loop.
-        let now = nanos_since_unix_epoch();
+        let now = nanos_since_unix_epoch().saturating_add_signed(self.offset_ns());
         loop {
             // Acquire to observe the latest stored value
             let last = self.load(Ordering::Acquire);
@@ -344,6 +363,20 @@ impl AtomicTime {
     pub fn make_static(&self) {
         self.realtime.store(false, Ordering::SeqCst);
     }
+
+    /// Returns the correction (nanoseconds) added to the system time in **real-time mode**.
+    #[must_use]
+    pub fn offset_ns(&self) -> i64 {
+        self.offset_ns.load(Ordering::Acquire)
+    }
+
+    /// Sets the correction (nanoseconds) added to the system time in **real-time mode**.
+    ///
+    /// A positive offset moves timestamps forward. Decreasing the offset never moves timestamps
+    /// backward: the clock holds until the corrected system time passes the last timestamp.
+    pub fn set_offset_ns(&self, offset_ns: i64) {
+        self.offset_ns.store(offset_ns, Ordering::Release);
+    }
 }
 
 #[cfg(test)]
@@ -413,6 +446,7 @@ mod tests {
         let clock = AtomicTime {
             realtime: AtomicBool::new(true),
             timestamp_ns: AtomicU64::new(u64::MAX),
+            offset_ns: std::sync::atomic::AtomicI64::new(0),
         };
 
         // This call will attempt to add 1 and must panic
@@ -505,6 +539,21 @@ mod tests {
         }
     }
 
+    #[rstest]
+    fn test_offset_applied_and_monotonic() {
+        let clock = AtomicTime::new(true, UnixNanos::default());
+        let offset_ns: i64 = 60_000_000_000;
+
+        clock.set_offset_ns(offset_ns);
+        let shifted = clock.get_time_ns().as_u64();
+        assert_eq!(clock.offset_ns(), offset_ns);
+        assert!(shifted >= nanos_since_unix_epoch() + 30 * NANOSECONDS_IN_SECOND);
+
+        // Removing the offset holds the clock rather than moving it backward
+        clock.set_offset_ns(0);
+        assert!(clock.get_time_ns().as_u64() > shifted);
+    }
+
     #[rstest]
     fn test_time_since_epoch_strictly_increasing_concurrent() {
         let time = Arc::new(AtomicTime::new(true, UnixNanos::default()));
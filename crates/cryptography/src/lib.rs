@@ -23,6 +23,8 @@
 //! - Digital signatures using RSA and Ed25519 algorithms.
 //! - TLS client configuration with platform certificate verification.
 //! - Cryptographic provider management and initialization.
+//! - Message digests for integrity checks.
+//! - Secrets providers for venue credentials (zeroized on drop).
 //! - Secure encoding and decoding utilities.
 //!
 //! # Platform
@@ -54,7 +56,9 @@
 #![deny(clippy::missing_panics_doc)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod digest;
 pub mod providers;
+pub mod secrets;
 pub mod signing;
 pub mod tls;
 
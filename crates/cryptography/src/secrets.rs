@@ -0,0 +1,620 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Secrets provider for venue credentials.
+//!
+//! Adapter configurations hold a [`Secret`] (a reference to where the credential lives) instead
+//! of a plaintext string. The secret is only resolved on first use, and the resolved value is
+//! held in a [`SecretString`] which is zeroized when dropped and never printed by `Debug`.
+//!
+//! Supported sources are:
+//! - Environment variables (`env:BINANCE_API_KEY`).
+//! - AES-256-GCM encrypted files, with the key supplied by an environment variable
+//!   (`file:/etc/nautilus/binance.key?key_env=NAUTILUS_SECRETS_KEY`).
+//! - [age](https://age-encryption.org) encrypted files (binary or ASCII armored), decrypted with
+//!   the X25519 identities of an `age-keygen` identity file
+//!   (`age:/etc/nautilus/binance.age?identity=/etc/nautilus/identity.txt`).
+//! - The OS keychain (`keychain:nautilus/binance-api-key`), via `security` on macOS and
+//!   `secret-tool` (libsecret) on Linux.
+
+use std::{
+    fmt::{Debug, Display},
+    io::Read,
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use aws_lc_rs::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    rand::{SecureRandom, SystemRandom},
+};
+use base64::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The length of an AES-256-GCM secrets key in bytes.
+pub const SECRETS_KEY_LEN: usize = 32;
+
+/// A secret string value which is zeroized on drop.
+///
+/// The `Debug` and `Display` implementations never reveal the value.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Creates a new [`SecretString`] instance taking ownership of `value`.
+    #[must_use]
+    pub fn new(value: String) -> Self {
+        Self(Zeroizing::new(value))
+    }
+
+    /// Returns the secret value.
+    ///
+    /// Callers should avoid copying the returned value into long lived plaintext strings.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns whether the secret value is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+impl Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(***)", stringify!(SecretString))
+    }
+}
+
+impl Display for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "***")
+    }
+}
+
+/// The source a [`Secret`] is resolved from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecretSource {
+    /// An in-memory value (intended for tests and sandbox environments).
+    Value(SecretString),
+    /// An environment variable.
+    Env {
+        /// The name of the environment variable.
+        var: String,
+    },
+    /// An AES-256-GCM encrypted file (see [`encrypt_secret`]).
+    EncryptedFile {
+        /// The path to the encrypted file.
+        path: PathBuf,
+        /// The environment variable holding the hex encoded decryption key.
+        key_env: String,
+    },
+    /// An age encrypted file.
+    AgeFile {
+        /// The path to the encrypted file.
+        path: PathBuf,
+        /// The path to the identity file holding the decryption keys.
+        identity: PathBuf,
+    },
+    /// An entry in the OS keychain.
+    Keychain {
+        /// The keychain service name.
+        service: String,
+        /// The keychain account name.
+        account: String,
+    },
+}
+
+impl SecretSource {
+    /// Resolves the secret value from the source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is unavailable, or the value cannot be decrypted.
+    pub fn resolve(&self) -> anyhow::Result<SecretString> {
+        match self {
+            Self::Value(value) => Ok(value.clone()),
+            Self::Env { var } => std::env::var(var)
+                .map(SecretString::new)
+                .map_err(|e| anyhow::anyhow!("Failed to read secret from env var '{var}': {e}")),
+            Self::EncryptedFile { path, key_env } => {
+                let key = key_from_env(key_env)?;
+                decrypt_secret_file(path, &key)
+            }
+            Self::AgeFile { path, identity } => decrypt_age_secret_file(path, identity),
+            Self::Keychain { service, account } => read_keychain(service, account),
+        }
+    }
+}
+
+impl Display for SecretSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(_) => write!(f, "value:***"),
+            Self::Env { var } => write!(f, "env:{var}"),
+            Self::EncryptedFile { path, key_env } => {
+                write!(f, "file:{}?key_env={key_env}", path.display())
+            }
+            Self::AgeFile { path, identity } => {
+                write!(f, "age:{}?identity={}", path.display(), identity.display())
+            }
+            Self::Keychain { service, account } => write!(f, "keychain:{service}/{account}"),
+        }
+    }
+}
+
+impl FromStr for SecretSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, rest) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid secret source '{s}', missing scheme"))?;
+
+        match scheme {
+            "env" if !rest.is_empty() => Ok(Self::Env {
+                var: rest.to_string(),
+            }),
+            "file" => {
+                let (path, key_env) = rest
+                    .split_once("?key_env=")
+                    .filter(|(path, key_env)| !path.is_empty() && !key_env.is_empty())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Invalid secret source '{s}', expected 'file:<path>?key_env=<VAR>'"
+                        )
+                    })?;
+                Ok(Self::EncryptedFile {
+                    path: PathBuf::from(path),
+                    key_env: key_env.to_string(),
+                })
+            }
+            "age" => {
+                let (path, identity) = rest
+                    .split_once("?identity=")
+                    .filter(|(path, identity)| !path.is_empty() && !identity.is_empty())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Invalid secret source '{s}', expected 'age:<path>?identity=<path>'"
+                        )
+                    })?;
+                Ok(Self::AgeFile {
+                    path: PathBuf::from(path),
+                    identity: PathBuf::from(identity),
+                })
+            }
+            "keychain" => {
+                let (service, account) = rest
+                    .split_once('/')
+                    .filter(|(service, account)| !service.is_empty() && !account.is_empty())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Invalid secret source '{s}', expected 'keychain:<service>/<account>'"
+                        )
+                    })?;
+                Ok(Self::Keychain {
+                    service: service.to_string(),
+                    account: account.to_string(),
+                })
+            }
+            _ => anyhow::bail!("Invalid secret source '{s}'"),
+        }
+    }
+}
+
+/// A lazily resolved secret.
+///
+/// The value is resolved from its [`SecretSource`] on first access and then cached in memory
+/// (zeroized on drop).
+#[derive(Debug)]
+pub struct Secret {
+    source: SecretSource,
+    value: OnceLock<SecretString>,
+}
+
+impl Secret {
+    /// Creates a new [`Secret`] instance for the given `source`.
+    #[must_use]
+    pub const fn new(source: SecretSource) -> Self {
+        Self {
+            source,
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Creates a new [`Secret`] instance from an in-memory value.
+    #[must_use]
+    pub fn from_value<S: Into<SecretString>>(value: S) -> Self {
+        Self::new(SecretSource::Value(value.into()))
+    }
+
+    /// Returns the source of the secret.
+    #[must_use]
+    pub const fn source(&self) -> &SecretSource {
+        &self.source
+    }
+
+    /// Returns whether the secret has been resolved.
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Returns the secret value, resolving it from the source on first access.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret cannot be resolved.
+    pub fn expose(&self) -> anyhow::Result<&str> {
+        if let Some(value) = self.value.get() {
+            return Ok(value.expose());
+        }
+
+        let resolved = self.source.resolve()?;
+        Ok(self.value.get_or_init(|| resolved).expose())
+    }
+}
+
+impl Clone for Secret {
+    fn clone(&self) -> Self {
+        // Clones are unresolved so plaintext copies are only made on demand
+        Self::new(self.source.clone())
+    }
+}
+
+impl FromStr for Secret {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SecretSource::from_str(s).map(Self::new)
+    }
+}
+
+/// Generates a new random AES-256-GCM secrets key.
+///
+/// # Errors
+///
+/// Returns an error if the system random number generator fails.
+pub fn generate_secrets_key() -> anyhow::Result<Zeroizing<[u8; SECRETS_KEY_LEN]>> {
+    let mut key = Zeroizing::new([0u8; SECRETS_KEY_LEN]);
+    SystemRandom::new()
+        .fill(key.as_mut())
+        .map_err(|_| anyhow::anyhow!("Failed to generate secrets key"))?;
+    Ok(key)
+}
+
+/// Encrypts `plaintext` with AES-256-GCM, returning the base64 encoded `nonce || ciphertext`.
+///
+/// # Errors
+///
+/// Returns an error if the key is invalid or encryption fails.
+pub fn encrypt_secret(key: &[u8], plaintext: &str) -> anyhow::Result<String> {
+    let key = aead_key(key)?;
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce_bytes)
+        .map_err(|_| anyhow::anyhow!("Failed to generate nonce"))?;
+
+    let mut in_out = Zeroizing::new(plaintext.as_bytes().to_vec());
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce_bytes),
+        Aad::empty(),
+        &mut *in_out,
+    )
+    .map_err(|_| anyhow::anyhow!("Failed to encrypt secret"))?;
+
+    let mut payload = Vec::with_capacity(NONCE_LEN + in_out.len());
+    payload.extend_from_slice(&nonce_bytes);
+    payload.extend_from_slice(&in_out);
+    Ok(BASE64_STANDARD.encode(payload))
+}
+
+/// Decrypts a base64 encoded `nonce || ciphertext` produced by [`encrypt_secret`].
+///
+/// # Errors
+///
+/// Returns an error if the payload is malformed, or the key does not authenticate it.
+pub fn decrypt_secret(key: &[u8], encoded: &str) -> anyhow::Result<SecretString> {
+    let key = aead_key(key)?;
+
+    let payload = BASE64_STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| anyhow::anyhow!("Failed to decode encrypted secret: {e}"))?;
+    if payload.len() < NONCE_LEN {
+        anyhow::bail!("Encrypted secret too short");
+    }
+
+    let (nonce_bytes, ciphertext) = payload.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce_bytes)
+        .map_err(|_| anyhow::anyhow!("Invalid encrypted secret nonce"))?;
+
+    let mut in_out = Zeroizing::new(ciphertext.to_vec());
+    let plaintext = key
+        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .map_err(|_| anyhow::anyhow!("Failed to decrypt secret (invalid key or corrupted data)"))?;
+
+    let value = String::from_utf8(plaintext.to_vec()).map_err(|e| {
+        let mut bytes = e.into_bytes();
+        bytes.zeroize();
+        anyhow::anyhow!("Decrypted secret is not valid UTF-8")
+    })?;
+    Ok(SecretString::new(value))
+}
+
+/// Decrypts the secret file at `path` produced by [`encrypt_secret`].
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or decrypted.
+pub fn decrypt_secret_file(path: &Path, key: &[u8]) -> anyhow::Result<SecretString> {
+    let encoded = std::fs::read_to_string(path).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read encrypted secret file '{}': {e}",
+            path.display()
+        )
+    })?;
+    decrypt_secret(key, &encoded)
+}
+
+/// Decrypts the age encrypted `ciphertext` (binary or ASCII armored) with `identities`.
+///
+/// A single trailing newline is stripped, as written by `echo <secret> | age ...`.
+///
+/// # Errors
+///
+/// Returns an error if the ciphertext is malformed, or no identity can decrypt it.
+pub fn decrypt_age_secret(
+    identities: &[Box<dyn age::Identity>],
+    ciphertext: &[u8],
+) -> anyhow::Result<SecretString> {
+    let decryptor = age::Decryptor::new_buffered(age::armor::ArmoredReader::new(ciphertext))
+        .map_err(|e| anyhow::anyhow!("Invalid age encrypted secret: {e}"))?;
+    let mut reader = decryptor
+        .decrypt(identities.iter().map(|identity| identity.as_ref()))
+        .map_err(|e| anyhow::anyhow!("Failed to decrypt age secret: {e}"))?;
+
+    let mut plaintext = Zeroizing::new(Vec::new());
+    reader
+        .read_to_end(&mut plaintext)
+        .map_err(|e| anyhow::anyhow!("Failed to decrypt age secret: {e}"))?;
+
+    let mut value = String::from_utf8(std::mem::take(&mut *plaintext)).map_err(|e| {
+        let mut bytes = e.into_bytes();
+        bytes.zeroize();
+        anyhow::anyhow!("Decrypted age secret is not valid UTF-8")
+    })?;
+    let trimmed_len = value
+        .strip_suffix('\n')
+        .map_or(value.len(), |v| v.strip_suffix('\r').unwrap_or(v).len());
+    value.truncate(trimmed_len);
+    Ok(SecretString::new(value))
+}
+
+/// Decrypts the age encrypted file at `path` with the identities in the `identity` file.
+///
+/// # Errors
+///
+/// Returns an error if either file cannot be read, or the secret cannot be decrypted.
+pub fn decrypt_age_secret_file(path: &Path, identity: &Path) -> anyhow::Result<SecretString> {
+    let identities = age::IdentityFile::from_file(identity.to_string_lossy().into_owned())
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to read age identity file '{}': {e}",
+                identity.display()
+            )
+        })?
+        .into_identities()
+        .map_err(|e| anyhow::anyhow!("Invalid age identity file '{}': {e}", identity.display()))?;
+    let ciphertext = std::fs::read(path).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to read age encrypted secret file '{}': {e}",
+            path.display()
+        )
+    })?;
+    decrypt_age_secret(&identities, &ciphertext)
+}
+
+fn aead_key(key: &[u8]) -> anyhow::Result<LessSafeKey> {
+    if key.len() != SECRETS_KEY_LEN {
+        anyhow::bail!(
+            "Invalid secrets key length {}, expected {SECRETS_KEY_LEN}",
+            key.len()
+        );
+    }
+
+    let unbound = UnboundKey::new(&AES_256_GCM, key)
+        .map_err(|_| anyhow::anyhow!("Invalid AES-256-GCM key"))?;
+    Ok(LessSafeKey::new(unbound))
+}
+
+fn key_from_env(key_env: &str) -> anyhow::Result<Zeroizing<Vec<u8>>> {
+    let encoded = Zeroizing::new(std::env::var(key_env).map_err(|e| {
+        anyhow::anyhow!("Failed to read secrets key from env var '{key_env}': {e}")
+    })?);
+    hex::decode(encoded.trim())
+        .map(Zeroizing::new)
+        .map_err(|e| anyhow::anyhow!("Invalid hex secrets key in env var '{key_env}': {e}"))
+}
+
+fn read_keychain(service: &str, account: &str) -> anyhow::Result<SecretString> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("security");
+        command.args(["find-generic-password", "-s", service, "-a", account, "-w"]);
+        command
+    } else if cfg!(target_os = "linux") {
+        let mut command = Command::new("secret-tool");
+        command.args(["lookup", "service", service, "account", account]);
+        command
+    } else {
+        anyhow::bail!("OS keychain secrets are not supported on this platform");
+    };
+
+    let mut output = command
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to query OS keychain: {e}"))?;
+
+    if !output.status.success() {
+        output.stdout.zeroize();
+        anyhow::bail!("Secret '{service}/{account}' not found in OS keychain");
+    }
+
+    let mut value = String::from_utf8(std::mem::take(&mut output.stdout))
+        .map_err(|_| anyhow::anyhow!("OS keychain secret is not valid UTF-8"))?;
+    let trimmed_len = value.trim_end_matches(['\r', '\n']).len();
+    value.truncate(trimmed_len);
+    Ok(SecretString::new(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_secret_string_debug_and_display_are_masked() {
+        let secret = SecretString::from("my-api-key");
+        assert_eq!(format!("{secret:?}"), "SecretString(***)");
+        assert_eq!(format!("{secret}"), "***");
+        assert_eq!(secret.expose(), "my-api-key");
+    }
+
+    #[rstest]
+    fn test_encrypt_decrypt_roundtrip() {
+        let key = generate_secrets_key().unwrap();
+        let encrypted = encrypt_secret(key.as_ref(), "my-api-secret").unwrap();
+
+        assert!(!encrypted.contains("my-api-secret"));
+        let decrypted = decrypt_secret(key.as_ref(), &encrypted).unwrap();
+        assert_eq!(decrypted.expose(), "my-api-secret");
+    }
+
+    #[rstest]
+    fn test_decrypt_with_wrong_key_fails() {
+        let key = generate_secrets_key().unwrap();
+        let other = generate_secrets_key().unwrap();
+        let encrypted = encrypt_secret(key.as_ref(), "my-api-secret").unwrap();
+
+        assert!(decrypt_secret(other.as_ref(), &encrypted).is_err());
+        assert!(decrypt_secret(&[0u8; 16], &encrypted).is_err());
+    }
+
+    #[rstest]
+    fn test_decrypt_secret_file() {
+        let key = generate_secrets_key().unwrap();
+        let path = std::env::temp_dir().join("nautilus_test_decrypt_secret_file.key");
+        std::fs::write(&path, encrypt_secret(key.as_ref(), "file-secret").unwrap()).unwrap();
+
+        let decrypted = decrypt_secret_file(&path, key.as_ref()).unwrap();
+        assert_eq!(decrypted.expose(), "file-secret");
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_age_secret_file_source() {
+        use age::secrecy::ExposeSecret;
+
+        let identity = age::x25519::Identity::generate();
+        let dir = std::env::temp_dir();
+        let identity_path = dir.join("nautilus_test_age_secret_identity.txt");
+        let secret_path = dir.join("nautilus_test_age_secret.age");
+        std::fs::write(&identity_path, identity.to_string().expose_secret()).unwrap();
+        std::fs::write(
+            &secret_path,
+            age::encrypt(&identity.to_public(), b"age-secret\n").unwrap(),
+        )
+        .unwrap();
+
+        let source = format!(
+            "age:{}?identity={}",
+            secret_path.display(),
+            identity_path.display()
+        );
+        let secret: Secret = source.parse().unwrap();
+        assert_eq!(secret.source().to_string(), source);
+        assert_eq!(secret.expose().unwrap(), "age-secret");
+
+        std::fs::remove_file(identity_path).unwrap();
+        std::fs::remove_file(secret_path).unwrap();
+    }
+
+    #[rstest]
+    fn test_age_secret_with_wrong_identity_fails() {
+        let identity = age::x25519::Identity::generate();
+        let other: Box<dyn age::Identity> = Box::new(age::x25519::Identity::generate());
+        let ciphertext = age::encrypt(&identity.to_public(), b"age-secret").unwrap();
+
+        assert!(decrypt_age_secret(&[other], &ciphertext).is_err());
+        assert!(decrypt_age_secret(&[], b"not an age file").is_err());
+    }
+
+    #[rstest]
+    fn test_secret_resolved_lazily() {
+        let secret = Secret::from_value("my-api-key");
+        assert!(!secret.is_resolved());
+        assert_eq!(secret.expose().unwrap(), "my-api-key");
+        assert!(secret.is_resolved());
+        assert!(!secret.clone().is_resolved());
+    }
+
+    #[rstest]
+    fn test_env_source_missing_var() {
+        let secret: Secret = "env:NAUTILUS_TEST_SECRET_MISSING".parse().unwrap();
+        assert!(secret.expose().is_err());
+        assert!(!secret.is_resolved());
+    }
+
+    #[rstest]
+    #[case("env:API_KEY", "env:API_KEY")]
+    #[case("file:/tmp/a.key?key_env=KEY", "file:/tmp/a.key?key_env=KEY")]
+    #[case(
+        "age:/tmp/a.age?identity=/tmp/id.txt",
+        "age:/tmp/a.age?identity=/tmp/id.txt"
+    )]
+    #[case("keychain:nautilus/binance", "keychain:nautilus/binance")]
+    fn test_secret_source_parse_display(#[case] input: &str, #[case] expected: &str) {
+        let source = SecretSource::from_str(input).unwrap();
+        assert_eq!(source.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("API_KEY")]
+    #[case("env:")]
+    #[case("file:/tmp/a.key")]
+    #[case("age:/tmp/a.age")]
+    #[case("keychain:nautilus")]
+    #[case("vault:path")]
+    fn test_secret_source_parse_invalid(#[case] input: &str) {
+        assert!(SecretSource::from_str(input).is_err());
+    }
+}
@@ -15,7 +15,7 @@
 
 use aws_lc_rs::{hmac, rand as lc_rand, rsa::KeyPair, signature as lc_signature};
 use base64::prelude::*;
-use ed25519_dalek::{Signature as Ed25519Signature, Signer, SigningKey};
+use ed25519_dalek::{Signature as Ed25519Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use hex;
 
 /// Generates an HMAC-SHA256 signature for the given data using the provided secret.
@@ -90,6 +90,66 @@ pub fn ed25519_signature(private_key: &[u8], data: &str) -> anyhow::Result<Strin
     Ok(BASE64_STANDARD.encode(signature.to_bytes()))
 }
 
+/// Computes the raw HMAC-SHA256 tag of `data` using the provided `key`.
+#[must_use]
+pub fn hmac_sha256_bytes(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+    hmac::sign(&key, data).as_ref().to_vec()
+}
+
+/// Verifies the raw HMAC-SHA256 `tag` of `data` using the provided `key` (in constant time).
+///
+/// # Errors
+///
+/// Returns an error if the tag does not authenticate `data`.
+pub fn hmac_sha256_verify(key: &[u8], data: &[u8], tag: &[u8]) -> anyhow::Result<()> {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key);
+    hmac::verify(&key, data, tag).map_err(|_| anyhow::anyhow!("Invalid HMAC-SHA256 signature"))
+}
+
+/// Signs `data` using Ed25519 with the provided private key seed, returning the raw signature.
+///
+/// # Errors
+///
+/// Returns an error if the provided private key seed is invalid.
+pub fn ed25519_sign_bytes(private_key: &[u8], data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let signing_key = ed25519_signing_key(private_key)?;
+    Ok(signing_key.sign(data).to_bytes().to_vec())
+}
+
+/// Returns the Ed25519 public key for the provided private key seed.
+///
+/// # Errors
+///
+/// Returns an error if the provided private key seed is invalid.
+pub fn ed25519_public_key(private_key: &[u8]) -> anyhow::Result<[u8; 32]> {
+    Ok(ed25519_signing_key(private_key)?.verifying_key().to_bytes())
+}
+
+/// Verifies the raw Ed25519 `signature` of `data` using the provided public key.
+///
+/// # Errors
+///
+/// Returns an error if the public key or signature is malformed, or verification fails.
+pub fn ed25519_verify(public_key: &[u8], data: &[u8], signature: &[u8]) -> anyhow::Result<()> {
+    let public_key: &[u8; 32] = public_key
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid Ed25519 public key length"))?;
+    let verifying_key = VerifyingKey::from_bytes(public_key)
+        .map_err(|e| anyhow::anyhow!("Invalid Ed25519 public key: {e}"))?;
+    let signature = Ed25519Signature::from_slice(signature)
+        .map_err(|e| anyhow::anyhow!("Invalid Ed25519 signature: {e}"))?;
+    verifying_key
+        .verify(data, &signature)
+        .map_err(|_| anyhow::anyhow!("Ed25519 signature verification failed"))
+}
+
+fn ed25519_signing_key(private_key: &[u8]) -> anyhow::Result<SigningKey> {
+    Ok(SigningKey::from_bytes(private_key.try_into().map_err(
+        |_| anyhow::anyhow!("Invalid Ed25519 private key length"),
+    )?))
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -183,4 +243,26 @@ INVALID_KEY_DATA
         );
         assert!(!result.unwrap().is_empty(), "Signature should not be empty");
     }
+
+    #[rstest]
+    fn test_hmac_sha256_bytes_verify() {
+        let tag = hmac_sha256_bytes(b"mysecretkey", b"data-to-sign");
+        assert_eq!(
+            hex::encode(&tag),
+            "19ed21a8b2a6b847d7d7aea059ab3134cd58f13c860cfbe89338c718685fe077"
+        );
+        assert!(hmac_sha256_verify(b"mysecretkey", b"data-to-sign", &tag).is_ok());
+        assert!(hmac_sha256_verify(b"otherkey", b"data-to-sign", &tag).is_err());
+    }
+
+    #[rstest]
+    fn test_ed25519_sign_verify_bytes() {
+        let private_key = valid_ed25519_private_key();
+        let public_key = ed25519_public_key(&private_key).unwrap();
+        let signature = ed25519_sign_bytes(&private_key, b"payload").unwrap();
+
+        assert!(ed25519_verify(&public_key, b"payload", &signature).is_ok());
+        assert!(ed25519_verify(&public_key, b"tampered", &signature).is_err());
+        assert!(ed25519_verify(&public_key[..16], b"payload", &signature).is_err());
+    }
 }
@@ -0,0 +1,29 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fuzzes order book delta application, checking ladder, cache, BBO and checksum invariants.
+//!
+//! Run with `cargo +nightly fuzz run orderbook_deltas` from `crates/model`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nautilus_model::orderbook::fuzz::fuzz_order_book;
+
+fuzz_target!(|data: &[u8]| {
+    if let Err(e) = fuzz_order_book(data) {
+        panic!("Order book invariant violated: {e}");
+    }
+});
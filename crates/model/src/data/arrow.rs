@@ -0,0 +1,438 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Columnar encoding of market data vectors into Arrow [`RecordBatch`]es.
+//!
+//! Batches are intended for analytics frontends (pyarrow, polars, pandas) rather than lossless
+//! persistence: prices and sizes are encoded as `Float64` columns and timestamps as UTC
+//! nanosecond timestamps. The instrument (or bar type) and precisions are stored in the schema
+//! metadata so the original fixed-point values can be reconstructed if required.
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow::{
+    array::{
+        ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray, TimestampNanosecondArray,
+        UInt8Array,
+    },
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    ipc::writer::StreamWriter,
+    record_batch::RecordBatch,
+};
+
+use super::{Bar, GreeksData, QuoteTick, TradeTick};
+
+/// Schema metadata key for the instrument ID of the batch rows.
+pub const KEY_INSTRUMENT_ID: &str = "instrument_id";
+/// Schema metadata key for the bar type of the batch rows.
+pub const KEY_BAR_TYPE: &str = "bar_type";
+/// Schema metadata key for the price precision of the batch rows.
+pub const KEY_PRICE_PRECISION: &str = "price_precision";
+/// Schema metadata key for the size precision of the batch rows.
+pub const KEY_SIZE_PRECISION: &str = "size_precision";
+
+const TZ_UTC: &str = "UTC";
+
+fn timestamp_field(name: &str) -> Field {
+    Field::new(
+        name,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some(TZ_UTC.into())),
+        false,
+    )
+}
+
+fn timestamp_array(values: Vec<i64>) -> ArrayRef {
+    Arc::new(TimestampNanosecondArray::from(values).with_timezone(TZ_UTC))
+}
+
+fn float_array(values: Vec<f64>) -> ArrayRef {
+    Arc::new(Float64Array::from(values))
+}
+
+fn metadata(
+    key: &str,
+    value: Option<String>,
+    precisions: Option<(u8, u8)>,
+) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    if let Some(value) = value {
+        metadata.insert(key.to_string(), value);
+    }
+    if let Some((price_precision, size_precision)) = precisions {
+        metadata.insert(KEY_PRICE_PRECISION.to_string(), price_precision.to_string());
+        metadata.insert(KEY_SIZE_PRECISION.to_string(), size_precision.to_string());
+    }
+    metadata
+}
+
+/// Returns the Arrow schema for encoded [`QuoteTick`]s.
+#[must_use]
+pub fn quote_schema(metadata: HashMap<String, String>) -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new("bid_price", DataType::Float64, false),
+            Field::new("ask_price", DataType::Float64, false),
+            Field::new("bid_size", DataType::Float64, false),
+            Field::new("ask_size", DataType::Float64, false),
+            timestamp_field("ts_event"),
+            timestamp_field("ts_init"),
+        ],
+        metadata,
+    )
+}
+
+/// Returns the Arrow schema for encoded [`TradeTick`]s.
+#[must_use]
+pub fn trade_schema(metadata: HashMap<String, String>) -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new("price", DataType::Float64, false),
+            Field::new("size", DataType::Float64, false),
+            Field::new("aggressor_side", DataType::UInt8, false),
+            Field::new("trade_id", DataType::Utf8, false),
+            timestamp_field("ts_event"),
+            timestamp_field("ts_init"),
+        ],
+        metadata,
+    )
+}
+
+/// Returns the Arrow schema for encoded [`Bar`]s.
+#[must_use]
+pub fn bar_schema(metadata: HashMap<String, String>) -> Schema {
+    Schema::new_with_metadata(
+        vec![
+            Field::new("open", DataType::Float64, false),
+            Field::new("high", DataType::Float64, false),
+            Field::new("low", DataType::Float64, false),
+            Field::new("close", DataType::Float64, false),
+            Field::new("volume", DataType::Float64, false),
+            timestamp_field("ts_event"),
+            timestamp_field("ts_init"),
+        ],
+        metadata,
+    )
+}
+
+/// Returns the Arrow schema for encoded [`GreeksData`].
+#[must_use]
+pub fn greeks_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("instrument_id", DataType::Utf8, false),
+        Field::new("is_call", DataType::Boolean, false),
+        Field::new("strike", DataType::Float64, false),
+        Field::new("expiry", DataType::Int32, false),
+        Field::new("expiry_in_years", DataType::Float64, false),
+        Field::new("multiplier", DataType::Float64, false),
+        Field::new("underlying_price", DataType::Float64, false),
+        Field::new("interest_rate", DataType::Float64, false),
+        Field::new("cost_of_carry", DataType::Float64, false),
+        Field::new("vol", DataType::Float64, false),
+        Field::new("price", DataType::Float64, false),
+        Field::new("delta", DataType::Float64, false),
+        Field::new("gamma", DataType::Float64, false),
+        Field::new("vega", DataType::Float64, false),
+        Field::new("theta", DataType::Float64, false),
+        Field::new("itm_prob", DataType::Float64, false),
+        timestamp_field("ts_event"),
+        timestamp_field("ts_init"),
+    ])
+}
+
+/// Encodes `quotes` into a single Arrow [`RecordBatch`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `quotes` contains more than one instrument ID.
+/// - The batch cannot be constructed from the encoded columns.
+pub fn quotes_to_record_batch(quotes: &[QuoteTick]) -> anyhow::Result<RecordBatch> {
+    let first = quotes.first();
+    if let Some(first) = first
+        && let Some(other) = quotes
+            .iter()
+            .find(|q| q.instrument_id != first.instrument_id)
+    {
+        anyhow::bail!(
+            "Cannot encode quotes for multiple instruments: {} and {}",
+            first.instrument_id,
+            other.instrument_id
+        );
+    }
+
+    let metadata = metadata(
+        KEY_INSTRUMENT_ID,
+        first.map(|q| q.instrument_id.to_string()),
+        first.map(|q| (q.bid_price.precision, q.bid_size.precision)),
+    );
+
+    let columns = vec![
+        float_array(quotes.iter().map(|q| q.bid_price.as_f64()).collect()),
+        float_array(quotes.iter().map(|q| q.ask_price.as_f64()).collect()),
+        float_array(quotes.iter().map(|q| q.bid_size.as_f64()).collect()),
+        float_array(quotes.iter().map(|q| q.ask_size.as_f64()).collect()),
+        timestamp_array(quotes.iter().map(|q| q.ts_event.as_i64()).collect()),
+        timestamp_array(quotes.iter().map(|q| q.ts_init.as_i64()).collect()),
+    ];
+
+    Ok(RecordBatch::try_new(
+        Arc::new(quote_schema(metadata)),
+        columns,
+    )?)
+}
+
+/// Encodes `trades` into a single Arrow [`RecordBatch`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `trades` contains more than one instrument ID.
+/// - The batch cannot be constructed from the encoded columns.
+pub fn trades_to_record_batch(trades: &[TradeTick]) -> anyhow::Result<RecordBatch> {
+    let first = trades.first();
+    if let Some(first) = first
+        && let Some(other) = trades
+            .iter()
+            .find(|t| t.instrument_id != first.instrument_id)
+    {
+        anyhow::bail!(
+            "Cannot encode trades for multiple instruments: {} and {}",
+            first.instrument_id,
+            other.instrument_id
+        );
+    }
+
+    let metadata = metadata(
+        KEY_INSTRUMENT_ID,
+        first.map(|t| t.instrument_id.to_string()),
+        first.map(|t| (t.price.precision, t.size.precision)),
+    );
+
+    let columns: Vec<ArrayRef> = vec![
+        float_array(trades.iter().map(|t| t.price.as_f64()).collect()),
+        float_array(trades.iter().map(|t| t.size.as_f64()).collect()),
+        Arc::new(UInt8Array::from_iter_values(
+            trades.iter().map(|t| t.aggressor_side as u8),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            trades.iter().map(|t| t.trade_id.as_str()),
+        )),
+        timestamp_array(trades.iter().map(|t| t.ts_event.as_i64()).collect()),
+        timestamp_array(trades.iter().map(|t| t.ts_init.as_i64()).collect()),
+    ];
+
+    Ok(RecordBatch::try_new(
+        Arc::new(trade_schema(metadata)),
+        columns,
+    )?)
+}
+
+/// Encodes `bars` into a single Arrow [`RecordBatch`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `bars` contains more than one bar type.
+/// - The batch cannot be constructed from the encoded columns.
+pub fn bars_to_record_batch(bars: &[Bar]) -> anyhow::Result<RecordBatch> {
+    let first = bars.first();
+    if let Some(first) = first
+        && let Some(other) = bars.iter().find(|b| b.bar_type != first.bar_type)
+    {
+        anyhow::bail!(
+            "Cannot encode bars for multiple bar types: {} and {}",
+            first.bar_type,
+            other.bar_type
+        );
+    }
+
+    let metadata = metadata(
+        KEY_BAR_TYPE,
+        first.map(|b| b.bar_type.to_string()),
+        first.map(|b| (b.open.precision, b.volume.precision)),
+    );
+
+    let columns = vec![
+        float_array(bars.iter().map(|b| b.open.as_f64()).collect()),
+        float_array(bars.iter().map(|b| b.high.as_f64()).collect()),
+        float_array(bars.iter().map(|b| b.low.as_f64()).collect()),
+        float_array(bars.iter().map(|b| b.close.as_f64()).collect()),
+        float_array(bars.iter().map(|b| b.volume.as_f64()).collect()),
+        timestamp_array(bars.iter().map(|b| b.ts_event.as_i64()).collect()),
+        timestamp_array(bars.iter().map(|b| b.ts_init.as_i64()).collect()),
+    ];
+
+    Ok(RecordBatch::try_new(
+        Arc::new(bar_schema(metadata)),
+        columns,
+    )?)
+}
+
+/// Encodes `greeks` into a single Arrow [`RecordBatch`].
+///
+/// Unlike market data, the rows may span multiple instruments (e.g. an option chain), so the
+/// instrument ID is stored as a column.
+///
+/// # Errors
+///
+/// Returns an error if the batch cannot be constructed from the encoded columns.
+pub fn greeks_to_record_batch(greeks: &[GreeksData]) -> anyhow::Result<RecordBatch> {
+    let column = |f: fn(&GreeksData) -> f64| float_array(greeks.iter().map(f).collect());
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            greeks.iter().map(|g| g.instrument_id.to_string()),
+        )),
+        Arc::new(BooleanArray::from_iter(
+            greeks.iter().map(|g| Some(g.is_call)),
+        )),
+        column(|g| g.strike),
+        Arc::new(Int32Array::from_iter_values(
+            greeks.iter().map(|g| g.expiry),
+        )),
+        column(|g| g.expiry_in_years),
+        column(|g| g.multiplier),
+        column(|g| g.underlying_price),
+        column(|g| g.interest_rate),
+        column(|g| g.cost_of_carry),
+        column(|g| g.vol),
+        column(|g| g.price),
+        column(|g| g.delta),
+        column(|g| g.gamma),
+        column(|g| g.vega),
+        column(|g| g.theta),
+        column(|g| g.itm_prob),
+        timestamp_array(greeks.iter().map(|g| g.ts_event.as_i64()).collect()),
+        timestamp_array(greeks.iter().map(|g| g.ts_init.as_i64()).collect()),
+    ];
+
+    Ok(RecordBatch::try_new(Arc::new(greeks_schema()), columns)?)
+}
+
+/// Writes `batch` as a complete Arrow IPC stream (schema, batch and end-of-stream marker).
+///
+/// The stream can be read by `pyarrow.ipc.open_stream` or `polars.read_ipc_stream`, e.g. after
+/// crossing a process boundary. Encoding copies every column into the buffer, so in-process
+/// handoff to pyarrow goes through the C Data Interface instead.
+///
+/// # Errors
+///
+/// Returns an error if writing the stream fails.
+pub fn record_batch_to_ipc_bytes(batch: &RecordBatch) -> anyhow::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let mut writer = StreamWriter::try_new(&mut buffer, batch.schema_ref())?;
+    writer.write(batch)?;
+    writer.finish()?;
+    drop(writer);
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{
+        array::{Array, AsArray},
+        datatypes::{Float64Type, TimestampNanosecondType, UInt8Type},
+        ipc::reader::StreamReader,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::stubs::{quote_audusd, quote_ethusdt_binance, stub_bar, stub_trade_ethusdt_buyer},
+        enums::AggressorSide,
+    };
+
+    #[rstest]
+    fn test_quotes_to_record_batch(quote_audusd: QuoteTick) {
+        let quotes = vec![quote_audusd, quote_audusd];
+        let batch = quotes_to_record_batch(&quotes).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), 6);
+        assert_eq!(
+            batch.schema().metadata()[KEY_INSTRUMENT_ID],
+            quote_audusd.instrument_id.to_string()
+        );
+        assert_eq!(
+            batch.schema().metadata()[KEY_PRICE_PRECISION],
+            quote_audusd.bid_price.precision.to_string()
+        );
+
+        let bids = batch.column(0).as_primitive::<Float64Type>();
+        assert_eq!(bids.value(0), quote_audusd.bid_price.as_f64());
+        let ts_init = batch.column(5).as_primitive::<TimestampNanosecondType>();
+        assert_eq!(ts_init.value(1), quote_audusd.ts_init.as_i64());
+    }
+
+    #[rstest]
+    fn test_quotes_to_record_batch_multiple_instruments_errors(
+        quote_audusd: QuoteTick,
+        quote_ethusdt_binance: QuoteTick,
+    ) {
+        let result = quotes_to_record_batch(&[quote_audusd, quote_ethusdt_binance]);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_quotes_to_record_batch_empty() {
+        let batch = quotes_to_record_batch(&[]).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert!(batch.schema().metadata().is_empty());
+    }
+
+    #[rstest]
+    fn test_trades_to_record_batch(stub_trade_ethusdt_buyer: TradeTick) {
+        let batch = trades_to_record_batch(&[stub_trade_ethusdt_buyer]).unwrap();
+
+        assert_eq!(batch.num_rows(), 1);
+        let sides = batch.column(2).as_primitive::<UInt8Type>();
+        assert_eq!(sides.value(0), AggressorSide::Buyer as u8);
+        let trade_ids = batch.column(3).as_string::<i32>();
+        assert_eq!(
+            trade_ids.value(0),
+            stub_trade_ethusdt_buyer.trade_id.as_str()
+        );
+    }
+
+    #[rstest]
+    fn test_bars_to_record_batch_ipc_roundtrip(stub_bar: Bar) {
+        let batch = bars_to_record_batch(&[stub_bar, stub_bar]).unwrap();
+        let bytes = record_batch_to_ipc_bytes(&batch).unwrap();
+
+        let reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+        let batches: Vec<RecordBatch> = reader.map(Result::unwrap).collect();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0], batch);
+        assert_eq!(
+            batches[0].schema().metadata()[KEY_BAR_TYPE],
+            stub_bar.bar_type.to_string()
+        );
+        assert_eq!(batches[0].column(4).len(), 2);
+    }
+
+    #[rstest]
+    fn test_greeks_to_record_batch_multiple_instruments() {
+        let first = GreeksData::from_delta("AAPL.XNAS".into(), 0.5, 1.0, 1.into());
+        let second = GreeksData::from_delta("MSFT.XNAS".into(), -0.25, 1.0, 2.into());
+        let batch = greeks_to_record_batch(&[first, second]).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), 18);
+        assert_eq!(batch.column(0).as_string::<i32>().value(1), "MSFT.XNAS");
+        let deltas = batch.column(11).as_primitive::<Float64Type>();
+        assert_eq!(deltas.value(1), -0.25);
+    }
+}
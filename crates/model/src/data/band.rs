@@ -0,0 +1,203 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Domain types representing exchange price bands (daily limits and dynamic circuit breakers).
+
+use std::fmt::Display;
+
+use nautilus_core::{
+    UnixNanos,
+    correctness::{FAILED, check_predicate_true},
+    serialization::Serializable,
+};
+use serde::{Deserialize, Serialize};
+
+use super::HasTsInit;
+use crate::{enums::OrderSide, identifiers::InstrumentId, types::Price};
+
+/// The kind of exchange price band.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PriceBandKind {
+    /// Daily limit-up/limit-down prices, fixed for the session.
+    DailyLimit,
+    /// A dynamic circuit breaker band around a reference price.
+    Dynamic,
+}
+
+/// Represents an update to the price band for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PriceBandUpdate {
+    /// The instrument ID for the band.
+    pub instrument_id: InstrumentId,
+    /// The kind of band.
+    pub kind: PriceBandKind,
+    /// The lowest price at which orders are accepted (limit down).
+    pub lower: Price,
+    /// The highest price at which orders are accepted (limit up).
+    pub upper: Price,
+    /// UNIX timestamp (nanoseconds) when the band update event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the instance was created.
+    pub ts_init: UnixNanos,
+}
+
+impl PriceBandUpdate {
+    /// Creates a new [`PriceBandUpdate`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` is greater than `upper`.
+    pub fn new_checked(
+        instrument_id: InstrumentId,
+        kind: PriceBandKind,
+        lower: Price,
+        upper: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(lower <= upper, "`lower` must not be greater than `upper`")?;
+
+        Ok(Self {
+            instrument_id,
+            kind,
+            lower,
+            upper,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`PriceBandUpdate`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lower` is greater than `upper`.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        kind: PriceBandKind,
+        lower: Price,
+        upper: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(instrument_id, kind, lower, upper, ts_event, ts_init).expect(FAILED)
+    }
+
+    /// Returns whether `price` is within the band (inclusive).
+    #[must_use]
+    pub fn contains(&self, price: Price) -> bool {
+        price >= self.lower && price <= self.upper
+    }
+
+    /// Returns `price` clamped into the band.
+    #[must_use]
+    pub fn clamp(&self, price: Price) -> Price {
+        if price < self.lower {
+            self.lower
+        } else if price > self.upper {
+            self.upper
+        } else {
+            price
+        }
+    }
+
+    /// Returns the band limit an order on `side` at `price` breaches, if any.
+    ///
+    /// Buys are only limited above and sells only below, as an order priced through the
+    /// opposite limit can never trade there.
+    #[must_use]
+    pub fn breached_limit(&self, side: OrderSide, price: Price) -> Option<Price> {
+        match side {
+            OrderSide::Buy if price > self.upper => Some(self.upper),
+            OrderSide::Sell if price < self.lower => Some(self.lower),
+            OrderSide::NoOrderSide if !self.contains(price) => Some(self.clamp(price)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PriceBandUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{:?},{},{},{},{}",
+            self.instrument_id, self.kind, self.lower, self.upper, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for PriceBandUpdate {}
+
+impl HasTsInit for PriceBandUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn band() -> PriceBandUpdate {
+        PriceBandUpdate::new(
+            InstrumentId::from("ESZ24.XCME"),
+            PriceBandKind::DailyLimit,
+            Price::from("4900.00"),
+            Price::from("5100.00"),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "5100.00", None)]
+    #[case(OrderSide::Buy, "5100.25", Some("5100.00"))]
+    #[case(OrderSide::Buy, "4800.00", None)]
+    #[case(OrderSide::Sell, "4899.75", Some("4900.00"))]
+    #[case(OrderSide::Sell, "5200.00", None)]
+    fn test_breached_limit(
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            band().breached_limit(side, Price::from(price)),
+            expected.map(Price::from)
+        );
+    }
+
+    #[rstest]
+    fn test_clamp_and_contains() {
+        let band = band();
+        assert!(band.contains(Price::from("4900.00")));
+        assert_eq!(band.clamp(Price::from("5300.00")), Price::from("5100.00"));
+    }
+
+    #[rstest]
+    fn test_new_checked_rejects_inverted_band() {
+        let result = PriceBandUpdate::new_checked(
+            InstrumentId::from("ESZ24.XCME"),
+            PriceBandKind::Dynamic,
+            Price::from("5100.00"),
+            Price::from("4900.00"),
+            0.into(),
+            0.into(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -421,6 +421,17 @@ impl BarSpecification {
             BarAggregation::TickRuns | BarAggregation::VolumeRuns | BarAggregation::ValueRuns
         )
     }
+
+    /// Return a value indicating whether the aggregation method is price-movement-driven, with
+    /// the step as a number of price increments:
+    ///  - [`BarAggregation::Renko`]
+    ///  - [`BarAggregation::Range`]
+    pub fn is_price_aggregated(&self) -> bool {
+        matches!(
+            self.aggregation,
+            BarAggregation::Renko | BarAggregation::Range
+        )
+    }
 }
 
 impl Display for BarSpecification {
@@ -499,6 +510,12 @@ impl BarType {
         }
     }
 
+    /// Returns a new [`BarTypeBuilder`].
+    #[must_use]
+    pub fn builder() -> BarTypeBuilder {
+        BarTypeBuilder::default()
+    }
+
     /// Returns whether this instance is a standard bar type.
     pub fn is_standard(&self) -> bool {
         match &self {
@@ -589,12 +606,116 @@ impl BarType {
     }
 }
 
-#[derive(thiserror::Error, Debug)]
+/// The component of a bar type string which failed to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum::Display, strum::AsRefStr)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum BarTypeParseErrorKind {
+    /// The input does not have the `{instrument_id}-{step}-{aggregation}-{price_type}-{source}`
+    /// structure (with an optional `@{step}-{aggregation}-{source}` composite suffix).
+    Format,
+    /// The instrument ID is invalid.
+    Instrument,
+    /// The step is not a positive integer.
+    Step,
+    /// The aggregation is not a valid [`BarAggregation`].
+    Aggregation,
+    /// The price type is not a valid [`PriceType`].
+    PriceType,
+    /// The aggregation source is not a valid [`AggregationSource`].
+    AggregationSource,
+    /// The composite step is not a positive integer.
+    CompositeStep,
+    /// The composite aggregation is not a valid [`BarAggregation`].
+    CompositeAggregation,
+    /// The composite aggregation source is not a valid [`AggregationSource`].
+    CompositeAggregationSource,
+}
+
+impl BarTypeParseErrorKind {
+    /// Returns the index of the token for this kind (0-4 standard, 5-7 composite).
+    #[must_use]
+    pub const fn token_index(&self) -> usize {
+        match self {
+            Self::Format | Self::Instrument => 0,
+            Self::Step => 1,
+            Self::Aggregation => 2,
+            Self::PriceType => 3,
+            Self::AggregationSource => 4,
+            Self::CompositeStep => 5,
+            Self::CompositeAggregation => 6,
+            Self::CompositeAggregationSource => 7,
+        }
+    }
+}
+
+/// The error returned when parsing a [`BarType`] from a string fails.
+///
+/// The `position` is the index of the offending token (0-4 for the standard components and 5-7
+/// for the composite components), while the `offset` is its byte offset within the input.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
 #[error("Error parsing `BarType` from '{input}', invalid token: '{token}' at position {position}")]
 pub struct BarTypeParseError {
+    kind: BarTypeParseErrorKind,
     input: String,
     token: String,
     position: usize,
+    offset: usize,
+}
+
+impl BarTypeParseError {
+    fn new(kind: BarTypeParseErrorKind, input: &str, token: &str) -> Self {
+        Self::with_position(kind, input, token, kind.token_index())
+    }
+
+    fn with_position(
+        kind: BarTypeParseErrorKind,
+        input: &str,
+        token: &str,
+        position: usize,
+    ) -> Self {
+        // Tokens are always sub-slices of the input, empty tokens are reported at the end
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        Self {
+            kind,
+            input: input.to_string(),
+            token: token.to_string(),
+            position,
+            offset,
+        }
+    }
+
+    /// Returns the component which failed to parse.
+    #[must_use]
+    pub const fn kind(&self) -> BarTypeParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the full input which failed to parse.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the offending token (empty if the input was structurally invalid).
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the index of the offending token.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the byte offset of the offending token within the input.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
 }
 
 impl FromStr for BarType {
@@ -602,102 +723,212 @@ impl FromStr for BarType {
 
     #[allow(clippy::needless_collect)] // Collect needed for .rev() and indexing
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BarTypeParseErrorKind as Kind;
+
         let parts: Vec<&str> = s.split('@').collect();
+        if parts.len() > 2 {
+            return Err(BarTypeParseError::with_position(
+                Kind::Format,
+                s,
+                parts[2],
+                5,
+            ));
+        }
         let standard = parts[0];
         let composite_str = parts.get(1);
 
         let pieces: Vec<&str> = standard.rsplitn(5, '-').collect();
         let rev_pieces: Vec<&str> = pieces.into_iter().rev().collect();
         if rev_pieces.len() != 5 {
-            return Err(BarTypeParseError {
-                input: s.to_string(),
-                token: String::new(),
-                position: 0,
-            });
+            return Err(BarTypeParseError::with_position(
+                Kind::Format,
+                s,
+                &s[s.len()..],
+                0,
+            ));
         }
 
-        let instrument_id =
-            InstrumentId::from_str(rev_pieces[0]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[0].to_string(),
-                position: 0,
-            })?;
-
-        let step = rev_pieces[1].parse().map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[1].to_string(),
-            position: 1,
-        })?;
-        let aggregation =
-            BarAggregation::from_str(rev_pieces[2]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[2].to_string(),
-                position: 2,
-            })?;
-        let price_type = PriceType::from_str(rev_pieces[3]).map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[3].to_string(),
-            position: 3,
-        })?;
-        let aggregation_source =
-            AggregationSource::from_str(rev_pieces[4]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[4].to_string(),
-                position: 4,
-            })?;
+        let instrument_id = InstrumentId::from_str(rev_pieces[0])
+            .map_err(|_| BarTypeParseError::new(Kind::Instrument, s, rev_pieces[0]))?;
+        let step = rev_pieces[1]
+            .parse()
+            .map_err(|_| BarTypeParseError::new(Kind::Step, s, rev_pieces[1]))?;
+        let aggregation = BarAggregation::from_str(rev_pieces[2])
+            .map_err(|_| BarTypeParseError::new(Kind::Aggregation, s, rev_pieces[2]))?;
+        let price_type = PriceType::from_str(rev_pieces[3])
+            .map_err(|_| BarTypeParseError::new(Kind::PriceType, s, rev_pieces[3]))?;
+        let aggregation_source = AggregationSource::from_str(rev_pieces[4])
+            .map_err(|_| BarTypeParseError::new(Kind::AggregationSource, s, rev_pieces[4]))?;
+
+        let spec = BarSpecification {
+            step,
+            aggregation,
+            price_type,
+        };
 
         if let Some(composite_str) = composite_str {
             let composite_pieces: Vec<&str> = composite_str.rsplitn(3, '-').collect();
             let rev_composite_pieces: Vec<&str> = composite_pieces.into_iter().rev().collect();
             if rev_composite_pieces.len() != 3 {
-                return Err(BarTypeParseError {
-                    input: s.to_string(),
-                    token: String::new(),
-                    position: 5,
-                });
+                return Err(BarTypeParseError::with_position(
+                    Kind::Format,
+                    s,
+                    &s[s.len()..],
+                    5,
+                ));
             }
 
-            let composite_step =
-                rev_composite_pieces[0]
-                    .parse()
-                    .map_err(|_| BarTypeParseError {
-                        input: s.to_string(),
-                        token: rev_composite_pieces[0].to_string(),
-                        position: 5,
-                    })?;
+            let composite_step = rev_composite_pieces[0]
+                .parse::<NonZeroUsize>()
+                .map_err(|_| {
+                    BarTypeParseError::new(Kind::CompositeStep, s, rev_composite_pieces[0])
+                })?;
             let composite_aggregation =
                 BarAggregation::from_str(rev_composite_pieces[1]).map_err(|_| {
-                    BarTypeParseError {
-                        input: s.to_string(),
-                        token: rev_composite_pieces[1].to_string(),
-                        position: 6,
-                    }
+                    BarTypeParseError::new(Kind::CompositeAggregation, s, rev_composite_pieces[1])
                 })?;
             let composite_aggregation_source = AggregationSource::from_str(rev_composite_pieces[2])
-                .map_err(|_| BarTypeParseError {
-                    input: s.to_string(),
-                    token: rev_composite_pieces[2].to_string(),
-                    position: 7,
+                .map_err(|_| {
+                    BarTypeParseError::new(
+                        Kind::CompositeAggregationSource,
+                        s,
+                        rev_composite_pieces[2],
+                    )
                 })?;
 
             Ok(Self::new_composite(
                 instrument_id,
-                BarSpecification::new(step, aggregation, price_type),
+                spec,
                 aggregation_source,
-                composite_step,
+                composite_step.get(),
                 composite_aggregation,
                 composite_aggregation_source,
             ))
         } else {
             Ok(Self::Standard {
                 instrument_id,
-                spec: BarSpecification::new(step, aggregation, price_type),
+                spec,
                 aggregation_source,
             })
         }
     }
 }
 
+/// Builder for [`BarType`] instances, validating that the result round-trips through its
+/// string representation.
+#[derive(Clone, Debug, Default)]
+pub struct BarTypeBuilder {
+    instrument_id: Option<InstrumentId>,
+    step: Option<usize>,
+    aggregation: Option<BarAggregation>,
+    price_type: Option<PriceType>,
+    aggregation_source: Option<AggregationSource>,
+    composite: Option<(usize, BarAggregation, AggregationSource)>,
+}
+
+impl BarTypeBuilder {
+    /// Sets the instrument ID.
+    pub fn instrument_id(&mut self, instrument_id: InstrumentId) -> &mut Self {
+        self.instrument_id = Some(instrument_id);
+        self
+    }
+
+    /// Sets the bar specification step.
+    pub fn step(&mut self, step: usize) -> &mut Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the bar specification aggregation.
+    pub fn aggregation(&mut self, aggregation: BarAggregation) -> &mut Self {
+        self.aggregation = Some(aggregation);
+        self
+    }
+
+    /// Sets the bar specification price type.
+    pub fn price_type(&mut self, price_type: PriceType) -> &mut Self {
+        self.price_type = Some(price_type);
+        self
+    }
+
+    /// Sets the step, aggregation and price type from an existing specification.
+    pub fn spec(&mut self, spec: BarSpecification) -> &mut Self {
+        self.step = Some(spec.step.get());
+        self.aggregation = Some(spec.aggregation);
+        self.price_type = Some(spec.price_type);
+        self
+    }
+
+    /// Sets the aggregation source (defaults to [`AggregationSource::External`]).
+    pub fn aggregation_source(&mut self, aggregation_source: AggregationSource) -> &mut Self {
+        self.aggregation_source = Some(aggregation_source);
+        self
+    }
+
+    /// Makes the bar type composite, aggregated from bars of the given step, aggregation and
+    /// source.
+    pub fn composite(
+        &mut self,
+        step: usize,
+        aggregation: BarAggregation,
+        aggregation_source: AggregationSource,
+    ) -> &mut Self {
+        self.composite = Some((step, aggregation, aggregation_source));
+        self
+    }
+
+    /// Builds the [`BarType`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The instrument ID, step, aggregation or price type was not set.
+    /// - A step is zero.
+    /// - The resulting bar type does not round-trip through its string representation (for
+    ///   example an instrument ID containing `@`).
+    pub fn build(&self) -> anyhow::Result<BarType> {
+        let Some(instrument_id) = self.instrument_id else {
+            anyhow::bail!("`instrument_id` must be set");
+        };
+        let Some(step) = self.step else {
+            anyhow::bail!("`step` must be set");
+        };
+        let Some(aggregation) = self.aggregation else {
+            anyhow::bail!("`aggregation` must be set");
+        };
+        let Some(price_type) = self.price_type else {
+            anyhow::bail!("`price_type` must be set");
+        };
+        let spec = BarSpecification::new_checked(step, aggregation, price_type)?;
+        let aggregation_source = self
+            .aggregation_source
+            .unwrap_or(AggregationSource::External);
+
+        let bar_type = match self.composite {
+            Some((composite_step, composite_aggregation, composite_aggregation_source)) => {
+                if composite_step == 0 {
+                    anyhow::bail!("Invalid composite step: 0 (must be non-zero)");
+                }
+                BarType::new_composite(
+                    instrument_id,
+                    spec,
+                    aggregation_source,
+                    composite_step,
+                    composite_aggregation,
+                    composite_aggregation_source,
+                )
+            }
+            None => BarType::new(instrument_id, spec, aggregation_source),
+        };
+
+        let parsed = BarType::from_str(&bar_type.to_string())?;
+        if parsed != bar_type {
+            anyhow::bail!("`BarType` '{bar_type}' does not round-trip through its string form");
+        }
+        Ok(bar_type)
+    }
+}
+
 impl<T: AsRef<str>> From<T> for BarType {
     fn from(value: T) -> Self {
         Self::from_str(value.as_ref()).expect(FAILED)
@@ -1282,6 +1513,140 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(
+        "BTCUSDT-PERP-1-MINUTE-LAST-INTERNAL",
+        BarTypeParseErrorKind::Instrument,
+        0,
+        0
+    )]
+    #[case(
+        "AUD/USD.SIM-0-MINUTE-LAST-EXTERNAL",
+        BarTypeParseErrorKind::Step,
+        1,
+        12
+    )]
+    #[case(
+        "AUD/USD.SIM-1-MINUTES-LAST-EXTERNAL",
+        BarTypeParseErrorKind::Aggregation,
+        2,
+        14
+    )]
+    #[case(
+        "AUD/USD.SIM-1-MINUTE-CLOSE-EXTERNAL",
+        BarTypeParseErrorKind::PriceType,
+        3,
+        21
+    )]
+    #[case(
+        "AUD/USD.SIM-1-MINUTE-LAST-VENUE",
+        BarTypeParseErrorKind::AggregationSource,
+        4,
+        26
+    )]
+    #[case(
+        "AUD/USD.SIM-5-MINUTE-LAST-INTERNAL@0-MINUTE-EXTERNAL",
+        BarTypeParseErrorKind::CompositeStep,
+        5,
+        35
+    )]
+    #[case("AUD/USD.SIM-MINUTE-LAST", BarTypeParseErrorKind::Format, 0, 23)]
+    #[case(
+        "AUD/USD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE",
+        BarTypeParseErrorKind::Format,
+        5,
+        43
+    )]
+    #[case(
+        "AUD/USD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL@X",
+        BarTypeParseErrorKind::Format,
+        5,
+        53
+    )]
+    fn test_bar_type_parse_error_details(
+        #[case] input: &str,
+        #[case] kind: BarTypeParseErrorKind,
+        #[case] position: usize,
+        #[case] offset: usize,
+    ) {
+        let error = BarType::from_str(input).unwrap_err();
+
+        assert_eq!(error.kind(), kind);
+        assert_eq!(error.input(), input);
+        assert_eq!(error.position(), position);
+        assert_eq!(error.offset(), offset);
+        assert!(input[error.offset()..].starts_with(error.token()));
+    }
+
+    #[rstest]
+    #[case("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL")]
+    #[case("BTCUSDT-PERP.BINANCE-100-TICK-MID-INTERNAL")]
+    #[case("ESM4.XCME-1000-VALUE_IMBALANCE-ASK-EXTERNAL")]
+    #[case("BTCUSDT-PERP.BINANCE-2-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL")]
+    fn test_bar_type_display_round_trip(#[case] input: &str) {
+        let bar_type = BarType::from_str(input).unwrap();
+
+        assert_eq!(bar_type.to_string(), input);
+        assert_eq!(BarType::from_str(&bar_type.to_string()).unwrap(), bar_type);
+    }
+
+    #[rstest]
+    fn test_bar_type_builder() {
+        let bar_type = BarType::builder()
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .step(5)
+            .aggregation(BarAggregation::Minute)
+            .price_type(PriceType::Bid)
+            .aggregation_source(AggregationSource::Internal)
+            .composite(1, BarAggregation::Minute, AggregationSource::External)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            bar_type,
+            BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL")
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_builder_defaults_to_external_source() {
+        let bar_type = BarType::builder()
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .spec(BarSpecification::new(
+                1,
+                BarAggregation::Hour,
+                PriceType::Last,
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(bar_type.aggregation_source(), AggregationSource::External);
+    }
+
+    #[rstest]
+    fn test_bar_type_builder_validation() {
+        assert!(BarType::builder().build().is_err());
+        assert!(
+            BarType::builder()
+                .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+                .step(0)
+                .aggregation(BarAggregation::Minute)
+                .price_type(PriceType::Last)
+                .build()
+                .is_err()
+        );
+        assert!(
+            BarType::builder()
+                .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+                .step(1)
+                .aggregation(BarAggregation::Minute)
+                .price_type(PriceType::Last)
+                .composite(0, BarAggregation::Minute, AggregationSource::External)
+                .build()
+                .is_err()
+        );
+    }
+
     #[rstest]
     fn test_bar_type_equality() {
         let instrument_id1 = InstrumentId {
@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Economic calendar events (scheduled macroeconomic and news releases).
+
+use std::fmt::Display;
+
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumString};
+use ustr::Ustr;
+
+use super::HasTsInit;
+
+/// The expected market impact of an economic event.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventImpact {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+/// Represents a scheduled economic calendar event and (once released) its actual value.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EconomicEvent {
+    /// The unique identifier of the event (from the calendar provider).
+    pub event_id: Ustr,
+    /// The event name, e.g. "Non-Farm Payrolls".
+    pub name: Ustr,
+    /// The ISO 3166 country code (or region, e.g. "EU") of the event.
+    pub country: Ustr,
+    /// The expected market impact.
+    pub impact: EventImpact,
+    /// The released value (`None` before release).
+    pub actual: Option<f64>,
+    /// The consensus forecast value.
+    pub forecast: Option<f64>,
+    /// The previously released value.
+    pub previous: Option<f64>,
+    /// UNIX timestamp (nanoseconds) of the scheduled release.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the event was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl EconomicEvent {
+    /// Creates a new [`EconomicEvent`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        event_id: Ustr,
+        name: Ustr,
+        country: Ustr,
+        impact: EventImpact,
+        actual: Option<f64>,
+        forecast: Option<f64>,
+        previous: Option<f64>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            event_id,
+            name,
+            country,
+            impact,
+            actual,
+            forecast,
+            previous,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns whether the actual value has been released.
+    #[must_use]
+    pub const fn is_released(&self) -> bool {
+        self.actual.is_some()
+    }
+
+    /// Returns the surprise (actual minus forecast) once both values are known.
+    #[must_use]
+    pub fn surprise(&self) -> Option<f64> {
+        Some(self.actual? - self.forecast?)
+    }
+}
+
+impl Display for EconomicEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{:?},{:?},{:?},{}",
+            self.event_id,
+            self.name,
+            self.country,
+            self.impact,
+            self.actual,
+            self.forecast,
+            self.previous,
+            self.ts_event,
+        )
+    }
+}
+
+impl HasTsInit for EconomicEvent {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// A filter selecting economic events by country and minimum impact.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EconomicEventFilter {
+    /// The countries to receive events for (all countries when empty).
+    pub countries: Vec<Ustr>,
+    /// The minimum impact to receive events for.
+    pub min_impact: EventImpact,
+}
+
+impl EconomicEventFilter {
+    /// Creates a new [`EconomicEventFilter`] instance.
+    #[must_use]
+    pub const fn new(countries: Vec<Ustr>, min_impact: EventImpact) -> Self {
+        Self {
+            countries,
+            min_impact,
+        }
+    }
+
+    /// Returns whether the `event` passes the filter.
+    #[must_use]
+    pub fn matches(&self, event: &EconomicEvent) -> bool {
+        event.impact >= self.min_impact
+            && (self.countries.is_empty() || self.countries.contains(&event.country))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn event(country: &str, impact: EventImpact) -> EconomicEvent {
+        EconomicEvent::new(
+            Ustr::from("NFP-2024-12"),
+            Ustr::from("Non-Farm Payrolls"),
+            Ustr::from(country),
+            impact,
+            Some(227.0),
+            Some(200.0),
+            Some(36.0),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+    }
+
+    #[rstest]
+    fn test_surprise() {
+        let mut event = event("US", EventImpact::High);
+        assert!(event.is_released());
+        assert_eq!(event.surprise(), Some(27.0));
+
+        event.actual = None;
+        assert!(event.surprise().is_none());
+    }
+
+    #[rstest]
+    fn test_impact_parse_and_order() {
+        assert_eq!(EventImpact::from_str("high").unwrap(), EventImpact::High);
+        assert!(EventImpact::High > EventImpact::Medium);
+        assert!(EventImpact::Medium > EventImpact::Low);
+    }
+
+    #[rstest]
+    #[case(vec![], EventImpact::Low, "US", EventImpact::Low, true)]
+    #[case(vec!["US"], EventImpact::High, "US", EventImpact::High, true)]
+    #[case(vec!["US"], EventImpact::High, "US", EventImpact::Medium, false)]
+    #[case(vec!["US", "EU"], EventImpact::Low, "JP", EventImpact::High, false)]
+    fn test_filter_matches(
+        #[case] countries: Vec<&str>,
+        #[case] min_impact: EventImpact,
+        #[case] country: &str,
+        #[case] impact: EventImpact,
+        #[case] expected: bool,
+    ) {
+        let filter =
+            EconomicEventFilter::new(countries.into_iter().map(Ustr::from).collect(), min_impact);
+        assert_eq!(filter.matches(&event(country, impact)), expected);
+    }
+}
@@ -0,0 +1,392 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trade sign inference and rolling order-flow imbalance derived from trades and quotes.
+//!
+//! Trades are signed using the quote rule (trades above the prevailing mid are buyer initiated,
+//! below are seller initiated), falling back to the tick rule when no quote is available or the
+//! trade prints at the mid. Quote-driven order-flow imbalance follows Cont, Kukanov and Stoikov
+//! (2014), measuring net changes in the best bid and ask sizes.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_core::{UnixNanos, correctness::FAILED};
+use serde::{Deserialize, Serialize};
+
+use super::{HasTsInit, QuoteTick, TradeTick};
+use crate::{enums::AggressorSide, identifiers::InstrumentId, types::Price};
+
+/// Rolling order-flow imbalance metrics for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderFlowImbalance {
+    /// The instrument ID for the metrics.
+    pub instrument_id: InstrumentId,
+    /// The rolling window (nanoseconds).
+    pub window_ns: u64,
+    /// The volume of buyer initiated trades within the window.
+    pub buy_volume: f64,
+    /// The volume of seller initiated trades within the window.
+    pub sell_volume: f64,
+    /// The number of trades within the window.
+    pub trade_count: u64,
+    /// The quote-driven order-flow imbalance within the window.
+    pub quote_ofi: f64,
+    /// UNIX timestamp (nanoseconds) of the last update included.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the metrics were initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OrderFlowImbalance {
+    /// Returns the signed trade volume (buy volume less sell volume).
+    #[must_use]
+    pub fn signed_volume(&self) -> f64 {
+        self.buy_volume - self.sell_volume
+    }
+
+    /// Returns the normalized trade imbalance in [-1, 1] (zero with no volume).
+    #[must_use]
+    pub fn trade_imbalance(&self) -> f64 {
+        let total = self.buy_volume + self.sell_volume;
+        if total == 0.0 {
+            0.0
+        } else {
+            self.signed_volume() / total
+        }
+    }
+}
+
+impl Display for OrderFlowImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.instrument_id,
+            self.buy_volume,
+            self.sell_volume,
+            self.trade_count,
+            self.quote_ofi,
+            self.ts_event,
+        )
+    }
+}
+
+impl HasTsInit for OrderFlowImbalance {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Infers the initiating side of trades using the quote rule, falling back to the tick rule.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TradeSignClassifier {
+    last_quote: Option<QuoteTick>,
+    last_price: Option<Price>,
+    last_side: Option<AggressorSide>,
+}
+
+impl TradeSignClassifier {
+    /// Creates a new [`TradeSignClassifier`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the prevailing quote used by the quote rule.
+    pub fn update_quote(&mut self, quote: &QuoteTick) {
+        self.last_quote = Some(*quote);
+    }
+
+    /// Returns the inferred initiating side of `trade`.
+    ///
+    /// Returns [`AggressorSide::NoAggressor`] when neither rule can classify the trade (the first
+    /// trade at the mid or without a quote).
+    pub fn classify(&mut self, trade: &TradeTick) -> AggressorSide {
+        let side = self
+            .quote_rule(trade)
+            .or_else(|| self.tick_rule(trade.price))
+            .unwrap_or(AggressorSide::NoAggressor);
+
+        self.last_price = Some(trade.price);
+        if side != AggressorSide::NoAggressor {
+            self.last_side = Some(side);
+        }
+        side
+    }
+
+    fn quote_rule(&self, trade: &TradeTick) -> Option<AggressorSide> {
+        let quote = self.last_quote?;
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        let price = trade.price.as_f64();
+        if price > mid {
+            Some(AggressorSide::Buyer)
+        } else if price < mid {
+            Some(AggressorSide::Seller)
+        } else {
+            None
+        }
+    }
+
+    fn tick_rule(&self, price: Price) -> Option<AggressorSide> {
+        let last_price = self.last_price?;
+        if price > last_price {
+            Some(AggressorSide::Buyer)
+        } else if price < last_price {
+            Some(AggressorSide::Seller)
+        } else {
+            self.last_side
+        }
+    }
+}
+
+/// Computes rolling order-flow imbalance metrics for an instrument from trades and quotes.
+#[derive(Clone, Debug)]
+pub struct OrderFlowImbalanceCalculator {
+    instrument_id: InstrumentId,
+    window_ns: u64,
+    classifier: TradeSignClassifier,
+    prev_quote: Option<QuoteTick>,
+    trades: VecDeque<(UnixNanos, f64)>,
+    quote_flows: VecDeque<(UnixNanos, f64)>,
+    ts_last: UnixNanos,
+}
+
+impl OrderFlowImbalanceCalculator {
+    /// Creates a new [`OrderFlowImbalanceCalculator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_ns` is zero.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, window_ns: u64) -> Self {
+        assert!(window_ns > 0, "{FAILED}: `window_ns` must be positive");
+        Self {
+            instrument_id,
+            window_ns,
+            classifier: TradeSignClassifier::new(),
+            prev_quote: None,
+            trades: VecDeque::new(),
+            quote_flows: VecDeque::new(),
+            ts_last: UnixNanos::default(),
+        }
+    }
+
+    /// Returns the instrument ID for the calculator.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Handles a quote, updating the quote-driven imbalance and trade classifier.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) {
+        if let Some(prev) = self.prev_quote {
+            let bid_flow = if quote.bid_price >= prev.bid_price {
+                quote.bid_size.as_f64()
+            } else {
+                0.0
+            } - if quote.bid_price <= prev.bid_price {
+                prev.bid_size.as_f64()
+            } else {
+                0.0
+            };
+            let ask_flow = if quote.ask_price <= prev.ask_price {
+                quote.ask_size.as_f64()
+            } else {
+                0.0
+            } - if quote.ask_price >= prev.ask_price {
+                prev.ask_size.as_f64()
+            } else {
+                0.0
+            };
+            self.quote_flows
+                .push_back((quote.ts_event, bid_flow - ask_flow));
+        }
+
+        self.classifier.update_quote(quote);
+        self.prev_quote = Some(*quote);
+        self.advance(quote.ts_event);
+    }
+
+    /// Handles a trade, returning its inferred initiating side.
+    ///
+    /// The venue reported aggressor side is used when available, otherwise it is inferred.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> AggressorSide {
+        let inferred = self.classifier.classify(trade);
+        let side = match trade.aggressor_side {
+            AggressorSide::NoAggressor => inferred,
+            reported => reported,
+        };
+
+        let size = trade.size.as_f64();
+        match side {
+            AggressorSide::Buyer => self.trades.push_back((trade.ts_event, size)),
+            AggressorSide::Seller => self.trades.push_back((trade.ts_event, -size)),
+            AggressorSide::NoAggressor => self.trades.push_back((trade.ts_event, 0.0)),
+        }
+
+        self.advance(trade.ts_event);
+        side
+    }
+
+    /// Returns the current metrics over the rolling window.
+    #[must_use]
+    pub fn snapshot(&self, ts_init: UnixNanos) -> OrderFlowImbalance {
+        let (buy_volume, sell_volume) =
+            self.trades
+                .iter()
+                .fold((0.0, 0.0), |(buy, sell), (_, signed)| {
+                    if *signed >= 0.0 {
+                        (buy + signed, sell)
+                    } else {
+                        (buy, sell - signed)
+                    }
+                });
+
+        OrderFlowImbalance {
+            instrument_id: self.instrument_id,
+            window_ns: self.window_ns,
+            buy_volume,
+            sell_volume,
+            trade_count: self.trades.len() as u64,
+            quote_ofi: self.quote_flows.iter().map(|(_, flow)| flow).sum(),
+            ts_event: self.ts_last,
+            ts_init,
+        }
+    }
+
+    /// Resets the calculator to its initial state.
+    pub fn reset(&mut self) {
+        self.classifier = TradeSignClassifier::new();
+        self.prev_quote = None;
+        self.trades.clear();
+        self.quote_flows.clear();
+        self.ts_last = UnixNanos::default();
+    }
+
+    fn advance(&mut self, ts: UnixNanos) {
+        self.ts_last = self.ts_last.max(ts);
+        let ts_last = self.ts_last.as_u64();
+        let window_ns = self.window_ns;
+        let expired = |(ts, _): &(UnixNanos, f64)| ts_last.saturating_sub(ts.as_u64()) >= window_ns;
+
+        while self.trades.front().is_some_and(expired) {
+            self.trades.pop_front();
+        }
+        while self.quote_flows.front().is_some_and(expired) {
+            self.quote_flows.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{identifiers::TradeId, types::Quantity};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT.BINANCE")
+    }
+
+    fn quote(bid: &str, ask: &str, bid_size: &str, ask_size: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn trade(price: &str, size: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            instrument_id(),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::NoAggressor,
+            TradeId::from("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_quote_rule_then_tick_rule() {
+        let mut classifier = TradeSignClassifier::new();
+
+        // Tick rule cannot classify the first trade without a quote
+        assert_eq!(
+            classifier.classify(&trade("100.0", "1", 1)),
+            AggressorSide::NoAggressor
+        );
+        assert_eq!(
+            classifier.classify(&trade("100.5", "1", 2)),
+            AggressorSide::Buyer
+        );
+        assert_eq!(
+            classifier.classify(&trade("100.5", "1", 3)),
+            AggressorSide::Buyer
+        );
+
+        classifier.update_quote(&quote("100.0", "101.0", "1", "1", 4));
+
+        assert_eq!(
+            classifier.classify(&trade("100.1", "1", 5)),
+            AggressorSide::Seller
+        );
+        // At the mid falls back to the tick rule
+        assert_eq!(
+            classifier.classify(&trade("100.5", "1", 6)),
+            AggressorSide::Buyer
+        );
+    }
+
+    #[rstest]
+    fn test_trade_imbalance_over_window() {
+        let mut calculator = OrderFlowImbalanceCalculator::new(instrument_id(), 10);
+        calculator.handle_quote(&quote("100.0", "101.0", "5", "5", 1));
+        calculator.handle_trade(&trade("101.0", "3", 2));
+        calculator.handle_trade(&trade("100.0", "1", 3));
+
+        let ofi = calculator.snapshot(3.into());
+
+        assert_eq!(ofi.buy_volume, 3.0);
+        assert_eq!(ofi.sell_volume, 1.0);
+        assert_eq!(ofi.trade_count, 2);
+        assert_eq!(ofi.trade_imbalance(), 0.5);
+
+        calculator.handle_trade(&trade("100.0", "2", 13));
+        let ofi = calculator.snapshot(13.into());
+
+        assert_eq!(ofi.buy_volume, 0.0);
+        assert_eq!(ofi.sell_volume, 2.0);
+    }
+
+    #[rstest]
+    fn test_quote_ofi() {
+        let mut calculator = OrderFlowImbalanceCalculator::new(instrument_id(), 100);
+        calculator.handle_quote(&quote("100.0", "101.0", "5", "5", 1));
+        // Bid size increases at the same price, ask price improves with a smaller size
+        calculator.handle_quote(&quote("100.0", "100.5", "8", "2", 2));
+
+        let ofi = calculator.snapshot(2.into());
+
+        // Bid flow = 8 - 5 = 3, ask flow = 2 (new better ask), OFI = 3 - 2 = 1
+        assert_eq!(ofi.quote_ofi, 1.0);
+    }
+}
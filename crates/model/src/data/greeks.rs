@@ -512,6 +512,10 @@ impl HasTsInit for PortfolioGreeks {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
 pub struct YieldCurveData {
     pub ts_init: UnixNanos,
     pub ts_event: UnixNanos,
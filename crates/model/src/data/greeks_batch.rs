@@ -0,0 +1,274 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Batch computation of historical implied volatilities and greeks.
+//!
+//! [`compute_greeks_batch`] takes option instruments with a series of underlying and option
+//! prices each, and produces a [`GreeksData`] series per instrument by implying the volatility
+//! from each option price. Instruments are processed in parallel on the rayon thread pool, so large
+//! option chains can be precomputed for backtests and research. Series can be encoded into Arrow
+//! with `greeks_to_record_batch` (requires the `arrow` feature).
+
+use std::num::NonZeroUsize;
+
+use nautilus_core::{UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use rayon::prelude::*;
+
+use super::greeks::{GreeksData, imply_vol_and_greeks};
+use crate::{
+    enums::{InstrumentClass, OptionKind},
+    instruments::{Instrument, InstrumentAny},
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+const DAYS_IN_YEAR: f64 = 365.25;
+
+/// A historical price observation for an option and its underlying.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GreeksSample {
+    /// UNIX timestamp (nanoseconds) of the observation.
+    pub ts_event: UnixNanos,
+    /// The underlying price.
+    pub underlying_price: f64,
+    /// The option price the volatility is implied from.
+    pub option_price: f64,
+}
+
+impl GreeksSample {
+    /// Creates a new [`GreeksSample`] instance.
+    #[must_use]
+    pub const fn new(ts_event: UnixNanos, underlying_price: f64, option_price: f64) -> Self {
+        Self {
+            ts_event,
+            underlying_price,
+            option_price,
+        }
+    }
+}
+
+/// Configuration for batch greeks computation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GreeksBatchConfig {
+    /// The flat interest rate.
+    pub interest_rate: f64,
+    /// The flat dividend yield, if any (the cost of carry is zero without one, as for futures).
+    pub dividend_yield: Option<f64>,
+    /// The number of worker threads (defaults to the global rayon thread pool).
+    pub workers: Option<NonZeroUsize>,
+}
+
+impl Default for GreeksBatchConfig {
+    fn default() -> Self {
+        Self {
+            interest_rate: 0.0425,
+            dividend_yield: None,
+            workers: None,
+        }
+    }
+}
+
+/// Computes the greeks series of an option `instrument` over `samples`.
+///
+/// Samples at or after the expiration of the instrument are skipped.
+///
+/// # Errors
+///
+/// Returns an error if `instrument` is not an option with a strike and expiration.
+pub fn compute_greeks_series(
+    instrument: &InstrumentAny,
+    samples: &[GreeksSample],
+    config: &GreeksBatchConfig,
+) -> anyhow::Result<Vec<GreeksData>> {
+    let instrument_id = instrument.id();
+    anyhow::ensure!(
+        instrument.instrument_class() == InstrumentClass::Option,
+        "Instrument {instrument_id} is not an option"
+    );
+    let Some(strike) = instrument.strike_price() else {
+        anyhow::bail!("Option {instrument_id} has no strike price");
+    };
+    let Some(expiration_ns) = instrument.expiration_ns() else {
+        anyhow::bail!("Option {instrument_id} has no expiration");
+    };
+
+    let strike = strike.as_f64();
+    let is_call = instrument.option_kind().unwrap_or(OptionKind::Call) == OptionKind::Call;
+    let multiplier = instrument.multiplier().as_f64();
+    let interest_rate = config.interest_rate;
+    let cost_of_carry = config
+        .dividend_yield
+        .map_or(0.0, |dividend_yield| interest_rate - dividend_yield);
+    let expiry = expiration_ns
+        .to_datetime_utc()
+        .format("%Y%m%d")
+        .to_string()
+        .parse::<i32>()
+        .unwrap_or(0);
+
+    Ok(samples
+        .iter()
+        .filter(|sample| sample.ts_event < expiration_ns)
+        .map(|sample| {
+            let remaining_ns = expiration_ns.as_u64() - sample.ts_event.as_u64();
+            let expiry_in_days = (remaining_ns / NANOSECONDS_IN_DAY) as i32;
+            let expiry_in_years = remaining_ns as f64 / NANOSECONDS_IN_DAY as f64 / DAYS_IN_YEAR;
+            let greeks = imply_vol_and_greeks(
+                sample.underlying_price,
+                interest_rate,
+                cost_of_carry,
+                is_call,
+                strike,
+                expiry_in_years,
+                sample.option_price,
+                multiplier,
+            );
+            GreeksData::new(
+                sample.ts_event,
+                sample.ts_event,
+                instrument_id,
+                is_call,
+                strike,
+                expiry,
+                expiry_in_days,
+                expiry_in_years,
+                multiplier,
+                1.0,
+                sample.underlying_price,
+                interest_rate,
+                cost_of_carry,
+                greeks.vol,
+                0.0,
+                greeks.price,
+                greeks.delta,
+                greeks.gamma,
+                greeks.vega,
+                greeks.theta,
+                (greeks.delta / multiplier).abs(),
+            )
+        })
+        .collect())
+}
+
+/// Computes the greeks series of each option instrument over its samples, in parallel.
+///
+/// The returned series are in the order of `inputs`.
+///
+/// # Errors
+///
+/// Returns an error if any instrument is not an option with a strike and expiration, or if the
+/// worker thread pool cannot be built.
+pub fn compute_greeks_batch(
+    inputs: &[(InstrumentAny, Vec<GreeksSample>)],
+    config: &GreeksBatchConfig,
+) -> anyhow::Result<Vec<Vec<GreeksData>>> {
+    let compute = || {
+        inputs
+            .par_iter()
+            .map(|(instrument, samples)| compute_greeks_series(instrument, samples, config))
+            .collect::<anyhow::Result<Vec<_>>>()
+    };
+
+    match config.workers {
+        Some(workers) => rayon::ThreadPoolBuilder::new()
+            .num_threads(workers.get())
+            .build()?
+            .install(compute),
+        None => compute(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::greeks::black_scholes_greeks_exact,
+        instruments::stubs::{audusd_sim, option_contract_appl},
+    };
+
+    fn samples(instrument: &InstrumentAny, vol: f64) -> Vec<GreeksSample> {
+        let expiration = instrument.expiration_ns().unwrap().as_u64();
+        (1..=5)
+            .map(|i| {
+                let ts = expiration - i * 10 * NANOSECONDS_IN_DAY;
+                let spot = 140.0 + i as f64 * 2.0;
+                let t = (i * 10) as f64 / DAYS_IN_YEAR;
+                let price =
+                    black_scholes_greeks_exact(spot, 0.05, 0.0, vol, true, 149.0, t, 1.0).price;
+                GreeksSample::new(ts.into(), spot, price)
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_series_implies_volatility_from_prices() {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl());
+        let config = GreeksBatchConfig {
+            interest_rate: 0.05,
+            ..Default::default()
+        };
+        let series =
+            compute_greeks_series(&instrument, &samples(&instrument, 0.3), &config).unwrap();
+
+        assert_eq!(series.len(), 5);
+        for greeks in &series {
+            assert!((greeks.vol - 0.3).abs() < 1e-3, "vol {}", greeks.vol);
+            assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+            assert_eq!(greeks.instrument_id, instrument.id());
+            assert_eq!(greeks.expiry, 20_211_217);
+        }
+        assert_eq!(series[0].expiry_in_days, 10);
+    }
+
+    #[rstest]
+    fn test_samples_after_expiry_are_skipped() {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl());
+        let expiration = instrument.expiration_ns().unwrap();
+        let samples = vec![GreeksSample::new(expiration, 150.0, 1.0)];
+        let series =
+            compute_greeks_series(&instrument, &samples, &GreeksBatchConfig::default()).unwrap();
+        assert!(series.is_empty());
+    }
+
+    #[rstest]
+    fn test_batch_preserves_input_order_across_workers() {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl());
+        let inputs: Vec<_> = [0.2, 0.3, 0.4, 0.5, 0.6]
+            .iter()
+            .map(|vol| (instrument.clone(), samples(&instrument, *vol)))
+            .collect();
+        let config = GreeksBatchConfig {
+            interest_rate: 0.05,
+            workers: NonZeroUsize::new(2),
+            ..Default::default()
+        };
+
+        let batch = compute_greeks_batch(&inputs, &config).unwrap();
+        assert_eq!(batch.len(), 5);
+        for ((_, samples), series) in inputs.iter().zip(&batch) {
+            let expected = compute_greeks_series(&instrument, samples, &config).unwrap();
+            assert_eq!(series.len(), expected.len());
+            assert_eq!(series[0].vol, expected[0].vol);
+        }
+        assert!(batch[0][0].vol < batch[4][0].vol);
+    }
+
+    #[rstest]
+    fn test_batch_rejects_non_options() {
+        let inputs = vec![(InstrumentAny::CurrencyPair(audusd_sim()), Vec::new())];
+        assert!(compute_greeks_batch(&inputs, &GreeksBatchConfig::default()).is_err());
+    }
+}
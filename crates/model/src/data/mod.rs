@@ -15,6 +15,7 @@
 
 //! Data types for the trading domain model.
 
+pub mod band;
 pub mod bar;
 pub mod bet;
 pub mod black_scholes;
@@ -22,13 +23,24 @@ pub mod close;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod economic;
+pub mod flow;
 pub mod funding;
 pub mod greeks;
+pub mod greeks_batch;
+pub mod numeric_greeks;
 pub mod order;
 pub mod prices;
 pub mod quote;
+pub mod session_stats;
 pub mod status;
+pub mod synthetic_bar;
+pub mod time_bar;
 pub mod trade;
+pub mod vol_surface;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
 
 #[cfg(any(test, feature = "stubs"))]
 pub mod stubs;
@@ -46,22 +58,30 @@ use serde_json::to_string;
 
 // Re-exports
 #[rustfmt::skip]  // Keep these grouped
+pub use band::{PriceBandKind, PriceBandUpdate};
 pub use bar::{Bar, BarSpecification, BarType};
 pub use black_scholes::Greeks;
 pub use close::InstrumentClose;
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{DEPTH10_LEN, OrderBookDepth10};
+pub use economic::{EconomicEvent, EconomicEventFilter, EventImpact};
+pub use flow::{OrderFlowImbalance, OrderFlowImbalanceCalculator, TradeSignClassifier};
 pub use funding::FundingRateUpdate;
 pub use greeks::{
     BlackScholesGreeksResult, GreeksData, PortfolioGreeks, YieldCurveData, black_scholes_greeks,
     imply_vol_and_greeks, refine_vol_and_greeks,
 };
+pub use greeks_batch::{GreeksBatchConfig, GreeksSample, compute_greeks_batch};
+pub use numeric_greeks::{FiniteDifferenceConfig, NumericGreeks, PricingInputs, numeric_greeks};
 pub use order::{BookOrder, NULL_ORDER};
 pub use prices::{IndexPriceUpdate, MarkPriceUpdate};
 pub use quote::QuoteTick;
+pub use session_stats::{SessionStatistics, SessionStatisticsCalculator};
 pub use status::InstrumentStatus;
+pub use time_bar::{EmptyBarPolicy, TimeBarBuilder};
 pub use trade::TradeTick;
+pub use vol_surface::{VolArbitrageKind, VolArbitrageViolation, VolSurface, VolSurfacePoint};
 
 use crate::identifiers::{InstrumentId, Venue};
 
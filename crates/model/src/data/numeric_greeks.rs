@@ -0,0 +1,328 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Numeric greeks for arbitrary pricing models via finite differences.
+//!
+//! [`numeric_greeks`] bumps the inputs of any `price_fn` to compute delta, gamma, vega and theta,
+//! so exotic or user-defined payoff models get greeks consistent with the Black-Scholes
+//! conventions of [`GreeksData`] (vega per vol point, theta per calendar day). Each derivative
+//! starts from the configured bump and halves it until two successive Richardson-extrapolated
+//! estimates agree within the tolerance, adapting the bump size to the curvature of the model.
+
+use nautilus_core::UnixNanos;
+
+use super::greeks::GreeksData;
+use crate::identifiers::InstrumentId;
+
+const DAYS_IN_YEAR: f64 = 365.25;
+
+/// The inputs of a pricing model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PricingInputs {
+    pub underlying_price: f64,
+    pub vol: f64,
+    /// Time to expiry in years.
+    pub time_to_expiry: f64,
+    pub interest_rate: f64,
+    pub cost_of_carry: f64,
+}
+
+/// Configuration of the finite difference bumps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FiniteDifferenceConfig {
+    /// The initial underlying price bump, relative to the underlying price.
+    pub spot_bump: f64,
+    /// The initial (absolute) volatility bump.
+    pub vol_bump: f64,
+    /// The initial time bump in years.
+    pub time_bump: f64,
+    /// The relative tolerance between successive estimates.
+    pub tolerance: f64,
+    /// The maximum number of bump halvings per derivative.
+    pub max_refinements: u32,
+}
+
+impl Default for FiniteDifferenceConfig {
+    fn default() -> Self {
+        Self {
+            spot_bump: 0.01,
+            vol_bump: 0.01,
+            time_bump: 1.0 / DAYS_IN_YEAR,
+            tolerance: 1e-6,
+            max_refinements: 8,
+        }
+    }
+}
+
+/// Greeks computed by finite differences, per unit of the priced instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NumericGreeks {
+    pub price: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    /// Price change for an absolute one percent change in volatility.
+    pub vega: f64,
+    /// Price change per calendar day.
+    pub theta: f64,
+}
+
+impl NumericGreeks {
+    /// Converts the greeks into [`GreeksData`] scaled by `multiplier`, for aggregation into
+    /// portfolio greeks.
+    #[must_use]
+    pub fn to_greeks_data(
+        &self,
+        instrument_id: InstrumentId,
+        inputs: &PricingInputs,
+        multiplier: f64,
+        ts_event: UnixNanos,
+    ) -> GreeksData {
+        let mut greeks =
+            GreeksData::from_delta(instrument_id, self.delta * multiplier, multiplier, ts_event);
+        greeks.expiry_in_years = inputs.time_to_expiry;
+        greeks.expiry_in_days = (inputs.time_to_expiry * DAYS_IN_YEAR) as i32;
+        greeks.underlying_price = inputs.underlying_price;
+        greeks.interest_rate = inputs.interest_rate;
+        greeks.cost_of_carry = inputs.cost_of_carry;
+        greeks.vol = inputs.vol;
+        greeks.price = self.price * multiplier;
+        greeks.gamma = self.gamma * multiplier;
+        greeks.vega = self.vega * multiplier;
+        greeks.theta = self.theta * multiplier;
+        greeks
+    }
+}
+
+/// Computes the greeks of `price_fn` at `inputs` by finite differences.
+///
+/// Theta uses central differences in time when the time to expiry allows, and one-sided
+/// differences towards expiry otherwise (zero at expiry).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The underlying price or volatility is not positive, or the time to expiry is negative.
+/// - `price_fn` returns a non-finite price.
+pub fn numeric_greeks<F>(
+    price_fn: F,
+    inputs: &PricingInputs,
+    config: &FiniteDifferenceConfig,
+) -> anyhow::Result<NumericGreeks>
+where
+    F: Fn(&PricingInputs) -> f64,
+{
+    anyhow::ensure!(
+        inputs.underlying_price > 0.0,
+        "Invalid `underlying_price` {}: must be positive",
+        inputs.underlying_price
+    );
+    anyhow::ensure!(
+        inputs.vol > 0.0,
+        "Invalid `vol` {}: must be positive",
+        inputs.vol
+    );
+    anyhow::ensure!(
+        inputs.time_to_expiry >= 0.0,
+        "Invalid `time_to_expiry` {}: must not be negative",
+        inputs.time_to_expiry
+    );
+
+    let price_at = |inputs: &PricingInputs| -> anyhow::Result<f64> {
+        let price = price_fn(inputs);
+        anyhow::ensure!(price.is_finite(), "Non-finite price {price} at {inputs:?}");
+        Ok(price)
+    };
+    let price = price_at(inputs)?;
+
+    let spot = inputs.underlying_price;
+    let with_spot = |s: f64| PricingInputs {
+        underlying_price: s,
+        ..*inputs
+    };
+    let spot_bump = config.spot_bump * spot;
+    let delta = refine(spot_bump, 2, config, |h| {
+        Ok((price_at(&with_spot(spot + h))? - price_at(&with_spot(spot - h))?) / (2.0 * h))
+    })?;
+    let gamma = refine(spot_bump, 2, config, |h| {
+        let up = price_at(&with_spot(spot + h))?;
+        let down = price_at(&with_spot(spot - h))?;
+        Ok((up - 2.0 * price + down) / (h * h))
+    })?;
+
+    let vol = inputs.vol;
+    let with_vol = |v: f64| PricingInputs { vol: v, ..*inputs };
+    let vol_bump = config.vol_bump.min(vol / 2.0);
+    let vega = refine(vol_bump, 2, config, |h| {
+        Ok((price_at(&with_vol(vol + h))? - price_at(&with_vol(vol - h))?) / (2.0 * h))
+    })? * 0.01;
+
+    let t = inputs.time_to_expiry;
+    let with_time = |t: f64| PricingInputs {
+        time_to_expiry: t,
+        ..*inputs
+    };
+    let theta = if t == 0.0 {
+        0.0
+    } else if t > config.time_bump {
+        refine(config.time_bump, 2, config, |h| {
+            Ok((price_at(&with_time(t - h))? - price_at(&with_time(t + h))?) / (2.0 * h))
+        })?
+    } else {
+        refine(t / 2.0, 1, config, |h| {
+            Ok((price_at(&with_time(t - h))? - price) / h)
+        })?
+    } / DAYS_IN_YEAR;
+
+    Ok(NumericGreeks {
+        price,
+        delta,
+        gamma,
+        vega,
+        theta,
+    })
+}
+
+/// Refines a finite difference estimate of error order `order` by halving the bump from
+/// `initial_bump`, returning the Richardson extrapolation once successive estimates agree.
+fn refine<E>(
+    initial_bump: f64,
+    order: i32,
+    config: &FiniteDifferenceConfig,
+    estimate: E,
+) -> anyhow::Result<f64>
+where
+    E: Fn(f64) -> anyhow::Result<f64>,
+{
+    let factor = 2f64.powi(order);
+    let mut bump = initial_bump;
+    let mut previous = estimate(bump)?;
+    let mut extrapolated = previous;
+
+    for _ in 0..config.max_refinements {
+        bump /= 2.0;
+        let next = estimate(bump)?;
+        extrapolated = (factor * next - previous) / (factor - 1.0);
+        if (next - previous).abs() <= config.tolerance * extrapolated.abs().max(1.0) {
+            break;
+        }
+        previous = next;
+    }
+    Ok(extrapolated)
+}
+
+#[cfg(test)]
+mod tests {
+    use implied_vol::{DefaultSpecialFn, SpecialFn};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::{PortfolioGreeks, greeks::black_scholes_greeks_exact};
+
+    fn inputs() -> PricingInputs {
+        PricingInputs {
+            underlying_price: 100.0,
+            vol: 0.25,
+            time_to_expiry: 0.5,
+            interest_rate: 0.05,
+            cost_of_carry: 0.05,
+        }
+    }
+
+    fn black_scholes_call(inputs: &PricingInputs) -> f64 {
+        black_scholes_greeks_exact(
+            inputs.underlying_price,
+            inputs.interest_rate,
+            inputs.cost_of_carry,
+            inputs.vol,
+            true,
+            105.0,
+            inputs.time_to_expiry,
+            1.0,
+        )
+        .price
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-4 * expected.abs().max(1.0),
+            "{actual} != {expected}"
+        );
+    }
+
+    #[rstest]
+    fn test_matches_black_scholes_greeks() {
+        let inputs = inputs();
+        let greeks = numeric_greeks(
+            black_scholes_call,
+            &inputs,
+            &FiniteDifferenceConfig::default(),
+        )
+        .unwrap();
+        let exact = black_scholes_greeks_exact(100.0, 0.05, 0.05, 0.25, true, 105.0, 0.5, 1.0);
+
+        assert_close(greeks.price, exact.price);
+        assert_close(greeks.delta, exact.delta);
+        assert_close(greeks.gamma, exact.gamma);
+        assert_close(greeks.vega, exact.vega);
+        assert_close(greeks.theta, exact.theta);
+    }
+
+    #[rstest]
+    fn test_theta_near_expiry_uses_one_sided_differences() {
+        let inputs = PricingInputs {
+            time_to_expiry: 0.5 / DAYS_IN_YEAR,
+            ..inputs()
+        };
+        let greeks = numeric_greeks(
+            black_scholes_call,
+            &inputs,
+            &FiniteDifferenceConfig::default(),
+        )
+        .unwrap();
+        assert!(greeks.theta.is_finite());
+        assert!(greeks.theta < 0.0);
+    }
+
+    #[rstest]
+    fn test_user_defined_payoff_feeds_portfolio_greeks() {
+        // Cash-or-nothing digital call paying 1 if S_T > K
+        let digital = |inputs: &PricingInputs| {
+            let t = inputs.time_to_expiry;
+            let d2 = ((inputs.underlying_price / 105.0).ln()
+                + (inputs.cost_of_carry - 0.5 * inputs.vol.powi(2)) * t)
+                / (inputs.vol * t.sqrt());
+            (-inputs.interest_rate * t).exp() * DefaultSpecialFn::norm_cdf(d2)
+        };
+        let inputs = inputs();
+        let greeks = numeric_greeks(digital, &inputs, &FiniteDifferenceConfig::default()).unwrap();
+        assert!(greeks.delta > 0.0);
+
+        let data = greeks.to_greeks_data("AAPL.XNAS".into(), &inputs, 100.0, 1.into());
+        let portfolio = PortfolioGreeks::from(data.clone()) + PortfolioGreeks::from(2.0 * &data);
+        assert_close(portfolio.delta, 300.0 * greeks.delta);
+        assert_close(portfolio.vega, 300.0 * greeks.vega);
+    }
+
+    #[rstest]
+    fn test_rejects_invalid_inputs_and_prices() {
+        let config = FiniteDifferenceConfig::default();
+        let invalid = PricingInputs {
+            vol: 0.0,
+            ..inputs()
+        };
+        assert!(numeric_greeks(black_scholes_call, &invalid, &config).is_err());
+        assert!(numeric_greeks(|_| f64::NAN, &inputs(), &config).is_err());
+    }
+}
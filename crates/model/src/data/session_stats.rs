@@ -0,0 +1,200 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Session-anchored statistics (VWAP, high/low and cumulative volume) derived from trades.
+
+use std::fmt::Display;
+
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use super::{HasTsInit, TradeTick};
+use crate::{identifiers::InstrumentId, types::Price};
+
+/// Statistics for an instrument accumulated since an anchor (typically the session open).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SessionStatistics {
+    /// The instrument ID for the statistics.
+    pub instrument_id: InstrumentId,
+    /// UNIX timestamp (nanoseconds) of the anchor the statistics accumulate from.
+    pub anchor: UnixNanos,
+    /// The volume-weighted average price since the anchor (`None` without volume).
+    pub vwap: Option<f64>,
+    /// The highest traded price since the anchor.
+    pub high: Option<Price>,
+    /// The lowest traded price since the anchor.
+    pub low: Option<Price>,
+    /// The cumulative traded volume since the anchor.
+    pub volume: f64,
+    /// The number of trades since the anchor.
+    pub trade_count: u64,
+    /// UNIX timestamp (nanoseconds) of the last trade included.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the statistics were initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Display for SessionStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{:?},{:?},{:?},{},{},{}",
+            self.instrument_id,
+            self.anchor,
+            self.vwap,
+            self.high.map(|p| p.to_string()),
+            self.low.map(|p| p.to_string()),
+            self.volume,
+            self.trade_count,
+            self.ts_event,
+        )
+    }
+}
+
+impl HasTsInit for SessionStatistics {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Accumulates [`SessionStatistics`] for an instrument from trades since an anchor.
+#[derive(Clone, Debug)]
+pub struct SessionStatisticsCalculator {
+    instrument_id: InstrumentId,
+    anchor: UnixNanos,
+    notional: f64,
+    volume: f64,
+    high: Option<Price>,
+    low: Option<Price>,
+    trade_count: u64,
+    ts_last: UnixNanos,
+}
+
+impl SessionStatisticsCalculator {
+    /// Creates a new [`SessionStatisticsCalculator`] instance anchored at `anchor`.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, anchor: UnixNanos) -> Self {
+        Self {
+            instrument_id,
+            anchor,
+            notional: 0.0,
+            volume: 0.0,
+            high: None,
+            low: None,
+            trade_count: 0,
+            ts_last: UnixNanos::default(),
+        }
+    }
+
+    /// Returns the instrument ID for the calculator.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the current anchor.
+    #[must_use]
+    pub const fn anchor(&self) -> UnixNanos {
+        self.anchor
+    }
+
+    /// Handles a trade, ignoring trades before the anchor.
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        if trade.ts_event < self.anchor {
+            return;
+        }
+        let size = trade.size.as_f64();
+        self.notional += trade.price.as_f64() * size;
+        self.volume += size;
+        self.high = Some(self.high.map_or(trade.price, |high| high.max(trade.price)));
+        self.low = Some(self.low.map_or(trade.price, |low| low.min(trade.price)));
+        self.trade_count += 1;
+        self.ts_last = trade.ts_event;
+    }
+
+    /// Resets the statistics, anchoring them at `anchor`.
+    pub fn reset(&mut self, anchor: UnixNanos) {
+        *self = Self::new(self.instrument_id, anchor);
+    }
+
+    /// Returns a snapshot of the statistics at `ts_init`.
+    #[must_use]
+    pub fn snapshot(&self, ts_init: UnixNanos) -> SessionStatistics {
+        SessionStatistics {
+            instrument_id: self.instrument_id,
+            anchor: self.anchor,
+            vwap: (self.volume > 0.0).then(|| self.notional / self.volume),
+            high: self.high,
+            low: self.low,
+            volume: self.volume,
+            trade_count: self.trade_count,
+            ts_event: self.ts_last,
+            ts_init,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{enums::AggressorSide, identifiers::TradeId, types::Quantity};
+
+    fn trade(price: &str, size: u64, ts: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_vwap_high_low_volume() {
+        let mut calculator =
+            SessionStatisticsCalculator::new(InstrumentId::from("ETHUSDT.BINANCE"), 10.into());
+        calculator.handle_trade(&trade("90.0", 5, 5)); // Before the anchor
+        calculator.handle_trade(&trade("100.0", 1, 10));
+        calculator.handle_trade(&trade("103.0", 2, 11));
+        calculator.handle_trade(&trade("99.0", 1, 12));
+
+        let stats = calculator.snapshot(20.into());
+
+        assert_eq!(stats.vwap, Some(101.25));
+        assert_eq!(stats.high, Some(Price::from("103.0")));
+        assert_eq!(stats.low, Some(Price::from("99.0")));
+        assert_eq!(stats.volume, 4.0);
+        assert_eq!(stats.trade_count, 3);
+        assert_eq!(stats.ts_event, UnixNanos::from(12));
+    }
+
+    #[rstest]
+    fn test_reset_starts_new_anchor() {
+        let mut calculator =
+            SessionStatisticsCalculator::new(InstrumentId::from("ETHUSDT.BINANCE"), 0.into());
+        calculator.handle_trade(&trade("100.0", 1, 1));
+        calculator.reset(50.into());
+
+        let stats = calculator.snapshot(50.into());
+
+        assert_eq!(stats.anchor, UnixNanos::from(50));
+        assert_eq!(stats.vwap, None);
+        assert_eq!(stats.trade_count, 0);
+    }
+}
@@ -0,0 +1,561 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Incremental builders for synthetic (`INTERNAL`) bar types: Heikin-Ashi, Renko and range bars.
+//!
+//! The bar type parameters configure each builder:
+//! - [`BarAggregation::HeikinAshi`] is a composite bar type, transforming bars of its composite
+//!   (source) bar type, e.g. `AUD/USD.SIM-1-HEIKIN_ASHI-LAST-INTERNAL@1-MINUTE-EXTERNAL`.
+//! - [`BarAggregation::Renko`] emits a brick each time the price moves the step (in price
+//!   increments) beyond the last brick, with a reversal requiring a move of two bricks.
+//! - [`BarAggregation::Range`] closes a bar once its high-low range reaches the step (in price
+//!   increments).
+//!
+//! Renko and range bars are built from trades for `LAST` bar types, or from quotes for
+//! `BID`/`ASK`/`MID`. The same builders resample historical data via [`resample_trades`] and
+//! [`resample_bars`].
+
+use nautilus_core::UnixNanos;
+
+use super::{Bar, BarType, QuoteTick, TradeTick};
+use crate::{
+    enums::{AggregationSource, BarAggregation, PriceType},
+    types::{Price, Quantity, price::PriceRaw},
+};
+
+/// Builds Heikin-Ashi bars from the bars of a source bar type.
+#[derive(Clone, Debug)]
+pub struct HeikinAshiBuilder {
+    bar_type: BarType,
+    /// The previous Heikin-Ashi (open, close).
+    prev: Option<(f64, f64)>,
+}
+
+impl HeikinAshiBuilder {
+    /// Creates a new [`HeikinAshiBuilder`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bar_type` is not a composite `HEIKIN_ASHI` bar type.
+    pub fn new(bar_type: BarType) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bar_type.spec().aggregation == BarAggregation::HeikinAshi,
+            "Invalid bar type {bar_type}: aggregation must be HEIKIN_ASHI"
+        );
+        anyhow::ensure!(
+            bar_type.is_composite(),
+            "Invalid bar type {bar_type}: HEIKIN_ASHI requires a composite source bar type"
+        );
+        Ok(Self {
+            bar_type,
+            prev: None,
+        })
+    }
+
+    /// Returns the source bar type transformed.
+    #[must_use]
+    pub fn source_bar_type(&self) -> BarType {
+        self.bar_type.composite()
+    }
+
+    /// Transforms the source `bar` into a Heikin-Ashi bar.
+    pub fn handle_bar(&mut self, bar: &Bar) -> Bar {
+        let precision = bar.close.precision;
+        let (open, high, low, close) = (
+            bar.open.as_f64(),
+            bar.high.as_f64(),
+            bar.low.as_f64(),
+            bar.close.as_f64(),
+        );
+
+        let ha_close = (open + high + low + close) / 4.0;
+        let ha_open = match self.prev {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (open + close) / 2.0,
+        };
+        self.prev = Some((ha_open, ha_close));
+
+        let ha_open = Price::new(ha_open, precision);
+        let ha_close = Price::new(ha_close, precision);
+        Bar::new(
+            self.bar_type,
+            ha_open,
+            bar.high.max(ha_open).max(ha_close),
+            bar.low.min(ha_open).min(ha_close),
+            ha_close,
+            bar.volume,
+            bar.ts_event,
+            bar.ts_init,
+        )
+    }
+
+    /// Resets the builder, discarding the previous Heikin-Ashi bar.
+    pub fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+/// Builds Renko bricks from price updates.
+#[derive(Clone, Debug)]
+pub struct RenkoBuilder {
+    bar_type: BarType,
+    brick: Price,
+    /// The (open, close) of the last brick, or the anchor price before the first brick.
+    last: Option<(Price, Price)>,
+    volume: Option<Quantity>,
+}
+
+impl RenkoBuilder {
+    /// Creates a new [`RenkoBuilder`] instance, with a brick size of the bar type step in
+    /// `price_increment` units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bar_type` is not a `RENKO` bar type.
+    pub fn new(bar_type: BarType, price_increment: Price) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bar_type.spec().aggregation == BarAggregation::Renko,
+            "Invalid bar type {bar_type}: aggregation must be RENKO"
+        );
+        Ok(Self {
+            bar_type,
+            brick: step_size(&bar_type, price_increment),
+            last: None,
+            volume: None,
+        })
+    }
+
+    /// Updates the builder with a price and size, returning any completed bricks.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        self.volume = Some(self.volume.map_or(size, |volume| volume + size));
+        let Some((mut open, mut close)) = self.last else {
+            self.last = Some((price, price));
+            return Vec::new();
+        };
+
+        let mut bricks = Vec::new();
+        loop {
+            let top = open.max(close);
+            let bottom = open.min(close);
+            if price >= top + self.brick {
+                (open, close) = (top, top + self.brick);
+            } else if price <= bottom - self.brick {
+                (open, close) = (bottom, bottom - self.brick);
+            } else {
+                break;
+            }
+            let volume = self
+                .volume
+                .take()
+                .unwrap_or_else(|| Quantity::zero(size.precision));
+            bricks.push(Bar::new(
+                self.bar_type,
+                open,
+                open.max(close),
+                open.min(close),
+                close,
+                volume,
+                ts_event,
+                ts_event,
+            ));
+        }
+
+        self.last = Some((open, close));
+        bricks
+    }
+
+    /// Resets the builder, discarding the last brick.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.volume = None;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PartialBar {
+    open: Price,
+    high: Price,
+    low: Price,
+    volume: Quantity,
+}
+
+/// Builds range bars from price updates.
+#[derive(Clone, Debug)]
+pub struct RangeBarBuilder {
+    bar_type: BarType,
+    range: Price,
+    current: Option<PartialBar>,
+}
+
+impl RangeBarBuilder {
+    /// Creates a new [`RangeBarBuilder`] instance, with a range of the bar type step in
+    /// `price_increment` units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bar_type` is not a `RANGE` bar type.
+    pub fn new(bar_type: BarType, price_increment: Price) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bar_type.spec().aggregation == BarAggregation::Range,
+            "Invalid bar type {bar_type}: aggregation must be RANGE"
+        );
+        Ok(Self {
+            bar_type,
+            range: step_size(&bar_type, price_increment),
+            current: None,
+        })
+    }
+
+    /// Updates the builder with a price and size, returning any completed bars.
+    ///
+    /// A price gapping beyond the range closes the bar at the range limit and continues with
+    /// new bars opened at each limit until the price is reached.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let mut bars = Vec::new();
+        let mut current = self.current.take().unwrap_or(PartialBar {
+            open: price,
+            high: price,
+            low: price,
+            volume: Quantity::zero(size.precision),
+        });
+
+        loop {
+            if price > current.high && price >= current.low + self.range {
+                let close = current.low + self.range;
+                bars.push(self.close_bar(current, close, close, current.low, ts_event));
+                current = PartialBar {
+                    open: close,
+                    high: close,
+                    low: close,
+                    volume: Quantity::zero(size.precision),
+                };
+            } else if price < current.low && price <= current.high - self.range {
+                let close = current.high - self.range;
+                bars.push(self.close_bar(current, close, current.high, close, ts_event));
+                current = PartialBar {
+                    open: close,
+                    high: close,
+                    low: close,
+                    volume: Quantity::zero(size.precision),
+                };
+            } else {
+                break;
+            }
+        }
+
+        current.high = current.high.max(price);
+        current.low = current.low.min(price);
+        current.volume = current.volume + size;
+        self.current = Some(current);
+        bars
+    }
+
+    fn close_bar(
+        &self,
+        bar: PartialBar,
+        close: Price,
+        high: Price,
+        low: Price,
+        ts_event: UnixNanos,
+    ) -> Bar {
+        Bar::new(
+            self.bar_type,
+            bar.open,
+            high,
+            low,
+            close,
+            bar.volume,
+            ts_event,
+            ts_event,
+        )
+    }
+
+    /// Resets the builder, discarding the partial bar.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+}
+
+/// A builder for any synthetic bar type.
+#[derive(Clone, Debug)]
+pub enum SyntheticBarBuilder {
+    HeikinAshi(HeikinAshiBuilder),
+    Renko(RenkoBuilder),
+    Range(RangeBarBuilder),
+}
+
+impl SyntheticBarBuilder {
+    /// Creates the builder for `bar_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bar_type` is not an `INTERNAL` synthetic bar type.
+    pub fn new(bar_type: BarType, price_increment: Price) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bar_type.aggregation_source() == AggregationSource::Internal,
+            "Invalid bar type {bar_type}: synthetic bars must be INTERNAL"
+        );
+        match bar_type.spec().aggregation {
+            BarAggregation::HeikinAshi => Ok(Self::HeikinAshi(HeikinAshiBuilder::new(bar_type)?)),
+            BarAggregation::Renko => Ok(Self::Renko(RenkoBuilder::new(bar_type, price_increment)?)),
+            BarAggregation::Range => Ok(Self::Range(RangeBarBuilder::new(
+                bar_type,
+                price_increment,
+            )?)),
+            aggregation => {
+                anyhow::bail!("Invalid bar type {bar_type}: {aggregation} is not synthetic")
+            }
+        }
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        match self {
+            Self::HeikinAshi(_) => Vec::new(),
+            Self::Renko(builder) => builder.update(price, size, ts_event),
+            Self::Range(builder) => builder.update(price, size, ts_event),
+        }
+    }
+
+    fn price_type(&self) -> PriceType {
+        match self {
+            Self::HeikinAshi(builder) => builder.bar_type.spec().price_type,
+            Self::Renko(builder) => builder.bar_type.spec().price_type,
+            Self::Range(builder) => builder.bar_type.spec().price_type,
+        }
+    }
+
+    /// Handles a trade, returning any completed bars (`LAST` bar types only).
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Vec<Bar> {
+        if self.price_type() != PriceType::Last {
+            return Vec::new();
+        }
+        self.update(trade.price, trade.size, trade.ts_event)
+    }
+
+    /// Handles a quote, returning any completed bars (`BID`/`ASK`/`MID` bar types only).
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> Vec<Bar> {
+        let price_type = self.price_type();
+        if price_type == PriceType::Last {
+            return Vec::new();
+        }
+        self.update(
+            quote.extract_price(price_type),
+            quote.extract_size(price_type),
+            quote.ts_event,
+        )
+    }
+
+    /// Handles a source bar, returning the transformed bar (Heikin-Ashi bar types only).
+    pub fn handle_bar(&mut self, bar: &Bar) -> Option<Bar> {
+        match self {
+            Self::HeikinAshi(builder) if bar.bar_type == builder.source_bar_type() => {
+                Some(builder.handle_bar(bar))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resets the builder state (e.g. at a session boundary).
+    pub fn reset(&mut self) {
+        match self {
+            Self::HeikinAshi(builder) => builder.reset(),
+            Self::Renko(builder) => builder.reset(),
+            Self::Range(builder) => builder.reset(),
+        }
+    }
+}
+
+/// Resamples historical `trades` into bars of the synthetic `bar_type`.
+///
+/// # Errors
+///
+/// Returns an error if `bar_type` is not a synthetic Renko or range bar type.
+pub fn resample_trades(
+    bar_type: BarType,
+    price_increment: Price,
+    trades: &[TradeTick],
+) -> anyhow::Result<Vec<Bar>> {
+    let mut builder = SyntheticBarBuilder::new(bar_type, price_increment)?;
+    anyhow::ensure!(
+        !matches!(builder, SyntheticBarBuilder::HeikinAshi(_)),
+        "Invalid bar type {bar_type}: HEIKIN_ASHI resamples bars, not trades"
+    );
+    Ok(trades
+        .iter()
+        .flat_map(|trade| builder.handle_trade(trade))
+        .collect())
+}
+
+/// Resamples historical source `bars` into Heikin-Ashi bars of `bar_type`.
+///
+/// # Errors
+///
+/// Returns an error if `bar_type` is not a composite Heikin-Ashi bar type.
+pub fn resample_bars(bar_type: BarType, bars: &[Bar]) -> anyhow::Result<Vec<Bar>> {
+    let mut builder = HeikinAshiBuilder::new(bar_type)?;
+    Ok(bars.iter().map(|bar| builder.handle_bar(bar)).collect())
+}
+
+fn step_size(bar_type: &BarType, price_increment: Price) -> Price {
+    Price::from_raw(
+        price_increment.raw * bar_type.spec().step.get() as PriceRaw,
+        price_increment.precision,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{enums::AggressorSide, identifiers::TradeId};
+
+    fn trade(price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            "AUD/USD.SIM".parse().unwrap(),
+            Price::from(price),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn closes(bars: &[Bar]) -> Vec<String> {
+        bars.iter().map(|bar| bar.close.to_string()).collect()
+    }
+
+    #[rstest]
+    fn test_renko_bricks_and_reversal() {
+        let bar_type = BarType::from("AUD/USD.SIM-10-RENKO-LAST-INTERNAL");
+        let prices = ["1.00000", "1.00025", "1.00015", "1.00005", "0.99995"];
+        let trades: Vec<_> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, p)| trade(p, i as u64))
+            .collect();
+
+        let bars = resample_trades(bar_type, Price::from("0.00001"), &trades).unwrap();
+
+        // Two up bricks, then a reversal needs a full brick below the last brick open
+        assert_eq!(closes(&bars), vec!["1.00010", "1.00020", "1.00000"]);
+        assert_eq!(bars[0].volume, Quantity::from(2));
+        assert_eq!(bars[2].open, Price::from("1.00010"));
+        assert_eq!(bars[2].high, Price::from("1.00010"));
+    }
+
+    #[rstest]
+    fn test_range_bars_close_at_range_limit() {
+        let bar_type = BarType::from("AUD/USD.SIM-10-RANGE-LAST-INTERNAL");
+        let prices = ["1.00000", "1.00004", "0.99998", "1.00008", "1.00030"];
+        let trades: Vec<_> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, p)| trade(p, i as u64))
+            .collect();
+
+        let bars = resample_trades(bar_type, Price::from("0.00001"), &trades).unwrap();
+
+        assert_eq!(closes(&bars), vec!["1.00008", "1.00018", "1.00028"]);
+        assert_eq!(bars[0].low, Price::from("0.99998"));
+        assert_eq!(bars[0].volume, Quantity::from(3));
+        for bar in &bars {
+            assert_eq!(bar.high - bar.low, Price::from("0.00010"));
+        }
+    }
+
+    #[rstest]
+    fn test_heikin_ashi_transform() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-HEIKIN_ASHI-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let source = bar_type.composite();
+        let bar = |o: &str, h: &str, l: &str, c: &str| {
+            Bar::new(
+                source,
+                Price::from(o),
+                Price::from(h),
+                Price::from(l),
+                Price::from(c),
+                Quantity::from(10),
+                0.into(),
+                0.into(),
+            )
+        };
+        let bars = [
+            bar("1.00000", "1.00060", "0.99980", "1.00040"),
+            bar("1.00020", "1.00060", "1.00000", "1.00040"),
+        ];
+
+        let ha = resample_bars(bar_type, &bars).unwrap();
+
+        assert_eq!(ha[0].bar_type, bar_type);
+        assert_eq!(ha[0].open, Price::from("1.00020"));
+        assert_eq!(ha[0].close, Price::from("1.00020"));
+        assert_eq!(ha[1].open, Price::from("1.00020"));
+        assert_eq!(ha[1].close, Price::from("1.00030"));
+        assert_eq!(ha[1].low, Price::from("1.00000"));
+    }
+
+    #[rstest]
+    fn test_builder_rejects_non_synthetic_or_external_bar_types() {
+        let increment = Price::from("0.00001");
+        assert!(
+            SyntheticBarBuilder::new(
+                BarType::from("AUD/USD.SIM-1-MINUTE-LAST-INTERNAL"),
+                increment
+            )
+            .is_err()
+        );
+        assert!(
+            SyntheticBarBuilder::new(
+                BarType::from("AUD/USD.SIM-10-RENKO-LAST-EXTERNAL"),
+                increment
+            )
+            .is_err()
+        );
+        assert!(
+            SyntheticBarBuilder::new(
+                BarType::from("AUD/USD.SIM-1-HEIKIN_ASHI-LAST-INTERNAL"),
+                increment
+            )
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_quotes_drive_mid_bar_types() {
+        let bar_type = BarType::from("AUD/USD.SIM-10-RENKO-MID-INTERNAL");
+        let mut builder = SyntheticBarBuilder::new(bar_type, Price::from("0.00001")).unwrap();
+        let quote = |bid: &str, ask: &str| {
+            QuoteTick::new(
+                "AUD/USD.SIM".parse().unwrap(),
+                Price::from(bid),
+                Price::from(ask),
+                Quantity::from(1),
+                Quantity::from(1),
+                0.into(),
+                0.into(),
+            )
+        };
+
+        assert!(builder.handle_trade(&trade("1.00000", 0)).is_empty());
+        assert!(
+            builder
+                .handle_quote(&quote("1.00000", "1.00002"))
+                .is_empty()
+        );
+        assert_eq!(builder.handle_quote(&quote("1.00010", "1.00012")).len(), 1);
+    }
+}
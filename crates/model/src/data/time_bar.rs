@@ -0,0 +1,375 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Incremental builder for time bars which closes bars at their interval boundaries.
+//!
+//! A time bar covers the interval `[open, open + step)`, aligned to multiples of the bar
+//! interval from the UNIX epoch (plus an optional origin offset), and is stamped with its close
+//! time. Bars close either when an update arrives at or beyond the boundary, or when the clock
+//! reaches the boundary through [`TimeBarBuilder::close_until`], so a bar is emitted on time even
+//! when the feed is silent. The [`EmptyBarPolicy`] decides what an interval without updates
+//! produces.
+
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use super::{Bar, BarType, QuoteTick, TradeTick, bar::get_bar_interval_ns};
+use crate::{
+    enums::{AggregationSource, BarAggregation, PriceType},
+    types::{Price, Quantity},
+};
+
+/// What a time bar builder emits for an interval without updates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EmptyBarPolicy {
+    /// Emit nothing for the interval.
+    #[default]
+    Skip,
+    /// Emit a flat bar at the previous close with zero volume (nothing before the first update).
+    RepeatClose,
+    /// Emit a bar with zero prices and zero volume, marking the interval as empty.
+    EmitEmpty,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PartialBar {
+    open: Price,
+    high: Price,
+    low: Price,
+    close: Price,
+    volume: Quantity,
+}
+
+/// Builds time bars from price updates, closing them at each interval boundary.
+#[derive(Clone, Debug)]
+pub struct TimeBarBuilder {
+    bar_type: BarType,
+    interval_ns: u64,
+    origin_ns: u64,
+    policy: EmptyBarPolicy,
+    price_precision: u8,
+    size_precision: u8,
+    /// The open time of the current interval, once aligned.
+    open_ns: Option<UnixNanos>,
+    current: Option<PartialBar>,
+    last_close: Option<Price>,
+}
+
+impl TimeBarBuilder {
+    /// Creates a new [`TimeBarBuilder`] instance.
+    ///
+    /// Interval boundaries are aligned to multiples of the bar interval offset by `origin_ns`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `bar_type` is not an `INTERNAL` bar type.
+    /// - The aggregation is not a fixed time interval (`MILLISECOND` to `DAY`).
+    /// - `origin_ns` is not less than the bar interval.
+    pub fn new(
+        bar_type: BarType,
+        price_precision: u8,
+        size_precision: u8,
+        policy: EmptyBarPolicy,
+        origin_ns: u64,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bar_type.aggregation_source() == AggregationSource::Internal,
+            "Invalid bar type {bar_type}: time bars must be INTERNAL"
+        );
+        anyhow::ensure!(
+            matches!(
+                bar_type.spec().aggregation,
+                BarAggregation::Millisecond
+                    | BarAggregation::Second
+                    | BarAggregation::Minute
+                    | BarAggregation::Hour
+                    | BarAggregation::Day
+            ),
+            "Invalid bar type {bar_type}: aggregation must be a fixed time interval"
+        );
+        let interval_ns = get_bar_interval_ns(&bar_type).as_u64();
+        anyhow::ensure!(
+            origin_ns < interval_ns,
+            "Invalid `origin_ns` {origin_ns}: must be less than the bar interval {interval_ns}"
+        );
+        Ok(Self {
+            bar_type,
+            interval_ns,
+            origin_ns,
+            policy,
+            price_precision,
+            size_precision,
+            open_ns: None,
+            current: None,
+            last_close: None,
+        })
+    }
+
+    /// Returns the bar type built.
+    #[must_use]
+    pub const fn bar_type(&self) -> BarType {
+        self.bar_type
+    }
+
+    /// Returns the empty bar policy.
+    #[must_use]
+    pub const fn policy(&self) -> EmptyBarPolicy {
+        self.policy
+    }
+
+    /// Returns the bar interval in nanoseconds.
+    #[must_use]
+    pub const fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    /// Returns the close time of the current interval, if aligned.
+    #[must_use]
+    pub fn next_close_ns(&self) -> Option<UnixNanos> {
+        self.open_ns.map(|open| open + self.interval_ns)
+    }
+
+    /// Returns the open time of the interval containing `ts`.
+    #[must_use]
+    pub fn interval_open(&self, ts: UnixNanos) -> UnixNanos {
+        let ts = ts.as_u64();
+        if ts < self.origin_ns {
+            return UnixNanos::default();
+        }
+        let offset = (ts - self.origin_ns) % self.interval_ns;
+        UnixNanos::from(ts - offset)
+    }
+
+    /// Aligns the builder to the interval containing `ts_now`, so that empty intervals are
+    /// closed from there on (no-op if already aligned).
+    pub fn start(&mut self, ts_now: UnixNanos) {
+        if self.open_ns.is_none() {
+            self.open_ns = Some(self.interval_open(ts_now));
+        }
+    }
+
+    /// Closes every interval ending at or before `ts_now`, returning the bars emitted.
+    ///
+    /// Call this from a clock timer at each boundary so bars are emitted without updates.
+    pub fn close_until(&mut self, ts_now: UnixNanos) -> Vec<Bar> {
+        let mut bars = Vec::new();
+        let Some(mut open) = self.open_ns else {
+            return bars;
+        };
+
+        while open + self.interval_ns <= ts_now {
+            let close_ns = open + self.interval_ns;
+            if let Some(bar) = self.close_interval(close_ns) {
+                bars.push(bar);
+            }
+            open = close_ns;
+
+            // Jump straight to the interval containing `ts_now` when nothing else is emitted
+            if self.policy == EmptyBarPolicy::Skip && open + self.interval_ns <= ts_now {
+                open = self.interval_open(ts_now);
+            }
+        }
+
+        self.open_ns = Some(open);
+        bars
+    }
+
+    fn close_interval(&mut self, close_ns: UnixNanos) -> Option<Bar> {
+        if let Some(bar) = self.current.take() {
+            self.last_close = Some(bar.close);
+            return Some(
+                self.new_bar(bar.open, bar.high, bar.low, bar.close, bar.volume, close_ns),
+            );
+        }
+
+        match self.policy {
+            EmptyBarPolicy::Skip => None,
+            EmptyBarPolicy::RepeatClose => self.last_close.map(|close| {
+                let volume = Quantity::zero(self.size_precision);
+                self.new_bar(close, close, close, close, volume, close_ns)
+            }),
+            EmptyBarPolicy::EmitEmpty => {
+                let zero = Price::zero(self.price_precision);
+                let volume = Quantity::zero(self.size_precision);
+                Some(self.new_bar(zero, zero, zero, zero, volume, close_ns))
+            }
+        }
+    }
+
+    fn new_bar(
+        &self,
+        open: Price,
+        high: Price,
+        low: Price,
+        close: Price,
+        volume: Quantity,
+        ts: UnixNanos,
+    ) -> Bar {
+        Bar::new(self.bar_type, open, high, low, close, volume, ts, ts)
+    }
+
+    /// Updates the builder with a price and size, returning any bars closed by the update.
+    ///
+    /// An update at or beyond the current boundary first closes the elapsed intervals; the
+    /// update then belongs to the interval containing `ts_event`.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        self.start(ts_event);
+        let bars = self.close_until(ts_event);
+
+        match self.current.as_mut() {
+            Some(bar) => {
+                bar.high = bar.high.max(price);
+                bar.low = bar.low.min(price);
+                bar.close = price;
+                bar.volume = bar.volume + size;
+            }
+            None => {
+                self.current = Some(PartialBar {
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: size,
+                });
+            }
+        }
+        bars
+    }
+
+    /// Handles a trade, returning any closed bars (`LAST` bar types only).
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Vec<Bar> {
+        if self.bar_type.spec().price_type != PriceType::Last {
+            return Vec::new();
+        }
+        self.update(trade.price, trade.size, trade.ts_event)
+    }
+
+    /// Handles a quote, returning any closed bars (`BID`/`ASK`/`MID` bar types only).
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> Vec<Bar> {
+        let price_type = self.bar_type.spec().price_type;
+        if price_type == PriceType::Last {
+            return Vec::new();
+        }
+        self.update(
+            quote.extract_price(price_type),
+            quote.extract_size(price_type),
+            quote.ts_event,
+        )
+    }
+
+    /// Resets the builder, discarding the partial bar, last close and alignment.
+    pub fn reset(&mut self) {
+        self.open_ns = None;
+        self.current = None;
+        self.last_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn builder(policy: EmptyBarPolicy) -> TimeBarBuilder {
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-LAST-INTERNAL");
+        TimeBarBuilder::new(bar_type, 5, 0, policy, 0).unwrap()
+    }
+
+    fn update(builder: &mut TimeBarBuilder, price: &str, ts: u64) -> Vec<Bar> {
+        builder.update(Price::from(price), Quantity::from(1), ts.into())
+    }
+
+    #[rstest]
+    fn test_update_closes_elapsed_bar() {
+        let mut builder = builder(EmptyBarPolicy::Skip);
+        assert!(update(&mut builder, "1.00000", SECOND / 2).is_empty());
+        assert!(update(&mut builder, "1.00010", SECOND * 3 / 4).is_empty());
+        assert_eq!(builder.next_close_ns(), Some(SECOND.into()));
+
+        let bars = update(&mut builder, "1.00020", SECOND);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("1.00000"));
+        assert_eq!(bars[0].close, Price::from("1.00010"));
+        assert_eq!(bars[0].volume, Quantity::from(2));
+        assert_eq!(bars[0].ts_event, UnixNanos::from(SECOND));
+    }
+
+    #[rstest]
+    fn test_timer_closes_bar_without_updates() {
+        let mut builder = builder(EmptyBarPolicy::Skip);
+        update(&mut builder, "1.00000", SECOND / 2);
+
+        let bars = builder.close_until(SECOND.into());
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ts_event, UnixNanos::from(SECOND));
+        assert!(builder.close_until((SECOND * 2).into()).is_empty());
+        assert_eq!(builder.next_close_ns(), Some((SECOND * 3).into()));
+    }
+
+    #[rstest]
+    #[case(EmptyBarPolicy::Skip, 1)]
+    #[case(EmptyBarPolicy::RepeatClose, 3)]
+    #[case(EmptyBarPolicy::EmitEmpty, 3)]
+    fn test_empty_bar_policies(#[case] policy: EmptyBarPolicy, #[case] expected: usize) {
+        let mut builder = builder(policy);
+        update(&mut builder, "1.00000", SECOND / 2);
+
+        let bars = builder.close_until((SECOND * 3).into());
+        assert_eq!(bars.len(), expected);
+        for bar in &bars[1..] {
+            assert_eq!(bar.volume, Quantity::from(0));
+            match policy {
+                EmptyBarPolicy::RepeatClose => assert_eq!(bar.close, Price::from("1.00000")),
+                EmptyBarPolicy::EmitEmpty => assert_eq!(bar.close, Price::zero(5)),
+                EmptyBarPolicy::Skip => unreachable!(),
+            }
+        }
+        let ts: Vec<u64> = bars.iter().map(|bar| bar.ts_event.as_u64()).collect();
+        assert_eq!(
+            ts,
+            (1..=expected as u64)
+                .map(|i| i * SECOND)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(builder.next_close_ns(), Some((SECOND * 4).into()));
+    }
+
+    #[rstest]
+    fn test_started_builder_emits_empty_bars_before_first_update() {
+        let mut repeat = builder(EmptyBarPolicy::RepeatClose);
+        repeat.start(UnixNanos::default());
+        assert!(repeat.close_until((SECOND * 2).into()).is_empty());
+
+        let mut empty = builder(EmptyBarPolicy::EmitEmpty);
+        empty.start(UnixNanos::default());
+        assert_eq!(empty.close_until((SECOND * 2).into()).len(), 2);
+    }
+
+    #[rstest]
+    fn test_rejects_non_time_bar_types() {
+        let bar_type = BarType::from("AUD/USD.SIM-100-TICK-LAST-INTERNAL");
+        assert!(TimeBarBuilder::new(bar_type, 5, 0, EmptyBarPolicy::Skip, 0).is_err());
+
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-LAST-EXTERNAL");
+        assert!(TimeBarBuilder::new(bar_type, 5, 0, EmptyBarPolicy::Skip, 0).is_err());
+
+        let bar_type = BarType::from("AUD/USD.SIM-1-SECOND-LAST-INTERNAL");
+        assert!(TimeBarBuilder::new(bar_type, 5, 0, EmptyBarPolicy::Skip, SECOND).is_err());
+    }
+}
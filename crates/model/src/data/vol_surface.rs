@@ -0,0 +1,394 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Implied volatility surfaces and static arbitrage checks.
+//!
+//! Surfaces are checked by converting each point to a Black-Scholes call price, which must be
+//! non-increasing and convex in strike for a fixed expiry, while total implied variance must be
+//! non-decreasing in expiry for a fixed strike.
+//!
+//! Within the engine, surfaces are only built and checked for batches published through
+//! `GreeksCalculator::publish_greeks_batch` in `nautilus-common`.
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use crate::data::greeks::{GreeksData, black_scholes_greeks_exact};
+
+/// Represents a single implied volatility quote on a surface.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolSurfacePoint {
+    /// The time to expiry in years.
+    pub expiry_in_years: f64,
+    /// The option strike price.
+    pub strike: f64,
+    /// The implied volatility.
+    pub vol: f64,
+}
+
+impl VolSurfacePoint {
+    /// Creates a new [`VolSurfacePoint`] instance.
+    #[must_use]
+    pub const fn new(expiry_in_years: f64, strike: f64, vol: f64) -> Self {
+        Self {
+            expiry_in_years,
+            strike,
+            vol,
+        }
+    }
+}
+
+/// The kind of static arbitrage detected on a volatility surface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolArbitrageKind {
+    /// Call prices increase with strike for a fixed expiry.
+    StrikeMonotonicity,
+    /// Call prices are not convex in strike for a fixed expiry (negative butterfly).
+    Butterfly,
+    /// Total implied variance decreases with expiry for a fixed strike.
+    Calendar,
+}
+
+/// Represents a static arbitrage violation found on a volatility surface.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolArbitrageViolation {
+    /// The kind of violation.
+    pub kind: VolArbitrageKind,
+    /// The expiries (in years) of the offending points.
+    pub expiries_in_years: Vec<f64>,
+    /// The strikes of the offending points.
+    pub strikes: Vec<f64>,
+    /// The size of the violation beyond the tolerance (in price or variance units).
+    pub magnitude: f64,
+}
+
+impl Display for VolArbitrageViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} arbitrage: expiries={:?}, strikes={:?}, magnitude={:.6}",
+            self.kind, self.expiries_in_years, self.strikes, self.magnitude,
+        )
+    }
+}
+
+/// Represents an implied volatility surface for a single underlying.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VolSurface {
+    /// UNIX timestamp (nanoseconds) when the surface was observed.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the instance was initialized.
+    pub ts_init: UnixNanos,
+    /// The underlying price.
+    pub underlying_price: f64,
+    /// The risk-free interest rate.
+    pub interest_rate: f64,
+    /// The cost of carry.
+    pub cost_of_carry: f64,
+    /// The implied volatility points.
+    pub points: Vec<VolSurfacePoint>,
+}
+
+impl VolSurface {
+    /// Creates a new [`VolSurface`] instance.
+    #[must_use]
+    pub const fn new(
+        underlying_price: f64,
+        interest_rate: f64,
+        cost_of_carry: f64,
+        points: Vec<VolSurfacePoint>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            ts_event,
+            ts_init,
+            underlying_price,
+            interest_rate,
+            cost_of_carry,
+            points,
+        }
+    }
+
+    /// Creates a surface from a batch of greeks for options on a single underlying.
+    ///
+    /// Market parameters are taken from the most recent greeks in the batch. The surface holds
+    /// one point per (expiry, strike): when both a call and a put are quoted there, the
+    /// out-of-the-money option is used (the call at or above the underlying price, the put
+    /// below it), as its implied volatility is the more reliable quote. Repeated quotes for the
+    /// same option keep the most recent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `greeks` is empty.
+    pub fn from_greeks(greeks: &[GreeksData]) -> anyhow::Result<Self> {
+        let Some(latest) = greeks.iter().max_by_key(|g| g.ts_event) else {
+            anyhow::bail!("Cannot create `VolSurface` from empty greeks");
+        };
+
+        let is_otm = |g: &GreeksData| g.is_call == (g.strike >= latest.underlying_price);
+
+        let mut selected: IndexMap<(u64, u64), &GreeksData> = IndexMap::new();
+        for g in greeks {
+            let key = (g.expiry_in_years.to_bits(), g.strike.to_bits());
+            match selected.get_mut(&key) {
+                Some(current) => {
+                    let replace = if is_otm(g) == is_otm(current) {
+                        g.ts_event >= current.ts_event
+                    } else {
+                        is_otm(g)
+                    };
+                    if replace {
+                        *current = g;
+                    }
+                }
+                None => {
+                    selected.insert(key, g);
+                }
+            }
+        }
+
+        let points = selected
+            .values()
+            .map(|g| VolSurfacePoint::new(g.expiry_in_years, g.strike, g.vol))
+            .collect();
+
+        Ok(Self::new(
+            latest.underlying_price,
+            latest.interest_rate,
+            latest.cost_of_carry,
+            points,
+            latest.ts_event,
+            latest.ts_init,
+        ))
+    }
+
+    /// Checks the surface for static arbitrage, returning any violations found.
+    ///
+    /// Strike monotonicity and butterfly checks compare call prices against `price_tolerance`,
+    /// the calendar check compares total implied variance against `variance_tolerance`. The
+    /// calendar check is made at fixed strike, which is exact when the cost of carry is zero.
+    #[must_use]
+    pub fn check_arbitrage(
+        &self,
+        price_tolerance: f64,
+        variance_tolerance: f64,
+    ) -> Vec<VolArbitrageViolation> {
+        let mut points: Vec<VolSurfacePoint> = self
+            .points
+            .iter()
+            .filter(|p| p.expiry_in_years > 0.0 && p.strike > 0.0 && p.vol.is_finite())
+            .copied()
+            .collect();
+        points.sort_by(|a, b| {
+            a.expiry_in_years
+                .total_cmp(&b.expiry_in_years)
+                .then(a.strike.total_cmp(&b.strike))
+        });
+
+        let mut violations = Vec::new();
+
+        for slice in points.chunk_by(|a, b| a.expiry_in_years == b.expiry_in_years) {
+            self.check_slice(slice, price_tolerance, &mut violations);
+        }
+
+        let mut by_strike = points.clone();
+        by_strike.sort_by(|a, b| {
+            a.strike
+                .total_cmp(&b.strike)
+                .then(a.expiry_in_years.total_cmp(&b.expiry_in_years))
+        });
+        for strip in by_strike.chunk_by(|a, b| a.strike == b.strike) {
+            for pair in strip.windows(2) {
+                let (near, far) = (pair[0], pair[1]);
+                let excess = total_variance(near) - total_variance(far) - variance_tolerance;
+                if excess > 0.0 {
+                    violations.push(VolArbitrageViolation {
+                        kind: VolArbitrageKind::Calendar,
+                        expiries_in_years: vec![near.expiry_in_years, far.expiry_in_years],
+                        strikes: vec![near.strike],
+                        magnitude: excess,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn check_slice(
+        &self,
+        slice: &[VolSurfacePoint],
+        price_tolerance: f64,
+        violations: &mut Vec<VolArbitrageViolation>,
+    ) {
+        let prices: Vec<f64> = slice.iter().map(|p| self.call_price(p)).collect();
+        let expiry = slice[0].expiry_in_years;
+
+        for i in 0..slice.len().saturating_sub(1) {
+            let excess = prices[i + 1] - prices[i] - price_tolerance;
+            if excess > 0.0 {
+                violations.push(VolArbitrageViolation {
+                    kind: VolArbitrageKind::StrikeMonotonicity,
+                    expiries_in_years: vec![expiry],
+                    strikes: vec![slice[i].strike, slice[i + 1].strike],
+                    magnitude: excess,
+                });
+            }
+        }
+
+        for i in 0..slice.len().saturating_sub(2) {
+            let (k1, k2, k3) = (slice[i].strike, slice[i + 1].strike, slice[i + 2].strike);
+            let weight = (k3 - k2) / (k3 - k1);
+            let interpolated = weight * prices[i] + (1.0 - weight) * prices[i + 2];
+            let excess = prices[i + 1] - interpolated - price_tolerance;
+            if excess > 0.0 {
+                violations.push(VolArbitrageViolation {
+                    kind: VolArbitrageKind::Butterfly,
+                    expiries_in_years: vec![expiry],
+                    strikes: vec![k1, k2, k3],
+                    magnitude: excess,
+                });
+            }
+        }
+    }
+
+    fn call_price(&self, point: &VolSurfacePoint) -> f64 {
+        black_scholes_greeks_exact(
+            self.underlying_price,
+            self.interest_rate,
+            self.cost_of_carry,
+            point.vol,
+            true,
+            point.strike,
+            point.expiry_in_years,
+            1.0,
+        )
+        .price
+    }
+}
+
+fn total_variance(point: VolSurfacePoint) -> f64 {
+    point.vol * point.vol * point.expiry_in_years
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn surface(points: Vec<VolSurfacePoint>) -> VolSurface {
+        VolSurface::new(100.0, 0.0, 0.0, points, 0.into(), 0.into())
+    }
+
+    #[rstest]
+    fn test_flat_surface_has_no_violations() {
+        let mut points = Vec::new();
+        for expiry in [0.25, 0.5, 1.0] {
+            for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+                points.push(VolSurfacePoint::new(expiry, strike, 0.2));
+            }
+        }
+
+        assert!(surface(points).check_arbitrage(1e-9, 1e-12).is_empty());
+    }
+
+    #[rstest]
+    fn test_butterfly_violation_detected() {
+        // A spike in vol at the middle strike makes the call price concave
+        let points = vec![
+            VolSurfacePoint::new(0.5, 95.0, 0.2),
+            VolSurfacePoint::new(0.5, 100.0, 0.6),
+            VolSurfacePoint::new(0.5, 105.0, 0.2),
+        ];
+
+        let violations = surface(points).check_arbitrage(1e-9, 1e-12);
+
+        assert!(violations.iter().any(
+            |v| v.kind == VolArbitrageKind::Butterfly && v.strikes == vec![95.0, 100.0, 105.0]
+        ));
+    }
+
+    #[rstest]
+    fn test_strike_monotonicity_violation_detected() {
+        let points = vec![
+            VolSurfacePoint::new(0.5, 100.0, 0.1),
+            VolSurfacePoint::new(0.5, 101.0, 1.0),
+        ];
+
+        let violations = surface(points).check_arbitrage(1e-9, 1e-12);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].kind, VolArbitrageKind::StrikeMonotonicity);
+        assert_eq!(violations[0].strikes, vec![100.0, 101.0]);
+    }
+
+    #[rstest]
+    fn test_calendar_violation_detected() {
+        let points = vec![
+            VolSurfacePoint::new(0.25, 100.0, 0.5),
+            VolSurfacePoint::new(1.0, 100.0, 0.2),
+        ];
+
+        let violations = surface(points).check_arbitrage(1e-9, 1e-12);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].kind, VolArbitrageKind::Calendar);
+        assert_eq!(violations[0].expiries_in_years, vec![0.25, 1.0]);
+        assert!((violations[0].magnitude - (0.0625 - 0.04)).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_from_greeks_empty_errors() {
+        assert!(VolSurface::from_greeks(&[]).is_err());
+    }
+
+    fn greeks(is_call: bool, strike: f64, vol: f64) -> GreeksData {
+        GreeksData {
+            is_call,
+            strike,
+            expiry_in_years: 0.5,
+            underlying_price: 100.0,
+            vol,
+            ..GreeksData::default()
+        }
+    }
+
+    #[rstest]
+    fn test_from_greeks_keeps_otm_option_per_strike() {
+        let batch = vec![
+            greeks(true, 90.0, 0.30),
+            greeks(false, 90.0, 0.25),
+            greeks(false, 110.0, 0.35),
+            greeks(true, 110.0, 0.20),
+            greeks(true, 120.0, 0.22),
+        ];
+
+        let surface = VolSurface::from_greeks(&batch).unwrap();
+
+        assert_eq!(
+            surface.points,
+            vec![
+                VolSurfacePoint::new(0.5, 90.0, 0.25),
+                VolSurfacePoint::new(0.5, 110.0, 0.20),
+                VolSurfacePoint::new(0.5, 120.0, 0.22),
+            ]
+        );
+    }
+}
@@ -298,6 +298,10 @@ pub enum BarAggregation {
     Year = 17,
     /// Based on fixed price movements (brick size).
     Renko = 18,
+    /// Based on the Heikin-Ashi transformation of a source bar type.
+    HeikinAshi = 19,
+    /// Based on a fixed high-low price range.
+    Range = 20,
 }
 
 /// The interval type for bar aggregation.
@@ -0,0 +1,352 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Versioned golden-file regression suite for serialization formats.
+//!
+//! Representative instances of the public model types are serialized to JSON, MsgPack and
+//! (with the `arrow` feature) Arrow, and compared byte-for-byte against fixtures checked in
+//! under `tests/golden/v{GOLDEN_VERSION}`. Any difference fails the test, so wire-format
+//! changes are always deliberate.
+//!
+//! # Migration path
+//!
+//! When a format change is intended:
+//!
+//! 1. Bump [`GOLDEN_VERSION`].
+//! 2. Run the suite with `UPDATE_GOLDEN=1` to write the new `v{N}` fixtures, then
+//!    commit them alongside the change. Earlier versions are kept untouched.
+//! 3. Fixtures from every version in `MIN_DECODABLE_GOLDEN_VERSION..GOLDEN_VERSION` must still
+//!    decode, so readers stay backward compatible. Dropping support for an old format requires
+//!    raising [`MIN_DECODABLE_GOLDEN_VERSION`] explicitly.
+//!
+//! A missing fixture fails the test like a mismatched one; fixtures are only ever written when
+//! `UPDATE_GOLDEN=1` is set.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use nautilus_core::{
+    UnixNanos,
+    serialization::{FromMsgPack, Serializable, ToMsgPack},
+};
+use rstest::rstest;
+use rust_decimal_macros::dec;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    data::{
+        BarType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, PriceBandKind,
+        PriceBandUpdate,
+        stubs::{
+            quote_ethusdt_binance, stub_bar, stub_book_order, stub_delta, stub_depth10,
+            stub_instrument_close, stub_instrument_status, stub_trade_ethusdt_buyer,
+        },
+    },
+    events::order::stubs::{
+        client_order_id, instrument_id_btc_usdt, order_filled, strategy_id_ema_cross, trader_id,
+        uuid4,
+    },
+    identifiers::InstrumentId,
+    instruments::{
+        InstrumentAny,
+        stubs::{crypto_perpetual_ethusdt, currency_pair_btcusdt, equity_aapl},
+    },
+    types::{Money, Price, Quantity},
+};
+
+/// The current golden-file format version.
+const GOLDEN_VERSION: u32 = 1;
+
+/// The oldest golden-file version whose fixtures must still decode.
+const MIN_DECODABLE_GOLDEN_VERSION: u32 = 1;
+
+const _: () = assert!(MIN_DECODABLE_GOLDEN_VERSION <= GOLDEN_VERSION);
+
+/// Environment variable which, when set to `1`, (re)writes the current version's fixtures.
+const UPDATE_ENV: &str = "UPDATE_GOLDEN";
+
+#[derive(Clone, Copy, Debug)]
+enum GoldenFormat {
+    Json,
+    MsgPack,
+    #[cfg(feature = "arrow")]
+    Arrow,
+}
+
+impl GoldenFormat {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::MsgPack => "msgpack",
+            #[cfg(feature = "arrow")]
+            Self::Arrow => "arrow.txt",
+        }
+    }
+}
+
+fn golden_dir(version: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("v{version}"))
+}
+
+fn golden_path(version: u32, name: &str, format: GoldenFormat) -> PathBuf {
+    golden_dir(version).join(format!("{name}.{}", format.extension()))
+}
+
+fn assert_golden(name: &str, format: GoldenFormat, actual: &[u8]) {
+    let path = golden_path(GOLDEN_VERSION, name, format);
+
+    if std::env::var(UPDATE_ENV).is_ok_and(|v| v == "1") {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "Cannot read golden file {} ({e}), run with {UPDATE_ENV}=1 and commit the result",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "Wire format changed for `{name}` ({format:?}), compare against {}.\n\
+         If the change is intended, bump `GOLDEN_VERSION` and run with {UPDATE_ENV}=1",
+        path.display()
+    );
+}
+
+fn previous_versions(name: &str, format: GoldenFormat) -> impl Iterator<Item = (u32, Vec<u8>)> {
+    (MIN_DECODABLE_GOLDEN_VERSION..GOLDEN_VERSION).filter_map(move |version| {
+        // Types added after a version have no fixture for it
+        let path = golden_path(version, name, format);
+        path.exists().then(|| (version, fs::read(path).unwrap()))
+    })
+}
+
+fn check_json<T: Serialize + DeserializeOwned>(name: &str, value: &T) {
+    let json = serde_json::to_vec_pretty(value).unwrap();
+    assert_golden(name, GoldenFormat::Json, &json);
+
+    let decoded: T = serde_json::from_slice(&json).unwrap();
+    assert_eq!(
+        serde_json::to_vec_pretty(&decoded).unwrap(),
+        json,
+        "JSON round-trip for `{name}` is not stable"
+    );
+
+    for (version, bytes) in previous_versions(name, GoldenFormat::Json) {
+        if let Err(e) = serde_json::from_slice::<T>(&bytes) {
+            panic!("v{version} JSON fixture for `{name}` no longer decodes: {e}");
+        }
+    }
+}
+
+fn check_msgpack<T: Serializable>(name: &str, value: &T) {
+    let bytes = value.to_msgpack_bytes().unwrap();
+    assert_golden(name, GoldenFormat::MsgPack, &bytes);
+
+    let decoded = T::from_msgpack_bytes(&bytes).unwrap();
+    assert_eq!(
+        decoded.to_msgpack_bytes().unwrap(),
+        bytes,
+        "MsgPack round-trip for `{name}` is not stable"
+    );
+
+    for (version, bytes) in previous_versions(name, GoldenFormat::MsgPack) {
+        if let Err(e) = T::from_msgpack_bytes(&bytes) {
+            panic!("v{version} MsgPack fixture for `{name}` no longer decodes: {e}");
+        }
+    }
+}
+
+fn check_serializable<T: Serializable>(name: &str, value: &T) {
+    check_json(name, value);
+    check_msgpack(name, value);
+}
+
+#[cfg(feature = "arrow")]
+fn check_arrow(name: &str, batch: &arrow::record_batch::RecordBatch) {
+    use std::fmt::Write;
+
+    use arrow::{
+        array::{Array, AsArray},
+        datatypes::{DataType, Float64Type, TimestampNanosecondType, UInt8Type},
+    };
+
+    // IPC bytes embed schema metadata in hash map order, and arrow's own pretty printers are
+    // not stable across releases, so compare a canonical rendering of the values instead
+    let schema = batch.schema();
+    let mut metadata: Vec<_> = schema.metadata().iter().collect();
+    metadata.sort();
+
+    let mut rendered = String::new();
+    for (key, value) in metadata {
+        writeln!(rendered, "metadata {key}={value}").unwrap();
+    }
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let values: Vec<String> = match field.data_type() {
+            DataType::Float64 => column
+                .as_primitive::<Float64Type>()
+                .values()
+                .iter()
+                .map(|v| format!("{v:?}"))
+                .collect(),
+            DataType::UInt8 => column
+                .as_primitive::<UInt8Type>()
+                .values()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            DataType::Timestamp(..) => column
+                .as_primitive::<TimestampNanosecondType>()
+                .values()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            DataType::Utf8 => column
+                .as_string::<i32>()
+                .iter()
+                .map(|v| format!("{:?}", v.unwrap()))
+                .collect(),
+            other => panic!("No golden rendering for column type {other:?}"),
+        };
+        writeln!(
+            rendered,
+            "field {}: {:?} nullable={} nulls={}",
+            field.name(),
+            field.data_type(),
+            field.is_nullable(),
+            column.null_count()
+        )
+        .unwrap();
+        writeln!(rendered, "  [{}]", values.join(", ")).unwrap();
+    }
+
+    assert_golden(name, GoldenFormat::Arrow, rendered.as_bytes());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Golden tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[rstest]
+fn test_golden_value_types() {
+    check_json("price", &Price::from("1234.5678"));
+    check_json("price_negative", &Price::from("-0.25"));
+    check_json("quantity", &Quantity::from("0.000001"));
+    check_json("money", &Money::from("12.20 USDT"));
+    check_json("instrument_id", &InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+    check_json(
+        "bar_type",
+        &BarType::from_str("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL").unwrap(),
+    );
+}
+
+#[rstest]
+fn test_golden_market_data() {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let ts = UnixNanos::from(1_700_000_000_000_000_000);
+
+    check_serializable("quote_tick", &quote_ethusdt_binance());
+    check_serializable("trade_tick", &stub_trade_ethusdt_buyer());
+    check_serializable("bar", &stub_bar());
+    check_serializable("book_order", &stub_book_order());
+    check_serializable("order_book_delta", &stub_delta());
+    check_serializable("order_book_depth10", &stub_depth10());
+    check_serializable("instrument_status", &stub_instrument_status());
+    check_serializable("instrument_close", &stub_instrument_close());
+    check_serializable(
+        "mark_price_update",
+        &MarkPriceUpdate::new(instrument_id, Price::from("2500.25"), ts, ts),
+    );
+    check_serializable(
+        "index_price_update",
+        &IndexPriceUpdate::new(instrument_id, Price::from("2500.10"), ts, ts),
+    );
+    check_serializable(
+        "funding_rate_update",
+        &FundingRateUpdate::new(instrument_id, dec!(0.0001), Some(ts), ts, ts),
+    );
+    check_serializable(
+        "price_band_update",
+        &PriceBandUpdate::new(
+            instrument_id,
+            PriceBandKind::DailyLimit,
+            Price::from("2250.00"),
+            Price::from("2750.00"),
+            ts,
+            ts,
+        ),
+    );
+}
+
+#[rstest]
+fn test_golden_instruments() {
+    check_json(
+        "instrument_crypto_perpetual",
+        &InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt()),
+    );
+    check_json(
+        "instrument_currency_pair",
+        &InstrumentAny::CurrencyPair(currency_pair_btcusdt()),
+    );
+    check_json("instrument_equity", &InstrumentAny::Equity(equity_aapl()));
+}
+
+#[rstest]
+fn test_golden_events() {
+    let filled = order_filled(
+        trader_id(),
+        strategy_id_ema_cross(),
+        instrument_id_btc_usdt(),
+        client_order_id(),
+        uuid4(),
+    );
+    check_json("order_filled", &filled);
+}
+
+#[cfg(feature = "arrow")]
+#[rstest]
+fn test_golden_arrow_batches() {
+    use crate::data::arrow::{
+        bars_to_record_batch, quotes_to_record_batch, trades_to_record_batch,
+    };
+
+    check_arrow(
+        "quotes_batch",
+        &quotes_to_record_batch(&[quote_ethusdt_binance()]).unwrap(),
+    );
+    check_arrow(
+        "trades_batch",
+        &trades_to_record_batch(&[stub_trade_ethusdt_buyer()]).unwrap(),
+    );
+    check_arrow("bars_batch", &bars_to_record_batch(&[stub_bar()]).unwrap());
+}
+
+#[rstest]
+fn test_golden_path_layout() {
+    assert_eq!(
+        golden_path(2, "quote_tick", GoldenFormat::MsgPack)
+            .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+            .unwrap(),
+        Path::new("tests/golden/v2/quote_tick.msgpack")
+    );
+}
@@ -27,7 +27,9 @@ pub mod futures_contract;
 pub mod futures_spread;
 pub mod option_contract;
 pub mod option_spread;
+pub mod schedule;
 pub mod synthetic;
+pub mod tick_table;
 
 #[cfg(any(test, feature = "stubs"))]
 pub mod stubs;
@@ -0,0 +1,326 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Funding and borrow accrual schedules for instruments.
+//!
+//! An [`AccrualSchedule`] defines the UTC times of day at which an accrual occurs, for example
+//! funding every 8 hours at 00:00/08:00/16:00 or borrow cost daily at 00:00. Schedules are
+//! attached to an instrument through an [`InstrumentDefinition`], which serializes together with
+//! the instrument itself, along with any variable tick size table.
+
+use nautilus_core::{
+    UnixNanos,
+    correctness::{FAILED, check_predicate_true},
+    datetime::NANOSECONDS_IN_SECOND,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::OrderSide,
+    identifiers::InstrumentId,
+    instruments::{
+        Instrument, InstrumentAny,
+        tick_table::{PriceRounding, TickSizeTable, TickTier},
+    },
+    types::Price,
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// The kind of periodic accrual an [`AccrualSchedule`] drives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AccrualKind {
+    /// Perpetual swap funding payments.
+    Funding,
+    /// Borrow cost for short or leveraged positions.
+    Borrow,
+}
+
+/// A daily schedule of accrual times, defined by UTC time-of-day offsets.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccrualSchedule {
+    /// The kind of accrual.
+    pub kind: AccrualKind,
+    /// The accrual times (nanoseconds after UTC midnight), strictly ascending.
+    pub times_of_day_ns: Vec<u64>,
+}
+
+impl AccrualSchedule {
+    /// Creates a new [`AccrualSchedule`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `times_of_day_ns` is empty, not strictly ascending, or any time is
+    /// not within one day.
+    pub fn new_checked(kind: AccrualKind, times_of_day_ns: Vec<u64>) -> anyhow::Result<Self> {
+        check_predicate_true(
+            !times_of_day_ns.is_empty(),
+            "`times_of_day_ns` must not be empty",
+        )?;
+        check_predicate_true(
+            times_of_day_ns.windows(2).all(|w| w[0] < w[1]),
+            "`times_of_day_ns` must be strictly ascending",
+        )?;
+        check_predicate_true(
+            times_of_day_ns.iter().all(|t| *t < NANOSECONDS_IN_DAY),
+            "`times_of_day_ns` must be within one day",
+        )?;
+
+        Ok(Self {
+            kind,
+            times_of_day_ns,
+        })
+    }
+
+    /// Creates a new [`AccrualSchedule`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `times_of_day_ns` is empty, not strictly ascending, or any time is not within
+    /// one day.
+    #[must_use]
+    pub fn new(kind: AccrualKind, times_of_day_ns: Vec<u64>) -> Self {
+        Self::new_checked(kind, times_of_day_ns).expect(FAILED)
+    }
+
+    /// Creates a schedule accruing every `interval_ns` from `offset_ns` after UTC midnight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval_ns` is zero or `offset_ns` is not within one day.
+    #[must_use]
+    pub fn every(kind: AccrualKind, interval_ns: u64, offset_ns: u64) -> Self {
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        let first = offset_ns % interval_ns;
+        let times = (first..NANOSECONDS_IN_DAY)
+            .step_by(interval_ns as usize)
+            .collect();
+        Self::new(kind, times)
+    }
+
+    /// Returns the standard perpetual funding schedule, every 8 hours at 00/08/16 UTC.
+    #[must_use]
+    pub fn funding_8h() -> Self {
+        Self::every(AccrualKind::Funding, 8 * 3_600 * NANOSECONDS_IN_SECOND, 0)
+    }
+
+    /// Returns a daily borrow accrual schedule at 00:00 UTC.
+    #[must_use]
+    pub fn borrow_daily() -> Self {
+        Self::new(AccrualKind::Borrow, vec![0])
+    }
+
+    /// Returns the first accrual time strictly after `ts`.
+    #[must_use]
+    pub fn next_after(&self, ts: UnixNanos) -> UnixNanos {
+        let ts = ts.as_u64();
+        let day_start = ts - ts % NANOSECONDS_IN_DAY;
+        let offset = ts - day_start;
+        let next = match self.times_of_day_ns.iter().find(|t| **t > offset) {
+            Some(t) => day_start + t,
+            None => day_start + NANOSECONDS_IN_DAY + self.times_of_day_ns[0],
+        };
+        UnixNanos::from(next)
+    }
+
+    /// Returns the accrual times in the interval `(start, end]`.
+    #[must_use]
+    pub fn occurrences(&self, start: UnixNanos, end: UnixNanos) -> Vec<UnixNanos> {
+        let mut times = Vec::new();
+        let mut next = self.next_after(start);
+        while next <= end {
+            times.push(next);
+            next = self.next_after(next);
+        }
+        times
+    }
+}
+
+/// An instrument definition together with its accrual schedules and tick size table.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InstrumentDefinition {
+    /// The instrument.
+    pub instrument: InstrumentAny,
+    /// The accrual schedules attached to the instrument.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedules: Vec<AccrualSchedule>,
+    /// The variable tick size table, replacing the fixed price increment when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_table: Option<TickSizeTable>,
+}
+
+impl InstrumentDefinition {
+    /// Creates a new [`InstrumentDefinition`] instance.
+    #[must_use]
+    pub const fn new(instrument: InstrumentAny, schedules: Vec<AccrualSchedule>) -> Self {
+        Self {
+            instrument,
+            schedules,
+            tick_table: None,
+        }
+    }
+
+    /// Returns the definition with the variable tick size `table` attached.
+    #[must_use]
+    pub fn with_tick_table(mut self, table: TickSizeTable) -> Self {
+        self.tick_table = Some(table);
+        self
+    }
+
+    /// Returns the instrument ID.
+    #[must_use]
+    pub fn id(&self) -> InstrumentId {
+        self.instrument.id()
+    }
+
+    /// Returns the schedule of the given `kind`, if attached.
+    #[must_use]
+    pub fn schedule(&self, kind: AccrualKind) -> Option<&AccrualSchedule> {
+        self.schedules.iter().find(|s| s.kind == kind)
+    }
+
+    /// Rounds `price` to the valid price grid of the instrument, using the tick size table if
+    /// attached, otherwise the fixed price increment.
+    #[must_use]
+    pub fn round_to_valid_price(&self, price: Price, rounding: PriceRounding) -> Price {
+        let rounded = match &self.tick_table {
+            Some(table) => table.round_to_valid_price(price, rounding),
+            None => TickSizeTable::new(vec![TickTier {
+                start: Price::zero(price.precision),
+                increment: self.instrument.price_increment(),
+            }])
+            .round_to_valid_price(price, rounding),
+        };
+        Price::from_raw(rounded.raw, self.instrument.price_precision())
+    }
+
+    /// Returns whether `price` is on the valid price grid of the instrument.
+    #[must_use]
+    pub fn is_valid_price(&self, price: Price) -> bool {
+        self.round_to_valid_price(price, PriceRounding::Down).raw == price.raw
+    }
+
+    /// Snaps `price` for an order on `side` to the valid price grid, rounding passively (buys
+    /// down, sells up) so a modified order never becomes more aggressive than requested.
+    #[must_use]
+    pub fn snap_order_price(&self, side: OrderSide, price: Price) -> Price {
+        let rounding = match side {
+            OrderSide::Buy => PriceRounding::Down,
+            OrderSide::Sell => PriceRounding::Up,
+            OrderSide::NoOrderSide => PriceRounding::Nearest,
+        };
+        self.round_to_valid_price(price, rounding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::stubs::audusd_sim;
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+
+    #[rstest]
+    fn test_funding_8h_times() {
+        let schedule = AccrualSchedule::funding_8h();
+        assert_eq!(schedule.times_of_day_ns, vec![0, 8 * HOUR, 16 * HOUR]);
+    }
+
+    #[rstest]
+    #[case(0, 8 * HOUR)]
+    #[case(8 * HOUR - 1, 8 * HOUR)]
+    #[case(8 * HOUR, 16 * HOUR)]
+    #[case(17 * HOUR, 24 * HOUR)]
+    fn test_next_after(#[case] ts: u64, #[case] expected: u64) {
+        let schedule = AccrualSchedule::funding_8h();
+        assert_eq!(schedule.next_after(ts.into()), UnixNanos::from(expected));
+    }
+
+    #[rstest]
+    fn test_occurrences_spans_days() {
+        let schedule = AccrualSchedule::borrow_daily();
+        let times = schedule.occurrences(0.into(), (3 * 24 * HOUR).into());
+        assert_eq!(times.len(), 3);
+        assert_eq!(times[2], UnixNanos::from(3 * 24 * HOUR));
+    }
+
+    #[rstest]
+    fn test_new_checked_rejects_unordered_times() {
+        assert!(AccrualSchedule::new_checked(AccrualKind::Funding, vec![8 * HOUR, 0]).is_err());
+        assert!(AccrualSchedule::new_checked(AccrualKind::Funding, vec![]).is_err());
+        assert!(AccrualSchedule::new_checked(AccrualKind::Funding, vec![24 * HOUR]).is_err());
+    }
+
+    #[rstest]
+    fn test_definition_serde_round_trip() {
+        let definition = InstrumentDefinition::new(
+            InstrumentAny::CurrencyPair(audusd_sim()),
+            vec![
+                AccrualSchedule::funding_8h(),
+                AccrualSchedule::borrow_daily(),
+            ],
+        );
+        let json = serde_json::to_string(&definition).unwrap();
+        let decoded: InstrumentDefinition = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.id(), definition.id());
+        assert_eq!(decoded.schedules, definition.schedules);
+        assert!(decoded.schedule(AccrualKind::Borrow).is_some());
+        assert!(decoded.tick_table.is_none());
+    }
+
+    #[rstest]
+    fn test_snap_order_price_with_tick_table() {
+        let table = TickSizeTable::new(vec![
+            TickTier {
+                start: Price::from("0.00000"),
+                increment: Price::from("0.00010"),
+            },
+            TickTier {
+                start: Price::from("1.00000"),
+                increment: Price::from("0.00050"),
+            },
+        ]);
+        let definition =
+            InstrumentDefinition::new(InstrumentAny::CurrencyPair(audusd_sim()), vec![])
+                .with_tick_table(table);
+
+        let price = Price::from("1.00020");
+        assert!(!definition.is_valid_price(price));
+        assert_eq!(
+            definition.snap_order_price(OrderSide::Buy, price),
+            Price::from("1.00000")
+        );
+        assert_eq!(
+            definition.snap_order_price(OrderSide::Sell, price),
+            Price::from("1.00050")
+        );
+
+        let json = serde_json::to_string(&definition).unwrap();
+        let decoded: InstrumentDefinition = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.tick_table, definition.tick_table);
+    }
+
+    #[rstest]
+    fn test_round_without_tick_table_uses_price_increment() {
+        let definition =
+            InstrumentDefinition::new(InstrumentAny::CurrencyPair(audusd_sim()), vec![]);
+        assert_eq!(
+            definition.round_to_valid_price(Price::from("1.000014"), PriceRounding::Nearest),
+            Price::from("1.00001")
+        );
+    }
+}
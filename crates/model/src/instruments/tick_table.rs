@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Variable tick size tables (price increment schedules by price band).
+//!
+//! Some venues use a tick size which depends on the price, for example 0.001 below 1.00, 0.01
+//! from 1.00 and 0.05 from 100.00. A [`TickSizeTable`] holds these tiers and rounds prices to
+//! the valid grid. Tables are attached to an instrument through its
+//! [`InstrumentDefinition`](super::schedule::InstrumentDefinition).
+
+use std::fmt::Display;
+
+use nautilus_core::correctness::{FAILED, check_predicate_true};
+use serde::{Deserialize, Serialize};
+
+use super::TickSchemeRule;
+use crate::types::{Price, price::PriceRaw};
+
+/// The direction to round a price to the valid price grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PriceRounding {
+    /// Round to the nearest valid price (half up).
+    #[default]
+    Nearest,
+    /// Round down to the valid price at or below.
+    Down,
+    /// Round up to the valid price at or above.
+    Up,
+}
+
+/// A tier of a [`TickSizeTable`], applying from its start price up to the next tier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TickTier {
+    /// The price from which the tier applies (inclusive).
+    pub start: Price,
+    /// The tick size within the tier.
+    pub increment: Price,
+}
+
+/// A schedule of tick sizes by price tier.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TickSizeTable {
+    tiers: Vec<TickTier>,
+}
+
+impl TickSizeTable {
+    /// Creates a new [`TickSizeTable`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `tiers` is empty, or the first tier does not start at zero.
+    /// - Tier starts are not strictly ascending.
+    /// - Any increment is not positive.
+    /// - Any tier start is not on the price grid of the tier below.
+    pub fn new_checked(tiers: Vec<TickTier>) -> anyhow::Result<Self> {
+        check_predicate_true(!tiers.is_empty(), "`tiers` must not be empty")?;
+        check_predicate_true(tiers[0].start.raw == 0, "first tier must start at zero")?;
+        check_predicate_true(
+            tiers.iter().all(|tier| tier.increment.is_positive()),
+            "tier increments must be positive",
+        )?;
+        for pair in tiers.windows(2) {
+            check_predicate_true(
+                pair[0].start < pair[1].start,
+                "tier starts must be strictly ascending",
+            )?;
+            check_predicate_true(
+                (pair[1].start.raw - pair[0].start.raw) % pair[0].increment.raw == 0,
+                "tier starts must be on the price grid of the tier below",
+            )?;
+        }
+
+        Ok(Self { tiers })
+    }
+
+    /// Creates a new [`TickSizeTable`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tiers are invalid (see [`TickSizeTable::new_checked`]).
+    #[must_use]
+    pub fn new(tiers: Vec<TickTier>) -> Self {
+        Self::new_checked(tiers).expect(FAILED)
+    }
+
+    /// Returns the tiers, in ascending order of start price.
+    #[must_use]
+    pub fn tiers(&self) -> &[TickTier] {
+        &self.tiers
+    }
+
+    /// Returns the precision of the finest tick size.
+    #[must_use]
+    pub fn precision(&self) -> u8 {
+        self.tiers
+            .iter()
+            .map(|tier| tier.increment.precision)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn tier_index(&self, raw: PriceRaw) -> usize {
+        self.tiers
+            .partition_point(|tier| tier.start.raw <= raw)
+            .saturating_sub(1)
+    }
+
+    /// Returns the tick size applying at `price`.
+    #[must_use]
+    pub fn increment_for(&self, price: Price) -> Price {
+        self.tiers[self.tier_index(price.raw)].increment
+    }
+
+    /// Returns whether `price` is on the valid price grid.
+    #[must_use]
+    pub fn is_valid_price(&self, price: Price) -> bool {
+        self.round_to_valid_price(price, PriceRounding::Down).raw == price.raw
+    }
+
+    /// Rounds `price` to the valid price grid in the direction of `rounding`.
+    #[must_use]
+    pub fn round_to_valid_price(&self, price: Price, rounding: PriceRounding) -> Price {
+        let precision = price.precision.max(self.precision());
+        let index = self.tier_index(price.raw);
+        let tier = self.tiers[index];
+        let step = tier.increment.raw;
+        let remainder = (price.raw - tier.start.raw).rem_euclid(step);
+
+        let down = price.raw - remainder;
+        let up = if remainder == 0 {
+            down
+        } else {
+            // The next tier start is always on this tier's grid
+            (down + step).min(
+                self.tiers
+                    .get(index + 1)
+                    .map_or(PriceRaw::MAX, |next| next.start.raw),
+            )
+        };
+
+        let raw = match rounding {
+            PriceRounding::Down => down,
+            PriceRounding::Up => up,
+            PriceRounding::Nearest if price.raw - down < up - price.raw => down,
+            PriceRounding::Nearest => up,
+        };
+        Price::from_raw(raw, precision)
+    }
+
+    /// Returns the valid price `n` ticks below (negative `n`: above) the valid price at or
+    /// below `price`, walking across tiers.
+    #[must_use]
+    pub fn ticks_below(&self, price: Price, n: i32) -> Price {
+        if n < 0 {
+            return self.ticks_above(price, -n);
+        }
+        let mut price = self.round_to_valid_price(price, PriceRounding::Down);
+        for _ in 0..n {
+            // The tick below a tier start belongs to the tier below
+            let below = Price::from_raw(price.raw - 1, price.precision);
+            let step = self.increment_for(below).raw;
+            price = Price::from_raw(price.raw - step, price.precision);
+        }
+        price
+    }
+
+    /// Returns the valid price `n` ticks above (negative `n`: below) the valid price at or
+    /// above `price`, walking across tiers.
+    #[must_use]
+    pub fn ticks_above(&self, price: Price, n: i32) -> Price {
+        if n < 0 {
+            return self.ticks_below(price, -n);
+        }
+        let mut price = self.round_to_valid_price(price, PriceRounding::Up);
+        for _ in 0..n {
+            let step = self.increment_for(price).raw;
+            price = Price::from_raw(price.raw + step, price.precision);
+        }
+        price
+    }
+}
+
+impl TickSchemeRule for TickSizeTable {
+    fn next_bid_price(&self, value: f64, n: i32, precision: u8) -> Option<Price> {
+        let price = self.ticks_below(Price::new(value, self.precision()), n);
+        (price.raw >= 0).then(|| Price::from_raw(price.raw, precision))
+    }
+
+    fn next_ask_price(&self, value: f64, n: i32, precision: u8) -> Option<Price> {
+        let price = self.ticks_above(Price::new(value, self.precision()), n);
+        Some(Price::from_raw(price.raw, precision))
+    }
+}
+
+impl Display for TickSizeTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TABLE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn table() -> TickSizeTable {
+        let tier = |start: &str, increment: &str| TickTier {
+            start: Price::from(start),
+            increment: Price::from(increment),
+        };
+        TickSizeTable::new(vec![
+            tier("0.000", "0.001"),
+            tier("1.000", "0.010"),
+            tier("100.000", "0.050"),
+        ])
+    }
+
+    #[rstest]
+    #[case("0.5004", PriceRounding::Nearest, "0.500")]
+    #[case("0.5004", PriceRounding::Up, "0.501")]
+    #[case("1.234", PriceRounding::Down, "1.230")]
+    #[case("1.234", PriceRounding::Nearest, "1.230")]
+    #[case("1.236", PriceRounding::Nearest, "1.240")]
+    #[case("99.995", PriceRounding::Up, "100.000")]
+    #[case("100.070", PriceRounding::Nearest, "100.050")]
+    #[case("100.080", PriceRounding::Up, "100.100")]
+    fn test_round_to_valid_price(
+        #[case] price: &str,
+        #[case] rounding: PriceRounding,
+        #[case] expected: &str,
+    ) {
+        let rounded = table().round_to_valid_price(Price::from(price), rounding);
+        assert_eq!(rounded.raw, Price::from(expected).raw);
+    }
+
+    #[rstest]
+    fn test_is_valid_price() {
+        let table = table();
+        assert!(table.is_valid_price(Price::from("0.999")));
+        assert!(table.is_valid_price(Price::from("100.05")));
+        assert!(!table.is_valid_price(Price::from("100.01")));
+    }
+
+    #[rstest]
+    fn test_tick_walk_across_tiers() {
+        let table = table();
+        assert_eq!(
+            table.ticks_below(Price::from("1.000"), 1),
+            Price::from("0.999")
+        );
+        assert_eq!(
+            table.ticks_above(Price::from("99.990"), 2),
+            Price::from("100.050")
+        );
+        assert_eq!(
+            table.next_bid_price(100.0, 2, 3),
+            Some(Price::from("99.980"))
+        );
+    }
+
+    #[rstest]
+    fn test_new_checked_rejects_misaligned_tier() {
+        let result = TickSizeTable::new_checked(vec![
+            TickTier {
+                start: Price::from("0.00"),
+                increment: Price::from("0.05"),
+            },
+            TickTier {
+                start: Price::from("1.02"),
+                increment: Price::from("0.10"),
+            },
+        ]);
+        assert!(result.is_err());
+    }
+}
@@ -37,10 +37,12 @@
 //!
 //! - `ffi`: Enables the C foreign function interface (FFI) from [cbindgen](https://github.com/mozilla/cbindgen).
 //! - `python`: Enables Python bindings from [PyO3](https://pyo3.rs).
+//! - `arrow`: Enables encoding market data vectors into [Arrow](https://arrow.apache.org) record batches.
 //! - `stubs`: Enables type stubs for use in testing scenarios.
 //! - `high-precision`: Enables [high-precision mode](https://nautilustrader.io/docs/nightly/getting_started/installation#precision-mode) to use 128-bit value types.
 //! - `defi`: Enables the DeFi (Decentralized Finance) domain model.
 //! - `extension-module`: Builds the crate as a Python extension module.
+//! - `wasm`: Enables building the value types, identifiers and order book for `wasm32-unknown-unknown`.
 
 #![warn(rustc::all)]
 #![deny(unsafe_code)]
@@ -60,6 +62,7 @@ pub mod events;
 pub mod identifiers;
 pub mod instruments;
 pub mod macros;
+pub mod matching;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
@@ -76,5 +79,8 @@ pub mod python;
 #[cfg(any(test, feature = "stubs"))]
 pub mod stubs;
 
+#[cfg(test)]
+mod golden;
+
 #[cfg(feature = "defi")]
 pub mod defi;
@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Price band (limit-up/limit-down) enforcement for simulated venues.
+//!
+//! The [`PriceBandEnforcer`] holds the current exchange price bands for an instrument and decides
+//! how an incoming order priced outside them is handled: rejected as a real venue would, or
+//! pegged to the breached limit.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    data::{PriceBandKind, PriceBandUpdate},
+    enums::OrderSide,
+    orders::{Order, OrderAny},
+    types::Price,
+};
+
+/// The policy applied to orders priced outside the price bands.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PriceBandPolicy {
+    /// Reject the order.
+    #[default]
+    Reject,
+    /// Reprice the order to the breached limit.
+    Peg,
+}
+
+/// The action a simulated venue should take for an order given the price bands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BandAction {
+    /// Accept the order at its price.
+    Accept,
+    /// Accept the order repriced to the band limit.
+    Peg(Price),
+    /// Reject the order with the reason.
+    Reject(Ustr),
+}
+
+/// Enforces exchange price bands for a simulated venue.
+#[derive(Clone, Debug, Default)]
+pub struct PriceBandEnforcer {
+    policy: PriceBandPolicy,
+    bands: IndexMap<PriceBandKind, PriceBandUpdate>,
+}
+
+impl PriceBandEnforcer {
+    /// Creates a new [`PriceBandEnforcer`] instance.
+    #[must_use]
+    pub fn new(policy: PriceBandPolicy) -> Self {
+        Self {
+            policy,
+            bands: IndexMap::new(),
+        }
+    }
+
+    /// Returns the policy applied.
+    #[must_use]
+    pub const fn policy(&self) -> PriceBandPolicy {
+        self.policy
+    }
+
+    /// Applies a band update, replacing the current band of its kind.
+    pub fn update(&mut self, band: PriceBandUpdate) {
+        self.bands.insert(band.kind, band);
+    }
+
+    /// Removes the band of `kind` (e.g. when a circuit breaker is lifted).
+    pub fn remove(&mut self, kind: PriceBandKind) {
+        self.bands.shift_remove(&kind);
+    }
+
+    /// Returns the current bands.
+    #[must_use]
+    pub fn bands(&self) -> Vec<PriceBandUpdate> {
+        self.bands.values().copied().collect()
+    }
+
+    /// Checks an order on `side` at `price` (`None` for a market order) against the bands.
+    #[must_use]
+    pub fn check(&self, side: OrderSide, price: Option<Price>) -> BandAction {
+        let Some(price) = price else {
+            return BandAction::Accept;
+        };
+
+        // The tightest breached limit across all bands
+        let limit = self
+            .bands
+            .values()
+            .filter_map(|band| band.breached_limit(side, price))
+            .reduce(|a, b| match side {
+                OrderSide::Buy => a.min(b),
+                _ => a.max(b),
+            });
+
+        match (limit, self.policy) {
+            (None, _) => BandAction::Accept,
+            (Some(limit), PriceBandPolicy::Peg) => BandAction::Peg(limit),
+            (Some(limit), PriceBandPolicy::Reject) => BandAction::Reject(Ustr::from(&format!(
+                "PRICE_OUTSIDE_BAND: {side} at {price} breaches limit {limit}"
+            ))),
+        }
+    }
+
+    /// Checks `order` against the bands using its limit price.
+    #[must_use]
+    pub fn check_order(&self, order: &OrderAny) -> BandAction {
+        self.check(order.order_side(), order.price())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::OrderType, identifiers::InstrumentId, orders::builder::OrderTestBuilder,
+        types::Quantity,
+    };
+
+    fn band(kind: PriceBandKind, lower: &str, upper: &str) -> PriceBandUpdate {
+        PriceBandUpdate::new(
+            InstrumentId::from("ESZ24.XCME"),
+            kind,
+            Price::from(lower),
+            Price::from(upper),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_reject_outside_band() {
+        let mut enforcer = PriceBandEnforcer::new(PriceBandPolicy::Reject);
+        enforcer.update(band(PriceBandKind::DailyLimit, "4900.00", "5100.00"));
+
+        assert_eq!(
+            enforcer.check(OrderSide::Buy, Some(Price::from("5000.00"))),
+            BandAction::Accept
+        );
+        assert_eq!(enforcer.check(OrderSide::Sell, None), BandAction::Accept);
+        assert!(matches!(
+            enforcer.check(OrderSide::Buy, Some(Price::from("5100.25"))),
+            BandAction::Reject(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_peg_to_tightest_band() {
+        let mut enforcer = PriceBandEnforcer::new(PriceBandPolicy::Peg);
+        enforcer.update(band(PriceBandKind::DailyLimit, "4900.00", "5100.00"));
+        enforcer.update(band(PriceBandKind::Dynamic, "4950.00", "5050.00"));
+
+        assert_eq!(
+            enforcer.check(OrderSide::Buy, Some(Price::from("5200.00"))),
+            BandAction::Peg(Price::from("5050.00"))
+        );
+        assert_eq!(
+            enforcer.check(OrderSide::Sell, Some(Price::from("4800.00"))),
+            BandAction::Peg(Price::from("4950.00"))
+        );
+
+        enforcer.remove(PriceBandKind::Dynamic);
+        assert_eq!(
+            enforcer.check(OrderSide::Buy, Some(Price::from("5200.00"))),
+            BandAction::Peg(Price::from("5100.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_check_order_uses_limit_price() {
+        let mut enforcer = PriceBandEnforcer::new(PriceBandPolicy::Reject);
+        enforcer.update(band(PriceBandKind::DailyLimit, "4900.00", "5100.00"));
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("ESZ24.XCME"))
+            .side(OrderSide::Sell)
+            .price(Price::from("4850.00"))
+            .quantity(Quantity::from(1))
+            .build();
+
+        assert!(matches!(
+            enforcer.check_order(&order),
+            BandAction::Reject(_)
+        ));
+    }
+}
@@ -0,0 +1,310 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Market impact models for large simulated orders.
+//!
+//! A [`MarketImpactModel`] maps an order's participation (its quantity relative to a reference
+//! volume, such as visible book liquidity or average daily volume) to a fractional price impact.
+//! The [`MarketImpact`] applies the model to fill prices, and can carry a decaying temporary
+//! impact forward to the prices of subsequent book states.
+
+use std::fmt::Debug;
+
+use ahash::AHashMap;
+use nautilus_core::UnixNanos;
+
+use crate::{
+    enums::{OrderSide, OrderSideSpecified},
+    identifiers::InstrumentId,
+    orderbook::OrderBook,
+    types::{Price, Quantity},
+};
+
+/// Provides a fractional price impact for an order participation rate.
+pub trait MarketImpactModel: Debug {
+    /// Returns the fractional price impact (e.g. `0.001` for 10 bps) for `participation`.
+    fn impact(&self, participation: f64) -> f64;
+}
+
+/// Impact proportional to participation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearImpact {
+    pub coefficient: f64,
+}
+
+impl MarketImpactModel for LinearImpact {
+    fn impact(&self, participation: f64) -> f64 {
+        self.coefficient * participation
+    }
+}
+
+/// Impact proportional to the square root of participation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SquareRootImpact {
+    pub coefficient: f64,
+}
+
+impl MarketImpactModel for SquareRootImpact {
+    fn impact(&self, participation: f64) -> f64 {
+        self.coefficient * participation.sqrt()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TemporaryImpact {
+    value: f64,
+    ts: UnixNanos,
+}
+
+/// Applies a [`MarketImpactModel`] to simulated fills.
+#[derive(Debug)]
+pub struct MarketImpact {
+    model: Box<dyn MarketImpactModel>,
+    min_participation: f64,
+    temporary_fraction: f64,
+    half_life_ns: u64,
+    temporary: AHashMap<InstrumentId, TemporaryImpact>,
+}
+
+impl MarketImpact {
+    /// Creates a new [`MarketImpact`] instance.
+    ///
+    /// Impact applies only to orders with a participation of at least `min_participation`.
+    /// The `temporary_fraction` of each fill's impact persists for subsequent prices, decaying
+    /// with `half_life_ns` (a fraction of zero disables propagation).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `temporary_fraction` is not within [0, 1].
+    #[must_use]
+    pub fn new(
+        model: Box<dyn MarketImpactModel>,
+        min_participation: f64,
+        temporary_fraction: f64,
+        half_life_ns: u64,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&temporary_fraction),
+            "`temporary_fraction` must be within [0, 1], was {temporary_fraction}"
+        );
+        Self {
+            model,
+            min_participation,
+            temporary_fraction,
+            half_life_ns,
+            temporary: AHashMap::new(),
+        }
+    }
+
+    /// Returns the participation of `quantity` relative to `reference_volume`.
+    #[must_use]
+    pub fn participation(quantity: Quantity, reference_volume: f64) -> f64 {
+        if reference_volume <= 0.0 {
+            return 1.0;
+        }
+        quantity.as_f64() / reference_volume
+    }
+
+    /// Returns the visible liquidity on the side of `book` an order on `side` would take.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is `NoOrderSide`.
+    #[must_use]
+    pub fn book_liquidity(book: &OrderBook, side: OrderSide) -> f64 {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => book.asks(None).map(|level| level.size()).sum(),
+            OrderSideSpecified::Sell => book.bids(None).map(|level| level.size()).sum(),
+        }
+    }
+
+    /// Returns the fractional impact for an order of `quantity` against `reference_volume`.
+    #[must_use]
+    pub fn impact(&self, quantity: Quantity, reference_volume: f64) -> f64 {
+        let participation = Self::participation(quantity, reference_volume);
+        if participation < self.min_participation {
+            return 0.0;
+        }
+        self.model.impact(participation)
+    }
+
+    /// Returns the impact-adjusted fill price for a fill on `side`, recording any temporary
+    /// impact for the instrument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is `NoOrderSide`.
+    pub fn fill_price(
+        &mut self,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+        reference_volume: f64,
+        ts: UnixNanos,
+    ) -> Price {
+        let impact = self.impact(quantity, reference_volume);
+        if impact == 0.0 {
+            return price;
+        }
+
+        let signed_impact = match side.as_specified() {
+            OrderSideSpecified::Buy => impact,
+            OrderSideSpecified::Sell => -impact,
+        };
+
+        if self.temporary_fraction > 0.0 {
+            let value =
+                self.temporary_impact(&instrument_id, ts) + signed_impact * self.temporary_fraction;
+            self.temporary
+                .insert(instrument_id, TemporaryImpact { value, ts });
+        }
+
+        Price::new(price.as_f64() * (1.0 + signed_impact), price.precision)
+    }
+
+    /// Returns the decayed signed temporary impact for the instrument at `ts`.
+    #[must_use]
+    pub fn temporary_impact(&self, instrument_id: &InstrumentId, ts: UnixNanos) -> f64 {
+        let Some(temporary) = self.temporary.get(instrument_id) else {
+            return 0.0;
+        };
+        if self.half_life_ns == 0 {
+            return 0.0;
+        }
+
+        let elapsed = ts.as_u64().saturating_sub(temporary.ts.as_u64()) as f64;
+        temporary.value * 0.5_f64.powf(elapsed / self.half_life_ns as f64)
+    }
+
+    /// Returns `price` shifted by the instrument's temporary impact at `ts`, for adjusting
+    /// subsequent book states.
+    #[must_use]
+    pub fn adjust_price(&self, instrument_id: &InstrumentId, price: Price, ts: UnixNanos) -> Price {
+        let impact = self.temporary_impact(instrument_id, ts);
+        if impact == 0.0 {
+            return price;
+        }
+        Price::new(price.as_f64() * (1.0 + impact), price.precision)
+    }
+
+    /// Clears all temporary impact.
+    pub fn reset(&mut self) {
+        self.temporary.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.XNAS")
+    }
+
+    #[rstest]
+    #[case(0.25, 0.0025)]
+    #[case(1.0, 0.01)]
+    fn test_models(#[case] participation: f64, #[case] linear: f64) {
+        let sqrt = SquareRootImpact { coefficient: 0.01 }.impact(participation);
+
+        assert!((LinearImpact { coefficient: 0.01 }.impact(participation) - linear).abs() < 1e-12);
+        assert!((sqrt - 0.01 * participation.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_small_orders_have_no_impact() {
+        let mut impact = MarketImpact::new(
+            Box::new(LinearImpact { coefficient: 0.1 }),
+            0.05,
+            0.5,
+            SECOND,
+        );
+
+        let price = impact.fill_price(
+            instrument_id(),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(10),
+            1_000.0,
+            UnixNanos::default(),
+        );
+
+        assert_eq!(price, Price::from("100.00"));
+        assert_eq!(
+            impact.temporary_impact(&instrument_id(), UnixNanos::default()),
+            0.0
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "101.00")]
+    #[case(OrderSide::Sell, "99.00")]
+    fn test_fill_price_adjusted_by_side(#[case] side: OrderSide, #[case] expected: &str) {
+        let mut impact = MarketImpact::new(
+            Box::new(LinearImpact { coefficient: 0.1 }),
+            0.0,
+            0.0,
+            SECOND,
+        );
+
+        let price = impact.fill_price(
+            instrument_id(),
+            side,
+            Price::from("100.00"),
+            Quantity::from(100),
+            1_000.0,
+            UnixNanos::default(),
+        );
+
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_temporary_impact_decays() {
+        let mut impact = MarketImpact::new(
+            Box::new(LinearImpact { coefficient: 0.1 }),
+            0.0,
+            0.5,
+            SECOND,
+        );
+        let _ = impact.fill_price(
+            instrument_id(),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(100),
+            1_000.0,
+            UnixNanos::default(),
+        );
+
+        let at_fill = impact.temporary_impact(&instrument_id(), UnixNanos::default());
+        let after_half_life = impact.temporary_impact(&instrument_id(), SECOND.into());
+
+        assert!((at_fill - 0.005).abs() < 1e-12);
+        assert!((after_half_life - 0.0025).abs() < 1e-12);
+        assert_eq!(
+            impact.adjust_price(
+                &instrument_id(),
+                Price::from("100.00"),
+                UnixNanos::default()
+            ),
+            Price::from("100.50")
+        );
+    }
+}
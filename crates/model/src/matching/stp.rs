@@ -0,0 +1,350 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Self-trade prevention (STP) for simulated venues.
+//!
+//! Before an incoming order is matched, the [`SelfTradePreventer`] finds resting orders from the
+//! same trader which the incoming order would cross, and determines which orders to cancel or
+//! reduce so that no self-fill is generated.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::OrderSideSpecified,
+    identifiers::{ClientOrderId, TraderId},
+    orderbook::{OwnBookOrder, own::OwnOrderBook},
+    orders::{Order, OrderAny},
+    types::{Price, Quantity},
+};
+
+/// The policy applied when an incoming order would match a resting order from the same trader.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SelfTradePrevention {
+    /// Cancel the incoming (newest) order.
+    #[default]
+    CancelNewest,
+    /// Cancel the crossed resting (oldest) orders, then match the incoming order as normal.
+    CancelOldest,
+    /// Reduce both the incoming and crossed resting orders by the overlapping quantity,
+    /// canceling any order reduced to zero.
+    DecrementBoth,
+}
+
+/// The orders to cancel or reduce to prevent a self-trade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfTradeOutcome {
+    /// Whether the incoming order should be canceled.
+    pub cancel_incoming: bool,
+    /// The incoming order quantity remaining to match against other participants.
+    pub incoming_qty: Quantity,
+    /// The resting orders to cancel.
+    pub cancel_resting: Vec<ClientOrderId>,
+    /// The resting orders to reduce, with their new quantity.
+    pub reduce_resting: Vec<(ClientOrderId, Quantity)>,
+}
+
+impl SelfTradeOutcome {
+    /// Returns whether any self-trade was prevented.
+    #[must_use]
+    pub fn is_prevented(&self) -> bool {
+        self.cancel_incoming || !self.cancel_resting.is_empty() || !self.reduce_resting.is_empty()
+    }
+}
+
+/// Applies a [`SelfTradePrevention`] policy against an [`OwnOrderBook`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SelfTradePreventer {
+    policy: SelfTradePrevention,
+    prevented_count: u64,
+}
+
+impl SelfTradePreventer {
+    /// Creates a new [`SelfTradePreventer`] instance.
+    #[must_use]
+    pub const fn new(policy: SelfTradePrevention) -> Self {
+        Self {
+            policy,
+            prevented_count: 0,
+        }
+    }
+
+    /// Returns the policy applied.
+    #[must_use]
+    pub const fn policy(&self) -> SelfTradePrevention {
+        self.policy
+    }
+
+    /// Returns the number of incoming orders for which a self-trade was prevented.
+    #[must_use]
+    pub const fn prevented_count(&self) -> u64 {
+        self.prevented_count
+    }
+
+    /// Returns the resting orders of `trader_id` in `own_book` which an incoming order on `side`
+    /// at `price` (`None` for a market order) would cross, in matching priority.
+    #[must_use]
+    pub fn crossed_orders(
+        own_book: &OwnOrderBook,
+        trader_id: TraderId,
+        side: OrderSideSpecified,
+        price: Option<Price>,
+    ) -> Vec<&OwnBookOrder> {
+        let crosses = |resting: Price| match (side, price) {
+            (_, None) => true,
+            (OrderSideSpecified::Buy, Some(price)) => resting <= price,
+            (OrderSideSpecified::Sell, Some(price)) => resting >= price,
+        };
+        let levels: Vec<_> = match side {
+            OrderSideSpecified::Buy => own_book.asks().collect(),
+            OrderSideSpecified::Sell => own_book.bids().collect(),
+        };
+
+        levels
+            .into_iter()
+            .take_while(|level| crosses(level.price.value))
+            .flat_map(|level| level.iter())
+            .filter(|order| order.trader_id == trader_id)
+            .collect()
+    }
+
+    /// Applies the policy for an incoming order, returning the orders to cancel or reduce.
+    pub fn apply(
+        &mut self,
+        own_book: &OwnOrderBook,
+        trader_id: TraderId,
+        side: OrderSideSpecified,
+        price: Option<Price>,
+        quantity: Quantity,
+    ) -> SelfTradeOutcome {
+        let crossed = Self::crossed_orders(own_book, trader_id, side, price);
+        let mut outcome = SelfTradeOutcome {
+            cancel_incoming: false,
+            incoming_qty: quantity,
+            cancel_resting: Vec::new(),
+            reduce_resting: Vec::new(),
+        };
+
+        if crossed.is_empty() {
+            return outcome;
+        }
+
+        match self.policy {
+            SelfTradePrevention::CancelNewest => {
+                outcome.cancel_incoming = true;
+                outcome.incoming_qty = Quantity::zero(quantity.precision);
+            }
+            SelfTradePrevention::CancelOldest => {
+                outcome.cancel_resting =
+                    crossed.iter().map(|order| order.client_order_id).collect();
+            }
+            SelfTradePrevention::DecrementBoth => {
+                for resting in crossed {
+                    if outcome.incoming_qty.is_zero() {
+                        break;
+                    }
+                    let overlap = outcome.incoming_qty.min(resting.size);
+                    outcome.incoming_qty = outcome.incoming_qty.saturating_sub(overlap);
+
+                    let remaining = resting.size.saturating_sub(overlap);
+                    if remaining.is_zero() {
+                        outcome.cancel_resting.push(resting.client_order_id);
+                    } else {
+                        outcome
+                            .reduce_resting
+                            .push((resting.client_order_id, remaining));
+                    }
+                }
+                outcome.cancel_incoming = outcome.incoming_qty.is_zero();
+            }
+        }
+
+        self.prevented_count += 1;
+        outcome
+    }
+
+    /// Applies the policy for the incoming `order` using its leaves quantity.
+    pub fn apply_order(&mut self, own_book: &OwnOrderBook, order: &OrderAny) -> SelfTradeOutcome {
+        self.apply(
+            own_book,
+            order.trader_id(),
+            order.order_side_specified(),
+            order.price(),
+            order.leaves_qty(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderStatus, OrderType, TimeInForce},
+        identifiers::InstrumentId,
+    };
+
+    fn own_order(
+        trader: &str,
+        id: &str,
+        side: OrderSideSpecified,
+        price: &str,
+        size: u64,
+    ) -> OwnBookOrder {
+        OwnBookOrder::new(
+            TraderId::from(trader),
+            ClientOrderId::from(id),
+            None,
+            side,
+            Price::from(price),
+            Quantity::from(size),
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            OrderStatus::Accepted,
+            1.into(),
+            1.into(),
+            1.into(),
+            1.into(),
+        )
+    }
+
+    fn own_book() -> OwnOrderBook {
+        let mut book = OwnOrderBook::new(InstrumentId::from("AUD/USD.SIM"));
+        book.add(own_order(
+            "TRADER-001",
+            "O-1",
+            OrderSideSpecified::Sell,
+            "1.00010",
+            30,
+        ));
+        book.add(own_order(
+            "TRADER-002",
+            "O-2",
+            OrderSideSpecified::Sell,
+            "1.00010",
+            50,
+        ));
+        book.add(own_order(
+            "TRADER-001",
+            "O-3",
+            OrderSideSpecified::Sell,
+            "1.00020",
+            40,
+        ));
+        book.add(own_order(
+            "TRADER-001",
+            "O-4",
+            OrderSideSpecified::Sell,
+            "1.00030",
+            10,
+        ));
+        book
+    }
+
+    fn apply(policy: SelfTradePrevention, quantity: u64) -> SelfTradeOutcome {
+        SelfTradePreventer::new(policy).apply(
+            &own_book(),
+            TraderId::from("TRADER-001"),
+            OrderSideSpecified::Buy,
+            Some(Price::from("1.00020")),
+            Quantity::from(quantity),
+        )
+    }
+
+    #[rstest]
+    fn test_crossed_orders_filters_trader_and_price() {
+        let book = own_book();
+
+        let crossed = SelfTradePreventer::crossed_orders(
+            &book,
+            TraderId::from("TRADER-001"),
+            OrderSideSpecified::Buy,
+            Some(Price::from("1.00020")),
+        );
+
+        let ids: Vec<_> = crossed.iter().map(|order| order.client_order_id).collect();
+        assert_eq!(
+            ids,
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-3")]
+        );
+    }
+
+    #[rstest]
+    fn test_no_cross_is_not_prevented() {
+        let mut preventer = SelfTradePreventer::default();
+
+        let outcome = preventer.apply(
+            &own_book(),
+            TraderId::from("TRADER-001"),
+            OrderSideSpecified::Buy,
+            Some(Price::from("1.00000")),
+            Quantity::from(100),
+        );
+
+        assert!(!outcome.is_prevented());
+        assert_eq!(preventer.prevented_count(), 0);
+    }
+
+    #[rstest]
+    fn test_cancel_newest() {
+        let outcome = apply(SelfTradePrevention::CancelNewest, 100);
+
+        assert!(outcome.cancel_incoming);
+        assert!(outcome.cancel_resting.is_empty());
+    }
+
+    #[rstest]
+    fn test_cancel_oldest() {
+        let outcome = apply(SelfTradePrevention::CancelOldest, 100);
+
+        assert!(!outcome.cancel_incoming);
+        assert_eq!(outcome.incoming_qty, Quantity::from(100));
+        assert_eq!(
+            outcome.cancel_resting,
+            vec![ClientOrderId::from("O-1"), ClientOrderId::from("O-3")]
+        );
+    }
+
+    #[rstest]
+    #[case(50, true, 0, vec!["O-1"], vec![("O-3", 20)])]
+    #[case(70, true, 0, vec!["O-1", "O-3"], vec![])]
+    #[case(100, false, 30, vec!["O-1", "O-3"], vec![])]
+    fn test_decrement_both(
+        #[case] quantity: u64,
+        #[case] cancel_incoming: bool,
+        #[case] incoming_qty: u64,
+        #[case] canceled: Vec<&str>,
+        #[case] reduced: Vec<(&str, u64)>,
+    ) {
+        let outcome = apply(SelfTradePrevention::DecrementBoth, quantity);
+
+        assert_eq!(outcome.cancel_incoming, cancel_incoming);
+        assert_eq!(outcome.incoming_qty, Quantity::from(incoming_qty));
+        assert_eq!(
+            outcome.cancel_resting,
+            canceled
+                .into_iter()
+                .map(ClientOrderId::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            outcome.reduce_resting,
+            reduced
+                .into_iter()
+                .map(|(id, qty)| (ClientOrderId::from(id), Quantity::from(qty)))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,448 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Time-in-force enforcement for simulated venues.
+//!
+//! The [`TimeInForceEnforcer`] decides how an order's time in force applies on submission, given
+//! the liquidity immediately available to it, and as time advances. It produces the corresponding
+//! rejected, canceled and expired events so backtests treat orders as real venues would, while
+//! the fills themselves remain the responsibility of the matching engine.
+
+use nautilus_core::{
+    UUID4, UnixNanos,
+    correctness::{FAILED, check_predicate_true},
+    datetime::NANOSECONDS_IN_SECOND,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    enums::TimeInForce,
+    events::{OrderCanceled, OrderEventAny, OrderExpired, OrderRejected},
+    identifiers::AccountId,
+    orders::{Order, OrderAny},
+    types::Quantity,
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// The phase of a trading session at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionPhase {
+    /// Before the session open.
+    PreOpen,
+    /// Between the session open and close.
+    Open,
+    /// After the session close.
+    Closed,
+}
+
+/// A daily venue trading session, defined by UTC time-of-day offsets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TradingSession {
+    /// The session open (nanoseconds after UTC midnight).
+    pub open_ns: u64,
+    /// The session close (nanoseconds after UTC midnight).
+    pub close_ns: u64,
+}
+
+impl TradingSession {
+    /// Creates a new [`TradingSession`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `open_ns` is not before `close_ns`, or `close_ns` is beyond one day.
+    pub fn new_checked(open_ns: u64, close_ns: u64) -> anyhow::Result<Self> {
+        check_predicate_true(open_ns < close_ns, "`open_ns` must be before `close_ns`")?;
+        check_predicate_true(
+            close_ns <= NANOSECONDS_IN_DAY,
+            "`close_ns` must be within one day",
+        )?;
+
+        Ok(Self { open_ns, close_ns })
+    }
+
+    /// Creates a new [`TradingSession`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `open_ns` is not before `close_ns`, or `close_ns` is beyond one day.
+    #[must_use]
+    pub fn new(open_ns: u64, close_ns: u64) -> Self {
+        Self::new_checked(open_ns, close_ns).expect(FAILED)
+    }
+
+    /// Returns the session open on the UTC day of `ts`.
+    #[must_use]
+    pub fn open_for(&self, ts: UnixNanos) -> UnixNanos {
+        UnixNanos::from(day_start(ts) + self.open_ns)
+    }
+
+    /// Returns the session close on the UTC day of `ts`.
+    #[must_use]
+    pub fn close_for(&self, ts: UnixNanos) -> UnixNanos {
+        UnixNanos::from(day_start(ts) + self.close_ns)
+    }
+
+    /// Returns the session phase at `ts`.
+    #[must_use]
+    pub fn phase(&self, ts: UnixNanos) -> SessionPhase {
+        let offset = ts.as_u64() % NANOSECONDS_IN_DAY;
+        if offset < self.open_ns {
+            SessionPhase::PreOpen
+        } else if offset < self.close_ns {
+            SessionPhase::Open
+        } else {
+            SessionPhase::Closed
+        }
+    }
+}
+
+/// The action a simulated venue should take for an order given its time in force.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TifAction {
+    /// Match the order as normal, resting any unfilled quantity.
+    Accept,
+    /// Fill the quantity immediately, completing the order.
+    Fill(Quantity),
+    /// Fill the quantity immediately, then cancel the unfilled remainder.
+    FillAndCancel(Quantity),
+    /// Cancel the order without any fill.
+    Cancel,
+    /// Expire the order.
+    Expire,
+    /// Reject the order with the reason.
+    Reject(Ustr),
+}
+
+/// Enforces time-in-force semantics for a simulated venue.
+///
+/// Without a [`TradingSession`] the venue trades continuously, `DAY` orders expire at the end of
+/// the UTC day, and `AT_THE_OPEN`/`AT_THE_CLOSE` orders are rejected.
+#[derive(Clone, Copy, Debug)]
+pub struct TimeInForceEnforcer {
+    account_id: AccountId,
+    session: Option<TradingSession>,
+}
+
+impl TimeInForceEnforcer {
+    /// Creates a new [`TimeInForceEnforcer`] instance.
+    #[must_use]
+    pub const fn new(account_id: AccountId, session: Option<TradingSession>) -> Self {
+        Self {
+            account_id,
+            session,
+        }
+    }
+
+    /// Returns the venue trading session, if any.
+    #[must_use]
+    pub const fn session(&self) -> Option<TradingSession> {
+        self.session
+    }
+
+    /// Returns the action to take on submission of `order`.
+    ///
+    /// The `fillable_qty` is the quantity which could be filled immediately against the book at
+    /// the order's price.
+    #[must_use]
+    pub fn on_submit(
+        &self,
+        order: &OrderAny,
+        fillable_qty: Quantity,
+        ts_now: UnixNanos,
+    ) -> TifAction {
+        let leaves_qty = order.leaves_qty();
+        let phase = self.session.map(|session| session.phase(ts_now));
+
+        match order.time_in_force() {
+            TimeInForce::Gtc => TifAction::Accept,
+            TimeInForce::Ioc => {
+                if fillable_qty.is_zero() {
+                    TifAction::Cancel
+                } else if fillable_qty < leaves_qty {
+                    TifAction::FillAndCancel(fillable_qty)
+                } else {
+                    TifAction::Fill(leaves_qty)
+                }
+            }
+            TimeInForce::Fok => {
+                if fillable_qty >= leaves_qty {
+                    TifAction::Fill(leaves_qty)
+                } else {
+                    TifAction::Cancel
+                }
+            }
+            TimeInForce::Gtd => match order.expire_time() {
+                None => TifAction::Reject(Ustr::from("GTD order has no expire time")),
+                Some(expire_time) if expire_time <= ts_now => {
+                    TifAction::Reject(Ustr::from("GTD order already past expire time"))
+                }
+                Some(_) => TifAction::Accept,
+            },
+            TimeInForce::Day => match phase {
+                Some(SessionPhase::Closed) => {
+                    TifAction::Reject(Ustr::from("DAY order submitted after session close"))
+                }
+                _ => TifAction::Accept,
+            },
+            TimeInForce::AtTheOpen => match phase {
+                None => TifAction::Reject(Ustr::from("AT_THE_OPEN requires a trading session")),
+                Some(SessionPhase::PreOpen) => TifAction::Accept,
+                Some(_) => {
+                    TifAction::Reject(Ustr::from("AT_THE_OPEN order submitted after session open"))
+                }
+            },
+            TimeInForce::AtTheClose => match phase {
+                None => TifAction::Reject(Ustr::from("AT_THE_CLOSE requires a trading session")),
+                Some(SessionPhase::Closed) => TifAction::Reject(Ustr::from(
+                    "AT_THE_CLOSE order submitted after session close",
+                )),
+                Some(_) => TifAction::Accept,
+            },
+        }
+    }
+
+    /// Returns whether `order` has expired at `ts_now` (always `false` once closed).
+    ///
+    /// `AT_THE_OPEN` and `AT_THE_CLOSE` orders expire once the open or close is reached, so any
+    /// auction at that time should be matched before checking expiry.
+    #[must_use]
+    pub fn is_expired(&self, order: &OrderAny, ts_now: UnixNanos) -> bool {
+        if order.is_closed() {
+            return false;
+        }
+
+        match order.time_in_force() {
+            TimeInForce::Gtd => order
+                .expire_time()
+                .is_some_and(|expire_time| ts_now >= expire_time),
+            TimeInForce::Day => {
+                let close = self.session.map_or_else(
+                    || UnixNanos::from(day_start(order.ts_init()) + NANOSECONDS_IN_DAY),
+                    |session| session.close_for(order.ts_init()),
+                );
+                ts_now >= close
+            }
+            TimeInForce::AtTheOpen => self
+                .session
+                .is_some_and(|session| ts_now >= session.open_for(order.ts_init())),
+            TimeInForce::AtTheClose => self
+                .session
+                .is_some_and(|session| ts_now >= session.close_for(order.ts_init())),
+            TimeInForce::Gtc | TimeInForce::Ioc | TimeInForce::Fok => false,
+        }
+    }
+
+    /// Returns expired events for all of `orders` which have expired at `ts_now`.
+    #[must_use]
+    pub fn expire_orders<'a>(
+        &self,
+        orders: impl IntoIterator<Item = &'a OrderAny>,
+        ts_now: UnixNanos,
+    ) -> Vec<OrderEventAny> {
+        orders
+            .into_iter()
+            .filter(|order| self.is_expired(order, ts_now))
+            .filter_map(|order| self.event(order, TifAction::Expire, ts_now))
+            .collect()
+    }
+
+    /// Returns the terminal event for `order` resulting from `action`, if any.
+    ///
+    /// [`TifAction::FillAndCancel`] results in the cancel of the remainder, and no event is
+    /// produced for [`TifAction::Accept`] or [`TifAction::Fill`].
+    #[must_use]
+    pub fn event(
+        &self,
+        order: &OrderAny,
+        action: TifAction,
+        ts_now: UnixNanos,
+    ) -> Option<OrderEventAny> {
+        match action {
+            TifAction::Accept | TifAction::Fill(_) => None,
+            TifAction::FillAndCancel(_) | TifAction::Cancel => {
+                Some(OrderEventAny::Canceled(OrderCanceled::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                    order.venue_order_id(),
+                    Some(self.account_id(order)),
+                )))
+            }
+            TifAction::Expire => Some(OrderEventAny::Expired(OrderExpired::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.venue_order_id(),
+                Some(self.account_id(order)),
+            ))),
+            TifAction::Reject(reason) => Some(OrderEventAny::Rejected(OrderRejected::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                self.account_id(order),
+                reason,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                false,
+            ))),
+        }
+    }
+
+    fn account_id(&self, order: &OrderAny) -> AccountId {
+        order.account_id().unwrap_or(self.account_id)
+    }
+}
+
+fn day_start(ts: UnixNanos) -> u64 {
+    ts.as_u64() - ts.as_u64() % NANOSECONDS_IN_DAY
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        orders::builder::OrderTestBuilder,
+        types::Price,
+    };
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+
+    fn enforcer() -> TimeInForceEnforcer {
+        TimeInForceEnforcer::new(
+            AccountId::from("SIM-001"),
+            Some(TradingSession::new(9 * HOUR, 17 * HOUR)),
+        )
+    }
+
+    fn order(time_in_force: TimeInForce, ts_init: u64) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AUD/USD.SIM".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100))
+            .time_in_force(time_in_force)
+            .ts_init(UnixNanos::from(ts_init))
+            .build()
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Ioc, 0, TifAction::Cancel)]
+    #[case(TimeInForce::Ioc, 40, TifAction::FillAndCancel(Quantity::from(40)))]
+    #[case(TimeInForce::Ioc, 150, TifAction::Fill(Quantity::from(100)))]
+    #[case(TimeInForce::Fok, 40, TifAction::Cancel)]
+    #[case(TimeInForce::Fok, 100, TifAction::Fill(Quantity::from(100)))]
+    #[case(TimeInForce::Gtc, 0, TifAction::Accept)]
+    fn test_immediate_tifs(
+        #[case] time_in_force: TimeInForce,
+        #[case] fillable: u64,
+        #[case] expected: TifAction,
+    ) {
+        let order = order(time_in_force, 10 * HOUR);
+
+        let action = enforcer().on_submit(&order, Quantity::from(fillable), (10 * HOUR).into());
+
+        assert_eq!(action, expected);
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Day, 18 * HOUR, false)]
+    #[case(TimeInForce::AtTheOpen, 8 * HOUR, true)]
+    #[case(TimeInForce::AtTheOpen, 10 * HOUR, false)]
+    #[case(TimeInForce::AtTheClose, 16 * HOUR, true)]
+    #[case(TimeInForce::AtTheClose, 18 * HOUR, false)]
+    fn test_session_tifs_on_submit(
+        #[case] time_in_force: TimeInForce,
+        #[case] ts_now: u64,
+        #[case] accepted: bool,
+    ) {
+        let order = order(time_in_force, ts_now);
+
+        let action = enforcer().on_submit(&order, Quantity::from(0), ts_now.into());
+
+        assert_eq!(action == TifAction::Accept, accepted);
+    }
+
+    #[rstest]
+    fn test_session_tifs_without_session_reject() {
+        let enforcer = TimeInForceEnforcer::new(AccountId::from("SIM-001"), None);
+        let order = order(TimeInForce::AtTheOpen, HOUR);
+
+        let action = enforcer.on_submit(&order, Quantity::from(0), HOUR.into());
+
+        assert!(matches!(action, TifAction::Reject(_)));
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Day, 16 * HOUR, false)]
+    #[case(TimeInForce::Day, 17 * HOUR, true)]
+    #[case(TimeInForce::AtTheOpen, 9 * HOUR, true)]
+    #[case(TimeInForce::AtTheClose, 17 * HOUR, true)]
+    #[case(TimeInForce::Gtc, 48 * HOUR, false)]
+    fn test_is_expired(
+        #[case] time_in_force: TimeInForce,
+        #[case] ts_now: u64,
+        #[case] expected: bool,
+    ) {
+        let order = order(time_in_force, 8 * HOUR);
+
+        assert_eq!(enforcer().is_expired(&order, ts_now.into()), expected);
+    }
+
+    #[rstest]
+    fn test_gtd_expiry_produces_expired_event() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AUD/USD.SIM".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(UnixNanos::from(12 * HOUR))
+            .build();
+        let enforcer = enforcer();
+
+        assert_eq!(
+            enforcer.on_submit(&order, Quantity::from(0), (10 * HOUR).into()),
+            TifAction::Accept
+        );
+        assert!(
+            enforcer
+                .expire_orders([&order], (11 * HOUR).into())
+                .is_empty()
+        );
+
+        let events = enforcer.expire_orders([&order], (12 * HOUR).into());
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Expired(_)));
+    }
+}
@@ -153,6 +153,38 @@ pub fn get_avg_px_qty_for_exposure(
     }
 }
 
+/// Computes a checksum over the top `depth` levels of the given order `book`.
+///
+/// Uses FNV-1a over each level's raw price and aggregate raw size, bids (best first) then asks
+/// (best first). The checksum depends only on the aggregated ladder, so two books holding the
+/// same levels hash equally regardless of order IDs or the sequence used to build them.
+#[must_use]
+pub fn book_checksum(book: &OrderBook, depth: Option<usize>) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+
+    write(b"B");
+    for level in book.bids(depth) {
+        write(&level.price.value.raw.to_le_bytes());
+        write(&level.size_raw().to_le_bytes());
+    }
+    write(b"A");
+    for level in book.asks(depth) {
+        write(&level.price.value.raw.to_le_bytes());
+        write(&level.size_raw().to_le_bytes());
+    }
+
+    hash
+}
+
 /// Checks the integrity of the given order `book`.
 ///
 /// # Errors
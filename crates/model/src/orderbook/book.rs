@@ -27,8 +27,11 @@ use super::{
     own::OwnOrderBook,
 };
 use crate::{
-    data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus},
+    data::{
+        BookOrder, DEPTH10_LEN, NULL_ORDER, OrderBookDelta, OrderBookDeltas, OrderBookDepth10,
+        QuoteTick, TradeTick,
+    },
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus, RecordFlag},
     identifiers::InstrumentId,
     orderbook::{
         BookIntegrityError, InvalidBookOperation,
@@ -37,6 +40,7 @@ use crate::{
     types::{
         Price, Quantity,
         price::{PRICE_ERROR, PRICE_UNDEF},
+        quantity::QuantityRaw,
     },
 };
 
@@ -63,6 +67,8 @@ pub struct OrderBook {
     pub ts_last: UnixNanos,
     /// The current count of updates applied to the order book.
     pub update_count: u64,
+    /// The maximum number of price levels maintained per side (`None` for unlimited).
+    pub(crate) max_depth: Option<usize>,
     pub(crate) bids: BookLadder,
     pub(crate) asks: BookLadder,
 }
@@ -98,11 +104,59 @@ impl OrderBook {
             sequence: 0,
             ts_last: UnixNanos::default(),
             update_count: 0,
+            max_depth: None,
             bids: BookLadder::new(OrderSideSpecified::Buy, book_type),
             asks: BookLadder::new(OrderSideSpecified::Sell, book_type),
         }
     }
 
+    /// Creates a new depth-limited [`OrderBook`] instance maintaining at most `max_depth` price
+    /// levels per side.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_depth` is zero.
+    #[must_use]
+    pub fn with_max_depth(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        max_depth: usize,
+    ) -> Self {
+        let mut book = Self::new(instrument_id, book_type);
+        book.set_max_depth(Some(max_depth));
+        book
+    }
+
+    /// Returns the maximum number of price levels maintained per side, if depth-limited.
+    #[must_use]
+    pub const fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Sets the maximum number of price levels maintained per side (`None` for unlimited),
+    /// immediately pruning any levels beyond the new depth.
+    ///
+    /// In depth-limited mode, levels beyond the depth are pruned after every update. The best
+    /// levels (and so the BBO) are never affected by pruning, however once levels are pruned the
+    /// book may hold fewer than `max_depth` levels until the venue publishes them again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_depth` is `Some(0)`.
+    pub fn set_max_depth(&mut self, max_depth: Option<usize>) {
+        assert!(max_depth != Some(0), "`max_depth` must be positive");
+        self.max_depth = max_depth;
+        self.prune_to_max_depth();
+    }
+
+    /// Prunes levels beyond the maximum depth (no-op when unlimited).
+    fn prune_to_max_depth(&mut self) {
+        if let Some(depth) = self.max_depth {
+            self.bids.truncate(depth);
+            self.asks.truncate(depth);
+        }
+    }
+
     /// Resets the order book to its initial empty state.
     pub fn reset(&mut self) {
         self.bids.clear();
@@ -119,6 +173,7 @@ impl OrderBook {
             OrderSideSpecified::Buy => self.bids.add(order, flags),
             OrderSideSpecified::Sell => self.asks.add(order, flags),
         }
+        self.prune_to_max_depth();
 
         self.increment(sequence, ts_event);
     }
@@ -130,6 +185,7 @@ impl OrderBook {
             OrderSideSpecified::Buy => self.bids.update(order, flags),
             OrderSideSpecified::Sell => self.asks.update(order, flags),
         }
+        self.prune_to_max_depth();
 
         self.increment(sequence, ts_event);
     }
@@ -437,6 +493,7 @@ impl OrderBook {
             let order = pre_process_order(self.book_type, order, depth.flags);
             self.asks.add(order, depth.flags);
         }
+        self.prune_to_max_depth();
 
         self.increment(depth.sequence, depth.ts_event);
 
@@ -472,6 +529,49 @@ impl OrderBook {
         self.asks.levels.values().take(depth.unwrap_or(usize::MAX))
     }
 
+    /// Derives an [`OrderBookDepth10`] snapshot from the top ten levels of each side.
+    ///
+    /// Each level is aggregated into a single order (with `order_id` 0) holding the total level
+    /// size, with the number of orders at the level recorded in the counts. Missing levels are
+    /// padded with null orders and zero counts. The snapshot carries the book's last sequence
+    /// and event timestamp.
+    #[must_use]
+    pub fn to_depth10(&self, ts_init: UnixNanos) -> OrderBookDepth10 {
+        fn fill_side<'a>(
+            levels: impl Iterator<Item = &'a BookLevel>,
+            side: OrderSide,
+            orders: &mut [BookOrder; DEPTH10_LEN],
+            counts: &mut [u32; DEPTH10_LEN],
+        ) {
+            for (i, level) in levels.take(DEPTH10_LEN).enumerate() {
+                let size_precision = level.first().map_or(0, |order| order.size.precision);
+                let size = Quantity::from_raw(level.size_raw(), size_precision);
+                orders[i] = BookOrder::new(side, level.price.value, size, 0);
+                counts[i] = u32::try_from(level.len()).unwrap_or(u32::MAX);
+            }
+        }
+
+        let mut bids = [NULL_ORDER; DEPTH10_LEN];
+        let mut asks = [NULL_ORDER; DEPTH10_LEN];
+        let mut bid_counts = [0u32; DEPTH10_LEN];
+        let mut ask_counts = [0u32; DEPTH10_LEN];
+
+        fill_side(self.bids(None), OrderSide::Buy, &mut bids, &mut bid_counts);
+        fill_side(self.asks(None), OrderSide::Sell, &mut asks, &mut ask_counts);
+
+        OrderBookDepth10::new(
+            self.instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8,
+            self.sequence,
+            self.ts_last,
+            ts_init,
+        )
+    }
+
     /// Returns bid price levels as a map of price to size.
     pub fn bids_as_map(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
         self.bids(depth)
@@ -670,6 +770,14 @@ impl OrderBook {
         }
     }
 
+    /// Returns a checksum of the top `depth` levels (all levels when `None`).
+    ///
+    /// See [`analysis::book_checksum`] for the algorithm.
+    #[must_use]
+    pub fn checksum(&self, depth: Option<usize>) -> u64 {
+        analysis::book_checksum(self, depth)
+    }
+
     /// Calculates the average price to fill the specified quantity.
     #[must_use]
     pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
@@ -749,6 +857,49 @@ impl OrderBook {
         }
     }
 
+    /// Simulates a market order of `qty` on `order_side` against the current book liquidity.
+    ///
+    /// Returns the (price, quantity) fills in execution order, walking the opposite side from the
+    /// top of book. If liquidity is exhausted first, the fill quantities sum to less than `qty`.
+    #[must_use]
+    pub fn simulate_market_order(
+        &self,
+        order_side: OrderSide,
+        qty: Quantity,
+    ) -> Vec<(Price, Quantity)> {
+        if !qty.is_positive() {
+            return Vec::new();
+        }
+
+        // Marketable at any price on the opposite side
+        let price = match order_side.as_specified() {
+            OrderSideSpecified::Buy => Price::max(0),
+            OrderSideSpecified::Sell => Price::min(0),
+        };
+        self.simulate_fills(&BookOrder::new(order_side, price, qty, 0))
+    }
+
+    /// Returns the expected average fill price of a market order of `qty` on `order_side`.
+    ///
+    /// Returns `None` if `qty` is not positive or the book does not have enough liquidity to
+    /// fill the full quantity.
+    #[must_use]
+    pub fn expected_avg_price(&self, order_side: OrderSide, qty: Quantity) -> Option<f64> {
+        let fills = self.simulate_market_order(order_side, qty);
+
+        let mut filled_raw: QuantityRaw = 0;
+        let mut notional = 0.0;
+        for (price, size) in &fills {
+            filled_raw += size.raw;
+            notional += price.as_f64() * size.as_f64();
+        }
+
+        if fills.is_empty() || filled_raw < qty.raw {
+            return None;
+        }
+        Some(notional / qty.as_f64())
+    }
+
     /// Returns all price levels crossed by an order at the given price and side.
     ///
     /// Unlike `simulate_fills`, this returns ALL crossed levels regardless of
@@ -0,0 +1,381 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conflation of order book deltas for consumers which read at a lower rate than updates arrive.
+//!
+//! Successive deltas for the same price level (L1/L2 books) or the same order (L3 books) are
+//! merged so that a consumer draining the buffer only receives the net effect since its last read.
+
+use indexmap::IndexMap;
+
+use crate::{
+    data::{OrderBookDelta, OrderBookDeltas},
+    enums::{BookAction, BookType, OrderSide, RecordFlag},
+    identifiers::InstrumentId,
+    types::price::PriceRaw,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ConflationKey {
+    Level(OrderSide, PriceRaw),
+    Order(u64),
+}
+
+/// Buffers order book deltas between consumer reads, keeping only the net change per key.
+///
+/// Applying the drained deltas to a book yields the same state as applying every delta that was
+/// pushed since the previous drain.
+#[derive(Clone, Debug)]
+pub struct OrderBookDeltaConflator {
+    /// The instrument ID for the buffered deltas.
+    pub instrument_id: InstrumentId,
+    /// The order book type used to key the deltas.
+    pub book_type: BookType,
+    clear: Option<OrderBookDelta>,
+    pending: IndexMap<ConflationKey, OrderBookDelta>,
+    received: u64,
+    emitted: u64,
+}
+
+impl OrderBookDeltaConflator {
+    /// Creates a new [`OrderBookDeltaConflator`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType) -> Self {
+        Self {
+            instrument_id,
+            book_type,
+            clear: None,
+            pending: IndexMap::new(),
+            received: 0,
+            emitted: 0,
+        }
+    }
+
+    /// Returns the number of conflated deltas currently pending (including any clear).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len() + usize::from(self.clear.is_some())
+    }
+
+    /// Returns whether there are no pending deltas.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total number of deltas pushed into the buffer.
+    #[must_use]
+    pub const fn received_count(&self) -> u64 {
+        self.received
+    }
+
+    /// Returns the total number of deltas delivered by [`Self::drain`].
+    #[must_use]
+    pub const fn emitted_count(&self) -> u64 {
+        self.emitted
+    }
+
+    /// Pushes a single delta into the buffer, merging it with any pending delta for the same key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delta's instrument ID does not match the conflator's.
+    pub fn push(&mut self, delta: OrderBookDelta) -> anyhow::Result<()> {
+        if delta.instrument_id != self.instrument_id {
+            anyhow::bail!(
+                "Instrument ID mismatch: expected {}, was {}",
+                self.instrument_id,
+                delta.instrument_id
+            );
+        }
+        self.received += 1;
+
+        if delta.action == BookAction::Clear {
+            // Anything pending is superseded by the clear
+            self.pending.clear();
+            self.clear = Some(delta);
+            return Ok(());
+        }
+
+        let key = self.key_for(&delta);
+        let merged = match self.pending.get(&key) {
+            Some(prev) => Self::merge(prev, delta),
+            None => delta,
+        };
+        self.pending.insert(key, merged);
+
+        Ok(())
+    }
+
+    /// Pushes every delta in `deltas` into the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deltas' instrument ID does not match the conflator's.
+    pub fn push_deltas(&mut self, deltas: &OrderBookDeltas) -> anyhow::Result<()> {
+        for delta in &deltas.deltas {
+            self.push(*delta)?;
+        }
+        Ok(())
+    }
+
+    /// Drains the buffer, returning the net deltas since the previous drain.
+    ///
+    /// Any clear is delivered first, followed by the remaining deltas in order of first arrival.
+    /// The `F_LAST` flag is set on the final delta only. Returns `None` if nothing is pending.
+    pub fn drain(&mut self) -> Option<OrderBookDeltas> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut deltas: Vec<OrderBookDelta> = Vec::with_capacity(self.len());
+        deltas.extend(self.clear.take());
+        deltas.extend(self.pending.drain(..).map(|(_, delta)| delta));
+
+        let last_index = deltas.len() - 1;
+        for (i, delta) in deltas.iter_mut().enumerate() {
+            if i == last_index {
+                delta.flags |= RecordFlag::F_LAST as u8;
+            } else {
+                delta.flags &= !(RecordFlag::F_LAST as u8);
+            }
+        }
+        self.emitted += deltas.len() as u64;
+
+        Some(OrderBookDeltas::new(self.instrument_id, deltas))
+    }
+
+    /// Discards all pending deltas without delivering them.
+    pub fn reset(&mut self) {
+        self.clear = None;
+        self.pending.clear();
+    }
+
+    fn key_for(&self, delta: &OrderBookDelta) -> ConflationKey {
+        match self.book_type {
+            BookType::L3_MBO => ConflationKey::Order(delta.order.order_id),
+            BookType::L1_MBP | BookType::L2_MBP => {
+                ConflationKey::Level(delta.order.side, delta.order.price.raw)
+            }
+        }
+    }
+
+    fn merge(prev: &OrderBookDelta, mut next: OrderBookDelta) -> OrderBookDelta {
+        if next.order.side == OrderSide::NoOrderSide {
+            next.order.side = prev.order.side;
+        }
+
+        // The consumer has not yet seen the add, so the update must still create the order
+        if prev.action == BookAction::Add && next.action == BookAction::Update {
+            next.action = BookAction::Add;
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        orderbook::OrderBook,
+        types::{Price, Quantity},
+    };
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    type Levels = Vec<(Price, f64)>;
+
+    fn snapshot(book: &OrderBook) -> (Levels, Levels) {
+        (
+            book.bids(None).map(|l| (l.price.value, l.size())).collect(),
+            book.asks(None).map(|l| (l.price.value, l.size())).collect(),
+        )
+    }
+
+    #[rstest]
+    fn test_drain_empty_returns_none() {
+        let mut conflator =
+            OrderBookDeltaConflator::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        assert!(conflator.is_empty());
+        assert!(conflator.drain().is_none());
+    }
+
+    #[rstest]
+    fn test_push_instrument_mismatch_errors() {
+        let mut conflator =
+            OrderBookDeltaConflator::new(InstrumentId::from("MSFT.XNAS"), BookType::L2_MBP);
+
+        let result = conflator.push(delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1));
+
+        assert!(result.is_err());
+        assert_eq!(conflator.received_count(), 0);
+    }
+
+    #[rstest]
+    fn test_l2_updates_for_same_level_are_merged() {
+        let mut conflator =
+            OrderBookDeltaConflator::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        conflator
+            .push(delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1))
+            .unwrap();
+        conflator
+            .push(delta(
+                BookAction::Update,
+                OrderSide::Buy,
+                "100.00",
+                "20",
+                0,
+                2,
+            ))
+            .unwrap();
+        conflator
+            .push(delta(
+                BookAction::Update,
+                OrderSide::Buy,
+                "100.00",
+                "15",
+                0,
+                3,
+            ))
+            .unwrap();
+        conflator
+            .push(delta(BookAction::Add, OrderSide::Sell, "101.00", "5", 0, 4))
+            .unwrap();
+
+        assert_eq!(conflator.len(), 2);
+
+        let deltas = conflator.drain().unwrap();
+
+        assert_eq!(deltas.deltas.len(), 2);
+        assert_eq!(deltas.deltas[0].action, BookAction::Add);
+        assert_eq!(deltas.deltas[0].order.size, Quantity::from("15"));
+        assert_eq!(deltas.deltas[0].flags, 0);
+        assert_eq!(deltas.deltas[1].flags, RecordFlag::F_LAST as u8);
+        assert_eq!(deltas.sequence, 4);
+        assert_eq!(conflator.received_count(), 4);
+        assert_eq!(conflator.emitted_count(), 2);
+        assert!(conflator.is_empty());
+    }
+
+    #[rstest]
+    fn test_clear_discards_pending_and_is_emitted_first() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut conflator = OrderBookDeltaConflator::new(instrument_id, BookType::L2_MBP);
+
+        conflator
+            .push(delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 0, 1))
+            .unwrap();
+        conflator
+            .push(OrderBookDelta::clear(instrument_id, 2, 2.into(), 2.into()))
+            .unwrap();
+        conflator
+            .push(delta(BookAction::Add, OrderSide::Buy, "99.00", "3", 0, 3))
+            .unwrap();
+
+        let deltas = conflator.drain().unwrap();
+
+        assert_eq!(deltas.deltas.len(), 2);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas.deltas[1].order.price, Price::from("99.00"));
+    }
+
+    #[rstest]
+    fn test_l3_update_without_side_keeps_add_and_side() {
+        let mut conflator =
+            OrderBookDeltaConflator::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+
+        conflator
+            .push(delta(
+                BookAction::Add,
+                OrderSide::Sell,
+                "101.00",
+                "10",
+                7,
+                1,
+            ))
+            .unwrap();
+        conflator
+            .push(delta(
+                BookAction::Update,
+                OrderSide::NoOrderSide,
+                "101.50",
+                "4",
+                7,
+                2,
+            ))
+            .unwrap();
+
+        let deltas = conflator.drain().unwrap();
+
+        assert_eq!(deltas.deltas.len(), 1);
+        assert_eq!(deltas.deltas[0].action, BookAction::Add);
+        assert_eq!(deltas.deltas[0].order.side, OrderSide::Sell);
+        assert_eq!(deltas.deltas[0].order.price, Price::from("101.50"));
+    }
+
+    #[rstest]
+    #[case(BookType::L2_MBP)]
+    #[case(BookType::L3_MBO)]
+    fn test_conflated_deltas_produce_same_book(#[case] book_type: BookType) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let raw = [
+            delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 1, 1),
+            delta(BookAction::Add, OrderSide::Sell, "101.00", "10", 2, 2),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "12", 1, 3),
+            delta(BookAction::Add, OrderSide::Buy, "99.00", "5", 3, 4),
+            delta(BookAction::Delete, OrderSide::Buy, "99.00", "0", 3, 5),
+            delta(BookAction::Update, OrderSide::Sell, "101.00", "7", 2, 6),
+            delta(BookAction::Add, OrderSide::Sell, "102.00", "1", 4, 7),
+        ];
+
+        let mut expected = OrderBook::new(instrument_id, book_type);
+        expected
+            .apply_deltas(&OrderBookDeltas::new(instrument_id, raw[..3].to_vec()))
+            .unwrap();
+        let mut actual = expected.clone();
+
+        let mut conflator = OrderBookDeltaConflator::new(instrument_id, book_type);
+        for delta in &raw[3..] {
+            expected.apply_delta(delta).unwrap();
+            conflator.push(*delta).unwrap();
+        }
+        actual.apply_deltas(&conflator.drain().unwrap()).unwrap();
+
+        assert_eq!(snapshot(&actual), snapshot(&expected));
+    }
+}
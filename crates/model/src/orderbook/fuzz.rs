@@ -0,0 +1,676 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fuzzing harness for order book invariants.
+//!
+//! Decodes arbitrary bytes into plausible [`OrderBookDelta`] sequences (adds, updates, deletes
+//! and clears across all book types and record flags), applies them to an [`OrderBook`] and
+//! checks ladder/cache consistency, BBO monotonicity and checksum stability after every delta.
+//!
+//! The same entry point ([`fuzz_order_book`]) drives the property tests in this module and the
+//! `cargo-fuzz` targets under `crates/model/fuzz`.
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+
+use super::{OrderBook, ladder::BookLadder};
+use crate::{
+    data::{BookOrder, OrderBookDelta, order::OrderId},
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, RecordFlag},
+    identifiers::InstrumentId,
+    types::{Price, Quantity, fixed::FIXED_PRECISION, price::PriceRaw, quantity::QuantityRaw},
+};
+
+/// The number of input bytes consumed per decoded delta.
+pub const FUZZ_OP_LEN: usize = 6;
+
+/// The instrument ID used for fuzzed order books.
+pub const FUZZ_INSTRUMENT_ID: &str = "FUZZ.VENUE";
+
+const PRICE_PRECISION: u8 = 2;
+const PRICE_BASE_TICKS: u16 = 9_872; // 98.72, so the byte range straddles 100.00
+
+/// Runs a full fuzz iteration over `data`.
+///
+/// The first byte selects the book type; the remaining bytes are decoded into deltas with
+/// [`decode_deltas`] and applied with [`run_deltas`].
+///
+/// # Errors
+///
+/// Returns an error describing the first violated invariant.
+pub fn fuzz_order_book(data: &[u8]) -> anyhow::Result<()> {
+    let Some((selector, ops)) = data.split_first() else {
+        return Ok(());
+    };
+    let book_type = book_type_from_byte(*selector);
+    let deltas = decode_deltas(InstrumentId::from(FUZZ_INSTRUMENT_ID), book_type, ops);
+    run_deltas(book_type, &deltas).map(|_| ())
+}
+
+/// Maps a fuzz input byte to a [`BookType`].
+#[must_use]
+pub const fn book_type_from_byte(byte: u8) -> BookType {
+    match byte % 3 {
+        0 => BookType::L1_MBP,
+        1 => BookType::L2_MBP,
+        _ => BookType::L3_MBO,
+    }
+}
+
+/// Decodes `data` into a sequence of order book deltas for the given `book_type`.
+///
+/// Each delta consumes [`FUZZ_OP_LEN`] bytes (trailing bytes are ignored):
+/// action, side, price, size, target order selector and flags. Updates and deletes target
+/// orders known to be live where possible, falling back to an add otherwise, so generated
+/// sequences exercise real book transitions rather than mostly no-ops. The final delta
+/// always carries `F_LAST`.
+#[must_use]
+pub fn decode_deltas(
+    instrument_id: InstrumentId,
+    book_type: BookType,
+    data: &[u8],
+) -> Vec<OrderBookDelta> {
+    let ops: Vec<&[u8]> = data.chunks_exact(FUZZ_OP_LEN).collect();
+    let count = ops.len();
+    let mut live = LiveOrders::default();
+    let mut next_order_id: OrderId = 1;
+    let mut deltas = Vec::with_capacity(count);
+
+    for (i, op) in ops.into_iter().enumerate() {
+        let sequence = i as u64 + 1;
+        let ts_event = UnixNanos::from(sequence);
+        let is_last = op[5] & 1 == 1 || i + 1 == count;
+        let flags = decode_flags(book_type, op[5], is_last);
+        let side = if op[1] & 1 == 0 {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let price = decode_price(op[2]);
+        let size = decode_size(op[3]);
+
+        let action = match op[0] % 16 {
+            15 => BookAction::Clear,
+            12..=14 => BookAction::Delete,
+            8..=11 => BookAction::Update,
+            _ => BookAction::Add,
+        };
+
+        let delta = match action {
+            BookAction::Clear => {
+                live.clear();
+                let mut delta = OrderBookDelta::clear(instrument_id, sequence, ts_event, ts_event);
+                if is_last {
+                    delta.flags |= RecordFlag::F_LAST as u8;
+                }
+                delta
+            }
+            BookAction::Delete | BookAction::Update => {
+                if let Some((order_id, live_price)) = live.select(side, op[4]) {
+                    let target_price = if action == BookAction::Update
+                        && book_type == BookType::L3_MBO
+                        && op[4] & 0x80 != 0
+                    {
+                        price
+                    } else {
+                        live_price
+                    };
+
+                    if action == BookAction::Delete {
+                        live.remove(side, order_id);
+                    } else {
+                        live.insert(side, order_id, target_price);
+                    }
+
+                    let order = BookOrder::new(side, target_price, size, order_id);
+                    OrderBookDelta::new(
+                        instrument_id,
+                        action,
+                        order,
+                        flags,
+                        sequence,
+                        ts_event,
+                        ts_event,
+                    )
+                } else {
+                    let order_id = add_order_id(book_type, side, op[2], &mut next_order_id);
+                    live.add(book_type, side, order_id, price);
+                    let order = BookOrder::new(side, price, size, order_id);
+                    OrderBookDelta::new(
+                        instrument_id,
+                        BookAction::Add,
+                        order,
+                        flags,
+                        sequence,
+                        ts_event,
+                        ts_event,
+                    )
+                }
+            }
+            BookAction::Add => {
+                let order_id = add_order_id(book_type, side, op[2], &mut next_order_id);
+                live.add(book_type, side, order_id, price);
+                let order = BookOrder::new(side, price, size, order_id);
+                OrderBookDelta::new(
+                    instrument_id,
+                    BookAction::Add,
+                    order,
+                    flags,
+                    sequence,
+                    ts_event,
+                    ts_event,
+                )
+            }
+        };
+
+        deltas.push(delta);
+    }
+
+    deltas
+}
+
+/// Applies `deltas` to a new book of `book_type`, checking invariants after every delta.
+///
+/// Checks performed:
+/// - Ladder and cache consistency (see [`check_book_invariants`]).
+/// - Sequence and update count track the applied deltas.
+/// - BBO monotonicity for L2/L3 books (see [`check_bbo_transition`]).
+/// - Checksum stability at every batch boundary (`F_LAST`): a book rebuilt from the current
+///   levels must hash identically.
+/// - Determinism: replaying the same deltas into a fresh book yields the same checksum.
+///
+/// # Errors
+///
+/// Returns an error describing the first violated invariant, or if a delta fails to apply.
+pub fn run_deltas(book_type: BookType, deltas: &[OrderBookDelta]) -> anyhow::Result<OrderBook> {
+    let instrument_id = deltas.first().map_or_else(
+        || InstrumentId::from(FUZZ_INSTRUMENT_ID),
+        |d| d.instrument_id,
+    );
+    let mut book = OrderBook::new(instrument_id, book_type);
+
+    for (i, delta) in deltas.iter().enumerate() {
+        let before = (book.best_bid_price(), book.best_ask_price());
+        book.apply_delta(delta)?;
+        let after = (book.best_bid_price(), book.best_ask_price());
+
+        anyhow::ensure!(
+            book.update_count == i as u64 + 1,
+            "Update count {} after {} deltas",
+            book.update_count,
+            i + 1
+        );
+        anyhow::ensure!(
+            book.sequence == delta.sequence,
+            "Book sequence {} != delta sequence {}",
+            book.sequence,
+            delta.sequence
+        );
+        check_book_invariants(&book)?;
+
+        if book_type != BookType::L1_MBP {
+            check_bbo_transition(delta.action, before, after)?;
+        }
+
+        if RecordFlag::F_LAST.matches(delta.flags) {
+            check_checksum_stable(&book)?;
+        }
+    }
+
+    let mut replay = OrderBook::new(instrument_id, book_type);
+    for delta in deltas {
+        replay.apply_delta(delta)?;
+    }
+    anyhow::ensure!(
+        replay.checksum(None) == book.checksum(None),
+        "Checksum differs between identical replays"
+    );
+
+    Ok(book)
+}
+
+/// Checks the structural invariants of both ladders of the given `book`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - A level is empty, keyed under a different price, or on the wrong side.
+/// - An order's price or side does not match its level, or its size is not positive.
+/// - The order ID cache does not exactly index the orders held in the levels.
+/// - Levels are not in best-first price order.
+/// - An L1 book holds more than one level per side, or an L2 level holds more than one order.
+pub fn check_book_invariants(book: &OrderBook) -> anyhow::Result<()> {
+    check_ladder(&book.bids, book.book_type)?;
+    check_ladder(&book.asks, book.book_type)?;
+
+    let bid_prices: Vec<Price> = book.bids(None).map(|level| level.price.value).collect();
+    anyhow::ensure!(
+        bid_prices.windows(2).all(|w| w[0] > w[1]),
+        "Bid levels not in descending price order: {bid_prices:?}"
+    );
+    let ask_prices: Vec<Price> = book.asks(None).map(|level| level.price.value).collect();
+    anyhow::ensure!(
+        ask_prices.windows(2).all(|w| w[0] < w[1]),
+        "Ask levels not in ascending price order: {ask_prices:?}"
+    );
+
+    Ok(())
+}
+
+/// Checks the best bid/ask moved in the direction implied by `action`.
+///
+/// Adding liquidity can only improve or keep the BBO; deleting or clearing can only worsen or
+/// remove it. Updates may move an order's price so are unconstrained. Applied per delta, this
+/// makes the BBO monotonic across any add-only or delete-only run within a batch.
+///
+/// # Errors
+///
+/// Returns an error if the BBO moved against the direction implied by `action`.
+pub fn check_bbo_transition(
+    action: BookAction,
+    before: (Option<Price>, Option<Price>),
+    after: (Option<Price>, Option<Price>),
+) -> anyhow::Result<()> {
+    let (bid_before, ask_before) = before;
+    let (bid_after, ask_after) = after;
+
+    match action {
+        BookAction::Add => {
+            if let Some(bid) = bid_before {
+                anyhow::ensure!(
+                    bid_after.is_some_and(|b| b >= bid),
+                    "Add worsened best bid: {bid} -> {bid_after:?}"
+                );
+            }
+            if let Some(ask) = ask_before {
+                anyhow::ensure!(
+                    ask_after.is_some_and(|a| a <= ask),
+                    "Add worsened best ask: {ask} -> {ask_after:?}"
+                );
+            }
+        }
+        BookAction::Delete | BookAction::Clear => {
+            match (bid_before, bid_after) {
+                (None, Some(bid)) => anyhow::bail!("{action:?} created best bid {bid}"),
+                (Some(before), Some(after)) => anyhow::ensure!(
+                    after <= before,
+                    "{action:?} improved best bid: {before} -> {after}"
+                ),
+                _ => {}
+            }
+            match (ask_before, ask_after) {
+                (None, Some(ask)) => anyhow::bail!("{action:?} created best ask {ask}"),
+                (Some(before), Some(after)) => anyhow::ensure!(
+                    after >= before,
+                    "{action:?} improved best ask: {before} -> {after}"
+                ),
+                _ => {}
+            }
+        }
+        BookAction::Update => {}
+    }
+
+    Ok(())
+}
+
+/// Checks that a book rebuilt from the current levels of `book` has an identical checksum.
+///
+/// # Errors
+///
+/// Returns an error if the checksums differ at full depth or at depth 10.
+pub fn check_checksum_stable(book: &OrderBook) -> anyhow::Result<()> {
+    let mut rebuilt = OrderBook::new(book.instrument_id, book.book_type);
+
+    // Insert in reverse so the rebuild does not simply mirror the original insertion order
+    let orders: Vec<BookOrder> = book
+        .bids(None)
+        .chain(book.asks(None))
+        .flat_map(|level| level.iter().copied())
+        .collect();
+    for order in orders.into_iter().rev() {
+        rebuilt.add(order, 0, book.sequence, book.ts_last);
+    }
+
+    for depth in [None, Some(10)] {
+        anyhow::ensure!(
+            rebuilt.checksum(depth) == book.checksum(depth),
+            "Checksum unstable at depth {depth:?} after rebuild"
+        );
+    }
+
+    Ok(())
+}
+
+fn check_ladder(ladder: &BookLadder, book_type: BookType) -> anyhow::Result<()> {
+    let mut order_count = 0;
+
+    for (key, level) in &ladder.levels {
+        anyhow::ensure!(
+            *key == level.price,
+            "Level keyed under {key:?} has price {:?}",
+            level.price
+        );
+        anyhow::ensure!(
+            level.price.side == ladder.side,
+            "Level {:?} on {:?} ladder",
+            level.price,
+            ladder.side
+        );
+        anyhow::ensure!(!level.is_empty(), "Empty level {:?} retained", level.price);
+
+        if book_type == BookType::L2_MBP {
+            anyhow::ensure!(
+                level.len() == 1,
+                "L2_MBP level {:?} holds {} orders",
+                level.price,
+                level.len()
+            );
+        }
+
+        for order in level.iter() {
+            anyhow::ensure!(
+                order.price == level.price.value,
+                "Order {} at {} held in level {}",
+                order.order_id,
+                order.price,
+                level.price.value
+            );
+            anyhow::ensure!(
+                order.side.as_specified() == ladder.side,
+                "Order {} side {} on {:?} ladder",
+                order.order_id,
+                order.side,
+                ladder.side
+            );
+            anyhow::ensure!(
+                order.size.is_positive(),
+                "Order {} has non-positive size {}",
+                order.order_id,
+                order.size
+            );
+            anyhow::ensure!(
+                ladder.cache.get(&order.order_id) == Some(key),
+                "Cache entry for order {} does not point at level {:?}",
+                order.order_id,
+                key
+            );
+            order_count += 1;
+        }
+    }
+
+    anyhow::ensure!(
+        ladder.cache.len() == order_count,
+        "Cache holds {} entries for {order_count} orders",
+        ladder.cache.len()
+    );
+
+    if book_type == BookType::L1_MBP {
+        anyhow::ensure!(
+            ladder.levels.len() <= 1,
+            "L1_MBP {:?} ladder holds {} levels",
+            ladder.side,
+            ladder.levels.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn decode_flags(book_type: BookType, byte: u8, is_last: bool) -> u8 {
+    let kind = usize::from((byte >> 1) % 4);
+    let flag = match book_type {
+        BookType::L1_MBP => [
+            None,
+            Some(RecordFlag::F_TOB),
+            Some(RecordFlag::F_MBP),
+            Some(RecordFlag::F_SNAPSHOT),
+        ][kind],
+        BookType::L2_MBP => [
+            None,
+            Some(RecordFlag::F_MBP),
+            Some(RecordFlag::F_SNAPSHOT),
+            None,
+        ][kind],
+        // F_TOB and F_MBP rewrite L3 order IDs, which the decoder cannot then target
+        BookType::L3_MBO => [None, Some(RecordFlag::F_SNAPSHOT), None, None][kind],
+    };
+
+    let mut flags = flag.map_or(0, |f| f as u8);
+    if is_last {
+        flags |= RecordFlag::F_LAST as u8;
+    }
+    flags
+}
+
+fn decode_price(byte: u8) -> Price {
+    let scale = (10 as PriceRaw).pow(u32::from(FIXED_PRECISION - PRICE_PRECISION));
+    let ticks = PriceRaw::from(PRICE_BASE_TICKS + u16::from(byte));
+    Price::from_raw(ticks * scale, PRICE_PRECISION)
+}
+
+fn decode_size(byte: u8) -> Quantity {
+    let scale = (10 as QuantityRaw).pow(u32::from(FIXED_PRECISION));
+    Quantity::from_raw(QuantityRaw::from(1 + byte % 100) * scale, 0)
+}
+
+fn add_order_id(
+    book_type: BookType,
+    side: OrderSide,
+    price_byte: u8,
+    next_order_id: &mut OrderId,
+) -> OrderId {
+    match book_type {
+        BookType::L1_MBP => side as OrderId,
+        // The book derives L2 IDs from price; a per-price key keeps tracking aligned with levels
+        BookType::L2_MBP => OrderId::from(price_byte) + 1,
+        BookType::L3_MBO => {
+            let order_id = *next_order_id;
+            *next_order_id += 1;
+            order_id
+        }
+    }
+}
+
+/// Tracks which orders the decoder believes are live so updates and deletes can target them.
+#[derive(Default)]
+struct LiveOrders {
+    bids: IndexMap<OrderId, Price>,
+    asks: IndexMap<OrderId, Price>,
+}
+
+impl LiveOrders {
+    fn side_mut(&mut self, side: OrderSide) -> &mut IndexMap<OrderId, Price> {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => &mut self.bids,
+            OrderSideSpecified::Sell => &mut self.asks,
+        }
+    }
+
+    fn select(&mut self, side: OrderSide, selector: u8) -> Option<(OrderId, Price)> {
+        let orders = self.side_mut(side);
+        if orders.is_empty() {
+            return None;
+        }
+        let index = usize::from(selector) % orders.len();
+        orders.get_index(index).map(|(id, price)| (*id, *price))
+    }
+
+    fn add(&mut self, book_type: BookType, side: OrderSide, order_id: OrderId, price: Price) {
+        let orders = self.side_mut(side);
+        if book_type == BookType::L1_MBP {
+            orders.clear();
+        }
+        orders.insert(order_id, price);
+    }
+
+    fn insert(&mut self, side: OrderSide, order_id: OrderId, price: Price) {
+        self.side_mut(side).insert(order_id, price);
+    }
+
+    fn remove(&mut self, side: OrderSide, order_id: OrderId) {
+        self.side_mut(side).shift_remove(&order_id);
+    }
+
+    fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from(FUZZ_INSTRUMENT_ID)
+    }
+
+    #[rstest]
+    fn test_decode_ignores_trailing_bytes_and_marks_last() {
+        let data = [0, 0, 10, 5, 0, 0, 0, 1, 200, 5, 0, 0, 7];
+        let deltas = decode_deltas(instrument_id(), BookType::L3_MBO, &data);
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].action, BookAction::Add);
+        assert!(!RecordFlag::F_LAST.matches(deltas[0].flags));
+        assert!(RecordFlag::F_LAST.matches(deltas[1].flags));
+        assert_eq!(deltas[1].order.side, OrderSide::Sell);
+        assert_eq!(deltas[1].sequence, 2);
+    }
+
+    #[rstest]
+    fn test_decode_delete_targets_live_order() {
+        // Add bid at tick 10, then delete on the bid side
+        let data = [0, 0, 10, 5, 0, 0, 12, 0, 99, 5, 0, 0];
+        let deltas = decode_deltas(instrument_id(), BookType::L3_MBO, &data);
+
+        assert_eq!(deltas[1].action, BookAction::Delete);
+        assert_eq!(deltas[1].order.order_id, deltas[0].order.order_id);
+        assert_eq!(deltas[1].order.price, deltas[0].order.price);
+
+        let book = run_deltas(BookType::L3_MBO, &deltas).unwrap();
+        assert!(!book.has_bid());
+    }
+
+    #[rstest]
+    fn test_decode_delete_without_live_order_becomes_add() {
+        let data = [12, 1, 10, 5, 0, 0];
+        let deltas = decode_deltas(instrument_id(), BookType::L2_MBP, &data);
+
+        assert_eq!(deltas[0].action, BookAction::Add);
+    }
+
+    #[rstest]
+    fn test_check_bbo_transition_rejects_worsening_add() {
+        let before = (Some(Price::from("100.00")), Some(Price::from("101.00")));
+        let after = (Some(Price::from("99.00")), Some(Price::from("101.00")));
+
+        assert!(check_bbo_transition(BookAction::Add, before, after).is_err());
+        assert!(check_bbo_transition(BookAction::Delete, before, after).is_ok());
+        assert!(check_bbo_transition(BookAction::Update, before, after).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_bbo_transition_rejects_delete_creating_level() {
+        let before = (None, None);
+        let after = (Some(Price::from("100.00")), None);
+
+        assert!(check_bbo_transition(BookAction::Delete, before, after).is_err());
+        assert!(check_bbo_transition(BookAction::Add, before, after).is_ok());
+    }
+
+    #[rstest]
+    fn test_checksum_ignores_order_ids_and_insertion_order() {
+        let mut book_a = OrderBook::new(instrument_id(), BookType::L3_MBO);
+        let mut book_b = OrderBook::new(instrument_id(), BookType::L3_MBO);
+        let price = Price::from("100.00");
+
+        book_a.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from(1), 1),
+            0,
+            1,
+            1.into(),
+        );
+        book_a.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from(2), 2),
+            0,
+            2,
+            2.into(),
+        );
+        book_b.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from(2), 7),
+            0,
+            1,
+            1.into(),
+        );
+        book_b.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from(1), 9),
+            0,
+            2,
+            2.into(),
+        );
+
+        assert_eq!(book_a.checksum(None), book_b.checksum(None));
+
+        book_b.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("101.00"),
+                Quantity::from(1),
+                10,
+            ),
+            0,
+            3,
+            3.into(),
+        );
+        assert_ne!(book_a.checksum(None), book_b.checksum(None));
+    }
+
+    #[rstest]
+    fn test_fuzz_order_book_empty_input() {
+        assert!(fuzz_order_book(&[]).is_ok());
+        assert!(fuzz_order_book(&[2]).is_ok());
+    }
+
+    proptest! {
+        #[rstest]
+        fn prop_fuzz_order_book_invariants(data in prop::collection::vec(any::<u8>(), 0..=1_200)) {
+            if let Err(e) = fuzz_order_book(&data) {
+                prop_assert!(false, "{e}");
+            }
+        }
+
+        #[rstest]
+        fn prop_l3_dense_batches_hold_invariants(
+            ops in prop::collection::vec(
+                // Narrow price range and selector space to force level contention
+                (any::<u8>(), any::<u8>(), 0u8..8, any::<u8>(), any::<u8>(), any::<u8>()),
+                1..=200,
+            )
+        ) {
+            let data: Vec<u8> = ops
+                .into_iter()
+                .flat_map(|(a, b, c, d, e, f)| [a, b, c, d, e, f])
+                .collect();
+            let deltas = decode_deltas(instrument_id(), BookType::L3_MBO, &data);
+            if let Err(e) = run_deltas(BookType::L3_MBO, &deltas) {
+                prop_assert!(false, "{e}");
+            }
+        }
+    }
+}
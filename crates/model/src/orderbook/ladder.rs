@@ -389,6 +389,28 @@ impl BookLadder {
         }
     }
 
+    /// Removes all price levels beyond the best `depth` levels, returning the number removed.
+    pub fn truncate(&mut self, depth: usize) -> usize {
+        let Some(first_pruned) = self.levels.keys().nth(depth).copied() else {
+            return 0;
+        };
+
+        let pruned = self.levels.split_off(&first_pruned);
+        for level in pruned.values() {
+            for order_id in level.orders.keys() {
+                self.cache.remove(order_id);
+            }
+        }
+
+        debug_assert_eq!(
+            self.cache.len(),
+            self.levels.values().map(|level| level.len()).sum::<usize>(),
+            "Cache size should equal total orders across all levels"
+        );
+
+        pruned.len()
+    }
+
     /// Retains only the best price level, removing all others.
     ///
     /// For L1_MBP books, this ensures only the top-of-book level is kept after
@@ -889,6 +911,43 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case(OrderSideSpecified::Buy, vec!["102.00", "101.00"])]
+    #[case(OrderSideSpecified::Sell, vec!["100.00", "101.00"])]
+    fn test_truncate_keeps_best_levels(
+        #[case] side: OrderSideSpecified,
+        #[case] expected: Vec<&str>,
+    ) {
+        let mut ladder = BookLadder::new(side, BookType::L3_MBO);
+        let order_side = match side {
+            OrderSideSpecified::Buy => OrderSide::Buy,
+            OrderSideSpecified::Sell => OrderSide::Sell,
+        };
+        for (i, price) in ["100.00", "101.00", "102.00"].into_iter().enumerate() {
+            ladder.add(
+                BookOrder::new(
+                    order_side,
+                    Price::from(price),
+                    Quantity::from(10),
+                    i as u64 + 1,
+                ),
+                0,
+            );
+        }
+
+        assert_eq!(ladder.truncate(2), 1);
+        assert_eq!(ladder.truncate(2), 0);
+        assert_eq!(ladder.len(), 2);
+        assert_eq!(ladder.cache.len(), 2);
+        let prices: Vec<Price> = ladder
+            .levels
+            .values()
+            .map(|level| level.price.value)
+            .collect();
+        let expected: Vec<Price> = expected.into_iter().map(Price::from).collect();
+        assert_eq!(prices, expected);
+    }
+
     #[rstest]
     fn test_simulate_fills_with_empty_book() {
         let ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L3_MBO);
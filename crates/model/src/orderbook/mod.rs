@@ -18,8 +18,11 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod conflation;
 pub mod display;
 pub mod error;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
 pub mod ladder;
 pub mod level;
 pub mod own;
@@ -30,6 +33,7 @@ mod tests;
 // Re-exports
 pub use crate::orderbook::{
     book::OrderBook,
+    conflation::OrderBookDeltaConflator,
     error::{BookIntegrityError, InvalidBookOperation},
     ladder::BookPrice,
     level::BookLevel,
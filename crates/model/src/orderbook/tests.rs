@@ -20,8 +20,8 @@ use rust_decimal_macros::dec;
 
 use crate::{
     data::{
-        OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, depth::OrderBookDepth10,
-        order::BookOrder, stubs::*,
+        DEPTH10_LEN, NULL_ORDER, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
+        depth::OrderBookDepth10, order::BookOrder, stubs::*,
     },
     enums::{
         AggressorSide, BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
@@ -300,6 +300,141 @@ fn test_book_get_price_for_quantity() {
     );
 }
 
+#[fixture]
+fn two_level_book() -> OrderBook {
+    let mut book = OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+    for (side, price, size) in [
+        (OrderSide::Sell, "2.010", "2.0"),
+        (OrderSide::Sell, "2.000", "1.0"),
+        (OrderSide::Buy, "1.000", "1.0"),
+        (OrderSide::Buy, "0.990", "2.0"),
+    ] {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        book.add(order, 0, 1, 2.into());
+    }
+    book
+}
+
+#[rstest]
+fn test_book_simulate_market_order(two_level_book: OrderBook) {
+    let fills = two_level_book.simulate_market_order(OrderSide::Buy, Quantity::from("1.5"));
+    assert_eq!(
+        fills,
+        vec![
+            (Price::from("2.000"), Quantity::from("1.0")),
+            (Price::from("2.010"), Quantity::from("0.5")),
+        ]
+    );
+
+    let fills = two_level_book.simulate_market_order(OrderSide::Sell, Quantity::from("2.0"));
+    assert_eq!(
+        fills,
+        vec![
+            (Price::from("1.000"), Quantity::from("1.0")),
+            (Price::from("0.990"), Quantity::from("1.0")),
+        ]
+    );
+}
+
+#[rstest]
+fn test_book_simulate_market_order_exhausts_liquidity(two_level_book: OrderBook) {
+    let fills = two_level_book.simulate_market_order(OrderSide::Buy, Quantity::from("5.0"));
+    let filled: f64 = fills.iter().map(|(_, qty)| qty.as_f64()).sum();
+
+    assert_eq!(fills.len(), 2);
+    assert_eq!(filled, 3.0);
+    assert!(
+        two_level_book
+            .simulate_market_order(OrderSide::Buy, Quantity::from("0.0"))
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_book_expected_avg_price(two_level_book: OrderBook) {
+    let qty = Quantity::from("1.5");
+
+    assert_eq!(
+        two_level_book.expected_avg_price(OrderSide::Buy, qty),
+        Some(2.003_333_333_333_333_4)
+    );
+    assert_eq!(
+        two_level_book.expected_avg_price(OrderSide::Sell, qty),
+        Some(0.996_666_666_666_666_7)
+    );
+    assert_eq!(
+        two_level_book.expected_avg_price(OrderSide::Buy, Quantity::from("3.5")),
+        None
+    );
+}
+
+#[rstest]
+fn test_book_expected_avg_price_empty_book() {
+    let book = OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+    assert_eq!(
+        book.expected_avg_price(OrderSide::Buy, Quantity::from("1.0")),
+        None
+    );
+}
+
+#[rstest]
+fn test_book_depth_limited_prunes_on_add() {
+    let mut book = OrderBook::with_max_depth(
+        InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+        BookType::L2_MBP,
+        2,
+    );
+    for (i, price) in ["1.000", "0.990", "0.980", "1.010"].into_iter().enumerate() {
+        let order = BookOrder::new(OrderSide::Buy, Price::from(price), Quantity::from("1.0"), 0);
+        book.add(order, 0, i as u64, (i as u64).into());
+    }
+
+    let bids: Vec<Price> = book.bids(None).map(|level| level.price.value).collect();
+    assert_eq!(book.max_depth(), Some(2));
+    assert_eq!(bids, vec![Price::from("1.010"), Price::from("1.000")]);
+    assert_eq!(book.best_bid_price(), Some(Price::from("1.010")));
+}
+
+#[rstest]
+fn test_book_depth_limited_apply_depth(stub_depth10: OrderBookDepth10) {
+    let mut unlimited = OrderBook::new(stub_depth10.instrument_id, BookType::L2_MBP);
+    let mut limited = OrderBook::with_max_depth(stub_depth10.instrument_id, BookType::L2_MBP, 3);
+    unlimited.apply_depth(&stub_depth10).unwrap();
+    limited.apply_depth(&stub_depth10).unwrap();
+
+    assert_eq!(limited.bids(None).count(), 3);
+    assert_eq!(limited.asks(None).count(), 3);
+    assert_eq!(limited.best_bid_price(), unlimited.best_bid_price());
+    assert_eq!(limited.best_ask_price(), unlimited.best_ask_price());
+    assert_eq!(limited.best_bid_size(), unlimited.best_bid_size());
+    assert_eq!(limited.best_ask_size(), unlimited.best_ask_size());
+}
+
+#[rstest]
+fn test_book_set_max_depth_prunes_existing_levels(stub_depth10: OrderBookDepth10) {
+    let mut book = OrderBook::new(stub_depth10.instrument_id, BookType::L2_MBP);
+    book.apply_depth(&stub_depth10).unwrap();
+    assert_eq!(book.bids(None).count(), 10);
+
+    book.set_max_depth(Some(5));
+    assert_eq!(book.bids(None).count(), 5);
+    assert_eq!(book.asks(None).count(), 5);
+
+    book.set_max_depth(None);
+    book.apply_depth(&stub_depth10).unwrap();
+    assert_eq!(book.bids(None).count(), 10);
+}
+
+#[rstest]
+#[should_panic(expected = "`max_depth` must be positive")]
+fn test_book_with_zero_max_depth_panics() {
+    let _ = OrderBook::with_max_depth(
+        InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+        BookType::L2_MBP,
+        0,
+    );
+}
+
 #[rstest]
 fn test_book_get_quantity_for_price() {
     let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -664,6 +799,65 @@ fn test_book_apply_depth(stub_depth10: OrderBookDepth10) {
     assert_eq!(book.best_ask_size().unwrap(), Quantity::from("100.0"));
 }
 
+#[rstest]
+fn test_book_to_depth10_round_trip(stub_depth10: OrderBookDepth10) {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    book.apply_depth(&stub_depth10).unwrap();
+
+    let depth = book.to_depth10(UnixNanos::from(5));
+
+    assert_eq!(depth.instrument_id, instrument_id);
+    assert_eq!(depth.ts_event, book.ts_last);
+    assert_eq!(depth.ts_init, UnixNanos::from(5));
+    assert_eq!(
+        depth.flags,
+        RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8
+    );
+    for i in 0..DEPTH10_LEN {
+        assert_eq!(depth.bids[i].price, stub_depth10.bids[i].price);
+        assert_eq!(depth.bids[i].size, stub_depth10.bids[i].size);
+        assert_eq!(depth.asks[i].price, stub_depth10.asks[i].price);
+        assert_eq!(depth.asks[i].size, stub_depth10.asks[i].size);
+        assert_eq!(depth.bid_counts[i], 1);
+        assert_eq!(depth.ask_counts[i], 1);
+    }
+}
+
+#[rstest]
+fn test_book_to_depth10_aggregates_l3_levels_and_pads() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    book.add(
+        BookOrder::new(
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from("10"),
+            1,
+        ),
+        0,
+        1,
+        1.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from("5"), 2),
+        0,
+        2,
+        2.into(),
+    );
+
+    let depth = book.to_depth10(UnixNanos::from(3));
+
+    assert_eq!(depth.bids[0].price, Price::from("99.00"));
+    assert_eq!(depth.bids[0].size, Quantity::from("15"));
+    assert_eq!(depth.bid_counts[0], 2);
+    assert_eq!(depth.bids[1].side, NULL_ORDER.side);
+    assert_eq!(depth.bid_counts[1], 0);
+    assert_eq!(depth.asks[0].side, NULL_ORDER.side);
+    assert_eq!(depth.asks[0].size, NULL_ORDER.size);
+    assert_eq!(depth.sequence, 2);
+}
+
 #[rstest]
 fn test_book_apply_depth_all_levels(stub_depth10: OrderBookDepth10) {
     let depth = stub_depth10;
@@ -26,8 +26,10 @@ pub mod market_if_touched;
 pub mod market_to_limit;
 pub mod stop_limit;
 pub mod stop_market;
+pub mod tags;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
+pub mod validation;
 
 #[cfg(any(test, feature = "stubs"))]
 pub mod stubs;
@@ -53,6 +55,7 @@ pub use crate::orders::{
     market_to_limit::MarketToLimitOrder,
     stop_limit::StopLimitOrder,
     stop_market::StopMarketOrder,
+    tags::OrderTag,
     trailing_stop_limit::TrailingStopLimitOrder,
     trailing_stop_market::TrailingStopMarketOrder,
 };
@@ -311,6 +314,17 @@ pub trait Order: 'static + Send {
     fn exec_algorithm_params(&self) -> Option<&IndexMap<Ustr, Ustr>>;
     fn exec_spawn_id(&self) -> Option<ClientOrderId>;
     fn tags(&self) -> Option<&[Ustr]>;
+
+    /// Returns the structured `key=value` tags of the order.
+    fn order_tags(&self) -> Vec<OrderTag> {
+        self.tags().map(tags::parse_order_tags).unwrap_or_default()
+    }
+
+    /// Returns whether the order has the structured tag `key=value`.
+    fn has_tag(&self, key: &str, value: &str) -> bool {
+        self.order_tags().iter().any(|tag| tag.matches(key, value))
+    }
+
     fn filled_qty(&self) -> Quantity;
     fn leaves_qty(&self) -> Quantity;
     fn overfill_qty(&self) -> Quantity;
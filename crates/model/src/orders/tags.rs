@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Structured `key=value` order tags.
+//!
+//! Order tags remain free-form strings on the order itself, tags of the form `key=value` are
+//! additionally interpreted as an [`OrderTag`] for filtering (e.g. `algo=twap`).
+
+use std::{fmt::Display, str::FromStr};
+
+use nautilus_core::correctness::FAILED;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// The separator between an order tag key and value.
+pub const TAG_SEPARATOR: char = '=';
+
+/// Represents a structured `key=value` order tag.
+///
+/// Keys must be non-empty and may contain only ASCII alphanumerics, `_`, `-` and `.`. Values must
+/// be non-empty, and may not contain whitespace, `=` or `,`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct OrderTag {
+    /// The tag key.
+    pub key: Ustr,
+    /// The tag value.
+    pub value: Ustr,
+}
+
+impl OrderTag {
+    /// Creates a new [`OrderTag`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` or `value` is invalid.
+    pub fn new_checked(key: &str, value: &str) -> anyhow::Result<Self> {
+        if key.is_empty() {
+            anyhow::bail!("invalid order tag key: empty");
+        }
+        if let Some(c) = key
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        {
+            anyhow::bail!("invalid order tag key '{key}': invalid character '{c}'");
+        }
+        if value.is_empty() {
+            anyhow::bail!("invalid order tag value for '{key}': empty");
+        }
+        if let Some(c) = value
+            .chars()
+            .find(|c| c.is_whitespace() || matches!(c, '=' | ','))
+        {
+            anyhow::bail!("invalid order tag value '{value}' for '{key}': invalid character '{c}'");
+        }
+
+        Ok(Self {
+            key: Ustr::from(key),
+            value: Ustr::from(value),
+        })
+    }
+
+    /// Creates a new [`OrderTag`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` or `value` is invalid.
+    #[must_use]
+    pub fn new(key: &str, value: &str) -> Self {
+        Self::new_checked(key, value).expect(FAILED)
+    }
+
+    /// Returns the tag in its free-form `key=value` representation.
+    #[must_use]
+    pub fn to_ustr(&self) -> Ustr {
+        Ustr::from(&self.to_string())
+    }
+
+    /// Returns whether this tag matches `key` and `value`.
+    #[must_use]
+    pub fn matches(&self, key: &str, value: &str) -> bool {
+        self.key.as_str() == key && self.value.as_str() == value
+    }
+}
+
+impl Display for OrderTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{TAG_SEPARATOR}{}", self.key, self.value)
+    }
+}
+
+impl FromStr for OrderTag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(TAG_SEPARATOR) {
+            Some((key, value)) => Self::new_checked(key, value),
+            None => anyhow::bail!("invalid order tag '{s}': expected 'key{TAG_SEPARATOR}value'"),
+        }
+    }
+}
+
+impl From<OrderTag> for Ustr {
+    fn from(tag: OrderTag) -> Self {
+        tag.to_ustr()
+    }
+}
+
+/// Returns the structured tags among free-form `tags`, skipping any which do not parse.
+#[must_use]
+pub fn parse_order_tags(tags: &[Ustr]) -> Vec<OrderTag> {
+    tags.iter()
+        .filter_map(|tag| OrderTag::from_str(tag.as_str()).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_and_display_round_trip() {
+        let tag = OrderTag::from_str("algo=twap").unwrap();
+
+        assert_eq!(tag.key.as_str(), "algo");
+        assert_eq!(tag.value.as_str(), "twap");
+        assert_eq!(tag.to_string(), "algo=twap");
+        assert!(tag.matches("algo", "twap"));
+        assert!(!tag.matches("algo", "vwap"));
+    }
+
+    #[rstest]
+    #[case("algo")]
+    #[case("=twap")]
+    #[case("algo=")]
+    #[case("al go=twap")]
+    #[case("algo=tw ap")]
+    #[case("algo=a=b")]
+    #[case("algo=a,b")]
+    fn test_invalid_tags(#[case] input: &str) {
+        assert!(OrderTag::from_str(input).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_order_tags_skips_free_form() {
+        let tags = vec![
+            Ustr::from("ENTRY"),
+            Ustr::from("algo=twap"),
+            Ustr::from("desk=rates"),
+        ];
+
+        let parsed = parse_order_tags(&tags);
+
+        assert_eq!(
+            parsed,
+            vec![
+                OrderTag::new("algo", "twap"),
+                OrderTag::new("desk", "rates")
+            ]
+        );
+    }
+}
@@ -0,0 +1,298 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pre-trade validation of order quantity, price and notional against instrument constraints.
+//!
+//! [`validate_order`] checks an order against the instrument min/max quantity, quantity
+//! increment, min/max notional, and price precision and tick grid (including any variable
+//! [`TickSizeTable`]), returning every violation with a specific rejection reason.
+
+use crate::{
+    instruments::{Instrument, InstrumentAny, tick_table::TickSizeTable},
+    orders::{Order, OrderAny},
+    types::{Money, Price, Quantity},
+};
+
+/// A violation of an instrument trading constraint by an order.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("quantity {quantity} below minimum {min}")]
+    QuantityBelowMin { quantity: Quantity, min: Quantity },
+    #[error("quantity {quantity} above maximum {max}")]
+    QuantityAboveMax { quantity: Quantity, max: Quantity },
+    #[error("quantity {quantity} precision exceeds size precision {precision}")]
+    QuantityPrecision { quantity: Quantity, precision: u8 },
+    #[error("quantity {quantity} not a multiple of size increment {increment}")]
+    QuantityIncrement {
+        quantity: Quantity,
+        increment: Quantity,
+    },
+    #[error("notional {notional} below minimum {min}")]
+    NotionalBelowMin { notional: Money, min: Money },
+    #[error("notional {notional} above maximum {max}")]
+    NotionalAboveMax { notional: Money, max: Money },
+    #[error("price {price} precision exceeds price precision {precision}")]
+    PricePrecision { price: Price, precision: u8 },
+    #[error("price {price} not on the valid price grid")]
+    PriceOffTick { price: Price },
+    #[error("price {price} below minimum {min}")]
+    PriceBelowMin { price: Price, min: Price },
+    #[error("price {price} above maximum {max}")]
+    PriceAboveMax { price: Price, max: Price },
+}
+
+impl OrderValidationError {
+    /// Returns the rejection reason code for the violation.
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::QuantityBelowMin { .. } => "QUANTITY_BELOW_MIN",
+            Self::QuantityAboveMax { .. } => "QUANTITY_ABOVE_MAX",
+            Self::QuantityPrecision { .. } => "QUANTITY_PRECISION",
+            Self::QuantityIncrement { .. } => "QUANTITY_INCREMENT",
+            Self::NotionalBelowMin { .. } => "NOTIONAL_BELOW_MIN",
+            Self::NotionalAboveMax { .. } => "NOTIONAL_ABOVE_MAX",
+            Self::PricePrecision { .. } => "PRICE_PRECISION",
+            Self::PriceOffTick { .. } => "PRICE_OFF_TICK",
+            Self::PriceBelowMin { .. } => "PRICE_BELOW_MIN",
+            Self::PriceAboveMax { .. } => "PRICE_ABOVE_MAX",
+        }
+    }
+
+    /// Returns the rejection message for the violation, prefixed by its reason code.
+    #[must_use]
+    pub fn rejection_reason(&self) -> String {
+        format!("{}: {self}", self.reason())
+    }
+}
+
+/// Validates an order `quantity` at `price` (`None` for market orders, skipping the price and
+/// notional checks) against the constraints of `instrument`.
+///
+/// Returns all violations found, empty when valid.
+#[must_use]
+pub fn validate_order_params(
+    instrument: &InstrumentAny,
+    quantity: Quantity,
+    price: Option<Price>,
+    tick_table: Option<&TickSizeTable>,
+) -> Vec<OrderValidationError> {
+    let mut errors = Vec::new();
+
+    if let Some(min) = instrument.min_quantity()
+        && quantity < min
+    {
+        errors.push(OrderValidationError::QuantityBelowMin { quantity, min });
+    }
+    if let Some(max) = instrument.max_quantity()
+        && quantity > max
+    {
+        errors.push(OrderValidationError::QuantityAboveMax { quantity, max });
+    }
+    let size_precision = instrument.size_precision();
+    let size_increment = instrument.size_increment();
+    if quantity.precision > size_precision {
+        errors.push(OrderValidationError::QuantityPrecision {
+            quantity,
+            precision: size_precision,
+        });
+    } else if size_increment.raw > 0 && !quantity.raw.is_multiple_of(size_increment.raw) {
+        errors.push(OrderValidationError::QuantityIncrement {
+            quantity,
+            increment: size_increment,
+        });
+    }
+
+    let Some(price) = price else {
+        return errors;
+    };
+
+    let price_precision = instrument.price_precision();
+    if price.precision > price_precision {
+        errors.push(OrderValidationError::PricePrecision {
+            price,
+            precision: price_precision,
+        });
+    } else {
+        let on_grid = match tick_table {
+            Some(table) => table.is_valid_price(price),
+            None => {
+                let increment = instrument.price_increment().raw;
+                increment <= 0 || price.raw % increment == 0
+            }
+        };
+        if !on_grid {
+            errors.push(OrderValidationError::PriceOffTick { price });
+        }
+    }
+    if let Some(min) = instrument.min_price()
+        && price < min
+    {
+        errors.push(OrderValidationError::PriceBelowMin { price, min });
+    }
+    if let Some(max) = instrument.max_price()
+        && price > max
+    {
+        errors.push(OrderValidationError::PriceAboveMax { price, max });
+    }
+
+    if price.is_positive() {
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        if let Some(min) = instrument.min_notional()
+            && min.currency == notional.currency
+            && notional < min
+        {
+            errors.push(OrderValidationError::NotionalBelowMin { notional, min });
+        }
+        if let Some(max) = instrument.max_notional()
+            && max.currency == notional.currency
+            && notional > max
+        {
+            errors.push(OrderValidationError::NotionalAboveMax { notional, max });
+        }
+    }
+
+    errors
+}
+
+/// Validates `order` against the constraints of `instrument`, using its limit price, or
+/// trigger price when it has no limit price.
+///
+/// Returns all violations found, empty when valid.
+#[must_use]
+pub fn validate_order(
+    instrument: &InstrumentAny,
+    order: &OrderAny,
+    tick_table: Option<&TickSizeTable>,
+) -> Vec<OrderValidationError> {
+    let mut errors = validate_order_params(
+        instrument,
+        order.quantity(),
+        order.price().or_else(|| order.trigger_price()),
+        tick_table,
+    );
+
+    // A stop-limit carries both prices, and its trigger must also be on the grid
+    if let (Some(_), Some(trigger_price)) = (order.price(), order.trigger_price()) {
+        errors.extend(
+            validate_order_params(
+                instrument,
+                order.quantity(),
+                Some(trigger_price),
+                tick_table,
+            )
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    OrderValidationError::PricePrecision { .. }
+                        | OrderValidationError::PriceOffTick { .. }
+                )
+            }),
+        );
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        instruments::{CurrencyPair, stubs::currency_pair_btcusdt},
+        orders::builder::OrderTestBuilder,
+    };
+
+    fn reasons(errors: &[OrderValidationError]) -> Vec<&'static str> {
+        errors.iter().map(OrderValidationError::reason).collect()
+    }
+
+    #[rstest]
+    fn test_valid_order_params(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let errors = validate_order_params(
+            &instrument,
+            Quantity::from("1.000000"),
+            Some(Price::from("50000.00")),
+            None,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[rstest]
+    fn test_quantity_and_price_violations(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let errors = validate_order_params(
+            &instrument,
+            Quantity::from("0.0000001"),
+            Some(Price::from("50000.001")),
+            None,
+        );
+        assert_eq!(
+            reasons(&errors),
+            vec![
+                "QUANTITY_BELOW_MIN",
+                "QUANTITY_PRECISION",
+                "PRICE_PRECISION"
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_max_quantity_violation(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let max = instrument.max_quantity().unwrap();
+        let errors =
+            validate_order_params(&instrument, max + Quantity::from("1.000000"), None, None);
+        assert_eq!(reasons(&errors), vec!["QUANTITY_ABOVE_MAX"]);
+    }
+
+    #[rstest]
+    fn test_min_notional_violation(mut currency_pair_btcusdt: CurrencyPair) {
+        currency_pair_btcusdt.min_notional = Some(Money::from("10.00 USDT"));
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let errors = validate_order_params(
+            &instrument,
+            Quantity::from("0.000100"),
+            Some(Price::from("50000.00")),
+            None,
+        );
+        assert_eq!(reasons(&errors), vec!["NOTIONAL_BELOW_MIN"]);
+    }
+
+    #[rstest]
+    fn test_validate_stop_limit_checks_trigger_grid(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("50000.00"))
+            .trigger_price(Price::from("49999.995"))
+            .quantity(Quantity::from("1.000000"))
+            .build();
+
+        let errors = validate_order(&instrument, &order, None);
+
+        assert_eq!(reasons(&errors), vec!["PRICE_PRECISION"]);
+        assert!(
+            errors[0]
+                .rejection_reason()
+                .starts_with("PRICE_PRECISION: ")
+        );
+    }
+}
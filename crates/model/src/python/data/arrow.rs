@@ -0,0 +1,150 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conversions of Arrow record batches into pyarrow tables and polars data frames.
+//!
+//! Historical quotes, trades and bars are encoded on the Rust side from their data responses (see
+//! `QuotesResponse::to_record_batch` in `nautilus-common`), so large result sets never pass
+//! through per-row Python objects.
+//!
+//! Record batches cross the boundary through the Arrow C Data Interface: pyarrow imports the
+//! exported `ArrowArray` and `ArrowSchema` structs and takes ownership of the Rust allocated
+//! buffers, so column data is shared rather than copied and no Python object is created per row.
+
+use std::{num::NonZeroUsize, ptr::addr_of_mut};
+
+use arrow::{
+    array::{Array, StructArray},
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema},
+    record_batch::RecordBatch,
+};
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::{prelude::*, types::PyList};
+
+use crate::{
+    data::{GreeksBatchConfig, GreeksSample, arrow::greeks_to_record_batch, compute_greeks_batch},
+    instruments::Instrument,
+    python::instruments::pyobject_to_instrument_any,
+};
+
+/// Converts `batch` into a `pyarrow.Table` without copying its column buffers.
+///
+/// The batch is exported through the Arrow C Data Interface and imported with
+/// `pyarrow.RecordBatch._import_from_c`, which moves the exported structs and releases the
+/// buffers once the Python objects are dropped. Schema metadata is carried across.
+///
+/// # Errors
+///
+/// Returns a `PyErr` if exporting the schema fails or `pyarrow` is not installed.
+pub fn record_batch_to_pyarrow(py: Python<'_>, batch: &RecordBatch) -> PyResult<Py<PyAny>> {
+    let data = StructArray::from(batch.clone()).into_data();
+    let mut ffi_schema =
+        FFI_ArrowSchema::try_from(batch.schema().as_ref()).map_err(to_pyvalue_err)?;
+    let mut ffi_array = FFI_ArrowArray::new(&data);
+
+    let pyarrow = py.import("pyarrow")?;
+    // The importer takes ownership of both structs, leaving them released for Rust to drop
+    let record_batch = pyarrow.getattr("RecordBatch")?.call_method1(
+        "_import_from_c",
+        (
+            addr_of_mut!(ffi_array) as usize,
+            addr_of_mut!(ffi_schema) as usize,
+        ),
+    )?;
+    let table = pyarrow
+        .getattr("Table")?
+        .call_method1("from_batches", (PyList::new(py, [record_batch])?,))?;
+    Ok(table.unbind())
+}
+
+/// Converts `batch` into a `polars.DataFrame`.
+///
+/// # Errors
+///
+/// Returns a `PyErr` if the conversion fails or `pyarrow`/`polars` are not installed.
+pub fn record_batch_to_polars(py: Python<'_>, batch: &RecordBatch) -> PyResult<Py<PyAny>> {
+    let table = record_batch_to_pyarrow(py, batch)?;
+    let frame = py.import("polars")?.call_method1("from_arrow", (table,))?;
+    Ok(frame.unbind())
+}
+
+/// Computes the historical greeks of option instruments into a single `pyarrow.Table`.
+///
+/// Each instrument has its own series of `ts_events`, `underlying_prices` and `option_prices`
+/// (all of equal length), from which the implied volatilities and greeks are computed in
+/// parallel with the GIL released.
+///
+/// # Errors
+///
+/// Returns a `PyErr` if the series lengths differ, an instrument is not an option, or the
+/// conversion fails.
+#[pyfunction]
+#[pyo3(name = "greeks_batch_to_pyarrow")]
+#[pyo3(signature = (instruments, ts_events, underlying_prices, option_prices, interest_rate=0.0425, dividend_yield=None, workers=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn py_greeks_batch_to_pyarrow(
+    py: Python<'_>,
+    instruments: Vec<Py<PyAny>>,
+    ts_events: Vec<Vec<u64>>,
+    underlying_prices: Vec<Vec<f64>>,
+    option_prices: Vec<Vec<f64>>,
+    interest_rate: f64,
+    dividend_yield: Option<f64>,
+    workers: Option<usize>,
+) -> PyResult<Py<PyAny>> {
+    let n = instruments.len();
+    if ts_events.len() != n || underlying_prices.len() != n || option_prices.len() != n {
+        return Err(to_pyvalue_err(
+            "Expected one series of timestamps and prices per instrument",
+        ));
+    }
+
+    let mut inputs = Vec::with_capacity(n);
+    for (((instrument, ts), spot), price) in instruments
+        .into_iter()
+        .zip(ts_events)
+        .zip(underlying_prices)
+        .zip(option_prices)
+    {
+        let instrument = pyobject_to_instrument_any(py, instrument)?;
+        if ts.len() != spot.len() || ts.len() != price.len() {
+            return Err(to_pyvalue_err(format!(
+                "Series lengths differ for {}",
+                instrument.id()
+            )));
+        }
+        let samples = ts
+            .into_iter()
+            .zip(spot)
+            .zip(price)
+            .map(|((ts, spot), price)| GreeksSample::new(ts.into(), spot, price))
+            .collect();
+        inputs.push((instrument, samples));
+    }
+
+    let config = GreeksBatchConfig {
+        interest_rate,
+        dividend_yield,
+        workers: workers.and_then(NonZeroUsize::new),
+    };
+    let greeks: Vec<_> = py
+        .detach(|| compute_greeks_batch(&inputs, &config))
+        .map_err(to_pyvalue_err)?
+        .into_iter()
+        .flatten()
+        .collect();
+    let batch = greeks_to_record_batch(&greeks).map_err(to_pyvalue_err)?;
+    record_batch_to_pyarrow(py, &batch)
+}
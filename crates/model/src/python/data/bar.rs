@@ -36,7 +36,7 @@ use super::data_to_pycapsule;
 use crate::{
     data::{
         Data,
-        bar::{Bar, BarSpecification, BarType},
+        bar::{Bar, BarSpecification, BarType, BarTypeParseError},
     },
     enums::{AggregationSource, BarAggregation, PriceType},
     identifiers::InstrumentId,
@@ -99,6 +99,35 @@ impl BarSpecification {
     }
 }
 
+/// Python exception types for bar data.
+pub mod exceptions {
+    use pyo3::exceptions::PyValueError;
+
+    pyo3::create_exception!(
+        model,
+        BarTypeParseError,
+        PyValueError,
+        "Raised when parsing a `BarType` from a string fails, with `kind`, `input`, `token`, `position` and `offset` attributes."
+    );
+}
+
+/// Converts a [`BarTypeParseError`] into a Python `BarTypeParseError` (a `ValueError` subclass)
+/// carrying the structured error details as attributes.
+#[must_use]
+pub fn bar_type_parse_error_to_pyerr(error: &BarTypeParseError) -> PyErr {
+    Python::attach(|py| {
+        let err = exceptions::BarTypeParseError::new_err(error.to_string());
+        let value = err.value(py);
+        // Attributes are best effort, the message already carries the same details
+        let _ = value.setattr("kind", error.kind().as_ref());
+        let _ = value.setattr("input", error.input());
+        let _ = value.setattr("token", error.token());
+        let _ = value.setattr("position", error.position());
+        let _ = value.setattr("offset", error.offset());
+        err
+    })
+}
+
 #[pymethods]
 impl BarType {
     #[new]
@@ -143,7 +172,7 @@ impl BarType {
     #[staticmethod]
     #[pyo3(name = "from_str")]
     fn py_from_str(value: &str) -> PyResult<Self> {
-        Self::from_str(value).map_err(to_pyvalue_err)
+        Self::from_str(value).map_err(|e| bar_type_parse_error_to_pyerr(&e))
     }
 
     #[staticmethod]
@@ -201,7 +230,8 @@ impl Bar {
     pub fn from_pyobject(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
         let bar_type_obj: Bound<'_, PyAny> = obj.getattr("bar_type")?.extract()?;
         let bar_type_str: String = bar_type_obj.call_method0("__str__")?.extract()?;
-        let bar_type = BarType::from(bar_type_str);
+        let bar_type =
+            BarType::from_str(&bar_type_str).map_err(|e| bar_type_parse_error_to_pyerr(&e))?;
 
         let open_py: Bound<'_, PyAny> = obj.getattr("open")?;
         let price_prec: u8 = open_py.getattr("precision")?.extract()?;
@@ -480,6 +510,24 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[rstest]
+    fn test_bar_type_py_from_str_error_details() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = BarType::py_from_str("AUD/USD.SIM-1-MINUTES-LAST-EXTERNAL").unwrap_err();
+
+            assert!(err.is_instance_of::<exceptions::BarTypeParseError>(py));
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+            let value = err.value(py);
+            let kind: String = value.getattr("kind").unwrap().extract().unwrap();
+            let token: String = value.getattr("token").unwrap().extract().unwrap();
+            let offset: usize = value.getattr("offset").unwrap().extract().unwrap();
+            assert_eq!(kind, "AGGREGATION");
+            assert_eq!(token, "MINUTES");
+            assert_eq!(offset, 14);
+        });
+    }
+
     #[rstest]
     fn test_to_dict() {
         let bar = Bar::default();
@@ -13,11 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_core::{UnixNanos, python::to_pyvalue_err};
 use pyo3::prelude::*;
 
 use crate::data::greeks::{
-    BlackScholesGreeksResult, black_scholes_greeks, imply_vol, imply_vol_and_greeks,
-    refine_vol_and_greeks,
+    BlackScholesGreeksResult, YieldCurveData, black_scholes_greeks, imply_vol,
+    imply_vol_and_greeks, refine_vol_and_greeks,
 };
 
 #[cfg(feature = "python")]
@@ -54,6 +55,70 @@ impl BlackScholesGreeksResult {
     }
 }
 
+#[pymethods]
+impl YieldCurveData {
+    #[new]
+    fn py_new(
+        curve_name: String,
+        tenors: Vec<f64>,
+        interest_rates: Vec<f64>,
+        ts_event: u64,
+        ts_init: u64,
+    ) -> PyResult<Self> {
+        if tenors.len() != interest_rates.len() || tenors.is_empty() {
+            return Err(to_pyvalue_err(
+                "`tenors` and `interest_rates` must be non-empty and of equal length",
+            ));
+        }
+        Ok(Self::new(
+            UnixNanos::from(ts_init),
+            UnixNanos::from(ts_event),
+            curve_name,
+            tenors,
+            interest_rates,
+        ))
+    }
+
+    #[getter]
+    #[pyo3(name = "curve_name")]
+    fn py_curve_name(&self) -> &str {
+        &self.curve_name
+    }
+
+    #[getter]
+    #[pyo3(name = "tenors")]
+    fn py_tenors(&self) -> Vec<f64> {
+        self.tenors.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "interest_rates")]
+    fn py_interest_rates(&self) -> Vec<f64> {
+        self.interest_rates.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[pyo3(name = "get_rate")]
+    fn py_get_rate(&self, expiry_in_years: f64) -> f64 {
+        self.get_rate(expiry_in_years)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+}
+
 /// Computes Black-Scholes greeks for given parameters using the fast compute_greeks implementation.
 ///
 /// # Errors
@@ -15,6 +15,7 @@
 
 //! Data types for the trading domain model.
 
+pub mod arrow;
 pub mod bar;
 pub mod bet;
 pub mod close;
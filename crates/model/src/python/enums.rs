@@ -625,6 +625,18 @@ impl BarAggregation {
     fn py_renko() -> Self {
         Self::Renko
     }
+
+    #[classattr]
+    #[pyo3(name = "HEIKIN_ASHI")]
+    fn py_heikin_ashi() -> Self {
+        Self::HeikinAshi
+    }
+
+    #[classattr]
+    #[pyo3(name = "RANGE")]
+    fn py_range() -> Self {
+        Self::Range
+    }
 }
 
 #[pymethods]
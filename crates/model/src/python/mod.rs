@@ -62,6 +62,11 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::data::bar::BarSpecification>()?;
     m.add_class::<crate::data::bar::BarType>()?;
     m.add_class::<crate::data::bar::Bar>()?;
+    m.add(
+        "BarTypeParseError",
+        m.py()
+            .get_type::<crate::python::data::bar::exceptions::BarTypeParseError>(),
+    )?;
     m.add_class::<crate::data::bet::Bet>()?;
     m.add_class::<crate::data::bet::BetPosition>()?;
     m.add_class::<crate::data::order::BookOrder>()?;
@@ -76,6 +81,7 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::data::close::InstrumentClose>()?;
     m.add_class::<crate::data::funding::FundingRateUpdate>()?;
     m.add_class::<crate::data::greeks::BlackScholesGreeksResult>()?;
+    m.add_class::<crate::data::greeks::YieldCurveData>()?;
     m.add_function(wrap_pyfunction!(
         crate::python::data::greeks::py_black_scholes_greeks,
         m
@@ -92,6 +98,10 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         crate::python::data::greeks::py_refine_vol_and_greeks,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::data::arrow::py_greeks_batch_to_pyarrow,
+        m
+    )?)?;
     // Enums
     m.add_class::<crate::enums::AccountType>()?;
     m.add_class::<crate::enums::AggregationSource>()?;
@@ -146,6 +156,10 @@ pub fn model(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::orders::StopMarketOrder>()?;
     m.add_class::<crate::orders::TrailingStopLimitOrder>()?;
     m.add_class::<crate::orders::TrailingStopMarketOrder>()?;
+    m.add_function(wrap_pyfunction!(
+        crate::python::orders::validation::py_validate_order,
+        m
+    )?)?;
     // Reports
     m.add_class::<crate::reports::fill::FillReport>()?;
     m.add_class::<crate::reports::order::OrderStatusReport>()?;
@@ -29,8 +29,18 @@ use crate::{
 #[pymethods]
 impl OrderBook {
     #[new]
-    fn py_new(instrument_id: InstrumentId, book_type: BookType) -> Self {
-        Self::new(instrument_id, book_type)
+    #[pyo3(signature = (instrument_id, book_type, max_depth=None))]
+    fn py_new(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        max_depth: Option<usize>,
+    ) -> PyResult<Self> {
+        if max_depth == Some(0) {
+            return Err(to_pyvalue_err("`max_depth` must be positive"));
+        }
+        let mut book = Self::new(instrument_id, book_type);
+        book.set_max_depth(max_depth);
+        Ok(book)
     }
 
     fn __repr__(&self) -> String {
@@ -343,6 +353,26 @@ impl OrderBook {
         self.simulate_fills(order)
     }
 
+    #[getter]
+    #[pyo3(name = "max_depth")]
+    fn py_max_depth(&self) -> Option<usize> {
+        self.max_depth()
+    }
+
+    #[pyo3(name = "simulate_market_order")]
+    fn py_simulate_market_order(
+        &self,
+        order_side: OrderSide,
+        qty: Quantity,
+    ) -> Vec<(Price, Quantity)> {
+        self.simulate_market_order(order_side, qty)
+    }
+
+    #[pyo3(name = "expected_avg_price")]
+    fn py_expected_avg_price(&self, order_side: OrderSide, qty: Quantity) -> Option<f64> {
+        self.expected_avg_price(order_side, qty)
+    }
+
     #[pyo3(name = "pprint")]
     #[pyo3(signature = (num_levels=3, group_size=None))]
     fn py_pprint(&self, num_levels: usize, group_size: Option<Decimal>) -> String {
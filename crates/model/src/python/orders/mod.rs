@@ -33,6 +33,7 @@ pub mod stop_limit;
 pub mod stop_market;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
+pub mod validation;
 
 /// Converts a Python order object into an [`OrderAny`] enum.
 ///
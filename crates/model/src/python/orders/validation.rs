@@ -0,0 +1,43 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use pyo3::prelude::*;
+
+use crate::{
+    orders::validation::validate_order,
+    python::{instruments::pyobject_to_instrument_any, orders::pyobject_to_order_any},
+};
+
+/// Validates an order against the trading constraints of its instrument.
+///
+/// Returns the rejection reasons for every violation found, empty when the order is valid.
+///
+/// # Errors
+///
+/// Returns a `PyErr` if the instrument or order cannot be extracted.
+#[pyfunction]
+#[pyo3(name = "validate_order")]
+pub fn py_validate_order(
+    py: Python,
+    instrument: Py<PyAny>,
+    order: Py<PyAny>,
+) -> PyResult<Vec<String>> {
+    let instrument = pyobject_to_instrument_any(py, instrument)?;
+    let order = pyobject_to_order_any(py, order)?;
+    Ok(validate_order(&instrument, &order, None)
+        .iter()
+        .map(|e| e.rejection_reason())
+        .collect())
+}
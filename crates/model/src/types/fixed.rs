@@ -629,6 +629,163 @@ pub fn fixed_u128_to_f64(value: u128) -> f64 {
     (value as f64) / FIXED_SCALAR
 }
 
+// -----------------------------------------------------------------------------
+// Cross-mode raw conversion
+// -----------------------------------------------------------------------------
+//
+// Raw values are always scaled to `FIXED_PRECISION`, which differs between standard (9) and
+// high-precision (16) builds. These helpers convert raw values between the two representations
+// so data produced by one build can be consumed by the other without an `f64` round-trip.
+//
+// The sentinels (`PRICE_UNDEF`/`PRICE_ERROR` as the raw type's `MAX`/`MIN`, and `QUANTITY_UNDEF`
+// as `MAX`) are mapped to the sentinels of the other representation rather than scaled.
+
+/// The fixed precision used by standard-precision builds (`i64`/`u64` raw values).
+pub const STANDARD_FIXED_PRECISION: u8 = 9;
+
+/// The fixed precision used by high-precision builds (`i128`/`u128` raw values).
+pub const HIGH_FIXED_PRECISION: u8 = 16;
+
+/// The scale between standard and high-precision raw values, 10^(16-9).
+const STANDARD_TO_HIGH_SCALE: u64 =
+    POWERS_OF_10[(HIGH_FIXED_PRECISION - STANDARD_FIXED_PRECISION) as usize];
+
+/// Converts a standard-precision signed raw value to its high-precision representation.
+///
+/// This conversion is always exact, with the `i64::MAX` and `i64::MIN` sentinels mapped to
+/// `i128::MAX` and `i128::MIN`.
+#[must_use]
+pub const fn standard_raw_i64_to_high_i128(raw: i64) -> i128 {
+    match raw {
+        i64::MAX => i128::MAX,
+        i64::MIN => i128::MIN,
+        _ => raw as i128 * STANDARD_TO_HIGH_SCALE as i128,
+    }
+}
+
+/// Converts a standard-precision unsigned raw value to its high-precision representation.
+///
+/// This conversion is always exact, with the `u64::MAX` sentinel mapped to `u128::MAX`.
+#[must_use]
+pub const fn standard_raw_u64_to_high_u128(raw: u64) -> u128 {
+    match raw {
+        u64::MAX => u128::MAX,
+        _ => raw as u128 * STANDARD_TO_HIGH_SCALE as u128,
+    }
+}
+
+/// Converts a high-precision signed raw value to its standard-precision representation.
+///
+/// The `i128::MAX` and `i128::MIN` sentinels are mapped to `i64::MAX` and `i64::MIN`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `raw` carries digits beyond [`STANDARD_FIXED_PRECISION`] decimal places.
+/// - The scaled value does not fit in an `i64`.
+pub fn high_raw_i128_to_standard_i64(raw: i128) -> anyhow::Result<i64> {
+    match raw {
+        i128::MAX => return Ok(i64::MAX),
+        i128::MIN => return Ok(i64::MIN),
+        _ => {}
+    }
+    let scale = i128::from(STANDARD_TO_HIGH_SCALE);
+    if raw % scale != 0 {
+        anyhow::bail!(
+            "Raw value {raw} has digits beyond {STANDARD_FIXED_PRECISION} decimal places and cannot be represented in standard precision"
+        );
+    }
+    i64::try_from(raw / scale)
+        .map_err(|_| anyhow::anyhow!("Raw value {raw} overflows standard precision `i64`"))
+}
+
+/// Converts a high-precision unsigned raw value to its standard-precision representation.
+///
+/// The `u128::MAX` sentinel is mapped to `u64::MAX`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `raw` carries digits beyond [`STANDARD_FIXED_PRECISION`] decimal places.
+/// - The scaled value does not fit in a `u64`.
+pub fn high_raw_u128_to_standard_u64(raw: u128) -> anyhow::Result<u64> {
+    if raw == u128::MAX {
+        return Ok(u64::MAX);
+    }
+    let scale = u128::from(STANDARD_TO_HIGH_SCALE);
+    if !raw.is_multiple_of(scale) {
+        anyhow::bail!(
+            "Raw value {raw} has digits beyond {STANDARD_FIXED_PRECISION} decimal places and cannot be represented in standard precision"
+        );
+    }
+    u64::try_from(raw / scale)
+        .map_err(|_| anyhow::anyhow!("Raw value {raw} overflows standard precision `u64`"))
+}
+
+/// Converts a [`PriceRaw`] for the current build to a high-precision `i128` raw value.
+#[must_use]
+#[inline]
+pub fn price_raw_to_high(raw: PriceRaw) -> i128 {
+    #[cfg(feature = "high-precision")]
+    {
+        raw
+    }
+    #[cfg(not(feature = "high-precision"))]
+    {
+        standard_raw_i64_to_high_i128(raw)
+    }
+}
+
+/// Converts a high-precision `i128` raw value to a [`PriceRaw`] for the current build.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be represented in standard precision
+/// (only possible when the `high-precision` feature is disabled).
+#[inline]
+pub fn price_raw_from_high(raw: i128) -> anyhow::Result<PriceRaw> {
+    #[cfg(feature = "high-precision")]
+    {
+        Ok(raw)
+    }
+    #[cfg(not(feature = "high-precision"))]
+    {
+        high_raw_i128_to_standard_i64(raw)
+    }
+}
+
+/// Converts a [`QuantityRaw`] for the current build to a high-precision `u128` raw value.
+#[must_use]
+#[inline]
+pub fn quantity_raw_to_high(raw: QuantityRaw) -> u128 {
+    #[cfg(feature = "high-precision")]
+    {
+        raw
+    }
+    #[cfg(not(feature = "high-precision"))]
+    {
+        standard_raw_u64_to_high_u128(raw)
+    }
+}
+
+/// Converts a high-precision `u128` raw value to a [`QuantityRaw`] for the current build.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be represented in standard precision
+/// (only possible when the `high-precision` feature is disabled).
+#[inline]
+pub fn quantity_raw_from_high(raw: u128) -> anyhow::Result<QuantityRaw> {
+    #[cfg(feature = "high-precision")]
+    {
+        Ok(raw)
+    }
+    #[cfg(not(feature = "high-precision"))]
+    {
+        high_raw_u128_to_standard_u64(raw)
+    }
+}
+
 #[cfg(feature = "high-precision")]
 #[cfg(test)]
 mod tests {
@@ -1282,4 +1439,76 @@ mod tests {
         assert!(check_fixed_raw_i64(i64::MAX, FIXED_PRECISION).is_ok());
         assert!(check_fixed_raw_i64(i64::MIN, FIXED_PRECISION).is_ok());
     }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 10_000_000)]
+    #[case(-1, -10_000_000)]
+    #[case(1_500_000_000, 15_000_000_000_000_000)]
+    #[case(i64::MAX - 1, (i64::MAX - 1) as i128 * 10_000_000)]
+    #[case(i64::MAX, i128::MAX)]
+    #[case(i64::MIN, i128::MIN)]
+    fn test_standard_raw_i64_to_high_round_trip(#[case] raw: i64, #[case] expected: i128) {
+        let high = standard_raw_i64_to_high_i128(raw);
+        assert_eq!(high, expected);
+        assert_eq!(high_raw_i128_to_standard_i64(high).unwrap(), raw);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(-9_999_999)]
+    #[case(15_000_000_000_000_001)]
+    fn test_high_raw_i128_to_standard_sub_precision_errors(#[case] raw: i128) {
+        assert!(high_raw_i128_to_standard_i64(raw).is_err());
+    }
+
+    #[rstest]
+    fn test_high_raw_to_standard_overflow_errors() {
+        let raw = (i128::from(i64::MAX) + 1) * 10_000_000;
+        assert!(high_raw_i128_to_standard_i64(raw).is_err());
+        let raw = (u128::from(u64::MAX) + 1) * 10_000_000;
+        assert!(high_raw_u128_to_standard_u64(raw).is_err());
+    }
+
+    #[rstest]
+    fn test_standard_raw_u64_to_high_round_trip() {
+        let high = standard_raw_u64_to_high_u128(2_500_000_000);
+        assert_eq!(high, 25_000_000_000_000_000);
+        assert_eq!(high_raw_u128_to_standard_u64(high).unwrap(), 2_500_000_000);
+        assert!(high_raw_u128_to_standard_u64(high + 1).is_err());
+
+        assert_eq!(standard_raw_u64_to_high_u128(u64::MAX), u128::MAX);
+        assert_eq!(high_raw_u128_to_standard_u64(u128::MAX).unwrap(), u64::MAX);
+    }
+
+    #[rstest]
+    fn test_price_and_quantity_raw_high_round_trip() {
+        let price_raw = f64_to_fixed_i64(-1.25, 2);
+        assert_eq!(
+            price_raw_from_high(price_raw_to_high(price_raw)).unwrap(),
+            price_raw
+        );
+
+        let quantity_raw = f64_to_fixed_u64(100.5, 1);
+        assert_eq!(
+            quantity_raw_from_high(quantity_raw_to_high(quantity_raw)).unwrap(),
+            quantity_raw
+        );
+    }
+
+    #[rstest]
+    fn test_sentinels_map_across_precision_modes() {
+        use crate::types::{
+            price::{PRICE_ERROR, PRICE_UNDEF},
+            quantity::QUANTITY_UNDEF,
+        };
+
+        assert_eq!(price_raw_to_high(PRICE_UNDEF), i128::MAX);
+        assert_eq!(price_raw_to_high(PRICE_ERROR), i128::MIN);
+        assert_eq!(quantity_raw_to_high(QUANTITY_UNDEF), u128::MAX);
+
+        assert_eq!(price_raw_from_high(i128::MAX).unwrap(), PRICE_UNDEF);
+        assert_eq!(price_raw_from_high(i128::MIN).unwrap(), PRICE_ERROR);
+        assert_eq!(quantity_raw_from_high(u128::MAX).unwrap(), QUANTITY_UNDEF);
+    }
 }
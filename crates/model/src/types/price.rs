@@ -49,7 +49,9 @@ use nautilus_core::{
 use rust_decimal::{Decimal, prelude::ToPrimitive};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use super::fixed::{FIXED_PRECISION, FIXED_SCALAR, check_fixed_precision};
+use super::fixed::{
+    FIXED_PRECISION, FIXED_SCALAR, check_fixed_precision, price_raw_from_high, price_raw_to_high,
+};
 #[cfg(feature = "high-precision")]
 use super::fixed::{PRECISION_DIFF_SCALAR, f64_to_fixed_i128, fixed_i128_to_f64};
 #[cfg(not(feature = "high-precision"))]
@@ -409,6 +411,25 @@ impl Price {
         let precision = decimal.scale() as u8;
         Self::from_decimal_dp(decimal, precision)
     }
+
+    /// Returns the raw value of this instance scaled to the high-precision (16 decimal place)
+    /// representation, regardless of whether the `high-precision` feature is enabled.
+    #[must_use]
+    pub fn to_high_precision_raw(&self) -> i128 {
+        price_raw_to_high(self.raw)
+    }
+
+    /// Creates a new [`Price`] from a raw value scaled to the high-precision (16 decimal place)
+    /// representation, allowing data produced by a high-precision build to be consumed here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The raw value cannot be represented in this build's [`PriceRaw`].
+    /// - The resulting raw value or `precision` is invalid (see `from_raw_checked`).
+    pub fn from_high_precision_raw(raw: i128, precision: u8) -> anyhow::Result<Self> {
+        Self::from_raw_checked(price_raw_from_high(raw)?, precision)
+    }
 }
 
 impl FromStr for Price {
@@ -1368,4 +1389,42 @@ mod property_tests {
             prop_assert_eq!(price.precision, precision);
         }
     }
+
+    /// Strategy to generate a (precision, units) pair representable in both standard and
+    /// high-precision builds, where the price value is `units * 10^-precision`.
+    fn mode_agnostic_price_strategy() -> impl Strategy<Value = (u8, i64)> {
+        (0u8..=6, -1_000_000_000i64..=1_000_000_000)
+    }
+
+    proptest! {
+        /// Property: addition and subtraction agree with `Decimal` arithmetic in either mode
+        #[rstest]
+        fn prop_price_arithmetic_parity_with_decimal(
+            (precision_a, units_a) in mode_agnostic_price_strategy(),
+            (precision_b, units_b) in mode_agnostic_price_strategy(),
+        ) {
+            let dec_a = Decimal::new(units_a, u32::from(precision_a));
+            let dec_b = Decimal::new(units_b, u32::from(precision_b));
+            let a = Price::from_decimal_dp(dec_a, precision_a).unwrap();
+            let b = Price::from_decimal_dp(dec_b, precision_b).unwrap();
+
+            prop_assert_eq!((a + b).as_decimal(), dec_a + dec_b);
+            prop_assert_eq!((a - b).as_decimal(), dec_a - dec_b);
+            prop_assert_eq!(a.cmp(&b), dec_a.cmp(&dec_b));
+        }
+
+        /// Property: converting through the high-precision raw representation is lossless
+        #[rstest]
+        fn prop_price_high_precision_raw_round_trip(
+            (precision, units) in mode_agnostic_price_strategy(),
+        ) {
+            let price = Price::from_decimal_dp(Decimal::new(units, u32::from(precision)), precision).unwrap();
+            let high = price.to_high_precision_raw();
+            let from_high = Price::from_high_precision_raw(high, precision).unwrap();
+
+            prop_assert_eq!(from_high, price);
+            prop_assert_eq!(from_high.precision, precision);
+            prop_assert_eq!(high, i128::from(units) * 10i128.pow(16 - u32::from(precision)));
+        }
+    }
 }
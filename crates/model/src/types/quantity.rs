@@ -51,7 +51,10 @@ use nautilus_core::{
 use rust_decimal::{Decimal, prelude::ToPrimitive};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use super::fixed::{FIXED_PRECISION, FIXED_SCALAR, MAX_FLOAT_PRECISION, check_fixed_precision};
+use super::fixed::{
+    FIXED_PRECISION, FIXED_SCALAR, MAX_FLOAT_PRECISION, check_fixed_precision,
+    quantity_raw_from_high, quantity_raw_to_high,
+};
 #[cfg(not(feature = "high-precision"))]
 use super::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
 #[cfg(feature = "high-precision")]
@@ -394,6 +397,25 @@ impl Quantity {
         Self::from_decimal_dp(decimal, precision)
     }
 
+    /// Returns the raw value of this instance scaled to the high-precision (16 decimal place)
+    /// representation, regardless of whether the `high-precision` feature is enabled.
+    #[must_use]
+    pub fn to_high_precision_raw(&self) -> u128 {
+        quantity_raw_to_high(self.raw)
+    }
+
+    /// Creates a new [`Quantity`] from a raw value scaled to the high-precision (16 decimal place)
+    /// representation, allowing data produced by a high-precision build to be consumed here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The raw value cannot be represented in this build's [`QuantityRaw`].
+    /// - The resulting raw value or `precision` is invalid (see `from_raw_checked`).
+    pub fn from_high_precision_raw(raw: u128, precision: u8) -> anyhow::Result<Self> {
+        Self::from_raw_checked(quantity_raw_from_high(raw)?, precision)
+    }
+
     /// Creates a new [`Quantity`] from a U256 amount with specified precision.
     ///
     /// # Errors
@@ -1584,4 +1606,64 @@ mod property_tests {
             prop_assert_eq!(quantity.precision, precision);
         }
     }
+
+    /// Strategy to generate a (precision, units) pair representable in both standard and
+    /// high-precision builds, where the quantity value is `units * 10^-precision`.
+    fn mode_agnostic_quantity_strategy() -> impl Strategy<Value = (u8, u64)> {
+        (0u8..=6, 0u64..=1_000_000_000)
+    }
+
+    proptest! {
+        /// Property: addition and subtraction agree with `Decimal` arithmetic in either mode
+        #[rstest]
+        fn prop_quantity_arithmetic_parity_with_decimal(
+            (precision_a, units_a) in mode_agnostic_quantity_strategy(),
+            (precision_b, units_b) in mode_agnostic_quantity_strategy(),
+        ) {
+            let dec_a = Decimal::from_i128_with_scale(i128::from(units_a), u32::from(precision_a));
+            let dec_b = Decimal::from_i128_with_scale(i128::from(units_b), u32::from(precision_b));
+            let a = Quantity::from_decimal_dp(dec_a, precision_a).unwrap();
+            let b = Quantity::from_decimal_dp(dec_b, precision_b).unwrap();
+
+            prop_assert_eq!((a + b).as_decimal(), dec_a + dec_b);
+            let (hi, lo, dec_hi, dec_lo) = if a >= b { (a, b, dec_a, dec_b) } else { (b, a, dec_b, dec_a) };
+            prop_assert_eq!((hi - lo).as_decimal(), dec_hi - dec_lo);
+        }
+
+        /// Property: multiplication agrees with `Decimal` arithmetic in either mode
+        #[rstest]
+        fn prop_quantity_mul_parity_with_decimal(
+            (precision, units_a, units_b) in (0u8..=3).prop_flat_map(|precision| {
+                // Keep values <= 4 so the raw product cannot overflow a standard `u64`
+                let max_units = 4 * 10u64.pow(u32::from(precision));
+                (Just(precision), 0..=max_units, 0..=max_units)
+            }),
+        ) {
+            let dec_a = Decimal::from_i128_with_scale(i128::from(units_a), u32::from(precision));
+            let dec_b = Decimal::from_i128_with_scale(i128::from(units_b), u32::from(precision));
+            let a = Quantity::from_decimal_dp(dec_a, precision).unwrap();
+            let b = Quantity::from_decimal_dp(dec_b, precision).unwrap();
+
+            let product = a * b;
+            let expected_raw = dec_a * dec_b * Decimal::from(10u64.pow(u32::from(FIXED_PRECISION)));
+            #[allow(clippy::unnecessary_cast)]
+            let product_raw = Decimal::from_i128_with_scale(product.raw as i128, 0);
+            prop_assert_eq!(product_raw, expected_raw);
+            prop_assert_eq!(product.precision, precision);
+        }
+
+        /// Property: converting through the high-precision raw representation is lossless
+        #[rstest]
+        fn prop_quantity_high_precision_raw_round_trip(
+            (precision, units) in mode_agnostic_quantity_strategy(),
+        ) {
+            let dec = Decimal::from_i128_with_scale(i128::from(units), u32::from(precision));
+            let quantity = Quantity::from_decimal_dp(dec, precision).unwrap();
+            let high = quantity.to_high_precision_raw();
+            let from_high = Quantity::from_high_precision_raw(high, precision).unwrap();
+
+            prop_assert_eq!(from_high, quantity);
+            prop_assert_eq!(high, u128::from(units) * 10u128.pow(16 - u32::from(precision)));
+        }
+    }
 }
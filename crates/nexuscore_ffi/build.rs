@@ -0,0 +1,56 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Build script for the `nexuscore-ffi` crate.
+//!
+//! When the `ffi` feature flag is enabled, generates the `include/nexuscore.h` C header with
+//! [`cbindgen`](https://github.com/mozilla/cbindgen) so that C/C++/C# frontends can link against
+//! the static or dynamic library. The header is a build artifact and is not committed.
+//! Generation is skipped in the docs.rs build environment.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    // Skip file generation if we're in the docs.rs environment
+    if std::env::var("DOCS_RS").is_ok() {
+        println!("cargo:warning=Running in docs.rs environment, skipping file generation");
+    } else {
+        #[cfg(feature = "ffi")]
+        generate_header();
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[allow(
+    clippy::expect_used,
+    reason = "Build script may panic on misconfiguration"
+)]
+fn generate_header() {
+    use std::path::PathBuf;
+
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let include_dir = crate_dir.join("include");
+    std::fs::create_dir_all(&include_dir).expect("unable to create include directory");
+
+    let config = cbindgen::Config::from_file("cbindgen.toml")
+        .expect("unable to find cbindgen.toml configuration file");
+
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("unable to generate bindings")
+        .write_to_file(include_dir.join("nexuscore.h"));
+}
@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock entry points.
+//!
+//! A [`NexusClock`] is an opaque handle owned by the frontend, created with
+//! [`nexuscore_clock_new`] and released with [`nexuscore_clock_drop`].
+
+use nautilus_common::clock::{Clock, TestClock};
+use nautilus_core::time::nanos_since_unix_epoch;
+
+use crate::{NexusStatus, abort_on_panic};
+
+/// Opaque clock handle for the C API.
+///
+/// The clock is driven by the frontend (for backtests, simulation or replay) via
+/// [`nexuscore_clock_set_time`] and [`nexuscore_clock_advance_time`].
+#[derive(Debug, Default)]
+pub struct NexusClock {
+    clock: TestClock,
+}
+
+/// Returns the current wall clock time as UNIX nanoseconds.
+#[unsafe(no_mangle)]
+pub extern "C" fn nexuscore_realtime_ns() -> u64 {
+    abort_on_panic(nanos_since_unix_epoch)
+}
+
+/// Creates a new clock handle initialized at the UNIX epoch.
+///
+/// The handle must be released with [`nexuscore_clock_drop`].
+#[unsafe(no_mangle)]
+pub extern "C" fn nexuscore_clock_new() -> *mut NexusClock {
+    abort_on_panic(|| Box::into_raw(Box::default()))
+}
+
+/// Releases a clock handle created with [`nexuscore_clock_new`].
+///
+/// # Safety
+///
+/// Assumes `clock` is `NULL` or a handle from [`nexuscore_clock_new`] not already released.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_clock_drop(clock: *mut NexusClock) {
+    if clock.is_null() {
+        return;
+    }
+    // SAFETY: Caller guarantees the handle was created by `nexuscore_clock_new`
+    abort_on_panic(|| drop(unsafe { Box::from_raw(clock) }));
+}
+
+/// Returns the current clock time as UNIX nanoseconds (0 for a `NULL` handle).
+///
+/// # Safety
+///
+/// Assumes `clock` is `NULL` or a valid handle from [`nexuscore_clock_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_clock_timestamp_ns(clock: *const NexusClock) -> u64 {
+    // SAFETY: Caller guarantees the handle is valid
+    let Some(clock) = (unsafe { clock.as_ref() }) else {
+        return 0;
+    };
+    abort_on_panic(|| clock.clock.timestamp_ns().as_u64())
+}
+
+/// Sets the clock time (UNIX nanoseconds) without firing timers.
+///
+/// # Safety
+///
+/// Assumes `clock` is `NULL` or a valid handle from [`nexuscore_clock_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_clock_set_time(
+    clock: *mut NexusClock,
+    to_time_ns: u64,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees the handle is valid
+    let Some(clock) = (unsafe { clock.as_mut() }) else {
+        return NexusStatus::NullPointer;
+    };
+    abort_on_panic(|| clock.clock.set_time(to_time_ns.into()));
+    NexusStatus::Ok
+}
+
+/// Advances the clock to `to_time_ns`, writing the number of timer events fired to `events_out`.
+///
+/// Returns [`NexusStatus::InvalidArgument`] if `to_time_ns` is earlier than the current time.
+///
+/// # Safety
+///
+/// Assumes `clock` is `NULL` or a valid handle from [`nexuscore_clock_new`], and `events_out`
+/// is `NULL` or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_clock_advance_time(
+    clock: *mut NexusClock,
+    to_time_ns: u64,
+    events_out: *mut usize,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees the handle is valid
+    let Some(clock) = (unsafe { clock.as_mut() }) else {
+        return NexusStatus::NullPointer;
+    };
+
+    abort_on_panic(|| {
+        if to_time_ns < clock.clock.timestamp_ns().as_u64() {
+            return NexusStatus::InvalidArgument;
+        }
+
+        let events = clock.clock.advance_time(to_time_ns.into(), true);
+        let handlers = clock.clock.match_handlers(events);
+        let count = handlers.len();
+        for handler in handlers {
+            handler.run();
+        }
+
+        if !events_out.is_null() {
+            // SAFETY: Caller guarantees `events_out` is valid for writes
+            unsafe { *events_out = count };
+        }
+        NexusStatus::Ok
+    })
+}
+
+/// Returns the number of active timers on the clock (0 for a `NULL` handle).
+///
+/// # Safety
+///
+/// Assumes `clock` is `NULL` or a valid handle from [`nexuscore_clock_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_clock_timer_count(clock: *const NexusClock) -> usize {
+    // SAFETY: Caller guarantees the handle is valid
+    let Some(clock) = (unsafe { clock.as_ref() }) else {
+        return 0;
+    };
+    abort_on_panic(|| clock.clock.timer_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_clock_lifecycle() {
+        let clock = nexuscore_clock_new();
+        unsafe {
+            assert_eq!(nexuscore_clock_timestamp_ns(clock), 0);
+            assert_eq!(nexuscore_clock_set_time(clock, 1_000), NexusStatus::Ok);
+            assert_eq!(nexuscore_clock_timestamp_ns(clock), 1_000);
+
+            let mut events = usize::MAX;
+            assert_eq!(
+                nexuscore_clock_advance_time(clock, 2_000, &raw mut events),
+                NexusStatus::Ok
+            );
+            assert_eq!(events, 0);
+            assert_eq!(nexuscore_clock_timestamp_ns(clock), 2_000);
+
+            assert_eq!(
+                nexuscore_clock_advance_time(clock, 1_000, std::ptr::null_mut()),
+                NexusStatus::InvalidArgument
+            );
+            assert_eq!(nexuscore_clock_timer_count(clock), 0);
+            nexuscore_clock_drop(clock);
+        }
+    }
+
+    #[rstest]
+    fn test_clock_null_handle() {
+        unsafe {
+            assert_eq!(nexuscore_clock_timestamp_ns(std::ptr::null()), 0);
+            assert_eq!(
+                nexuscore_clock_set_time(std::ptr::null_mut(), 1),
+                NexusStatus::NullPointer
+            );
+            nexuscore_clock_drop(std::ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! C API for embedding the nexuscore engine without Python.
+//!
+//! The `nexuscore-ffi` crate exposes a small, stable C ABI (header generated by
+//! [cbindgen](https://github.com/mozilla/cbindgen) into `include/nexuscore.h` when the `ffi`
+//! feature is enabled) so that C, C++ and C# frontends can drive the engine directly:
+//!
+//! - Message bus publish/subscribe of opaque byte payloads.
+//! - Clock handles for reading and advancing time.
+//! - Order submission and cancellation entry points routed to the risk engine.
+//!
+//! # ABI versioning
+//!
+//! The ABI follows semantic versioning independent of the crate version. The major version is
+//! incremented for any breaking change to an exported signature or `#[repr(C)]` layout, and the
+//! minor version for backwards compatible additions. Frontends should call
+//! [`nexuscore_abi_compatible`] with the version they were compiled against before any other call.
+//!
+//! All functions returning [`NexusStatus`] never panic across the boundary; a panic inside the
+//! Rust implementation aborts the process instead of unwinding into foreign code.
+//!
+//! The engine components are single-threaded: all calls for a given engine must be made from the
+//! same thread.
+
+#![warn(rustc::all)]
+#![allow(unsafe_code)]
+#![deny(unsafe_op_in_unsafe_fn)]
+#![deny(nonstandard_style)]
+#![deny(missing_debug_implementations)]
+#![deny(clippy::missing_errors_doc)]
+#![deny(clippy::missing_panics_doc)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+pub mod clock;
+pub mod msgbus;
+pub mod orders;
+
+use std::{
+    ffi::{CStr, c_char},
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+/// The major version of the C ABI (incremented on breaking changes).
+pub const NEXUSCORE_ABI_VERSION_MAJOR: u16 = 1;
+
+/// The minor version of the C ABI (incremented on backwards compatible additions).
+pub const NEXUSCORE_ABI_VERSION_MINOR: u16 = 0;
+
+/// The status code returned by fallible C API functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NexusStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was `NULL`.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// An argument failed validation.
+    InvalidArgument = 3,
+    /// The referenced handle or subscription was not found.
+    NotFound = 4,
+}
+
+/// Returns the C ABI version encoded as `(major << 16) | minor`.
+#[unsafe(no_mangle)]
+pub extern "C" fn nexuscore_abi_version() -> u32 {
+    (u32::from(NEXUSCORE_ABI_VERSION_MAJOR) << 16) | u32::from(NEXUSCORE_ABI_VERSION_MINOR)
+}
+
+/// Returns whether a frontend compiled against ABI `major.minor` can use this library (1) or not (0).
+///
+/// A frontend is compatible when the major versions match and the library minor version is at
+/// least the frontend minor version.
+#[allow(
+    clippy::absurd_extreme_comparisons,
+    reason = "The library minor version is 0 until the first additive ABI change"
+)]
+#[unsafe(no_mangle)]
+pub extern "C" fn nexuscore_abi_compatible(major: u16, minor: u16) -> u8 {
+    u8::from(major == NEXUSCORE_ABI_VERSION_MAJOR && minor <= NEXUSCORE_ABI_VERSION_MINOR)
+}
+
+/// Executes `f`, aborting the process if it panics.
+///
+/// Unwinding into foreign code is undefined behaviour, so every export routes through this.
+#[inline]
+pub(crate) fn abort_on_panic<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => process::abort(),
+    }
+}
+
+/// Converts a C string pointer into a `&str`, returning a status on failure.
+///
+/// # Safety
+///
+/// Assumes `ptr` is either `NULL` or a valid null-terminated C string which outlives `'a`.
+pub(crate) unsafe fn cstr_arg<'a>(ptr: *const c_char) -> Result<&'a str, NexusStatus> {
+    if ptr.is_null() {
+        return Err(NexusStatus::NullPointer);
+    }
+    // SAFETY: Caller guarantees `ptr` is a valid null-terminated C string
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| NexusStatus::InvalidUtf8)
+}
+
+/// Converts an optional C string pointer into an `Option<&str>` (`NULL` maps to `None`).
+///
+/// # Safety
+///
+/// Assumes `ptr` is either `NULL` or a valid null-terminated C string which outlives `'a`.
+pub(crate) unsafe fn optional_cstr_arg<'a>(
+    ptr: *const c_char,
+) -> Result<Option<&'a str>, NexusStatus> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    // SAFETY: Caller guarantees `ptr` is a valid null-terminated C string
+    unsafe { cstr_arg(ptr) }.map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_abi_version_encoding() {
+        let version = nexuscore_abi_version();
+        assert_eq!(version >> 16, u32::from(NEXUSCORE_ABI_VERSION_MAJOR));
+        assert_eq!(version & 0xFFFF, u32::from(NEXUSCORE_ABI_VERSION_MINOR));
+    }
+
+    #[rstest]
+    #[case(NEXUSCORE_ABI_VERSION_MAJOR, NEXUSCORE_ABI_VERSION_MINOR, 1)]
+    #[case(NEXUSCORE_ABI_VERSION_MAJOR, NEXUSCORE_ABI_VERSION_MINOR + 1, 0)]
+    #[case(NEXUSCORE_ABI_VERSION_MAJOR + 1, 0, 0)]
+    fn test_abi_compatible(#[case] major: u16, #[case] minor: u16, #[case] expected: u8) {
+        assert_eq!(nexuscore_abi_compatible(major, minor), expected);
+    }
+
+    #[rstest]
+    fn test_cstr_arg() {
+        assert_eq!(
+            unsafe { cstr_arg(std::ptr::null()) },
+            Err(NexusStatus::NullPointer)
+        );
+        assert_eq!(unsafe { cstr_arg(c"abc".as_ptr()) }, Ok("abc"));
+        assert_eq!(unsafe { optional_cstr_arg(std::ptr::null()) }, Ok(None));
+    }
+}
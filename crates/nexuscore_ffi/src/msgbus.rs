@@ -0,0 +1,297 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message bus entry points.
+//!
+//! Frontends publish opaque byte payloads (typically serialized with one of the message bus
+//! serializers) and subscribe callbacks to topic patterns. Payloads published from Rust as
+//! [`BusMessage`]s are also delivered to C subscribers.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    ffi::{CString, c_char, c_void},
+};
+
+use ahash::AHashMap;
+use nautilus_common::msgbus::{self, BusMessage, MStr, Pattern, ShareableMessageHandler, Topic};
+use ustr::Ustr;
+
+use crate::{NexusStatus, abort_on_panic, cstr_arg};
+
+/// A callback receiving messages for a subscription.
+///
+/// The `topic` and `payload` pointers are only valid for the duration of the call.
+pub type NexusMessageCallback = Option<
+    unsafe extern "C" fn(
+        topic: *const c_char,
+        payload: *const u8,
+        payload_len: usize,
+        user_data: *mut c_void,
+    ),
+>;
+
+/// A byte payload published on the message bus through the C API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FfiMessage {
+    /// The topic the message was published on.
+    pub topic: Ustr,
+    /// The opaque message payload.
+    pub payload: Vec<u8>,
+}
+
+thread_local! {
+    static SUBSCRIPTIONS: RefCell<AHashMap<u64, (MStr<Pattern>, ShareableMessageHandler)>> =
+        RefCell::new(AHashMap::new());
+    static NEXT_SUBSCRIPTION_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Publishes `payload_len` bytes from `payload` to `topic`.
+///
+/// # Safety
+///
+/// Assumes `topic` is a valid null-terminated C string, and `payload` is valid for reads of
+/// `payload_len` bytes (or `NULL` when `payload_len` is zero).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_msgbus_publish(
+    topic: *const c_char,
+    payload: *const u8,
+    payload_len: usize,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees `topic` is a valid C string
+    let topic = match unsafe { cstr_arg(topic) } {
+        Ok(topic) if !topic.is_empty() => topic,
+        Ok(_) => return NexusStatus::InvalidArgument,
+        Err(status) => return status,
+    };
+    if payload.is_null() && payload_len > 0 {
+        return NexusStatus::NullPointer;
+    }
+
+    let payload = if payload_len == 0 {
+        Vec::new()
+    } else {
+        // SAFETY: Caller guarantees `payload` is valid for `payload_len` bytes
+        unsafe { std::slice::from_raw_parts(payload, payload_len) }.to_vec()
+    };
+
+    // Topics must not contain wildcards
+    let Ok(topic) = MStr::<Topic>::topic(topic) else {
+        return NexusStatus::InvalidArgument;
+    };
+
+    abort_on_panic(|| {
+        let message = FfiMessage {
+            topic: *topic,
+            payload,
+        };
+        msgbus::publish_any(topic, &message);
+    });
+    NexusStatus::Ok
+}
+
+/// Subscribes `callback` to messages on topics matching `pattern`.
+///
+/// On success the subscription ID is written to `subscription_id_out` for use with
+/// [`nexuscore_msgbus_unsubscribe`]. Higher `priority` subscribers are called first.
+///
+/// # Safety
+///
+/// Assumes `pattern` is a valid null-terminated C string, `subscription_id_out` is valid for
+/// writes, and `user_data` remains valid until the subscription is removed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_msgbus_subscribe(
+    pattern: *const c_char,
+    callback: NexusMessageCallback,
+    user_data: *mut c_void,
+    priority: u8,
+    subscription_id_out: *mut u64,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees `pattern` is a valid C string
+    let pattern = match unsafe { cstr_arg(pattern) } {
+        Ok(pattern) if !pattern.is_empty() => pattern,
+        Ok(_) => return NexusStatus::InvalidArgument,
+        Err(status) => return status,
+    };
+    let Some(callback) = callback else {
+        return NexusStatus::NullPointer;
+    };
+    if subscription_id_out.is_null() {
+        return NexusStatus::NullPointer;
+    }
+
+    abort_on_panic(|| {
+        let handler = ShareableMessageHandler::from_any(move |message: &dyn Any| {
+            let (topic, payload) = if let Some(message) = message.downcast_ref::<FfiMessage>() {
+                (message.topic, message.payload.as_slice())
+            } else if let Some(message) = message.downcast_ref::<BusMessage>() {
+                (message.topic, message.payload.as_ref())
+            } else {
+                return; // Not a byte payload, cannot be delivered across the C ABI
+            };
+
+            let Ok(topic) = CString::new(topic.as_str()) else {
+                log::error!("Cannot deliver message with interior NUL in topic '{topic}'");
+                return;
+            };
+
+            // SAFETY: Frontend guarantees the callback and `user_data` remain valid
+            unsafe { callback(topic.as_ptr(), payload.as_ptr(), payload.len(), user_data) };
+        });
+
+        let pattern = MStr::<Pattern>::from(pattern);
+        msgbus::subscribe_any(pattern, handler.clone(), Some(priority));
+
+        let subscription_id = NEXT_SUBSCRIPTION_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+        SUBSCRIPTIONS.with_borrow_mut(|subs| subs.insert(subscription_id, (pattern, handler)));
+
+        // SAFETY: Caller guarantees `subscription_id_out` is valid for writes
+        unsafe { *subscription_id_out = subscription_id };
+    });
+    NexusStatus::Ok
+}
+
+/// Removes the subscription with the given ID.
+#[unsafe(no_mangle)]
+pub extern "C" fn nexuscore_msgbus_unsubscribe(subscription_id: u64) -> NexusStatus {
+    abort_on_panic(
+        || match SUBSCRIPTIONS.with_borrow_mut(|subs| subs.remove(&subscription_id)) {
+            Some((pattern, handler)) => {
+                msgbus::unsubscribe_any(pattern, handler);
+                NexusStatus::Ok
+            }
+            None => NexusStatus::NotFound,
+        },
+    )
+}
+
+/// Returns the number of subscriptions for `topic` (0 if `topic` is invalid).
+///
+/// # Safety
+///
+/// Assumes `topic` is `NULL` or a valid null-terminated C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_msgbus_subscriber_count(topic: *const c_char) -> usize {
+    // SAFETY: Caller guarantees `topic` is a valid C string
+    let Ok(topic) = (unsafe { cstr_arg(topic) }) else {
+        return 0;
+    };
+    abort_on_panic(|| msgbus::subscriptions_count_any(topic))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    unsafe extern "C" fn record(
+        topic: *const c_char,
+        payload: *const u8,
+        payload_len: usize,
+        user_data: *mut c_void,
+    ) {
+        let received = unsafe { &mut *user_data.cast::<Vec<(String, Vec<u8>)>>() };
+        let topic = unsafe { std::ffi::CStr::from_ptr(topic) }
+            .to_string_lossy()
+            .into_owned();
+        let payload = unsafe { std::slice::from_raw_parts(payload, payload_len) }.to_vec();
+        received.push((topic, payload));
+    }
+
+    #[rstest]
+    fn test_publish_subscribe_unsubscribe() {
+        let mut received: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut subscription_id = 0;
+
+        unsafe {
+            assert_eq!(
+                nexuscore_msgbus_subscribe(
+                    c"ffi.test.*".as_ptr(),
+                    Some(record),
+                    (&raw mut received).cast(),
+                    0,
+                    &raw mut subscription_id,
+                ),
+                NexusStatus::Ok
+            );
+            assert_eq!(nexuscore_msgbus_subscriber_count(c"ffi.test.a".as_ptr()), 1);
+
+            let payload = [1u8, 2, 3];
+            assert_eq!(
+                nexuscore_msgbus_publish(c"ffi.test.a".as_ptr(), payload.as_ptr(), payload.len()),
+                NexusStatus::Ok
+            );
+            msgbus::publish_any(
+                "ffi.test.b".into(),
+                &BusMessage::with_str_topic("ffi.test.b", vec![4u8].into()),
+            );
+        }
+
+        assert_eq!(
+            received,
+            vec![
+                ("ffi.test.a".to_string(), vec![1, 2, 3]),
+                ("ffi.test.b".to_string(), vec![4]),
+            ]
+        );
+
+        assert_eq!(
+            nexuscore_msgbus_unsubscribe(subscription_id),
+            NexusStatus::Ok
+        );
+        assert_eq!(
+            nexuscore_msgbus_unsubscribe(subscription_id),
+            NexusStatus::NotFound
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_arguments() {
+        let mut subscription_id = 0;
+        unsafe {
+            assert_eq!(
+                nexuscore_msgbus_publish(std::ptr::null(), std::ptr::null(), 0),
+                NexusStatus::NullPointer
+            );
+            assert_eq!(
+                nexuscore_msgbus_publish(c"".as_ptr(), std::ptr::null(), 0),
+                NexusStatus::InvalidArgument
+            );
+            assert_eq!(
+                nexuscore_msgbus_publish(c"ffi.test.*".as_ptr(), std::ptr::null(), 0),
+                NexusStatus::InvalidArgument
+            );
+            assert_eq!(
+                nexuscore_msgbus_publish(c"ffi.test".as_ptr(), std::ptr::null(), 4),
+                NexusStatus::NullPointer
+            );
+            assert_eq!(
+                nexuscore_msgbus_subscribe(
+                    c"ffi.*".as_ptr(),
+                    None,
+                    std::ptr::null_mut(),
+                    0,
+                    &raw mut subscription_id,
+                ),
+                NexusStatus::NullPointer
+            );
+        }
+    }
+}
@@ -0,0 +1,450 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2026 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Order submission entry points.
+//!
+//! Commands are sent to the risk engine execute endpoint, exactly as a Rust strategy would, so
+//! all pre-trade checks apply. Enum arguments are passed as their `u8` discriminants and
+//! quantities/prices as decimal strings so no precision is lost crossing the boundary.
+
+use std::{ffi::c_char, str::FromStr};
+
+use nautilus_common::{
+    messages::execution::{CancelOrder, SubmitOrder, TradingCommand},
+    msgbus::{self, switchboard::MessagingSwitchboard},
+};
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TimeInForce},
+    events::OrderInitialized,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    types::{Price, Quantity},
+};
+
+use crate::{NexusStatus, abort_on_panic, cstr_arg, optional_cstr_arg};
+
+/// Submits a new market or limit order.
+///
+/// `order_side`, `order_type` and `time_in_force` are the `u8` discriminants of the model enums.
+/// `price` must be provided for limit orders and `NULL` for market orders. `client_id` may be
+/// `NULL` to route by venue.
+///
+/// # Safety
+///
+/// Assumes all non-`NULL` pointer arguments are valid null-terminated C strings.
+#[allow(clippy::too_many_arguments)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_submit_order(
+    trader_id: *const c_char,
+    strategy_id: *const c_char,
+    instrument_id: *const c_char,
+    client_order_id: *const c_char,
+    client_id: *const c_char,
+    order_side: u8,
+    order_type: u8,
+    quantity: *const c_char,
+    price: *const c_char,
+    time_in_force: u8,
+    post_only: u8,
+    reduce_only: u8,
+    ts_init: u64,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees all pointer arguments are valid C strings
+    let args = unsafe {
+        (
+            cstr_arg(trader_id),
+            cstr_arg(strategy_id),
+            cstr_arg(instrument_id),
+            cstr_arg(client_order_id),
+            optional_cstr_arg(client_id),
+            cstr_arg(quantity),
+            optional_cstr_arg(price),
+        )
+    };
+    let (
+        Ok(trader_id),
+        Ok(strategy_id),
+        Ok(instrument_id),
+        Ok(client_order_id),
+        Ok(client_id),
+        Ok(quantity),
+        Ok(price),
+    ) = args
+    else {
+        return first_status(&[
+            args.0.err(),
+            args.1.err(),
+            args.2.err(),
+            args.3.err(),
+            args.4.err(),
+            args.5.err(),
+            args.6.err(),
+        ]);
+    };
+
+    abort_on_panic(|| {
+        let Some(command) = build_submit_order(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            client_id,
+            order_side,
+            order_type,
+            quantity,
+            price,
+            time_in_force,
+            post_only != 0,
+            reduce_only != 0,
+            ts_init.into(),
+        ) else {
+            return NexusStatus::InvalidArgument;
+        };
+
+        msgbus::send_trading_command(
+            MessagingSwitchboard::risk_engine_execute(),
+            TradingCommand::SubmitOrder(command),
+        );
+        NexusStatus::Ok
+    })
+}
+
+/// Cancels an open order.
+///
+/// `client_id` may be `NULL` to route by venue.
+///
+/// # Safety
+///
+/// Assumes all non-`NULL` pointer arguments are valid null-terminated C strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn nexuscore_cancel_order(
+    trader_id: *const c_char,
+    strategy_id: *const c_char,
+    instrument_id: *const c_char,
+    client_order_id: *const c_char,
+    client_id: *const c_char,
+    ts_init: u64,
+) -> NexusStatus {
+    // SAFETY: Caller guarantees all pointer arguments are valid C strings
+    let args = unsafe {
+        (
+            cstr_arg(trader_id),
+            cstr_arg(strategy_id),
+            cstr_arg(instrument_id),
+            cstr_arg(client_order_id),
+            optional_cstr_arg(client_id),
+        )
+    };
+    let (Ok(trader_id), Ok(strategy_id), Ok(instrument_id), Ok(client_order_id), Ok(client_id)) =
+        args
+    else {
+        return first_status(&[
+            args.0.err(),
+            args.1.err(),
+            args.2.err(),
+            args.3.err(),
+            args.4.err(),
+        ]);
+    };
+
+    abort_on_panic(|| {
+        let (Ok(trader_id), Ok(strategy_id), Ok(instrument_id), Ok(client_order_id), Ok(client_id)) = (
+            TraderId::new_checked(trader_id),
+            StrategyId::new_checked(strategy_id),
+            InstrumentId::from_str(instrument_id),
+            ClientOrderId::new_checked(client_order_id),
+            client_id.map(ClientId::new_checked).transpose(),
+        ) else {
+            return NexusStatus::InvalidArgument;
+        };
+
+        let command = CancelOrder::new(
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            None,
+            UUID4::new(),
+            ts_init.into(),
+            None,
+        );
+
+        msgbus::send_trading_command(
+            MessagingSwitchboard::risk_engine_execute(),
+            TradingCommand::CancelOrder(command),
+        );
+        NexusStatus::Ok
+    })
+}
+
+fn first_status(errors: &[Option<NexusStatus>]) -> NexusStatus {
+    errors
+        .iter()
+        .flatten()
+        .copied()
+        .next()
+        .unwrap_or(NexusStatus::InvalidArgument)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_submit_order(
+    trader_id: &str,
+    strategy_id: &str,
+    instrument_id: &str,
+    client_order_id: &str,
+    client_id: Option<&str>,
+    order_side: u8,
+    order_type: u8,
+    quantity: &str,
+    price: Option<&str>,
+    time_in_force: u8,
+    post_only: bool,
+    reduce_only: bool,
+    ts_init: UnixNanos,
+) -> Option<SubmitOrder> {
+    let trader_id = TraderId::new_checked(trader_id).ok()?;
+    let strategy_id = StrategyId::new_checked(strategy_id).ok()?;
+    let instrument_id = InstrumentId::from_str(instrument_id).ok()?;
+    let client_order_id = ClientOrderId::new_checked(client_order_id).ok()?;
+    let client_id = client_id.map(ClientId::new_checked).transpose().ok()?;
+
+    let order_side =
+        OrderSide::from_repr(order_side as usize).filter(|side| *side != OrderSide::NoOrderSide)?;
+    let order_type = OrderType::from_repr(order_type as usize)?;
+    let time_in_force = TimeInForce::from_repr(time_in_force as usize)?;
+
+    let quantity = Quantity::from_str(quantity).ok()?;
+    if !quantity.is_positive() {
+        return None;
+    }
+
+    let price = match (order_type, price) {
+        (OrderType::Market, None) => None,
+        (OrderType::Limit, Some(price)) => Some(Price::from_str(price).ok()?),
+        _ => return None, // Only market and limit orders are supported through the C API
+    };
+    if post_only && order_type != OrderType::Limit {
+        return None;
+    }
+
+    let order_init = OrderInitialized::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        order_side,
+        order_type,
+        quantity,
+        time_in_force,
+        post_only,
+        reduce_only,
+        false,
+        false,
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        price,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    Some(SubmitOrder::new(
+        trader_id,
+        client_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        order_init,
+        None,
+        None,
+        None,
+        UUID4::new(),
+        ts_init,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::msgbus::TypedIntoHandler;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_submit_and_cancel_order_routed_to_risk_engine() {
+        let received: Rc<RefCell<Vec<TradingCommand>>> = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        msgbus::register_trading_command_endpoint(
+            MessagingSwitchboard::risk_engine_execute(),
+            TypedIntoHandler::from(move |command: TradingCommand| {
+                received_clone.borrow_mut().push(command);
+            }),
+        );
+
+        unsafe {
+            assert_eq!(
+                nexuscore_submit_order(
+                    c"TRADER-001".as_ptr(),
+                    c"S-001".as_ptr(),
+                    c"AUD/USD.SIM".as_ptr(),
+                    c"O-001".as_ptr(),
+                    std::ptr::null(),
+                    OrderSide::Buy as u8,
+                    OrderType::Limit as u8,
+                    c"100000".as_ptr(),
+                    c"0.75000".as_ptr(),
+                    TimeInForce::Gtc as u8,
+                    1,
+                    0,
+                    1,
+                ),
+                NexusStatus::Ok
+            );
+            assert_eq!(
+                nexuscore_cancel_order(
+                    c"TRADER-001".as_ptr(),
+                    c"S-001".as_ptr(),
+                    c"AUD/USD.SIM".as_ptr(),
+                    c"O-001".as_ptr(),
+                    std::ptr::null(),
+                    2,
+                ),
+                NexusStatus::Ok
+            );
+        }
+
+        let received = received.borrow();
+        assert_eq!(received.len(), 2);
+        match &received[0] {
+            TradingCommand::SubmitOrder(command) => {
+                assert_eq!(command.client_order_id.as_str(), "O-001");
+                assert_eq!(
+                    command.order_init.price,
+                    Some(Price::from_str("0.75000").unwrap())
+                );
+                assert!(command.order_init.post_only);
+            }
+            other => panic!("Unexpected command {other:?}"),
+        }
+        assert!(matches!(received[1], TradingCommand::CancelOrder(_)));
+    }
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide as u8, OrderType::Market as u8, c"1", None)]
+    #[case(OrderSide::Buy as u8, OrderType::Limit as u8, c"1", None)]
+    #[case(OrderSide::Buy as u8, OrderType::Market as u8, c"1", Some(c"1.0"))]
+    #[case(OrderSide::Buy as u8, OrderType::StopMarket as u8, c"1", None)]
+    #[case(OrderSide::Buy as u8, OrderType::Market as u8, c"0", None)]
+    #[case(OrderSide::Buy as u8, 255, c"1", None)]
+    fn test_submit_order_invalid_arguments(
+        #[case] order_side: u8,
+        #[case] order_type: u8,
+        #[case] quantity: &std::ffi::CStr,
+        #[case] price: Option<&std::ffi::CStr>,
+    ) {
+        let status = unsafe {
+            nexuscore_submit_order(
+                c"TRADER-001".as_ptr(),
+                c"S-001".as_ptr(),
+                c"AUD/USD.SIM".as_ptr(),
+                c"O-001".as_ptr(),
+                std::ptr::null(),
+                order_side,
+                order_type,
+                quantity.as_ptr(),
+                price.map_or(std::ptr::null(), std::ffi::CStr::as_ptr),
+                TimeInForce::Gtc as u8,
+                0,
+                0,
+                1,
+            )
+        };
+        assert_eq!(status, NexusStatus::InvalidArgument);
+    }
+
+    #[rstest]
+    fn test_invalid_client_id_rejected() {
+        let status = unsafe {
+            nexuscore_submit_order(
+                c"TRADER-001".as_ptr(),
+                c"S-001".as_ptr(),
+                c"AUD/USD.SIM".as_ptr(),
+                c"O-001".as_ptr(),
+                c" ".as_ptr(),
+                OrderSide::Buy as u8,
+                OrderType::Market as u8,
+                c"1".as_ptr(),
+                std::ptr::null(),
+                TimeInForce::Gtc as u8,
+                0,
+                0,
+                1,
+            )
+        };
+        assert_eq!(status, NexusStatus::InvalidArgument);
+
+        let status = unsafe {
+            nexuscore_cancel_order(
+                c"TRADER-001".as_ptr(),
+                c"S-001".as_ptr(),
+                c"AUD/USD.SIM".as_ptr(),
+                c"O-001".as_ptr(),
+                c" ".as_ptr(),
+                1,
+            )
+        };
+        assert_eq!(status, NexusStatus::InvalidArgument);
+    }
+
+    #[rstest]
+    fn test_submit_order_null_pointer() {
+        let status = unsafe {
+            nexuscore_submit_order(
+                std::ptr::null(),
+                c"S-001".as_ptr(),
+                c"AUD/USD.SIM".as_ptr(),
+                c"O-001".as_ptr(),
+                std::ptr::null(),
+                OrderSide::Buy as u8,
+                OrderType::Market as u8,
+                c"1".as_ptr(),
+                std::ptr::null(),
+                TimeInForce::Gtc as u8,
+                0,
+                0,
+                1,
+            )
+        };
+        assert_eq!(status, NexusStatus::NullPointer);
+    }
+}